@@ -0,0 +1,158 @@
+//! [cliff3-util](https://crates.io/crates/cliff3-util)에서 사용하는 derive macro 모음입니다.
+//!
+//! 직접 사용하지 않고 `cliff3-util`의 `derive` feature를 활성화하여 사용합니다.
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Fields, Ident, ItemFn, LitStr};
+
+/// `#[mask(..)]` 인자와 `MaskKind` variant 대응 목록
+const MASK_KINDS: &[(&str, &str)] = &[
+    ("email", "Email"),
+    ("phone", "Phone"),
+    ("full", "Full"),
+    ("rrn", "Rrn"),
+    ("card", "Card"),
+    ("name", "Name"),
+];
+
+/// 함수 실행 시간 측정
+///
+/// 함수 실행 시간을 `cliff3_util::timing_util::TimerRegistry::global()`에 기록하며, `tracing` feature
+/// 활성화시 `debug` 수준의 event로도 기록한다. `async fn`에도 사용할 수 있다.
+///
+/// - `#[timed]` - `모듈 경로::함수명`을 label로 사용
+/// - `#[timed("label")]` - 지정된 label 사용
+#[proc_macro_attribute]
+pub fn timed(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let mut item = parse_macro_input!(item as ItemFn);
+    let fn_name = item.sig.ident.to_string();
+    let label = if attr.is_empty() {
+        quote! { ::core::concat!(::core::module_path!(), "::", #fn_name) }
+    } else {
+        let label = parse_macro_input!(attr as LitStr);
+
+        quote! { #label }
+    };
+    let block = &item.block;
+
+    item.block = syn::parse_quote! {{
+        let __cliff3_timing_guard = ::cliff3_util::timing_util::TimingGuard::new(#label);
+
+        #block
+    }};
+
+    quote! { #item }.into()
+}
+
+/// `Maskable` trait 및 마스킹된 [Debug] 구현 생성
+///
+/// Named field 구조체에만 사용할 수 있으며 `#[mask(..)]`이 지정된 항목은 `MaskField`를, 나머지 항목은
+/// [Clone] 및 [Debug]를 구현해야 한다. [Debug]를 직접 생성하므로 `#[derive(Debug)]`와 함께 사용할 수
+/// 없다.
+///
+/// - `#[mask(email)]` - 이메일 마스킹
+/// - `#[mask(phone)]` - 전화번호 마스킹
+/// - `#[mask(full)]` - 전체 마스킹
+/// - `#[mask(rrn)]` - 주민등록번호 마스킹
+/// - `#[mask(card)]` - 카드번호 마스킹
+/// - `#[mask(name)]` - 이름 마스킹
+#[proc_macro_derive(Maskable, attributes(mask))]
+pub fn derive_maskable(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    match expand_maskable(&input) {
+        Ok(v) => v.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+fn expand_maskable(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(v) => &v.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &input.ident,
+                    "Maskable은 named field 구조체에만 사용할 수 있습니다.",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "Maskable은 구조체에만 사용할 수 있습니다.",
+            ))
+        }
+    };
+
+    let name = &input.ident;
+    let name_str = name.to_string();
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let mut masked_fields = vec![];
+    let mut debug_fields = vec![];
+
+    for field in fields.iter() {
+        let ident = field.ident.as_ref().unwrap();
+        let ident_str = ident.to_string();
+        let mut kind: Option<Ident> = None;
+
+        for attr in field.attrs.iter().filter(|a| a.path().is_ident("mask")) {
+            attr.parse_nested_meta(|meta| {
+                if kind.is_some() {
+                    return Err(meta.error("마스킹 종류는 하나만 지정할 수 있습니다."));
+                }
+
+                let found = MASK_KINDS
+                    .iter()
+                    .find(|(arg, _)| meta.path.is_ident(arg))
+                    .ok_or_else(|| meta.error("지원하지 않는 마스킹 종류 입니다."))?;
+
+                kind = Some(Ident::new(found.1, Span::call_site()));
+
+                Ok(())
+            })?;
+        }
+
+        match kind {
+            None => {
+                masked_fields.push(quote! {
+                    #ident: ::core::clone::Clone::clone(&self.#ident)
+                });
+                debug_fields.push(quote! {
+                    .field(#ident_str, &self.#ident)
+                });
+            }
+            Some(kind) => {
+                let expr = quote! {
+                    ::cliff3_util::string_util::mask::MaskField::mask_field(
+                        &self.#ident,
+                        ::cliff3_util::string_util::mask::MaskKind::#kind,
+                    )
+                };
+
+                masked_fields.push(quote! { #ident: #expr });
+                debug_fields.push(quote! { .field(#ident_str, &#expr) });
+            }
+        }
+    }
+
+    Ok(quote! {
+        impl #impl_generics ::cliff3_util::string_util::mask::Maskable for #name #ty_generics #where_clause {
+            fn masked(&self) -> Self {
+                Self {
+                    #(#masked_fields,)*
+                }
+            }
+        }
+
+        impl #impl_generics ::core::fmt::Debug for #name #ty_generics #where_clause {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.debug_struct(#name_str)
+                    #(#debug_fields)*
+                    .finish()
+            }
+        }
+    })
+}
@@ -0,0 +1,102 @@
+use cliff3_util::string_util::mask::Maskable;
+
+#[derive(Clone, Maskable)]
+struct Member {
+    id: u64,
+
+    name: String,
+
+    #[mask(email)]
+    email: String,
+
+    #[mask(phone)]
+    phone: Option<String>,
+
+    #[mask(full)]
+    password: String,
+}
+
+#[derive(Clone, Maskable)]
+struct Wrapper<T: Clone + std::fmt::Debug> {
+    value: T,
+
+    #[mask(email)]
+    emails: Vec<String>,
+}
+
+fn member() -> Member {
+    Member {
+        id: 7,
+        name: "홍길동".to_owned(),
+        email: "hong@test.com".to_owned(),
+        phone: Some("010-1234-5678".to_owned()),
+        password: "p@ssw0rd".to_owned(),
+    }
+}
+
+#[test]
+fn masked_test() {
+    let original = member();
+    let masked = original.masked();
+
+    assert_eq!(7, masked.id);
+    assert_eq!("홍길동", masked.name);
+    assert_eq!("ho**@test.com", masked.email);
+    assert_eq!(Some("010-****-5678".to_owned()), masked.phone);
+    assert_eq!("********", masked.password);
+
+    // 원본 유지
+    assert_eq!("hong@test.com", original.email);
+}
+
+#[test]
+fn masked_debug_test() {
+    let debug = format!("{:?}", member());
+
+    assert_eq!(
+        r#"Member { id: 7, name: "홍길동", email: "ho**@test.com", phone: Some("010-****-5678"), password: "********" }"#,
+        debug
+    );
+    assert!(!debug.contains("p@ssw0rd"));
+}
+
+#[test]
+fn generic_struct_test() {
+    let wrapper = Wrapper {
+        value: 1u8,
+        emails: vec!["abc@test.com".to_owned(), "x@test.com".to_owned()],
+    };
+
+    assert_eq!(vec!["ab*@test.com", "x@test.com"], wrapper.masked().emails);
+    assert_eq!(
+        r#"Wrapper { value: 1, emails: ["ab*@test.com", "x@test.com"] }"#,
+        format!("{:?}", wrapper)
+    );
+}
+
+#[derive(Clone, Maskable)]
+struct Customer {
+    #[mask(name)]
+    name: String,
+
+    #[mask(rrn)]
+    rrn: String,
+
+    #[mask(card)]
+    card: Option<String>,
+}
+
+#[test]
+fn personal_data_kinds_test() {
+    let customer = Customer {
+        name: "홍길동".to_owned(),
+        rrn: "900101-1234567".to_owned(),
+        card: Some("1234-5678-9012-3456".to_owned()),
+    };
+
+    assert_eq!(
+        r#"Customer { name: "홍*동", rrn: "900101-1******", card: Some("1234-56**-****-3456") }"#,
+        format!("{:?}", customer)
+    );
+    assert_eq!("홍*동", customer.masked().name);
+}
@@ -0,0 +1,54 @@
+use cliff3_util::timing_util::{timed, TimerRegistry};
+
+#[timed]
+fn default_label() -> u32 {
+    1
+}
+
+#[timed("timed.early_return")]
+fn early_return(value: i32) -> Result<i32, String> {
+    if value < 0 {
+        return Err("negative".to_owned());
+    }
+
+    Ok(value * 2)
+}
+
+struct Service;
+
+impl Service {
+    #[timed("timed.method")]
+    fn call(&self, value: &str) -> usize {
+        value.len()
+    }
+}
+
+#[test]
+fn default_label_test() {
+    assert_eq!(1, default_label());
+
+    let stats = TimerRegistry::global()
+        .stats(concat!(module_path!(), "::default_label"))
+        .unwrap();
+
+    assert_eq!(1, stats.count);
+}
+
+#[test]
+fn early_return_test() {
+    assert_eq!(Ok(4), early_return(2));
+    assert!(early_return(-1).is_err());
+    assert_eq!(
+        2,
+        TimerRegistry::global()
+            .stats("timed.early_return")
+            .unwrap()
+            .count
+    );
+}
+
+#[test]
+fn method_test() {
+    assert_eq!(3, Service.call("abc"));
+    assert!(TimerRegistry::global().stats("timed.method").is_some());
+}
@@ -0,0 +1,67 @@
+/*
+ * cliff3-util C ABI
+ *
+ * - 문자열 인자는 NUL로 끝나는 UTF-8 문자열이어야 한다.
+ * - 결과 문자열은 out 인자로 반환되며 사용 후 cliff3_string_free로 해제해야 한다.
+ * - 실패할 경우 CLIFF3_OK 이외의 결과 코드를 반환하며 out에는 NULL이 기록된다.
+ *   오류 메시지는 cliff3_last_error로 확인한다(thread 단위).
+ */
+#ifndef CLIFF3_UTIL_H
+#define CLIFF3_UTIL_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#define CLIFF3_ABI_VERSION 1
+
+#define CLIFF3_OK 0
+#define CLIFF3_ERR_NULL 1
+#define CLIFF3_ERR_UTF8 2
+#define CLIFF3_ERR_INVALID 3
+#define CLIFF3_ERR_PANIC 4
+
+uint32_t cliff3_abi_version(void);
+
+char *cliff3_last_error(void);
+
+void cliff3_string_free(char *target);
+
+/* 날짜 변환(timezone은 IANA 형식, e.g. "Asia/Seoul") */
+int32_t cliff3_local_datetime_to_utc(const char *datetime, const char *pattern, const char *timezone,
+                                     char **out);
+
+int32_t cliff3_utc_datetime_to_local(const char *datetime, const char *pattern, const char *timezone,
+                                     char **out);
+
+/* 마스킹 */
+int32_t cliff3_mask_email(const char *target, char **out);
+
+int32_t cliff3_mask_phone(const char *target, char **out);
+
+int32_t cliff3_mask_full(const char *target, char **out);
+
+/* 검증(성공시 정규화된 값 반환) */
+int32_t cliff3_validate_rrn_kr(const char *target, char **out);
+
+int32_t cliff3_validate_business_number_kr(const char *target, char **out);
+
+int32_t cliff3_validate_card_number(const char *target, char **out);
+
+/* Hash */
+int32_t cliff3_sha256_hex(const uint8_t *data, size_t len, char **out);
+
+int32_t cliff3_sha512_hex(const uint8_t *data, size_t len, char **out);
+
+uint64_t cliff3_fnv1a(const uint8_t *data, size_t len);
+
+uint32_t cliff3_murmur3_32(const uint8_t *data, size_t len, uint32_t seed);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* CLIFF3_UTIL_H */
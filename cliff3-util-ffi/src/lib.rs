@@ -0,0 +1,337 @@
+//! [cliff3-util](https://crates.io/crates/cliff3-util)의 일부 기능(날짜 변환, 마스킹, 검증, hash)을 C ABI로
+//! 제공합니다.
+//!
+//! 함수 선언은 `include/cliff3_util.h`를 참고합니다.
+//!
+//! # 규칙
+//!
+//! - 모든 함수는 `cliff3_` 접두어를 사용하며 [CLIFF3_ABI_VERSION]이 변경되지 않는 한 서명을 변경하지 않는다.
+//! - 문자열 인자는 NUL(`\0`)로 끝나는 UTF-8 문자열이어야 한다.
+//! - 결과 문자열은 `out` 인자로 반환하며 호출자는 사용 후 반드시 [cliff3_string_free]로 해제해야 한다.
+//! - 결과 코드(`CLIFF3_*`)를 반환하며, 실패할 경우 [cliff3_last_error]로 오류 메시지를 확인할 수 있다.
+//!   오류 메시지는 thread 단위로 보관된다.
+//! - Rust 측 panic은 C로 전파되지 않고 [CLIFF3_ERR_PANIC]으로 반환된다.
+
+use std::cell::RefCell;
+use std::ffi::{c_char, CStr, CString};
+use std::panic::{catch_unwind, UnwindSafe};
+use std::str::FromStr;
+
+use chrono_tz::Tz;
+
+use cliff3_util::date_util::{local_datetime_to_utc, utc_datetime_to_local};
+use cliff3_util::encrypt_util::{make_sha_hash_string, SHA_TYPE};
+use cliff3_util::error::LibError;
+use cliff3_util::hash_util::{fnv1a, murmur3_32};
+use cliff3_util::string_util::mask::{mask_email, mask_full, mask_phone};
+use cliff3_util::validate_util::{validate_brn, validate_card_number, validate_rrn};
+
+/// ABI 버전. 함수 서명 혹은 결과 코드가 호환되지 않게 변경될 경우 증가한다.
+pub const CLIFF3_ABI_VERSION: u32 = 1;
+
+/// 성공
+pub const CLIFF3_OK: i32 = 0;
+
+/// `NULL` 인자
+pub const CLIFF3_ERR_NULL: i32 = 1;
+
+/// UTF-8 형식이 아닌 문자열 인자
+pub const CLIFF3_ERR_UTF8: i32 = 2;
+
+/// 잘못된 인자(검증 실패, 형식 오류 등)
+pub const CLIFF3_ERR_INVALID: i32 = 3;
+
+/// 내부 오류(panic)
+pub const CLIFF3_ERR_PANIC: i32 = 4;
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+/// 함수 실패 결과(결과 코드, 오류 메시지)
+struct FfiError(i32, String);
+
+impl FfiError {
+    fn invalid(message: impl Into<String>) -> Self {
+        FfiError(CLIFF3_ERR_INVALID, message.into())
+    }
+}
+
+fn set_last_error(message: Option<String>) {
+    LAST_ERROR.with(|last| {
+        *last.borrow_mut() = message.map(|v| CString::new(v.replace('\0', " ")).unwrap_or_default())
+    });
+}
+
+/// 결과 문자열을 `out`에 기록하고 결과 코드 반환
+///
+/// panic이 발생하거나 실패할 경우 `out`에는 `NULL`이 기록된다.
+unsafe fn run<F>(out: *mut *mut c_char, f: F) -> i32
+where
+    F: FnOnce() -> Result<String, FfiError> + UnwindSafe,
+{
+    if out.is_null() {
+        set_last_error(Some("out is null".to_owned()));
+
+        return CLIFF3_ERR_NULL;
+    }
+
+    *out = std::ptr::null_mut();
+
+    let (code, message) = match catch_unwind(f) {
+        Ok(Ok(v)) => match CString::new(v) {
+            Ok(v) => {
+                *out = v.into_raw();
+
+                return finish(CLIFF3_OK, None);
+            }
+            Err(_) => (CLIFF3_ERR_INVALID, "result contains NUL".to_owned()),
+        },
+        Ok(Err(FfiError(code, message))) => (code, message),
+        Err(_) => (CLIFF3_ERR_PANIC, "internal panic".to_owned()),
+    };
+
+    finish(code, Some(message))
+}
+
+fn finish(code: i32, message: Option<String>) -> i32 {
+    set_last_error(message);
+
+    code
+}
+
+/// C 문자열을 `&str`로 변환
+unsafe fn to_str<'a>(name: &str, target: *const c_char) -> Result<&'a str, FfiError> {
+    if target.is_null() {
+        return Err(FfiError(CLIFF3_ERR_NULL, format!("{} is null", name)));
+    }
+
+    CStr::from_ptr(target)
+        .to_str()
+        .map_err(|_| FfiError(CLIFF3_ERR_UTF8, format!("{} is not UTF-8", name)))
+}
+
+/// `(data, len)`을 byte slice로 변환. `len`이 `0`일 경우 `data`는 `NULL`일 수 있다.
+unsafe fn to_bytes<'a>(data: *const u8, len: usize) -> Result<&'a [u8], FfiError> {
+    if len == 0 {
+        return Ok(&[]);
+    }
+
+    if data.is_null() {
+        return Err(FfiError(CLIFF3_ERR_NULL, "data is null".to_owned()));
+    }
+
+    Ok(std::slice::from_raw_parts(data, len))
+}
+
+fn parse_timezone(timezone: &str) -> Result<Tz, FfiError> {
+    Tz::from_str(timezone).map_err(|_| FfiError::invalid(format!("unknown timezone: {}", timezone)))
+}
+
+fn lib_error(e: impl LibError) -> FfiError {
+    FfiError::invalid(e.get_message())
+}
+
+/// ABI 버전([CLIFF3_ABI_VERSION]) 반환
+#[no_mangle]
+pub extern "C" fn cliff3_abi_version() -> u32 {
+    CLIFF3_ABI_VERSION
+}
+
+/// 현재 thread에서 마지막으로 호출한 함수의 오류 메시지 반환
+///
+/// 마지막 호출이 성공하였을 경우 `NULL`을 반환한다. 반환된 문자열은 [cliff3_string_free]로 해제해야 한다.
+#[no_mangle]
+pub extern "C" fn cliff3_last_error() -> *mut c_char {
+    LAST_ERROR.with(|last| match last.borrow().as_ref() {
+        Some(v) => v.clone().into_raw(),
+        None => std::ptr::null_mut(),
+    })
+}
+
+/// 이 library에서 반환한 문자열 해제
+///
+/// # Safety
+///
+/// `target`은 이 library에서 반환한 문자열이거나 `NULL`이어야 하며 한 번만 해제해야 한다.
+#[no_mangle]
+pub unsafe extern "C" fn cliff3_string_free(target: *mut c_char) {
+    if !target.is_null() {
+        drop(CString::from_raw(target));
+    }
+}
+
+/// 지역 날짜 및 시간 문자열을 UTC 기준으로 변환하여 동일한 `pattern`으로 반환
+///
+/// `timezone`은 IANA 형식(e.g. `Asia/Seoul`)으로 지정한다.
+///
+/// # Safety
+///
+/// 문자열 인자는 NUL로 끝나는 유효한 문자열이어야 하며 `out`은 기록 가능한 위치여야 한다.
+#[no_mangle]
+pub unsafe extern "C" fn cliff3_local_datetime_to_utc(
+    datetime: *const c_char,
+    pattern: *const c_char,
+    timezone: *const c_char,
+    out: *mut *mut c_char,
+) -> i32 {
+    run(out, || {
+        let pattern = to_str("pattern", pattern)?;
+        let timezone = parse_timezone(to_str("timezone", timezone)?)?;
+
+        local_datetime_to_utc(to_str("datetime", datetime)?, pattern, &timezone)
+            .map(|v| v.format(pattern).to_string())
+            .map_err(lib_error)
+    })
+}
+
+/// UTC 기준 날짜 및 시간 문자열을 지정된 timezone의 시간으로 변환하여 동일한 `pattern`으로 반환
+///
+/// # Safety
+///
+/// 문자열 인자는 NUL로 끝나는 유효한 문자열이어야 하며 `out`은 기록 가능한 위치여야 한다.
+#[no_mangle]
+pub unsafe extern "C" fn cliff3_utc_datetime_to_local(
+    datetime: *const c_char,
+    pattern: *const c_char,
+    timezone: *const c_char,
+    out: *mut *mut c_char,
+) -> i32 {
+    run(out, || {
+        let pattern = to_str("pattern", pattern)?;
+        let timezone = parse_timezone(to_str("timezone", timezone)?)?;
+
+        utc_datetime_to_local(to_str("datetime", datetime)?, pattern, &timezone)
+            .map(|v| v.format(pattern).to_string())
+            .map_err(lib_error)
+    })
+}
+
+/// 이메일 마스킹
+///
+/// # Safety
+///
+/// `target`은 NUL로 끝나는 유효한 문자열이어야 하며 `out`은 기록 가능한 위치여야 한다.
+#[no_mangle]
+pub unsafe extern "C" fn cliff3_mask_email(target: *const c_char, out: *mut *mut c_char) -> i32 {
+    run(out, || Ok(mask_email(to_str("target", target)?)))
+}
+
+/// 전화번호 마스킹
+///
+/// # Safety
+///
+/// `target`은 NUL로 끝나는 유효한 문자열이어야 하며 `out`은 기록 가능한 위치여야 한다.
+#[no_mangle]
+pub unsafe extern "C" fn cliff3_mask_phone(target: *const c_char, out: *mut *mut c_char) -> i32 {
+    run(out, || Ok(mask_phone(to_str("target", target)?)))
+}
+
+/// 문자열 전체 마스킹
+///
+/// # Safety
+///
+/// `target`은 NUL로 끝나는 유효한 문자열이어야 하며 `out`은 기록 가능한 위치여야 한다.
+#[no_mangle]
+pub unsafe extern "C" fn cliff3_mask_full(target: *const c_char, out: *mut *mut c_char) -> i32 {
+    run(out, || Ok(mask_full(to_str("target", target)?)))
+}
+
+/// 주민등록번호 검증. 성공시 `000000-0000000` 형식을 반환한다.
+///
+/// # Safety
+///
+/// `target`은 NUL로 끝나는 유효한 문자열이어야 하며 `out`은 기록 가능한 위치여야 한다.
+#[no_mangle]
+pub unsafe extern "C" fn cliff3_validate_rrn_kr(
+    target: *const c_char,
+    out: *mut *mut c_char,
+) -> i32 {
+    run(out, || {
+        validate_rrn(to_str("target", target)?)
+            .map(|v| v.normalized())
+            .map_err(lib_error)
+    })
+}
+
+/// 사업자등록번호 검증. 성공시 `000-00-00000` 형식을 반환한다.
+///
+/// # Safety
+///
+/// `target`은 NUL로 끝나는 유효한 문자열이어야 하며 `out`은 기록 가능한 위치여야 한다.
+#[no_mangle]
+pub unsafe extern "C" fn cliff3_validate_business_number_kr(
+    target: *const c_char,
+    out: *mut *mut c_char,
+) -> i32 {
+    run(out, || {
+        validate_brn(to_str("target", target)?).map_err(lib_error)
+    })
+}
+
+/// 카드번호 검증. 성공시 숫자만으로 구성된 카드번호를 반환한다.
+///
+/// # Safety
+///
+/// `target`은 NUL로 끝나는 유효한 문자열이어야 하며 `out`은 기록 가능한 위치여야 한다.
+#[no_mangle]
+pub unsafe extern "C" fn cliff3_validate_card_number(
+    target: *const c_char,
+    out: *mut *mut c_char,
+) -> i32 {
+    run(out, || {
+        validate_card_number(to_str("target", target)?).map_err(lib_error)
+    })
+}
+
+/// SHA-256 hash를 hex 문자열로 반환
+///
+/// # Safety
+///
+/// `data`는 `len` bytes 이상 읽기 가능한 위치여야 하며 `out`은 기록 가능한 위치여야 한다.
+#[no_mangle]
+pub unsafe extern "C" fn cliff3_sha256_hex(
+    data: *const u8,
+    len: usize,
+    out: *mut *mut c_char,
+) -> i32 {
+    run(out, || {
+        make_sha_hash_string(SHA_TYPE::SHA_256, to_bytes(data, len)?, None).map_err(lib_error)
+    })
+}
+
+/// SHA-512 hash를 hex 문자열로 반환
+///
+/// # Safety
+///
+/// `data`는 `len` bytes 이상 읽기 가능한 위치여야 하며 `out`은 기록 가능한 위치여야 한다.
+#[no_mangle]
+pub unsafe extern "C" fn cliff3_sha512_hex(
+    data: *const u8,
+    len: usize,
+    out: *mut *mut c_char,
+) -> i32 {
+    run(out, || {
+        make_sha_hash_string(SHA_TYPE::SHA_512, to_bytes(data, len)?, None).map_err(lib_error)
+    })
+}
+
+/// FNV-1a 64 bit hash 반환. `data`가 `NULL`일 경우 빈 입력으로 처리한다.
+///
+/// # Safety
+///
+/// `data`는 `NULL`이거나 `len` bytes 이상 읽기 가능한 위치여야 한다.
+#[no_mangle]
+pub unsafe extern "C" fn cliff3_fnv1a(data: *const u8, len: usize) -> u64 {
+    fnv1a(to_bytes(data, len).unwrap_or_default())
+}
+
+/// MurmurHash3 32 bit hash 반환. `data`가 `NULL`일 경우 빈 입력으로 처리한다.
+///
+/// # Safety
+///
+/// `data`는 `NULL`이거나 `len` bytes 이상 읽기 가능한 위치여야 한다.
+#[no_mangle]
+pub unsafe extern "C" fn cliff3_murmur3_32(data: *const u8, len: usize, seed: u32) -> u32 {
+    murmur3_32(to_bytes(data, len).unwrap_or_default(), seed)
+}
@@ -0,0 +1,164 @@
+use std::ffi::{c_char, CStr, CString};
+use std::ptr;
+
+use cliff3_util_ffi::*;
+
+/// 결과 코드 및 결과 문자열(해제 후 복사본) 반환
+fn call(f: impl FnOnce(*mut *mut c_char) -> i32) -> (i32, Option<String>) {
+    let mut out: *mut c_char = ptr::null_mut();
+    let code = f(&mut out);
+
+    if out.is_null() {
+        return (code, None);
+    }
+
+    let result = unsafe { CStr::from_ptr(out) }.to_str().unwrap().to_owned();
+
+    unsafe { cliff3_string_free(out) };
+
+    (code, Some(result))
+}
+
+fn last_error() -> Option<String> {
+    let error = cliff3_last_error();
+
+    if error.is_null() {
+        return None;
+    }
+
+    let message = unsafe { CStr::from_ptr(error) }
+        .to_str()
+        .unwrap()
+        .to_owned();
+
+    unsafe { cliff3_string_free(error) };
+
+    Some(message)
+}
+
+#[test]
+fn date_test() {
+    let datetime = CString::new("2024-11-22 10:29:48").unwrap();
+    let pattern = CString::new("%Y-%m-%d %H:%M:%S").unwrap();
+    let timezone = CString::new("Asia/Seoul").unwrap();
+
+    assert_eq!(
+        (CLIFF3_OK, Some("2024-11-22 01:29:48".to_owned())),
+        call(|out| unsafe {
+            cliff3_local_datetime_to_utc(
+                datetime.as_ptr(),
+                pattern.as_ptr(),
+                timezone.as_ptr(),
+                out,
+            )
+        })
+    );
+    assert_eq!(
+        (CLIFF3_OK, Some("2024-11-22 19:29:48".to_owned())),
+        call(|out| unsafe {
+            cliff3_utc_datetime_to_local(
+                datetime.as_ptr(),
+                pattern.as_ptr(),
+                timezone.as_ptr(),
+                out,
+            )
+        })
+    );
+    assert_eq!(None, last_error());
+
+    let timezone = CString::new("Mars/Olympus").unwrap();
+
+    assert_eq!(
+        (CLIFF3_ERR_INVALID, None),
+        call(|out| unsafe {
+            cliff3_local_datetime_to_utc(
+                datetime.as_ptr(),
+                pattern.as_ptr(),
+                timezone.as_ptr(),
+                out,
+            )
+        })
+    );
+    assert_eq!(
+        Some("unknown timezone: Mars/Olympus".to_owned()),
+        last_error()
+    );
+}
+
+#[test]
+fn mask_validate_test() {
+    let phone = CString::new("010-1234-5678").unwrap();
+    let email = CString::new("hong@test.com").unwrap();
+
+    assert_eq!(
+        (CLIFF3_OK, Some("010-****-5678".to_owned())),
+        call(|out| unsafe { cliff3_mask_phone(phone.as_ptr(), out) })
+    );
+    assert_eq!(
+        (CLIFF3_OK, Some("ho**@test.com".to_owned())),
+        call(|out| unsafe { cliff3_mask_email(email.as_ptr(), out) })
+    );
+    assert_eq!(
+        (CLIFF3_OK, Some("***".to_owned())),
+        call(|out| unsafe { cliff3_mask_full(c"abc".as_ptr(), out) })
+    );
+
+    let rrn = CString::new("9001011234568").unwrap();
+
+    assert_eq!(
+        (CLIFF3_OK, Some("900101-1234568".to_owned())),
+        call(|out| unsafe { cliff3_validate_rrn_kr(rrn.as_ptr(), out) })
+    );
+
+    let card = CString::new("4111-1111-1111-1112").unwrap();
+
+    assert_eq!(
+        (CLIFF3_ERR_INVALID, None),
+        call(|out| unsafe { cliff3_validate_card_number(card.as_ptr(), out) })
+    );
+    assert!(last_error().is_some());
+}
+
+#[test]
+fn invalid_argument_test() {
+    assert_eq!(
+        (CLIFF3_ERR_NULL, None),
+        call(|out| unsafe { cliff3_mask_phone(ptr::null(), out) })
+    );
+    assert_eq!(Some("target is null".to_owned()), last_error());
+
+    let invalid = [0xffu8, 0xfe, 0x00];
+
+    assert_eq!(
+        (CLIFF3_ERR_UTF8, None),
+        call(|out| unsafe { cliff3_mask_full(invalid.as_ptr() as *const c_char, out) })
+    );
+    assert_eq!(CLIFF3_ERR_NULL, unsafe {
+        cliff3_mask_full(c"abc".as_ptr(), ptr::null_mut())
+    });
+    assert_eq!(1, cliff3_abi_version());
+
+    // NULL 해제 허용
+    unsafe { cliff3_string_free(ptr::null_mut()) };
+}
+
+#[test]
+fn hash_test() {
+    let data = b"test";
+
+    assert_eq!(
+        (
+            CLIFF3_OK,
+            Some("9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08".to_owned())
+        ),
+        call(|out| unsafe { cliff3_sha256_hex(data.as_ptr(), data.len(), out) })
+    );
+    assert_eq!(
+        (CLIFF3_ERR_INVALID, None),
+        call(|out| unsafe { cliff3_sha512_hex(ptr::null(), 0, out) })
+    );
+    assert_eq!(0xcbf29ce484222325, unsafe { cliff3_fnv1a(ptr::null(), 0) });
+    assert_eq!(cliff3_util::hash_util::murmur3_32(data, 7), unsafe {
+        cliff3_murmur3_32(data.as_ptr(), data.len(), 7)
+    });
+}
@@ -0,0 +1,136 @@
+//! [cliff3-util](https://crates.io/crates/cliff3-util)의 날짜, 마스킹 및 검증 함수를 Python module
+//! (`cliff3_util`)로 제공합니다.
+//!
+//! [maturin](https://www.maturin.rs)을 이용하여 빌드합니다.
+//!
+//! ```text
+//! $ cd cliff3-util-py
+//! $ maturin develop --release
+//! ```
+//!
+//! ```python
+//! import cliff3_util
+//!
+//! cliff3_util.mask_phone("010-1234-5678")  # '010-****-5678'
+//! cliff3_util.validate_rrn_kr("9001011234568")  # '900101-1234568'
+//! cliff3_util.local_datetime_to_utc("2024-11-22 10:29:48", "%Y-%m-%d %H:%M:%S", "Asia/Seoul")
+//! ```
+//!
+//! 검증 혹은 변환에 실패할 경우 `ValueError`가 발생한다.
+
+use std::str::FromStr;
+
+use chrono_tz::Tz;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use cliff3_util::date_util;
+use cliff3_util::error::LibError;
+use cliff3_util::string_util::{self, mask};
+use cliff3_util::validate_util;
+
+fn value_error(e: impl LibError) -> PyErr {
+    PyValueError::new_err(e.get_message().to_owned())
+}
+
+fn parse_timezone(timezone: &str) -> PyResult<Tz> {
+    Tz::from_str(timezone)
+        .map_err(|_| PyValueError::new_err(format!("unknown timezone: {}", timezone)))
+}
+
+/// 지역 날짜 및 시간 문자열을 UTC 기준으로 변환하여 동일한 `pattern`으로 반환
+///
+/// `timezone`은 IANA 형식(e.g. `Asia/Seoul`)으로 지정한다.
+#[pyfunction]
+fn local_datetime_to_utc(datetime: &str, pattern: &str, timezone: &str) -> PyResult<String> {
+    date_util::local_datetime_to_utc(datetime, pattern, &parse_timezone(timezone)?)
+        .map(|v| v.format(pattern).to_string())
+        .map_err(value_error)
+}
+
+/// UTC 기준 날짜 및 시간 문자열을 지정된 timezone의 시간으로 변환하여 동일한 `pattern`으로 반환
+#[pyfunction]
+fn utc_datetime_to_local(datetime: &str, pattern: &str, timezone: &str) -> PyResult<String> {
+    date_util::utc_datetime_to_local(datetime, pattern, &parse_timezone(timezone)?)
+        .map(|v| v.format(pattern).to_string())
+        .map_err(value_error)
+}
+
+/// 이메일 마스킹
+#[pyfunction]
+fn mask_email(target: &str) -> String {
+    mask::mask_email(target)
+}
+
+/// 전화번호 마스킹
+#[pyfunction]
+fn mask_phone(target: &str) -> String {
+    mask::mask_phone(target)
+}
+
+/// 문자열 전체 마스킹
+#[pyfunction]
+fn mask_full(target: &str) -> String {
+    mask::mask_full(target)
+}
+
+/// 이메일 형식 여부
+#[pyfunction]
+fn validate_email(target: &str) -> bool {
+    string_util::validate_email(Some(target)).unwrap_or(false)
+}
+
+/// 주민등록번호 검증. 성공시 `000000-0000000` 형식을 반환한다.
+#[pyfunction]
+fn validate_rrn_kr(target: &str) -> PyResult<String> {
+    validate_util::validate_rrn(target)
+        .map(|v| v.normalized())
+        .map_err(value_error)
+}
+
+/// 사업자등록번호 검증. 성공시 `000-00-00000` 형식을 반환한다.
+#[pyfunction]
+fn validate_business_number_kr(target: &str) -> PyResult<String> {
+    validate_util::validate_brn(target).map_err(value_error)
+}
+
+/// 카드번호 검증. 성공시 숫자만으로 구성된 카드번호를 반환한다.
+#[pyfunction]
+fn validate_card_number(target: &str) -> PyResult<String> {
+    validate_util::validate_card_number(target).map_err(value_error)
+}
+
+/// 운전면허번호 검증. 성공시 `00-00-000000-00` 형식을 반환한다.
+#[pyfunction]
+fn validate_driver_license_kr(target: &str) -> PyResult<String> {
+    validate_util::validate_driver_license_kr(target)
+        .map(|v| v.normalized())
+        .map_err(value_error)
+}
+
+/// 자동차 등록번호 검증. 성공시 정규화된 등록번호(e.g. `123가 4567`)를 반환한다.
+#[pyfunction]
+fn validate_plate_kr(target: &str) -> PyResult<String> {
+    validate_util::validate_plate_kr(target)
+        .map(|v| v.normalized())
+        .map_err(value_error)
+}
+
+/// Python module(`cliff3_util`) 정의
+#[pymodule]
+#[pyo3(name = "cliff3_util")]
+pub fn cliff3_util_module(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(local_datetime_to_utc, m)?)?;
+    m.add_function(wrap_pyfunction!(utc_datetime_to_local, m)?)?;
+    m.add_function(wrap_pyfunction!(mask_email, m)?)?;
+    m.add_function(wrap_pyfunction!(mask_phone, m)?)?;
+    m.add_function(wrap_pyfunction!(mask_full, m)?)?;
+    m.add_function(wrap_pyfunction!(validate_email, m)?)?;
+    m.add_function(wrap_pyfunction!(validate_rrn_kr, m)?)?;
+    m.add_function(wrap_pyfunction!(validate_business_number_kr, m)?)?;
+    m.add_function(wrap_pyfunction!(validate_card_number, m)?)?;
+    m.add_function(wrap_pyfunction!(validate_driver_license_kr, m)?)?;
+    m.add_function(wrap_pyfunction!(validate_plate_kr, m)?)?;
+
+    Ok(())
+}
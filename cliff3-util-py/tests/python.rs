@@ -0,0 +1,57 @@
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use pyo3::wrap_pymodule;
+
+use cliff3_util_py::cliff3_util_module;
+
+fn run(script: &std::ffi::CStr) {
+    Python::with_gil(|py| {
+        let module = wrap_pymodule!(cliff3_util_module)(py);
+        let locals = PyDict::new(py);
+
+        locals.set_item("cliff3_util", module).unwrap();
+        py.run(script, None, Some(&locals)).unwrap();
+    });
+}
+
+#[test]
+fn date_test() {
+    run(cr#"
+assert cliff3_util.local_datetime_to_utc("2024-11-22 10:29:48", "%Y-%m-%d %H:%M:%S", "Asia/Seoul") == "2024-11-22 01:29:48"
+assert cliff3_util.utc_datetime_to_local("20240911234758", "%Y%m%d%H%M%S", "Asia/Seoul") == "20240912084758"
+
+try:
+    cliff3_util.local_datetime_to_utc("2024-11-22", "%Y-%m-%d %H:%M:%S", "Asia/Seoul")
+    raise AssertionError("ValueError expected")
+except ValueError:
+    pass
+
+try:
+    cliff3_util.local_datetime_to_utc("2024-11-22 10:29:48", "%Y-%m-%d %H:%M:%S", "Mars/Olympus")
+    raise AssertionError("ValueError expected")
+except ValueError as e:
+    assert str(e) == "unknown timezone: Mars/Olympus"
+"#);
+}
+
+#[test]
+fn mask_validate_test() {
+    run(cr#"
+assert cliff3_util.mask_phone("010-1234-5678") == "010-****-5678"
+assert cliff3_util.mask_email("hong@test.com") == "ho**@test.com"
+assert cliff3_util.mask_full("abc") == "***"
+assert cliff3_util.validate_email("hong@test.com")
+assert not cliff3_util.validate_email("hong")
+assert cliff3_util.validate_rrn_kr("9001011234568") == "900101-1234568"
+assert cliff3_util.validate_business_number_kr("1234567891") == "123-45-67891"
+assert cliff3_util.validate_card_number("4111 1111 1111 1111") == "4111111111111111"
+assert cliff3_util.validate_plate_kr("123가4567") == "123가 4567"
+
+for f, v in [(cliff3_util.validate_rrn_kr, "900101-1234567"), (cliff3_util.validate_card_number, "4111-1111-1111-1112")]:
+    try:
+        f(v)
+        raise AssertionError("ValueError expected")
+    except ValueError:
+        pass
+"#);
+}
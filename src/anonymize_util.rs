@@ -0,0 +1,613 @@
+//! 개인정보 비식별화(anonymization) 관련 함수 모음
+//!
+//! 항목별로 지정된 [Strategy]를 serde 직렬화 가능한 레코드 혹은 CSV 파일에 적용하는 [Anonymizer]를
+//! 제공한다. 처리 결과와 함께 항목별 적용 내역([AnonymizeReport])을 반환하며, 동일한 비밀값(secret)으로
+//! 처리한 결과는 항상 동일하므로 비식별화된 추출본을 재현할 수 있다.
+//!
+//! 변환할 수 없는 값(e.g. 날짜 형식이 아닌 값에 [Strategy::GeneralizeDate] 적용)은 원본이 노출되지 않도록
+//! 제거(suppress)하며 [FieldReport::failed]로 집계한다.
+
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::io::{Read, Write};
+
+use serde::Serialize;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+use crate::error::InvalidArgumentError;
+use crate::string_util::mask::MaskKind;
+use crate::string_util::to_hex;
+
+/// 중첩 항목 구분자 (e.g. `address.city`)
+const KEY_SEPARATOR: char = '.';
+
+/// 가명처리(pseudonymize) Feistel round 수
+const FEISTEL_ROUNDS: u8 = 10;
+
+/// 가명처리 key 유도용 구분값
+const FPE_KEY_CONTEXT: &[u8] = b"cliff3-util:anonymize:fpe";
+
+/// 비식별화 방식
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Strategy {
+    /// salt를 적용한 SHA-256 hash(16진수 소문자)
+    Hash,
+
+    /// 날짜를 월 단위로 일반화 (e.g. `2024-03-15` -> `2024-03`)
+    ///
+    /// `YYYY-MM-DD`, `YYYY/MM/DD`, `YYYY.MM.DD`, `YYYYMMDD` 및 이후 시각이 포함된 형식을 허용한다.
+    GeneralizeDate,
+
+    /// [MaskKind]에 따른 마스킹
+    Mask(MaskKind),
+
+    /// 항목 제거. 레코드는 `null`, CSV는 빈 값으로 처리
+    Suppress,
+
+    /// 형식 보존 가명처리
+    ///
+    /// 숫자는 숫자로, 알파벳은 대소문자를 유지한 알파벳으로 대체하며 그 외의 문자는 유지한다. 비밀값이
+    /// 동일하면 같은 원본은 항상 같은 결과로 대체되고 서로 다른 원본은 서로 다른 결과로 대체(Feistel 구조의
+    /// 일대일 대응)되므로 식별자간 연결(join)이 유지된다.
+    Pseudonymize,
+}
+
+impl Display for Strategy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Strategy::Hash => write!(f, "hash"),
+            Strategy::GeneralizeDate => write!(f, "generalize_date"),
+            Strategy::Mask(kind) => write!(f, "mask({:?})", kind),
+            Strategy::Suppress => write!(f, "suppress"),
+            Strategy::Pseudonymize => write!(f, "pseudonymize"),
+        }
+    }
+}
+
+/// 항목별 적용 내역
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FieldReport {
+    /// 적용된 방식
+    pub strategy: Strategy,
+
+    /// 적용 건수
+    pub applied: usize,
+
+    /// 항목이 없거나 `null`인 건수
+    pub missing: usize,
+
+    /// 변환할 수 없어 제거된 건수
+    pub failed: usize,
+}
+
+/// 비식별화 적용 내역
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AnonymizeReport {
+    /// 처리한 레코드(행) 수
+    pub records: usize,
+
+    /// 항목별 적용 내역
+    pub fields: BTreeMap<String, FieldReport>,
+}
+
+impl Display for AnonymizeReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "records={}", self.records)?;
+
+        for (name, report) in &self.fields {
+            write!(
+                f,
+                "\n{}: {} applied={} missing={} failed={}",
+                name, report.strategy, report.applied, report.missing, report.failed
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// 항목 처리 결과
+enum Outcome {
+    Applied(Value),
+    Missing,
+    Failed,
+}
+
+/// 항목별 비식별화 처리
+///
+/// # Example
+///
+/// ```rust
+/// use serde::Serialize;
+/// use cliff3_util::anonymize_util::{Anonymizer, Strategy};
+/// use cliff3_util::string_util::mask::MaskKind;
+///
+/// #[derive(Serialize)]
+/// struct Member {
+///     id: String,
+///     email: String,
+///     birth: String,
+///     memo: Option<String>,
+/// }
+///
+/// let anonymizer = Anonymizer::new(b"secret")
+///     .with_rule("id", Strategy::Pseudonymize)
+///     .with_rule("email", Strategy::Mask(MaskKind::Email))
+///     .with_rule("birth", Strategy::GeneralizeDate)
+///     .with_rule("memo", Strategy::Suppress);
+/// let member = Member {
+///     id: "M-20240001".to_owned(),
+///     email: "hong@test.com".to_owned(),
+///     birth: "1990-05-17".to_owned(),
+///     memo: Some("VIP".to_owned()),
+/// };
+/// let (result, report) = anonymizer.anonymize(&[member]).unwrap();
+///
+/// assert_eq!("ho**@test.com", result[0]["email"]);
+/// assert_eq!("1990-05", result[0]["birth"]);
+/// assert!(result[0]["memo"].is_null());
+/// // 형식 유지
+/// let id = result[0]["id"].as_str().unwrap();
+///
+/// assert_ne!("M-20240001", id);
+/// assert_eq!(Some(1), id.find('-'));
+/// assert!(id[2..].chars().all(|c| c.is_ascii_digit()));
+/// assert_eq!(1, report.fields["birth"].applied);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Anonymizer {
+    salt: Vec<u8>,
+
+    fpe_key: Vec<u8>,
+
+    rules: BTreeMap<String, Strategy>,
+}
+
+impl Anonymizer {
+    /// [Anonymizer] 생성
+    ///
+    /// # Arguments
+    ///
+    /// - `secret` - hash salt 및 가명처리 key로 사용할 비밀값
+    pub fn new(secret: &[u8]) -> Self {
+        let mut hasher = Sha256::new();
+
+        hasher.update(FPE_KEY_CONTEXT);
+        hasher.update(secret);
+
+        Anonymizer {
+            salt: secret.to_vec(),
+            fpe_key: hasher.finalize().to_vec(),
+            rules: BTreeMap::new(),
+        }
+    }
+
+    /// 항목별 방식 지정
+    ///
+    /// # Arguments
+    ///
+    /// - `field` - 항목명. 레코드의 중첩 항목은 `.`으로 구분 (e.g. `address.city`), CSV는 머리글
+    /// - `strategy` - 비식별화 방식
+    pub fn with_rule(mut self, field: &str, strategy: Strategy) -> Self {
+        self.rules.insert(field.to_owned(), strategy);
+
+        self
+    }
+
+    /// 레코드 목록 비식별화
+    ///
+    /// # Arguments
+    ///
+    /// - `records` - 대상 레코드 목록
+    ///
+    /// # Return
+    ///
+    /// - (비식별화된 레코드 목록, 적용 내역)
+    ///
+    /// # Errors
+    ///
+    /// - [InvalidArgumentError] - 레코드 직렬화 실패
+    pub fn anonymize<T: Serialize>(
+        &self,
+        records: &[T],
+    ) -> Result<(Vec<Value>, AnonymizeReport), InvalidArgumentError> {
+        let mut report = self.new_report();
+        let mut result = Vec::with_capacity(records.len());
+
+        for record in records {
+            let mut value = serde_json::to_value(record).map_err(|e| {
+                InvalidArgumentError::new(&format!("레코드 직렬화 실패 입니다({}).", e))
+            })?;
+
+            for (field, strategy) in &self.rules {
+                let path: Vec<&str> = field.split(KEY_SEPARATOR).collect();
+                let outcome = match lookup(&mut value, &path) {
+                    Some(target) => {
+                        let outcome = self.apply(*strategy, target);
+
+                        *target = match &outcome {
+                            Outcome::Applied(v) => v.clone(),
+                            _ => Value::Null,
+                        };
+
+                        outcome
+                    }
+                    None => Outcome::Missing,
+                };
+
+                record_outcome(&mut report, field, &outcome);
+            }
+
+            report.records += 1;
+            result.push(value);
+        }
+
+        Ok((result, report))
+    }
+
+    /// CSV 비식별화
+    ///
+    /// 첫 행은 머리글로 처리하며 규칙에 지정된 머리글이 없을 경우 해당 항목은 `missing`으로 집계한다.
+    ///
+    /// # Arguments
+    ///
+    /// - `reader` - 원본 CSV
+    /// - `writer` - 결과 CSV
+    ///
+    /// # Return
+    ///
+    /// - 적용 내역
+    ///
+    /// # Errors
+    ///
+    /// - [InvalidArgumentError] - CSV 읽기/쓰기 실패 혹은 형식 오류
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cliff3_util::anonymize_util::{Anonymizer, Strategy};
+    ///
+    /// let source = "name,joined\n홍길동,2023-07-01\n";
+    /// let mut output = vec![];
+    /// let report = Anonymizer::new(b"secret")
+    ///     .with_rule("name", Strategy::Suppress)
+    ///     .with_rule("joined", Strategy::GeneralizeDate)
+    ///     .anonymize_csv(source.as_bytes(), &mut output)
+    ///     .unwrap();
+    ///
+    /// assert_eq!("name,joined\n,2023-07\n", String::from_utf8(output).unwrap());
+    /// assert_eq!(1, report.records);
+    /// ```
+    pub fn anonymize_csv<R: Read, W: Write>(
+        &self,
+        reader: R,
+        writer: W,
+    ) -> Result<AnonymizeReport, InvalidArgumentError> {
+        let csv_error =
+            |e: csv::Error| InvalidArgumentError::new(&format!("CSV 처리 실패 입니다({}).", e));
+        let mut reader = csv::Reader::from_reader(reader);
+        let mut writer = csv::Writer::from_writer(writer);
+        let headers = reader.headers().map_err(csv_error)?.clone();
+        let columns: Vec<(&String, Strategy, Option<usize>)> = self
+            .rules
+            .iter()
+            .map(|(field, strategy)| (field, *strategy, headers.iter().position(|h| h == field)))
+            .collect();
+        let mut report = self.new_report();
+
+        writer.write_record(&headers).map_err(csv_error)?;
+
+        for row in reader.records() {
+            let mut row: Vec<String> = row
+                .map_err(csv_error)?
+                .iter()
+                .map(|v| v.to_owned())
+                .collect();
+
+            for (field, strategy, index) in &columns {
+                let outcome = match index.and_then(|i| row.get_mut(i)) {
+                    Some(cell) if !cell.is_empty() => {
+                        let outcome = self.apply(*strategy, &Value::String(cell.clone()));
+
+                        *cell = match &outcome {
+                            Outcome::Applied(Value::String(v)) => v.clone(),
+                            _ => String::new(),
+                        };
+
+                        outcome
+                    }
+                    _ => Outcome::Missing,
+                };
+
+                record_outcome(&mut report, field, &outcome);
+            }
+
+            writer.write_record(&row).map_err(csv_error)?;
+            report.records += 1;
+        }
+
+        writer
+            .flush()
+            .map_err(|e| InvalidArgumentError::new(&format!("CSV 쓰기 실패 입니다({}).", e)))?;
+
+        Ok(report)
+    }
+
+    fn new_report(&self) -> AnonymizeReport {
+        AnonymizeReport {
+            records: 0,
+            fields: self
+                .rules
+                .iter()
+                .map(|(field, strategy)| {
+                    (
+                        field.clone(),
+                        FieldReport {
+                            strategy: *strategy,
+                            applied: 0,
+                            missing: 0,
+                            failed: 0,
+                        },
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    fn apply(&self, strategy: Strategy, value: &Value) -> Outcome {
+        let text = match value {
+            Value::Null => return Outcome::Missing,
+            _ if strategy == Strategy::Suppress => return Outcome::Applied(Value::Null),
+            Value::String(v) => v.clone(),
+            Value::Number(v) => v.to_string(),
+            _ => return Outcome::Failed,
+        };
+        let result = match strategy {
+            Strategy::Hash => Some(self.hash(&text)),
+            Strategy::GeneralizeDate => generalize_date(&text),
+            Strategy::Mask(kind) => Some(kind.apply(&text)),
+            Strategy::Pseudonymize => Some(self.pseudonymize(&text)),
+            Strategy::Suppress => unreachable!(),
+        };
+
+        match result {
+            Some(v) => Outcome::Applied(Value::String(v)),
+            None => Outcome::Failed,
+        }
+    }
+
+    fn hash(&self, target: &str) -> String {
+        let mut hasher = Sha256::new();
+
+        hasher.update(&self.salt);
+        hasher.update(target.as_bytes());
+
+        to_hex(Some(&hasher.finalize()), false).unwrap_or_default()
+    }
+
+    fn pseudonymize(&self, target: &str) -> String {
+        let digits: Vec<u8> = target
+            .chars()
+            .filter(|c| c.is_ascii_digit())
+            .map(|c| c as u8 - b'0')
+            .collect();
+        let letters: Vec<u8> = target
+            .chars()
+            .filter(|c| c.is_ascii_alphabetic())
+            .map(|c| c.to_ascii_lowercase() as u8 - b'a')
+            .collect();
+        let mut digits = self.feistel(b'0', digits, 10).into_iter();
+        let mut letters = self.feistel(b'a', letters, 26).into_iter();
+
+        target
+            .chars()
+            .map(|c| match c {
+                _ if c.is_ascii_digit() => (b'0' + digits.next().unwrap_or_default()) as char,
+                _ if c.is_ascii_uppercase() => (b'A' + letters.next().unwrap_or_default()) as char,
+                _ if c.is_ascii_lowercase() => (b'a' + letters.next().unwrap_or_default()) as char,
+                _ => c,
+            })
+            .collect()
+    }
+
+    /// 자릿수 단위 덧셈(mod `radix`)을 round 함수로 사용하는 불균형 Feistel 암호화
+    fn feistel(&self, domain: u8, values: Vec<u8>, radix: u8) -> Vec<u8> {
+        let mut a = values[..values.len() / 2].to_vec();
+        let mut b = values[values.len() / 2..].to_vec();
+
+        for round in 0..FEISTEL_ROUNDS {
+            let stream = self.key_stream(domain, round, &b, a.len());
+            let c: Vec<u8> = a
+                .iter()
+                .zip(stream)
+                .map(|(v, k)| ((*v as u16 + (k % radix) as u16) % radix as u16) as u8)
+                .collect();
+
+            a = b;
+            b = c;
+        }
+
+        a.extend(b);
+
+        a
+    }
+
+    fn key_stream(&self, domain: u8, round: u8, source: &[u8], length: usize) -> Vec<u8> {
+        let mut result = Vec::with_capacity(length);
+        let mut counter: u32 = 0;
+
+        while result.len() < length {
+            let mut hasher = Sha256::new();
+
+            hasher.update(&self.fpe_key);
+            hasher.update([domain, round]);
+            hasher.update((source.len() as u32).to_be_bytes());
+            hasher.update(source);
+            hasher.update(counter.to_be_bytes());
+            result.extend_from_slice(&hasher.finalize());
+            counter += 1;
+        }
+
+        result.truncate(length);
+
+        result
+    }
+}
+
+/// `.`으로 구분된 경로의 항목 반환
+fn lookup<'a>(value: &'a mut Value, path: &[&str]) -> Option<&'a mut Value> {
+    path.iter()
+        .try_fold(value, |current, key| current.as_object_mut()?.get_mut(*key))
+}
+
+fn record_outcome(report: &mut AnonymizeReport, field: &str, outcome: &Outcome) {
+    if let Some(entry) = report.fields.get_mut(field) {
+        match outcome {
+            Outcome::Applied(_) => entry.applied += 1,
+            Outcome::Missing => entry.missing += 1,
+            Outcome::Failed => entry.failed += 1,
+        }
+    }
+}
+
+/// 날짜 문자열을 `YYYY-MM` 형식으로 일반화
+fn generalize_date(target: &str) -> Option<String> {
+    let target = target.trim();
+    let year = target
+        .get(..4)
+        .filter(|v| v.chars().all(|c| c.is_ascii_digit()))?;
+    let rest = target[4..]
+        .strip_prefix(['-', '/', '.'])
+        .unwrap_or(&target[4..]);
+    let month: u32 = rest
+        .get(..2)
+        .filter(|v| v.chars().all(|c| c.is_ascii_digit()))?
+        .parse()
+        .ok()?;
+
+    (1..=12)
+        .contains(&month)
+        .then(|| format!("{}-{:02}", year, month))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn anonymize_test() {
+        let anonymizer = Anonymizer::new(b"salt")
+            .with_rule("user.phone", Strategy::Mask(MaskKind::Phone))
+            .with_rule("user.email", Strategy::Hash)
+            .with_rule("joined", Strategy::GeneralizeDate)
+            .with_rule("card", Strategy::Pseudonymize)
+            .with_rule("tags", Strategy::Hash);
+        let records = vec![
+            json!({
+                "user": {"phone": "010-1234-5678", "email": "a@test.com"},
+                "joined": "20240315",
+                "card": 4111111111111111u64,
+                "tags": ["a"]
+            }),
+            json!({"user": {"email": null}, "joined": "unknown", "card": "4111-1111"}),
+        ];
+        let (result, report) = anonymizer.anonymize(&records).unwrap();
+
+        assert_eq!("010-****-5678", result[0]["user"]["phone"]);
+        assert_eq!(64, result[0]["user"]["email"].as_str().unwrap().len());
+        assert_eq!("2024-03", result[0]["joined"]);
+        assert_eq!(16, result[0]["card"].as_str().unwrap().len());
+        // 변환할 수 없는 값은 제거
+        assert!(result[0]["tags"].is_null());
+        assert!(result[1]["joined"].is_null());
+        assert_eq!("4111-1111".len(), result[1]["card"].as_str().unwrap().len());
+        assert_eq!(Some(4), result[1]["card"].as_str().unwrap().find('-'));
+
+        assert_eq!(2, report.records);
+        assert_eq!(1, report.fields["user.phone"].applied);
+        assert_eq!(1, report.fields["user.phone"].missing);
+        assert_eq!(1, report.fields["user.email"].missing);
+        assert_eq!(1, report.fields["joined"].failed);
+        assert_eq!(1, report.fields["tags"].failed);
+        assert!(report
+            .to_string()
+            .contains("joined: generalize_date applied=1 missing=0 failed=1"));
+
+        // 동일한 비밀값은 동일한 결과
+        let (again, _) = Anonymizer::new(b"salt")
+            .with_rule("card", Strategy::Pseudonymize)
+            .anonymize(&records)
+            .unwrap();
+        let (other, _) = Anonymizer::new(b"other")
+            .with_rule("card", Strategy::Pseudonymize)
+            .anonymize(&records)
+            .unwrap();
+
+        assert_eq!(result[0]["card"], again[0]["card"]);
+        assert_ne!(result[0]["card"], other[0]["card"]);
+    }
+
+    #[test]
+    fn pseudonymize_test() {
+        let anonymizer = Anonymizer::new(b"key");
+        let mut seen = std::collections::HashSet::new();
+
+        // 일대일 대응
+        for v in 0..1000 {
+            let result = anonymizer.pseudonymize(&format!("{:03}", v));
+
+            assert_eq!(3, result.len());
+            assert!(result.chars().all(|c| c.is_ascii_digit()));
+            assert!(seen.insert(result));
+        }
+
+        let result = anonymizer.pseudonymize("Ab-7 가");
+
+        assert!(result.chars().next().unwrap().is_ascii_uppercase());
+        assert!(result.chars().nth(1).unwrap().is_ascii_lowercase());
+        assert!(result.ends_with(" 가"));
+        assert_eq!("", anonymizer.pseudonymize(""));
+    }
+
+    #[test]
+    fn generalize_date_test() {
+        assert_eq!(Some("2024-03".to_owned()), generalize_date("2024-03-15"));
+        assert_eq!(
+            Some("2024-12".to_owned()),
+            generalize_date("2024/12/01 10:00:00")
+        );
+        assert_eq!(Some("2024-01".to_owned()), generalize_date("2024.01.31"));
+        assert_eq!(None, generalize_date("2024-13-01"));
+        assert_eq!(None, generalize_date("24-01-01"));
+        assert_eq!(None, generalize_date("날짜"));
+    }
+
+    #[test]
+    fn anonymize_csv_test() {
+        let source = "id,email,note\n\
+                      1001,\"a,b@test.com\",x\n\
+                      1002,,y\n";
+        let mut output = vec![];
+        let report = Anonymizer::new(b"salt")
+            .with_rule("id", Strategy::Pseudonymize)
+            .with_rule("email", Strategy::Mask(MaskKind::Full))
+            .with_rule("missing", Strategy::Suppress)
+            .anonymize_csv(source.as_bytes(), &mut output)
+            .unwrap();
+        let output = String::from_utf8(output).unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+
+        assert_eq!("id,email,note", lines[0]);
+        assert!(lines[1].ends_with(",************,x"), "{}", lines[1]);
+        assert!(lines[2].ends_with(",,y"));
+        assert_eq!(2, report.records);
+        assert_eq!(2, report.fields["id"].applied);
+        assert_eq!(1, report.fields["email"].missing);
+        assert_eq!(2, report.fields["missing"].missing);
+    }
+}
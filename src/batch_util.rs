@@ -0,0 +1,370 @@
+//! 일괄 처리(batch) 관련 함수 모음
+//!
+//! 동시 실행 수를 제한하여 여러 항목을 병렬로 처리하는 [process_batch]를 제공한다. 처리 결과는 입력 순서를
+//! 유지하며 항목별 오류는 [BatchResult]에 수집된다. `async` feature 활성화시 tokio 기반의
+//! [process_batch_async]를 사용할 수 있다.
+//!
+//! 작업(worker) 함수에서 panic이 발생할 경우 호출한 thread로 전파된다.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+/// 진행 상태
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BatchProgress {
+    /// 처리 완료(성공 및 실패) 항목 수
+    pub completed: usize,
+
+    /// 실패 항목 수
+    pub failed: usize,
+
+    /// 전체 항목 수
+    pub total: usize,
+}
+
+impl BatchProgress {
+    /// 진행률(0.0 ~ 1.0) 반환. 전체 항목이 없을 경우 `1.0`
+    pub fn ratio(&self) -> f64 {
+        if self.total == 0 {
+            1.0
+        } else {
+            self.completed as f64 / self.total as f64
+        }
+    }
+}
+
+/// 일괄 처리 결과
+///
+/// `results`는 입력 순서와 동일하다.
+#[derive(Debug)]
+pub struct BatchResult<T, E> {
+    pub results: Vec<Result<T, E>>,
+}
+
+impl<T, E> BatchResult<T, E> {
+    /// 성공 항목 수
+    pub fn success_count(&self) -> usize {
+        self.results.iter().filter(|r| r.is_ok()).count()
+    }
+
+    /// 모든 항목 성공 여부
+    pub fn is_all_ok(&self) -> bool {
+        self.results.iter().all(|r| r.is_ok())
+    }
+
+    /// 실패 항목의 (입력 위치, 오류) 목록 반환
+    pub fn errors(&self) -> Vec<(usize, &E)> {
+        self.results
+            .iter()
+            .enumerate()
+            .filter_map(|(i, r)| r.as_ref().err().map(|e| (i, e)))
+            .collect()
+    }
+
+    /// 전체 결과를 하나의 `Result`로 변환. 실패 항목이 있을 경우 첫 번째 오류 반환
+    pub fn into_result(self) -> Result<Vec<T>, E> {
+        self.results.into_iter().collect()
+    }
+}
+
+/// 동시 실행 수를 제한하여 일괄 처리
+///
+/// [process_batch_with_progress] 참고
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::batch_util::process_batch;
+///
+/// let result = process_batch(vec![1, 2, 3, 4], 2, |v| {
+///     if v % 2 == 0 {
+///         Ok(v * 10)
+///     } else {
+///         Err(format!("odd: {}", v))
+///     }
+/// });
+///
+/// assert_eq!(2, result.success_count());
+/// assert_eq!(Ok(20), result.results[1]);
+/// assert_eq!(vec![(0, &"odd: 1".to_owned()), (2, &"odd: 3".to_owned())], result.errors());
+/// ```
+pub fn process_batch<I, T, E, F>(items: Vec<I>, concurrency: usize, worker: F) -> BatchResult<T, E>
+where
+    I: Send,
+    T: Send,
+    E: Send,
+    F: Fn(I) -> Result<T, E> + Sync,
+{
+    process_batch_with_progress(items, concurrency, worker, |_| {})
+}
+
+/// 동시 실행 수를 제한하여 일괄 처리하며 항목 처리시마다 진행 상태 전달
+///
+/// 최대 `concurrency`개의 thread에서 처리하며 `progress`는 처리한 thread에서 호출된다.
+///
+/// # Arguments
+///
+/// - `items` - 처리 대상 목록
+/// - `concurrency` - 최대 동시 실행 수. `0`일 경우 `1`로 처리
+/// - `worker` - 항목별 처리 함수
+/// - `progress` - 진행 상태 수신 함수
+///
+/// # Return
+///
+/// - 입력 순서와 동일한 처리 결과 [BatchResult]
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        level = "debug",
+        skip_all,
+        fields(items = items.len(), concurrency = concurrency, failed)
+    )
+)]
+pub fn process_batch_with_progress<I, T, E, F, P>(
+    items: Vec<I>,
+    concurrency: usize,
+    worker: F,
+    progress: P,
+) -> BatchResult<T, E>
+where
+    I: Send,
+    T: Send,
+    E: Send,
+    F: Fn(I) -> Result<T, E> + Sync,
+    P: Fn(&BatchProgress) + Sync,
+{
+    let total = items.len();
+    let queue = Mutex::new(items.into_iter().enumerate());
+    let slots: Mutex<Vec<Option<Result<T, E>>>> = Mutex::new((0..total).map(|_| None).collect());
+    let tracker = Tracker::new(total);
+
+    thread::scope(|scope| {
+        for _ in 0..concurrency.max(1).min(total) {
+            scope.spawn(|| loop {
+                let next = queue.lock().unwrap_or_else(|e| e.into_inner()).next();
+                let Some((index, item)) = next else {
+                    break;
+                };
+                let result = worker(item);
+
+                progress(&tracker.complete(result.is_err()));
+                slots.lock().unwrap_or_else(|e| e.into_inner())[index] = Some(result);
+            });
+        }
+    });
+
+    #[cfg(feature = "tracing")]
+    tracing::Span::current().record("failed", tracker.failed.load(Ordering::Relaxed));
+
+    BatchResult {
+        results: slots
+            .into_inner()
+            .unwrap_or_else(|e| e.into_inner())
+            .into_iter()
+            .map(|v| v.expect("모든 항목이 처리되어야 합니다."))
+            .collect(),
+    }
+}
+
+/// 비동기 작업을 동시 실행 수를 제한하여 일괄 처리
+///
+/// 각 항목은 현재 tokio runtime의 task로 실행된다.
+///
+/// # Arguments
+///
+/// - `items` - 처리 대상 목록
+/// - `concurrency` - 최대 동시 실행 수. `0`일 경우 `1`로 처리
+/// - `worker` - 항목별 처리 함수
+/// - `progress` - 진행 상태 수신 함수
+///
+/// # Return
+///
+/// - 입력 순서와 동일한 처리 결과 [BatchResult]
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::batch_util::process_batch_async;
+///
+/// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+/// let result = process_batch_async(vec![1u64, 2, 3], 2, |v| async move {
+///     Ok::<u64, String>(v * 2)
+/// }, |_| {}).await;
+///
+/// assert_eq!(Ok(vec![2, 4, 6]), result.into_result());
+/// # });
+/// ```
+#[cfg(feature = "async")]
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        level = "debug",
+        skip_all,
+        fields(items = items.len(), concurrency = concurrency, failed)
+    )
+)]
+pub async fn process_batch_async<I, T, E, F, Fut, P>(
+    items: Vec<I>,
+    concurrency: usize,
+    worker: F,
+    progress: P,
+) -> BatchResult<T, E>
+where
+    I: Send + 'static,
+    T: Send + 'static,
+    E: Send + 'static,
+    F: Fn(I) -> Fut,
+    Fut: std::future::Future<Output = Result<T, E>> + Send + 'static,
+    P: Fn(&BatchProgress),
+{
+    let total = items.len();
+    let mut slots: Vec<Option<Result<T, E>>> = (0..total).map(|_| None).collect();
+    let mut tasks = tokio::task::JoinSet::new();
+    let mut queue = items.into_iter().enumerate();
+    let tracker = Tracker::new(total);
+    let concurrency = concurrency.max(1);
+
+    loop {
+        while tasks.len() < concurrency {
+            let Some((index, item)) = queue.next() else {
+                break;
+            };
+            let future = worker(item);
+
+            tasks.spawn(async move { (index, future.await) });
+        }
+
+        let Some(joined) = tasks.join_next().await else {
+            break;
+        };
+
+        match joined {
+            Ok((index, result)) => {
+                progress(&tracker.complete(result.is_err()));
+                slots[index] = Some(result);
+            }
+            Err(e) => std::panic::resume_unwind(e.into_panic()),
+        }
+    }
+
+    #[cfg(feature = "tracing")]
+    tracing::Span::current().record("failed", tracker.failed.load(Ordering::Relaxed));
+
+    BatchResult {
+        results: slots
+            .into_iter()
+            .map(|v| v.expect("모든 항목이 처리되어야 합니다."))
+            .collect(),
+    }
+}
+
+/// 진행 상태 계산
+struct Tracker {
+    completed: AtomicUsize,
+
+    failed: AtomicUsize,
+
+    total: usize,
+}
+
+impl Tracker {
+    fn new(total: usize) -> Self {
+        Tracker {
+            completed: AtomicUsize::new(0),
+            failed: AtomicUsize::new(0),
+            total,
+        }
+    }
+
+    fn complete(&self, failed: bool) -> BatchProgress {
+        let failed = if failed {
+            self.failed.fetch_add(1, Ordering::SeqCst) + 1
+        } else {
+            self.failed.load(Ordering::SeqCst)
+        };
+
+        BatchProgress {
+            completed: self.completed.fetch_add(1, Ordering::SeqCst) + 1,
+            failed,
+            total: self.total,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn order_and_concurrency_test() {
+        let running = AtomicUsize::new(0);
+        let peak = AtomicUsize::new(0);
+        let result = process_batch((0..20).collect(), 3, |v: u64| {
+            let current = running.fetch_add(1, Ordering::SeqCst) + 1;
+
+            peak.fetch_max(current, Ordering::SeqCst);
+            // 늦게 시작한 항목이 먼저 끝나도록 처리
+            thread::sleep(Duration::from_millis(20 - v));
+            running.fetch_sub(1, Ordering::SeqCst);
+
+            Ok::<u64, ()>(v * v)
+        });
+
+        assert_eq!(
+            (0..20).map(|v| v * v).collect::<Vec<u64>>(),
+            result.into_result().unwrap()
+        );
+        assert!(peak.load(Ordering::SeqCst) <= 3);
+    }
+
+    #[test]
+    fn progress_test() {
+        let reports = Mutex::new(vec![]);
+        let result = process_batch_with_progress(
+            vec!["1", "x", "3"],
+            2,
+            |v| v.parse::<u32>(),
+            |p| reports.lock().unwrap().push(*p),
+        );
+        let reports = reports.into_inner().unwrap();
+
+        assert!(!result.is_all_ok());
+        assert_eq!(1, result.errors()[0].0);
+        assert_eq!(3, reports.len());
+        assert_eq!(3, reports.iter().map(|p| p.completed).max().unwrap());
+        assert_eq!(1, reports.iter().map(|p| p.failed).max().unwrap());
+        assert_eq!(1.0, reports.iter().map(|p| p.ratio()).fold(0.0, f64::max));
+
+        // 빈 목록
+        let result = process_batch(Vec::<u32>::new(), 4, Ok::<u32, ()>);
+
+        assert!(result.results.is_empty());
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn process_batch_async_test() {
+        let result = process_batch_async(
+            (0..10u64).collect(),
+            3,
+            |v| async move {
+                tokio::time::sleep(Duration::from_millis(10 - v)).await;
+
+                if v == 5 {
+                    Err(v)
+                } else {
+                    Ok(v + 1)
+                }
+            },
+            |_| {},
+        )
+        .await;
+
+        assert_eq!(vec![(5, &5)], result.errors());
+        assert_eq!(Ok(&1), result.results[0].as_ref());
+        assert_eq!(Ok(&10), result.results[9].as_ref());
+    }
+}
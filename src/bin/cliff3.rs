@@ -0,0 +1,280 @@
+//! cliff3-util 명령행 도구
+//!
+//! ```text
+//! $ cargo install cliff3-util --features cli
+//! $ cliff3 date convert "2024-11-22 10:29:48" --from Asia/Seoul --to UTC
+//! $ cliff3 hash file a.txt b.txt --algorithm sha512
+//! $ cliff3 encrypt --secret secret --input plain.txt --output encrypted.bin
+//! $ cliff3 mask phone 010-1234-5678
+//! $ cliff3 uuid --count 3
+//! ```
+
+use std::fs::File;
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::str::FromStr;
+
+use chrono_tz::Tz;
+use clap::{Args, Parser, Subcommand, ValueEnum};
+
+use cliff3_util::date_util::{local_datetime_to_utc, utc_datetime_to_local};
+use cliff3_util::encrypt_util::{
+    aes_decrypt_stream, aes_encrypt_stream, make_sha_hash_string, AES_TYPE, SHA_TYPE,
+};
+use cliff3_util::error::LibError;
+use cliff3_util::string_util::mask::{mask_email, mask_full, mask_phone};
+use cliff3_util::string_util::uuid::new_uuid_v4;
+
+#[derive(Parser)]
+#[command(name = "cliff3", version, about = "cliff3-util 명령행 도구")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// 날짜 관련 명령
+    #[command(subcommand)]
+    Date(DateCommand),
+
+    /// SHA hash 관련 명령
+    #[command(subcommand)]
+    Hash(HashCommand),
+
+    /// AES 암호화(표준 입력/출력 혹은 파일)
+    Encrypt(CryptoArgs),
+
+    /// AES 복호화(표준 입력/출력 혹은 파일)
+    Decrypt(CryptoArgs),
+
+    /// 개인정보 마스킹
+    Mask {
+        /// 마스킹 종류
+        kind: MaskType,
+
+        /// 마스킹 대상
+        value: String,
+    },
+
+    /// 무작위 UUID(v4) 생성
+    Uuid {
+        /// 생성 개수
+        #[arg(short, long, default_value_t = 1)]
+        count: usize,
+    },
+}
+
+#[derive(Subcommand)]
+enum DateCommand {
+    /// 지정된 timezone의 날짜 및 시간을 다른 timezone으로 변환
+    Convert {
+        /// 날짜 및 시간 (e.g. "2024-11-22 10:29:48")
+        datetime: String,
+
+        /// 날짜 및 시간 패턴
+        #[arg(short, long, default_value = "%Y-%m-%d %H:%M:%S")]
+        pattern: String,
+
+        /// 원본 timezone (e.g. Asia/Seoul)
+        #[arg(long, default_value = "UTC")]
+        from: String,
+
+        /// 변환 timezone (e.g. UTC)
+        #[arg(long, default_value = "UTC")]
+        to: String,
+    },
+}
+
+#[derive(Subcommand)]
+enum HashCommand {
+    /// 파일 hash
+    File {
+        /// 대상 파일 목록
+        #[arg(required = true)]
+        paths: Vec<PathBuf>,
+
+        /// Hash 알고리즘
+        #[arg(short, long, value_enum, default_value_t = Algorithm::Sha256)]
+        algorithm: Algorithm,
+    },
+
+    /// 문자열 hash
+    Text {
+        /// 대상 문자열
+        value: String,
+
+        /// Hash 알고리즘
+        #[arg(short, long, value_enum, default_value_t = Algorithm::Sha256)]
+        algorithm: Algorithm,
+
+        /// Salt
+        #[arg(short, long)]
+        salt: Option<String>,
+    },
+}
+
+#[derive(Args)]
+struct CryptoArgs {
+    /// Secret key
+    #[arg(short, long)]
+    secret: String,
+
+    /// Salt (8 bytes)
+    #[arg(long)]
+    salt: Option<String>,
+
+    /// 반복 횟수
+    #[arg(short, long, default_value_t = 10)]
+    repeat: usize,
+
+    /// AES-128 사용(기본 AES-256)
+    #[arg(long)]
+    aes128: bool,
+
+    /// 입력 파일(미지정시 표준 입력)
+    #[arg(short, long)]
+    input: Option<PathBuf>,
+
+    /// 출력 파일(미지정시 표준 출력)
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Algorithm {
+    Sha256,
+    Sha512,
+}
+
+impl Algorithm {
+    fn sha_type(self) -> SHA_TYPE {
+        match self {
+            Algorithm::Sha256 => SHA_TYPE::SHA_256,
+            Algorithm::Sha512 => SHA_TYPE::SHA_512,
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum MaskType {
+    Email,
+    Phone,
+    Full,
+}
+
+fn main() -> ExitCode {
+    match run(Cli::parse().command) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("cliff3: {}", message);
+
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(command: Command) -> Result<(), String> {
+    match command {
+        Command::Date(DateCommand::Convert {
+            datetime,
+            pattern,
+            from,
+            to,
+        }) => {
+            let utc = local_datetime_to_utc(&datetime, &pattern, &parse_timezone(&from)?)
+                .map_err(|e| e.get_message().to_owned())?;
+            let local = utc_datetime_to_local(
+                &utc.format(&pattern).to_string(),
+                &pattern,
+                &parse_timezone(&to)?,
+            )
+            .map_err(|e| e.get_message().to_owned())?;
+
+            println!("{}", local.format(&pattern));
+        }
+        Command::Hash(HashCommand::File { paths, algorithm }) => {
+            for path in paths {
+                let content =
+                    std::fs::read(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+                let hash = make_sha_hash_string(algorithm.sha_type(), &content, None)
+                    .map_err(|_| format!("{}: 빈 파일 입니다.", path.display()))?;
+
+                println!("{}  {}", hash, path.display());
+            }
+        }
+        Command::Hash(HashCommand::Text {
+            value,
+            algorithm,
+            salt,
+        }) => {
+            let hash =
+                make_sha_hash_string(algorithm.sha_type(), value.as_bytes(), salt.as_deref())
+                    .map_err(|_| "Hash 대상이 빈 문자열 입니다.".to_owned())?;
+
+            println!("{}", hash);
+        }
+        Command::Encrypt(args) => crypto(args, true)?,
+        Command::Decrypt(args) => crypto(args, false)?,
+        Command::Mask { kind, value } => {
+            let masked = match kind {
+                MaskType::Email => mask_email(&value),
+                MaskType::Phone => mask_phone(&value),
+                MaskType::Full => mask_full(&value),
+            };
+
+            println!("{}", masked);
+        }
+        Command::Uuid { count } => {
+            for _ in 0..count {
+                println!("{}", new_uuid_v4());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn parse_timezone(timezone: &str) -> Result<Tz, String> {
+    Tz::from_str(timezone).map_err(|_| format!("알 수 없는 timezone 입니다: {}", timezone))
+}
+
+fn crypto(args: CryptoArgs, encrypt: bool) -> Result<(), String> {
+    let enc_type = if args.aes128 {
+        AES_TYPE::AES_128
+    } else {
+        AES_TYPE::AES_256
+    };
+    let mut reader: Box<dyn Read> = match &args.input {
+        Some(path) => Box::new(open(path)?),
+        None => Box::new(io::stdin().lock()),
+    };
+    let mut writer: Box<dyn Write> = match &args.output {
+        Some(path) => {
+            Box::new(File::create(path).map_err(|e| format!("{}: {}", path.display(), e))?)
+        }
+        None => Box::new(io::stdout().lock()),
+    };
+    let salt = args.salt.as_ref().map(|v| v.as_bytes());
+    let stream = if encrypt {
+        aes_encrypt_stream
+    } else {
+        aes_decrypt_stream
+    };
+
+    stream(
+        enc_type,
+        &mut reader,
+        &mut writer,
+        args.secret.as_bytes(),
+        salt,
+        args.repeat,
+    )
+    .map(|_| ())
+    .map_err(|e| e.get_message().to_owned())
+}
+
+fn open(path: &Path) -> Result<File, String> {
+    File::open(path).map_err(|e| format!("{}: {}", path.display(), e))
+}
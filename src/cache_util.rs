@@ -0,0 +1,518 @@
+//! In-memory cache 관련 함수 모음
+//!
+//! 최대 항목 수를 초과할 경우 가장 오래 사용되지 않은 항목(LRU)을 제거하며, 항목별 만료 시간(TTL)을
+//! 지정할 수 있는 [Cache]를 제공한다. 내부 상태는 [Mutex]로 보호되므로 [std::sync::Arc]로 감싸
+//! 여러 thread에서 공유할 수 있다.
+
+use std::collections::{BTreeMap, HashMap};
+use std::hash::Hash;
+use std::sync::{Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+use crate::error::InvalidArgumentError;
+
+/// Cache 사용 통계
+///
+/// # Link
+///
+/// - [Cache::stats]
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CacheStats {
+    /// 조회 성공 횟수
+    pub hits: u64,
+
+    /// 조회 실패 횟수(만료된 항목 포함)
+    pub misses: u64,
+
+    /// 최대 항목 수 초과로 제거된 항목 수
+    pub evictions: u64,
+
+    /// 만료되어 제거된 항목 수
+    pub expirations: u64,
+}
+
+impl CacheStats {
+    /// 전체 조회 대비 성공 비율 반환(조회 이력이 없을 경우 `0.0`)
+    pub fn hit_ratio(&self) -> f64 {
+        let total = self.hits + self.misses;
+
+        if total == 0 {
+            return 0.0;
+        }
+
+        self.hits as f64 / total as f64
+    }
+}
+
+/// Cache 항목
+#[derive(Debug)]
+struct CacheEntry<V> {
+    /// 값
+    value: V,
+
+    /// 만료 시각(`None`일 경우 만료되지 않음)
+    expires_at: Option<Instant>,
+
+    /// 마지막 사용 순번
+    tick: u64,
+}
+
+impl<V> CacheEntry<V> {
+    #[inline]
+    fn is_expired(&self, now: Instant) -> bool {
+        matches!(self.expires_at, Some(v) if v <= now)
+    }
+}
+
+/// 내부 상태
+#[derive(Debug)]
+struct CacheInner<K, V> {
+    entries: HashMap<K, CacheEntry<V>>,
+
+    /// 사용 순번 => key (가장 작은 순번이 가장 오래 사용되지 않은 항목)
+    order: BTreeMap<u64, K>,
+
+    /// 사용 순번 발급용
+    tick: u64,
+
+    stats: CacheStats,
+}
+
+impl<K: Eq + Hash + Clone, V> CacheInner<K, V> {
+    /// 해당 항목을 가장 최근 사용으로 갱신
+    fn touch(&mut self, key: &K) {
+        self.tick += 1;
+
+        let tick = self.tick;
+
+        if let Some(entry) = self.entries.get_mut(key) {
+            self.order.remove(&entry.tick);
+            entry.tick = tick;
+            self.order.insert(tick, key.clone());
+        }
+    }
+
+    /// 항목 제거
+    fn remove(&mut self, key: &K) -> Option<V> {
+        let entry = self.entries.remove(key)?;
+
+        self.order.remove(&entry.tick);
+
+        Some(entry.value)
+    }
+
+    /// 만료되지 않은 항목 여부 확인. 만료된 항목은 제거
+    fn contains_live(&mut self, key: &K, now: Instant) -> bool {
+        match self.entries.get(key) {
+            None => false,
+            Some(entry) if entry.is_expired(now) => {
+                self.remove(key);
+                self.stats.expirations += 1;
+
+                false
+            }
+            Some(_) => true,
+        }
+    }
+
+    /// 새 항목 추가. 최대 항목 수를 초과할 경우 만료 항목 정리 후 LRU 항목 제거
+    fn insert(&mut self, key: K, value: V, ttl: Option<Duration>, max_entries: usize) {
+        let now = Instant::now();
+
+        self.remove(&key);
+
+        if self.entries.len() >= max_entries {
+            self.purge_expired(now);
+        }
+
+        while self.entries.len() >= max_entries {
+            let oldest = match self.order.first_key_value() {
+                None => break,
+                Some((_, k)) => k.clone(),
+            };
+
+            self.remove(&oldest);
+            self.stats.evictions += 1;
+        }
+
+        self.tick += 1;
+        self.order.insert(self.tick, key.clone());
+        self.entries.insert(
+            key,
+            CacheEntry {
+                value,
+                // 범위를 벗어나는 만료 시간은 만료되지 않는 것으로 처리
+                expires_at: ttl.and_then(|v| now.checked_add(v)),
+                tick: self.tick,
+            },
+        );
+    }
+
+    /// 만료된 항목 모두 제거
+    fn purge_expired(&mut self, now: Instant) -> usize {
+        let expired: Vec<K> = self
+            .entries
+            .iter()
+            .filter(|(_, v)| v.is_expired(now))
+            .map(|(k, _)| k.clone())
+            .collect();
+
+        for k in expired.iter() {
+            self.remove(k);
+        }
+
+        self.stats.expirations += expired.len() as u64;
+
+        expired.len()
+    }
+}
+
+/// TTL 및 LRU 기반 thread-safe in-memory cache
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use cliff3_util::cache_util::Cache;
+///
+/// let cache: Cache<&str, u32> = Cache::with_ttl(2, Duration::from_secs(60)).unwrap();
+///
+/// cache.insert("a", 1);
+/// cache.insert("b", 2);
+///
+/// assert_eq!(Some(1), cache.get(&"a")); // "a" 사용으로 "b"가 가장 오래된 항목이 됨
+///
+/// cache.insert("c", 3); // 최대 항목 수 초과로 "b" 제거
+///
+/// assert_eq!(None, cache.get(&"b"));
+/// assert_eq!(3, cache.get_or_insert_with("c", || 100));
+/// assert_eq!(2, cache.stats().hits);
+/// ```
+#[derive(Debug)]
+pub struct Cache<K, V> {
+    /// 최대 항목 수
+    max_entries: usize,
+
+    /// 기본 만료 시간(`None`일 경우 만료되지 않음)
+    default_ttl: Option<Duration>,
+
+    /// 내부 상태
+    inner: Mutex<CacheInner<K, V>>,
+}
+
+impl<K: Eq + Hash + Clone, V: Clone> Cache<K, V> {
+    /// 만료 시간 없이 최대 항목 수만 지정하여 [Cache] 생성
+    ///
+    /// # Arguments
+    ///
+    /// - `max_entries` - 최대 항목 수
+    ///
+    /// # Return
+    ///
+    /// - 생성 결과 `Result<Cache<K, V>, InvalidArgumentError>`
+    ///
+    /// # Errors
+    ///
+    /// - [InvalidArgumentError] - `max_entries`가 0일 경우
+    pub fn new(max_entries: usize) -> Result<Self, InvalidArgumentError> {
+        Self::create(max_entries, None)
+    }
+
+    /// 최대 항목 수 및 기본 만료 시간을 지정하여 [Cache] 생성
+    ///
+    /// # Arguments
+    ///
+    /// - `max_entries` - 최대 항목 수
+    /// - `ttl` - 기본 만료 시간
+    ///
+    /// # Return
+    ///
+    /// - 생성 결과 `Result<Cache<K, V>, InvalidArgumentError>`
+    ///
+    /// # Errors
+    ///
+    /// - [InvalidArgumentError] - `max_entries` 혹은 `ttl`이 0일 경우
+    pub fn with_ttl(max_entries: usize, ttl: Duration) -> Result<Self, InvalidArgumentError> {
+        validate_ttl(ttl)?;
+
+        Self::create(max_entries, Some(ttl))
+    }
+
+    fn create(max_entries: usize, ttl: Option<Duration>) -> Result<Self, InvalidArgumentError> {
+        if max_entries == 0 {
+            return Err(InvalidArgumentError::from(
+                "최대 항목 수는 0보다 커야 합니다.",
+            ));
+        }
+
+        Ok(Cache {
+            max_entries,
+            default_ttl: ttl,
+            inner: Mutex::new(CacheInner {
+                entries: HashMap::new(),
+                order: BTreeMap::new(),
+                tick: 0,
+                stats: CacheStats::default(),
+            }),
+        })
+    }
+
+    #[inline]
+    fn lock(&self) -> MutexGuard<'_, CacheInner<K, V>> {
+        self.inner.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// 최대 항목 수 반환
+    #[inline]
+    pub fn max_entries(&self) -> usize {
+        self.max_entries
+    }
+
+    /// 기본 만료 시간 반환
+    #[inline]
+    pub fn default_ttl(&self) -> Option<Duration> {
+        self.default_ttl
+    }
+
+    /// 기본 만료 시간을 적용하여 항목 추가
+    ///
+    /// 동일한 key가 존재할 경우 대체한다.
+    pub fn insert(&self, key: K, value: V) {
+        self.lock()
+            .insert(key, value, self.default_ttl, self.max_entries);
+    }
+
+    /// 지정된 만료 시간을 적용하여 항목 추가
+    ///
+    /// # Arguments
+    ///
+    /// - `key` - Key
+    /// - `value` - 값
+    /// - `ttl` - 해당 항목의 만료 시간
+    ///
+    /// # Return
+    ///
+    /// - 추가 결과 `Result<(), InvalidArgumentError>`
+    ///
+    /// # Errors
+    ///
+    /// - [InvalidArgumentError] - `ttl`이 0일 경우
+    pub fn insert_with_ttl(
+        &self,
+        key: K,
+        value: V,
+        ttl: Duration,
+    ) -> Result<(), InvalidArgumentError> {
+        validate_ttl(ttl)?;
+        self.lock().insert(key, value, Some(ttl), self.max_entries);
+
+        Ok(())
+    }
+
+    /// 항목 조회
+    ///
+    /// 조회된 항목은 가장 최근 사용 항목으로 갱신되며, 만료된 항목은 제거 후 `None`을 반환한다.
+    pub fn get(&self, key: &K) -> Option<V> {
+        let mut inner = self.lock();
+
+        if !inner.contains_live(key, Instant::now()) {
+            inner.stats.misses += 1;
+
+            return None;
+        }
+
+        inner.stats.hits += 1;
+        inner.touch(key);
+        inner.entries.get(key).map(|v| v.value.clone())
+    }
+
+    /// 항목 조회 후 존재하지 않을 경우 `f`의 결과를 추가하여 반환
+    ///
+    /// `f`는 내부 lock을 획득한 상태에서 호출되므로 동일한 key에 대해 중복 호출되지 않는다.
+    /// 따라서 `f` 내부에서 동일한 [Cache]에 접근해서는 안된다(deadlock 발생).
+    ///
+    /// # Arguments
+    ///
+    /// - `key` - Key
+    /// - `f` - 값 생성 함수
+    ///
+    /// # Return
+    ///
+    /// - 기존 값 혹은 새로 생성된 값
+    pub fn get_or_insert_with<F: FnOnce() -> V>(&self, key: K, f: F) -> V {
+        let mut inner = self.lock();
+
+        if inner.contains_live(&key, Instant::now()) {
+            inner.stats.hits += 1;
+            inner.touch(&key);
+
+            return inner.entries[&key].value.clone();
+        }
+
+        inner.stats.misses += 1;
+
+        let value = f();
+
+        inner.insert(key, value.clone(), self.default_ttl, self.max_entries);
+
+        value
+    }
+
+    /// 항목 제거 후 제거된 값 반환
+    pub fn remove(&self, key: &K) -> Option<V> {
+        self.lock().remove(key)
+    }
+
+    /// 만료된 항목을 모두 제거하고 제거된 항목 수 반환
+    pub fn purge_expired(&self) -> usize {
+        self.lock().purge_expired(Instant::now())
+    }
+
+    /// 현재 항목 수 반환(아직 정리되지 않은 만료 항목 포함)
+    pub fn len(&self) -> usize {
+        self.lock().entries.len()
+    }
+
+    /// 항목 존재 여부 반환
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// 모든 항목 제거. 통계는 유지된다.
+    pub fn clear(&self) {
+        let mut inner = self.lock();
+
+        inner.entries.clear();
+        inner.order.clear();
+    }
+
+    /// 사용 통계 반환
+    pub fn stats(&self) -> CacheStats {
+        self.lock().stats
+    }
+
+    /// 사용 통계 초기화
+    pub fn reset_stats(&self) {
+        self.lock().stats = CacheStats::default();
+    }
+}
+
+fn validate_ttl(ttl: Duration) -> Result<(), InvalidArgumentError> {
+    if ttl.is_zero() {
+        return Err(InvalidArgumentError::from("만료 시간은 0보다 커야 합니다."));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    #[test]
+    fn invalid_argument_test() {
+        assert!(Cache::<u32, u32>::new(0).is_err());
+        assert!(Cache::<u32, u32>::with_ttl(1, Duration::ZERO).is_err());
+        assert!(Cache::<u32, u32>::new(1)
+            .unwrap()
+            .insert_with_ttl(1, 1, Duration::ZERO)
+            .is_err());
+    }
+
+    #[test]
+    fn ttl_overflow_test() {
+        // 범위를 벗어나는 만료 시간은 만료되지 않음
+        let cache: Cache<u32, u32> = Cache::with_ttl(10, Duration::MAX).unwrap();
+
+        cache.insert(1, 1);
+        cache.insert_with_ttl(2, 2, Duration::MAX).unwrap();
+
+        assert_eq!(Some(1), cache.get(&1));
+        assert_eq!(Some(2), cache.get(&2));
+        assert_eq!(0, cache.purge_expired());
+    }
+
+    #[test]
+    fn lru_eviction_test() {
+        let cache: Cache<u32, String> = Cache::new(3).unwrap();
+
+        cache.insert(1, "one".to_owned());
+        cache.insert(2, "two".to_owned());
+        cache.insert(3, "three".to_owned());
+
+        assert_eq!(Some("one".to_owned()), cache.get(&1));
+
+        // 2가 가장 오래 사용되지 않은 항목
+        cache.insert(4, "four".to_owned());
+
+        assert_eq!(3, cache.len());
+        assert_eq!(None, cache.get(&2), "LRU 항목 제거 실패");
+        assert!(cache.get(&1).is_some());
+        assert!(cache.get(&3).is_some());
+        assert!(cache.get(&4).is_some());
+        assert_eq!(1, cache.stats().evictions);
+
+        // 기존 key 대체시 제거 없음
+        cache.insert(4, "FOUR".to_owned());
+
+        assert_eq!(Some("FOUR".to_owned()), cache.get(&4));
+        assert_eq!(1, cache.stats().evictions);
+    }
+
+    #[test]
+    fn ttl_test() {
+        let cache: Cache<&str, u32> = Cache::with_ttl(10, Duration::from_millis(50)).unwrap();
+
+        cache.insert("short", 1);
+        cache
+            .insert_with_ttl("long", 2, Duration::from_secs(60))
+            .unwrap();
+
+        thread::sleep(Duration::from_millis(60));
+
+        assert_eq!(None, cache.get(&"short"), "만료 항목 조회됨");
+        assert_eq!(Some(2), cache.get(&"long"));
+        assert_eq!(1, cache.stats().expirations);
+
+        cache.insert("short", 3);
+
+        thread::sleep(Duration::from_millis(60));
+
+        assert_eq!(1, cache.purge_expired());
+        assert_eq!(1, cache.len());
+    }
+
+    #[test]
+    fn get_or_insert_with_test() {
+        let cache: Cache<u32, u32> = Cache::new(10).unwrap();
+        let mut called = 0;
+
+        assert_eq!(
+            10,
+            cache.get_or_insert_with(1, || {
+                called += 1;
+                10
+            })
+        );
+        assert_eq!(
+            10,
+            cache.get_or_insert_with(1, || {
+                called += 1;
+                20
+            })
+        );
+        assert_eq!(1, called, "생성 함수 중복 호출");
+
+        let stats = cache.stats();
+
+        assert_eq!(1, stats.hits);
+        assert_eq!(1, stats.misses);
+        assert_eq!(0.5, stats.hit_ratio());
+
+        cache.reset_stats();
+
+        assert_eq!(CacheStats::default(), cache.stats());
+    }
+}
@@ -0,0 +1,870 @@
+//! 바이트 배열 변환(encoding) 및 출력 관련 함수 모음
+//!
+//! 바이트 배열을 `hexdump -C` 형식의 (offset, 16진수, ASCII) 세 열로 출력하는 [hexdump], RFC 4648,
+//! Crockford 방식의 base32 변환([base32_encode], [base32_decode]) 및 단축 URL ID 등에 사용하는 base62
+//! 변환([base62_encode], [base62_decode])을 제공한다.
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Write;
+
+use crate::error::InvalidArgumentError;
+
+/// RFC 4648 base32 문자
+const RFC4648_ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+/// Crockford base32 문자(`I`, `L`, `O`, `U` 제외)
+const CROCKFORD_ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// base32 padding 문자
+const PADDING: char = '=';
+
+/// 기본 base62 문자(`0`-`9`, `A`-`Z`, `a`-`z`)
+pub const BASE62_ALPHABET: &str = "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+/// 혼동하기 쉬운 문자(`0`, `O`, `o`, `1`, `l`, `I`)를 제외한 base62 문자(56자)
+pub const BASE62_UNAMBIGUOUS_ALPHABET: &str =
+    "23456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnpqrstuvwxyz";
+
+/// [hexdump] 출력 설정
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HexdumpOptions {
+    width: usize,
+
+    group: usize,
+
+    uppercase: bool,
+
+    elide_repeated: bool,
+}
+
+impl Default for HexdumpOptions {
+    /// 한 줄 16 bytes, 8 bytes 단위 구분, 소문자, 반복되는 줄 생략
+    fn default() -> Self {
+        HexdumpOptions {
+            width: 16,
+            group: 8,
+            uppercase: false,
+            elide_repeated: true,
+        }
+    }
+}
+
+impl HexdumpOptions {
+    /// 기본 설정으로 생성
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 한 줄에 출력할 byte 수 지정. `0`일 경우 `1`로 처리
+    pub fn with_width(mut self, width: usize) -> Self {
+        self.width = width.max(1);
+
+        self
+    }
+
+    /// 16진수 열을 구분할 byte 수 지정. `0`일 경우 구분하지 않음
+    pub fn with_group(mut self, group: usize) -> Self {
+        self.group = group;
+
+        self
+    }
+
+    /// 16진수 대문자 출력 여부 지정
+    pub fn with_uppercase(mut self, uppercase: bool) -> Self {
+        self.uppercase = uppercase;
+
+        self
+    }
+
+    /// 이전 줄과 동일한 줄을 `*` 한 줄로 생략할지 여부 지정
+    pub fn with_elide_repeated(mut self, elide_repeated: bool) -> Self {
+        self.elide_repeated = elide_repeated;
+
+        self
+    }
+}
+
+/// 기본 설정([HexdumpOptions::default])으로 hexdump 문자열 생성
+///
+/// [hexdump_with_options] 참고
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::codec_util::hexdump;
+///
+/// let dump = hexdump(b"Hello, World!\n");
+///
+/// assert_eq!(
+///     "00000000  48 65 6c 6c 6f 2c 20 57  6f 72 6c 64 21 0a        |Hello, World!.|\n\
+///      0000000e",
+///     dump
+/// );
+/// ```
+pub fn hexdump(bytes: &[u8]) -> String {
+    hexdump_with_options(bytes, &HexdumpOptions::default())
+}
+
+/// hexdump 문자열 생성
+///
+/// 각 줄은 offset, 16진수 및 ASCII(출력 불가 문자는 `.`) 열로 구성되며 마지막 줄은 전체 길이(offset)이다.
+/// 연속으로 반복되는 줄은 설정에 따라 `*`로 생략된다. 마지막 줄 끝에는 개행 문자를 추가하지 않는다.
+///
+/// # Arguments
+///
+/// - `bytes` - 대상 바이트 배열
+/// - `options` - 출력 설정
+///
+/// # Return
+///
+/// - hexdump 문자열. 대상이 비어있을 경우 빈 문자열
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::codec_util::{hexdump_with_options, HexdumpOptions};
+///
+/// let options = HexdumpOptions::new().with_width(4).with_group(0).with_uppercase(true);
+/// let dump = hexdump_with_options(&[0u8, 0, 0, 0, 0, 0, 0, 0, 0xAB, 0xCD], &options);
+///
+/// assert_eq!(
+///     "00000000  00 00 00 00  |....|\n\
+///      *\n\
+///      00000008  AB CD        |..|\n\
+///      0000000a",
+///     dump
+/// );
+/// ```
+pub fn hexdump_with_options(bytes: &[u8], options: &HexdumpOptions) -> String {
+    if bytes.is_empty() {
+        return String::new();
+    }
+
+    let width = options.width.max(1);
+    let mut result = String::new();
+    let mut previous: Option<&[u8]> = None;
+    let mut eliding = false;
+
+    for (index, line) in bytes.chunks(width).enumerate() {
+        if options.elide_repeated && line.len() == width && previous == Some(line) {
+            if !eliding {
+                result.push_str("*\n");
+                eliding = true;
+            }
+
+            continue;
+        }
+
+        eliding = false;
+        previous = Some(line);
+
+        write_line(&mut result, index * width, line, width, options);
+    }
+
+    let _ = write!(result, "{:08x}", bytes.len());
+
+    result
+}
+
+fn write_line(
+    target: &mut String,
+    offset: usize,
+    line: &[u8],
+    width: usize,
+    options: &HexdumpOptions,
+) {
+    let _ = write!(target, "{:08x}  ", offset);
+
+    for i in 0..width {
+        if i > 0 {
+            target.push(' ');
+
+            if options.group > 0 && i % options.group == 0 {
+                target.push(' ');
+            }
+        }
+
+        match line.get(i) {
+            Some(b) if options.uppercase => {
+                let _ = write!(target, "{:02X}", b);
+            }
+            Some(b) => {
+                let _ = write!(target, "{:02x}", b);
+            }
+            None => target.push_str("  "),
+        }
+    }
+
+    target.push_str("  |");
+    target.extend(line.iter().map(|&b| {
+        if b.is_ascii_graphic() || b == b' ' {
+            b as char
+        } else {
+            '.'
+        }
+    }));
+    target.push_str("|\n");
+}
+
+/// base32 변환 방식
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Base32Alphabet {
+    /// RFC 4648 (`A`-`Z`, `2`-`7`), `=` padding 포함
+    Rfc4648,
+
+    /// RFC 4648, padding 미포함 (e.g. TOTP secret)
+    Rfc4648NoPad,
+
+    /// Crockford (`0`-`9`, `I`, `L`, `O`, `U`를 제외한 `A`-`Z`), padding 미포함
+    ///
+    /// 복원시 `I`, `L`은 `1`, `O`는 `0`으로 처리하며 `-`는 무시한다.
+    Crockford,
+}
+
+impl Base32Alphabet {
+    fn symbols(&self) -> &'static [u8; 32] {
+        match self {
+            Base32Alphabet::Rfc4648 | Base32Alphabet::Rfc4648NoPad => RFC4648_ALPHABET,
+            Base32Alphabet::Crockford => CROCKFORD_ALPHABET,
+        }
+    }
+
+    /// 문자에 해당하는 5 bit 값 반환(대소문자 구분 없음)
+    fn value_of(&self, c: char) -> Option<u8> {
+        let c = match (self, c.to_ascii_uppercase()) {
+            (Base32Alphabet::Crockford, 'I' | 'L') => '1',
+            (Base32Alphabet::Crockford, 'O') => '0',
+            (_, c) => c,
+        };
+
+        self.symbols()
+            .iter()
+            .position(|&v| v as char == c)
+            .map(|v| v as u8)
+    }
+}
+
+/// base32 문자열로 변환
+///
+/// # Arguments
+///
+/// - `target` - 대상 바이트 배열
+/// - `alphabet` - 변환 방식
+///
+/// # Return
+///
+/// - 변환 결과(대문자)
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::codec_util::{base32_encode, Base32Alphabet};
+///
+/// assert_eq!("MZXW6YQ=", base32_encode(b"foob", Base32Alphabet::Rfc4648));
+/// assert_eq!("MZXW6YQ", base32_encode(b"foob", Base32Alphabet::Rfc4648NoPad));
+/// assert_eq!("CSQPYRG", base32_encode(b"foob", Base32Alphabet::Crockford));
+/// ```
+pub fn base32_encode(target: &[u8], alphabet: Base32Alphabet) -> String {
+    let symbols = alphabet.symbols();
+    let mut result = String::with_capacity(target.len().div_ceil(5) * 8);
+    let mut buffer: u32 = 0;
+    let mut bits: u32 = 0;
+
+    for &b in target {
+        buffer = (buffer << 8) | b as u32;
+        bits += 8;
+
+        while bits >= 5 {
+            bits -= 5;
+            result.push(symbols[((buffer >> bits) & 0x1f) as usize] as char);
+        }
+    }
+
+    if bits > 0 {
+        result.push(symbols[((buffer << (5 - bits)) & 0x1f) as usize] as char);
+    }
+
+    if alphabet == Base32Alphabet::Rfc4648 {
+        while !result.len().is_multiple_of(8) {
+            result.push(PADDING);
+        }
+    }
+
+    result
+}
+
+/// base32 문자열을 바이트 배열로 복원
+///
+/// 대소문자를 구분하지 않으며 RFC 4648 방식은 padding 포함 여부와 관계 없이 복원한다.
+///
+/// # Arguments
+///
+/// - `target` - base32 문자열
+/// - `alphabet` - 변환 방식
+///
+/// # Return
+///
+/// - 복원된 바이트 배열
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 허용되지 않는 문자 혹은 잘못된 길이
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::codec_util::{base32_decode, Base32Alphabet};
+///
+/// assert_eq!(b"foob".to_vec(), base32_decode("mzxw6yq=", Base32Alphabet::Rfc4648).unwrap());
+/// assert_eq!(b"foob".to_vec(), base32_decode("MZXW6YQ", Base32Alphabet::Rfc4648).unwrap());
+/// // 혼동하기 쉬운 문자 및 구분자 허용
+/// assert_eq!(b"foob".to_vec(), base32_decode("csqp-yrg", Base32Alphabet::Crockford).unwrap());
+/// assert!(base32_decode("MZXW6Y1=", Base32Alphabet::Rfc4648).is_err());
+/// ```
+pub fn base32_decode(
+    target: &str,
+    alphabet: Base32Alphabet,
+) -> Result<Vec<u8>, InvalidArgumentError> {
+    let source: Vec<char> = match alphabet {
+        Base32Alphabet::Crockford => target.chars().filter(|&c| c != '-').collect(),
+        _ => target.trim_end_matches(PADDING).chars().collect(),
+    };
+
+    // 마지막 블록의 문자 수가 1, 3, 6일 경우 복원 불가
+    if matches!(source.len() % 8, 1 | 3 | 6) {
+        return Err(InvalidArgumentError::new(&format!(
+            "잘못된 base32 길이 입니다({}).",
+            target
+        )));
+    }
+
+    let mut result = Vec::with_capacity(source.len() * 5 / 8);
+    let mut buffer: u32 = 0;
+    let mut bits: u32 = 0;
+
+    for c in source {
+        let value = alphabet.value_of(c).ok_or_else(|| {
+            InvalidArgumentError::new(&format!("허용되지 않는 base32 문자 입니다({}).", c))
+        })?;
+
+        buffer = (buffer << 5) | value as u32;
+        bits += 5;
+
+        if bits >= 8 {
+            bits -= 8;
+            result.push((buffer >> bits) as u8);
+        }
+    }
+
+    Ok(result)
+}
+
+/// base62 변환 문자
+///
+/// 문자 수가 62자가 아닐 경우 문자 수를 진법으로 사용한다(e.g. [BASE62_UNAMBIGUOUS_ALPHABET]은 56진법).
+/// 첫번째 문자는 `0`에 해당한다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Base62Alphabet {
+    symbols: Vec<u8>,
+}
+
+impl Default for Base62Alphabet {
+    /// [BASE62_ALPHABET]
+    fn default() -> Self {
+        Base62Alphabet {
+            symbols: BASE62_ALPHABET.as_bytes().to_vec(),
+        }
+    }
+}
+
+impl Base62Alphabet {
+    /// 지정된 문자로 생성
+    ///
+    /// # Arguments
+    ///
+    /// - `symbols` - 변환 문자(중복되지 않는 공백 제외 ASCII 문자 2자 이상)
+    ///
+    /// # Errors
+    ///
+    /// - [InvalidArgumentError] - 문자가 2자 미만이거나 중복 혹은 ASCII(공백 제외)가 아닌 문자가 있을 경우
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cliff3_util::codec_util::{base62_encode_u128, Base62Alphabet};
+    ///
+    /// let binary = Base62Alphabet::new("01").unwrap();
+    ///
+    /// assert_eq!("101", base62_encode_u128(5, &binary));
+    /// assert!(Base62Alphabet::new("0").is_err());
+    /// assert!(Base62Alphabet::new("0120").is_err());
+    /// assert!(Base62Alphabet::new("가나").is_err());
+    /// ```
+    pub fn new(symbols: &str) -> Result<Self, InvalidArgumentError> {
+        let bytes = symbols.as_bytes();
+
+        if bytes.len() < 2 {
+            return Err(InvalidArgumentError::new(
+                "base62 변환 문자는 2자 이상이어야 합니다.",
+            ));
+        }
+
+        if !bytes.iter().all(u8::is_ascii_graphic) {
+            return Err(InvalidArgumentError::new(&format!(
+                "base62 변환 문자는 공백을 제외한 ASCII 문자만 사용할 수 있습니다({}).",
+                symbols
+            )));
+        }
+
+        if bytes
+            .iter()
+            .enumerate()
+            .any(|(i, b)| bytes[..i].contains(b))
+        {
+            return Err(InvalidArgumentError::new(&format!(
+                "base62 변환 문자가 중복되었습니다({}).",
+                symbols
+            )));
+        }
+
+        Ok(Base62Alphabet {
+            symbols: bytes.to_vec(),
+        })
+    }
+
+    /// [BASE62_UNAMBIGUOUS_ALPHABET]으로 생성
+    pub fn unambiguous() -> Self {
+        Base62Alphabet {
+            symbols: BASE62_UNAMBIGUOUS_ALPHABET.as_bytes().to_vec(),
+        }
+    }
+
+    /// 진법(문자 수) 반환
+    #[inline]
+    pub fn base(&self) -> usize {
+        self.symbols.len()
+    }
+
+    /// 문자에 해당하는 값 반환(대소문자 구분)
+    fn value_of(&self, c: char) -> Result<usize, InvalidArgumentError> {
+        self.symbols
+            .iter()
+            .position(|&v| v as char == c)
+            .ok_or_else(|| {
+                InvalidArgumentError::new(&format!("허용되지 않는 base62 문자 입니다({}).", c))
+            })
+    }
+}
+
+/// 정수를 base62 문자열로 변환
+///
+/// # Arguments
+///
+/// - `value` - 대상 정수
+/// - `alphabet` - 변환 문자 [Base62Alphabet]
+///
+/// # Return
+///
+/// - 변환 결과. `0`은 첫번째 문자 한 자
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::codec_util::{base62_encode_u128, Base62Alphabet};
+///
+/// let alphabet = Base62Alphabet::default();
+///
+/// assert_eq!("0", base62_encode_u128(0, &alphabet));
+/// assert_eq!("10", base62_encode_u128(62, &alphabet));
+/// assert_eq!("LygHa16AHYF", base62_encode_u128(u64::MAX as u128, &alphabet));
+/// ```
+pub fn base62_encode_u128(value: u128, alphabet: &Base62Alphabet) -> String {
+    let base = alphabet.base() as u128;
+    let mut digits: Vec<u8> = Vec::with_capacity(24);
+    let mut value = value;
+
+    loop {
+        digits.push(alphabet.symbols[(value % base) as usize]);
+        value /= base;
+
+        if value == 0 {
+            break;
+        }
+    }
+
+    digits.iter().rev().map(|&v| v as char).collect()
+}
+
+/// base62 문자열을 정수로 복원
+///
+/// # Arguments
+///
+/// - `target` - base62 문자열
+/// - `alphabet` - 변환 문자 [Base62Alphabet]
+///
+/// # Return
+///
+/// - 복원된 정수
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 빈 문자열, 허용되지 않는 문자 혹은 `u128` 범위 초과
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::codec_util::{base62_decode_u128, Base62Alphabet};
+///
+/// let alphabet = Base62Alphabet::default();
+///
+/// assert_eq!(62, base62_decode_u128("10", &alphabet).unwrap());
+/// assert_eq!(62, base62_decode_u128("0010", &alphabet).unwrap());
+/// assert!(base62_decode_u128("", &alphabet).is_err());
+/// assert!(base62_decode_u128("1-0", &alphabet).is_err());
+/// ```
+pub fn base62_decode_u128(
+    target: &str,
+    alphabet: &Base62Alphabet,
+) -> Result<u128, InvalidArgumentError> {
+    if target.is_empty() {
+        return Err(InvalidArgumentError::new("base62 문자열이 비어 있습니다."));
+    }
+
+    let base = alphabet.base() as u128;
+
+    target.chars().try_fold(0u128, |result, c| {
+        let value = alphabet.value_of(c)? as u128;
+
+        result
+            .checked_mul(base)
+            .and_then(|v| v.checked_add(value))
+            .ok_or_else(|| {
+                InvalidArgumentError::new(&format!(
+                    "base62 문자열이 u128 범위를 벗어납니다({}).",
+                    target
+                ))
+            })
+    })
+}
+
+/// 바이트 배열을 base62 문자열로 변환
+///
+/// 바이트 배열을 하나의 큰 정수(big-endian)로 변환하며, 앞의 `0` byte는 각각 첫번째 문자 한 자로 변환하여
+/// 복원시 유지한다.
+///
+/// # Arguments
+///
+/// - `target` - 대상 바이트 배열
+/// - `alphabet` - 변환 문자 [Base62Alphabet]
+///
+/// # Return
+///
+/// - 변환 결과
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::codec_util::{base62_encode, Base62Alphabet};
+///
+/// let alphabet = Base62Alphabet::default();
+///
+/// assert_eq!("T8dgcjRGkZ3aysdN", base62_encode(b"Hello World!", &alphabet));
+/// assert_eq!("001", base62_encode(&[0, 0, 1], &alphabet));
+/// assert_eq!("", base62_encode(&[], &alphabet));
+/// ```
+pub fn base62_encode(target: &[u8], alphabet: &Base62Alphabet) -> String {
+    let base = alphabet.base() as u32;
+    let zeros = target.iter().take_while(|&&b| b == 0).count();
+    // base 진법 값(little-endian)
+    let mut digits: Vec<u32> = Vec::with_capacity(target.len() * 3 / 2);
+
+    for &b in &target[zeros..] {
+        let mut carry = b as u32;
+
+        for digit in digits.iter_mut() {
+            carry += *digit << 8;
+            *digit = carry % base;
+            carry /= base;
+        }
+
+        while carry > 0 {
+            digits.push(carry % base);
+            carry /= base;
+        }
+    }
+
+    let mut result = String::with_capacity(zeros + digits.len());
+
+    result.extend(core::iter::repeat_n(alphabet.symbols[0] as char, zeros));
+    result.extend(
+        digits
+            .iter()
+            .rev()
+            .map(|&v| alphabet.symbols[v as usize] as char),
+    );
+
+    result
+}
+
+/// base62 문자열을 바이트 배열로 복원
+///
+/// # Arguments
+///
+/// - `target` - base62 문자열
+/// - `alphabet` - 변환 문자 [Base62Alphabet]
+///
+/// # Return
+///
+/// - 복원된 바이트 배열
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 허용되지 않는 문자
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::codec_util::{base62_decode, Base62Alphabet};
+///
+/// let alphabet = Base62Alphabet::default();
+///
+/// assert_eq!(b"Hello World!".to_vec(), base62_decode("T8dgcjRGkZ3aysdN", &alphabet).unwrap());
+/// assert_eq!(vec![0, 0, 1], base62_decode("001", &alphabet).unwrap());
+/// assert!(base62_decode("T8dg+", &alphabet).is_err());
+/// ```
+pub fn base62_decode(
+    target: &str,
+    alphabet: &Base62Alphabet,
+) -> Result<Vec<u8>, InvalidArgumentError> {
+    let zero = alphabet.symbols[0] as char;
+    let zeros = target.chars().take_while(|&c| c == zero).count();
+    let base = alphabet.base() as u32;
+    // 256 진법 값(little-endian)
+    let mut bytes: Vec<u8> = Vec::with_capacity(target.len());
+
+    for c in target.chars().skip(zeros) {
+        let mut carry = alphabet.value_of(c)? as u32;
+
+        for byte in bytes.iter_mut() {
+            carry += *byte as u32 * base;
+            *byte = (carry & 0xff) as u8;
+            carry >>= 8;
+        }
+
+        while carry > 0 {
+            bytes.push((carry & 0xff) as u8);
+            carry >>= 8;
+        }
+    }
+
+    bytes.extend(core::iter::repeat_n(0, zeros));
+    bytes.reverse();
+
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hexdump_test() {
+        assert_eq!("", hexdump(&[]));
+
+        let bytes: Vec<u8> = (0u8..=0x1f).collect();
+        let dump = hexdump(&bytes);
+        let lines: Vec<&str> = dump.lines().collect();
+
+        assert_eq!(3, lines.len());
+        assert_eq!(
+            "00000000  00 01 02 03 04 05 06 07  08 09 0a 0b 0c 0d 0e 0f  |................|",
+            lines[0]
+        );
+        assert_eq!("00000020", lines[2]);
+    }
+
+    #[test]
+    fn elide_test() {
+        let mut bytes = vec![0u8; 64];
+
+        bytes.extend_from_slice(b"ABC");
+
+        let dump = hexdump(&bytes);
+
+        assert_eq!(
+            vec![
+                "00000000  00 00 00 00 00 00 00 00  00 00 00 00 00 00 00 00  |................|",
+                "*",
+                "00000040  41 42 43                                          |ABC|",
+                "00000043",
+            ],
+            dump.lines().collect::<Vec<&str>>()
+        );
+
+        // 생략하지 않을 경우
+        let dump = hexdump_with_options(&bytes, &HexdumpOptions::new().with_elide_repeated(false));
+
+        assert_eq!(6, dump.lines().count());
+
+        // 동일한 줄이 반복된 후 다른 줄이 나오고 다시 반복될 경우
+        let bytes = [[1u8; 2], [1; 2], [1; 2], [2; 2], [2; 2]].concat();
+        let dump = hexdump_with_options(&bytes, &HexdumpOptions::new().with_width(2));
+
+        assert_eq!(
+            vec![
+                "00000000  01 01  |..|",
+                "*",
+                "00000006  02 02  |..|",
+                "*",
+                "0000000a",
+            ],
+            dump.lines().collect::<Vec<&str>>()
+        );
+    }
+
+    #[test]
+    fn base32_rfc4648_test() {
+        // RFC 4648 test vector
+        let vectors = [
+            ("", ""),
+            ("f", "MY======"),
+            ("fo", "MZXQ===="),
+            ("foo", "MZXW6==="),
+            ("foob", "MZXW6YQ="),
+            ("fooba", "MZXW6YTB"),
+            ("foobar", "MZXW6YTBOI======"),
+        ];
+
+        for (plain, encoded) in vectors {
+            assert_eq!(
+                encoded,
+                base32_encode(plain.as_bytes(), Base32Alphabet::Rfc4648)
+            );
+            assert_eq!(
+                plain.as_bytes(),
+                base32_decode(encoded, Base32Alphabet::Rfc4648).unwrap()
+            );
+            assert_eq!(
+                plain.as_bytes(),
+                base32_decode(&encoded.to_lowercase(), Base32Alphabet::Rfc4648NoPad).unwrap()
+            );
+        }
+
+        assert_eq!(
+            "MZXW6YTBOI",
+            base32_encode(b"foobar", Base32Alphabet::Rfc4648NoPad)
+        );
+        assert!(base32_decode("M", Base32Alphabet::Rfc4648).is_err());
+        assert!(base32_decode("MZX", Base32Alphabet::Rfc4648).is_err());
+        assert!(base32_decode("MZ=XW6", Base32Alphabet::Rfc4648).is_err());
+        assert!(base32_decode("MZ0W6YQ", Base32Alphabet::Rfc4648).is_err());
+    }
+
+    #[test]
+    fn base32_crockford_test() {
+        let bytes: Vec<u8> = (0u8..=255).collect();
+        let encoded = base32_encode(&bytes, Base32Alphabet::Crockford);
+
+        assert!(!encoded.contains(['I', 'L', 'O', 'U', '=']));
+        assert_eq!(
+            bytes,
+            base32_decode(&encoded, Base32Alphabet::Crockford).unwrap()
+        );
+        assert_eq!(
+            bytes,
+            base32_decode(&encoded.to_lowercase(), Base32Alphabet::Crockford).unwrap()
+        );
+        assert_eq!("00", base32_encode(&[0], Base32Alphabet::Crockford));
+        assert_eq!(
+            base32_decode("1a-1b0", Base32Alphabet::Crockford),
+            base32_decode("iAlBo", Base32Alphabet::Crockford)
+        );
+        assert!(base32_decode("0U", Base32Alphabet::Crockford).is_err());
+    }
+
+    #[test]
+    fn base62_test() {
+        let alphabet = Base62Alphabet::default();
+
+        assert_eq!(62, alphabet.base());
+
+        for (value, expected) in [
+            (0u128, "0"),
+            (9, "9"),
+            (10, "A"),
+            (61, "z"),
+            (62, "10"),
+            (3843, "zz"),
+            (u128::MAX, "7n42DGM5Tflk9n8mt7Fhc7"),
+        ] {
+            assert_eq!(expected, base62_encode_u128(value, &alphabet));
+            assert_eq!(value, base62_decode_u128(expected, &alphabet).unwrap());
+        }
+
+        assert!(base62_decode_u128("7n42DGM5Tflk9n8mt7Fhc8", &alphabet).is_err());
+        assert!(base62_decode_u128("100000000000000000000000", &alphabet).is_err());
+
+        for bytes in [
+            vec![],
+            vec![0],
+            vec![0, 0],
+            vec![1],
+            vec![255],
+            vec![0, 255, 0],
+            (0u8..=255).collect::<Vec<u8>>(),
+            vec![255; 64],
+        ] {
+            let encoded = base62_encode(&bytes, &alphabet);
+
+            assert!(encoded.chars().all(|c| c.is_ascii_alphanumeric()));
+            assert_eq!(bytes, base62_decode(&encoded, &alphabet).unwrap());
+        }
+
+        // 정수와 바이트 배열 변환 결과 비교
+        let value = 0x0123_4567_89ab_cdef_u128;
+
+        assert_eq!(
+            base62_encode_u128(value, &alphabet),
+            base62_encode(&value.to_be_bytes()[8..], &alphabet)
+        );
+        assert!(base62_decode("가", &alphabet).is_err());
+    }
+
+    #[test]
+    fn base62_alphabet_test() {
+        let unambiguous = Base62Alphabet::unambiguous();
+
+        assert_eq!(56, unambiguous.base());
+        assert_eq!(
+            Base62Alphabet::new(BASE62_UNAMBIGUOUS_ALPHABET).unwrap(),
+            unambiguous
+        );
+
+        let bytes: Vec<u8> = (0u8..=255).rev().collect();
+        let encoded = base62_encode(&bytes, &unambiguous);
+
+        assert!(!encoded.contains(['0', 'O', 'o', '1', 'l', 'I']));
+        assert_eq!(bytes, base62_decode(&encoded, &unambiguous).unwrap());
+        assert_eq!("2", base62_encode_u128(0, &unambiguous));
+        assert_eq!("32", base62_encode_u128(56, &unambiguous));
+        assert_eq!(56, base62_decode_u128("32", &unambiguous).unwrap());
+        assert!(base62_decode_u128("10", &unambiguous).is_err());
+
+        // 16진법
+        let hex = Base62Alphabet::new("0123456789abcdef").unwrap();
+
+        assert_eq!("deadbeef", base62_encode(&[0xde, 0xad, 0xbe, 0xef], &hex));
+        assert_eq!(
+            u128::MAX,
+            base62_decode_u128(&"f".repeat(32), &hex).unwrap()
+        );
+
+        for symbols in ["", "a", "aba", "a b", "ab\n", "가나"] {
+            assert!(Base62Alphabet::new(symbols).is_err(), "{:?}", symbols);
+        }
+    }
+}
@@ -0,0 +1,674 @@
+//! 설정(configuration) 관련 함수 모음
+//!
+//! 기본값 -> 설정 파일(JSON/TOML) -> 환경 변수 -> 명령행(CLI) 지정값 순서로 설정을 병합하여 구조체로
+//! 변환하는 [ConfigBuilder]를 제공한다. 나중에 추가된 값이 우선하며, 최종 값의 출처는
+//! [ResolvedConfig::source_of]로 확인할 수 있다.
+//!
+//! 환경 변수 및 CLI 지정값은 문자열이므로 구조체 항목의 형식에 맞춰 숫자, `bool` 및 목록(`,` 구분)으로
+//! 변환된다.
+
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::de::{self, DeserializeOwned, IntoDeserializer, Visitor};
+use serde::forward_to_deserialize_any;
+use serde_json::{Map, Value};
+
+use crate::error::InvalidArgumentError;
+
+/// 중첩 항목 구분자 (e.g. `database.url`)
+const KEY_SEPARATOR: char = '.';
+
+/// 환경 변수의 중첩 항목 구분자 (e.g. `APP_DATABASE__URL` -> `database.url`)
+const ENV_SEPARATOR: &str = "__";
+
+/// 설정 값의 출처
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ConfigSource {
+    /// 기본값
+    Default,
+
+    /// 설정 파일(문자열로 추가된 경우 지정된 명칭)
+    File(PathBuf),
+
+    /// 환경 변수(변수명)
+    Env(String),
+
+    /// 명령행 등에서 직접 지정된 값
+    Override,
+}
+
+impl Display for ConfigSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigSource::Default => write!(f, "default"),
+            ConfigSource::File(v) => write!(f, "file({})", v.display()),
+            ConfigSource::Env(v) => write!(f, "env({})", v),
+            ConfigSource::Override => write!(f, "override"),
+        }
+    }
+}
+
+/// 병합된 설정 및 항목별 출처
+#[derive(Debug, Clone)]
+pub struct ResolvedConfig<T> {
+    /// 변환된 설정
+    pub value: T,
+
+    /// 항목(`.` 구분 경로)별 출처
+    sources: BTreeMap<String, ConfigSource>,
+}
+
+impl<T> ResolvedConfig<T> {
+    /// 지정된 항목의 출처 반환
+    ///
+    /// # Arguments
+    ///
+    /// - `key` - `.`으로 구분된 항목 경로 (e.g. `database.url`)
+    pub fn source_of(&self, key: &str) -> Option<&ConfigSource> {
+        self.sources.get(key)
+    }
+
+    /// 전체 항목의 출처 반환(항목 경로 순 정렬)
+    pub fn sources(&self) -> &BTreeMap<String, ConfigSource> {
+        &self.sources
+    }
+}
+
+/// 다단계 설정 병합
+///
+/// # Example
+///
+/// ```rust
+/// use serde::Deserialize;
+/// use cliff3_util::config_util::{ConfigBuilder, ConfigSource};
+///
+/// #[derive(Deserialize)]
+/// struct Database {
+///     url: String,
+///     pool_size: u32,
+/// }
+///
+/// #[derive(Deserialize)]
+/// struct AppConfig {
+///     port: u16,
+///     debug: bool,
+///     database: Database,
+/// }
+///
+/// let resolved = ConfigBuilder::new()
+///     .set_default("port", 8080)
+///     .set_default("debug", false)
+///     .set_default("database.pool_size", 5)
+///     .add_toml_str("app.toml", "[database]\nurl = \"postgres://localhost/app\"")
+///     .unwrap()
+///     .add_env_vars("APP", vec![("APP_DATABASE__POOL_SIZE".to_owned(), "20".to_owned())])
+///     .set_override("debug", "true")
+///     .build::<AppConfig>()
+///     .unwrap();
+///
+/// assert_eq!(8080, resolved.value.port);
+/// assert!(resolved.value.debug);
+/// assert_eq!(20, resolved.value.database.pool_size);
+/// assert_eq!(Some(&ConfigSource::Default), resolved.source_of("port"));
+/// assert_eq!(
+///     Some(&ConfigSource::Env("APP_DATABASE__POOL_SIZE".to_owned())),
+///     resolved.source_of("database.pool_size")
+/// );
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ConfigBuilder {
+    root: Map<String, Value>,
+
+    sources: BTreeMap<String, ConfigSource>,
+}
+
+impl ConfigBuilder {
+    /// [ConfigBuilder] 생성
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 기본값 지정
+    ///
+    /// # Arguments
+    ///
+    /// - `key` - `.`으로 구분된 항목 경로
+    /// - `value` - 기본값
+    pub fn set_default<V: Into<Value>>(mut self, key: &str, value: V) -> Self {
+        self.merge_value(key, value.into(), &ConfigSource::Default);
+
+        self
+    }
+
+    /// 설정 파일 추가. 파일 형식은 확장자(`json`, `toml`)로 판단한다.
+    ///
+    /// # Errors
+    ///
+    /// - [InvalidArgumentError] - 지원하지 않는 확장자, 파일 읽기 실패 혹은 형식 오류
+    pub fn add_file<P: AsRef<Path>>(self, path: P) -> Result<Self, InvalidArgumentError> {
+        let path = path.as_ref();
+        let source = fs::read_to_string(path).map_err(|e| {
+            InvalidArgumentError::new(&format!("파일 읽기 실패 입니다({:?}, {}).", path, e))
+        })?;
+
+        self.add_str(path, &source)
+    }
+
+    /// 설정 파일이 존재할 경우에만 추가
+    ///
+    /// # Errors
+    ///
+    /// - [InvalidArgumentError] - 파일이 존재하나 읽기 실패 혹은 형식 오류
+    pub fn add_optional_file<P: AsRef<Path>>(self, path: P) -> Result<Self, InvalidArgumentError> {
+        if path.as_ref().exists() {
+            self.add_file(path)
+        } else {
+            Ok(self)
+        }
+    }
+
+    /// JSON 문자열 추가
+    ///
+    /// # Arguments
+    ///
+    /// - `name` - 출처로 사용할 명칭 (e.g. 파일명)
+    /// - `source` - JSON object 문자열
+    ///
+    /// # Errors
+    ///
+    /// - [InvalidArgumentError] - JSON object 형식이 아닐 경우
+    pub fn add_json_str(self, name: &str, source: &str) -> Result<Self, InvalidArgumentError> {
+        let value: Value = serde_json::from_str(source)
+            .map_err(|e| InvalidArgumentError::new(&format!("JSON 형식 오류 입니다({}).", e)))?;
+
+        self.add_document(Path::new(name), value)
+    }
+
+    /// TOML 문자열 추가
+    ///
+    /// # Arguments
+    ///
+    /// - `name` - 출처로 사용할 명칭 (e.g. 파일명)
+    /// - `source` - TOML 문자열
+    ///
+    /// # Errors
+    ///
+    /// - [InvalidArgumentError] - 잘못된 TOML 형식
+    pub fn add_toml_str(self, name: &str, source: &str) -> Result<Self, InvalidArgumentError> {
+        let table: toml::Table = source.parse().map_err(|e: toml::de::Error| {
+            InvalidArgumentError::new(&format!("TOML 형식 오류 입니다({}).", e.message()))
+        })?;
+        let value = serde_json::to_value(table)
+            .map_err(|e| InvalidArgumentError::new(&format!("TOML 변환 오류 입니다({}).", e)))?;
+
+        self.add_document(Path::new(name), value)
+    }
+
+    /// 현재 프로세스의 환경 변수 중 `<prefix>_`로 시작하는 항목 추가
+    ///
+    /// [ConfigBuilder::add_env_vars] 참고
+    pub fn add_env(self, prefix: &str) -> Self {
+        self.add_env_vars(prefix, std::env::vars())
+    }
+
+    /// 환경 변수 목록 중 `<prefix>_`로 시작하는 항목 추가
+    ///
+    /// prefix를 제외한 변수명을 소문자로 변환하며 `__`는 중첩 항목 구분자로 처리한다
+    /// (e.g. `APP_DATABASE__POOL_SIZE` -> `database.pool_size`). prefix 비교시 대소문자를 구분하지 않는다.
+    ///
+    /// # Arguments
+    ///
+    /// - `prefix` - 변수명 prefix (e.g. `APP`)
+    /// - `vars` - (변수명, 값) 목록
+    pub fn add_env_vars<I>(mut self, prefix: &str, vars: I) -> Self
+    where
+        I: IntoIterator<Item = (String, String)>,
+    {
+        let prefix = format!("{}_", prefix.to_ascii_uppercase());
+        let mut vars: Vec<(String, String)> = vars
+            .into_iter()
+            .filter(|(k, _)| k.to_ascii_uppercase().starts_with(&prefix) && k.len() > prefix.len())
+            .collect();
+
+        // 적용 순서를 일정하게 유지
+        vars.sort();
+
+        for (name, value) in vars {
+            let key = name[prefix.len()..]
+                .to_ascii_lowercase()
+                .replace(ENV_SEPARATOR, &KEY_SEPARATOR.to_string());
+
+            self.merge_value(&key, Value::String(value), &ConfigSource::Env(name.clone()));
+        }
+
+        self
+    }
+
+    /// 명령행 등에서 직접 지정된 값 추가
+    pub fn set_override<V: Into<Value>>(mut self, key: &str, value: V) -> Self {
+        self.merge_value(key, value.into(), &ConfigSource::Override);
+
+        self
+    }
+
+    /// `key=value` 형식의 명령행 인자 목록 추가 (e.g. `--set database.url=..`의 값)
+    ///
+    /// # Errors
+    ///
+    /// - [InvalidArgumentError] - `=`가 없거나 key가 비어있는 항목
+    pub fn add_overrides<I, S>(mut self, pairs: I) -> Result<Self, InvalidArgumentError>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        for pair in pairs {
+            let pair = pair.as_ref();
+
+            match pair.split_once('=') {
+                Some((k, v)) if !k.trim().is_empty() => self.merge_value(
+                    k.trim(),
+                    Value::String(v.to_owned()),
+                    &ConfigSource::Override,
+                ),
+                _ => {
+                    return Err(InvalidArgumentError::new(&format!(
+                        "잘못된 설정 형식 입니다({}). key=value 형식이어야 합니다.",
+                        pair
+                    )))
+                }
+            }
+        }
+
+        Ok(self)
+    }
+
+    /// 병합된 설정을 구조체로 변환
+    ///
+    /// # Errors
+    ///
+    /// - [InvalidArgumentError] - 필수 항목 누락 혹은 형식 변환 실패
+    pub fn build<T: DeserializeOwned>(self) -> Result<ResolvedConfig<T>, InvalidArgumentError> {
+        let value = T::deserialize(Lenient(Value::Object(self.root)))
+            .map_err(|e| InvalidArgumentError::new(&format!("설정 변환 오류 입니다({}).", e)))?;
+
+        Ok(ResolvedConfig {
+            value,
+            sources: self.sources,
+        })
+    }
+
+    fn add_str(self, path: &Path, source: &str) -> Result<Self, InvalidArgumentError> {
+        let extension = path
+            .extension()
+            .and_then(|v| v.to_str())
+            .map(|v| v.to_ascii_lowercase());
+        let name = path.to_string_lossy();
+
+        match extension.as_deref() {
+            Some("json") => self.add_json_str(&name, source),
+            Some("toml") => self.add_toml_str(&name, source),
+            _ => Err(InvalidArgumentError::new(&format!(
+                "지원하지 않는 파일 형식 입니다({:?}).",
+                path
+            ))),
+        }
+    }
+
+    fn add_document(mut self, path: &Path, value: Value) -> Result<Self, InvalidArgumentError> {
+        let Value::Object(map) = value else {
+            return Err(InvalidArgumentError::from(
+                "설정은 object 형식이어야 합니다.",
+            ));
+        };
+        let source = ConfigSource::File(path.to_path_buf());
+
+        for (k, v) in map {
+            self.merge_value(&k, v, &source);
+        }
+
+        Ok(self)
+    }
+
+    /// 항목 병합. object는 하위 항목 단위로 병합하며 그 외의 값은 대체한다.
+    fn merge_value(&mut self, key: &str, value: Value, source: &ConfigSource) {
+        let path: Vec<&str> = key.split(KEY_SEPARATOR).filter(|v| !v.is_empty()).collect();
+
+        if path.is_empty() {
+            return;
+        }
+
+        match value {
+            Value::Object(map) => {
+                for (k, v) in map {
+                    self.merge_value(&format!("{}{}{}", key, KEY_SEPARATOR, k), v, source);
+                }
+            }
+            value => {
+                let mut current = &mut self.root;
+
+                for segment in &path[..path.len() - 1] {
+                    let entry = current
+                        .entry(segment.to_string())
+                        .or_insert_with(|| Value::Object(Map::new()));
+
+                    if !entry.is_object() {
+                        *entry = Value::Object(Map::new());
+                    }
+
+                    current = entry.as_object_mut().unwrap();
+                }
+
+                let full_key = path.join(&KEY_SEPARATOR.to_string());
+                let nested = format!("{}{}", full_key, KEY_SEPARATOR);
+
+                // 하위 항목 및 상위 항목의 출처 제거
+                self.sources.retain(|k, _| {
+                    !k.starts_with(&nested)
+                        && !full_key.starts_with(&format!("{}{}", k, KEY_SEPARATOR))
+                });
+                self.sources.insert(full_key, source.clone());
+                current.insert(path[path.len() - 1].to_owned(), value);
+            }
+        }
+    }
+}
+
+/// 문자열 <-> 숫자/`bool`/목록 변환을 허용하는 deserializer
+struct Lenient(Value);
+
+impl Lenient {
+    fn parse<T: std::str::FromStr>(&self) -> Option<T> {
+        match &self.0 {
+            Value::String(s) => s.trim().parse().ok(),
+            _ => None,
+        }
+    }
+}
+
+macro_rules! lenient_number {
+    ($($method:ident => $visit:ident : $ty:ty),* $(,)?) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+                match self.parse::<$ty>() {
+                    Some(v) => visitor.$visit(v),
+                    None => self.0.$method(visitor),
+                }
+            }
+        )*
+    };
+}
+
+impl<'de> de::Deserializer<'de> for Lenient {
+    type Error = serde_json::Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.0 {
+            Value::Object(map) => visitor.visit_map(LenientMap {
+                iter: map.into_iter(),
+                value: None,
+            }),
+            Value::Array(list) => visitor.visit_seq(LenientSeq {
+                iter: list.into_iter(),
+            }),
+            v => v.deserialize_any(visitor),
+        }
+    }
+
+    lenient_number! {
+        deserialize_bool => visit_bool: bool,
+        deserialize_i8 => visit_i8: i8,
+        deserialize_i16 => visit_i16: i16,
+        deserialize_i32 => visit_i32: i32,
+        deserialize_i64 => visit_i64: i64,
+        deserialize_u8 => visit_u8: u8,
+        deserialize_u16 => visit_u16: u16,
+        deserialize_u32 => visit_u32: u32,
+        deserialize_u64 => visit_u64: u64,
+        deserialize_f32 => visit_f32: f32,
+        deserialize_f64 => visit_f64: f64,
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.0 {
+            Value::Number(n) => visitor.visit_string(n.to_string()),
+            Value::Bool(b) => visitor.visit_string(b.to_string()),
+            v => v.deserialize_string(visitor),
+        }
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        self.deserialize_string(visitor)
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.0 {
+            Value::Null => visitor.visit_none(),
+            v => visitor.visit_some(Lenient(v)),
+        }
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.0 {
+            // `a,b,c` 형식의 문자열
+            Value::String(s) => visitor.visit_seq(LenientSeq {
+                iter: s
+                    .split(',')
+                    .map(|v| v.trim())
+                    .filter(|v| !v.is_empty())
+                    .map(|v| Value::String(v.to_owned()))
+                    .collect::<Vec<Value>>()
+                    .into_iter(),
+            }),
+            v => Lenient(v).deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.0.deserialize_enum(name, variants, visitor)
+    }
+
+    forward_to_deserialize_any! {
+        i128 u128 char bytes byte_buf unit unit_struct tuple tuple_struct map struct identifier
+        ignored_any
+    }
+}
+
+struct LenientMap {
+    iter: serde_json::map::IntoIter,
+
+    value: Option<Value>,
+}
+
+impl<'de> de::MapAccess<'de> for LenientMap {
+    type Error = serde_json::Error;
+
+    fn next_key_seed<K: de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Self::Error> {
+        match self.iter.next() {
+            Some((k, v)) => {
+                self.value = Some(v);
+
+                seed.deserialize(k.into_deserializer()).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, Self::Error> {
+        match self.value.take() {
+            Some(v) => seed.deserialize(Lenient(v)),
+            None => Err(de::Error::custom("값이 없습니다.")),
+        }
+    }
+}
+
+struct LenientSeq {
+    iter: std::vec::IntoIter<Value>,
+}
+
+impl<'de> de::SeqAccess<'de> for LenientSeq {
+    type Error = serde_json::Error;
+
+    fn next_element_seed<T: de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Self::Error> {
+        match self.iter.next() {
+            Some(v) => seed.deserialize(Lenient(v)).map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    #[serde(rename_all = "lowercase")]
+    enum Level {
+        Info,
+        Debug,
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct Server {
+        host: String,
+        port: u16,
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct Config {
+        name: String,
+        level: Level,
+        ratio: f64,
+        tags: Vec<String>,
+        timeout: Option<u64>,
+        server: Server,
+    }
+
+    fn builder() -> ConfigBuilder {
+        ConfigBuilder::new()
+            .set_default("name", "app")
+            .set_default("level", "info")
+            .set_default("ratio", 0.5)
+            .set_default("tags", Vec::<String>::new())
+            .set_default("server.host", "127.0.0.1")
+            .set_default("server.port", 80)
+    }
+
+    #[test]
+    fn layer_order_test() {
+        let resolved = builder()
+            .add_json_str("base.json", r#"{"server": {"port": 8080}, "tags": ["a"]}"#)
+            .unwrap()
+            .add_toml_str("local.toml", "name = 12345\n[server]\nhost = \"0.0.0.0\"")
+            .unwrap()
+            .add_env_vars(
+                "app",
+                vec![
+                    ("APP_SERVER__PORT".to_owned(), "9090".to_owned()),
+                    ("APP_TAGS".to_owned(), "x, y".to_owned()),
+                    ("OTHER_NAME".to_owned(), "ignored".to_owned()),
+                ],
+            )
+            .add_overrides(["level=debug", "ratio=0.75"])
+            .unwrap()
+            .build::<Config>()
+            .unwrap();
+        let config = &resolved.value;
+
+        // 숫자 -> 문자열 변환
+        assert_eq!("12345", config.name);
+        assert_eq!(Level::Debug, config.level);
+        assert_eq!(0.75, config.ratio);
+        assert_eq!(vec!["x", "y"], config.tags);
+        assert_eq!(None, config.timeout);
+        assert_eq!("0.0.0.0", config.server.host);
+        assert_eq!(9090, config.server.port);
+
+        assert_eq!(
+            Some(&ConfigSource::File(PathBuf::from("local.toml"))),
+            resolved.source_of("server.host")
+        );
+        assert_eq!(
+            Some(&ConfigSource::Env("APP_SERVER__PORT".to_owned())),
+            resolved.source_of("server.port")
+        );
+        assert_eq!(Some(&ConfigSource::Override), resolved.source_of("level"));
+        assert_eq!(6, resolved.sources().len());
+        assert_eq!(
+            "env(APP_TAGS)",
+            resolved.source_of("tags").unwrap().to_string()
+        );
+    }
+
+    #[test]
+    fn replace_object_test() {
+        let resolved = ConfigBuilder::new()
+            .set_default("server.host", "a")
+            .set_default("server.port", 1)
+            .set_override("server", "none")
+            .build::<BTreeMap<String, String>>()
+            .unwrap();
+
+        assert_eq!("none", resolved.value["server"]);
+        assert_eq!(
+            vec!["server"],
+            resolved.sources().keys().collect::<Vec<&String>>()
+        );
+    }
+
+    #[test]
+    fn error_test() {
+        assert!(builder()
+            .set_override("server.port", "abc")
+            .build::<Config>()
+            .is_err());
+        assert!(ConfigBuilder::new().build::<Config>().is_err());
+        assert!(ConfigBuilder::new().add_json_str("a", "[1]").is_err());
+        assert!(ConfigBuilder::new().add_toml_str("a", "a = ").is_err());
+        assert!(ConfigBuilder::new().add_overrides(["novalue"]).is_err());
+        assert!(ConfigBuilder::new().add_file("none.yaml").is_err());
+        assert!(ConfigBuilder::new().add_optional_file("none.toml").is_ok());
+    }
+
+    #[test]
+    fn add_file_test() {
+        let path = std::env::temp_dir().join(format!("cliff3_config_{}.toml", std::process::id()));
+
+        fs::write(&path, "name = \"file\"").unwrap();
+
+        let resolved = builder().add_file(&path);
+
+        fs::remove_file(&path).unwrap();
+
+        let resolved = resolved.unwrap().build::<Config>().unwrap();
+
+        assert_eq!("file", resolved.value.name);
+        assert_eq!(Some(&ConfigSource::File(path)), resolved.source_of("name"));
+    }
+}
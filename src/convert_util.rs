@@ -0,0 +1,479 @@
+//! 설정 파일 형식(JSON/TOML/YAML) 변환 관련 함수 모음
+//!
+//! [convert]는 원본을 해석한 후 대상 형식으로 출력하며 문자열, 정수, 실수, `bool`, 배열 및 table(object)
+//! 형식을 유지한다. 대상 형식으로 표현할 수 없는 값은 아래와 같이 처리한다.
+//!
+//! - TOML 날짜/시각 - RFC 3339 형식의 문자열로 변환
+//! - YAML의 문자열이 아닌 key - 문자열로 변환
+//! - `null`(JSON/YAML) -> TOML - 해당 항목의 경로를 포함한 [ConvertError] 반환
+//! - 최상위 값이 table이 아닌 경우 -> TOML - [ConvertError] 반환
+
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+
+use serde::Serialize;
+use serde_json::{Map, Number, Value};
+
+use crate::error::LibError;
+
+/// 변환 형식
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Format {
+    Json,
+
+    Toml,
+
+    Yaml,
+}
+
+impl Format {
+    /// 파일 확장자로 형식 판단
+    ///
+    /// # Return
+    ///
+    /// - `json`, `toml`, `yaml` 혹은 `yml`(대소문자 무시)이 아닐 경우 `None`
+    pub fn from_path(path: &Path) -> Option<Format> {
+        match path.extension()?.to_str()?.to_ascii_lowercase().as_str() {
+            "json" => Some(Format::Json),
+            "toml" => Some(Format::Toml),
+            "yaml" | "yml" => Some(Format::Yaml),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Format {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Format::Json => write!(f, "JSON"),
+            Format::Toml => write!(f, "TOML"),
+            Format::Yaml => write!(f, "YAML"),
+        }
+    }
+}
+
+/// 형식 변환 중 발생하는 오류
+#[derive(PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ConvertError {
+    format: Format,
+
+    message: String,
+
+    line: Option<usize>,
+
+    column: Option<usize>,
+}
+
+impl ConvertError {
+    pub fn new(format: Format, message: &str) -> Self {
+        ConvertError {
+            format,
+            message: message.to_owned(),
+            line: None,
+            column: None,
+        }
+    }
+
+    fn at(mut self, line: usize, column: usize) -> Self {
+        self.line = Some(line);
+        self.column = Some(column);
+
+        self
+    }
+
+    /// 오류가 발생한 형식(해석시 원본, 출력시 대상 형식) 반환
+    #[inline]
+    pub fn format(&self) -> Format {
+        self.format
+    }
+
+    /// 오류 위치(1부터 시작하는 행 번호) 반환. 원본 해석 오류일 경우에만 존재
+    #[inline]
+    pub fn line(&self) -> Option<usize> {
+        self.line
+    }
+
+    /// 오류 위치(1부터 시작하는 열 번호) 반환. 원본 해석 오류일 경우에만 존재
+    #[inline]
+    pub fn column(&self) -> Option<usize> {
+        self.column
+    }
+}
+
+impl Display for ConvertError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => write!(
+                f,
+                "{} error(line {}, column {}): {}",
+                self.format, line, column, self.message
+            ),
+            _ => write!(f, "{} error: {}", self.format, self.message),
+        }
+    }
+}
+
+impl LibError for ConvertError {
+    fn get_message(&self) -> &str {
+        self.message.as_str()
+    }
+
+    fn get_type_name_from_instance(&self) -> &str {
+        std::any::type_name::<ConvertError>()
+    }
+}
+
+/// 출력 설정
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ConvertOptions {
+    pretty: bool,
+
+    indent: usize,
+}
+
+impl Default for ConvertOptions {
+    /// 들여쓰기 2칸의 pretty print
+    fn default() -> Self {
+        ConvertOptions {
+            pretty: true,
+            indent: 2,
+        }
+    }
+}
+
+impl ConvertOptions {
+    /// 기본 설정으로 생성
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// pretty print 여부 지정. `false`일 경우 JSON은 한 줄, TOML은 배열을 한 줄로 출력하며 YAML은 영향
+    /// 없음
+    pub fn with_pretty(mut self, pretty: bool) -> Self {
+        self.pretty = pretty;
+
+        self
+    }
+
+    /// JSON pretty print 들여쓰기 칸 수 지정
+    pub fn with_indent(mut self, indent: usize) -> Self {
+        self.indent = indent;
+
+        self
+    }
+}
+
+/// 형식 변환
+///
+/// 기본 설정([ConvertOptions::default])으로 변환한다. [convert_with_options] 참고
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::convert_util::{convert, convert_with_options, ConvertOptions, Format};
+///
+/// let toml = "title = \"app\"\n\n[server]\nhosts = [\"a\", \"b\"]\nport = 8080\n";
+/// let yaml = convert(toml, Format::Toml, Format::Yaml).unwrap();
+///
+/// assert_eq!("server:\n  hosts:\n  - a\n  - b\n  port: 8080\ntitle: app\n", yaml);
+///
+/// let options = ConvertOptions::new().with_pretty(false);
+///
+/// assert_eq!(toml, convert_with_options(&yaml, Format::Yaml, Format::Toml, &options).unwrap());
+///
+/// let error = convert("{\n  \"a\": 1,\n  \"b\" 2\n}", Format::Json, Format::Toml).unwrap_err();
+///
+/// assert_eq!((Some(3), Some(7)), (error.line(), error.column()));
+/// ```
+pub fn convert(input: &str, from: Format, to: Format) -> Result<String, ConvertError> {
+    convert_with_options(input, from, to, &ConvertOptions::default())
+}
+
+/// 형식 변환
+///
+/// # Arguments
+///
+/// - `input` - 원본 문자열
+/// - `from` - 원본 형식
+/// - `to` - 대상 형식
+/// - `options` - 출력 설정
+///
+/// # Return
+///
+/// - 변환 결과
+///
+/// # Errors
+///
+/// - [ConvertError] - 원본 해석 실패(행/열 위치 포함) 혹은 대상 형식으로 표현할 수 없는 값
+pub fn convert_with_options(
+    input: &str,
+    from: Format,
+    to: Format,
+    options: &ConvertOptions,
+) -> Result<String, ConvertError> {
+    let value = parse(input, from)?;
+
+    match to {
+        Format::Json => {
+            let mut result = vec![];
+            let written = if options.pretty {
+                let indent = " ".repeat(options.indent);
+                let formatter = serde_json::ser::PrettyFormatter::with_indent(indent.as_bytes());
+
+                value.serialize(&mut serde_json::Serializer::with_formatter(
+                    &mut result,
+                    formatter,
+                ))
+            } else {
+                value.serialize(&mut serde_json::Serializer::new(&mut result))
+            };
+
+            written.map_err(|e| ConvertError::new(to, &e.to_string()))?;
+
+            String::from_utf8(result).map_err(|e| ConvertError::new(to, &e.to_string()))
+        }
+        Format::Toml => {
+            if !value.is_object() {
+                return Err(ConvertError::new(
+                    to,
+                    "최상위 값은 table(object)이어야 합니다.",
+                ));
+            }
+
+            if let Some(path) = find_null(&value, String::new()) {
+                return Err(ConvertError::new(
+                    to,
+                    &format!("null 값은 지원하지 않습니다({}).", path),
+                ));
+            }
+
+            let result = if options.pretty {
+                toml::to_string_pretty(&value)
+            } else {
+                toml::to_string(&value)
+            };
+
+            result.map_err(|e| ConvertError::new(to, &e.to_string()))
+        }
+        Format::Yaml => {
+            serde_yaml::to_string(&value).map_err(|e| ConvertError::new(to, &e.to_string()))
+        }
+    }
+}
+
+/// 원본 해석
+fn parse(input: &str, from: Format) -> Result<Value, ConvertError> {
+    match from {
+        Format::Json => serde_json::from_str(input)
+            .map_err(|e| ConvertError::new(from, &e.to_string()).at(e.line(), e.column())),
+        Format::Toml => {
+            let table: toml::Table = input.parse().map_err(|e: toml::de::Error| {
+                let error = ConvertError::new(from, e.message());
+
+                match e.span() {
+                    Some(span) => {
+                        let (line, column) = position(input, span.start);
+
+                        error.at(line, column)
+                    }
+                    None => error,
+                }
+            })?;
+
+            from_toml(toml::Value::Table(table))
+        }
+        Format::Yaml => {
+            let value: serde_yaml::Value = serde_yaml::from_str(input).map_err(|e| {
+                let error = ConvertError::new(from, &e.to_string());
+
+                match e.location() {
+                    Some(location) => error.at(location.line(), location.column()),
+                    None => error,
+                }
+            })?;
+
+            from_yaml(value)
+        }
+    }
+}
+
+/// byte 위치의 (행, 열) 반환
+fn position(input: &str, offset: usize) -> (usize, usize) {
+    let before = &input[..offset.min(input.len())];
+    let line = before.matches('\n').count() + 1;
+    let column = before.chars().rev().take_while(|c| *c != '\n').count() + 1;
+
+    (line, column)
+}
+
+fn float(value: f64, from: Format) -> Result<Value, ConvertError> {
+    Number::from_f64(value).map(Value::Number).ok_or_else(|| {
+        ConvertError::new(from, &format!("지원하지 않는 실수 값 입니다({}).", value))
+    })
+}
+
+fn from_toml(value: toml::Value) -> Result<Value, ConvertError> {
+    Ok(match value {
+        toml::Value::String(v) => Value::String(v),
+        toml::Value::Integer(v) => Value::from(v),
+        toml::Value::Float(v) => float(v, Format::Toml)?,
+        toml::Value::Boolean(v) => Value::Bool(v),
+        toml::Value::Datetime(v) => Value::String(v.to_string()),
+        toml::Value::Array(v) => {
+            Value::Array(v.into_iter().map(from_toml).collect::<Result<_, _>>()?)
+        }
+        toml::Value::Table(v) => Value::Object(
+            v.into_iter()
+                .map(|(k, v)| Ok((k, from_toml(v)?)))
+                .collect::<Result<Map<String, Value>, ConvertError>>()?,
+        ),
+    })
+}
+
+fn from_yaml(value: serde_yaml::Value) -> Result<Value, ConvertError> {
+    use serde_yaml::Value as Yaml;
+
+    Ok(match value {
+        Yaml::Null => Value::Null,
+        Yaml::Bool(v) => Value::Bool(v),
+        Yaml::Number(v) => match (v.as_i64(), v.as_u64(), v.as_f64()) {
+            (Some(i), _, _) => Value::from(i),
+            (_, Some(u), _) => Value::from(u),
+            (_, _, Some(f)) => float(f, Format::Yaml)?,
+            _ => Value::Null,
+        },
+        Yaml::String(v) => Value::String(v),
+        Yaml::Sequence(v) => Value::Array(v.into_iter().map(from_yaml).collect::<Result<_, _>>()?),
+        Yaml::Mapping(v) => {
+            let mut map = Map::new();
+
+            for (k, v) in v {
+                let key = match from_yaml(k)? {
+                    Value::String(s) => s,
+                    key @ (Value::Number(_) | Value::Bool(_)) => key.to_string(),
+                    Value::Null => "null".to_owned(),
+                    _ => {
+                        return Err(ConvertError::new(
+                            Format::Yaml,
+                            "문자열로 변환할 수 없는 key 입니다.",
+                        ))
+                    }
+                };
+
+                map.insert(key, from_yaml(v)?);
+            }
+
+            Value::Object(map)
+        }
+        Yaml::Tagged(v) => from_yaml(v.value)?,
+    })
+}
+
+/// 첫 번째 `null` 값의 경로 반환
+fn find_null(value: &Value, path: String) -> Option<String> {
+    match value {
+        Value::Null => Some(path),
+        Value::Object(map) => map.iter().find_map(|(k, v)| {
+            let path = if path.is_empty() {
+                k.clone()
+            } else {
+                format!("{}.{}", path, k)
+            };
+
+            find_null(v, path)
+        }),
+        Value::Array(list) => list
+            .iter()
+            .enumerate()
+            .find_map(|(i, v)| find_null(v, format!("{}[{}]", path, i))),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn convert_test() {
+        let toml = r#"
+name = "app"
+ratio = 0.5
+count = -3
+enabled = true
+started = 1979-05-27T07:32:00Z
+
+[[servers]]
+host = "a"
+ports = [80, 443]
+"#;
+        let json = convert_with_options(
+            toml,
+            Format::Toml,
+            Format::Json,
+            &ConvertOptions::new().with_pretty(false),
+        )
+        .unwrap();
+
+        assert_eq!(
+            r#"{"count":-3,"enabled":true,"name":"app","ratio":0.5,"servers":[{"host":"a","ports":[80,443]}],"started":"1979-05-27T07:32:00Z"}"#,
+            json
+        );
+
+        let pretty = convert_with_options(
+            r#"{"a":[1]}"#,
+            Format::Json,
+            Format::Json,
+            &ConvertOptions::new().with_indent(4),
+        )
+        .unwrap();
+
+        assert_eq!("{\n    \"a\": [\n        1\n    ]\n}", pretty);
+
+        // YAML key 및 값 형식
+        let json = convert(
+            "1: one\ntrue: yes\nlist: [1.5, ~, 'x']\n",
+            Format::Yaml,
+            Format::Json,
+        )
+        .unwrap();
+        let value: Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!("one", value["1"]);
+        assert_eq!("yes", value["true"]);
+        assert_eq!(serde_json::json!([1.5, null, "x"]), value["list"]);
+    }
+
+    #[test]
+    fn error_test() {
+        let error = convert("a = 1\nb = \n", Format::Toml, Format::Json).unwrap_err();
+
+        assert_eq!(Format::Toml, error.format());
+        assert_eq!(Some(2), error.line());
+        assert!(error.to_string().starts_with("TOML error(line 2, column"));
+
+        let error = convert("a: 1\n b: 2\n", Format::Yaml, Format::Json).unwrap_err();
+
+        assert_eq!(Some(2), error.line());
+
+        let error = convert(r#"{"a": {"b": [1, null]}}"#, Format::Json, Format::Toml).unwrap_err();
+
+        assert_eq!(None, error.line());
+        assert!(error.get_message().contains("a.b[1]"));
+        assert!(convert("[1, 2]", Format::Json, Format::Toml).is_err());
+        assert!(convert("a: .nan", Format::Yaml, Format::Json).is_err());
+    }
+
+    #[test]
+    fn from_path_test() {
+        assert_eq!(Some(Format::Yaml), Format::from_path(Path::new("a/b.YML")));
+        assert_eq!(
+            Some(Format::Toml),
+            Format::from_path(Path::new("Cargo.toml"))
+        );
+        assert_eq!(None, Format::from_path(Path::new("README")));
+    }
+}
@@ -1,8 +1,30 @@
 //! 날짜 관련 함수 모음
+//!
+//! 모듈 내 함수는 panic이 발생하지 않는다. 범위를 벗어나는 날짜(e.g. [NaiveDateTime::MIN]) 혹은 DST 전환
+//! 구간 등 실패할 수 있는 연산은 `Result`로 반환한다.
+#![deny(clippy::unwrap_used, clippy::expect_used)]
 
 use crate::error::InvalidArgumentError;
-use chrono::{DateTime, Datelike, Days, Months, NaiveDateTime, Offset, TimeZone, Utc, Weekday};
+use chrono::format::{Item, ParseError};
+use chrono::{
+    DateTime, Datelike, Days, DurationRound, LocalResult, Months, NaiveDate, NaiveDateTime,
+    NaiveTime, Offset, TimeDelta, TimeZone, Timelike, Utc, Weekday,
+};
 use chrono_tz::Tz;
+use range::DateRange;
+use std::sync::Arc;
+
+pub mod bucket;
+pub mod business;
+pub mod cache;
+pub mod cron;
+pub mod epoch;
+pub mod korean;
+pub mod lunar;
+pub mod range;
+pub mod rrule;
+#[cfg(feature = "serde")]
+pub mod serde_pattern;
 
 /// 지정된 날짜 및 시간 문자열을 UTC 날짜로 변경
 ///
@@ -27,7 +49,7 @@ use chrono_tz::Tz;
 ///
 /// # Errors
 ///
-/// - [InvalidArgumentError] - 잘못된 날짜 및 시간 형식 혹은 패턴
+/// - [InvalidArgumentError] - 잘못된 날짜 및 시간 형식 혹은 패턴, UTC 변환 결과가 범위를 벗어날 경우
 ///
 /// # Example
 ///
@@ -59,27 +81,83 @@ pub fn local_datetime_to_utc(
     pattern: &str,
     timezone: &Tz,
 ) -> Result<DateTime<Utc>, InvalidArgumentError> {
-    let naive_datetime = NaiveDateTime::parse_from_str(datetime, pattern);
-
-    if naive_datetime.is_err() {
-        let err = naive_datetime.as_ref().unwrap_err();
+    naive_local_to_utc(&parse_naive_datetime(datetime, pattern)?, timezone)
+}
 
-        println!("parse_from_str error => {:#?}", err);
+fn naive_local_to_utc(
+    naive_datetime: &NaiveDateTime,
+    timezone: &Tz,
+) -> Result<DateTime<Utc>, InvalidArgumentError> {
+    let fixed = timezone.offset_from_utc_datetime(naive_datetime).fix();
 
-        return Err(InvalidArgumentError::new(format!("{err:#?}").as_ref()));
-    }
+    naive_datetime
+        .checked_sub_offset(fixed)
+        .map(|utc| Utc.from_utc_datetime(&utc))
+        .ok_or_else(|| InvalidArgumentError::new("UTC 변환 결과가 범위를 벗어납니다."))
+}
 
-    Ok({
-        let offset = timezone.offset_from_utc_datetime(naive_datetime.as_ref().unwrap());
-        let fixed = offset.fix();
+/// 지정된 날짜 및 시간 문자열을 UTC 날짜로 변경(DST 전환 구간 확인)
+///
+/// [local_datetime_to_utc]와 동일하지만 일광 절약 시간(DST) 전환으로 지역 시각이 중복되거나 존재하지 않는
+/// 경우를 [LocalResult]로 구분하여 반환한다.
+///
+/// - [LocalResult::Single] - 유일한 UTC 시각
+/// - [LocalResult::Ambiguous] - DST 종료 등으로 중복되는 지역 시각(이른 시각, 늦은 시각)
+/// - [LocalResult::None] - DST 시작 등으로 존재하지 않는 지역 시각
+///
+/// # Arguments
+///
+/// - `datetime` - 날짜 및 시간 문자열 (e.g. '2024-11-27 13:23:47')
+/// - `pattern` - 날짜 및 시간 패턴 (e.g. '%Y-%m-%d %H:%M:%S')
+/// - `timezone` - [Tz]에서 정의된 timezone 정보 (e.g. [Tz::America__New_York])
+///
+/// # Return
+///
+/// - 변환 결과 `Result<LocalResult<DateTime<Utc>>, InvalidArgumentError>`
+///
+/// # Link
+///
+/// - [TimeZone::from_local_datetime]
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 잘못된 날짜 및 시간 형식 혹은 패턴
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::LocalResult;
+/// use chrono_tz::Tz;
+/// use cliff3_util::date_util::local_datetime_to_utc_checked;
+///
+/// let pattern = "%Y-%m-%d %H:%M";
+/// let timezone = Tz::America__New_York;
+///
+/// // DST 시작(02:00 -> 03:00)
+/// assert_eq!(
+///     LocalResult::None,
+///     local_datetime_to_utc_checked("2024-03-10 02:30", pattern, &timezone).unwrap()
+/// );
+///
+/// // DST 종료(02:00 -> 01:00)
+/// match local_datetime_to_utc_checked("2024-11-03 01:30", pattern, &timezone).unwrap() {
+///     LocalResult::Ambiguous(earliest, latest) => {
+///         assert_eq!("2024-11-03 05:30:00 UTC", earliest.to_string());
+///         assert_eq!("2024-11-03 06:30:00 UTC", latest.to_string());
+///     }
+///     _ => unreachable!(),
+/// }
+/// ```
+pub fn local_datetime_to_utc_checked(
+    datetime: &str,
+    pattern: &str,
+    timezone: &Tz,
+) -> Result<LocalResult<DateTime<Utc>>, InvalidArgumentError> {
+    let naive_datetime = parse_naive_datetime(datetime, pattern)?;
 
-        Utc.from_utc_datetime(
-            &fixed
-                .from_local_datetime(naive_datetime.as_ref().unwrap())
-                .unwrap()
-                .naive_utc(),
-        )
-    })
+    Ok(timezone
+        .from_local_datetime(&naive_datetime)
+        .map(|v| v.with_timezone(&Utc)))
 }
 
 /// 지정된 UTC 기준 날짜 및 시간 문자열을 지정된 timezone의 시간대([NaiveDateTime])의 시간으로 변경
@@ -99,8 +177,7 @@ pub fn local_datetime_to_utc(
 /// # Link
 ///
 /// - [NaiveDateTime::parse_from_str]
-/// - [Tz::offset_from_local_datetime]
-/// - [chrono_tz::TzOffset::fix]
+/// - [TimeZone::from_utc_datetime]
 ///
 /// # Errors
 ///
@@ -136,204 +213,2334 @@ pub fn utc_datetime_to_local(
     pattern: &str,
     timezone: &Tz,
 ) -> Result<NaiveDateTime, InvalidArgumentError> {
-    let utc_datetime = NaiveDateTime::parse_from_str(datetime, pattern);
+    let utc_datetime = parse_naive_datetime(datetime, pattern)?;
 
-    if utc_datetime.is_err() {
-        let err = utc_datetime.as_ref().unwrap_err();
+    // UTC 기준 시각은 DST 전환 구간에서도 지역 시각이 유일하게 결정된다
+    Ok(timezone.from_utc_datetime(&utc_datetime).naive_local())
+}
 
+fn parse_naive_datetime(
+    datetime: &str,
+    pattern: &str,
+) -> Result<NaiveDateTime, InvalidArgumentError> {
+    cache::parse_datetime(datetime, pattern).map_err(|err| {
         println!("parse_from_str error => {:#?}", err);
 
-        return Err(InvalidArgumentError::new(format!("{err:#?}").as_ref()));
-    }
-
-    Ok({
-        let utc_datetime = utc_datetime.unwrap();
-        let offset = timezone.offset_from_local_datetime(&utc_datetime).unwrap();
-        let fixed = offset.fix();
-
-        fixed.from_utc_datetime(&utc_datetime).naive_local()
+        parse_error(err)
     })
 }
 
-/// 지정한 날짜의 해당 월 마지막 날짜 반환
+fn parse_error(err: ParseError) -> InvalidArgumentError {
+    InvalidArgumentError::new(format!("{err:#?}").as_ref())
+}
+
+/// RFC 3339 형식(e.g. '2024-11-22T10:29:48+09:00') 문자열을 지정된 timezone의 날짜 및 시간으로 변경
+///
+/// 문자열에 포함된 offset으로 시각을 결정한 후 `timezone`의 지역 시각으로 표현한다.
 ///
 /// # Arguments
 ///
-/// - `datetime` - 마지막 날짜를 구하고자 하는 [DateTime]
+/// - `input` - RFC 3339 형식 문자열
+/// - `timezone` - [Tz]에서 정의된 변경하려는 지역의 시간대 정보 (e.g. [Tz::America__New_York])
 ///
 /// # Return
 ///
-/// - 해당 월의 마지막 날짜
+/// - 변환 결과 `Result<DateTime<Tz>, InvalidArgumentError>`
 ///
-/// # Link
+/// # Errors
 ///
-/// - [DateTime::checked_add_months]
-/// - [DateTime::with_day]
-/// - [DateTime::checked_sub_days]
+/// - [InvalidArgumentError] - 잘못된 RFC 3339 형식
 ///
 /// # Example
 ///
 /// ```rust
-/// use chrono::{DateTime, TimeZone, Utc};
-/// use cliff3_util::date_util::get_latest_day;
-///
-/// // leap month 2024
-/// // normal     2025
+/// use chrono_tz::Tz;
+/// use cliff3_util::date_util::parse_rfc3339_to_tz;
 ///
-/// let utc_leap_datetime: DateTime<Utc> = Utc.with_ymd_and_hms(2024, 2, 11, 13, 27, 0).unwrap();
-///  let latest_day = get_latest_day(&utc_leap_datetime);
+/// let result = parse_rfc3339_to_tz("2024-11-22T10:29:48+09:00", &Tz::America__New_York).unwrap();
 ///
-///  assert_eq!(29, latest_day);
+/// assert_eq!("2024-11-21 20:29:48 EST", result.to_string());
+/// assert!(parse_rfc3339_to_tz("2024-11-22 10:29:48", &Tz::UTC).is_err());
+/// ```
 ///
-///  let utc_datetime = Utc.with_ymd_and_hms(2025, 2, 11, 13, 27, 0).unwrap();
-///  let latest_day = get_latest_day(&utc_datetime);
+/// # Link
 ///
-///  assert_eq!(28, latest_day);
-/// ```
-pub fn get_latest_day<T: TimeZone + Sized>(datetime: &DateTime<T>) -> u32 {
-    let mut dummy = DateTime::from_timestamp(datetime.timestamp(), 0).unwrap();
-
-    dummy = dummy.checked_add_months(Months::new(1)).unwrap();
-    dummy = dummy.with_day(1).unwrap();
-    dummy = dummy.checked_sub_days(Days::new(1)).unwrap();
-
-    dummy.day()
+/// - [DateTime::parse_from_rfc3339]
+pub fn parse_rfc3339_to_tz(
+    input: &str,
+    timezone: &Tz,
+) -> Result<DateTime<Tz>, InvalidArgumentError> {
+    DateTime::parse_from_rfc3339(input)
+        .map(|v| v.with_timezone(timezone))
+        .map_err(parse_error)
 }
 
-/// 해당 일자가 포함된 주의 월요일/일요일 날짜 반환
+/// RFC 2822 형식(e.g. 'Fri, 22 Nov 2024 10:29:48 +0900') 문자열을 UTC 날짜 및 시간으로 변경
 ///
 /// # Arguments
 ///
-/// - `datetime` - 찾고자 하는 주에 포함된 날짜
+/// - `input` - RFC 2822 형식 문자열
 ///
 /// # Return
 ///
-/// - (월요일, 일요일) tuple
+/// - 변환 결과 `Result<DateTime<Utc>, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 잘못된 RFC 2822 형식
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::date_util::parse_rfc2822_to_utc;
+///
+/// let result = parse_rfc2822_to_utc("Fri, 22 Nov 2024 10:29:48 +0900").unwrap();
+///
+/// assert_eq!("2024-11-22 01:29:48 UTC", result.to_string());
+/// assert!(parse_rfc2822_to_utc("2024-11-22T10:29:48+09:00").is_err());
+/// ```
 ///
 /// # Link
 ///
-/// - [DateTime::weekday]
-/// - [DateTime::checked_sub_days]
-/// - [DateTime::checked_add_days]
-/// - [Weekday]
-/// - [Weekday::days_since]
+/// - [DateTime::parse_from_rfc2822]
+pub fn parse_rfc2822_to_utc(input: &str) -> Result<DateTime<Utc>, InvalidArgumentError> {
+    DateTime::parse_from_rfc2822(input)
+        .map(|v| v.with_timezone(&Utc))
+        .map_err(parse_error)
+}
+
+/// 날짜 및 시간 문자열 목록 전체를 UTC 날짜로 변경([local_datetime_to_utc] 참고)
+///
+/// 패턴은 한번만 해석하여 모든 항목에 재사용하며, 항목별 변환 결과를 입력 순서대로 반환한다. 잘못된 패턴일 경우
+/// 모든 항목이 동일한 오류를 반환한다.
+///
+/// # Arguments
+///
+/// - `datetimes` - 날짜 및 시간 문자열 목록
+/// - `pattern` - 날짜 및 시간 패턴 (e.g. '%Y-%m-%d %H:%M:%S')
+/// - `timezone` - [Tz]에서 정의된 timezone 정보 (e.g. [Tz::Asia__Seoul])
+///
+/// # Return
+///
+/// - 입력 순서와 동일한 변환 결과 목록
 ///
 /// # Example
 ///
 /// ```rust
-/// use chrono::{Utc, Datelike, TimeZone, NaiveDate, NaiveTime, NaiveDateTime};
-/// use cliff3_util::date_util::get_week_start_end;
-/// // 1978-06-22
-/// // 일요일 : 06-25
-/// // 월요일 : 06-19
-/// let datetime = NaiveDateTime::new(NaiveDate::from_ymd_opt(1978, 6, 22).unwrap(), NaiveTime::from_hms_opt(0, 0, 0).unwrap());
-/// let (monday, sunday) = get_week_start_end(&datetime);
+/// use chrono_tz::Tz;
+/// use cliff3_util::date_util::local_datetimes_to_utc;
 ///
-/// assert_eq!(1978, monday.year());
-/// assert_eq!(6, monday.month());
-/// assert_eq!(19, monday.day());
+/// let result = local_datetimes_to_utc(&["20241122102948", "invalid"], "%Y%m%d%H%M%S", &Tz::Asia__Seoul);
 ///
-/// assert_eq!(6, sunday.month());
-/// assert_eq!(25, sunday.day());
+/// assert_eq!("2024-11-22 01:29:48", result[0].as_ref().unwrap().format("%Y-%m-%d %H:%M:%S").to_string());
+/// assert!(result[1].is_err());
 /// ```
-pub fn get_week_start_end(datetime: &NaiveDateTime) -> (NaiveDateTime, NaiveDateTime) {
-    let current_day_of_week = datetime.weekday();
-    let days_since = current_day_of_week.days_since(Weekday::Mon);
-    let mut monday = datetime.clone();
+pub fn local_datetimes_to_utc<S: AsRef<str>>(
+    datetimes: &[S],
+    pattern: &str,
+    timezone: &Tz,
+) -> Vec<Result<DateTime<Utc>, InvalidArgumentError>> {
+    convert_all(datetimes, pattern, |v| naive_local_to_utc(&v, timezone))
+}
 
-    monday = monday
-        .checked_sub_days(Days::new(days_since as u64))
-        .unwrap();
+/// UTC 기준 날짜 및 시간 문자열 목록 전체를 지정된 timezone의 시간으로 변경([utc_datetime_to_local] 참고)
+///
+/// 패턴은 한번만 해석하여 모든 항목에 재사용하며, 항목별 변환 결과를 입력 순서대로 반환한다. 잘못된 패턴일 경우
+/// 모든 항목이 동일한 오류를 반환한다.
+///
+/// # Arguments
+///
+/// - `datetimes` - UTC 기준 날짜 및 시간 문자열 목록
+/// - `pattern` - 날짜 및 시간 패턴 (e.g. '%Y-%m-%d %H:%M:%S')
+/// - `timezone` - [Tz]에서 정의된 변경하려는 지역의 시간대 정보 (e.g. [Tz::Asia__Seoul])
+///
+/// # Return
+///
+/// - 입력 순서와 동일한 변환 결과 목록
+///
+/// # Example
+///
+/// ```rust
+/// use chrono_tz::Tz;
+/// use cliff3_util::date_util::utc_datetimes_to_local;
+///
+/// let result = utc_datetimes_to_local(&["20240911234758", "2024"], "%Y%m%d%H%M%S", &Tz::Asia__Seoul);
+///
+/// assert_eq!("2024-09-12 08:47:58", result[0].as_ref().unwrap().format("%Y-%m-%d %H:%M:%S").to_string());
+/// assert!(result[1].is_err());
+/// ```
+pub fn utc_datetimes_to_local<S: AsRef<str>>(
+    datetimes: &[S],
+    pattern: &str,
+    timezone: &Tz,
+) -> Vec<Result<NaiveDateTime, InvalidArgumentError>> {
+    convert_all(datetimes, pattern, |v| {
+        Ok(timezone.from_utc_datetime(&v).naive_local())
+    })
+}
 
-    let mut sunday = monday.clone();
+/// 패턴을 한번 해석하여 목록 전체를 변환
+fn convert_all<S: AsRef<str>, R>(
+    datetimes: &[S],
+    pattern: &str,
+    convert: impl Fn(NaiveDateTime) -> Result<R, InvalidArgumentError>,
+) -> Vec<Result<R, InvalidArgumentError>> {
+    let items = match cache::compiled_pattern(pattern) {
+        Ok(v) => v,
+        Err(err) => {
+            return datetimes.iter().map(|_| Err(parse_error(err))).collect();
+        }
+    };
 
-    sunday = sunday.checked_add_days(Days::new(6)).unwrap();
+    datetimes
+        .iter()
+        .map(|v| {
+            cache::parse_datetime_with(v.as_ref(), &items)
+                .map_err(parse_error)
+                .and_then(&convert)
+        })
+        .collect()
+}
 
-    (monday, sunday)
+/// 날짜 및 시간 패턴과 timezone을 보관하는 변환기
+///
+/// 생성시 패턴을 한번 해석하여 보관하므로 동일한 패턴과 timezone으로 반복 변환하는 경우
+/// [local_datetime_to_utc], [utc_datetime_to_local]을 매번 호출하는 것보다 효율적이다.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono_tz::Tz;
+/// use cliff3_util::date_util::DateTimeConverter;
+///
+/// let converter = DateTimeConverter::new("%Y%m%d%H%M%S", Tz::Asia__Seoul).unwrap();
+///
+/// assert_eq!(
+///     "2024-11-22 01:29:48",
+///     converter.to_utc("20241122102948").unwrap().format("%Y-%m-%d %H:%M:%S").to_string()
+/// );
+/// assert_eq!(
+///     "2024-09-12 08:47:58",
+///     converter.to_local("20240911234758").unwrap().format("%Y-%m-%d %H:%M:%S").to_string()
+/// );
+/// assert!(converter.to_utc("invalid").is_err());
+/// assert!(DateTimeConverter::new("%Q", Tz::UTC).is_err());
+/// ```
+#[derive(Debug, Clone)]
+pub struct DateTimeConverter {
+    pattern: String,
+    items: Arc<[Item<'static>]>,
+    timezone: Tz,
 }
 
-#[cfg(test)]
-mod tests {
-    use crate::date_util::{
-        get_latest_day, get_week_start_end, local_datetime_to_utc, utc_datetime_to_local,
-    };
-    use chrono::{
-        DateTime, Datelike, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Timelike, Utc,
-    };
-    use chrono_tz::Tz;
+impl DateTimeConverter {
+    /// 변환기 생성
+    ///
+    /// # Arguments
+    ///
+    /// - `pattern` - 날짜 및 시간 패턴 (e.g. '%Y-%m-%d %H:%M:%S')
+    /// - `timezone` - [Tz]에서 정의된 timezone 정보 (e.g. [Tz::Asia__Seoul])
+    ///
+    /// # Return
+    ///
+    /// - 생성 결과 `Result<DateTimeConverter, InvalidArgumentError>`
+    ///
+    /// # Errors
+    ///
+    /// - [InvalidArgumentError] - 잘못된 패턴
+    pub fn new(pattern: &str, timezone: Tz) -> Result<Self, InvalidArgumentError> {
+        let items = cache::compiled_pattern(pattern).map_err(parse_error)?;
 
-    #[test]
-    fn local_datetime_to_utc_test() {
-        // KST 2024-11-22 09:54:45
-        // UTC 2024-11-22 00:54:45
-        let str_datetime = "20241122095445"; // 2024-11-22 09:54:45
-        let pattern = "%Y%m%d%H%M%S";
-        let timezone = Tz::Asia__Seoul;
+        Ok(DateTimeConverter {
+            pattern: pattern.to_owned(),
+            items,
+            timezone,
+        })
+    }
 
-        let result = local_datetime_to_utc(str_datetime, pattern, &timezone);
+    /// 날짜 및 시간 패턴 반환
+    pub fn pattern(&self) -> &str {
+        &self.pattern
+    }
 
-        assert!(
-            result.is_ok(),
-            "{}",
-            format!("변환 실패 : {:#?}", result.as_ref().unwrap_err())
-        );
+    /// timezone 반환
+    pub fn timezone(&self) -> Tz {
+        self.timezone
+    }
 
-        let result = result.unwrap();
+    /// 지역 날짜 및 시간 문자열을 UTC 날짜로 변경([local_datetime_to_utc] 참고)
+    ///
+    /// # Errors
+    ///
+    /// - [InvalidArgumentError] - 잘못된 날짜 및 시간 형식, UTC 변환 결과가 범위를 벗어날 경우
+    pub fn to_utc(&self, datetime: &str) -> Result<DateTime<Utc>, InvalidArgumentError> {
+        naive_local_to_utc(&self.parse(datetime)?, &self.timezone)
+    }
 
-        println!("utc result => {:#?}", result);
+    /// UTC 기준 날짜 및 시간 문자열을 지역 시간으로 변경([utc_datetime_to_local] 참고)
+    ///
+    /// # Errors
+    ///
+    /// - [InvalidArgumentError] - 잘못된 날짜 및 시간 형식
+    pub fn to_local(&self, datetime: &str) -> Result<NaiveDateTime, InvalidArgumentError> {
+        Ok(self
+            .timezone
+            .from_utc_datetime(&self.parse(datetime)?)
+            .naive_local())
+    }
 
-        assert_eq!(2024, result.year());
-        assert_eq!(11, result.month());
-        assert_eq!(22, result.day());
-        assert_eq!(0, result.hour());
-        assert_eq!(54, result.minute());
-        assert_eq!(45, result.second());
+    fn parse(&self, datetime: &str) -> Result<NaiveDateTime, InvalidArgumentError> {
+        cache::parse_datetime_with(datetime, &self.items).map_err(parse_error)
+    }
+}
+
+/// 두 timezone의 지정된 날짜 업무 시간이 겹치는 구간을 UTC로 반환
+///
+/// 두 timezone 모두 동일한 지역 날짜 `date`의 `hours`(시작 포함, 종료 제외)를 업무 시간으로 보며, 각 지역 시각은
+/// 해당 시각의 offset을 적용하여 UTC로 변환한다. 회의 가능 시간 계산 등에 사용한다.
+///
+/// DST 전환으로 지역 시각이 중복될 경우 시작 시각은 이른 시각, 종료 시각은 늦은 시각을 사용하며, 존재하지 않는
+/// 지역 시각은 전환 이전 offset을 적용한다(e.g. New York `02:30` -> `03:30 EDT`).
+///
+/// # Arguments
+///
+/// - `tz_a` - 첫번째 timezone [Tz]
+/// - `tz_b` - 두번째 timezone [Tz]
+/// - `date` - 지역 날짜
+/// - `hours` - 업무 시간 (시작 시각, 종료 시각) (e.g. `09:00`, `18:00`)
+///
+/// # Return
+///
+/// - UTC 기준 겹치는 구간. 겹치지 않을 경우 `None`
+///   `Result<Option<DateRange<DateTime<Utc>>>, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 업무 종료 시각이 시작 시각 이전이거나 같을 경우 혹은 UTC 변환 결과가 범위를 벗어날 경우
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::{NaiveDate, NaiveTime};
+/// use chrono_tz::Tz;
+/// use cliff3_util::date_util::overlap_business_hours;
+///
+/// let date = NaiveDate::from_ymd_opt(2024, 7, 1).unwrap();
+/// let hours = (
+///     NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+///     NaiveTime::from_hms_opt(18, 0, 0).unwrap(),
+/// );
+/// // KST 09:00 ~ 18:00 = UTC 00:00 ~ 09:00, BST 09:00 ~ 18:00 = UTC 08:00 ~ 17:00
+/// let overlap = overlap_business_hours(&Tz::Asia__Seoul, &Tz::Europe__London, &date, hours)
+///     .unwrap()
+///     .unwrap();
+///
+/// assert_eq!("2024-07-01 08:00:00 UTC", overlap.start().to_string());
+/// assert_eq!("2024-07-01 09:00:00 UTC", overlap.end().to_string());
+/// assert!(overlap_business_hours(&Tz::Asia__Seoul, &Tz::America__New_York, &date, hours)
+///     .unwrap()
+///     .is_none());
+/// ```
+pub fn overlap_business_hours(
+    tz_a: &Tz,
+    tz_b: &Tz,
+    date: &NaiveDate,
+    hours: (NaiveTime, NaiveTime),
+) -> Result<Option<DateRange<DateTime<Utc>>>, InvalidArgumentError> {
+    let (open, close) = hours;
+
+    if close <= open {
+        return Err(InvalidArgumentError::new(
+            "업무 종료 시각은 시작 시각 이후이어야 합니다.",
+        ));
     }
 
-    #[test]
-    fn utc_datetime_to_local_test() {
-        // UTC 2024-11-22 22:54:45
-        // KST 2024-11-23 07:54:45
-        let utc_datetime = "20241122225445";
-        let patter = "%Y%m%d%H%M%S";
-        let timezone = Tz::Asia__Seoul;
-        let result = utc_datetime_to_local(utc_datetime, patter, &timezone);
+    let business_hours = |timezone: &Tz| {
+        DateRange::new(
+            resolve_local_to_utc(&date.and_time(open), timezone, false)?,
+            resolve_local_to_utc(&date.and_time(close), timezone, true)?,
+        )
+    };
 
-        assert!(
-            result.is_ok(),
-            "{}",
-            format!("변환 실패 : {:#?}", result.as_ref().unwrap_err())
-        );
+    Ok(business_hours(tz_a)?.intersection(&business_hours(tz_b)?))
+}
 
-        let result = result.unwrap();
+/// DST 전환 구간을 고려하여 지역 시각을 UTC로 변환
+///
+/// 중복되는 지역 시각은 `latest` 여부에 따라 늦은 시각 혹은 이른 시각을 사용하며, 존재하지 않는 지역 시각은 전환
+/// 이전 offset을 적용한다(건너뛴 시간만큼 뒤의 시각).
+fn resolve_local_to_utc(
+    naive_datetime: &NaiveDateTime,
+    timezone: &Tz,
+    latest: bool,
+) -> Result<DateTime<Utc>, InvalidArgumentError> {
+    let out_of_range = || InvalidArgumentError::new("UTC 변환 결과가 범위를 벗어납니다.");
 
-        println!("local result => {:#?}", result);
+    match timezone.from_local_datetime(naive_datetime) {
+        LocalResult::Single(v) => Ok(v.with_timezone(&Utc)),
+        LocalResult::Ambiguous(earliest, latest_v) => {
+            Ok(if latest { latest_v } else { earliest }.with_timezone(&Utc))
+        }
+        LocalResult::None => {
+            let before = naive_datetime
+                .checked_sub_days(Days::new(1))
+                .ok_or_else(out_of_range)?;
+            let fixed = timezone.offset_from_utc_datetime(&before).fix();
 
-        assert_eq!(2024, result.year());
-        assert_eq!(11, result.month());
-        assert_eq!(23, result.day());
-        assert_eq!(7, result.hour());
-        assert_eq!(54, result.minute());
-        assert_eq!(45, result.second());
+            naive_datetime
+                .checked_sub_offset(fixed)
+                .map(|utc| Utc.from_utc_datetime(&utc))
+                .ok_or_else(out_of_range)
+        }
     }
+}
 
-    #[test]
-    fn get_latest_day_test() {
-        // leap month 2024
-        // normal     2025
-        let utc_leap_datetime: DateTime<Utc> =
-            Utc.with_ymd_and_hms(2024, 2, 11, 13, 27, 0).unwrap();
-        let latest_day = get_latest_day(&utc_leap_datetime);
+/// [parse_datetime_auto]에서 시도하는 날짜 및 시간 형식
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateTimeFormat<'a> {
+    /// RFC 3339(ISO 8601) offset 포함 형식(e.g. `2024-11-22T10:29:48+09:00`). UTC 기준 시각으로 변환한다.
+    Rfc3339,
 
-        assert_eq!(29, latest_day);
+    /// 날짜 및 시간 패턴(e.g. `%Y-%m-%d %H:%M:%S`)
+    Pattern(&'a str),
 
-        let utc_datetime = Utc.with_ymd_and_hms(2025, 2, 11, 13, 27, 0).unwrap();
-        let latest_day = get_latest_day(&utc_datetime);
+    /// Unix epoch 초. 부호를 포함한 11자리 이하의 숫자
+    EpochSeconds,
 
-        assert_eq!(28, latest_day);
-    }
+    /// Unix epoch 밀리초. 부호를 포함한 12 ~ 13자리 숫자
+    EpochMillis,
+}
 
-    #[test]
+/// [parse_datetime_auto]의 기본 형식 목록(순서대로 시도)
+pub const DEFAULT_DATETIME_FORMATS: &[DateTimeFormat<'static>] = &[
+    DateTimeFormat::Rfc3339,
+    DateTimeFormat::Pattern("%Y-%m-%dT%H:%M:%S%.f"),
+    DateTimeFormat::Pattern("%Y-%m-%d %H:%M:%S%.f"),
+    DateTimeFormat::Pattern("%Y%m%d%H%M%S"),
+    DateTimeFormat::EpochMillis,
+    DateTimeFormat::EpochSeconds,
+];
+
+impl DateTimeFormat<'_> {
+    fn parse(&self, input: &str) -> Option<NaiveDateTime> {
+        let epoch_digits = |range: std::ops::RangeInclusive<usize>| {
+            let digits = input.strip_prefix('-').unwrap_or(input);
+
+            range.contains(&digits.len()) && digits.bytes().all(|v| v.is_ascii_digit())
+        };
+
+        match self {
+            DateTimeFormat::Rfc3339 => DateTime::parse_from_rfc3339(input)
+                .ok()
+                .map(|v| v.naive_utc()),
+            DateTimeFormat::Pattern(pattern) => cache::parse_datetime(input, pattern).ok(),
+            DateTimeFormat::EpochSeconds if epoch_digits(1..=11) => input
+                .parse()
+                .ok()
+                .and_then(|v| DateTime::from_timestamp(v, 0))
+                .map(|v| v.naive_utc()),
+            DateTimeFormat::EpochMillis if epoch_digits(12..=13) => input
+                .parse()
+                .ok()
+                .and_then(DateTime::from_timestamp_millis)
+                .map(|v| v.naive_utc()),
+            _ => None,
+        }
+    }
+}
+
+/// 여러 형식을 순서대로 시도하여 날짜 및 시간 문자열 변환
+///
+/// [DEFAULT_DATETIME_FORMATS]를 순서대로 시도하며 처음 성공한 결과와 해당 형식을 반환한다. offset을 포함한
+/// 형식 및 epoch 형식은 UTC 기준 시각으로, 그 외 형식은 입력된 시각 그대로 반환한다. 앞뒤 공백은 무시한다.
+///
+/// # Arguments
+///
+/// - `input` - 날짜 및 시간 문자열
+///
+/// # Return
+///
+/// - (변환 결과, 사용된 형식) tuple `Result<(NaiveDateTime, DateTimeFormat), InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 일치하는 형식이 없을 경우
+///
+/// # Link
+///
+/// - [parse_datetime_auto_with]
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::date_util::{parse_datetime_auto, DateTimeFormat};
+///
+/// let (datetime, format) = parse_datetime_auto("2024-11-22T10:29:48+09:00").unwrap();
+///
+/// assert_eq!("2024-11-22 01:29:48", datetime.to_string());
+/// assert_eq!(DateTimeFormat::Rfc3339, format);
+///
+/// let (datetime, format) = parse_datetime_auto("20241122102948").unwrap();
+///
+/// assert_eq!("2024-11-22 10:29:48", datetime.to_string());
+/// assert_eq!(DateTimeFormat::Pattern("%Y%m%d%H%M%S"), format);
+///
+/// let (datetime, format) = parse_datetime_auto("1732238988000").unwrap();
+///
+/// assert_eq!("2024-11-22 01:29:48", datetime.to_string());
+/// assert_eq!(DateTimeFormat::EpochMillis, format);
+/// assert!(parse_datetime_auto("2024/11/22").is_err());
+/// ```
+pub fn parse_datetime_auto(
+    input: &str,
+) -> Result<(NaiveDateTime, DateTimeFormat<'static>), InvalidArgumentError> {
+    parse_datetime_auto_with(input, DEFAULT_DATETIME_FORMATS)
+}
+
+/// 지정된 형식 목록을 순서대로 시도하여 날짜 및 시간 문자열 변환([parse_datetime_auto] 참고)
+///
+/// # Arguments
+///
+/// - `input` - 날짜 및 시간 문자열
+/// - `formats` - 시도할 형식 목록 [DateTimeFormat]
+///
+/// # Return
+///
+/// - (변환 결과, 사용된 형식) tuple `Result<(NaiveDateTime, DateTimeFormat), InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 일치하는 형식이 없을 경우
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::date_util::{parse_datetime_auto_with, DateTimeFormat, DEFAULT_DATETIME_FORMATS};
+///
+/// let mut formats = vec![DateTimeFormat::Pattern("%Y/%m/%d %H:%M")];
+///
+/// formats.extend_from_slice(DEFAULT_DATETIME_FORMATS);
+///
+/// let (datetime, format) = parse_datetime_auto_with("2024/11/22 10:29", &formats).unwrap();
+///
+/// assert_eq!("2024-11-22 10:29:00", datetime.to_string());
+/// assert_eq!(DateTimeFormat::Pattern("%Y/%m/%d %H:%M"), format);
+/// ```
+pub fn parse_datetime_auto_with<'a>(
+    input: &str,
+    formats: &[DateTimeFormat<'a>],
+) -> Result<(NaiveDateTime, DateTimeFormat<'a>), InvalidArgumentError> {
+    let input = input.trim();
+
+    formats
+        .iter()
+        .find_map(|format| format.parse(input).map(|v| (v, *format)))
+        .ok_or_else(|| InvalidArgumentError::new("일치하는 날짜 및 시간 형식이 없습니다."))
+}
+
+/// 지정한 날짜의 해당 월 마지막 날짜 반환
+///
+/// `datetime`의 timezone 기준 연/월로 계산하며, 날짜 연산을 거치지 않으므로 모든 범위의 [DateTime]에 대해
+/// 실패하지 않는다.
+///
+/// # Arguments
+///
+/// - `datetime` - 마지막 날짜를 구하고자 하는 [DateTime]
+///
+/// # Return
+///
+/// - 해당 월의 마지막 날짜
+///
+/// # Link
+///
+/// - [chrono::NaiveDate::leap_year]
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::{DateTime, TimeZone, Utc};
+/// use cliff3_util::date_util::get_latest_day;
+///
+/// // leap month 2024
+/// // normal     2025
+///
+/// let utc_leap_datetime: DateTime<Utc> = Utc.with_ymd_and_hms(2024, 2, 11, 13, 27, 0).unwrap();
+///  let latest_day = get_latest_day(&utc_leap_datetime);
+///
+///  assert_eq!(29, latest_day);
+///
+///  let utc_datetime = Utc.with_ymd_and_hms(2025, 2, 11, 13, 27, 0).unwrap();
+///  let latest_day = get_latest_day(&utc_datetime);
+///
+///  assert_eq!(28, latest_day);
+/// ```
+pub fn get_latest_day<T: TimeZone + Sized>(datetime: &DateTime<T>) -> u32 {
+    last_day_of_month(&datetime.date_naive())
+}
+
+/// 해당 일자가 포함된 월의 마지막 순간 반환
+///
+/// 입력과 동일한 timezone의 해당 월 마지막 날 지역 시각 `23:59:59.999999999`를 반환한다. 일광 절약 시간(DST)
+/// 전환으로 해당 지역 시각이 중복될 경우 늦은 시각을 반환한다.
+///
+/// # Arguments
+///
+/// - `datetime` - 찾고자 하는 월에 포함된 날짜
+///
+/// # Return
+///
+/// - 해당 월의 마지막 순간 `Result<DateTime<T>, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 해당 timezone에 존재하지 않는 시각일 경우
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::{TimeZone, Timelike};
+/// use chrono_tz::Tz;
+/// use cliff3_util::date_util::get_latest_datetime;
+///
+/// let datetime = Tz::Asia__Seoul.with_ymd_and_hms(2024, 2, 11, 13, 27, 0).unwrap();
+/// let latest = get_latest_datetime(&datetime).unwrap();
+///
+/// assert_eq!("2024-02-29 23:59:59 KST", latest.format("%Y-%m-%d %H:%M:%S %Z").to_string());
+/// assert_eq!(999_999_999, latest.nanosecond());
+/// ```
+pub fn get_latest_datetime<T: TimeZone>(
+    datetime: &DateTime<T>,
+) -> Result<DateTime<T>, InvalidArgumentError> {
+    let date = datetime.date_naive();
+    let last = date
+        .with_day(last_day_of_month(&date))
+        .and_then(|v| v.and_hms_nano_opt(23, 59, 59, 999_999_999))
+        .ok_or_else(|| InvalidArgumentError::new("해당 월이 날짜 범위를 벗어납니다."))?;
+
+    datetime
+        .timezone()
+        .from_local_datetime(&last)
+        .latest()
+        .ok_or_else(|| InvalidArgumentError::new("해당 timezone에 존재하지 않는 시각 입니다."))
+}
+
+fn last_day_of_month(date: &NaiveDate) -> u32 {
+    month_days(date.year(), date.month())
+}
+
+/// 해당 월의 일 수. `month`는 1 ~ 12
+const fn month_days(year: i32, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// 윤년 여부
+///
+/// 그레고리력 기준으로 4의 배수이면서 100의 배수가 아니거나, 400의 배수인 연도를 윤년으로 판단한다.
+///
+/// # Arguments
+///
+/// - `year` - 연도
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::date_util::is_leap_year;
+///
+/// assert!(is_leap_year(2024));
+/// assert!(is_leap_year(2000));
+/// assert!(!is_leap_year(1900));
+/// assert!(!is_leap_year(2025));
+/// ```
+pub const fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+/// 해당 연도의 일 수(365 혹은 366) 반환
+///
+/// # Arguments
+///
+/// - `year` - 연도
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::date_util::days_in_year;
+///
+/// assert_eq!(366, days_in_year(2024));
+/// assert_eq!(365, days_in_year(2100));
+/// ```
+pub const fn days_in_year(year: i32) -> u32 {
+    if is_leap_year(year) {
+        366
+    } else {
+        365
+    }
+}
+
+/// 해당 연월의 일 수 반환
+///
+/// [get_latest_day]와 달리 [DateTime] 없이 연도와 월로 계산한다.
+///
+/// # Arguments
+///
+/// - `year` - 연도
+/// - `month` - 월(1 ~ 12)
+///
+/// # Return
+///
+/// - 일 수 `Result<u32, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 잘못된 월
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::date_util::days_in_month;
+///
+/// assert_eq!(29, days_in_month(2024, 2).unwrap());
+/// assert_eq!(28, days_in_month(2025, 2).unwrap());
+/// assert_eq!(31, days_in_month(2025, 12).unwrap());
+/// assert!(days_in_month(2025, 13).is_err());
+/// ```
+pub fn days_in_month(year: i32, month: u32) -> Result<u32, InvalidArgumentError> {
+    if !(1..=12).contains(&month) {
+        return Err(InvalidArgumentError::new("월은 1 ~ 12 이어야 합니다."));
+    }
+
+    Ok(month_days(year, month))
+}
+
+/// 해당 일자의 분기(1 ~ 4) 반환
+///
+/// # Arguments
+///
+/// - `datetime` - 분기를 구하고자 하는 [DateTime]
+///
+/// # Return
+///
+/// - 분기(1 ~ 4)
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use cliff3_util::date_util::get_quarter;
+///
+/// assert_eq!(1, get_quarter(&Utc.with_ymd_and_hms(2024, 3, 31, 23, 59, 59).unwrap()));
+/// assert_eq!(2, get_quarter(&Utc.with_ymd_and_hms(2024, 4, 1, 0, 0, 0).unwrap()));
+/// ```
+pub fn get_quarter<T: TimeZone + Sized>(datetime: &DateTime<T>) -> u32 {
+    quarter_of(&datetime.date_naive())
+}
+
+fn quarter_of(date: &NaiveDate) -> u32 {
+    (date.month() - 1) / 3 + 1
+}
+
+/// 해당 일자가 포함된 분기의 첫 시각/마지막 시각 반환
+///
+/// 첫 시각은 분기 첫째 날 `00:00:00`, 마지막 시각은 분기 마지막 날 `23:59:59.999999999`이다.
+///
+/// # Arguments
+///
+/// - `datetime` - 찾고자 하는 분기에 포함된 [DateTime]
+///
+/// # Return
+///
+/// - (분기, 첫 시각, 마지막 시각) tuple `Result<(u32, NaiveDateTime, NaiveDateTime), InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 첫 시각 혹은 마지막 시각이 [NaiveDateTime]의 범위를 벗어날 경우
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use cliff3_util::date_util::quarter_start_end;
+///
+/// let datetime = Utc.with_ymd_and_hms(2024, 11, 22, 13, 27, 0).unwrap();
+/// let (quarter, start, end) = quarter_start_end(&datetime).unwrap();
+///
+/// assert_eq!(4, quarter);
+/// assert_eq!("2024-10-01 00:00:00", start.to_string());
+/// assert_eq!("2024-12-31 23:59:59.999999999", end.to_string());
+/// ```
+pub fn quarter_start_end<T: TimeZone + Sized>(
+    datetime: &DateTime<T>,
+) -> Result<(u32, NaiveDateTime, NaiveDateTime), InvalidArgumentError> {
+    let date = datetime.date_naive();
+    let quarter = quarter_of(&date);
+    let out_of_range = || InvalidArgumentError::new("해당 분기가 날짜 범위를 벗어납니다.");
+    let first =
+        NaiveDate::from_ymd_opt(date.year(), (quarter - 1) * 3 + 1, 1).ok_or_else(out_of_range)?;
+    let last = NaiveDate::from_ymd_opt(date.year(), quarter * 3, 1)
+        .and_then(|v| v.with_day(last_day_of_month(&v)))
+        .ok_or_else(out_of_range)?;
+    let end_of_day =
+        NaiveTime::from_hms_nano_opt(23, 59, 59, 999_999_999).ok_or_else(out_of_range)?;
+
+    Ok((
+        quarter,
+        first.and_time(NaiveTime::MIN),
+        last.and_time(end_of_day),
+    ))
+}
+
+/// 회계연도 및 회계연도 시작 이후 경과 개월 수(0 ~ 11) 반환
+fn fiscal_month(date: &NaiveDate, start_month: u32) -> Result<(i32, u32), InvalidArgumentError> {
+    if !(1..=12).contains(&start_month) {
+        return Err(InvalidArgumentError::new(
+            "회계연도 시작 월은 1 ~ 12 이어야 합니다.",
+        ));
+    }
+
+    if date.month() >= start_month {
+        Ok((date.year(), date.month() - start_month))
+    } else {
+        Ok((date.year() - 1, date.month() + 12 - start_month))
+    }
+}
+
+/// 회계연도 시작 월 기준 `offset`개월 이후부터 `months`개월 기간의 첫 시각/마지막 시각 반환
+fn fiscal_period(
+    fiscal_year: i32,
+    start_month: u32,
+    offset: u32,
+    months: u32,
+) -> Result<(NaiveDateTime, NaiveDateTime), InvalidArgumentError> {
+    let out_of_range = || InvalidArgumentError::new("해당 회계 기간이 날짜 범위를 벗어납니다.");
+    let first = NaiveDate::from_ymd_opt(fiscal_year, start_month, 1)
+        .and_then(|v| v.checked_add_months(Months::new(offset)))
+        .ok_or_else(out_of_range)?;
+    let last = first
+        .checked_add_months(Months::new(months))
+        .and_then(|v| v.pred_opt())
+        .ok_or_else(out_of_range)?;
+    let end_of_day =
+        NaiveTime::from_hms_nano_opt(23, 59, 59, 999_999_999).ok_or_else(out_of_range)?;
+
+    Ok((first.and_time(NaiveTime::MIN), last.and_time(end_of_day)))
+}
+
+/// 해당 일자의 회계연도 반환
+///
+/// 회계연도는 시작하는 해의 연도로 표기한다(e.g. 4월 시작 회계연도의 2025년 2월은 2024 회계연도). 종료하는
+/// 해의 연도로 표기하는 경우(e.g. 미국 연방정부)는 `start_month`가 `1`이 아닐 때 결과에 1을 더한다.
+///
+/// # Arguments
+///
+/// - `datetime` - 회계연도를 구하고자 하는 [DateTime]
+/// - `start_month` - 회계연도 시작 월(1 ~ 12)
+///
+/// # Return
+///
+/// - 회계연도 `Result<i32, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 잘못된 시작 월
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use cliff3_util::date_util::fiscal_year;
+///
+/// assert_eq!(2024, fiscal_year(&Utc.with_ymd_and_hms(2025, 2, 28, 0, 0, 0).unwrap(), 4).unwrap());
+/// assert_eq!(2025, fiscal_year(&Utc.with_ymd_and_hms(2025, 4, 1, 0, 0, 0).unwrap(), 4).unwrap());
+/// assert!(fiscal_year(&Utc.with_ymd_and_hms(2025, 4, 1, 0, 0, 0).unwrap(), 13).is_err());
+/// ```
+pub fn fiscal_year<T: TimeZone + Sized>(
+    datetime: &DateTime<T>,
+    start_month: u32,
+) -> Result<i32, InvalidArgumentError> {
+    fiscal_month(&datetime.date_naive(), start_month).map(|(year, _)| year)
+}
+
+/// 해당 일자의 회계 분기(1 ~ 4) 반환
+///
+/// # Arguments
+///
+/// - `datetime` - 회계 분기를 구하고자 하는 [DateTime]
+/// - `start_month` - 회계연도 시작 월(1 ~ 12)
+///
+/// # Return
+///
+/// - 회계 분기 `Result<u32, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 잘못된 시작 월
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use cliff3_util::date_util::fiscal_quarter;
+///
+/// let datetime = Utc.with_ymd_and_hms(2024, 11, 22, 13, 27, 0).unwrap();
+///
+/// assert_eq!(3, fiscal_quarter(&datetime, 4).unwrap());
+/// assert_eq!(2, fiscal_quarter(&datetime, 7).unwrap());
+/// ```
+pub fn fiscal_quarter<T: TimeZone + Sized>(
+    datetime: &DateTime<T>,
+    start_month: u32,
+) -> Result<u32, InvalidArgumentError> {
+    fiscal_month(&datetime.date_naive(), start_month).map(|(_, month)| month / 3 + 1)
+}
+
+/// 해당 일자가 포함된 회계연도의 첫 시각/마지막 시각 반환
+///
+/// 첫 시각은 회계연도 첫째 날 `00:00:00`, 마지막 시각은 회계연도 마지막 날 `23:59:59.999999999`이다.
+///
+/// # Arguments
+///
+/// - `datetime` - 찾고자 하는 회계연도에 포함된 [DateTime]
+/// - `start_month` - 회계연도 시작 월(1 ~ 12)
+///
+/// # Return
+///
+/// - (회계연도, 첫 시각, 마지막 시각) tuple `Result<(i32, NaiveDateTime, NaiveDateTime), InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 잘못된 시작 월, 첫 시각 혹은 마지막 시각이 [NaiveDateTime]의 범위를 벗어날 경우
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use cliff3_util::date_util::fiscal_year_start_end;
+///
+/// let datetime = Utc.with_ymd_and_hms(2024, 11, 22, 13, 27, 0).unwrap();
+/// let (year, start, end) = fiscal_year_start_end(&datetime, 3).unwrap();
+///
+/// assert_eq!(2024, year);
+/// assert_eq!("2024-03-01 00:00:00", start.to_string());
+/// assert_eq!("2025-02-28 23:59:59.999999999", end.to_string());
+/// ```
+pub fn fiscal_year_start_end<T: TimeZone + Sized>(
+    datetime: &DateTime<T>,
+    start_month: u32,
+) -> Result<(i32, NaiveDateTime, NaiveDateTime), InvalidArgumentError> {
+    let (year, _) = fiscal_month(&datetime.date_naive(), start_month)?;
+    let (start, end) = fiscal_period(year, start_month, 0, 12)?;
+
+    Ok((year, start, end))
+}
+
+/// 해당 일자가 포함된 회계 분기의 첫 시각/마지막 시각 반환
+///
+/// # Arguments
+///
+/// - `datetime` - 찾고자 하는 회계 분기에 포함된 [DateTime]
+/// - `start_month` - 회계연도 시작 월(1 ~ 12)
+///
+/// # Return
+///
+/// - (회계 분기, 첫 시각, 마지막 시각) tuple `Result<(u32, NaiveDateTime, NaiveDateTime), InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 잘못된 시작 월, 첫 시각 혹은 마지막 시각이 [NaiveDateTime]의 범위를 벗어날 경우
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use cliff3_util::date_util::fiscal_quarter_start_end;
+///
+/// let datetime = Utc.with_ymd_and_hms(2025, 1, 15, 0, 0, 0).unwrap();
+/// let (quarter, start, end) = fiscal_quarter_start_end(&datetime, 11).unwrap();
+///
+/// assert_eq!(1, quarter);
+/// assert_eq!("2024-11-01 00:00:00", start.to_string());
+/// assert_eq!("2025-01-31 23:59:59.999999999", end.to_string());
+/// ```
+pub fn fiscal_quarter_start_end<T: TimeZone + Sized>(
+    datetime: &DateTime<T>,
+    start_month: u32,
+) -> Result<(u32, NaiveDateTime, NaiveDateTime), InvalidArgumentError> {
+    let (year, month) = fiscal_month(&datetime.date_naive(), start_month)?;
+    let quarter = month / 3 + 1;
+    let (start, end) = fiscal_period(year, start_month, (quarter - 1) * 3, 3)?;
+
+    Ok((quarter, start, end))
+}
+
+/// 해당 일자가 포함된 주의 월요일/일요일 날짜 반환
+///
+/// # Arguments
+///
+/// - `datetime` - 찾고자 하는 주에 포함된 날짜
+///
+/// # Return
+///
+/// - (월요일, 일요일) tuple `Result<(NaiveDateTime, NaiveDateTime), InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 월요일 혹은 일요일이 [NaiveDateTime]의 범위를 벗어날 경우
+///
+/// # Link
+///
+/// - [DateTime::weekday]
+/// - [DateTime::checked_sub_days]
+/// - [DateTime::checked_add_days]
+/// - [Weekday]
+/// - [Weekday::days_since]
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::{Utc, Datelike, TimeZone, NaiveDate, NaiveTime, NaiveDateTime};
+/// use cliff3_util::date_util::get_week_start_end;
+/// // 1978-06-22
+/// // 일요일 : 06-25
+/// // 월요일 : 06-19
+/// let datetime = NaiveDateTime::new(NaiveDate::from_ymd_opt(1978, 6, 22).unwrap(), NaiveTime::from_hms_opt(0, 0, 0).unwrap());
+/// let (monday, sunday) = get_week_start_end(&datetime).unwrap();
+///
+/// assert_eq!(1978, monday.year());
+/// assert_eq!(6, monday.month());
+/// assert_eq!(19, monday.day());
+///
+/// assert_eq!(6, sunday.month());
+/// assert_eq!(25, sunday.day());
+///
+/// // 범위를 벗어나는 주
+/// assert!(get_week_start_end(&NaiveDateTime::MIN).is_err());
+/// ```
+pub fn get_week_start_end(
+    datetime: &NaiveDateTime,
+) -> Result<(NaiveDateTime, NaiveDateTime), InvalidArgumentError> {
+    get_week_start_end_from(datetime, Weekday::Mon)
+}
+
+/// 지정된 요일을 시작으로 하는 주의 첫째 날/마지막 날 날짜 반환
+///
+/// [get_week_start_end]와 동일하며 주의 시작 요일을 지정할 수 있다(e.g. 일요일 시작 달력은 [Weekday::Sun]).
+///
+/// # Arguments
+///
+/// - `datetime` - 찾고자 하는 주에 포함된 날짜
+/// - `week_start` - 주의 시작 요일
+///
+/// # Return
+///
+/// - (첫째 날, 마지막 날) tuple `Result<(NaiveDateTime, NaiveDateTime), InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 첫째 날 혹은 마지막 날이 [NaiveDateTime]의 범위를 벗어날 경우
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::{NaiveDateTime, Weekday};
+/// use cliff3_util::date_util::get_week_start_end_from;
+///
+/// let datetime = NaiveDateTime::parse_from_str("1978-06-22 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+/// let (sunday, saturday) = get_week_start_end_from(&datetime, Weekday::Sun).unwrap();
+///
+/// assert_eq!("1978-06-18", sunday.format("%Y-%m-%d").to_string());
+/// assert_eq!("1978-06-24", saturday.format("%Y-%m-%d").to_string());
+/// ```
+pub fn get_week_start_end_from(
+    datetime: &NaiveDateTime,
+    week_start: Weekday,
+) -> Result<(NaiveDateTime, NaiveDateTime), InvalidArgumentError> {
+    let days_since = datetime.weekday().days_since(week_start);
+    let out_of_range = || InvalidArgumentError::new("해당 주가 날짜 범위를 벗어납니다.");
+    let start = datetime
+        .checked_sub_days(Days::new(days_since as u64))
+        .ok_or_else(out_of_range)?;
+    let end = start
+        .checked_add_days(Days::new(6))
+        .ok_or_else(out_of_range)?;
+
+    Ok((start, end))
+}
+
+/// 해당 일자가 포함된 월의 첫째 날 `00:00:00`/마지막 날 `23:59:59` 반환
+///
+/// # Arguments
+///
+/// - `datetime` - 찾고자 하는 월에 포함된 날짜
+///
+/// # Return
+///
+/// - (첫째 날, 마지막 날) tuple `Result<(NaiveDateTime, NaiveDateTime), InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 첫째 날 혹은 마지막 날이 [NaiveDateTime]의 범위를 벗어날 경우
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::NaiveDateTime;
+/// use cliff3_util::date_util::get_month_start_end;
+///
+/// let datetime = NaiveDateTime::parse_from_str("2024-02-11 13:27:00", "%Y-%m-%d %H:%M:%S").unwrap();
+/// let (start, end) = get_month_start_end(&datetime).unwrap();
+///
+/// assert_eq!("2024-02-01 00:00:00", start.to_string());
+/// assert_eq!("2024-02-29 23:59:59", end.to_string());
+/// ```
+pub fn get_month_start_end(
+    datetime: &NaiveDateTime,
+) -> Result<(NaiveDateTime, NaiveDateTime), InvalidArgumentError> {
+    let date = datetime.date();
+    let out_of_range = || InvalidArgumentError::new("해당 월이 날짜 범위를 벗어납니다.");
+    let first = date.with_day(1).ok_or_else(out_of_range)?;
+    let last = date
+        .with_day(last_day_of_month(&date))
+        .ok_or_else(out_of_range)?;
+    let end_of_day = NaiveTime::from_hms_opt(23, 59, 59).ok_or_else(out_of_range)?;
+
+    Ok((first.and_time(NaiveTime::MIN), last.and_time(end_of_day)))
+}
+
+/// 지정된 개월 수를 더한 날짜 및 시간 반환(월말 보정)
+///
+/// 결과 월에 해당 일이 없을 경우 결과 월의 마지막 날로 보정한다(e.g. `01-31` + 1개월 = `02-28` 혹은 윤년의
+/// `02-29`, `03-31` - 1개월 = `02-28`). 보정된 날짜는 이후 계산에 영향을 주지 않으므로 `01-31` + 2개월은
+/// `03-31`이지만 (`01-31` + 1개월) + 1개월은 `03-28`이다. 시각(지역 시각)은 유지하며, 결과 지역 시각이
+/// 일광 절약 시간(DST) 전환으로 중복될 경우 이른 시각, 존재하지 않을 경우 입력 시각의 offset으로 해석한 시각을
+/// 반환한다.
+///
+/// # Arguments
+///
+/// - `datetime` - 기준 날짜 및 시간
+/// - `n` - 더할 개월 수. 음수일 경우 뺀다
+///
+/// # Return
+///
+/// - 계산 결과 `Result<DateTime<T>, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 계산 결과가 날짜 범위를 벗어날 경우
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use cliff3_util::date_util::add_months_clamped;
+///
+/// let datetime = Utc.with_ymd_and_hms(2024, 1, 31, 13, 27, 0).unwrap();
+///
+/// assert_eq!("2024-02-29 13:27:00 UTC", add_months_clamped(&datetime, 1).unwrap().to_string());
+/// assert_eq!("2024-03-31 13:27:00 UTC", add_months_clamped(&datetime, 2).unwrap().to_string());
+/// assert_eq!("2023-11-30 13:27:00 UTC", add_months_clamped(&datetime, -2).unwrap().to_string());
+/// ```
+///
+/// # Link
+///
+/// - [NaiveDate::checked_add_months]
+pub fn add_months_clamped<T: TimeZone>(
+    datetime: &DateTime<T>,
+    n: i32,
+) -> Result<DateTime<T>, InvalidArgumentError> {
+    let local = datetime.naive_local();
+    let months = Months::new(n.unsigned_abs());
+    let shifted = if n < 0 {
+        local.checked_sub_months(months)
+    } else {
+        local.checked_add_months(months)
+    }
+    .ok_or_else(|| InvalidArgumentError::new("계산 결과가 날짜 범위를 벗어납니다."))?;
+    let timezone = datetime.timezone();
+
+    if let Some(v) = timezone.from_local_datetime(&shifted).earliest() {
+        return Ok(v);
+    }
+
+    // DST 시작 등으로 존재하지 않는 지역 시각
+    shifted
+        .checked_sub_offset(datetime.offset().fix())
+        .map(|utc| timezone.from_utc_datetime(&utc))
+        .ok_or_else(|| InvalidArgumentError::new("계산 결과가 날짜 범위를 벗어납니다."))
+}
+
+/// 지정된 연수를 더한 날짜 및 시간 반환(월말 보정)
+///
+/// `n * 12`개월을 더하는 것과 동일하다([add_months_clamped] 참고). 윤년의 `02-29`에 윤년이 아닌 연도가 되도록
+/// 더할 경우 `02-28`을 반환한다.
+///
+/// # Arguments
+///
+/// - `datetime` - 기준 날짜 및 시간
+/// - `n` - 더할 연수. 음수일 경우 뺀다
+///
+/// # Return
+///
+/// - 계산 결과 `Result<DateTime<T>, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 계산 결과가 날짜 범위를 벗어날 경우
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use cliff3_util::date_util::add_years_clamped;
+///
+/// let datetime = Utc.with_ymd_and_hms(2024, 2, 29, 0, 0, 0).unwrap();
+///
+/// assert_eq!("2025-02-28 00:00:00 UTC", add_years_clamped(&datetime, 1).unwrap().to_string());
+/// assert_eq!("2028-02-29 00:00:00 UTC", add_years_clamped(&datetime, 4).unwrap().to_string());
+/// assert!(add_years_clamped(&datetime, i32::MAX).is_err());
+/// ```
+pub fn add_years_clamped<T: TimeZone>(
+    datetime: &DateTime<T>,
+    n: i32,
+) -> Result<DateTime<T>, InvalidArgumentError> {
+    let months = n
+        .checked_mul(12)
+        .ok_or_else(|| InvalidArgumentError::new("계산 결과가 날짜 범위를 벗어납니다."))?;
+
+    add_months_clamped(datetime, months)
+}
+
+/// 해당 월의 `n`번째 요일 반환(e.g. 3월 둘째 화요일)
+///
+/// # Arguments
+///
+/// - `year` - 연도
+/// - `month` - 월(1 ~ 12)
+/// - `weekday` - 요일 [Weekday]
+/// - `n` - 순서(1부터 시작)
+///
+/// # Return
+///
+/// - 해당 날짜 `Result<NaiveDate, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 잘못된 연도 혹은 월, `n`이 `0`이거나 해당 월에 `n`번째 요일이 없을 경우
+///
+/// # Link
+///
+/// - [NaiveDate::from_weekday_of_month_opt]
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::Weekday;
+/// use cliff3_util::date_util::nth_weekday_of_month;
+///
+/// assert_eq!("2025-03-11", nth_weekday_of_month(2025, 3, Weekday::Tue, 2).unwrap().to_string());
+/// assert!(nth_weekday_of_month(2025, 2, Weekday::Mon, 5).is_err());
+/// ```
+pub fn nth_weekday_of_month(
+    year: i32,
+    month: u32,
+    weekday: Weekday,
+    n: u32,
+) -> Result<NaiveDate, InvalidArgumentError> {
+    u8::try_from(n)
+        .ok()
+        .filter(|v| *v > 0)
+        .and_then(|v| NaiveDate::from_weekday_of_month_opt(year, month, weekday, v))
+        .ok_or_else(|| InvalidArgumentError::new("해당 월에 지정된 순서의 요일이 없습니다."))
+}
+
+/// 해당 월의 마지막 요일 반환(e.g. 5월 마지막 월요일)
+///
+/// # Arguments
+///
+/// - `year` - 연도
+/// - `month` - 월(1 ~ 12)
+/// - `weekday` - 요일 [Weekday]
+///
+/// # Return
+///
+/// - 해당 날짜 `Result<NaiveDate, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 잘못된 연도 혹은 월
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::Weekday;
+/// use cliff3_util::date_util::last_weekday_of_month;
+///
+/// assert_eq!("2025-05-26", last_weekday_of_month(2025, 5, Weekday::Mon).unwrap().to_string());
+/// assert!(last_weekday_of_month(2025, 13, Weekday::Mon).is_err());
+/// ```
+pub fn last_weekday_of_month(
+    year: i32,
+    month: u32,
+    weekday: Weekday,
+) -> Result<NaiveDate, InvalidArgumentError> {
+    let last = NaiveDate::from_ymd_opt(year, month, 1)
+        .and_then(|v| v.with_day(last_day_of_month(&v)))
+        .ok_or_else(|| InvalidArgumentError::new("잘못된 연도 혹은 월 입니다."))?;
+    let days_since = last.weekday().days_since(weekday);
+
+    last.checked_sub_days(Days::new(days_since as u64))
+        .ok_or_else(|| InvalidArgumentError::new("해당 요일이 날짜 범위를 벗어납니다."))
+}
+
+/// 날짜 및 시간 절삭 단위
+///
+/// # Link
+///
+/// - [truncate_to]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TimeUnit {
+    /// 초
+    Second,
+
+    /// 분
+    Minute,
+
+    /// 시
+    Hour,
+
+    /// 일
+    Day,
+
+    /// 주(월요일 시작)
+    Week,
+
+    /// 월
+    Month,
+
+    /// 년
+    Year,
+}
+
+/// 지정된 단위 미만의 값을 절삭한 날짜 및 시간 반환
+///
+/// [TimeUnit::Week]은 해당 주 월요일 `00:00:00`을 반환한다.
+///
+/// # Arguments
+///
+/// - `datetime` - 대상 날짜 및 시간
+/// - `unit` - [TimeUnit]
+///
+/// # Return
+///
+/// - 절삭 결과 `Result<NaiveDateTime, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 절삭 결과가 [NaiveDateTime]의 범위를 벗어날 경우
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::NaiveDateTime;
+/// use cliff3_util::date_util::{truncate_to, TimeUnit};
+///
+/// let datetime = NaiveDateTime::parse_from_str("2024-11-22 13:27:45", "%Y-%m-%d %H:%M:%S").unwrap();
+///
+/// assert_eq!("2024-11-22 13:00:00", truncate_to(&datetime, TimeUnit::Hour).unwrap().to_string());
+/// assert_eq!("2024-11-18 00:00:00", truncate_to(&datetime, TimeUnit::Week).unwrap().to_string());
+/// assert_eq!("2024-01-01 00:00:00", truncate_to(&datetime, TimeUnit::Year).unwrap().to_string());
+/// ```
+pub fn truncate_to(
+    datetime: &NaiveDateTime,
+    unit: TimeUnit,
+) -> Result<NaiveDateTime, InvalidArgumentError> {
+    let date = datetime.date();
+    let time = |h: u32, m: u32, s: u32| NaiveTime::from_hms_opt(h, m, s).unwrap_or(NaiveTime::MIN);
+    let truncated = match unit {
+        TimeUnit::Second => {
+            Some(date.and_time(time(datetime.hour(), datetime.minute(), datetime.second())))
+        }
+        TimeUnit::Minute => Some(date.and_time(time(datetime.hour(), datetime.minute(), 0))),
+        TimeUnit::Hour => Some(date.and_time(time(datetime.hour(), 0, 0))),
+        TimeUnit::Day => Some(date.and_time(NaiveTime::MIN)),
+        TimeUnit::Week => get_week_start_end(datetime)
+            .ok()
+            .map(|(monday, _)| monday.date().and_time(NaiveTime::MIN)),
+        TimeUnit::Month => date.with_day(1).map(|v| v.and_time(NaiveTime::MIN)),
+        TimeUnit::Year => {
+            NaiveDate::from_ymd_opt(date.year(), 1, 1).map(|v| v.and_time(NaiveTime::MIN))
+        }
+    };
+
+    truncated.ok_or_else(|| InvalidArgumentError::new("절삭 결과가 날짜 범위를 벗어납니다."))
+}
+
+/// 지정된 간격의 가장 가까운 배수로 반올림한 날짜 및 시간 반환(e.g. 15분 단위)
+///
+/// 간격은 `1970-01-01 00:00:00`을 기준으로 계산하므로 하루를 나누어 떨어지게 하는 간격(e.g. 15분, 1시간)은
+/// 해당 일 `00:00:00` 기준과 같다. 정확히 중간인 경우 올림한다.
+///
+/// # Arguments
+///
+/// - `datetime` - 대상 날짜 및 시간
+/// - `interval` - 반올림 간격(e.g. `TimeDelta::minutes(15)`)
+///
+/// # Return
+///
+/// - 반올림 결과 `Result<NaiveDateTime, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 간격이 `0` 이하이거나 반올림 결과가 [NaiveDateTime]의 범위를 벗어날 경우
+///
+/// # Link
+///
+/// - [DurationRound::duration_round]
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::{NaiveDateTime, TimeDelta};
+/// use cliff3_util::date_util::round_to;
+///
+/// let datetime = NaiveDateTime::parse_from_str("2024-11-22 13:37:30", "%Y-%m-%d %H:%M:%S").unwrap();
+///
+/// assert_eq!("2024-11-22 13:45:00", round_to(&datetime, TimeDelta::minutes(15)).unwrap().to_string());
+/// assert_eq!("2024-11-22 14:00:00", round_to(&datetime, TimeDelta::hours(1)).unwrap().to_string());
+/// assert!(round_to(&datetime, TimeDelta::zero()).is_err());
+/// ```
+pub fn round_to(
+    datetime: &NaiveDateTime,
+    interval: TimeDelta,
+) -> Result<NaiveDateTime, InvalidArgumentError> {
+    if interval <= TimeDelta::zero() {
+        return Err(InvalidArgumentError::new(
+            "반올림 간격은 0보다 커야 합니다.",
+        ));
+    }
+
+    datetime
+        .duration_round(interval)
+        .map_err(|_| InvalidArgumentError::new("반올림 결과가 날짜 범위를 벗어납니다."))
+}
+
+/// 만 나이 계산 결과([international_age_detail])
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AgeDetail {
+    /// 만 나이
+    pub years: u32,
+
+    /// 마지막 생일 이후 경과 개월 수(0 ~ 11)
+    pub months: u32,
+
+    /// 마지막 `months` 경과일 이후 경과 일 수
+    pub days: u32,
+}
+
+/// 생년월일과 기준일 사이의 경과 개월 수 및 마지막 경과일
+///
+/// 경과일은 생일과 같은 일자이며, 해당 월에 같은 일자가 없을 경우(e.g. 1월 31일 출생자의 2월) 다음 달 1일이다.
+fn elapsed_months(
+    birth: &NaiveDate,
+    as_of: &NaiveDate,
+) -> Result<(u32, NaiveDate), InvalidArgumentError> {
+    if as_of < birth {
+        return Err(InvalidArgumentError::new(
+            "기준일은 생년월일 이후이어야 합니다.",
+        ));
+    }
+
+    let mut months =
+        (as_of.year() - birth.year()) * 12 + as_of.month() as i32 - birth.month() as i32;
+
+    if as_of.day() < birth.day() {
+        months -= 1;
+    }
+
+    let months = u32::try_from(months).unwrap_or(0);
+    let out_of_range = || InvalidArgumentError::new("계산 결과가 날짜 범위를 벗어납니다.");
+    let first_day = birth
+        .with_day(1)
+        .and_then(|v| v.checked_add_months(Months::new(months)))
+        .ok_or_else(out_of_range)?;
+    let anniversary = if birth.day() > last_day_of_month(&first_day) {
+        first_day.checked_add_months(Months::new(1))
+    } else {
+        first_day.with_day(birth.day())
+    };
+
+    Ok((months, anniversary.ok_or_else(out_of_range)?))
+}
+
+/// 만 나이 계산
+///
+/// 생일 당일에 나이가 증가한다. 2월 29일 출생자는 윤년이 아닌 해의 경우 3월 1일에 증가한다.
+///
+/// # Arguments
+///
+/// - `birth` - 생년월일
+/// - `as_of` - 기준일
+///
+/// # Return
+///
+/// - 만 나이 `Result<u32, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 기준일이 생년월일 이전일 경우
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use cliff3_util::date_util::international_age;
+///
+/// let birth = NaiveDate::from_ymd_opt(1990, 5, 15).unwrap();
+///
+/// assert_eq!(34, international_age(&birth, &NaiveDate::from_ymd_opt(2025, 5, 14).unwrap()).unwrap());
+/// assert_eq!(35, international_age(&birth, &NaiveDate::from_ymd_opt(2025, 5, 15).unwrap()).unwrap());
+/// assert!(international_age(&birth, &NaiveDate::from_ymd_opt(1990, 5, 14).unwrap()).is_err());
+/// ```
+pub fn international_age(
+    birth: &NaiveDate,
+    as_of: &NaiveDate,
+) -> Result<u32, InvalidArgumentError> {
+    elapsed_months(birth, as_of).map(|(months, _)| months / 12)
+}
+
+/// 만 나이 및 마지막 생일 이후 경과 개월 수, 일 수 계산
+///
+/// 경과 개월 수는 생일과 같은 일자에 증가하며, 해당 월에 같은 일자가 없을 경우 다음 달 1일에 증가한다
+/// (e.g. 1월 31일 출생자는 3월 1일에 1개월).
+///
+/// # Arguments
+///
+/// - `birth` - 생년월일
+/// - `as_of` - 기준일
+///
+/// # Return
+///
+/// - 계산 결과 `Result<AgeDetail, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 기준일이 생년월일 이전일 경우
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use cliff3_util::date_util::{international_age_detail, AgeDetail};
+///
+/// let birth = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+/// let age = international_age_detail(&birth, &NaiveDate::from_ymd_opt(2025, 3, 5).unwrap()).unwrap();
+///
+/// assert_eq!(AgeDetail { years: 1, months: 1, days: 4 }, age);
+/// ```
+pub fn international_age_detail(
+    birth: &NaiveDate,
+    as_of: &NaiveDate,
+) -> Result<AgeDetail, InvalidArgumentError> {
+    let (months, anniversary) = elapsed_months(birth, as_of)?;
+    let days = as_of.signed_duration_since(anniversary).num_days();
+
+    Ok(AgeDetail {
+        years: months / 12,
+        months: months % 12,
+        days: u32::try_from(days).unwrap_or(0),
+    })
+}
+
+/// 세는 나이(한국식 나이) 계산
+///
+/// 출생 연도를 1세로 하여 매년 1월 1일에 증가한다.
+///
+/// # Arguments
+///
+/// - `birth` - 생년월일
+/// - `as_of` - 기준일
+///
+/// # Return
+///
+/// - 세는 나이 `Result<u32, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 기준일이 생년월일 이전일 경우
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use cliff3_util::date_util::korean_counting_age;
+///
+/// let birth = NaiveDate::from_ymd_opt(2024, 12, 31).unwrap();
+///
+/// assert_eq!(1, korean_counting_age(&birth, &birth).unwrap());
+/// assert_eq!(2, korean_counting_age(&birth, &NaiveDate::from_ymd_opt(2025, 1, 1).unwrap()).unwrap());
+/// ```
+pub fn korean_counting_age(
+    birth: &NaiveDate,
+    as_of: &NaiveDate,
+) -> Result<u32, InvalidArgumentError> {
+    if as_of < birth {
+        return Err(InvalidArgumentError::new(
+            "기준일은 생년월일 이후이어야 합니다.",
+        ));
+    }
+
+    Ok(u32::try_from(as_of.year() - birth.year()).unwrap_or(0) + 1)
+}
+
+/// 두 날짜 및 시간 사이의 달력 기준 차이([date_diff])
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DateDiff {
+    /// 경과 연수
+    pub years: u32,
+
+    /// 경과 개월 수(0 ~ 11)
+    pub months: u32,
+
+    /// 경과 일 수
+    pub days: u32,
+
+    /// 경과 시간(0 ~ 23)
+    pub hours: u32,
+
+    /// 경과 분(0 ~ 59)
+    pub minutes: u32,
+
+    /// 경과 초(0 ~ 59)
+    pub seconds: u32,
+}
+
+/// 두 날짜 및 시간 사이의 차이를 연, 월, 일, 시, 분, 초 단위로 계산
+///
+/// 개월 수는 시작일과 같은 일자, 같은 시각에 증가하며 해당 월에 같은 일자가 없을 경우 다음 달 1일에 증가한다
+/// (e.g. 1월 31일 시작은 3월 1일에 1개월, [international_age_detail]과 동일). 나머지 기간은 일, 시, 분, 초로
+/// 표현하며 초 미만은 버린다.
+///
+/// # Arguments
+///
+/// - `from` - 시작 날짜 및 시간
+/// - `to` - 종료 날짜 및 시간
+///
+/// # Return
+///
+/// - 계산 결과 `Result<DateDiff, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - `to`가 `from` 이전일 경우
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::NaiveDateTime;
+/// use cliff3_util::date_util::{date_diff, DateDiff};
+///
+/// let pattern = "%Y-%m-%d %H:%M:%S";
+/// let from = NaiveDateTime::parse_from_str("2022-03-15 09:00:00", pattern).unwrap();
+/// let to = NaiveDateTime::parse_from_str("2024-11-22 10:29:48", pattern).unwrap();
+///
+/// assert_eq!(
+///     DateDiff { years: 2, months: 8, days: 7, hours: 1, minutes: 29, seconds: 48 },
+///     date_diff(&from, &to).unwrap()
+/// );
+/// assert!(date_diff(&to, &from).is_err());
+/// ```
+pub fn date_diff(
+    from: &NaiveDateTime,
+    to: &NaiveDateTime,
+) -> Result<DateDiff, InvalidArgumentError> {
+    if to < from {
+        return Err(InvalidArgumentError::new(
+            "종료 시각은 시작 시각 이후이어야 합니다.",
+        ));
+    }
+
+    let (mut months, mut anniversary) = elapsed_months(&from.date(), &to.date())?;
+
+    // 같은 일자이나 시작 시각에 도달하지 않은 경우 이전 경과일 기준
+    if anniversary.and_time(from.time()) > *to {
+        let previous = to
+            .date()
+            .pred_opt()
+            .ok_or_else(|| InvalidArgumentError::new("계산 결과가 날짜 범위를 벗어납니다."))?;
+
+        (months, anniversary) = elapsed_months(&from.date(), &previous)?;
+    }
+
+    let rest = to
+        .signed_duration_since(anniversary.and_time(from.time()))
+        .num_seconds();
+    let part = |v: i64| u32::try_from(v).unwrap_or(0);
+
+    Ok(DateDiff {
+        years: months / 12,
+        months: months % 12,
+        days: part(rest / 86_400),
+        hours: part(rest % 86_400 / 3_600),
+        minutes: part(rest % 3_600 / 60),
+        seconds: part(rest % 60),
+    })
+}
+
+/// 기한까지 남은(혹은 지난) 시간([time_until])
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Countdown {
+    /// 기한 경과 여부. 기한과 같은 시각은 경과로 판단한다([is_expired]와 동일).
+    pub overdue: bool,
+
+    /// 일 수
+    pub days: u64,
+
+    /// 시간(0 ~ 23)
+    pub hours: u32,
+
+    /// 분(0 ~ 59)
+    pub minutes: u32,
+
+    /// 초(0 ~ 59)
+    pub seconds: u32,
+}
+
+impl Countdown {
+    /// 전체 초. 기한 경과시 음수
+    pub fn total_seconds(&self) -> i64 {
+        let total = i64::try_from(self.days)
+            .unwrap_or(i64::MAX / 86_400)
+            .saturating_mul(86_400)
+            .saturating_add(
+                self.hours as i64 * 3_600 + self.minutes as i64 * 60 + self.seconds as i64,
+            );
+
+        if self.overdue {
+            -total
+        } else {
+            total
+        }
+    }
+}
+
+/// 기준 시각부터 기한까지 남은 시간 계산
+///
+/// 남은 시간(기한 경과시 지난 시간)을 일, 시, 분, 초로 반환하며 초 미만은 버린다. 따라서 기한 직전
+/// 1초 미만은 `overdue`가 `false`인 `0`초로 표현된다.
+///
+/// # Arguments
+///
+/// - `target` - 기한
+/// - `now` - 기준 시각
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use chrono_tz::Tz;
+/// use cliff3_util::date_util::{time_until, Countdown};
+///
+/// let target = Tz::Asia__Seoul.with_ymd_and_hms(2024, 12, 1, 0, 0, 0).unwrap();
+/// let now = Utc.with_ymd_and_hms(2024, 11, 22, 1, 29, 48).unwrap(); // KST 10:29:48
+///
+/// assert_eq!(
+///     Countdown { overdue: false, days: 8, hours: 13, minutes: 30, seconds: 12 },
+///     time_until(&target, &now)
+/// );
+/// assert!(time_until(&now, &target).overdue);
+/// ```
+pub fn time_until<T: TimeZone, U: TimeZone>(target: &DateTime<T>, now: &DateTime<U>) -> Countdown {
+    let remaining = target.clone().signed_duration_since(now);
+    let overdue = remaining <= TimeDelta::zero();
+    let seconds = remaining.num_seconds().unsigned_abs();
+
+    Countdown {
+        overdue,
+        days: seconds / 86_400,
+        hours: (seconds % 86_400 / 3_600) as u32,
+        minutes: (seconds % 3_600 / 60) as u32,
+        seconds: (seconds % 60) as u32,
+    }
+}
+
+/// 기한 경과 여부
+///
+/// 현재 시각이 기한과 같거나 이후일 경우 `true`
+///
+/// # Arguments
+///
+/// - `target` - 기한
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::{TimeDelta, Utc};
+/// use cliff3_util::date_util::is_expired;
+///
+/// assert!(is_expired(&(Utc::now() - TimeDelta::seconds(1))));
+/// assert!(!is_expired(&(Utc::now() + TimeDelta::hours(1))));
+/// ```
+pub fn is_expired<T: TimeZone>(target: &DateTime<T>) -> bool {
+    time_until(target, &Utc::now()).overdue
+}
+
+/// 날짜 관련 함수를 method 형태로 제공하는 확장 trait
+///
+/// [NaiveDateTime] 및 [DateTime]에 대해 구현되어 있으며 [DateTime]은 해당 timezone의 지역 시각을
+/// 기준으로 계산한다.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::{NaiveDateTime, TimeZone, Timelike, Utc};
+/// use chrono_tz::Tz;
+/// use cliff3_util::date_util::{DateTimeExt, TimeUnit};
+///
+/// let datetime = Utc.with_ymd_and_hms(2024, 2, 29, 20, 27, 0).unwrap();
+/// let local = datetime.to_tz(Tz::Asia__Seoul);
+///
+/// assert_eq!(5, local.hour());
+/// assert_eq!(31, local.latest_day()); // 2024-03-01 (KST)
+/// assert_eq!("2024-03-01 00:00:00 KST", local.truncate_to(TimeUnit::Day).unwrap().to_string());
+///
+/// let (monday, sunday) = local.naive_local().week_bounds().unwrap();
+///
+/// assert_eq!("2024-02-26", monday.format("%Y-%m-%d").to_string());
+/// assert_eq!("2024-03-03", sunday.format("%Y-%m-%d").to_string());
+/// ```
+pub trait DateTimeExt: Sized {
+    /// 해당 월의 마지막 날짜([get_latest_day])
+    fn latest_day(&self) -> u32;
+
+    /// 해당 주의 (월요일, 일요일)([get_week_start_end])
+    ///
+    /// # Errors
+    ///
+    /// - [InvalidArgumentError] - 월요일 혹은 일요일이 날짜 범위를 벗어날 경우
+    fn week_bounds(&self) -> Result<(NaiveDateTime, NaiveDateTime), InvalidArgumentError>;
+
+    /// 지정된 요일을 시작으로 하는 주의 첫째 날/마지막 날([get_week_start_end_from])
+    ///
+    /// # Errors
+    ///
+    /// - [InvalidArgumentError] - 첫째 날 혹은 마지막 날이 날짜 범위를 벗어날 경우
+    fn week_bounds_from(
+        &self,
+        week_start: Weekday,
+    ) -> Result<(NaiveDateTime, NaiveDateTime), InvalidArgumentError>;
+
+    /// 해당 월의 (첫째 날 `00:00:00`, 마지막 날 `23:59:59`)([get_month_start_end])
+    ///
+    /// # Errors
+    ///
+    /// - [InvalidArgumentError] - 첫째 날 혹은 마지막 날이 날짜 범위를 벗어날 경우
+    fn month_bounds(&self) -> Result<(NaiveDateTime, NaiveDateTime), InvalidArgumentError>;
+
+    /// 지정된 timezone의 시각으로 변환. [NaiveDateTime]은 UTC 기준 시각으로 간주한다.
+    fn to_tz(&self, timezone: Tz) -> DateTime<Tz>;
+
+    /// 지정된 단위 미만의 값을 절삭([truncate_to])
+    ///
+    /// # Errors
+    ///
+    /// - [InvalidArgumentError] - 절삭 결과가 날짜 범위를 벗어나거나 해당 timezone에 존재하지 않는
+    ///   시각(DST 전환 구간)일 경우
+    fn truncate_to(&self, unit: TimeUnit) -> Result<Self, InvalidArgumentError>;
+
+    /// 지정된 간격의 가장 가까운 배수로 반올림([round_to])
+    ///
+    /// # Errors
+    ///
+    /// - [InvalidArgumentError] - 간격이 `0` 이하, 반올림 결과가 날짜 범위를 벗어나거나 해당 timezone에
+    ///   존재하지 않는 시각(DST 전환 구간)일 경우
+    fn round_to(&self, interval: TimeDelta) -> Result<Self, InvalidArgumentError>;
+}
+
+impl DateTimeExt for NaiveDateTime {
+    fn latest_day(&self) -> u32 {
+        last_day_of_month(&self.date())
+    }
+
+    fn week_bounds(&self) -> Result<(NaiveDateTime, NaiveDateTime), InvalidArgumentError> {
+        get_week_start_end(self)
+    }
+
+    fn week_bounds_from(
+        &self,
+        week_start: Weekday,
+    ) -> Result<(NaiveDateTime, NaiveDateTime), InvalidArgumentError> {
+        get_week_start_end_from(self, week_start)
+    }
+
+    fn month_bounds(&self) -> Result<(NaiveDateTime, NaiveDateTime), InvalidArgumentError> {
+        get_month_start_end(self)
+    }
+
+    fn to_tz(&self, timezone: Tz) -> DateTime<Tz> {
+        timezone.from_utc_datetime(self)
+    }
+
+    fn truncate_to(&self, unit: TimeUnit) -> Result<Self, InvalidArgumentError> {
+        truncate_to(self, unit)
+    }
+
+    fn round_to(&self, interval: TimeDelta) -> Result<Self, InvalidArgumentError> {
+        round_to(self, interval)
+    }
+}
+
+impl<T: TimeZone> DateTimeExt for DateTime<T> {
+    fn latest_day(&self) -> u32 {
+        get_latest_day(self)
+    }
+
+    fn week_bounds(&self) -> Result<(NaiveDateTime, NaiveDateTime), InvalidArgumentError> {
+        get_week_start_end(&self.naive_local())
+    }
+
+    fn week_bounds_from(
+        &self,
+        week_start: Weekday,
+    ) -> Result<(NaiveDateTime, NaiveDateTime), InvalidArgumentError> {
+        get_week_start_end_from(&self.naive_local(), week_start)
+    }
+
+    fn month_bounds(&self) -> Result<(NaiveDateTime, NaiveDateTime), InvalidArgumentError> {
+        get_month_start_end(&self.naive_local())
+    }
+
+    fn to_tz(&self, timezone: Tz) -> DateTime<Tz> {
+        self.with_timezone(&timezone)
+    }
+
+    fn truncate_to(&self, unit: TimeUnit) -> Result<Self, InvalidArgumentError> {
+        let truncated = truncate_to(&self.naive_local(), unit)?;
+
+        self.timezone()
+            .from_local_datetime(&truncated)
+            .earliest()
+            .ok_or_else(|| InvalidArgumentError::new("해당 timezone에 존재하지 않는 시각 입니다."))
+    }
+
+    fn round_to(&self, interval: TimeDelta) -> Result<Self, InvalidArgumentError> {
+        let rounded = round_to(&self.naive_local(), interval)?;
+
+        self.timezone()
+            .from_local_datetime(&rounded)
+            .earliest()
+            .ok_or_else(|| InvalidArgumentError::new("해당 timezone에 존재하지 않는 시각 입니다."))
+    }
+}
+
+/// 날짜 및 시간 문자열 목록 전체를 UTC 날짜로 병렬 변환([local_datetime_to_utc] 참고)
+///
+/// `rayon` feature 활성화시 사용 가능하며 결과는 입력 순서를 유지한다.
+///
+/// # Arguments
+///
+/// - `datetimes` - 날짜 및 시간 문자열 목록
+/// - `pattern` - 날짜 및 시간 패턴 (e.g. '%Y-%m-%d %H:%M:%S')
+/// - `timezone` - [Tz]에서 정의된 timezone 정보 (e.g. [Tz::Asia__Seoul])
+///
+/// # Return
+///
+/// - 입력 순서와 동일한 변환 결과 목록
+///
+/// # Example
+///
+/// ```rust
+/// use chrono_tz::Tz;
+/// use cliff3_util::date_util::par_local_datetime_to_utc;
+///
+/// let result = par_local_datetime_to_utc(&["20241122102948", "invalid"], "%Y%m%d%H%M%S", &Tz::Asia__Seoul);
+///
+/// assert_eq!("2024-11-22 01:29:48", result[0].as_ref().unwrap().format("%Y-%m-%d %H:%M:%S").to_string());
+/// assert!(result[1].is_err());
+/// ```
+#[cfg(feature = "rayon")]
+pub fn par_local_datetime_to_utc<S: AsRef<str> + Sync>(
+    datetimes: &[S],
+    pattern: &str,
+    timezone: &Tz,
+) -> Vec<Result<DateTime<Utc>, InvalidArgumentError>> {
+    use rayon::prelude::*;
+
+    datetimes
+        .par_iter()
+        .map(|v| local_datetime_to_utc(v.as_ref(), pattern, timezone))
+        .collect()
+}
+
+/// UTC 기준 날짜 및 시간 문자열 목록 전체를 지정된 timezone의 시간으로 병렬 변환([utc_datetime_to_local] 참고)
+///
+/// `rayon` feature 활성화시 사용 가능하며 결과는 입력 순서를 유지한다.
+///
+/// # Arguments
+///
+/// - `datetimes` - UTC 기준 날짜 및 시간 문자열 목록
+/// - `pattern` - 날짜 및 시간 패턴 (e.g. '%Y-%m-%d %H:%M:%S')
+/// - `timezone` - [Tz]에서 정의된 변경하려는 지역의 시간대 정보 (e.g. [Tz::Asia__Seoul])
+///
+/// # Return
+///
+/// - 입력 순서와 동일한 변환 결과 목록
+#[cfg(feature = "rayon")]
+pub fn par_utc_datetime_to_local<S: AsRef<str> + Sync>(
+    datetimes: &[S],
+    pattern: &str,
+    timezone: &Tz,
+) -> Vec<Result<NaiveDateTime, InvalidArgumentError>> {
+    use rayon::prelude::*;
+
+    datetimes
+        .par_iter()
+        .map(|v| utc_datetime_to_local(v.as_ref(), pattern, timezone))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::date_util::{
+        get_latest_day, get_week_start_end, local_datetime_to_utc, utc_datetime_to_local,
+    };
+    use chrono::{
+        DateTime, Datelike, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Timelike, Utc,
+    };
+    use chrono_tz::Tz;
+
+    #[test]
+    fn local_datetime_to_utc_test() {
+        // KST 2024-11-22 09:54:45
+        // UTC 2024-11-22 00:54:45
+        let str_datetime = "20241122095445"; // 2024-11-22 09:54:45
+        let pattern = "%Y%m%d%H%M%S";
+        let timezone = Tz::Asia__Seoul;
+
+        let result = local_datetime_to_utc(str_datetime, pattern, &timezone);
+
+        assert!(
+            result.is_ok(),
+            "{}",
+            format!("변환 실패 : {:#?}", result.as_ref().unwrap_err())
+        );
+
+        let result = result.unwrap();
+
+        println!("utc result => {:#?}", result);
+
+        assert_eq!(2024, result.year());
+        assert_eq!(11, result.month());
+        assert_eq!(22, result.day());
+        assert_eq!(0, result.hour());
+        assert_eq!(54, result.minute());
+        assert_eq!(45, result.second());
+    }
+
+    #[test]
+    fn utc_datetime_to_local_test() {
+        // UTC 2024-11-22 22:54:45
+        // KST 2024-11-23 07:54:45
+        let utc_datetime = "20241122225445";
+        let patter = "%Y%m%d%H%M%S";
+        let timezone = Tz::Asia__Seoul;
+        let result = utc_datetime_to_local(utc_datetime, patter, &timezone);
+
+        assert!(
+            result.is_ok(),
+            "{}",
+            format!("변환 실패 : {:#?}", result.as_ref().unwrap_err())
+        );
+
+        let result = result.unwrap();
+
+        println!("local result => {:#?}", result);
+
+        assert_eq!(2024, result.year());
+        assert_eq!(11, result.month());
+        assert_eq!(23, result.day());
+        assert_eq!(7, result.hour());
+        assert_eq!(54, result.minute());
+        assert_eq!(45, result.second());
+    }
+
+    #[test]
+    fn parse_datetime_auto_test() {
+        use crate::date_util::{parse_datetime_auto, parse_datetime_auto_with, DateTimeFormat};
+
+        let expected = [
+            (
+                "2024-11-22T10:29:48Z",
+                "2024-11-22 10:29:48",
+                DateTimeFormat::Rfc3339,
+            ),
+            (
+                "2024-11-22T10:29:48.5-05:00",
+                "2024-11-22 15:29:48.500",
+                DateTimeFormat::Rfc3339,
+            ),
+            (
+                "2024-11-22T10:29:48",
+                "2024-11-22 10:29:48",
+                DateTimeFormat::Pattern("%Y-%m-%dT%H:%M:%S%.f"),
+            ),
+            (
+                " 2024-11-22 10:29:48.123 ",
+                "2024-11-22 10:29:48.123",
+                DateTimeFormat::Pattern("%Y-%m-%d %H:%M:%S%.f"),
+            ),
+            (
+                "20241122102948",
+                "2024-11-22 10:29:48",
+                DateTimeFormat::Pattern("%Y%m%d%H%M%S"),
+            ),
+            (
+                "1732238988",
+                "2024-11-22 01:29:48",
+                DateTimeFormat::EpochSeconds,
+            ),
+            (
+                "-86400",
+                "1969-12-31 00:00:00",
+                DateTimeFormat::EpochSeconds,
+            ),
+            (
+                "1732238988123",
+                "2024-11-22 01:29:48.123",
+                DateTimeFormat::EpochMillis,
+            ),
+        ];
+
+        for (input, datetime, format) in expected {
+            let result = parse_datetime_auto(input).unwrap();
+
+            assert_eq!(
+                (datetime.to_owned(), format),
+                (result.0.to_string(), result.1)
+            );
+        }
+
+        // 14자리 숫자는 epoch로 해석하지 않는다
+        assert!(parse_datetime_auto("20241322102948").is_err());
+        assert!(parse_datetime_auto("").is_err());
+        assert!(parse_datetime_auto("12ab").is_err());
+        assert!(parse_datetime_auto_with("1732238988", &[DateTimeFormat::EpochMillis]).is_err());
+        assert!(parse_datetime_auto_with("2024-11-22", &[]).is_err());
+    }
+
+    #[test]
+    fn local_datetime_to_utc_checked_test() {
+        use crate::date_util::local_datetime_to_utc_checked;
+        use chrono::LocalResult;
+
+        let pattern = "%Y-%m-%d %H:%M:%S";
+        let convert = |v, tz| local_datetime_to_utc_checked(v, pattern, &tz).unwrap();
+
+        assert_eq!(
+            LocalResult::Single(Utc.with_ymd_and_hms(2024, 11, 22, 1, 29, 48).unwrap()),
+            convert("2024-11-22 10:29:48", Tz::Asia__Seoul)
+        );
+        assert_eq!(
+            LocalResult::None,
+            convert("2024-03-31 02:30:00", Tz::Europe__Berlin)
+        );
+        assert_eq!(
+            LocalResult::Ambiguous(
+                Utc.with_ymd_and_hms(2024, 10, 27, 0, 30, 0).unwrap(),
+                Utc.with_ymd_and_hms(2024, 10, 27, 1, 30, 0).unwrap()
+            ),
+            convert("2024-10-27 02:30:00", Tz::Europe__Berlin)
+        );
+        assert!(local_datetime_to_utc_checked("2024-13-01", pattern, &Tz::UTC).is_err());
+    }
+
+    #[test]
+    fn get_latest_day_test() {
+        // leap month 2024
+        // normal     2025
+        let utc_leap_datetime: DateTime<Utc> =
+            Utc.with_ymd_and_hms(2024, 2, 11, 13, 27, 0).unwrap();
+        let latest_day = get_latest_day(&utc_leap_datetime);
+
+        assert_eq!(29, latest_day);
+
+        let utc_datetime = Utc.with_ymd_and_hms(2025, 2, 11, 13, 27, 0).unwrap();
+        let latest_day = get_latest_day(&utc_datetime);
+
+        assert_eq!(28, latest_day);
+    }
+
+    #[test]
+    fn get_latest_datetime_test() {
+        use crate::date_util::get_latest_datetime;
+        use chrono::TimeDelta;
+
+        let datetime = Tz::America__New_York
+            .with_ymd_and_hms(2024, 11, 3, 1, 30, 0)
+            .earliest()
+            .unwrap()
+            + TimeDelta::nanoseconds(123_456_789);
+        let latest = get_latest_datetime(&datetime).unwrap();
+
+        assert_eq!(Tz::America__New_York, latest.timezone());
+        assert_eq!(
+            "2024-11-30 23:59:59.999999999 EST",
+            latest.format("%Y-%m-%d %H:%M:%S%.9f %Z").to_string()
+        );
+        assert_eq!(
+            "2024-12-01 00:00:00 EST",
+            (latest + TimeDelta::nanoseconds(1)).to_string()
+        );
+
+        // 지역 날짜 기준(UTC 2024-02-29 20:27 = KST 2024-03-01 05:27)
+        let kst = Utc
+            .with_ymd_and_hms(2024, 2, 29, 20, 27, 0)
+            .unwrap()
+            .with_timezone(&Tz::Asia__Seoul);
+
+        assert_eq!(
+            "2024-03-31 23:59:59.999999999 KST",
+            get_latest_datetime(&kst)
+                .unwrap()
+                .format("%Y-%m-%d %H:%M:%S%.9f %Z")
+                .to_string()
+        );
+        assert_eq!(
+            DateTime::<Utc>::MAX_UTC,
+            get_latest_datetime(&DateTime::<Utc>::MAX_UTC).unwrap()
+        );
+        assert!(get_latest_datetime(&DateTime::<Utc>::MIN_UTC).is_ok());
+    }
+
+    #[test]
+    fn quarter_test() {
+        use crate::date_util::{get_quarter, quarter_start_end};
+
+        let expected = [
+            (1, 1, "2024-01-01 00:00:00", "2024-03-31 23:59:59.999999999"),
+            (3, 1, "2024-01-01 00:00:00", "2024-03-31 23:59:59.999999999"),
+            (4, 2, "2024-04-01 00:00:00", "2024-06-30 23:59:59.999999999"),
+            (9, 3, "2024-07-01 00:00:00", "2024-09-30 23:59:59.999999999"),
+            (
+                12,
+                4,
+                "2024-10-01 00:00:00",
+                "2024-12-31 23:59:59.999999999",
+            ),
+        ];
+
+        for (month, quarter, start, end) in expected {
+            let datetime = Utc.with_ymd_and_hms(2024, month, 15, 12, 0, 0).unwrap();
+            let result = quarter_start_end(&datetime).unwrap();
+
+            assert_eq!(quarter, get_quarter(&datetime));
+            assert_eq!(
+                (quarter, start.to_owned(), end.to_owned()),
+                (result.0, result.1.to_string(), result.2.to_string())
+            );
+        }
+
+        // 지역 시각 기준(UTC 2024-03-31 15:00 = KST 2024-04-01 00:00)
+        let local = Utc
+            .with_ymd_and_hms(2024, 3, 31, 15, 0, 0)
+            .unwrap()
+            .with_timezone(&Tz::Asia__Seoul);
+
+        assert_eq!(2, get_quarter(&local));
+        assert_eq!(4, get_quarter(&DateTime::<Utc>::MAX_UTC));
+        assert!(quarter_start_end(&DateTime::<Utc>::MIN_UTC).is_ok());
+        assert!(quarter_start_end(&DateTime::<Utc>::MAX_UTC).is_ok());
+    }
+
+    #[test]
+    fn leap_year_test() {
+        use crate::date_util::{days_in_month, days_in_year, is_leap_year};
+
+        for year in [-400, -4, 0, 4, 1600, 1996, 2000, 2024, 2400] {
+            assert!(is_leap_year(year), "{}", year);
+            assert_eq!(366, days_in_year(year));
+        }
+
+        for year in [-100, -1, 1, 1700, 1800, 1900, 2023, 2100] {
+            assert!(!is_leap_year(year), "{}", year);
+            assert_eq!(365, days_in_year(year));
+        }
+
+        // chrono 결과와 비교
+        for year in [-1000, 1, 1900, 2000, 2024, 2025, 9999] {
+            let total: u32 = (1..=12).map(|m| days_in_month(year, m).unwrap()).sum();
+
+            assert_eq!(days_in_year(year), total);
+            assert_eq!(
+                NaiveDate::from_ymd_opt(year, 1, 1).unwrap().leap_year(),
+                is_leap_year(year)
+            );
+        }
+
+        assert!(days_in_month(2024, 0).is_err());
+        assert!(days_in_month(2024, 13).is_err());
+    }
+
+    #[test]
+    fn fiscal_year_test() {
+        use crate::date_util::{
+            fiscal_quarter, fiscal_quarter_start_end, fiscal_year, fiscal_year_start_end,
+            get_quarter, quarter_start_end,
+        };
+
+        let utc = |y, m, d| Utc.with_ymd_and_hms(y, m, d, 12, 0, 0).unwrap();
+
+        // 1월 시작은 역년 및 분기와 동일
+        for month in 1..=12 {
+            let datetime = utc(2024, month, 15);
+
+            assert_eq!(2024, fiscal_year(&datetime, 1).unwrap());
+            assert_eq!(
+                get_quarter(&datetime),
+                fiscal_quarter(&datetime, 1).unwrap()
+            );
+            assert_eq!(
+                quarter_start_end(&datetime).unwrap(),
+                fiscal_quarter_start_end(&datetime, 1).unwrap()
+            );
+        }
+
+        assert_eq!(2024, fiscal_year(&utc(2025, 3, 31), 4).unwrap());
+        assert_eq!(4, fiscal_quarter(&utc(2025, 3, 31), 4).unwrap());
+        assert_eq!(1, fiscal_quarter(&utc(2024, 12, 1), 12).unwrap());
+        assert_eq!(4, fiscal_quarter(&utc(2024, 11, 30), 12).unwrap());
+
+        let (year, start, end) = fiscal_year_start_end(&utc(2024, 2, 29), 3).unwrap();
+
+        assert_eq!(2023, year);
+        assert_eq!("2023-03-01 00:00:00", start.to_string());
+        assert_eq!("2024-02-29 23:59:59.999999999", end.to_string());
+
+        let (quarter, start, end) = fiscal_quarter_start_end(&utc(2024, 12, 25), 10).unwrap();
+
+        assert_eq!(1, quarter);
+        assert_eq!("2024-10-01 00:00:00", start.to_string());
+        assert_eq!("2024-12-31 23:59:59.999999999", end.to_string());
+
+        for invalid in [0, 13] {
+            assert!(fiscal_year(&utc(2024, 1, 1), invalid).is_err());
+            assert!(fiscal_quarter(&utc(2024, 1, 1), invalid).is_err());
+            assert!(fiscal_year_start_end(&utc(2024, 1, 1), invalid).is_err());
+            assert!(fiscal_quarter_start_end(&utc(2024, 1, 1), invalid).is_err());
+        }
+
+        assert!(fiscal_year_start_end(&DateTime::<Utc>::MAX_UTC, 7).is_err());
+        assert!(fiscal_year_start_end(&DateTime::<Utc>::MIN_UTC, 1).is_ok());
+    }
+
+    #[test]
     fn get_week_start_end_test() {
         // 1978-06-22
         // 일요일 : 06-25
@@ -342,7 +2549,7 @@ mod tests {
             NaiveDate::from_ymd_opt(1978, 6, 22).unwrap(),
             NaiveTime::from_hms_opt(0, 0, 0).unwrap(),
         );
-        let (monday, sunday) = get_week_start_end(&datetime);
+        let (monday, sunday) = get_week_start_end(&datetime).unwrap();
 
         assert_eq!(1978, monday.year());
         assert_eq!(6, monday.month());
@@ -350,5 +2557,643 @@ mod tests {
 
         assert_eq!(6, sunday.month());
         assert_eq!(25, sunday.day());
+
+        assert!(get_week_start_end(&NaiveDateTime::MIN).is_err());
+        assert!(get_week_start_end(&NaiveDateTime::MAX).is_err());
+    }
+
+    #[test]
+    fn get_month_start_end_test() {
+        use crate::date_util::get_month_start_end;
+
+        let bounds = |v| {
+            let datetime = NaiveDateTime::parse_from_str(v, "%Y-%m-%d %H:%M:%S").unwrap();
+            let (start, end) = get_month_start_end(&datetime).unwrap();
+
+            (start.to_string(), end.to_string())
+        };
+
+        assert_eq!(
+            (
+                "2025-02-01 00:00:00".to_owned(),
+                "2025-02-28 23:59:59".to_owned()
+            ),
+            bounds("2025-02-28 23:59:59")
+        );
+        assert_eq!(
+            (
+                "2024-12-01 00:00:00".to_owned(),
+                "2024-12-31 23:59:59".to_owned()
+            ),
+            bounds("2024-12-01 00:00:00")
+        );
+        assert!(get_month_start_end(&NaiveDateTime::MIN).is_ok());
+        assert!(get_month_start_end(&NaiveDateTime::MAX).is_ok());
+    }
+
+    #[test]
+    fn nth_weekday_of_month_test() {
+        use crate::date_util::{last_weekday_of_month, nth_weekday_of_month};
+        use chrono::Weekday;
+
+        let nth = |y, m, w, n| nth_weekday_of_month(y, m, w, n).map(|v| v.to_string());
+        let last = |y, m, w| last_weekday_of_month(y, m, w).map(|v| v.to_string());
+
+        // 2024-11-01 금요일
+        assert_eq!("2024-11-01", nth(2024, 11, Weekday::Fri, 1).unwrap());
+        assert_eq!("2024-11-29", nth(2024, 11, Weekday::Fri, 5).unwrap());
+        assert_eq!("2024-11-28", nth(2024, 11, Weekday::Thu, 4).unwrap());
+        assert!(nth(2024, 11, Weekday::Thu, 5).is_err());
+        assert!(nth(2024, 11, Weekday::Thu, 0).is_err());
+        assert!(nth(2024, 11, Weekday::Thu, 300).is_err());
+        assert!(nth(2024, 0, Weekday::Thu, 1).is_err());
+
+        assert_eq!("2024-11-30", last(2024, 11, Weekday::Sat).unwrap());
+        assert_eq!("2024-11-24", last(2024, 11, Weekday::Sun).unwrap());
+        assert_eq!("2024-02-29", last(2024, 2, Weekday::Thu).unwrap());
+        assert_eq!("2025-02-27", last(2025, 2, Weekday::Thu).unwrap());
+    }
+
+    #[test]
+    fn get_week_start_end_from_test() {
+        use crate::date_util::get_week_start_end_from;
+        use chrono::Weekday;
+
+        let date = |d| {
+            NaiveDate::from_ymd_opt(2024, 11, d)
+                .unwrap()
+                .and_time(NaiveTime::MIN)
+        };
+        let bounds = |d, start| {
+            let (start, end) = get_week_start_end_from(&date(d), start).unwrap();
+
+            (start.day(), end.day())
+        };
+
+        // 2024-11-17 일요일
+        assert_eq!((17, 23), bounds(17, Weekday::Sun));
+        assert_eq!((17, 23), bounds(23, Weekday::Sun));
+        assert_eq!((11, 17), bounds(17, Weekday::Mon));
+        assert_eq!((16, 22), bounds(22, Weekday::Sat));
+        assert_eq!(
+            get_week_start_end(&date(20)).unwrap(),
+            get_week_start_end_from(&date(20), Weekday::Mon).unwrap()
+        );
+        assert!(get_week_start_end_from(&NaiveDateTime::MIN, Weekday::Sun).is_err());
+    }
+
+    #[test]
+    fn extreme_datetime_test() {
+        assert_eq!(31, get_latest_day(&DateTime::<Utc>::MIN_UTC));
+        assert_eq!(31, get_latest_day(&DateTime::<Utc>::MAX_UTC));
+
+        let timezone = Tz::Asia__Seoul;
+
+        // UTC 변환시 범위를 벗어나는 지역 시각
+        assert!(local_datetime_to_utc(
+            &NaiveDateTime::MIN.format("%Y-%m-%d %H:%M:%S").to_string(),
+            "%Y-%m-%d %H:%M:%S",
+            &timezone
+        )
+        .is_err());
+
+        // DST 전환 구간(America/New_York, 2024-03-10 02:00 ~ 03:00 지역 시각 없음)
+        let result = utc_datetime_to_local(
+            "2024-03-10 07:30:00",
+            "%Y-%m-%d %H:%M:%S",
+            &Tz::America__New_York,
+        )
+        .unwrap();
+
+        assert_eq!(
+            "2024-03-10 03:30:00",
+            result.format("%Y-%m-%d %H:%M:%S").to_string()
+        );
+    }
+
+    #[test]
+    fn truncate_to_test() {
+        use crate::date_util::{truncate_to, DateTimeExt, TimeUnit};
+
+        let datetime =
+            NaiveDateTime::parse_from_str("2024-11-22 13:27:45.123", "%Y-%m-%d %H:%M:%S%.3f")
+                .unwrap();
+        let expected = [
+            (TimeUnit::Second, "2024-11-22 13:27:45"),
+            (TimeUnit::Minute, "2024-11-22 13:27:00"),
+            (TimeUnit::Hour, "2024-11-22 13:00:00"),
+            (TimeUnit::Day, "2024-11-22 00:00:00"),
+            (TimeUnit::Week, "2024-11-18 00:00:00"),
+            (TimeUnit::Month, "2024-11-01 00:00:00"),
+            (TimeUnit::Year, "2024-01-01 00:00:00"),
+        ];
+
+        for (unit, result) in expected {
+            assert_eq!(result, truncate_to(&datetime, unit).unwrap().to_string());
+        }
+
+        assert!(truncate_to(&NaiveDateTime::MIN, TimeUnit::Week).is_err());
+
+        // America/Sao_Paulo 2018-11-04 00:00 지역 시각 없음(DST 시작)
+        let local = Utc
+            .with_ymd_and_hms(2018, 11, 4, 12, 0, 0)
+            .unwrap()
+            .to_tz(Tz::America__Sao_Paulo);
+
+        assert!(local.truncate_to(TimeUnit::Day).is_err());
+        assert_eq!(
+            "2018-11-04 10:00:00 -02",
+            local.truncate_to(TimeUnit::Hour).unwrap().to_string()
+        );
+    }
+
+    #[test]
+    fn round_to_test() {
+        use crate::date_util::{round_to, DateTimeExt};
+        use chrono::TimeDelta;
+
+        let parse = |v| NaiveDateTime::parse_from_str(v, "%Y-%m-%d %H:%M:%S").unwrap();
+        let round = |v, interval| round_to(&parse(v), interval).unwrap().to_string();
+        let quarter = TimeDelta::minutes(15);
+
+        assert_eq!("2024-11-22 13:30:00", round("2024-11-22 13:37:29", quarter));
+        assert_eq!("2024-11-22 13:45:00", round("2024-11-22 13:37:30", quarter));
+        assert_eq!("2024-11-23 00:00:00", round("2024-11-22 23:52:30", quarter));
+        assert_eq!(
+            "2024-11-22 13:37:00",
+            round("2024-11-22 13:37:00", TimeDelta::seconds(10))
+        );
+        assert!(round_to(&parse("2024-11-22 13:37:00"), TimeDelta::minutes(-15)).is_err());
+        assert!(round_to(&NaiveDateTime::MAX, TimeDelta::days(1)).is_err());
+
+        // 지역 시각 기준
+        let local = Utc
+            .with_ymd_and_hms(2024, 11, 22, 4, 40, 0)
+            .unwrap()
+            .with_timezone(&Tz::Asia__Kolkata);
+
+        assert_eq!(
+            "2024-11-22 10:00:00 IST",
+            local.round_to(TimeDelta::hours(1)).unwrap().to_string()
+        );
+    }
+
+    #[test]
+    fn date_time_ext_test() {
+        use crate::date_util::DateTimeExt;
+
+        let naive =
+            NaiveDateTime::parse_from_str("2025-02-11 23:30:00", "%Y-%m-%d %H:%M:%S").unwrap();
+
+        assert_eq!(28, naive.latest_day());
+        assert_eq!(
+            "2025-02-12 08:30:00 KST",
+            naive.to_tz(Tz::Asia__Seoul).to_string()
+        );
+
+        let utc = Utc.with_ymd_and_hms(2025, 2, 11, 23, 30, 0).unwrap();
+
+        assert_eq!(naive.week_bounds().unwrap(), utc.week_bounds().unwrap());
+        assert_eq!(
+            naive.week_bounds_from(chrono::Weekday::Sun).unwrap(),
+            utc.week_bounds_from(chrono::Weekday::Sun).unwrap()
+        );
+        assert_eq!(28, utc.to_tz(Tz::Asia__Seoul).latest_day());
+        assert_eq!(naive.month_bounds().unwrap(), utc.month_bounds().unwrap());
+    }
+
+    #[test]
+    fn international_age_test() {
+        use crate::date_util::{
+            international_age, international_age_detail, korean_counting_age, AgeDetail,
+        };
+
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let age = |birth, as_of| international_age(&birth, &as_of).unwrap();
+        let detail = |birth, as_of| {
+            let v: AgeDetail = international_age_detail(&birth, &as_of).unwrap();
+
+            (v.years, v.months, v.days)
+        };
+
+        assert_eq!(0, age(date(2024, 11, 22), date(2024, 11, 22)));
+        assert_eq!(0, age(date(2024, 11, 22), date(2025, 11, 21)));
+        assert_eq!(1, age(date(2024, 11, 22), date(2025, 11, 22)));
+
+        // 2월 29일 출생
+        assert_eq!(0, age(date(2024, 2, 29), date(2025, 2, 28)));
+        assert_eq!(1, age(date(2024, 2, 29), date(2025, 3, 1)));
+        assert_eq!(4, age(date(2024, 2, 29), date(2028, 2, 29)));
+        assert_eq!((0, 11, 30), detail(date(2024, 2, 29), date(2025, 2, 28)));
+        assert_eq!((1, 0, 0), detail(date(2024, 2, 29), date(2025, 3, 1)));
+
+        // 말일 출생
+        assert_eq!((0, 0, 28), detail(date(2025, 1, 31), date(2025, 2, 28)));
+        assert_eq!((0, 1, 0), detail(date(2025, 1, 31), date(2025, 3, 1)));
+        assert_eq!((0, 2, 0), detail(date(2025, 1, 31), date(2025, 3, 31)));
+        assert_eq!((0, 2, 30), detail(date(2025, 1, 31), date(2025, 4, 30)));
+        assert_eq!((34, 6, 7), detail(date(1990, 5, 15), date(2024, 11, 22)));
+
+        assert!(international_age(&date(2024, 11, 22), &date(2024, 11, 21)).is_err());
+        assert!(international_age_detail(&date(2024, 11, 22), &date(2024, 11, 21)).is_err());
+
+        assert_eq!(
+            1,
+            korean_counting_age(&date(2024, 1, 1), &date(2024, 12, 31)).unwrap()
+        );
+        assert_eq!(
+            36,
+            korean_counting_age(&date(1990, 5, 15), &date(2025, 1, 1)).unwrap()
+        );
+        assert!(korean_counting_age(&date(2024, 1, 1), &date(2023, 12, 31)).is_err());
+    }
+
+    #[test]
+    fn date_diff_test() {
+        use crate::date_util::{date_diff, DateDiff};
+
+        let datetime = |v: &str| NaiveDateTime::parse_from_str(v, "%Y-%m-%d %H:%M:%S").unwrap();
+        let diff = |from, to| date_diff(&datetime(from), &datetime(to)).unwrap();
+
+        assert_eq!(
+            DateDiff::default(),
+            diff("2024-11-22 10:29:48", "2024-11-22 10:29:48")
+        );
+        assert_eq!(
+            DateDiff {
+                years: 1,
+                ..Default::default()
+            },
+            diff("2024-02-29 00:00:00", "2025-03-01 00:00:00")
+        );
+        // 시작 시각에 도달하지 않은 경우 이전 달 기준
+        assert_eq!(
+            DateDiff {
+                months: 11,
+                days: 30,
+                hours: 23,
+                minutes: 59,
+                seconds: 59,
+                ..Default::default()
+            },
+            diff("2023-11-22 10:00:00", "2024-11-22 09:59:59")
+        );
+        assert_eq!(
+            DateDiff {
+                days: 29,
+                hours: 12,
+                ..Default::default()
+            },
+            diff("2024-01-31 00:00:00", "2024-02-29 12:00:00")
+        );
+        assert_eq!(
+            DateDiff {
+                months: 1,
+                ..Default::default()
+            },
+            diff("2024-01-31 00:00:00", "2024-03-01 00:00:00")
+        );
+        assert_eq!(
+            DateDiff {
+                hours: 23,
+                ..Default::default()
+            },
+            diff("2024-12-31 01:00:00", "2025-01-01 00:00:00")
+        );
+        assert_eq!(
+            DateDiff {
+                years: 9999,
+                months: 11,
+                days: 30,
+                hours: 23,
+                minutes: 59,
+                seconds: 59,
+            },
+            diff("0000-01-01 00:00:00", "9999-12-31 23:59:59")
+        );
+        assert!(date_diff(&NaiveDateTime::MAX, &NaiveDateTime::MIN).is_err());
+        assert!(date_diff(&NaiveDateTime::MIN, &NaiveDateTime::MAX).is_ok());
+    }
+
+    #[test]
+    fn time_until_test() {
+        use crate::date_util::{time_until, Countdown};
+        use chrono::TimeDelta;
+
+        let now = Utc.with_ymd_and_hms(2024, 11, 22, 10, 0, 0).unwrap();
+        let countdown = |delta: TimeDelta| time_until(&(now + delta), &now);
+
+        assert_eq!(
+            Countdown {
+                overdue: true,
+                ..Default::default()
+            },
+            countdown(TimeDelta::zero())
+        );
+        // 1초 미만 남은 경우
+        assert_eq!(
+            Countdown::default(),
+            countdown(TimeDelta::milliseconds(999))
+        );
+        assert_eq!(
+            Countdown {
+                overdue: true,
+                ..Default::default()
+            },
+            countdown(TimeDelta::milliseconds(-999))
+        );
+        assert_eq!(
+            Countdown {
+                overdue: true,
+                days: 1,
+                hours: 2,
+                minutes: 3,
+                seconds: 4,
+            },
+            countdown(-TimeDelta::seconds(93_784))
+        );
+        assert_eq!(
+            93_784,
+            countdown(TimeDelta::seconds(93_784)).total_seconds()
+        );
+        assert_eq!(
+            -93_784,
+            countdown(-TimeDelta::seconds(93_784)).total_seconds()
+        );
+        assert!(!time_until(&DateTime::<Utc>::MAX_UTC, &DateTime::<Utc>::MIN_UTC).overdue);
+        assert!(time_until(&DateTime::<Utc>::MIN_UTC, &DateTime::<Utc>::MAX_UTC).overdue);
+    }
+
+    #[test]
+    fn batch_datetime_test() {
+        use crate::date_util::{local_datetimes_to_utc, utc_datetimes_to_local};
+
+        let targets: Vec<String> = (0..24).map(|h| format!("20241122{:02}0000", h)).collect();
+        let pattern = "%Y%m%d%H%M%S";
+        let utc = local_datetimes_to_utc(&targets, pattern, &Tz::Asia__Seoul);
+
+        assert_eq!(24, utc.len());
+
+        for (target, result) in targets.iter().zip(&utc) {
+            assert_eq!(
+                &local_datetime_to_utc(target, pattern, &Tz::Asia__Seoul).unwrap(),
+                result.as_ref().unwrap()
+            );
+        }
+
+        let local = utc_datetimes_to_local(&targets, pattern, &Tz::America__New_York);
+
+        for (target, result) in targets.iter().zip(&local) {
+            assert_eq!(
+                &utc_datetime_to_local(target, pattern, &Tz::America__New_York).unwrap(),
+                result.as_ref().unwrap()
+            );
+        }
+
+        let mixed =
+            local_datetimes_to_utc(&["20241122102948", "", "20241322102948"], pattern, &Tz::UTC);
+
+        assert!(mixed[0].is_ok());
+        assert!(mixed[1].is_err());
+        assert!(mixed[2].is_err());
+
+        // 잘못된 패턴은 모든 항목이 오류
+        let invalid = utc_datetimes_to_local(&["2024", "2025"], "%Q", &Tz::UTC);
+
+        assert_eq!(2, invalid.len());
+        assert!(invalid.iter().all(|v| v.is_err()));
+        assert!(local_datetimes_to_utc::<&str>(&[], pattern, &Tz::UTC).is_empty());
+    }
+
+    #[test]
+    fn date_time_converter_test() {
+        use crate::date_util::DateTimeConverter;
+
+        let pattern = "%Y-%m-%d %H:%M:%S";
+        let converter = DateTimeConverter::new(pattern, Tz::America__New_York).unwrap();
+
+        assert_eq!(pattern, converter.pattern());
+        assert_eq!(Tz::America__New_York, converter.timezone());
+
+        for target in [
+            "2024-03-10 02:30:00",
+            "2024-11-03 01:30:00",
+            "2024-07-01 12:00:00",
+        ] {
+            assert_eq!(
+                local_datetime_to_utc(target, pattern, &Tz::America__New_York).unwrap(),
+                converter.to_utc(target).unwrap()
+            );
+            assert_eq!(
+                utc_datetime_to_local(target, pattern, &Tz::America__New_York).unwrap(),
+                converter.to_local(target).unwrap()
+            );
+        }
+
+        assert!(converter.to_utc("2024-02-30 00:00:00").is_err());
+        assert!(converter.to_local("").is_err());
+        assert!(converter.clone().to_utc("2024-07-01 12:00:00").is_ok());
+        assert!(DateTimeConverter::new("%Q", Tz::UTC).is_err());
+    }
+
+    #[test]
+    fn overlap_business_hours_test() {
+        use crate::date_util::overlap_business_hours;
+
+        let time = |h| NaiveTime::from_hms_opt(h, 0, 0).unwrap();
+        let date = |m, d| NaiveDate::from_ymd_opt(2024, m, d).unwrap();
+        let hours = (time(9), time(18));
+        let overlap = |a, b, d| {
+            overlap_business_hours(a, b, &d, hours)
+                .unwrap()
+                .map(|v| (v.start().hour(), v.end().hour()))
+        };
+
+        // EDT(UTC-4) 13:00 ~ 22:00, BST(UTC+1) 08:00 ~ 17:00
+        assert_eq!(
+            Some((13, 17)),
+            overlap(&Tz::America__New_York, &Tz::Europe__London, date(7, 1))
+        );
+        // 미국만 DST 적용중인 기간: EDT 13:00 ~ 22:00, GMT 09:00 ~ 18:00
+        assert_eq!(
+            Some((13, 18)),
+            overlap(&Tz::Europe__London, &Tz::America__New_York, date(3, 15))
+        );
+        assert_eq!(
+            Some((0, 9)),
+            overlap(&Tz::Asia__Seoul, &Tz::Asia__Tokyo, date(11, 22))
+        );
+        assert_eq!(
+            None,
+            overlap(&Tz::Asia__Seoul, &Tz::America__New_York, date(11, 22))
+        );
+        // 종료 시각과 시작 시각이 같은 경우 겹치지 않음(KST 09:00 ~ 18:00, UTC 09:00 ~ 18:00)
+        assert_eq!(None, overlap(&Tz::Asia__Seoul, &Tz::UTC, date(11, 22)));
+        // DST 전환 전날(지역 시각 기준 offset 적용)
+        let utc = |d: NaiveDate, h| d.and_hms_opt(h, 0, 0).unwrap().and_utc();
+        let same = |tz: &Tz, d: NaiveDate, h: (NaiveTime, NaiveTime)| {
+            overlap_business_hours(tz, tz, &d, h)
+                .unwrap()
+                .map(|v| (*v.start(), *v.end()))
+        };
+
+        // AEST(UTC+10) 09:00 ~ 18:00, 10월 6일 DST 시작
+        assert_eq!(
+            Some((utc(date(10, 4), 23), utc(date(10, 5), 8))),
+            same(&Tz::Australia__Sydney, date(10, 5), hours)
+        );
+        // NZST(UTC+12) 09:00 ~ 18:00, 9월 29일 DST 시작
+        assert_eq!(
+            Some((utc(date(9, 27), 21), utc(date(9, 28), 6))),
+            same(&Tz::Pacific__Auckland, date(9, 28), hours)
+        );
+        // AEDT(UTC+11) 09:00 ~ 18:00, 4월 7일 DST 종료
+        assert_eq!(
+            Some((utc(date(4, 5), 22), utc(date(4, 6), 7))),
+            same(&Tz::Australia__Sydney, date(4, 6), hours)
+        );
+
+        let half = NaiveTime::from_hms_opt(1, 30, 0).unwrap();
+        let gap = NaiveTime::from_hms_opt(2, 30, 0).unwrap();
+        let at = |d: NaiveDate, h, m| d.and_hms_opt(h, m, 0).unwrap().and_utc();
+
+        // 존재하지 않는 시각(02:30 -> 03:30 EDT)
+        assert_eq!(
+            Some((at(date(3, 10), 7, 30), utc(date(3, 10), 16))),
+            same(&Tz::America__New_York, date(3, 10), (gap, time(12)))
+        );
+        // 중복되는 시각: 시작은 이른 시각(EDT), 종료는 늦은 시각(EST)
+        assert_eq!(
+            Some((at(date(11, 3), 5, 30), utc(date(11, 3), 17))),
+            same(&Tz::America__New_York, date(11, 3), (half, time(12)))
+        );
+        assert_eq!(
+            Some((utc(date(11, 3), 4), at(date(11, 3), 6, 30))),
+            same(&Tz::America__New_York, date(11, 3), (time(0), half))
+        );
+        assert!(
+            overlap_business_hours(&Tz::UTC, &Tz::UTC, &date(1, 1), (time(9), time(9))).is_err()
+        );
+        assert!(
+            overlap_business_hours(&Tz::UTC, &Tz::UTC, &date(1, 1), (time(18), time(9))).is_err()
+        );
+    }
+
+    #[test]
+    fn rfc_parse_test() {
+        use crate::date_util::{parse_rfc2822_to_utc, parse_rfc3339_to_tz};
+
+        let seoul = parse_rfc3339_to_tz("2024-11-22T01:29:48.123Z", &Tz::Asia__Seoul).unwrap();
+
+        assert_eq!(Tz::Asia__Seoul, seoul.timezone());
+        assert_eq!("2024-11-22 10:29:48.123", seoul.naive_local().to_string());
+        // DST 적용 여부에 따라 offset 결정
+        assert_eq!(
+            "2024-07-01 12:00:00 EDT",
+            parse_rfc3339_to_tz("2024-07-01T16:00:00+00:00", &Tz::America__New_York)
+                .unwrap()
+                .to_string()
+        );
+        assert!(parse_rfc3339_to_tz("", &Tz::UTC).is_err());
+        assert!(parse_rfc3339_to_tz("2024-02-30T00:00:00Z", &Tz::UTC).is_err());
+
+        assert_eq!(
+            "2024-03-10 07:30:00 UTC",
+            parse_rfc2822_to_utc("Sun, 10 Mar 2024 02:30:00 -0500")
+                .unwrap()
+                .to_string()
+        );
+        // 요일 생략 가능
+        assert!(parse_rfc2822_to_utc("22 Nov 2024 10:29:48 +0900").is_ok());
+        assert!(parse_rfc2822_to_utc("Mon, 22 Nov 2024 10:29:48 +0900").is_err());
+        assert!(parse_rfc2822_to_utc("20241122102948").is_err());
+    }
+
+    #[test]
+    fn add_months_clamped_test() {
+        use crate::date_util::{add_months_clamped, add_years_clamped};
+
+        let utc = |y, m, d| Utc.with_ymd_and_hms(y, m, d, 23, 59, 59).unwrap();
+        let date = |v: DateTime<Utc>| v.format("%Y-%m-%d").to_string();
+
+        for (n, expected) in [
+            (0, "2023-01-31"),
+            (1, "2023-02-28"),
+            (3, "2023-04-30"),
+            (13, "2024-02-29"),
+            (-1, "2022-12-31"),
+            (-11, "2022-02-28"),
+        ] {
+            assert_eq!(
+                expected,
+                date(add_months_clamped(&utc(2023, 1, 31), n).unwrap())
+            );
+        }
+
+        // 보정된 날짜는 이후 계산에 영향을 주지 않음
+        let once = add_months_clamped(&utc(2023, 1, 31), 1).unwrap();
+
+        assert_eq!("2023-03-28", date(add_months_clamped(&once, 1).unwrap()));
+        assert_eq!(
+            "2023-02-28 23:59:59 UTC",
+            add_years_clamped(&utc(2024, 2, 29), -1)
+                .unwrap()
+                .to_string()
+        );
+        assert!(add_months_clamped(&DateTime::<Utc>::MAX_UTC, 1).is_err());
+        assert!(add_years_clamped(&DateTime::<Utc>::MIN_UTC, -1).is_err());
+
+        // DST 시작일의 존재하지 않는 시각은 입력 시각의 offset(EST)으로 해석
+        let new_york = |m, d, h| {
+            Tz::America__New_York
+                .with_ymd_and_hms(2024, m, d, h, 30, 0)
+                .unwrap()
+        };
+
+        assert_eq!(
+            "2024-03-10 03:30:00 EDT",
+            add_months_clamped(&new_york(2, 10, 2), 1)
+                .unwrap()
+                .to_string()
+        );
+        // DST 종료일의 중복 시각은 이른 시각
+        assert_eq!(
+            "2024-11-03 01:30:00 EDT",
+            add_months_clamped(&new_york(10, 3, 1), 1)
+                .unwrap()
+                .to_string()
+        );
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_datetime_test() {
+        use crate::date_util::{par_local_datetime_to_utc, par_utc_datetime_to_local};
+
+        let targets: Vec<String> = (0..24).map(|h| format!("20241122{:02}0000", h)).collect();
+        let pattern = "%Y%m%d%H%M%S";
+        let utc = par_local_datetime_to_utc(&targets, pattern, &Tz::Asia__Seoul);
+
+        assert_eq!(24, utc.len());
+        assert_eq!(
+            "2024-11-21 15:00:00",
+            utc[0]
+                .as_ref()
+                .unwrap()
+                .format("%Y-%m-%d %H:%M:%S")
+                .to_string()
+        );
+
+        let local = par_utc_datetime_to_local(&targets, pattern, &Tz::Asia__Seoul);
+
+        assert_eq!(
+            "2024-11-23 08:00:00",
+            local[23]
+                .as_ref()
+                .unwrap()
+                .format("%Y-%m-%d %H:%M:%S")
+                .to_string()
+        );
+        assert!(par_utc_datetime_to_local(&["x"], pattern, &Tz::UTC)[0].is_err());
     }
 }
@@ -0,0 +1,177 @@
+//! 시간 구간(window) 기준 묶음(bucket) 관련 함수 모음
+//!
+//! 시각 목록을 고정된 길이의 구간으로 묶는다. 로그 집계 등 간단한 시간대별 통계에 사용한다.
+//!
+//! ```rust
+//! use chrono::{TimeDelta, TimeZone, Utc};
+//! use cliff3_util::date_util::bucket::bucket_timestamps;
+//!
+//! let timestamps = [
+//!     Utc.with_ymd_and_hms(2024, 11, 22, 10, 1, 0).unwrap(),
+//!     Utc.with_ymd_and_hms(2024, 11, 22, 10, 14, 59).unwrap(),
+//!     Utc.with_ymd_and_hms(2024, 11, 22, 10, 15, 0).unwrap(),
+//! ];
+//! let buckets = bucket_timestamps(timestamps, TimeDelta::minutes(15)).unwrap();
+//! let counts: Vec<(String, usize)> = buckets
+//!     .iter()
+//!     .map(|(start, items)| (start.format("%H:%M").to_string(), items.len()))
+//!     .collect();
+//!
+//! assert_eq!(vec![("10:00".to_owned(), 2), ("10:15".to_owned(), 1)], counts);
+//! ```
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, DurationRound, TimeDelta, TimeZone};
+
+use crate::error::InvalidArgumentError;
+
+/// 시각 목록을 지정된 길이의 구간으로 묶어 반환
+///
+/// 구간은 해당 timezone의 지역 시각 `1970-01-01 00:00:00`을 기준으로 나누며([crate::date_util::round_to]와
+/// 동일), 각 구간은 시작 시각(포함)부터 다음 구간 시작 시각(제외)까지이다. 구간 내 시각은 입력 순서를 유지한다.
+///
+/// # Arguments
+///
+/// - `timestamps` - 시각 목록
+/// - `window` - 구간 길이(e.g. `TimeDelta::minutes(5)`)
+///
+/// # Return
+///
+/// - 구간 시작 시각 순서의 (구간 시작 시각, 시각 목록) `Result<BTreeMap<DateTime<T>, Vec<DateTime<T>>>, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 구간 길이가 `0` 이하이거나 구간 시작 시각이 범위를 벗어날 경우
+pub fn bucket_timestamps<T, I>(
+    timestamps: I,
+    window: TimeDelta,
+) -> Result<BTreeMap<DateTime<T>, Vec<DateTime<T>>>, InvalidArgumentError>
+where
+    T: TimeZone,
+    I: IntoIterator<Item = DateTime<T>>,
+{
+    bucket_by(timestamps, window, |v| v.clone())
+}
+
+/// 항목 목록을 각 항목의 시각 기준으로 지정된 길이의 구간으로 묶어 반환([bucket_timestamps] 참고)
+///
+/// # Arguments
+///
+/// - `items` - 항목 목록
+/// - `window` - 구간 길이(e.g. `TimeDelta::hours(1)`)
+/// - `timestamp` - 항목의 시각을 반환하는 함수
+///
+/// # Return
+///
+/// - 구간 시작 시각 순서의 (구간 시작 시각, 항목 목록) `Result<BTreeMap<DateTime<T>, Vec<V>>, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 구간 길이가 `0` 이하이거나 구간 시작 시각이 범위를 벗어날 경우
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::{DateTime, TimeDelta};
+/// use chrono_tz::Tz;
+/// use cliff3_util::date_util::bucket::bucket_by;
+///
+/// let logs = [
+///     ("2024-11-22T23:59:00+09:00", "login"),
+///     ("2024-11-23T00:10:00+09:00", "logout"),
+///     ("2024-11-23T08:00:00+09:00", "login"),
+/// ];
+/// // KST 기준 일별 집계
+/// let buckets = bucket_by(logs, TimeDelta::days(1), |(at, _)| {
+///     DateTime::parse_from_rfc3339(at).unwrap().with_timezone(&Tz::Asia__Seoul)
+/// })
+/// .unwrap();
+/// let days: Vec<String> = buckets.keys().map(|v| v.format("%Y-%m-%d").to_string()).collect();
+///
+/// assert_eq!(vec!["2024-11-22", "2024-11-23"], days);
+/// assert_eq!(2, buckets.values().last().unwrap().len());
+/// ```
+pub fn bucket_by<V, T, I, F>(
+    items: I,
+    window: TimeDelta,
+    timestamp: F,
+) -> Result<BTreeMap<DateTime<T>, Vec<V>>, InvalidArgumentError>
+where
+    T: TimeZone,
+    I: IntoIterator<Item = V>,
+    F: Fn(&V) -> DateTime<T>,
+{
+    if window <= TimeDelta::zero() {
+        return Err(InvalidArgumentError::new("구간 길이는 0보다 커야 합니다."));
+    }
+
+    let mut buckets: BTreeMap<DateTime<T>, Vec<V>> = BTreeMap::new();
+
+    for item in items {
+        let start = timestamp(&item)
+            .duration_trunc(window)
+            .map_err(|_| InvalidArgumentError::new("구간 시작 시각이 날짜 범위를 벗어납니다."))?;
+
+        buckets.entry(start).or_default().push(item);
+    }
+
+    Ok(buckets)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use chrono_tz::Tz;
+
+    #[test]
+    fn bucket_timestamps_test() {
+        let utc = |h, m, s| Utc.with_ymd_and_hms(2024, 11, 22, h, m, s).unwrap();
+        let buckets = bucket_timestamps(
+            [utc(10, 9, 0), utc(10, 0, 0), utc(9, 59, 59), utc(10, 4, 59)],
+            TimeDelta::minutes(5),
+        )
+        .unwrap();
+
+        assert_eq!(
+            vec![utc(9, 55, 0), utc(10, 0, 0), utc(10, 5, 0)],
+            buckets.keys().cloned().collect::<Vec<_>>()
+        );
+        // 구간 내 입력 순서 유지
+        assert_eq!(vec![utc(10, 0, 0), utc(10, 4, 59)], buckets[&utc(10, 0, 0)]);
+        assert!(
+            bucket_timestamps(Vec::<DateTime<Utc>>::new(), TimeDelta::hours(1))
+                .unwrap()
+                .is_empty()
+        );
+        assert!(bucket_timestamps([utc(10, 0, 0)], TimeDelta::zero()).is_err());
+        assert!(bucket_timestamps([utc(10, 0, 0)], TimeDelta::seconds(-1)).is_err());
+    }
+
+    #[test]
+    fn local_window_test() {
+        // 지역 시각 기준 일별 구간
+        let kst = |d, h| {
+            Tz::Asia__Seoul
+                .with_ymd_and_hms(2024, 11, d, h, 0, 0)
+                .unwrap()
+        };
+        let buckets = bucket_by(
+            vec![(kst(22, 1), 1), (kst(22, 23), 2), (kst(23, 0), 3)],
+            TimeDelta::days(1),
+            |(at, _)| *at,
+        )
+        .unwrap();
+
+        assert_eq!(2, buckets.len());
+        assert_eq!(
+            vec![1, 2],
+            buckets[&kst(22, 0)]
+                .iter()
+                .map(|(_, v)| *v)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(1, buckets[&kst(23, 0)].len());
+    }
+}
@@ -0,0 +1,1031 @@
+//! 영업일(business day) 관련 함수 모음
+//!
+//! 주말(토요일, 일요일) 및 [HolidayProvider]가 휴일로 판단하는 날을 제외한 날을 영업일로 계산한다.
+//! [HolidaySet::korean] 및 [KoreanHolidays]는 「관공서의 공휴일에 관한 규정」에 따른 한국 공휴일(설날, 추석 및
+//! 대체공휴일 포함)을 제공하며, 다른 국가 혹은 회사 휴일(창립기념일 등)은 [HolidaySet::insert],
+//! [HolidaySet::load_file]로 등록하거나 [HolidayProvider]를 직접 구현하여 사용할 수 있다.
+//!
+//! ```rust
+//! use chrono::NaiveDate;
+//! use cliff3_util::date_util::business::{add_business_days, is_business_day, HolidaySet};
+//!
+//! let holidays = HolidaySet::korean(2025..=2025).unwrap();
+//! let date = NaiveDate::from_ymd_opt(2025, 10, 2).unwrap(); // 목요일
+//!
+//! // 개천절(10/3), 추석 연휴(10/5 ~ 10/7), 대체공휴일(10/8) 및 한글날(10/9)
+//! assert!(!is_business_day(&NaiveDate::from_ymd_opt(2025, 10, 8).unwrap(), &holidays));
+//! assert_eq!("2025-10-10", add_business_days(&date, 1, &holidays).unwrap().to_string());
+//! ```
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::ops::RangeInclusive;
+use std::path::Path;
+use std::sync::OnceLock;
+
+use chrono::{Datelike, Days, NaiveDate, Weekday};
+
+use crate::date_util::lunar::{lunar_to_solar_date, MAX_LUNAR_YEAR, MIN_LUNAR_YEAR};
+use crate::error::InvalidArgumentError;
+
+/// 휴일 판단 기준
+///
+/// [is_business_day], [add_business_days] 등 영업일 계산 함수는 이 trait을 구현한 휴일 기준을 사용한다.
+/// 두 기준의 tuple `(A, B)`는 둘 중 하나라도 휴일로 판단하는 날을 휴일로 판단한다.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::{Datelike, NaiveDate, Weekday};
+/// use cliff3_util::date_util::business::{add_business_days, HolidayProvider, KoreanHolidays};
+///
+/// /// 매월 마지막 금요일 휴무
+/// struct LastFriday;
+///
+/// impl HolidayProvider for LastFriday {
+///     fn holiday_name(&self, date: &NaiveDate) -> Option<&str> {
+///         let next_week = *date + chrono::Days::new(7);
+///
+///         (date.weekday() == Weekday::Fri && next_week.month() != date.month()).then_some("휴무일")
+///     }
+/// }
+///
+/// let calendar = (KoreanHolidays, LastFriday);
+/// let thursday = NaiveDate::from_ymd_opt(2024, 11, 28).unwrap();
+///
+/// assert!(calendar.is_holiday(&NaiveDate::from_ymd_opt(2024, 11, 29).unwrap()));
+/// assert_eq!("2024-12-02", add_business_days(&thursday, 1, &calendar).unwrap().to_string());
+/// ```
+pub trait HolidayProvider {
+    /// 휴일 이름. 휴일이 아닐 경우 `None`
+    fn holiday_name(&self, date: &NaiveDate) -> Option<&str>;
+
+    /// 휴일 여부
+    fn is_holiday(&self, date: &NaiveDate) -> bool {
+        self.holiday_name(date).is_some()
+    }
+
+    /// 주말 여부. 기본값은 토요일, 일요일
+    fn is_weekend(&self, date: &NaiveDate) -> bool {
+        is_weekend(date)
+    }
+}
+
+impl<T: HolidayProvider + ?Sized> HolidayProvider for &T {
+    fn holiday_name(&self, date: &NaiveDate) -> Option<&str> {
+        (**self).holiday_name(date)
+    }
+
+    fn is_holiday(&self, date: &NaiveDate) -> bool {
+        (**self).is_holiday(date)
+    }
+
+    fn is_weekend(&self, date: &NaiveDate) -> bool {
+        (**self).is_weekend(date)
+    }
+}
+
+impl<A: HolidayProvider, B: HolidayProvider> HolidayProvider for (A, B) {
+    fn holiday_name(&self, date: &NaiveDate) -> Option<&str> {
+        self.0
+            .holiday_name(date)
+            .or_else(|| self.1.holiday_name(date))
+    }
+
+    fn is_holiday(&self, date: &NaiveDate) -> bool {
+        self.0.is_holiday(date) || self.1.is_holiday(date)
+    }
+
+    fn is_weekend(&self, date: &NaiveDate) -> bool {
+        self.0.is_weekend(date) || self.1.is_weekend(date)
+    }
+}
+
+/// 한국 공휴일([HolidaySet::korean]) 기준
+///
+/// 지원 범위(1900 ~ 2100년) 전체의 공휴일을 최초 사용시 한번 생성하여 공유하며, 범위를 벗어나는 날짜는
+/// 공휴일로 판단하지 않는다.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use cliff3_util::date_util::business::{HolidayProvider, KoreanHolidays};
+///
+/// assert_eq!(Some("한글날"), KoreanHolidays.holiday_name(&NaiveDate::from_ymd_opt(2024, 10, 9).unwrap()));
+/// assert!(!KoreanHolidays.is_holiday(&NaiveDate::from_ymd_opt(2024, 10, 10).unwrap()));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct KoreanHolidays;
+
+impl KoreanHolidays {
+    /// 지원 범위 전체의 한국 공휴일 목록
+    pub fn holidays(&self) -> &'static HolidaySet {
+        static HOLIDAYS: OnceLock<HolidaySet> = OnceLock::new();
+
+        // 지원 범위 내의 연도이므로 실패하지 않는다
+        HOLIDAYS
+            .get_or_init(|| HolidaySet::korean(MIN_LUNAR_YEAR..=MAX_LUNAR_YEAR).unwrap_or_default())
+    }
+}
+
+impl HolidayProvider for KoreanHolidays {
+    fn holiday_name(&self, date: &NaiveDate) -> Option<&str> {
+        self.holidays().name(date)
+    }
+}
+
+/// 공휴일 목록
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use cliff3_util::date_util::business::HolidaySet;
+///
+/// let mut holidays = HolidaySet::korean(2024..=2024).unwrap();
+/// let founding_day = NaiveDate::from_ymd_opt(2024, 11, 22).unwrap();
+///
+/// assert_eq!(Some("추석"), holidays.name(&NaiveDate::from_ymd_opt(2024, 9, 17).unwrap()));
+///
+/// holidays.insert(founding_day, "창립기념일");
+///
+/// assert!(holidays.contains(&founding_day));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HolidaySet {
+    dates: BTreeMap<NaiveDate, String>,
+}
+
+impl HolidaySet {
+    /// 빈 공휴일 목록 생성
+    pub fn new() -> Self {
+        HolidaySet::default()
+    }
+
+    /// 지정된 연도 범위의 한국 공휴일 목록 생성
+    ///
+    /// 신정, 설날 연휴, 삼일절, 어린이날, 부처님오신날, 현충일, 광복절, 추석 연휴, 개천절, 한글날(2013년
+    /// 이후), 기독탄신일, 식목일(2005년 이전), 제헌절(2007년 이전) 및 대체공휴일을 포함한다. 선거일 및
+    /// 임시공휴일은 포함하지 않는다.
+    ///
+    /// 대체공휴일은 공휴일이 주말 혹은 다른 공휴일과 겹치는 경우 이후의 첫 번째 평일(공휴일 제외)로
+    /// 지정되며 다음과 같이 적용된다.
+    ///
+    /// - 설날, 추석 연휴 - 일요일 혹은 다른 공휴일과 겹칠 경우(2014년 이후)
+    /// - 어린이날 - 토요일, 일요일 혹은 다른 공휴일과 겹칠 경우(2014년 이후)
+    /// - 삼일절, 광복절, 개천절, 한글날 - 2021년 8월 이후 동일
+    /// - 부처님오신날, 기독탄신일 - 2023년 5월 이후 동일
+    ///
+    /// # Arguments
+    ///
+    /// - `years` - 연도 범위(e.g. `2024..=2026`)
+    ///
+    /// # Return
+    ///
+    /// - 생성 결과 `Result<HolidaySet, InvalidArgumentError>`
+    ///
+    /// # Errors
+    ///
+    /// - [InvalidArgumentError] - 지원 범위(1900 ~ 2100년)를 벗어나는 연도
+    pub fn korean(years: RangeInclusive<i32>) -> Result<Self, InvalidArgumentError> {
+        if *years.start() < MIN_LUNAR_YEAR || *years.end() > MAX_LUNAR_YEAR {
+            return Err(InvalidArgumentError::new(
+                "지원하지 않는 연도 입니다(1900 ~ 2100).",
+            ));
+        }
+
+        let mut holidays = HolidaySet::new();
+
+        for year in years {
+            for (date, name) in korean_holidays(year)? {
+                holidays
+                    .dates
+                    .entry(date)
+                    .or_insert_with(|| name.to_owned());
+            }
+        }
+
+        Ok(holidays)
+    }
+
+    /// 공휴일 등록. 이미 등록된 날짜는 이름을 변경한다.
+    pub fn insert(&mut self, date: NaiveDate, name: &str) {
+        self.dates.insert(date, name.to_owned());
+    }
+
+    /// 공휴일 삭제. 등록되어 있었을 경우 `true`
+    pub fn remove(&mut self, date: &NaiveDate) -> bool {
+        self.dates.remove(date).is_some()
+    }
+
+    /// 공휴일 여부
+    pub fn contains(&self, date: &NaiveDate) -> bool {
+        self.dates.contains_key(date)
+    }
+
+    /// 공휴일 이름
+    pub fn name(&self, date: &NaiveDate) -> Option<&str> {
+        self.dates.get(date).map(|v| v.as_str())
+    }
+
+    /// 등록된 공휴일 수
+    pub fn len(&self) -> usize {
+        self.dates.len()
+    }
+
+    /// 등록된 공휴일이 없을 경우 `true`
+    pub fn is_empty(&self) -> bool {
+        self.dates.is_empty()
+    }
+
+    /// 날짜 순서의 (날짜, 이름) iterator
+    pub fn iter(&self) -> impl Iterator<Item = (&NaiveDate, &str)> {
+        self.dates.iter().map(|(k, v)| (k, v.as_str()))
+    }
+
+    /// 문자열로 정의된 공휴일 목록 해석
+    ///
+    /// 한 줄에 하나의 공휴일을 `날짜(%Y-%m-%d) 이름` 형식으로 정의하며, 날짜와 이름은 공백 혹은 `,`로
+    /// 구분한다. 이름을 생략할 경우 `휴일`로 등록되며, 빈 줄과 `#`으로 시작하는 줄은 무시한다.
+    ///
+    /// # Arguments
+    ///
+    /// - `source` - 공휴일 목록 문자열
+    ///
+    /// # Return
+    ///
+    /// - 해석 결과 `Result<HolidaySet, InvalidArgumentError>`
+    ///
+    /// # Errors
+    ///
+    /// - [InvalidArgumentError] - 잘못된 날짜 형식
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use cliff3_util::date_util::business::HolidaySet;
+    ///
+    /// let holidays = HolidaySet::parse("# 회사 휴일\n2024-11-22 창립기념일\n2024-12-31,종무식\n2025-01-02").unwrap();
+    ///
+    /// assert_eq!(3, holidays.len());
+    /// assert_eq!(Some("종무식"), holidays.name(&NaiveDate::from_ymd_opt(2024, 12, 31).unwrap()));
+    /// assert_eq!(Some("휴일"), holidays.name(&NaiveDate::from_ymd_opt(2025, 1, 2).unwrap()));
+    /// assert!(HolidaySet::parse("2024-13-01 잘못된 날짜").is_err());
+    /// ```
+    pub fn parse(source: &str) -> Result<Self, InvalidArgumentError> {
+        let mut holidays = HolidaySet::new();
+
+        for (index, line) in source.lines().enumerate() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (date, name) = line
+                .split_once(|c: char| c.is_whitespace() || c == ',')
+                .map_or((line, ""), |(d, n)| (d, n.trim()));
+            let date = NaiveDate::parse_from_str(date, "%Y-%m-%d").map_err(|_| {
+                InvalidArgumentError::new(&format!(
+                    "잘못된 날짜 형식 입니다({}번째 줄, {}).",
+                    index + 1,
+                    line
+                ))
+            })?;
+
+            holidays.insert(date, if name.is_empty() { "휴일" } else { name });
+        }
+
+        Ok(holidays)
+    }
+
+    /// 파일로 정의된 공휴일 목록 해석([HolidaySet::parse] 참고)
+    ///
+    /// # Arguments
+    ///
+    /// - `path` - 공휴일 목록 파일 경로
+    ///
+    /// # Return
+    ///
+    /// - 해석 결과 `Result<HolidaySet, InvalidArgumentError>`
+    ///
+    /// # Errors
+    ///
+    /// - [InvalidArgumentError] - 파일 읽기 실패 혹은 잘못된 날짜 형식
+    pub fn load_file<P: AsRef<Path>>(path: P) -> Result<Self, InvalidArgumentError> {
+        let path = path.as_ref();
+        let source = fs::read_to_string(path).map_err(|e| {
+            InvalidArgumentError::new(&format!("파일 읽기 실패 입니다({:?}, {}).", path, e))
+        })?;
+
+        HolidaySet::parse(&source)
+    }
+}
+
+impl HolidayProvider for HolidaySet {
+    fn holiday_name(&self, date: &NaiveDate) -> Option<&str> {
+        self.name(date)
+    }
+
+    fn is_holiday(&self, date: &NaiveDate) -> bool {
+        self.contains(date)
+    }
+}
+
+impl Extend<(NaiveDate, String)> for HolidaySet {
+    fn extend<I: IntoIterator<Item = (NaiveDate, String)>>(&mut self, iter: I) {
+        self.dates.extend(iter);
+    }
+}
+
+impl<'a> Extend<(NaiveDate, &'a str)> for HolidaySet {
+    fn extend<I: IntoIterator<Item = (NaiveDate, &'a str)>>(&mut self, iter: I) {
+        self.dates
+            .extend(iter.into_iter().map(|(d, n)| (d, n.to_owned())));
+    }
+}
+
+impl FromIterator<(NaiveDate, String)> for HolidaySet {
+    fn from_iter<I: IntoIterator<Item = (NaiveDate, String)>>(iter: I) -> Self {
+        HolidaySet {
+            dates: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<'a> FromIterator<(NaiveDate, &'a str)> for HolidaySet {
+    fn from_iter<I: IntoIterator<Item = (NaiveDate, &'a str)>>(iter: I) -> Self {
+        let mut holidays = HolidaySet::new();
+
+        holidays.extend(iter);
+        holidays
+    }
+}
+
+/// 대체공휴일 적용 기준
+#[derive(Clone, Copy, PartialEq)]
+enum Substitute {
+    /// 적용 안함
+    None,
+
+    /// 일요일 혹은 다른 공휴일과 겹칠 경우
+    Sunday,
+
+    /// 토요일, 일요일 혹은 다른 공휴일과 겹칠 경우
+    Weekend,
+}
+
+/// 공휴일(연휴는 하나로 취급)
+struct Holiday {
+    name: &'static str,
+    dates: Vec<NaiveDate>,
+    substitute: Substitute,
+}
+
+/// 해당 연도의 한국 공휴일(대체공휴일 포함) 목록
+fn korean_holidays(year: i32) -> Result<Vec<(NaiveDate, &'static str)>, InvalidArgumentError> {
+    let invalid = || InvalidArgumentError::new("공휴일 계산 중 날짜 범위를 벗어났습니다.");
+    let solar =
+        |month: u32, day: u32| NaiveDate::from_ymd_opt(year, month, day).ok_or_else(invalid);
+    let lunar =
+        |month: u32, day: u32| lunar_to_solar_date(year, month, false, day).ok_or_else(invalid);
+    let around = |date: NaiveDate| -> Result<Vec<NaiveDate>, InvalidArgumentError> {
+        Ok(vec![
+            date.pred_opt().ok_or_else(invalid)?,
+            date,
+            date.succ_opt().ok_or_else(invalid)?,
+        ])
+    };
+    let since = |date: NaiveDate, rule: Substitute, from: (i32, u32)| {
+        if (date.year(), date.month()) >= from {
+            rule
+        } else {
+            Substitute::None
+        }
+    };
+    let holiday = |name, dates: Vec<NaiveDate>, substitute| Holiday {
+        name,
+        dates,
+        substitute,
+    };
+
+    let new_year = lunar(1, 1)?;
+    let chuseok = lunar(8, 15)?;
+    let buddha = lunar(4, 8)?;
+    let mut holidays = vec![
+        holiday("신정", vec![solar(1, 1)?], Substitute::None),
+        holiday(
+            "설날",
+            around(new_year)?,
+            since(new_year, Substitute::Sunday, (2014, 1)),
+        ),
+        holiday(
+            "삼일절",
+            vec![solar(3, 1)?],
+            since(solar(3, 1)?, Substitute::Weekend, (2021, 8)),
+        ),
+        holiday(
+            "어린이날",
+            vec![solar(5, 5)?],
+            since(solar(5, 5)?, Substitute::Weekend, (2014, 1)),
+        ),
+        holiday(
+            "부처님오신날",
+            vec![buddha],
+            since(buddha, Substitute::Weekend, (2023, 5)),
+        ),
+        holiday("현충일", vec![solar(6, 6)?], Substitute::None),
+        holiday(
+            "광복절",
+            vec![solar(8, 15)?],
+            since(solar(8, 15)?, Substitute::Weekend, (2021, 8)),
+        ),
+        holiday(
+            "추석",
+            around(chuseok)?,
+            since(chuseok, Substitute::Sunday, (2014, 1)),
+        ),
+        holiday(
+            "개천절",
+            vec![solar(10, 3)?],
+            since(solar(10, 3)?, Substitute::Weekend, (2021, 8)),
+        ),
+        holiday(
+            "기독탄신일",
+            vec![solar(12, 25)?],
+            since(solar(12, 25)?, Substitute::Weekend, (2023, 5)),
+        ),
+    ];
+
+    if year >= 2013 {
+        holidays.push(holiday(
+            "한글날",
+            vec![solar(10, 9)?],
+            since(solar(10, 9)?, Substitute::Weekend, (2021, 8)),
+        ));
+    }
+
+    if year <= 2005 {
+        holidays.push(holiday("식목일", vec![solar(4, 5)?], Substitute::None));
+    }
+
+    if year <= 2007 {
+        holidays.push(holiday("제헌절", vec![solar(7, 17)?], Substitute::None));
+    }
+
+    holidays.sort_by_key(|v| v.dates[0]);
+
+    let mut result: Vec<(NaiveDate, &'static str)> = holidays
+        .iter()
+        .flat_map(|h| h.dates.iter().map(|&d| (d, h.name)))
+        .collect();
+
+    for (i, h) in holidays.iter().enumerate() {
+        if h.substitute == Substitute::None {
+            continue;
+        }
+
+        let on_weekend = h.dates.iter().any(|d| match d.weekday() {
+            Weekday::Sun => true,
+            Weekday::Sat => h.substitute == Substitute::Weekend,
+            _ => false,
+        });
+        // 겹치는 공휴일 중 하나에만 대체공휴일을 지정한다(앞선 공휴일 혹은 대체공휴일 미적용 공휴일과
+        // 겹칠 경우 지정)
+        let overlapped = holidays.iter().enumerate().any(|(j, other)| {
+            j != i
+                && other.dates.iter().any(|d| h.dates.contains(d))
+                && (j < i || other.substitute == Substitute::None)
+        });
+
+        if !on_weekend && !overlapped {
+            continue;
+        }
+
+        let last = h.dates.last().copied().ok_or_else(invalid)?;
+        let mut candidate = last.succ_opt().ok_or_else(invalid)?;
+
+        while is_weekend(&candidate) || result.iter().any(|(d, _)| *d == candidate) {
+            candidate = candidate.succ_opt().ok_or_else(invalid)?;
+        }
+
+        result.push((candidate, "대체공휴일"));
+    }
+
+    Ok(result)
+}
+
+fn is_weekend(date: &NaiveDate) -> bool {
+    matches!(date.weekday(), Weekday::Sat | Weekday::Sun)
+}
+
+/// 영업일 여부
+///
+/// `holidays`의 주말([HolidayProvider::is_weekend]) 및 휴일([HolidayProvider::is_holiday])이 아닐 경우 `true`
+///
+/// # Arguments
+///
+/// - `date` - 대상 날짜
+/// - `holidays` - 휴일 기준 [HolidayProvider]
+pub fn is_business_day<H: HolidayProvider + ?Sized>(date: &NaiveDate, holidays: &H) -> bool {
+    !holidays.is_weekend(date) && !holidays.is_holiday(date)
+}
+
+/// 지정된 영업일 수 이후(음수일 경우 이전)의 영업일 반환
+///
+/// `days`가 `0`일 경우 `date`를 그대로 반환한다. `date`가 영업일이 아닌 경우에도 `date` 이후(이전)의
+/// 영업일부터 계산한다.
+///
+/// # Arguments
+///
+/// - `date` - 기준 날짜
+/// - `days` - 영업일 수
+/// - `holidays` - 휴일 기준 [HolidayProvider]
+///
+/// # Return
+///
+/// - 계산 결과 `Result<NaiveDate, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 계산 결과가 날짜 범위를 벗어날 경우
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use cliff3_util::date_util::business::{add_business_days, HolidaySet};
+///
+/// let holidays = HolidaySet::new();
+/// let friday = NaiveDate::from_ymd_opt(2024, 11, 22).unwrap();
+///
+/// assert_eq!("2024-11-25", add_business_days(&friday, 1, &holidays).unwrap().to_string());
+/// assert_eq!("2024-11-18", add_business_days(&friday, -4, &holidays).unwrap().to_string());
+/// ```
+pub fn add_business_days<H: HolidayProvider + ?Sized>(
+    date: &NaiveDate,
+    days: i64,
+    holidays: &H,
+) -> Result<NaiveDate, InvalidArgumentError> {
+    let step = |v: NaiveDate| {
+        if days > 0 {
+            v.checked_add_days(Days::new(1))
+        } else {
+            v.checked_sub_days(Days::new(1))
+        }
+    };
+    let mut current = *date;
+    let mut remaining = days.unsigned_abs();
+
+    while remaining > 0 {
+        current = step(current)
+            .ok_or_else(|| InvalidArgumentError::new("계산 결과가 날짜 범위를 벗어납니다."))?;
+
+        if is_business_day(&current, holidays) {
+            remaining -= 1;
+        }
+    }
+
+    Ok(current)
+}
+
+/// `date` 이후의 첫 번째 영업일 반환([add_business_days]의 `days`가 `1`인 경우와 동일)
+///
+/// # Arguments
+///
+/// - `date` - 기준 날짜
+/// - `holidays` - 휴일 기준 [HolidayProvider]
+///
+/// # Return
+///
+/// - 계산 결과 `Result<NaiveDate, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 계산 결과가 날짜 범위를 벗어날 경우
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use cliff3_util::date_util::business::{next_business_day, previous_business_day, KoreanHolidays};
+///
+/// let date = NaiveDate::from_ymd_opt(2024, 12, 24).unwrap();
+///
+/// assert_eq!("2024-12-26", next_business_day(&date, &KoreanHolidays).unwrap().to_string());
+/// assert_eq!("2024-12-23", previous_business_day(&date, &KoreanHolidays).unwrap().to_string());
+/// ```
+pub fn next_business_day<H: HolidayProvider + ?Sized>(
+    date: &NaiveDate,
+    holidays: &H,
+) -> Result<NaiveDate, InvalidArgumentError> {
+    add_business_days(date, 1, holidays)
+}
+
+/// `date` 이전의 첫 번째 영업일 반환([add_business_days]의 `days`가 `-1`인 경우와 동일)
+///
+/// # Arguments
+///
+/// - `date` - 기준 날짜
+/// - `holidays` - 휴일 기준 [HolidayProvider]
+///
+/// # Return
+///
+/// - 계산 결과 `Result<NaiveDate, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 계산 결과가 날짜 범위를 벗어날 경우
+pub fn previous_business_day<H: HolidayProvider + ?Sized>(
+    date: &NaiveDate,
+    holidays: &H,
+) -> Result<NaiveDate, InvalidArgumentError> {
+    add_business_days(date, -1, holidays)
+}
+
+/// 영업일이 아닌 날짜의 조정 규칙([adjust_business_day])
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BusinessDayConvention {
+    /// 조정하지 않음
+    Unadjusted,
+
+    /// 이후의 첫 번째 영업일
+    Following,
+
+    /// 이후의 첫 번째 영업일. 다음 달로 넘어갈 경우 이전의 첫 번째 영업일
+    ModifiedFollowing,
+
+    /// 이전의 첫 번째 영업일
+    Preceding,
+
+    /// 이전의 첫 번째 영업일. 이전 달로 넘어갈 경우 이후의 첫 번째 영업일
+    ModifiedPreceding,
+}
+
+/// 영업일이 아닌 날짜를 지정된 규칙으로 영업일로 조정
+///
+/// `date`가 영업일일 경우 그대로 반환한다. 결제일, 만기일 등의 계산에 사용한다.
+///
+/// # Arguments
+///
+/// - `date` - 대상 날짜
+/// - `convention` - 조정 규칙 [BusinessDayConvention]
+/// - `holidays` - 휴일 기준 [HolidayProvider]
+///
+/// # Return
+///
+/// - 조정 결과 `Result<NaiveDate, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 계산 결과가 날짜 범위를 벗어날 경우
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use cliff3_util::date_util::business::{adjust_business_day, BusinessDayConvention, KoreanHolidays};
+///
+/// let date = NaiveDate::from_ymd_opt(2024, 11, 30).unwrap(); // 토요일
+///
+/// assert_eq!("2024-12-02", adjust_business_day(&date, BusinessDayConvention::Following, &KoreanHolidays).unwrap().to_string());
+/// assert_eq!("2024-11-29", adjust_business_day(&date, BusinessDayConvention::ModifiedFollowing, &KoreanHolidays).unwrap().to_string());
+/// ```
+pub fn adjust_business_day<H: HolidayProvider + ?Sized>(
+    date: &NaiveDate,
+    convention: BusinessDayConvention,
+    holidays: &H,
+) -> Result<NaiveDate, InvalidArgumentError> {
+    if convention == BusinessDayConvention::Unadjusted || is_business_day(date, holidays) {
+        return Ok(*date);
+    }
+
+    match convention {
+        BusinessDayConvention::Unadjusted => Ok(*date),
+        BusinessDayConvention::Following => next_business_day(date, holidays),
+        BusinessDayConvention::Preceding => previous_business_day(date, holidays),
+        BusinessDayConvention::ModifiedFollowing => match next_business_day(date, holidays) {
+            Ok(v) if v.month() == date.month() => Ok(v),
+            _ => previous_business_day(date, holidays),
+        },
+        BusinessDayConvention::ModifiedPreceding => match previous_business_day(date, holidays) {
+            Ok(v) if v.month() == date.month() => Ok(v),
+            _ => next_business_day(date, holidays),
+        },
+    }
+}
+
+/// 두 날짜 사이의 영업일 수
+///
+/// `start`(포함)부터 `end`(제외)까지의 영업일 수를 반환하며, `end`가 `start` 이전일 경우 음수를 반환한다.
+///
+/// # Arguments
+///
+/// - `start` - 시작 날짜(포함)
+/// - `end` - 종료 날짜(제외)
+/// - `holidays` - 휴일 기준 [HolidayProvider]
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use cliff3_util::date_util::business::{business_days_between, HolidaySet};
+///
+/// let holidays = HolidaySet::korean(2024..=2024).unwrap();
+/// let start = NaiveDate::from_ymd_opt(2024, 9, 1).unwrap();
+/// let end = NaiveDate::from_ymd_opt(2024, 10, 1).unwrap();
+///
+/// // 9월 평일 21일 중 추석 연휴(9/16 ~ 9/18) 제외
+/// assert_eq!(18, business_days_between(&start, &end, &holidays));
+/// assert_eq!(-18, business_days_between(&end, &start, &holidays));
+/// ```
+pub fn business_days_between<H: HolidayProvider + ?Sized>(
+    start: &NaiveDate,
+    end: &NaiveDate,
+    holidays: &H,
+) -> i64 {
+    if end < start {
+        return -business_days_between(end, start, holidays);
+    }
+
+    start
+        .iter_days()
+        .take_while(|d| d < end)
+        .filter(|d| is_business_day(d, holidays))
+        .count() as i64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::LibError;
+
+    fn dates(holidays: &HolidaySet, year: i32) -> Vec<String> {
+        holidays
+            .iter()
+            .filter(|(d, _)| d.year() == year)
+            .map(|(d, _)| d.format("%m-%d").to_string())
+            .collect()
+    }
+
+    #[test]
+    fn korean_holidays_test() {
+        let holidays = HolidaySet::korean(2014..=2026).unwrap();
+
+        assert_eq!(
+            vec![
+                "01-01", "02-09", "02-10", "02-11", "02-12", "03-01", "05-05", "05-06", "05-15",
+                "06-06", "08-15", "09-16", "09-17", "09-18", "10-03", "10-09", "12-25"
+            ],
+            dates(&holidays, 2024)
+        );
+        // 어린이날과 부처님오신날이 겹치는 경우 대체공휴일 1일
+        assert_eq!(
+            vec![
+                "01-01", "01-28", "01-29", "01-30", "03-01", "03-03", "05-05", "05-06", "06-06",
+                "08-15", "10-03", "10-05", "10-06", "10-07", "10-08", "10-09", "12-25"
+            ],
+            dates(&holidays, 2025)
+        );
+
+        let substitutes = |year| -> Vec<String> {
+            holidays
+                .iter()
+                .filter(|(d, name)| d.year() == year && *name == "대체공휴일")
+                .map(|(d, _)| d.format("%m-%d").to_string())
+                .collect()
+        };
+
+        assert_eq!(vec!["09-10"], substitutes(2014));
+        // 추석과 개천절이 겹치는 경우
+        assert_eq!(vec!["01-30", "10-06"], substitutes(2017));
+        assert_eq!(vec!["08-16", "10-04", "10-11"], substitutes(2021));
+        assert_eq!(vec!["01-24", "05-29"], substitutes(2023));
+        assert_eq!(vec!["03-02", "05-25", "08-17", "10-05"], substitutes(2026));
+        assert_eq!(
+            Some("추석"),
+            holidays.name(&NaiveDate::from_ymd_opt(2025, 10, 6).unwrap())
+        );
+    }
+
+    #[test]
+    fn historical_holidays_test() {
+        let holidays = HolidaySet::korean(2005..=2012).unwrap();
+        let contains = |y, m, d| holidays.contains(&NaiveDate::from_ymd_opt(y, m, d).unwrap());
+
+        assert!(contains(2005, 4, 5));
+        assert!(!contains(2006, 4, 5));
+        assert!(contains(2007, 7, 17));
+        assert!(!contains(2008, 7, 17));
+        assert!(!contains(2012, 10, 9));
+        assert!(HolidaySet::korean(1899..=1900).is_err());
+        assert!(HolidaySet::korean(2100..=2101).is_err());
+    }
+
+    #[test]
+    fn business_day_test() {
+        let mut holidays = HolidaySet::korean(2024..=2025).unwrap();
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        assert!(is_business_day(&date(2024, 11, 22), &holidays));
+        assert!(!is_business_day(&date(2024, 11, 23), &holidays));
+        assert!(!is_business_day(&date(2024, 12, 25), &holidays));
+
+        // 2024-12-24(화) + 1 영업일 = 12-26(목)
+        assert_eq!(
+            date(2024, 12, 26),
+            add_business_days(&date(2024, 12, 24), 1, &holidays).unwrap()
+        );
+        // 2025-01-27(월) 기준 이전 영업일
+        assert_eq!(
+            date(2025, 1, 24),
+            add_business_days(&date(2025, 1, 27), -1, &holidays).unwrap()
+        );
+        assert_eq!(
+            date(2024, 11, 23),
+            add_business_days(&date(2024, 11, 23), 0, &holidays).unwrap()
+        );
+        assert!(add_business_days(&NaiveDate::MAX, 1, &holidays).is_err());
+
+        holidays.insert(date(2025, 1, 27), "임시공휴일");
+
+        assert_eq!(
+            date(2025, 1, 31),
+            add_business_days(&date(2025, 1, 24), 1, &holidays).unwrap()
+        );
+        assert_eq!(
+            0,
+            business_days_between(&date(2025, 1, 25), &date(2025, 1, 31), &holidays)
+        );
+        assert_eq!(
+            1,
+            business_days_between(&date(2025, 1, 24), &date(2025, 1, 31), &holidays)
+        );
+        assert!(holidays.remove(&date(2025, 1, 27)));
+        assert_eq!(
+            1,
+            business_days_between(&date(2025, 1, 25), &date(2025, 1, 31), &holidays)
+        );
+
+        let custom: HolidaySet = vec![(date(2024, 11, 22), "창립기념일".to_owned())]
+            .into_iter()
+            .collect();
+
+        assert!(!is_business_day(&date(2024, 11, 22), &custom));
+        assert_eq!(1, custom.len());
+    }
+
+    #[test]
+    fn holiday_provider_test() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        assert_eq!(
+            HolidaySet::korean(2025..=2025).unwrap().len(),
+            KoreanHolidays
+                .holidays()
+                .iter()
+                .filter(|(d, _)| d.year() == 2025)
+                .count()
+        );
+        assert_eq!(
+            Some("설날"),
+            KoreanHolidays.holiday_name(&date(1950, 2, 17))
+        );
+        assert_eq!(
+            Some("기독탄신일"),
+            KoreanHolidays.holiday_name(&date(2100, 12, 25))
+        );
+        assert!(!KoreanHolidays.is_holiday(&date(1899, 12, 25)));
+        assert!(KoreanHolidays.is_weekend(&date(2024, 11, 23)));
+
+        let company: HolidaySet = [(date(2024, 11, 22), "창립기념일")].into_iter().collect();
+        let calendar = (KoreanHolidays, &company);
+
+        assert_eq!(
+            Some("창립기념일"),
+            calendar.holiday_name(&date(2024, 11, 22))
+        );
+        assert_eq!(
+            Some("기독탄신일"),
+            calendar.holiday_name(&date(2024, 12, 25))
+        );
+        assert!(!is_business_day(&date(2024, 11, 22), &calendar));
+        assert_eq!(
+            date(2024, 11, 25),
+            add_business_days(&date(2024, 11, 21), 1, &calendar).unwrap()
+        );
+        assert_eq!(
+            4,
+            business_days_between(&date(2024, 11, 18), &date(2024, 11, 25), &calendar)
+        );
+
+        // 금요일, 토요일 주말
+        struct FriSat;
+
+        impl HolidayProvider for FriSat {
+            fn holiday_name(&self, _: &NaiveDate) -> Option<&str> {
+                None
+            }
+
+            fn is_weekend(&self, date: &NaiveDate) -> bool {
+                matches!(date.weekday(), Weekday::Fri | Weekday::Sat)
+            }
+        }
+
+        assert!(!is_business_day(&date(2024, 11, 22), &FriSat));
+        assert!(is_business_day(&date(2024, 11, 24), &FriSat));
+        assert_eq!(
+            date(2024, 11, 24),
+            add_business_days(&date(2024, 11, 21), 1, &FriSat).unwrap()
+        );
+    }
+
+    #[test]
+    fn adjust_business_day_test() {
+        use BusinessDayConvention::*;
+
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let adjust = |d, c| adjust_business_day(&d, c, &KoreanHolidays).unwrap();
+
+        // 2025-01-28 ~ 2025-01-30 설날 연휴
+        assert_eq!(
+            date(2025, 1, 31),
+            next_business_day(&date(2025, 1, 27), &KoreanHolidays).unwrap()
+        );
+        assert_eq!(
+            date(2025, 1, 27),
+            previous_business_day(&date(2025, 1, 31), &KoreanHolidays).unwrap()
+        );
+        // 영업일은 다음 영업일로 이동
+        assert_eq!(
+            date(2024, 11, 25),
+            next_business_day(&date(2024, 11, 22), &KoreanHolidays).unwrap()
+        );
+
+        for convention in [
+            Unadjusted,
+            Following,
+            ModifiedFollowing,
+            Preceding,
+            ModifiedPreceding,
+        ] {
+            assert_eq!(date(2024, 11, 22), adjust(date(2024, 11, 22), convention));
+        }
+
+        assert_eq!(date(2025, 1, 28), adjust(date(2025, 1, 28), Unadjusted));
+        assert_eq!(date(2025, 1, 31), adjust(date(2025, 1, 28), Following));
+        assert_eq!(date(2025, 1, 27), adjust(date(2025, 1, 28), Preceding));
+        // 2025-08-31(일) 이후 영업일은 9월
+        assert_eq!(date(2025, 9, 1), adjust(date(2025, 8, 31), Following));
+        assert_eq!(
+            date(2025, 8, 29),
+            adjust(date(2025, 8, 31), ModifiedFollowing)
+        );
+        // 2025-03-01(토, 삼일절) 이전 영업일은 2월
+        assert_eq!(date(2025, 2, 28), adjust(date(2025, 3, 1), Preceding));
+        assert_eq!(
+            date(2025, 3, 4),
+            adjust(date(2025, 3, 1), ModifiedPreceding)
+        );
+        assert!(next_business_day(&NaiveDate::MAX, &KoreanHolidays).is_err());
+    }
+
+    #[test]
+    fn parse_holidays_test() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let holidays = HolidaySet::parse(
+            "# 회사 휴일\n\n  2024-11-22   창립 기념일  \n2024-12-31,종무식\n2025-01-02\n",
+        )
+        .unwrap();
+
+        assert_eq!(3, holidays.len());
+        assert_eq!(Some("창립 기념일"), holidays.name(&date(2024, 11, 22)));
+        assert_eq!(Some("종무식"), holidays.name(&date(2024, 12, 31)));
+        assert_eq!(Some("휴일"), holidays.name(&date(2025, 1, 2)));
+        assert!(HolidaySet::parse("").unwrap().is_empty());
+
+        let err = HolidaySet::parse("2024-11-22\n2024/12/31 종무식").unwrap_err();
+
+        assert!(err.get_message().contains("2번째 줄"));
+
+        let path = std::env::temp_dir().join("cliff3_util_holidays_test.txt");
+
+        fs::write(&path, "2024-11-22 창립기념일\n").unwrap();
+
+        let loaded = HolidaySet::load_file(&path).unwrap();
+
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(Some("창립기념일"), loaded.name(&date(2024, 11, 22)));
+        assert!(HolidaySet::load_file(&path).is_err());
+
+        let mut extended = HolidaySet::new();
+
+        extended.extend([(date(2024, 11, 22), "창립기념일")]);
+
+        assert!(extended.contains(&date(2024, 11, 22)));
+    }
+}
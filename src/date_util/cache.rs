@@ -0,0 +1,289 @@
+//! 날짜 및 시간 패턴, timezone 조회 결과 전역 cache
+//!
+//! [crate::date_util]의 문자열 변환 함수는 해석(compile)된 날짜 및 시간 패턴을 전역 cache에 보관하여 동일한
+//! 패턴을 반복하여 해석하지 않는다. timezone 이름은 [timezone]으로 조회하면 cache에 보관된다.
+//!
+//! 최초 요청의 지연 시간이 중요한 경우 [warm_up]으로 자주 사용하는 패턴과 timezone을 미리 cache에
+//! 등록할 수 있으며, [stats]로 cache 적중(hit) 및 실패(miss) 횟수를 확인할 수 있다.
+//!
+//! 이 crate의 문자열 검사 함수는 정규식을 사용하지 않으므로 정규식 cache는 제공하지 않는다.
+//!
+//! ```rust
+//! use cliff3_util::date_util::cache::{stats, timezone, warm_up};
+//! use cliff3_util::date_util::local_datetime_to_utc;
+//!
+//! warm_up();
+//!
+//! let seoul = timezone("Asia/Seoul").unwrap();
+//! let utc = local_datetime_to_utc("2024-11-22 10:29:48", "%Y-%m-%d %H:%M:%S", &seoul).unwrap();
+//!
+//! assert_eq!("2024-11-22 01:29:48", utc.format("%Y-%m-%d %H:%M:%S").to_string());
+//! assert!(stats().pattern_hits > 0);
+//! ```
+
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, OnceLock, PoisonError, RwLock};
+
+use chrono::format::{self, Item, ParseError, Parsed, StrftimeItems};
+use chrono::NaiveDateTime;
+use chrono_tz::Tz;
+
+use crate::error::InvalidArgumentError;
+
+/// cache에 보관하는 최대 패턴 수. 초과한 패턴은 보관하지 않고 매번 해석한다.
+pub const PATTERN_CAPACITY: usize = 256;
+
+/// [warm_up]에서 등록하는 날짜 및 시간 패턴
+const WARM_UP_PATTERNS: &[&str] = &[
+    "%Y-%m-%d %H:%M:%S",
+    "%Y-%m-%d %H:%M:%S%.3f",
+    "%Y-%m-%d %H:%M",
+    "%Y-%m-%dT%H:%M:%S",
+    "%Y%m%d%H%M%S",
+];
+
+/// [warm_up]에서 등록하는 timezone
+const WARM_UP_TIMEZONES: &[Tz] = &[
+    Tz::UTC,
+    Tz::Asia__Seoul,
+    Tz::Asia__Tokyo,
+    Tz::Asia__Shanghai,
+    Tz::America__New_York,
+    Tz::America__Los_Angeles,
+    Tz::Europe__London,
+    Tz::Europe__Paris,
+];
+
+/// cache 통계
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CacheStats {
+    /// 보관중인 패턴 수
+    pub patterns: usize,
+
+    /// 패턴 cache 적중 횟수
+    pub pattern_hits: u64,
+
+    /// 패턴 cache 실패 횟수
+    pub pattern_misses: u64,
+
+    /// 보관중인 timezone 수
+    pub timezones: usize,
+
+    /// timezone cache 적중 횟수
+    pub timezone_hits: u64,
+
+    /// timezone cache 실패 횟수
+    pub timezone_misses: u64,
+}
+
+/// 문자열 key 기반 전역 cache
+struct Cache<V> {
+    entries: RwLock<HashMap<String, V>>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl<V: Clone> Cache<V> {
+    fn new() -> Self {
+        Cache {
+            entries: RwLock::new(HashMap::new()),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    /// `key`에 해당하는 값 반환. 없을 경우 `init`으로 생성하여 `capacity` 이내일 경우 보관한다.
+    fn get_or_insert<E>(
+        &self,
+        key: &str,
+        capacity: usize,
+        init: impl FnOnce() -> Result<V, E>,
+    ) -> Result<V, E> {
+        // 다른 thread의 panic으로 lock이 오염되어도 cache 값 자체는 유효하다
+        let cached = self
+            .entries
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(key)
+            .cloned();
+
+        if let Some(v) = cached {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+
+            return Ok(v);
+        }
+
+        self.misses.fetch_add(1, Ordering::Relaxed);
+
+        let value = init()?;
+        let mut entries = self.entries.write().unwrap_or_else(PoisonError::into_inner);
+
+        if entries.len() < capacity {
+            entries.insert(key.to_owned(), value.clone());
+        }
+
+        Ok(value)
+    }
+
+    fn len(&self) -> usize {
+        self.entries
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .len()
+    }
+}
+
+fn patterns() -> &'static Cache<Arc<[Item<'static>]>> {
+    static PATTERNS: OnceLock<Cache<Arc<[Item<'static>]>>> = OnceLock::new();
+
+    PATTERNS.get_or_init(Cache::new)
+}
+
+fn timezones() -> &'static Cache<Tz> {
+    static TIMEZONES: OnceLock<Cache<Tz>> = OnceLock::new();
+
+    TIMEZONES.get_or_init(Cache::new)
+}
+
+/// 해석된 패턴 반환
+pub(crate) fn compiled_pattern(pattern: &str) -> Result<Arc<[Item<'static>]>, ParseError> {
+    patterns().get_or_insert(pattern, PATTERN_CAPACITY, || {
+        StrftimeItems::new(pattern).parse_to_owned().map(Arc::from)
+    })
+}
+
+/// cache된 패턴으로 날짜 및 시간 문자열 해석([NaiveDateTime::parse_from_str]과 동일)
+pub(crate) fn parse_datetime(datetime: &str, pattern: &str) -> Result<NaiveDateTime, ParseError> {
+    parse_datetime_with(datetime, &compiled_pattern(pattern)?)
+}
+
+/// 해석된 패턴([compiled_pattern])으로 날짜 및 시간 문자열 해석
+pub(crate) fn parse_datetime_with(
+    datetime: &str,
+    items: &[Item<'static>],
+) -> Result<NaiveDateTime, ParseError> {
+    let mut parsed = Parsed::new();
+
+    format::parse(&mut parsed, datetime, items.iter())?;
+    parsed.to_naive_datetime_with_offset(0)
+}
+
+/// timezone 이름(e.g. `Asia/Seoul`)으로 [Tz] 조회
+///
+/// 조회 결과는 전역 cache에 보관된다.
+///
+/// # Arguments
+///
+/// - `name` - IANA timezone 이름
+///
+/// # Return
+///
+/// - 조회 결과 `Result<Tz, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 알 수 없는 timezone
+///
+/// # Example
+///
+/// ```rust
+/// use chrono_tz::Tz;
+/// use cliff3_util::date_util::cache::timezone;
+///
+/// assert_eq!(Tz::Asia__Seoul, timezone("Asia/Seoul").unwrap());
+/// assert!(timezone("Mars/Olympus").is_err());
+/// ```
+pub fn timezone(name: &str) -> Result<Tz, InvalidArgumentError> {
+    timezones().get_or_insert(name, usize::MAX, || {
+        Tz::from_str(name).map_err(|_| InvalidArgumentError::from("알 수 없는 timezone 입니다."))
+    })
+}
+
+/// 자주 사용하는 날짜 및 시간 패턴과 timezone을 cache에 등록
+///
+/// 여러번 호출하여도 이미 등록된 항목은 다시 해석하지 않는다.
+pub fn warm_up() {
+    for pattern in WARM_UP_PATTERNS {
+        let _ = compiled_pattern(pattern);
+    }
+
+    for tz in WARM_UP_TIMEZONES {
+        let _ = timezone(tz.name());
+    }
+}
+
+/// 현재 cache 통계 반환
+pub fn stats() -> CacheStats {
+    let patterns = patterns();
+    let timezones = timezones();
+
+    CacheStats {
+        patterns: patterns.len(),
+        pattern_hits: patterns.hits.load(Ordering::Relaxed),
+        pattern_misses: patterns.misses.load(Ordering::Relaxed),
+        timezones: timezones.len(),
+        timezone_hits: timezones.hits.load(Ordering::Relaxed),
+        timezone_misses: timezones.misses.load(Ordering::Relaxed),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn warm_up_test() {
+        warm_up();
+
+        let before = stats();
+
+        assert!(before.patterns >= WARM_UP_PATTERNS.len());
+        assert!(before.timezones >= WARM_UP_TIMEZONES.len());
+
+        warm_up();
+
+        let after = stats();
+
+        assert!(after.pattern_hits >= before.pattern_hits + WARM_UP_PATTERNS.len() as u64);
+        assert!(after.timezone_hits >= before.timezone_hits + WARM_UP_TIMEZONES.len() as u64);
+    }
+
+    #[test]
+    fn parse_datetime_test() {
+        // 다른 테스트와 겹치지 않는 패턴
+        let pattern = "%Y/%m/%d %H-%M-%S";
+        let expected = NaiveDateTime::parse_from_str("2024/11/22 10-29-48", pattern).unwrap();
+
+        assert_eq!(
+            expected,
+            parse_datetime("2024/11/22 10-29-48", pattern).unwrap()
+        );
+
+        let before = stats();
+
+        assert_eq!(
+            expected,
+            parse_datetime("2024/11/22 10-29-48", pattern).unwrap()
+        );
+        assert!(stats().pattern_hits > before.pattern_hits);
+        assert_eq!(
+            NaiveDateTime::parse_from_str("2024/13/22 10-29-48", pattern).unwrap_err(),
+            parse_datetime("2024/13/22 10-29-48", pattern).unwrap_err()
+        );
+
+        // 잘못된 패턴은 보관하지 않는다
+        assert!(parse_datetime("2024", "%Q").is_err());
+        assert!(parse_datetime("2024", "%Q").is_err());
+    }
+
+    #[test]
+    fn timezone_test() {
+        assert_eq!(Tz::Europe__Berlin, timezone("Europe/Berlin").unwrap());
+        assert_eq!(Tz::Europe__Berlin, timezone("Europe/Berlin").unwrap());
+        assert!(timezone("Mars/Olympus").is_err());
+        assert!(stats().timezone_hits > 0);
+    }
+}
@@ -0,0 +1,587 @@
+//! cron 표현식 관련 함수 모음
+//!
+//! 5자리(`분 시 일 월 요일`) 혹은 6자리(`초 분 시 일 월 요일`) cron 표현식을 해석하여 다음 실행 시각을
+//! 계산한다. 각 항목은 `*`, 숫자, 범위(`1-5`), 간격(`*/15`, `10-30/5`) 및 목록(`1,15,30`)을 지원한다.
+//!
+//! 일(day of month)과 요일(day of week)이 모두 `*`가 아닐 경우 표준 cron과 동일하게 둘 중 하나라도
+//! 일치하면 실행 대상으로 판단한다.
+
+use crate::error::InvalidArgumentError;
+#[cfg(feature = "serde")]
+use crate::error::LibError;
+use chrono::{
+    DateTime, Datelike, Duration, LocalResult, NaiveDate, NaiveDateTime, NaiveTime, TimeZone,
+    Timelike,
+};
+use chrono_tz::Tz;
+use std::str::FromStr;
+
+/// 다음 실행 시각 검색 최대 기간(년). 윤년 2월 29일(최대 8년 간격) 검색을 위해 8년으로 지정
+const SEARCH_YEARS: i32 = 8;
+
+/// cron 항목별 허용 범위
+#[derive(Debug, Clone, Copy)]
+struct FieldRange {
+    name: &'static str,
+    min: u32,
+    max: u32,
+}
+
+const SECOND: FieldRange = FieldRange {
+    name: "초",
+    min: 0,
+    max: 59,
+};
+
+const MINUTE: FieldRange = FieldRange {
+    name: "분",
+    min: 0,
+    max: 59,
+};
+
+const HOUR: FieldRange = FieldRange {
+    name: "시",
+    min: 0,
+    max: 23,
+};
+
+const DAY_OF_MONTH: FieldRange = FieldRange {
+    name: "일",
+    min: 1,
+    max: 31,
+};
+
+const MONTH: FieldRange = FieldRange {
+    name: "월",
+    min: 1,
+    max: 12,
+};
+
+// 일요일은 0 혹은 7
+const DAY_OF_WEEK: FieldRange = FieldRange {
+    name: "요일",
+    min: 0,
+    max: 7,
+};
+
+/// 해석된 cron 표현식
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use cliff3_util::date_util::cron::CronSchedule;
+///
+/// // 평일 09:30
+/// let schedule = CronSchedule::parse("30 9 * * 1-5").unwrap();
+/// // 2024-11-22(금) 10:00
+/// let after = Utc.with_ymd_and_hms(2024, 11, 22, 10, 0, 0).unwrap();
+/// let next = schedule.next_after(&after).unwrap();
+///
+/// // 2024-11-25(월) 09:30
+/// assert_eq!(Utc.with_ymd_and_hms(2024, 11, 25, 9, 30, 0).unwrap(), next);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CronSchedule {
+    /// 원본 표현식
+    expression: String,
+
+    seconds: u64,
+
+    minutes: u64,
+
+    hours: u64,
+
+    days_of_month: u64,
+
+    months: u64,
+
+    days_of_week: u64,
+
+    /// 일 항목 `*` 여부
+    any_day_of_month: bool,
+
+    /// 요일 항목 `*` 여부
+    any_day_of_week: bool,
+}
+
+impl CronSchedule {
+    /// cron 표현식 해석
+    ///
+    /// 5자리 표현식은 초를 `0`으로 처리한다.
+    ///
+    /// # Arguments
+    ///
+    /// - `expression` - cron 표현식 (e.g. `*/5 * * * *`, `0 0 9 * * 1-5`)
+    ///
+    /// # Return
+    ///
+    /// - 해석 결과 `Result<CronSchedule, InvalidArgumentError>`
+    ///
+    /// # Errors
+    ///
+    /// - [InvalidArgumentError] - 항목 개수 오류, 허용 범위를 벗어난 값 혹은 잘못된 형식
+    pub fn parse(expression: &str) -> Result<CronSchedule, InvalidArgumentError> {
+        let fields: Vec<&str> = expression.split_whitespace().collect();
+        let (second, rest) = match fields.len() {
+            5 => ("0", &fields[..]),
+            6 => (fields[0], &fields[1..]),
+            _ => {
+                return Err(InvalidArgumentError::from(
+                    "cron 표현식은 5개 혹은 6개 항목으로 구성되어야 합니다.",
+                ))
+            }
+        };
+
+        let mut days_of_week = parse_field(rest[4], &DAY_OF_WEEK)?;
+
+        // 7(일요일)은 0으로 처리
+        if days_of_week & (1 << 7) != 0 {
+            days_of_week = (days_of_week & !(1 << 7)) | 1;
+        }
+
+        Ok(CronSchedule {
+            expression: fields.join(" "),
+            seconds: parse_field(second, &SECOND)?,
+            minutes: parse_field(rest[0], &MINUTE)?,
+            hours: parse_field(rest[1], &HOUR)?,
+            days_of_month: parse_field(rest[2], &DAY_OF_MONTH)?,
+            months: parse_field(rest[3], &MONTH)?,
+            days_of_week,
+            any_day_of_month: rest[2] == "*",
+            any_day_of_week: rest[4] == "*",
+        })
+    }
+
+    /// 원본 표현식 반환
+    #[inline]
+    pub fn expression(&self) -> &str {
+        self.expression.as_str()
+    }
+
+    /// 지정된 시각 이후(지정된 시각 미포함)의 다음 실행 시각 반환
+    ///
+    /// 실행 시각은 `after`의 timezone 기준으로 계산한다. 일광 절약 시간(DST) 전환으로 존재하지 않는
+    /// 시각은 건너뛰며, 중복되는 시각은 이른 시각을 사용한다.
+    ///
+    /// # Arguments
+    ///
+    /// - `after` - 기준 시각
+    ///
+    /// # Return
+    ///
+    /// - 다음 실행 시각. 8년 이내에 실행 시각이 없을 경우(e.g. `0 0 31 2 *`) `None`
+    pub fn next_after<T: TimeZone>(&self, after: &DateTime<T>) -> Option<DateTime<T>> {
+        let timezone = after.timezone();
+        let start = after
+            .naive_local()
+            .with_nanosecond(0)?
+            .checked_add_signed(Duration::seconds(1))?;
+        let limit = start.year() + SEARCH_YEARS;
+        let mut current = start;
+
+        while current.year() <= limit {
+            if !contains(self.months, current.month()) {
+                current = first_day_of_next_month(&current.date())?.and_time(NaiveTime::MIN);
+                continue;
+            }
+
+            if !self.matches_day(&current.date()) {
+                current = current.date().succ_opt()?.and_time(NaiveTime::MIN);
+                continue;
+            }
+
+            if !contains(self.hours, current.hour()) {
+                current = truncate(&current, 3600)?.checked_add_signed(Duration::hours(1))?;
+                continue;
+            }
+
+            if !contains(self.minutes, current.minute()) {
+                current = truncate(&current, 60)?.checked_add_signed(Duration::minutes(1))?;
+                continue;
+            }
+
+            match next_bit(self.seconds, current.second()) {
+                Some(second) => current = current.with_second(second)?,
+                None => {
+                    current = truncate(&current, 60)?.checked_add_signed(Duration::minutes(1))?;
+                    continue;
+                }
+            }
+
+            match timezone.from_local_datetime(&current) {
+                LocalResult::Single(v) => return Some(v),
+                LocalResult::Ambiguous(earliest, latest) => {
+                    if earliest > *after {
+                        return Some(earliest);
+                    }
+
+                    if latest > *after {
+                        return Some(latest);
+                    }
+                }
+                LocalResult::None => {}
+            }
+
+            current = current.checked_add_signed(Duration::seconds(1))?;
+        }
+
+        None
+    }
+
+    /// 지정된 시각 이후의 실행 시각을 순서대로 반환하는 iterator 생성
+    ///
+    /// 각 실행 시각은 [CronSchedule::next_after]로 계산하며, 다음 실행 시각이 없을 경우 종료된다.
+    ///
+    /// # Arguments
+    ///
+    /// - `after` - 기준 시각(미포함)
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use cliff3_util::date_util::cron::CronSchedule;
+    ///
+    /// let schedule = CronSchedule::parse("0 */6 * * *").unwrap();
+    /// let after = Utc.with_ymd_and_hms(2024, 11, 22, 10, 0, 0).unwrap();
+    /// let upcoming: Vec<String> = schedule
+    ///     .upcoming(&after)
+    ///     .take(3)
+    ///     .map(|v| v.format("%d %H:%M").to_string())
+    ///     .collect();
+    ///
+    /// assert_eq!(vec!["22 12:00", "22 18:00", "23 00:00"], upcoming);
+    /// ```
+    pub fn upcoming<T: TimeZone>(&self, after: &DateTime<T>) -> CronIter<'_, T> {
+        CronIter {
+            schedule: self,
+            current: Some(after.clone()),
+        }
+    }
+
+    /// 지정된 timezone 기준 실행 시각 iterator 생성
+    ///
+    /// `after`를 `timezone`의 시각으로 변환한 후 [CronSchedule::upcoming]과 동일하게 동작한다.
+    ///
+    /// # Arguments
+    ///
+    /// - `after` - 기준 시각(미포함)
+    /// - `timezone` - 실행 시각 계산 기준 timezone [Tz]
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use chrono_tz::Tz;
+    /// use cliff3_util::date_util::cron::CronSchedule;
+    ///
+    /// // 매일 09:00(KST)
+    /// let schedule = CronSchedule::parse("0 9 * * *").unwrap();
+    /// let after = Utc.with_ymd_and_hms(2024, 11, 22, 1, 0, 0).unwrap();
+    /// let next = schedule.upcoming_in(&after, Tz::Asia__Seoul).next().unwrap();
+    ///
+    /// assert_eq!("2024-11-23 09:00:00 KST", next.to_string());
+    /// assert_eq!(Utc.with_ymd_and_hms(2024, 11, 23, 0, 0, 0).unwrap(), next);
+    /// ```
+    pub fn upcoming_in<T: TimeZone>(&self, after: &DateTime<T>, timezone: Tz) -> CronIter<'_, Tz> {
+        CronIter {
+            schedule: self,
+            current: Some(after.with_timezone(&timezone)),
+        }
+    }
+
+    fn matches_day(&self, date: &NaiveDate) -> bool {
+        let day_of_month = contains(self.days_of_month, date.day());
+        let day_of_week = contains(self.days_of_week, date.weekday().num_days_from_sunday());
+
+        match (self.any_day_of_month, self.any_day_of_week) {
+            (false, false) => day_of_month || day_of_week,
+            _ => day_of_month && day_of_week,
+        }
+    }
+}
+
+impl FromStr for CronSchedule {
+    type Err = InvalidArgumentError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        CronSchedule::parse(s)
+    }
+}
+
+/// [CronSchedule::upcoming] 및 [CronSchedule::upcoming_in]이 반환하는 iterator
+#[derive(Debug, Clone)]
+pub struct CronIter<'a, T: TimeZone> {
+    schedule: &'a CronSchedule,
+    current: Option<DateTime<T>>,
+}
+
+impl<T: TimeZone> Iterator for CronIter<'_, T> {
+    type Item = DateTime<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = self.schedule.next_after(self.current.as_ref()?);
+
+        self.current = next.clone();
+
+        next
+    }
+}
+
+/// cron 항목 하나를 bit mask로 변환
+fn parse_field(field: &str, range: &FieldRange) -> Result<u64, InvalidArgumentError> {
+    let mut mask: u64 = 0;
+
+    for item in field.split(',') {
+        let (body, step) = match item.split_once('/') {
+            Some((b, s)) => (b, Some(parse_number(s, range)?)),
+            None => (item, None),
+        };
+
+        if step == Some(0) {
+            return Err(field_error(range, item));
+        }
+
+        let (from, to) = if body == "*" {
+            (range.min, range.max)
+        } else if let Some((f, t)) = body.split_once('-') {
+            (parse_number(f, range)?, parse_number(t, range)?)
+        } else {
+            let v = parse_number(body, range)?;
+
+            // `a/n`은 a부터 최대값까지 n 간격
+            (v, if step.is_some() { range.max } else { v })
+        };
+
+        if from < range.min || to > range.max || from > to {
+            return Err(field_error(range, item));
+        }
+
+        for v in (from..=to).step_by(step.unwrap_or(1) as usize) {
+            mask |= 1 << v;
+        }
+    }
+
+    Ok(mask)
+}
+
+fn parse_number(value: &str, range: &FieldRange) -> Result<u32, InvalidArgumentError> {
+    value.parse::<u32>().map_err(|_| field_error(range, value))
+}
+
+fn field_error(range: &FieldRange, value: &str) -> InvalidArgumentError {
+    InvalidArgumentError::new(&format!(
+        "잘못된 {} 항목 입니다({}). 허용 범위 : {}-{}",
+        range.name, value, range.min, range.max
+    ))
+}
+
+#[inline]
+fn contains(mask: u64, value: u32) -> bool {
+    mask & (1 << value) != 0
+}
+
+/// `from` 이상인 첫 번째 bit 위치 반환
+fn next_bit(mask: u64, from: u32) -> Option<u32> {
+    let remain = mask >> from;
+
+    if remain == 0 {
+        None
+    } else {
+        Some(from + remain.trailing_zeros())
+    }
+}
+
+/// `unit`초 단위로 절삭
+fn truncate(datetime: &NaiveDateTime, unit: u32) -> Option<NaiveDateTime> {
+    let seconds = datetime.num_seconds_from_midnight();
+
+    NaiveTime::from_num_seconds_from_midnight_opt(seconds - seconds % unit, 0)
+        .map(|t| datetime.date().and_time(t))
+}
+
+fn first_day_of_next_month(date: &NaiveDate) -> Option<NaiveDate> {
+    if date.month() == 12 {
+        NaiveDate::from_ymd_opt(date.year() + 1, 1, 1)
+    } else {
+        NaiveDate::from_ymd_opt(date.year(), date.month() + 1, 1)
+    }
+}
+
+/// 원본 표현식 문자열로 직렬화
+#[cfg(feature = "serde")]
+impl serde::Serialize for CronSchedule {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.expression)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for CronSchedule {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+
+        CronSchedule::parse(&value).map_err(|e| serde::de::Error::custom(e.get_message()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn utc(y: i32, m: u32, d: u32, h: u32, min: u32, s: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(y, m, d, h, min, s).unwrap()
+    }
+
+    #[test]
+    fn parse_test() {
+        assert!(CronSchedule::parse("* * * * *").is_ok());
+        assert!(CronSchedule::parse("*/10 0 9-18/3 1,15 * 0-7").is_ok());
+        assert!(CronSchedule::parse("* * * *").is_err());
+        assert!(CronSchedule::parse("60 * * * *").is_err());
+        assert!(CronSchedule::parse("* * 0 * *").is_err());
+        assert!(CronSchedule::parse("*/0 * * * *").is_err());
+        assert!(CronSchedule::parse("5-1 * * * *").is_err());
+        assert!(CronSchedule::parse("a * * * *").is_err());
+
+        let schedule = CronSchedule::parse("  0  12 * *   * ").unwrap();
+
+        assert_eq!("0 12 * * *", schedule.expression());
+    }
+
+    #[test]
+    fn next_after_test() {
+        let schedule = CronSchedule::parse("*/15 * * * *").unwrap();
+
+        assert_eq!(
+            Some(utc(2024, 11, 22, 10, 15, 0)),
+            schedule.next_after(&utc(2024, 11, 22, 10, 0, 0))
+        );
+        assert_eq!(
+            Some(utc(2024, 11, 22, 11, 0, 0)),
+            schedule.next_after(&utc(2024, 11, 22, 10, 59, 59))
+        );
+
+        // 6자리(초 포함)
+        let schedule = CronSchedule::parse("*/20 * * * * *").unwrap();
+
+        assert_eq!(
+            Some(utc(2024, 12, 31, 23, 59, 40)),
+            schedule.next_after(&utc(2024, 12, 31, 23, 59, 21))
+        );
+        assert_eq!(
+            Some(utc(2025, 1, 1, 0, 0, 0)),
+            schedule.next_after(&utc(2024, 12, 31, 23, 59, 40))
+        );
+
+        // 윤년 2월 29일
+        let schedule = CronSchedule::parse("0 0 29 2 *").unwrap();
+
+        assert_eq!(
+            Some(utc(2028, 2, 29, 0, 0, 0)),
+            schedule.next_after(&utc(2024, 3, 1, 0, 0, 0))
+        );
+
+        // 존재하지 않는 날짜
+        let schedule = CronSchedule::parse("0 0 31 2 *").unwrap();
+
+        assert_eq!(None, schedule.next_after(&utc(2024, 1, 1, 0, 0, 0)));
+
+        // 표현 가능한 최대 시각 부근
+        let max = DateTime::<Utc>::MAX_UTC;
+        let near_max = utc(262142, 12, 31, 23, 59, 29);
+
+        for expression in ["* * * * * *", "0 0 1 1 *", "59 23 * * *"] {
+            let schedule = CronSchedule::parse(expression).unwrap();
+
+            assert_eq!(None, schedule.next_after(&max), "{}", expression);
+        }
+
+        assert_eq!(
+            Some(utc(262142, 12, 31, 23, 59, 30)),
+            CronSchedule::parse("* * * * * *")
+                .unwrap()
+                .next_after(&near_max)
+        );
+        assert_eq!(
+            None,
+            CronSchedule::parse("0 0 1 1 *")
+                .unwrap()
+                .next_after(&near_max)
+        );
+    }
+
+    #[test]
+    fn day_of_week_test() {
+        // 일요일(7)
+        let schedule = CronSchedule::parse("0 0 * * 7").unwrap();
+
+        assert_eq!(
+            Some(utc(2024, 11, 24, 0, 0, 0)),
+            schedule.next_after(&utc(2024, 11, 22, 0, 0, 0))
+        );
+
+        // 일, 요일 모두 지정시 둘 중 하나 일치 : 매월 1일 혹은 월요일
+        let schedule = CronSchedule::parse("0 0 1 * 1").unwrap();
+
+        assert_eq!(
+            Some(utc(2024, 11, 25, 0, 0, 0)),
+            schedule.next_after(&utc(2024, 11, 22, 0, 0, 0))
+        );
+        assert_eq!(
+            Some(utc(2024, 12, 1, 0, 0, 0)),
+            schedule.next_after(&utc(2024, 11, 25, 0, 0, 0))
+        );
+    }
+
+    #[test]
+    fn timezone_test() {
+        // KST 09:00 = UTC 00:00
+        let schedule = CronSchedule::parse("0 9 * * *").unwrap();
+        let after = utc(2024, 11, 22, 1, 0, 0).with_timezone(&Tz::Asia__Seoul);
+        let next = schedule.next_after(&after).unwrap();
+
+        assert_eq!(utc(2024, 11, 23, 0, 0, 0), next.with_timezone(&Utc));
+
+        // 2024-03-10 02:30 (America/New_York)은 존재하지 않으므로 다음날 실행
+        let schedule = CronSchedule::parse("30 2 * * *").unwrap();
+        let after = utc(2024, 3, 10, 5, 0, 0).with_timezone(&Tz::America__New_York);
+        let next = schedule.next_after(&after).unwrap();
+
+        assert_eq!(11, next.day());
+        assert_eq!(2, next.hour());
+    }
+
+    #[test]
+    fn upcoming_test() {
+        let schedule: CronSchedule = "30 9 * * 1-5".parse().unwrap();
+        let days: Vec<u32> = schedule
+            .upcoming(&utc(2024, 11, 22, 10, 0, 0))
+            .take(5)
+            .map(|v| v.day())
+            .collect();
+
+        // 2024-11-22(금) 이후 평일
+        assert_eq!(vec![25, 26, 27, 28, 29], days);
+
+        // DST 종료(2024-11-03 01:00 ~ 02:00 중복) 구간은 이른 시각에 한 번만 실행
+        let schedule = CronSchedule::parse("30 1 * * *").unwrap();
+        let upcoming: Vec<String> = schedule
+            .upcoming_in(&utc(2024, 11, 2, 12, 0, 0), Tz::America__New_York)
+            .take(3)
+            .map(|v| v.format("%m-%d %H:%M %Z").to_string())
+            .collect();
+
+        assert_eq!(
+            vec!["11-03 01:30 EDT", "11-04 01:30 EST", "11-05 01:30 EST"],
+            upcoming
+        );
+
+        let schedule = CronSchedule::parse("0 0 31 2 *").unwrap();
+
+        assert_eq!(0, schedule.upcoming(&utc(2024, 1, 1, 0, 0, 0)).count());
+        assert!("* * *".parse::<CronSchedule>().is_err());
+    }
+}
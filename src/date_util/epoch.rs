@@ -0,0 +1,208 @@
+//! Unix epoch 변환 관련 함수 모음
+//!
+//! epoch 값을 지정된 timezone의 [DateTime]으로 변환하거나 [DateTime]을 epoch 값으로 변환한다. 단위는 함수
+//! 이름 혹은 [EpochUnit]으로 명시한다.
+//!
+//! ```rust
+//! use chrono_tz::Tz;
+//! use cliff3_util::date_util::epoch::{from_epoch_millis, to_epoch_millis};
+//!
+//! let datetime = from_epoch_millis(1732238988123, &Tz::Asia__Seoul).unwrap();
+//!
+//! assert_eq!("2024-11-22 10:29:48.123 KST", datetime.to_string());
+//! assert_eq!(1732238988123, to_epoch_millis(&datetime));
+//! ```
+
+use chrono::{DateTime, TimeZone, Utc};
+use chrono_tz::Tz;
+
+use crate::error::InvalidArgumentError;
+
+/// epoch 값 단위
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EpochUnit {
+    /// 초
+    Seconds,
+
+    /// 밀리초
+    Millis,
+
+    /// 마이크로초
+    Micros,
+}
+
+/// epoch 값을 지정된 timezone의 [DateTime]으로 변환
+///
+/// # Arguments
+///
+/// - `value` - epoch 값(음수는 1970-01-01 이전)
+/// - `unit` - 단위 [EpochUnit]
+/// - `timezone` - 변환할 timezone [Tz]
+///
+/// # Return
+///
+/// - 변환 결과 `Result<DateTime<Tz>, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 변환 결과가 [DateTime]의 범위를 벗어날 경우
+///
+/// # Example
+///
+/// ```rust
+/// use chrono_tz::Tz;
+/// use cliff3_util::date_util::epoch::{from_epoch, EpochUnit};
+///
+/// let datetime = from_epoch(1732238988, EpochUnit::Seconds, &Tz::UTC).unwrap();
+///
+/// assert_eq!("2024-11-22 01:29:48 UTC", datetime.to_string());
+/// assert!(from_epoch(i64::MAX, EpochUnit::Seconds, &Tz::UTC).is_err());
+/// ```
+pub fn from_epoch(
+    value: i64,
+    unit: EpochUnit,
+    timezone: &Tz,
+) -> Result<DateTime<Tz>, InvalidArgumentError> {
+    let utc: Option<DateTime<Utc>> = match unit {
+        EpochUnit::Seconds => DateTime::from_timestamp(value, 0),
+        EpochUnit::Millis => DateTime::from_timestamp_millis(value),
+        EpochUnit::Micros => DateTime::from_timestamp_micros(value),
+    };
+
+    utc.map(|v| v.with_timezone(timezone))
+        .ok_or_else(|| InvalidArgumentError::new("epoch 값이 날짜 범위를 벗어납니다."))
+}
+
+/// epoch 밀리초를 지정된 timezone의 [DateTime]으로 변환([from_epoch] 참고)
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 변환 결과가 [DateTime]의 범위를 벗어날 경우
+pub fn from_epoch_millis(millis: i64, timezone: &Tz) -> Result<DateTime<Tz>, InvalidArgumentError> {
+    from_epoch(millis, EpochUnit::Millis, timezone)
+}
+
+/// epoch 마이크로초를 지정된 timezone의 [DateTime]으로 변환([from_epoch] 참고)
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 변환 결과가 [DateTime]의 범위를 벗어날 경우
+///
+/// # Example
+///
+/// ```rust
+/// use chrono_tz::Tz;
+/// use cliff3_util::date_util::epoch::from_epoch_micros;
+///
+/// let datetime = from_epoch_micros(1732238988123456, &Tz::Asia__Seoul).unwrap();
+///
+/// assert_eq!("2024-11-22 10:29:48.123456 KST", datetime.to_string());
+/// ```
+pub fn from_epoch_micros(micros: i64, timezone: &Tz) -> Result<DateTime<Tz>, InvalidArgumentError> {
+    from_epoch(micros, EpochUnit::Micros, timezone)
+}
+
+/// [DateTime]을 epoch 밀리초로 변환. 밀리초 미만은 절삭한다.
+pub fn to_epoch_millis<T: TimeZone>(datetime: &DateTime<T>) -> i64 {
+    datetime.timestamp_millis()
+}
+
+/// [DateTime]을 epoch 마이크로초로 변환. 마이크로초 미만은 절삭한다.
+pub fn to_epoch_micros<T: TimeZone>(datetime: &DateTime<T>) -> i64 {
+    datetime.timestamp_micros()
+}
+
+/// 숫자 형태의 epoch 문자열을 지정된 timezone의 [DateTime]으로 변환
+///
+/// 앞뒤 공백은 무시하며 부호(`-`, `+`)를 허용한다.
+///
+/// # Arguments
+///
+/// - `input` - epoch 문자열(e.g. `1732238988123`)
+/// - `unit` - 단위 [EpochUnit]
+/// - `timezone` - 변환할 timezone [Tz]
+///
+/// # Return
+///
+/// - 변환 결과 `Result<DateTime<Tz>, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 숫자가 아닌 문자열 혹은 변환 결과가 [DateTime]의 범위를 벗어날 경우
+///
+/// # Example
+///
+/// ```rust
+/// use chrono_tz::Tz;
+/// use cliff3_util::date_util::epoch::{parse_epoch, EpochUnit};
+///
+/// let datetime = parse_epoch(" 1732238988123 ", EpochUnit::Millis, &Tz::UTC).unwrap();
+///
+/// assert_eq!("2024-11-22 01:29:48.123 UTC", datetime.to_string());
+/// assert!(parse_epoch("17322389.88", EpochUnit::Seconds, &Tz::UTC).is_err());
+/// ```
+pub fn parse_epoch(
+    input: &str,
+    unit: EpochUnit,
+    timezone: &Tz,
+) -> Result<DateTime<Tz>, InvalidArgumentError> {
+    let value: i64 = input
+        .trim()
+        .parse()
+        .map_err(|_| InvalidArgumentError::new("숫자 형식의 epoch 값이 아닙니다."))?;
+
+    from_epoch(value, unit, timezone)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn epoch_test() {
+        let seoul = Tz::Asia__Seoul;
+        let expected = "2024-11-22 10:29:48 KST";
+
+        assert_eq!(
+            expected,
+            from_epoch(1732238988, EpochUnit::Seconds, &seoul)
+                .unwrap()
+                .to_string()
+        );
+        assert_eq!(
+            expected,
+            from_epoch_millis(1732238988000, &seoul)
+                .unwrap()
+                .to_string()
+        );
+        assert_eq!(
+            expected,
+            from_epoch_micros(1732238988000000, &seoul)
+                .unwrap()
+                .to_string()
+        );
+        assert_eq!(
+            "1969-12-31 23:59:59.999 UTC",
+            from_epoch_millis(-1, &Tz::UTC).unwrap().to_string()
+        );
+
+        let datetime = from_epoch_micros(1732238988123456, &seoul).unwrap();
+
+        // timezone과 무관하게 동일한 epoch
+        assert_eq!(1732238988123, to_epoch_millis(&datetime));
+        assert_eq!(
+            1732238988123456,
+            to_epoch_micros(&datetime.with_timezone(&Utc))
+        );
+
+        assert!(from_epoch_millis(i64::MAX, &seoul).is_err());
+        assert!(from_epoch_micros(i64::MIN, &seoul).is_err());
+        assert_eq!(
+            from_epoch_millis(-1000, &seoul).unwrap(),
+            parse_epoch("-1000", EpochUnit::Millis, &seoul).unwrap()
+        );
+        assert!(parse_epoch("", EpochUnit::Millis, &seoul).is_err());
+        assert!(parse_epoch("1e10", EpochUnit::Millis, &seoul).is_err());
+    }
+}
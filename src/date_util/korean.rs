@@ -0,0 +1,420 @@
+//! 한국어 날짜 표현 관련 함수 모음
+//!
+//! [format_korean]은 `2024년 11월 22일 (금) 오후 1시 27분` 형식의 문자열을 생성하며, [parse_relative_korean]은
+//! `내일`, `3일 후`, `지난주 월요일` 등의 상대 날짜 표현을 날짜로 변환한다.
+
+use chrono::{DateTime, Datelike, Days, Months, NaiveDate, TimeZone, Timelike, Weekday};
+
+use crate::error::InvalidArgumentError;
+
+/// [format_korean] 출력 형식
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum KoreanDateStyle {
+    /// `2024년 11월 22일`
+    Date,
+
+    /// `2024년 11월 22일 (금)`
+    DateWithWeekday,
+
+    /// `2024년 11월 22일 (금) 오후 1시 27분`
+    DateTime,
+
+    /// `2024년 11월 22일 (금) 오후 1시 27분 45초`
+    DateTimeWithSecond,
+
+    /// `오후 1시 27분`
+    Time,
+}
+
+/// 요일의 한글 약칭(e.g. [Weekday::Fri] - `금`)
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::Weekday;
+/// use cliff3_util::date_util::korean::weekday_korean;
+///
+/// assert_eq!("월", weekday_korean(Weekday::Mon));
+/// assert_eq!("일", weekday_korean(Weekday::Sun));
+/// ```
+pub fn weekday_korean(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Mon => "월",
+        Weekday::Tue => "화",
+        Weekday::Wed => "수",
+        Weekday::Thu => "목",
+        Weekday::Fri => "금",
+        Weekday::Sat => "토",
+        Weekday::Sun => "일",
+    }
+}
+
+/// 한국어 형식의 날짜 및 시간 문자열 생성
+///
+/// 시간은 12시간제(`오전`/`오후`)로 표시하며 자정은 `오전 12시`, 정오는 `오후 12시`이다. [chrono::DateTime]은
+/// 해당 timezone의 지역 시각을 기준으로 한다.
+///
+/// # Arguments
+///
+/// - `datetime` - 대상 날짜 및 시간([chrono::NaiveDateTime], [chrono::DateTime] 등)
+/// - `style` - 출력 형식 [KoreanDateStyle]
+///
+/// # Return
+///
+/// - 변환 결과 문자열
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::NaiveDateTime;
+/// use cliff3_util::date_util::korean::{format_korean, KoreanDateStyle};
+///
+/// let datetime = NaiveDateTime::parse_from_str("2024-11-22 13:27:45", "%Y-%m-%d %H:%M:%S").unwrap();
+///
+/// assert_eq!("2024년 11월 22일", format_korean(&datetime, KoreanDateStyle::Date));
+/// assert_eq!(
+///     "2024년 11월 22일 (금) 오후 1시 27분",
+///     format_korean(&datetime, KoreanDateStyle::DateTime)
+/// );
+/// assert_eq!("오후 1시 27분", format_korean(&datetime, KoreanDateStyle::Time));
+/// ```
+pub fn format_korean<T: Datelike + Timelike>(datetime: &T, style: KoreanDateStyle) -> String {
+    let date = format!(
+        "{}년 {}월 {}일",
+        datetime.year(),
+        datetime.month(),
+        datetime.day()
+    );
+    let weekday = format!("({})", weekday_korean(datetime.weekday()));
+    let (pm, hour) = datetime.hour12();
+    let meridiem = if pm { "오후" } else { "오전" };
+    let time = format!("{meridiem} {hour}시 {}분", datetime.minute());
+
+    match style {
+        KoreanDateStyle::Date => date,
+        KoreanDateStyle::DateWithWeekday => format!("{date} {weekday}"),
+        KoreanDateStyle::DateTime => format!("{date} {weekday} {time}"),
+        KoreanDateStyle::DateTimeWithSecond => {
+            format!("{date} {weekday} {time} {}초", datetime.second())
+        }
+        KoreanDateStyle::Time => time,
+    }
+}
+
+/// 상대 날짜 표현(e.g. `내일`, `3일 후`, `지난주 월요일`, `다음달 말일`)을 날짜로 변환
+///
+/// `reference`의 timezone 기준 날짜를 기준일로 하며 공백은 무시한다. 주는 월요일부터 시작한다.
+///
+/// | 표현 | 예 |
+/// |---|---|
+/// | 기준일 | `오늘`, `내일`, `모레`, `글피`, `어제`, `그제`(`그저께`) |
+/// | 기간 | `3일 후`(`뒤`), `2주 전`, `1개월 후`(`1달 후`), `1년 전` |
+/// | 주 | `이번주`/`지난주`(`저번주`)/`다음주` + `월요일` ~ `일요일` |
+/// | 월 | `이번달`/`지난달`(`저번달`)/`다음달` + `15일` 혹은 `말일` |
+/// | 연 | `올해`/`작년`/`재작년`/`내년`/`내후년` + `3월 1일` 혹은 `2월 말일` |
+///
+/// 월 단위 기간 계산시 해당 월에 같은 일자가 없을 경우 말일로 보정한다(e.g. 1월 31일의 `1개월 후`는 2월 말일).
+///
+/// # Arguments
+///
+/// - `input` - 상대 날짜 표현
+/// - `reference` - 기준 시각. 해당 timezone의 날짜를 기준일로 한다.
+///
+/// # Return
+///
+/// - 변환 결과 `Result<NaiveDate, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 해석할 수 없는 표현, 존재하지 않는 날짜(e.g. `다음달 31일`) 혹은 날짜 범위를
+///   벗어날 경우
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use chrono_tz::Tz;
+/// use cliff3_util::date_util::korean::parse_relative_korean;
+///
+/// // KST 2024-11-22(금) 10:00
+/// let reference = Utc
+///     .with_ymd_and_hms(2024, 11, 22, 1, 0, 0)
+///     .unwrap()
+///     .with_timezone(&Tz::Asia__Seoul);
+/// let parse = |v| parse_relative_korean(v, &reference).unwrap().to_string();
+///
+/// assert_eq!("2024-11-24", parse("모레"));
+/// assert_eq!("2024-11-25", parse("3일 후"));
+/// assert_eq!("2024-11-11", parse("지난주 월요일"));
+/// assert_eq!("2024-12-31", parse("다음달 말일"));
+/// assert_eq!("2025-03-01", parse("내년 3월 1일"));
+/// assert!(parse_relative_korean("언젠가", &reference).is_err());
+/// ```
+pub fn parse_relative_korean<T: TimeZone>(
+    input: &str,
+    reference: &DateTime<T>,
+) -> Result<NaiveDate, InvalidArgumentError> {
+    let text: String = input.chars().filter(|c| !c.is_whitespace()).collect();
+
+    relative_date(&text, reference.date_naive())
+        .ok_or_else(|| InvalidArgumentError::new("해석할 수 없는 날짜 표현 입니다."))
+}
+
+fn relative_date(text: &str, today: NaiveDate) -> Option<NaiveDate> {
+    let days = match text {
+        "오늘" => Some(0),
+        "내일" => Some(1),
+        "모레" => Some(2),
+        "글피" => Some(3),
+        "어제" => Some(-1),
+        "그제" | "그저께" => Some(-2),
+        _ => None,
+    };
+
+    if let Some(days) = days {
+        return add_days(today, days);
+    }
+
+    period_expression(text, today)
+        .or_else(|| week_expression(text, today))
+        .or_else(|| month_expression(text, today))
+        .or_else(|| year_expression(text, today))
+}
+
+/// `N일 후`, `N주 전`, `N개월 후`, `N년 전`
+fn period_expression(text: &str, today: NaiveDate) -> Option<NaiveDate> {
+    let (count, rest) = leading_number(text)?;
+    let (sign, rest) = if let Some(v) = rest.strip_suffix('전') {
+        (-1, v)
+    } else {
+        (
+            1,
+            rest.strip_suffix('후')
+                .or_else(|| rest.strip_suffix('뒤'))?,
+        )
+    };
+    let count = i64::from(count) * sign;
+
+    match rest {
+        "일" => add_days(today, count),
+        "주" => add_days(today, count.checked_mul(7)?),
+        "개월" | "달" => add_months(today, count),
+        "년" => add_months(today, count.checked_mul(12)?),
+        _ => None,
+    }
+}
+
+/// `이번주`/`지난주`/`다음주` + 요일
+fn week_expression(text: &str, today: NaiveDate) -> Option<NaiveDate> {
+    let (weeks, rest) = strip_any_prefix(
+        text,
+        &[("이번주", 0), ("지난주", -1), ("저번주", -1), ("다음주", 1)],
+    )?;
+    let weekday = WEEKDAYS.iter().find(|v| {
+        rest == weekday_korean(**v) || rest.strip_suffix("요일") == Some(weekday_korean(**v))
+    })?;
+    let monday = add_days(today, -i64::from(today.weekday().num_days_from_monday()))?;
+
+    add_days(
+        monday,
+        weeks * 7 + i64::from(weekday.num_days_from_monday()),
+    )
+}
+
+/// `이번달`/`지난달`/`다음달` + `N일` 혹은 `말일`
+fn month_expression(text: &str, today: NaiveDate) -> Option<NaiveDate> {
+    let (months, rest) = strip_any_prefix(
+        text,
+        &[("이번달", 0), ("지난달", -1), ("저번달", -1), ("다음달", 1)],
+    )?;
+    let first = add_months(today.with_day(1)?, months)?;
+
+    day_of_month(first, rest)
+}
+
+/// `올해`/`작년`/`내년` + `N월 N일` 혹은 `N월 말일`
+fn year_expression(text: &str, today: NaiveDate) -> Option<NaiveDate> {
+    let (years, rest) = strip_any_prefix(
+        text,
+        &[
+            ("올해", 0),
+            ("재작년", -2),
+            ("작년", -1),
+            ("내후년", 2),
+            ("내년", 1),
+        ],
+    )?;
+    let (month, rest) = leading_number(rest)?;
+    let rest = rest.strip_prefix('월')?;
+    let year = today.year().checked_add(i32::try_from(years).ok()?)?;
+
+    day_of_month(NaiveDate::from_ymd_opt(year, month, 1)?, rest)
+}
+
+const WEEKDAYS: [Weekday; 7] = [
+    Weekday::Mon,
+    Weekday::Tue,
+    Weekday::Wed,
+    Weekday::Thu,
+    Weekday::Fri,
+    Weekday::Sat,
+    Weekday::Sun,
+];
+
+/// `first`가 속한 월의 `N일` 혹은 `말일`
+fn day_of_month(first: NaiveDate, text: &str) -> Option<NaiveDate> {
+    if text == "말일" {
+        return first.checked_add_months(Months::new(1))?.pred_opt();
+    }
+
+    let (day, rest) = leading_number(text)?;
+
+    if rest != "일" {
+        return None;
+    }
+
+    first.with_day(day)
+}
+
+/// 앞쪽의 숫자와 나머지 문자열
+fn leading_number(text: &str) -> Option<(u32, &str)> {
+    let end = text
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(text.len());
+
+    text[..end].parse().ok().map(|v| (v, &text[end..]))
+}
+
+fn strip_any_prefix<'a>(text: &'a str, prefixes: &[(&str, i64)]) -> Option<(i64, &'a str)> {
+    prefixes
+        .iter()
+        .find_map(|(prefix, v)| text.strip_prefix(prefix).map(|rest| (*v, rest)))
+}
+
+fn add_days(date: NaiveDate, days: i64) -> Option<NaiveDate> {
+    if days < 0 {
+        date.checked_sub_days(Days::new(days.unsigned_abs()))
+    } else {
+        date.checked_add_days(Days::new(days.unsigned_abs()))
+    }
+}
+
+fn add_months(date: NaiveDate, months: i64) -> Option<NaiveDate> {
+    let count = Months::new(u32::try_from(months.unsigned_abs()).ok()?);
+
+    if months < 0 {
+        date.checked_sub_months(count)
+    } else {
+        date.checked_add_months(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{NaiveDate, TimeZone, Utc};
+    use chrono_tz::Tz;
+
+    #[test]
+    fn format_korean_test() {
+        let datetime = |h, m, s| {
+            NaiveDate::from_ymd_opt(2024, 11, 22)
+                .unwrap()
+                .and_hms_opt(h, m, s)
+                .unwrap()
+        };
+
+        assert_eq!(
+            "2024년 11월 22일 (금)",
+            format_korean(&datetime(0, 0, 0), KoreanDateStyle::DateWithWeekday)
+        );
+        assert_eq!(
+            "2024년 11월 22일 (금) 오후 1시 27분 5초",
+            format_korean(&datetime(13, 27, 5), KoreanDateStyle::DateTimeWithSecond)
+        );
+
+        // 자정, 정오
+        assert_eq!(
+            "오전 12시 0분",
+            format_korean(&datetime(0, 0, 0), KoreanDateStyle::Time)
+        );
+        assert_eq!(
+            "오후 12시 30분",
+            format_korean(&datetime(12, 30, 0), KoreanDateStyle::Time)
+        );
+        assert_eq!(
+            "오전 11시 59분",
+            format_korean(&datetime(11, 59, 59), KoreanDateStyle::Time)
+        );
+
+        // 지역 시각 기준(UTC 2024-11-22 15:00 = KST 2024-11-23 00:00)
+        let local = Utc
+            .with_ymd_and_hms(2024, 11, 22, 15, 0, 0)
+            .unwrap()
+            .with_timezone(&Tz::Asia__Seoul);
+
+        assert_eq!(
+            "2024년 11월 23일 (토) 오전 12시 0분",
+            format_korean(&local, KoreanDateStyle::DateTime)
+        );
+    }
+
+    #[test]
+    fn parse_relative_korean_test() {
+        // 2024-01-31(수)
+        let reference = Utc.with_ymd_and_hms(2024, 1, 31, 12, 0, 0).unwrap();
+        let parse = |v| {
+            parse_relative_korean(v, &reference)
+                .map(|v| v.to_string())
+                .unwrap_or_default()
+        };
+
+        assert_eq!("2024-01-31", parse("오늘"));
+        assert_eq!("2024-02-01", parse("내일"));
+        assert_eq!("2024-02-03", parse("글피"));
+        assert_eq!("2024-01-29", parse("그저께"));
+        assert_eq!("2024-02-10", parse("10일뒤"));
+        assert_eq!("2024-01-17", parse("2 주 전"));
+        assert_eq!("2024-02-29", parse("1개월 후"));
+        assert_eq!("2023-12-31", parse("1달 전"));
+        assert_eq!("2023-01-31", parse("1년 전"));
+
+        assert_eq!("2024-01-29", parse("이번주 월요일"));
+        assert_eq!("2024-02-04", parse("이번주 일요일"));
+        assert_eq!("2024-01-26", parse("저번주 금"));
+        assert_eq!("2024-02-06", parse("다음주 화요일"));
+
+        assert_eq!("2024-02-29", parse("다음달 말일"));
+        assert_eq!("2023-12-25", parse("지난달 25일"));
+        assert_eq!("2024-01-01", parse("이번달 1일"));
+        assert_eq!("2022-12-25", parse("재작년 12월 25일"));
+        assert_eq!("2025-02-28", parse("내년 2월 말일"));
+
+        // 지역 시각 기준(KST 2024-02-01 02:00)
+        let local = Utc
+            .with_ymd_and_hms(2024, 1, 31, 17, 0, 0)
+            .unwrap()
+            .with_timezone(&Tz::Asia__Seoul);
+
+        assert_eq!(
+            "2024-02-02",
+            parse_relative_korean("내일", &local).unwrap().to_string()
+        );
+
+        for invalid in [
+            "",
+            "내일모레",
+            "다음달 30일",
+            "3일",
+            "3시간 후",
+            "다음주",
+            "올해 13월 1일",
+        ] {
+            assert!(
+                parse_relative_korean(invalid, &reference).is_err(),
+                "{invalid}"
+            );
+        }
+    }
+}
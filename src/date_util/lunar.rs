@@ -0,0 +1,341 @@
+//! 음력(한국) 관련 함수 모음
+//!
+//! [solar_to_lunar]와 [lunar_to_solar]로 양력과 음력(윤달 포함)을 상호 변환하며 음력 1900년 1월 1일(양력
+//! 1900년 1월 31일)부터 음력 2100년 12월 29일(양력 2101년 1월 28일)까지 지원한다.
+//!
+//! 음력 2050년까지는 한국천문연구원(KASI)의 음양력 자료를 기준으로 하며, 이후는 같은 방식(한국 표준시 기준
+//! 삭 및 중기)으로 계산한 결과이다. 계산 결과는 1912년 ~ 2050년 자료와 일치하지만 삭이 자정 전후 수 분
+//! 이내인 날(양력 2051-11-03, 2074-08-22, 2092-02-08 및 2097-01-13)이 시작일인 달은 추후 발표되는 자료와
+//! 다를 수 있다.
+//!
+//! ```rust
+//! use chrono::NaiveDate;
+//! use cliff3_util::date_util::lunar::{lunar_to_solar, solar_to_lunar, LunarDate};
+//!
+//! let chuseok = LunarDate::new(2025, 8, 15);
+//! let solar = lunar_to_solar(&chuseok).unwrap();
+//!
+//! assert_eq!("2025-10-06", solar.to_string());
+//! assert_eq!(chuseok, solar_to_lunar(&solar).unwrap());
+//!
+//! // 윤달
+//! let date = NaiveDate::from_ymd_opt(2025, 7, 25).unwrap();
+//!
+//! assert_eq!(LunarDate::leap(2025, 6, 1), solar_to_lunar(&date).unwrap());
+//! ```
+
+use std::fmt::{Display, Formatter};
+
+use chrono::{Days, NaiveDate};
+
+use crate::error::InvalidArgumentError;
+
+/// 지원하는 첫 번째 음력 연도
+pub const MIN_LUNAR_YEAR: i32 = 1900;
+
+/// 지원하는 마지막 음력 연도
+pub const MAX_LUNAR_YEAR: i32 = 2100;
+
+/// 음력 연도별 월 정보
+///
+/// - bit 0 ~ 12 - 윤달을 포함한 순서의 월별 일 수(`1`: 30일, `0`: 29일)
+/// - bit 13 ~ 16 - 윤달(`0`: 없음)
+///
+/// 2051년 이후는 천문 계산 결과이다.
+const LUNAR_YEARS: [u32; 201] = [
+    // 1900 ~ 1909
+    0x116d2, 0x00752, 0x00ea5, 0x0b64a, 0x0064b, 0x00a9b, 0x09556, 0x0056a, 0x00b59, 0x05752,
+    // 1910 ~ 1919
+    0x00752, 0x0db25, 0x00b25, 0x00a4b, 0x0b29b, 0x00aad, 0x0056a, 0x04b69, 0x00ba9, 0x0fb52,
+    // 1920 ~ 1929
+    0x00d92, 0x00d25, 0x0ba4d, 0x00956, 0x002b5, 0x095ad, 0x006d4, 0x00da9, 0x05d92, 0x00e92,
+    // 1930 ~ 1939
+    0x0cd26, 0x00527, 0x00a57, 0x0b2b6, 0x00ada, 0x006d4, 0x06ea9, 0x00749, 0x0f693, 0x00a93,
+    // 1940 ~ 1949
+    0x0052b, 0x0ca5b, 0x0096d, 0x00b6a, 0x09b54, 0x00ba4, 0x00b49, 0x05a93, 0x00a95, 0x0f52b,
+    // 1950 ~ 1959
+    0x0052d, 0x00aad, 0x0b56a, 0x00db2, 0x00da4, 0x07d49, 0x00d4a, 0x11a95, 0x00a96, 0x00556,
+    // 1960 ~ 1969
+    0x0cab5, 0x00ad5, 0x006d2, 0x08ea5, 0x00ea5, 0x00e4a, 0x06c96, 0x00a9b, 0x0f556, 0x0056a,
+    // 1970 ~ 1979
+    0x00b59, 0x0b752, 0x00752, 0x00725, 0x0964b, 0x00a4b, 0x112ab, 0x002ad, 0x0056b, 0x0cb69,
+    // 1980 ~ 1989
+    0x00da9, 0x00d92, 0x09b25, 0x00d25, 0x15a4d, 0x00a56, 0x002b6, 0x0d5ad, 0x006d4, 0x00da9,
+    // 1990 ~ 1999
+    0x0bd92, 0x00e92, 0x00d26, 0x06a56, 0x00a57, 0x112b6, 0x00b5a, 0x006d4, 0x0aec9, 0x00749,
+    // 2000 ~ 2009
+    0x00693, 0x09527, 0x0052b, 0x00a5b, 0x0555a, 0x0036a, 0x0fb55, 0x00ba4, 0x00b49, 0x0ba93,
+    // 2010 ~ 2019
+    0x00a95, 0x0052d, 0x06a5d, 0x00aad, 0x135aa, 0x005d2, 0x00da5, 0x0bd4a, 0x00d4a, 0x00a95,
+    // 2020 ~ 2029
+    0x0952d, 0x00556, 0x00ab5, 0x055aa, 0x006d2, 0x0cea5, 0x00ea5, 0x00e4a, 0x0ac96, 0x00c9b,
+    // 2030 ~ 2039
+    0x0055a, 0x06ad5, 0x00b69, 0x17752, 0x00752, 0x00b25, 0x0d64b, 0x00a4b, 0x004ab, 0x0a55b,
+    // 2040 ~ 2049
+    0x0056d, 0x00b69, 0x05b52, 0x00d92, 0x0fd25, 0x00d25, 0x00a4d, 0x0b4ad, 0x002b6, 0x005b5,
+    // 2050 ~ 2059
+    0x06da9, 0x00ea9, 0x11d92, 0x00e92, 0x00d26, 0x0ca56, 0x00a57, 0x004d6, 0x086b5, 0x006d5,
+    // 2060 ~ 2069
+    0x00ec9, 0x06e92, 0x00693, 0x0f52b, 0x0052b, 0x00a5b, 0x0b55a, 0x0056a, 0x00b55, 0x09749,
+    // 2070 ~ 2079
+    0x00b49, 0x11a93, 0x00a95, 0x0052d, 0x0caad, 0x00ab5, 0x005aa, 0x08ba5, 0x00da5, 0x00d4a,
+    // 2080 ~ 2089
+    0x07a95, 0x00c95, 0x0f52e, 0x00556, 0x00ab5, 0x0b5b2, 0x006d2, 0x00ea5, 0x09e4a, 0x0064a,
+    // 2090 ~ 2099
+    0x10c97, 0x00cab, 0x0055a, 0x0cad5, 0x00b69, 0x00752, 0x096a5, 0x00b25, 0x0064b, 0x07497,
+    // 2100
+    0x004ab,
+];
+
+/// 음력 날짜
+///
+/// 필드 순서대로 비교하므로 같은 월의 윤달은 평달 이후로 정렬된다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LunarDate {
+    /// 연도
+    pub year: i32,
+
+    /// 월(1 ~ 12)
+    pub month: u32,
+
+    /// 윤달 여부
+    pub leap: bool,
+
+    /// 일(1 ~ 30)
+    pub day: u32,
+}
+
+impl LunarDate {
+    /// 평달 음력 날짜 생성. 유효성은 [lunar_to_solar]에서 확인한다.
+    pub fn new(year: i32, month: u32, day: u32) -> Self {
+        LunarDate {
+            year,
+            month,
+            leap: false,
+            day,
+        }
+    }
+
+    /// 윤달 음력 날짜 생성. 유효성은 [lunar_to_solar]에서 확인한다.
+    pub fn leap(year: i32, month: u32, day: u32) -> Self {
+        LunarDate {
+            year,
+            month,
+            leap: true,
+            day,
+        }
+    }
+}
+
+/// `2025-08-15` 형식. 윤달은 `2025-윤06-01` 형식
+impl Display for LunarDate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let leap = if self.leap { "윤" } else { "" };
+
+        write!(
+            f,
+            "{:04}-{}{:02}-{:02}",
+            self.year, leap, self.month, self.day
+        )
+    }
+}
+
+/// 음력 1900년 1월 1일 (양력)
+fn first_new_year() -> Option<NaiveDate> {
+    NaiveDate::from_ymd_opt(1900, 1, 31)
+}
+
+/// 해당 음력 연도의 윤달. 없을 경우 `0`
+fn leap_month(info: u32) -> u32 {
+    (info >> 13) & 0xf
+}
+
+/// 해당 음력 연도의 (월, 윤달 여부, 일 수) 목록
+fn months(info: u32) -> impl Iterator<Item = (u32, bool, u32)> {
+    let leap = leap_month(info);
+
+    (1..=12u32)
+        .flat_map(move |m| {
+            let regular = Some((m, false));
+            let leap = (m == leap).then_some((m, true));
+
+            regular.into_iter().chain(leap)
+        })
+        .enumerate()
+        .map(move |(i, (m, is_leap))| (m, is_leap, 29 + ((info >> i) & 1)))
+}
+
+/// 해당 음력 연도의 전체 일 수
+fn year_days(info: u32) -> u32 {
+    months(info).map(|(_, _, days)| days).sum()
+}
+
+/// 음력 날짜를 양력 날짜로 변환. 존재하지 않는 날짜 혹은 지원 범위를 벗어날 경우 `None`
+pub(crate) fn lunar_to_solar_date(
+    year: i32,
+    month: u32,
+    leap: bool,
+    day: u32,
+) -> Option<NaiveDate> {
+    if !(MIN_LUNAR_YEAR..=MAX_LUNAR_YEAR).contains(&year) {
+        return None;
+    }
+
+    let index = (year - MIN_LUNAR_YEAR) as usize;
+    let mut offset: u32 = LUNAR_YEARS[..index].iter().map(|&v| year_days(v)).sum();
+
+    for (m, is_leap, days) in months(LUNAR_YEARS[index]) {
+        if m == month && is_leap == leap {
+            if !(1..=days).contains(&day) {
+                return None;
+            }
+
+            return first_new_year()?.checked_add_days(Days::new((offset + day - 1) as u64));
+        }
+
+        offset += days;
+    }
+
+    None
+}
+
+/// 음력 날짜를 양력 날짜로 변환
+///
+/// # Arguments
+///
+/// - `lunar` - 음력 날짜 [LunarDate]
+///
+/// # Return
+///
+/// - 변환 결과 `Result<NaiveDate, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 존재하지 않는 날짜(e.g. 윤달이 없는 해의 윤달, 29일인 달의 30일) 혹은 지원
+///   범위를 벗어나는 연도
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::date_util::lunar::{lunar_to_solar, LunarDate};
+///
+/// assert_eq!("2024-02-10", lunar_to_solar(&LunarDate::new(2024, 1, 1)).unwrap().to_string());
+/// assert_eq!("1993-05-16", lunar_to_solar(&LunarDate::leap(1993, 3, 25)).unwrap().to_string());
+///
+/// // 2024년은 윤달이 없다
+/// assert!(lunar_to_solar(&LunarDate::leap(2024, 3, 1)).is_err());
+/// ```
+pub fn lunar_to_solar(lunar: &LunarDate) -> Result<NaiveDate, InvalidArgumentError> {
+    lunar_to_solar_date(lunar.year, lunar.month, lunar.leap, lunar.day)
+        .ok_or_else(|| InvalidArgumentError::new("존재하지 않거나 지원하지 않는 음력 날짜 입니다."))
+}
+
+/// 양력 날짜를 음력 날짜로 변환
+///
+/// # Arguments
+///
+/// - `date` - 양력 날짜
+///
+/// # Return
+///
+/// - 변환 결과 `Result<LunarDate, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 지원 범위(1900-01-31 ~ 2101-01-28)를 벗어나는 날짜
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use cliff3_util::date_util::lunar::{solar_to_lunar, LunarDate};
+///
+/// let date = NaiveDate::from_ymd_opt(2024, 2, 9).unwrap();
+///
+/// assert_eq!(LunarDate::new(2023, 12, 30), solar_to_lunar(&date).unwrap());
+/// assert_eq!("2023-12-30", solar_to_lunar(&date).unwrap().to_string());
+/// ```
+pub fn solar_to_lunar(date: &NaiveDate) -> Result<LunarDate, InvalidArgumentError> {
+    let out_of_range = || InvalidArgumentError::new("지원 범위를 벗어나는 날짜 입니다.");
+    let first = first_new_year().ok_or_else(out_of_range)?;
+    let mut offset =
+        u32::try_from(date.signed_duration_since(first).num_days()).map_err(|_| out_of_range())?;
+
+    for (year, &info) in (MIN_LUNAR_YEAR..).zip(LUNAR_YEARS.iter()) {
+        let days = year_days(info);
+
+        if offset >= days {
+            offset -= days;
+
+            continue;
+        }
+
+        for (month, leap, days) in months(info) {
+            if offset < days {
+                return Ok(LunarDate {
+                    year,
+                    month,
+                    leap,
+                    day: offset + 1,
+                });
+            }
+
+            offset -= days;
+        }
+    }
+
+    Err(out_of_range())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lunar_to_solar_date_test() {
+        let solar = |y, m, l, d| lunar_to_solar_date(y, m, l, d).map(|v| v.to_string());
+
+        assert_eq!(Some("1900-01-31".to_owned()), solar(1900, 1, false, 1));
+        assert_eq!(Some("1993-05-16".to_owned()), solar(1993, 3, true, 25));
+        assert_eq!(Some("2024-02-10".to_owned()), solar(2024, 1, false, 1));
+        assert_eq!(Some("2025-10-06".to_owned()), solar(2025, 8, false, 15));
+        assert_eq!(Some("2025-05-05".to_owned()), solar(2025, 4, false, 8));
+        assert_eq!(Some("2050-01-22".to_owned()), solar(2049, 12, false, 29));
+        assert_eq!(Some("2050-04-21".to_owned()), solar(2050, 3, true, 1));
+        assert_eq!(Some("2101-01-28".to_owned()), solar(2100, 12, false, 29));
+
+        // 윤달이 없는 해
+        assert_eq!(None, solar(2024, 3, true, 1));
+        // 29일인 달
+        assert_eq!(None, solar(2024, 1, false, 30));
+        assert_eq!(None, solar(1899, 1, false, 1));
+        assert_eq!(None, solar(2101, 1, false, 1));
+        assert_eq!(None, solar(2024, 13, false, 1));
+    }
+
+    #[test]
+    fn solar_to_lunar_test() {
+        let lunar = |y, m, d| solar_to_lunar(&NaiveDate::from_ymd_opt(y, m, d).unwrap());
+
+        assert_eq!(LunarDate::new(1900, 1, 1), lunar(1900, 1, 31).unwrap());
+        assert_eq!(LunarDate::leap(1993, 3, 25), lunar(1993, 5, 16).unwrap());
+        assert_eq!(LunarDate::leap(2025, 6, 29), lunar(2025, 8, 22).unwrap());
+        assert_eq!(LunarDate::new(2025, 7, 1), lunar(2025, 8, 23).unwrap());
+        assert_eq!(LunarDate::new(2100, 12, 29), lunar(2101, 1, 28).unwrap());
+        assert!(lunar(1900, 1, 30).is_err());
+        assert!(lunar(2101, 1, 29).is_err());
+        assert_eq!("1993-윤03-25", lunar(1993, 5, 16).unwrap().to_string());
+
+        // 전체 범위 왕복 변환(7일 간격)
+        let mut date = first_new_year().unwrap();
+
+        while let Ok(v) = solar_to_lunar(&date) {
+            assert_eq!(date, lunar_to_solar(&v).unwrap());
+
+            date = date.checked_add_days(Days::new(7)).unwrap();
+        }
+
+        assert!(date > NaiveDate::from_ymd_opt(2101, 1, 28).unwrap());
+    }
+}
@@ -0,0 +1,416 @@
+//! 날짜 범위 관련 함수 모음
+//!
+//! [DateRange]는 시작(포함) ~ 종료(제외) 구간의 포함, 겹침 및 교집합을 계산한다.
+//!
+//! [date_range]는 시작 날짜부터 종료 날짜까지 일/주/월 단위로 증가하는 날짜를 반환하는 iterator를 생성한다.
+//! [NaiveDate], [NaiveDateTime] 및 [DateTime]을 지원한다.
+//!
+//! 각 값은 이전 값이 아닌 시작 날짜를 기준으로 계산하므로 월 단위 증가시 말일 보정이 누적되지 않는다
+//! (e.g. 1월 31일 시작시 `01-31`, `02-29`, `03-31`, ...).
+
+use chrono::{DateTime, Days, Months, NaiveDate, NaiveDateTime, TimeDelta, TimeZone};
+
+use crate::error::InvalidArgumentError;
+
+/// 날짜 증가 단위
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DateStep {
+    /// 지정된 일 수
+    Days(u32),
+
+    /// 지정된 주 수
+    Weeks(u32),
+
+    /// 지정된 월 수. 해당 월에 같은 일자가 없을 경우 말일로 보정한다.
+    Months(u32),
+}
+
+impl DateStep {
+    fn is_zero(&self) -> bool {
+        matches!(
+            self,
+            DateStep::Days(0) | DateStep::Weeks(0) | DateStep::Months(0)
+        )
+    }
+}
+
+/// [date_range]에서 사용할 수 있는 날짜 type
+///
+/// [NaiveDate], [NaiveDateTime] 및 [DateTime]에 대해 구현되어 있다.
+pub trait Steppable: Clone + PartialOrd {
+    /// `step`을 `count`번 더한 값. 범위를 벗어나거나 해당 timezone에 존재하지 않는 시각일 경우 `None`
+    fn checked_add_step(&self, step: DateStep, count: u32) -> Option<Self>;
+}
+
+/// 공통 구현(`checked_add_days` 및 `checked_add_months`를 제공하는 type)
+macro_rules! checked_add_step {
+    ($target:expr, $step:expr, $count:expr) => {
+        match $step {
+            DateStep::Days(v) => v
+                .checked_mul($count)
+                .and_then(|v| $target.checked_add_days(Days::new(v as u64))),
+            DateStep::Weeks(v) => v
+                .checked_mul($count)
+                .and_then(|v| v.checked_mul(7))
+                .and_then(|v| $target.checked_add_days(Days::new(v as u64))),
+            DateStep::Months(v) => v
+                .checked_mul($count)
+                .and_then(|v| $target.checked_add_months(Months::new(v))),
+        }
+    };
+}
+
+impl Steppable for NaiveDate {
+    fn checked_add_step(&self, step: DateStep, count: u32) -> Option<Self> {
+        checked_add_step!(self, step, count)
+    }
+}
+
+impl Steppable for NaiveDateTime {
+    fn checked_add_step(&self, step: DateStep, count: u32) -> Option<Self> {
+        checked_add_step!(self, step, count)
+    }
+}
+
+impl<T: TimeZone> Steppable for DateTime<T> {
+    fn checked_add_step(&self, step: DateStep, count: u32) -> Option<Self> {
+        checked_add_step!(self.clone(), step, count)
+    }
+}
+
+/// [date_range]가 반환하는 iterator
+#[derive(Debug, Clone)]
+pub struct DateRangeIter<T: Steppable> {
+    start: T,
+    end: T,
+    step: DateStep,
+    index: u32,
+    finished: bool,
+}
+
+impl<T: Steppable> Iterator for DateRangeIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        match self.start.checked_add_step(self.step, self.index) {
+            Some(v) if v <= self.end => {
+                match self.index.checked_add(1) {
+                    Some(i) => self.index = i,
+                    None => self.finished = true,
+                }
+
+                Some(v)
+            }
+            _ => {
+                self.finished = true;
+
+                None
+            }
+        }
+    }
+}
+
+/// 시작 날짜부터 종료 날짜(포함)까지 `step` 단위로 증가하는 날짜 iterator 생성
+///
+/// 시작 날짜가 종료 날짜 이후일 경우 빈 iterator를 반환한다. 계산 결과가 날짜 범위를 벗어나거나
+/// [DateTime]의 경우 해당 timezone에 존재하지 않는 시각(DST 전환 구간)일 경우 종료된다.
+///
+/// # Arguments
+///
+/// - `start` - 시작 날짜
+/// - `end` - 종료 날짜(포함)
+/// - `step` - 증가 단위 [DateStep]
+///
+/// # Return
+///
+/// - 생성 결과 `Result<DateRangeIter<T>, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 증가 단위가 `0`일 경우
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use cliff3_util::date_util::range::{date_range, DateStep};
+///
+/// let start = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+/// let end = NaiveDate::from_ymd_opt(2024, 4, 30).unwrap();
+/// let months: Vec<String> = date_range(start, end, DateStep::Months(1))
+///     .unwrap()
+///     .map(|v| v.to_string())
+///     .collect();
+///
+/// assert_eq!(vec!["2024-01-31", "2024-02-29", "2024-03-31", "2024-04-30"], months);
+/// assert_eq!(91, date_range(start, end, DateStep::Days(1)).unwrap().count());
+/// assert!(date_range(start, end, DateStep::Weeks(0)).is_err());
+/// ```
+pub fn date_range<T: Steppable>(
+    start: T,
+    end: T,
+    step: DateStep,
+) -> Result<DateRangeIter<T>, InvalidArgumentError> {
+    if step.is_zero() {
+        return Err(InvalidArgumentError::new("증가 단위는 0보다 커야 합니다."));
+    }
+
+    Ok(DateRangeIter {
+        start,
+        end,
+        step,
+        index: 0,
+        finished: false,
+    })
+}
+
+/// 시작(포함) ~ 종료(제외) 날짜 구간
+///
+/// 반개구간(`[start, end)`)이므로 종료 시각이 다른 구간의 시작 시각과 같은 경우 겹치지 않는 것으로 판단한다
+/// (e.g. `10:00 ~ 11:00` 예약과 `11:00 ~ 12:00` 예약). 시작과 종료가 같은 구간은 빈 구간이다.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::NaiveDateTime;
+/// use cliff3_util::date_util::range::DateRange;
+///
+/// let time = |v| NaiveDateTime::parse_from_str(v, "%Y-%m-%d %H:%M").unwrap();
+/// let morning = DateRange::new(time("2024-11-22 09:00"), time("2024-11-22 12:00")).unwrap();
+/// let meeting = DateRange::new(time("2024-11-22 11:00"), time("2024-11-22 13:00")).unwrap();
+///
+/// assert!(morning.contains(&time("2024-11-22 09:00")));
+/// assert!(!morning.contains(&time("2024-11-22 12:00")));
+/// assert!(morning.overlaps(&meeting));
+/// assert_eq!(
+///     Some(DateRange::new(time("2024-11-22 11:00"), time("2024-11-22 12:00")).unwrap()),
+///     morning.intersection(&meeting)
+/// );
+/// assert_eq!(3, morning.duration().num_hours());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DateRange<T> {
+    start: T,
+    end: T,
+}
+
+impl<T: Clone + PartialOrd> DateRange<T> {
+    /// 구간 생성
+    ///
+    /// # Arguments
+    ///
+    /// - `start` - 시작(포함)
+    /// - `end` - 종료(제외)
+    ///
+    /// # Return
+    ///
+    /// - 생성 결과 `Result<DateRange<T>, InvalidArgumentError>`
+    ///
+    /// # Errors
+    ///
+    /// - [InvalidArgumentError] - 종료가 시작 이전일 경우
+    pub fn new(start: T, end: T) -> Result<Self, InvalidArgumentError> {
+        if end < start {
+            return Err(InvalidArgumentError::new("종료는 시작 이후이어야 합니다."));
+        }
+
+        Ok(DateRange { start, end })
+    }
+
+    /// 시작(포함)
+    pub fn start(&self) -> &T {
+        &self.start
+    }
+
+    /// 종료(제외)
+    pub fn end(&self) -> &T {
+        &self.end
+    }
+
+    /// 빈 구간(시작과 종료가 같음) 여부
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// 지정된 값이 구간에 포함(`start <= value < end`)될 경우 `true`
+    pub fn contains(&self, value: &T) -> bool {
+        self.start <= *value && *value < self.end
+    }
+
+    /// 두 구간이 겹칠 경우 `true`. 빈 구간은 다른 구간과 겹치지 않는다.
+    pub fn overlaps(&self, other: &DateRange<T>) -> bool {
+        !self.is_empty() && !other.is_empty() && self.start < other.end && other.start < self.end
+    }
+
+    /// 두 구간의 교집합. 겹치지 않을 경우 `None`
+    pub fn intersection(&self, other: &DateRange<T>) -> Option<DateRange<T>> {
+        if !self.overlaps(other) {
+            return None;
+        }
+
+        let start = if self.start < other.start {
+            &other.start
+        } else {
+            &self.start
+        };
+        let end = if self.end < other.end {
+            &self.end
+        } else {
+            &other.end
+        };
+
+        Some(DateRange {
+            start: start.clone(),
+            end: end.clone(),
+        })
+    }
+}
+
+/// [DateRange::duration] 구현(`signed_duration_since`를 제공하는 type)
+macro_rules! date_range_duration {
+    ($($target:ty),* $(,)?) => {
+        $(
+            impl DateRange<$target> {
+                /// 구간 길이
+                pub fn duration(&self) -> TimeDelta {
+                    self.end.signed_duration_since(self.start)
+                }
+            }
+        )*
+    };
+}
+
+date_range_duration!(NaiveDate, NaiveDateTime);
+
+impl<T: TimeZone> DateRange<DateTime<T>> {
+    /// 구간 길이
+    pub fn duration(&self) -> TimeDelta {
+        self.end.clone().signed_duration_since(self.start.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{NaiveTime, Utc};
+    use chrono_tz::Tz;
+
+    #[test]
+    fn date_range_test() {
+        let start = NaiveDate::from_ymd_opt(2024, 11, 1).unwrap();
+        let end = NaiveDate::from_ymd_opt(2024, 11, 30).unwrap();
+
+        let days: Vec<NaiveDate> = date_range(start, end, DateStep::Days(1)).unwrap().collect();
+
+        assert_eq!(30, days.len());
+        assert_eq!(Some(&end), days.last());
+
+        let weeks: Vec<String> = date_range(start, end, DateStep::Weeks(1))
+            .unwrap()
+            .map(|v| v.format("%d").to_string())
+            .collect();
+
+        assert_eq!(vec!["01", "08", "15", "22", "29"], weeks);
+        assert_eq!(
+            0,
+            date_range(end, start, DateStep::Days(1)).unwrap().count()
+        );
+        assert_eq!(
+            1,
+            date_range(start, start, DateStep::Days(1)).unwrap().count()
+        );
+        assert!(date_range(start, end, DateStep::Months(0)).is_err());
+
+        // 범위를 벗어나는 경우 종료
+        assert_eq!(
+            1,
+            date_range(NaiveDate::MAX, NaiveDate::MAX, DateStep::Days(1))
+                .unwrap()
+                .count()
+        );
+    }
+
+    #[test]
+    fn datetime_range_test() {
+        let start = NaiveDate::from_ymd_opt(2024, 1, 31)
+            .unwrap()
+            .and_time(NaiveTime::from_hms_opt(9, 30, 0).unwrap());
+        let end = start.checked_add_months(Months::new(12)).unwrap();
+        let months: Vec<NaiveDateTime> = date_range(start, end, DateStep::Months(3))
+            .unwrap()
+            .collect();
+
+        assert_eq!(5, months.len());
+        assert_eq!("2024-04-30 09:30:00", months[1].to_string());
+        assert_eq!("2024-07-31 09:30:00", months[2].to_string());
+
+        let start = Utc.with_ymd_and_hms(2024, 3, 9, 7, 30, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 3, 12, 7, 30, 0).unwrap();
+        let utc: Vec<DateTime<Utc>> = date_range(start, end, DateStep::Days(1)).unwrap().collect();
+
+        assert_eq!(4, utc.len());
+
+        // DST 전환(2024-03-10 02:00 -> 03:00) 구간은 지역 시각 기준으로 증가
+        let timezone = Tz::America__New_York;
+        let start = timezone.with_ymd_and_hms(2024, 3, 9, 1, 30, 0).unwrap();
+        let end = timezone.with_ymd_and_hms(2024, 3, 12, 1, 30, 0).unwrap();
+        let local: Vec<String> = date_range(start, end, DateStep::Days(1))
+            .unwrap()
+            .map(|v| v.format("%m-%d %H:%M %Z").to_string())
+            .collect();
+
+        assert_eq!(
+            vec![
+                "03-09 01:30 EST",
+                "03-10 01:30 EST",
+                "03-11 01:30 EDT",
+                "03-12 01:30 EDT"
+            ],
+            local
+        );
+    }
+
+    #[test]
+    fn date_range_struct_test() {
+        let date = |d| NaiveDate::from_ymd_opt(2024, 11, d).unwrap();
+        let range = |s, e| DateRange::new(date(s), date(e)).unwrap();
+        let week = range(18, 25);
+
+        assert!(week.contains(&date(18)));
+        assert!(week.contains(&date(24)));
+        assert!(!week.contains(&date(25)));
+        assert_eq!(7, week.duration().num_days());
+        assert!(DateRange::new(date(25), date(18)).is_err());
+
+        // 맞닿은 구간은 겹치지 않는다
+        assert!(!week.overlaps(&range(25, 30)));
+        assert!(!week.overlaps(&range(10, 18)));
+        assert!(week.overlaps(&range(24, 30)));
+        assert_eq!(Some(range(24, 25)), week.intersection(&range(24, 30)));
+        assert_eq!(Some(range(20, 22)), week.intersection(&range(20, 22)));
+        assert_eq!(Some(week), week.intersection(&range(1, 30)));
+        assert_eq!(None, week.intersection(&range(25, 30)));
+
+        // 빈 구간
+        let empty = range(20, 20);
+
+        assert!(empty.is_empty());
+        assert!(!empty.contains(&date(20)));
+        assert!(!empty.overlaps(&week));
+        assert_eq!(None, week.intersection(&empty));
+
+        let utc = DateRange::new(
+            Utc.with_ymd_and_hms(2024, 11, 22, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2024, 11, 22, 1, 30, 0).unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(90, utc.duration().num_minutes());
+    }
+}
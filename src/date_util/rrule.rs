@@ -0,0 +1,774 @@
+//! 반복 규칙(RRULE) 관련 함수 모음
+//!
+//! [RFC 5545](https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.10)의 반복 규칙 중 아래 항목을 해석하여
+//! 지정된 timezone 기준의 반복 일정을 계산한다.
+//!
+//! - `FREQ` - `DAILY`, `WEEKLY`, `MONTHLY`
+//! - `INTERVAL` - 반복 간격(기본값 `1`)
+//! - `BYDAY` - 요일 목록(`MO,WE,FR`). `MONTHLY`는 순번 지정 가능(`1MO`, `-1FR`)
+//! - `COUNT` - 최대 반복 횟수
+//! - `UNTIL` - 반복 종료 시각(포함). `YYYYMMDD`, `YYYYMMDDTHHMMSS`(지역 시각) 혹은
+//!   `YYYYMMDDTHHMMSSZ`(UTC)
+//!
+//! 주(week)의 시작은 월요일(`WKST=MO`)이며, 시작 시각(`DTSTART`)과 일치하지 않는 일정은 생성하지 않는다.
+//!
+//! ```rust
+//! use chrono::NaiveDateTime;
+//! use chrono_tz::Tz;
+//! use cliff3_util::date_util::rrule::RecurrenceRule;
+//!
+//! // 격주 월, 수요일 4회
+//! let rule = RecurrenceRule::parse("FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,WE;COUNT=4").unwrap();
+//! let start = NaiveDateTime::parse_from_str("2024-11-18 09:00", "%Y-%m-%d %H:%M").unwrap();
+//! let dates: Vec<String> = rule
+//!     .occurrences(&start, Tz::Asia__Seoul)
+//!     .map(|v| v.format("%m-%d %H:%M").to_string())
+//!     .collect();
+//!
+//! assert_eq!(vec!["11-18 09:00", "11-20 09:00", "12-02 09:00", "12-04 09:00"], dates);
+//! ```
+
+use core::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use chrono::{
+    DateTime, Datelike, Days, Months, NaiveDate, NaiveDateTime, Offset, TimeDelta, TimeZone, Utc,
+    Weekday,
+};
+use chrono_tz::Tz;
+
+use crate::date_util::{last_weekday_of_month, nth_weekday_of_month};
+use crate::error::InvalidArgumentError;
+#[cfg(feature = "serde")]
+use crate::error::LibError;
+
+/// 일정이 없는 반복 주기가 연속될 경우 검색을 중단하는 주기 수
+///
+/// 시작 시각에 따라 일정이 생성되지 않는 규칙(e.g. `FREQ=DAILY;INTERVAL=7;BYDAY=TU`를 월요일에 시작)의
+/// 무한 반복을 방지한다.
+const MAX_EMPTY_PERIODS: u32 = 1_000;
+
+/// 반복 주기
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Frequency {
+    /// 매일(`DAILY`)
+    Daily,
+
+    /// 매주(`WEEKLY`)
+    Weekly,
+
+    /// 매월(`MONTHLY`)
+    Monthly,
+}
+
+/// 반복 종료 시각(`UNTIL`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Until {
+    /// 일정의 timezone 기준 지역 시각
+    Local(NaiveDateTime),
+
+    /// UTC 시각
+    Utc(DateTime<Utc>),
+}
+
+/// 해석된 반복 규칙
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecurrenceRule {
+    frequency: Frequency,
+    interval: u32,
+
+    /// (순번, 요일). 순번은 `MONTHLY`에서만 지정 가능하며 음수는 마지막부터의 순번
+    by_day: Vec<(Option<i32>, Weekday)>,
+    count: Option<u32>,
+    until: Option<Until>,
+}
+
+impl RecurrenceRule {
+    /// 반복 규칙 문자열 해석
+    ///
+    /// `RRULE:` 접두어는 생략할 수 있으며 항목 순서는 무관하다.
+    ///
+    /// # Arguments
+    ///
+    /// - `rule` - 반복 규칙 (e.g. `FREQ=MONTHLY;BYDAY=-1FR;UNTIL=20251231`)
+    ///
+    /// # Return
+    ///
+    /// - 해석 결과 `Result<RecurrenceRule, InvalidArgumentError>`
+    ///
+    /// # Errors
+    ///
+    /// - [InvalidArgumentError] - `FREQ` 누락, 지원하지 않는 항목 혹은 값, `COUNT`와 `UNTIL`을 함께 지정한 경우
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cliff3_util::date_util::rrule::{Frequency, RecurrenceRule};
+    ///
+    /// let rule = RecurrenceRule::parse("RRULE:FREQ=MONTHLY;BYDAY=-1FR;COUNT=12").unwrap();
+    ///
+    /// assert_eq!(Frequency::Monthly, rule.frequency());
+    /// assert_eq!(Some(12), rule.count());
+    /// assert!(RecurrenceRule::parse("FREQ=YEARLY").is_err());
+    /// assert!(RecurrenceRule::parse("FREQ=DAILY;COUNT=3;UNTIL=20251231").is_err());
+    /// ```
+    pub fn parse(rule: &str) -> Result<RecurrenceRule, InvalidArgumentError> {
+        let rule = rule.trim();
+        let rule = rule.strip_prefix("RRULE:").unwrap_or(rule);
+        let mut frequency = None;
+        let mut interval = 1;
+        let mut by_day = Vec::new();
+        let mut count = None;
+        let mut until = None;
+
+        for part in rule.split(';').filter(|v| !v.is_empty()) {
+            let (key, value) = part
+                .split_once('=')
+                .ok_or_else(|| rule_error("잘못된 항목", part))?;
+
+            match key.to_ascii_uppercase().as_str() {
+                "FREQ" => {
+                    frequency = Some(match value.to_ascii_uppercase().as_str() {
+                        "DAILY" => Frequency::Daily,
+                        "WEEKLY" => Frequency::Weekly,
+                        "MONTHLY" => Frequency::Monthly,
+                        _ => return Err(rule_error("지원하지 않는 FREQ", value)),
+                    })
+                }
+                "INTERVAL" => interval = parse_positive(value, "INTERVAL")?,
+                "COUNT" => count = Some(parse_positive(value, "COUNT")?),
+                "UNTIL" => until = Some(parse_until(value)?),
+                "BYDAY" => {
+                    by_day = value
+                        .split(',')
+                        .map(parse_by_day)
+                        .collect::<Result<_, _>>()?
+                }
+                _ => return Err(rule_error("지원하지 않는 항목", key)),
+            }
+        }
+
+        let frequency =
+            frequency.ok_or_else(|| InvalidArgumentError::new("FREQ 항목이 누락되었습니다."))?;
+
+        if count.is_some() && until.is_some() {
+            return Err(InvalidArgumentError::new(
+                "COUNT와 UNTIL은 함께 지정할 수 없습니다.",
+            ));
+        }
+
+        if frequency != Frequency::Monthly && by_day.iter().any(|(n, _)| n.is_some()) {
+            return Err(InvalidArgumentError::new(
+                "BYDAY 순번은 FREQ=MONTHLY에서만 지정할 수 있습니다.",
+            ));
+        }
+
+        Ok(RecurrenceRule {
+            frequency,
+            interval,
+            by_day,
+            count,
+            until,
+        })
+    }
+
+    /// 반복 주기
+    pub fn frequency(&self) -> Frequency {
+        self.frequency
+    }
+
+    /// 반복 간격
+    pub fn interval(&self) -> u32 {
+        self.interval
+    }
+
+    /// 최대 반복 횟수
+    pub fn count(&self) -> Option<u32> {
+        self.count
+    }
+
+    /// 반복 종료 시각
+    pub fn until(&self) -> Option<Until> {
+        self.until
+    }
+
+    /// 지정된 시작 시각부터의 반복 일정 iterator 생성
+    ///
+    /// 일정은 `start`의 시각(시, 분, 초)으로 생성되며 `timezone`의 지역 시각으로 해석한다. 일광 절약
+    /// 시간(DST) 전환으로 중복되는 시각은 이른 시각을, 존재하지 않는 시각은 전환 이전의 offset으로 해석한
+    /// 시각(e.g. 02:30이 존재하지 않을 경우 03:30)을 사용한다.
+    ///
+    /// # Arguments
+    ///
+    /// - `start` - 시작 시각(`DTSTART`, 지역 시각)
+    /// - `timezone` - 일정 timezone [Tz]
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDateTime;
+    /// use chrono_tz::Tz;
+    /// use cliff3_util::date_util::rrule::RecurrenceRule;
+    ///
+    /// let rule: RecurrenceRule = "FREQ=MONTHLY;BYDAY=-1FR;UNTIL=20250331".parse().unwrap();
+    /// let start = NaiveDateTime::parse_from_str("2025-01-01 18:00", "%Y-%m-%d %H:%M").unwrap();
+    /// let dates: Vec<String> = rule
+    ///     .occurrences(&start, Tz::Asia__Seoul)
+    ///     .map(|v| v.format("%Y-%m-%d").to_string())
+    ///     .collect();
+    ///
+    /// assert_eq!(vec!["2025-01-31", "2025-02-28", "2025-03-28"], dates);
+    /// ```
+    pub fn occurrences(&self, start: &NaiveDateTime, timezone: Tz) -> Occurrences<'_> {
+        let period = match self.frequency {
+            Frequency::Daily => Some(start.date()),
+            Frequency::Weekly => start
+                .date()
+                .checked_sub_days(Days::new(start.weekday().num_days_from_monday() as u64)),
+            Frequency::Monthly => start.date().with_day(1),
+        };
+
+        Occurrences {
+            rule: self,
+            start: *start,
+            timezone,
+            period,
+            pending: Vec::new(),
+            emitted: 0,
+        }
+    }
+
+    /// 반복 주기 하나에 해당하는 일정 후보(날짜 순)
+    fn candidates(&self, period: &NaiveDate, start: &NaiveDateTime) -> Vec<NaiveDate> {
+        let mut dates: Vec<NaiveDate> = match self.frequency {
+            Frequency::Daily => {
+                if self.by_day.is_empty() || self.by_day.iter().any(|(_, w)| *w == period.weekday())
+                {
+                    vec![*period]
+                } else {
+                    vec![]
+                }
+            }
+            Frequency::Weekly => {
+                let weekdays: Vec<Weekday> = if self.by_day.is_empty() {
+                    vec![start.weekday()]
+                } else {
+                    self.by_day.iter().map(|(_, w)| *w).collect()
+                };
+
+                weekdays
+                    .iter()
+                    .filter_map(|w| {
+                        period.checked_add_days(Days::new(w.num_days_from_monday() as u64))
+                    })
+                    .collect()
+            }
+            Frequency::Monthly => {
+                if self.by_day.is_empty() {
+                    // 해당 일자가 없는 달은 건너뛴다
+                    period.with_day(start.day()).into_iter().collect()
+                } else {
+                    self.by_day
+                        .iter()
+                        .flat_map(|(n, w)| monthly_weekdays(period, *n, *w))
+                        .collect()
+                }
+            }
+        };
+
+        dates.sort();
+        dates.dedup();
+        dates
+    }
+
+    fn next_period(&self, period: &NaiveDate) -> Option<NaiveDate> {
+        match self.frequency {
+            Frequency::Daily => period.checked_add_days(Days::new(self.interval as u64)),
+            Frequency::Weekly => period.checked_add_days(Days::new(self.interval as u64 * 7)),
+            Frequency::Monthly => period.checked_add_months(Months::new(self.interval)),
+        }
+    }
+}
+
+impl FromStr for RecurrenceRule {
+    type Err = InvalidArgumentError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        RecurrenceRule::parse(s)
+    }
+}
+
+/// RFC 5545 형식(`RRULE:` 접두어 제외)으로 표현
+impl Display for RecurrenceRule {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let frequency = match self.frequency {
+            Frequency::Daily => "DAILY",
+            Frequency::Weekly => "WEEKLY",
+            Frequency::Monthly => "MONTHLY",
+        };
+
+        write!(f, "FREQ={}", frequency)?;
+
+        if self.interval != 1 {
+            write!(f, ";INTERVAL={}", self.interval)?;
+        }
+
+        if !self.by_day.is_empty() {
+            let by_day: Vec<String> = self
+                .by_day
+                .iter()
+                .map(|(n, w)| {
+                    let code = &WEEKDAY_CODES[w.num_days_from_monday() as usize];
+
+                    n.map_or_else(|| code.to_string(), |n| format!("{}{}", n, code))
+                })
+                .collect();
+
+            write!(f, ";BYDAY={}", by_day.join(","))?;
+        }
+
+        if let Some(count) = self.count {
+            write!(f, ";COUNT={}", count)?;
+        }
+
+        match self.until {
+            Some(Until::Local(v)) => write!(f, ";UNTIL={}", v.format("%Y%m%dT%H%M%S")),
+            Some(Until::Utc(v)) => write!(f, ";UNTIL={}", v.format("%Y%m%dT%H%M%SZ")),
+            None => Ok(()),
+        }
+    }
+}
+
+/// [RecurrenceRule::occurrences]가 반환하는 iterator
+#[derive(Debug, Clone)]
+pub struct Occurrences<'a> {
+    rule: &'a RecurrenceRule,
+    start: NaiveDateTime,
+    timezone: Tz,
+
+    /// 다음에 계산할 반복 주기의 시작일. 종료된 경우 `None`
+    period: Option<NaiveDate>,
+
+    /// 현재 주기의 남은 일정(역순)
+    pending: Vec<NaiveDateTime>,
+    emitted: u32,
+}
+
+impl Occurrences<'_> {
+    /// 다음 일정의 지역 시각
+    fn next_local(&mut self) -> Option<NaiveDateTime> {
+        let mut empty_periods = 0;
+
+        while self.pending.is_empty() {
+            let period = self.period?;
+
+            self.pending = self
+                .rule
+                .candidates(&period, &self.start)
+                .into_iter()
+                .map(|d| d.and_time(self.start.time()))
+                .filter(|v| *v >= self.start)
+                .rev()
+                .collect();
+            self.period = self.rule.next_period(&period);
+
+            if self.pending.is_empty() {
+                empty_periods += 1;
+
+                if empty_periods >= MAX_EMPTY_PERIODS {
+                    self.period = None;
+                }
+            }
+        }
+
+        self.pending.pop()
+    }
+
+    fn finish(&mut self) {
+        self.period = None;
+        self.pending.clear();
+    }
+}
+
+impl Iterator for Occurrences<'_> {
+    type Item = DateTime<Tz>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rule.count.is_some_and(|c| self.emitted >= c) {
+            self.finish();
+
+            return None;
+        }
+
+        let local = self.next_local()?;
+        let Some(datetime) = resolve_local(&self.timezone, &local) else {
+            self.finish();
+
+            return None;
+        };
+
+        let exceeded = match self.rule.until {
+            Some(Until::Local(until)) => local > until,
+            Some(Until::Utc(until)) => datetime.with_timezone(&Utc) > until,
+            None => false,
+        };
+
+        if exceeded {
+            self.finish();
+
+            return None;
+        }
+
+        self.emitted += 1;
+
+        Some(datetime)
+    }
+}
+
+const WEEKDAY_CODES: [&str; 7] = ["MO", "TU", "WE", "TH", "FR", "SA", "SU"];
+
+/// 지역 시각을 timezone의 시각으로 변환. 존재하지 않는 시각은 전환 이전의 offset으로 해석한다.
+fn resolve_local(timezone: &Tz, local: &NaiveDateTime) -> Option<DateTime<Tz>> {
+    if let Some(v) = timezone.from_local_datetime(local).earliest() {
+        return Some(v);
+    }
+
+    let before = local.checked_sub_signed(TimeDelta::days(1))?;
+    let offset = timezone.offset_from_utc_datetime(&before).fix();
+
+    local
+        .checked_sub_offset(offset)
+        .map(|utc| timezone.from_utc_datetime(&utc))
+}
+
+/// 해당 월의 지정된 요일 목록. 순번이 없을 경우 모든 해당 요일
+fn monthly_weekdays(
+    first_day: &NaiveDate,
+    ordinal: Option<i32>,
+    weekday: Weekday,
+) -> Vec<NaiveDate> {
+    let (year, month) = (first_day.year(), first_day.month());
+
+    match ordinal {
+        None => (1..=5)
+            .filter_map(|n| nth_weekday_of_month(year, month, weekday, n).ok())
+            .collect(),
+        Some(n) if n > 0 => nth_weekday_of_month(year, month, weekday, n.unsigned_abs())
+            .ok()
+            .into_iter()
+            .collect(),
+        Some(n) => last_weekday_of_month(year, month, weekday)
+            .ok()
+            .and_then(|last| last.checked_sub_days(Days::new((n.unsigned_abs() as u64 - 1) * 7)))
+            .filter(|d| d.month() == month)
+            .into_iter()
+            .collect(),
+    }
+}
+
+fn parse_positive(value: &str, name: &str) -> Result<u32, InvalidArgumentError> {
+    value
+        .parse::<u32>()
+        .ok()
+        .filter(|v| *v > 0)
+        .ok_or_else(|| rule_error(&format!("잘못된 {}", name), value))
+}
+
+fn parse_until(value: &str) -> Result<Until, InvalidArgumentError> {
+    let invalid = || rule_error("잘못된 UNTIL", value);
+
+    if let Some(utc) = value.strip_suffix('Z') {
+        return NaiveDateTime::parse_from_str(utc, "%Y%m%dT%H%M%S")
+            .map(|v| Until::Utc(Utc.from_utc_datetime(&v)))
+            .map_err(|_| invalid());
+    }
+
+    if value.contains('T') {
+        return NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S")
+            .map(Until::Local)
+            .map_err(|_| invalid());
+    }
+
+    // 날짜만 지정된 경우 해당 일자 전체 포함
+    NaiveDate::parse_from_str(value, "%Y%m%d")
+        .ok()
+        .and_then(|d| d.and_hms_opt(23, 59, 59))
+        .map(Until::Local)
+        .ok_or_else(invalid)
+}
+
+fn parse_by_day(value: &str) -> Result<(Option<i32>, Weekday), InvalidArgumentError> {
+    let value = value.trim().to_ascii_uppercase();
+    let invalid = || rule_error("잘못된 BYDAY", &value);
+    let split = value.len().checked_sub(2).ok_or_else(invalid)?;
+    let (ordinal, code) = (value.get(..split).ok_or_else(invalid)?, &value[split..]);
+    let weekday = WEEKDAY_CODES
+        .iter()
+        .position(|v| *v == code)
+        .and_then(|v| Weekday::try_from(v as u8).ok())
+        .ok_or_else(invalid)?;
+
+    if ordinal.is_empty() {
+        return Ok((None, weekday));
+    }
+
+    match ordinal.parse::<i32>() {
+        Ok(n) if n != 0 && n.abs() <= 5 => Ok((Some(n), weekday)),
+        _ => Err(invalid()),
+    }
+}
+
+fn rule_error(reason: &str, value: &str) -> InvalidArgumentError {
+    InvalidArgumentError::new(&format!("{} 입니다({}).", reason, value))
+}
+
+/// RFC 5545 형식 문자열로 직렬화
+#[cfg(feature = "serde")]
+impl serde::Serialize for RecurrenceRule {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for RecurrenceRule {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+
+        RecurrenceRule::parse(&value).map_err(|e| serde::de::Error::custom(e.get_message()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveTime;
+
+    fn local(v: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(v, "%Y-%m-%d %H:%M").unwrap()
+    }
+
+    fn dates(rule: &str, start: &str, tz: Tz, take: usize) -> Vec<String> {
+        RecurrenceRule::parse(rule)
+            .unwrap()
+            .occurrences(&local(start), tz)
+            .take(take)
+            .map(|v| v.format("%Y-%m-%d %H:%M %Z").to_string())
+            .collect()
+    }
+
+    #[test]
+    fn parse_test() {
+        let rule =
+            RecurrenceRule::parse("freq=weekly;interval=2;byday=mo,fr;until=20241231T000000Z")
+                .unwrap();
+
+        assert_eq!(Frequency::Weekly, rule.frequency());
+        assert_eq!(2, rule.interval());
+        assert_eq!(None, rule.count());
+        assert_eq!(
+            Some(Until::Utc(
+                Utc.with_ymd_and_hms(2024, 12, 31, 0, 0, 0).unwrap()
+            )),
+            rule.until()
+        );
+        assert_eq!(
+            "FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,FR;UNTIL=20241231T000000Z",
+            rule.to_string()
+        );
+        assert_eq!(
+            "FREQ=MONTHLY;BYDAY=2TU,-1FR;COUNT=3",
+            RecurrenceRule::parse("RRULE:FREQ=MONTHLY;BYDAY=+2TU,-1FR;COUNT=3")
+                .unwrap()
+                .to_string()
+        );
+        assert_eq!(
+            "FREQ=DAILY;UNTIL=20241231T235959",
+            RecurrenceRule::parse("FREQ=DAILY;UNTIL=20241231")
+                .unwrap()
+                .to_string()
+        );
+
+        for invalid in [
+            "",
+            "INTERVAL=2",
+            "FREQ=YEARLY",
+            "FREQ=DAILY;INTERVAL=0",
+            "FREQ=DAILY;COUNT=-1",
+            "FREQ=DAILY;BYDAY=XX",
+            "FREQ=DAILY;BYDAY=M",
+            "FREQ=WEEKLY;BYDAY=1MO",
+            "FREQ=MONTHLY;BYDAY=6MO",
+            "FREQ=MONTHLY;BYDAY=0MO",
+            "FREQ=DAILY;UNTIL=2024-12-31",
+            "FREQ=DAILY;COUNT=1;UNTIL=20241231",
+            "FREQ=DAILY;BYMONTH=1",
+            "FREQ",
+        ] {
+            assert!(RecurrenceRule::parse(invalid).is_err(), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn daily_test() {
+        assert_eq!(
+            vec![
+                "2024-11-22 09:00 KST",
+                "2024-11-25 09:00 KST",
+                "2024-11-26 09:00 KST"
+            ],
+            dates(
+                "FREQ=DAILY;BYDAY=MO,TU,WE,TH,FR",
+                "2024-11-22 09:00",
+                Tz::Asia__Seoul,
+                3
+            )
+        );
+        assert_eq!(
+            vec!["2024-12-30 00:00 UTC", "2025-01-02 00:00 UTC"],
+            dates(
+                "FREQ=DAILY;INTERVAL=3;UNTIL=20250104",
+                "2024-12-30 00:00",
+                Tz::UTC,
+                10
+            )
+        );
+        // 일치하는 일정이 없는 규칙
+        assert!(dates(
+            "FREQ=DAILY;INTERVAL=7;BYDAY=TU",
+            "2024-11-18 00:00",
+            Tz::UTC,
+            1
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn weekly_test() {
+        // 시작일 이전 요일은 제외
+        assert_eq!(
+            vec![
+                "2024-11-22 18:30 KST",
+                "2024-11-25 18:30 KST",
+                "2024-11-29 18:30 KST"
+            ],
+            dates(
+                "FREQ=WEEKLY;BYDAY=FR,MO",
+                "2024-11-22 18:30",
+                Tz::Asia__Seoul,
+                3
+            )
+        );
+        assert_eq!(
+            vec!["2024-11-22 10:00 UTC", "2024-12-06 10:00 UTC"],
+            dates(
+                "FREQ=WEEKLY;INTERVAL=2;COUNT=2",
+                "2024-11-22 10:00",
+                Tz::UTC,
+                5
+            )
+        );
+    }
+
+    #[test]
+    fn monthly_test() {
+        // 31일이 없는 달은 건너뛴다
+        assert_eq!(
+            vec![
+                "2024-01-31 12:00 UTC",
+                "2024-03-31 12:00 UTC",
+                "2024-05-31 12:00 UTC"
+            ],
+            dates("FREQ=MONTHLY", "2024-01-31 12:00", Tz::UTC, 3)
+        );
+        assert_eq!(
+            vec![
+                "2024-11-12 10:00 UTC",
+                "2024-11-29 10:00 UTC",
+                "2024-12-10 10:00 UTC",
+                "2024-12-27 10:00 UTC"
+            ],
+            dates(
+                "FREQ=MONTHLY;BYDAY=2TU,-1FR",
+                "2024-11-01 10:00",
+                Tz::UTC,
+                4
+            )
+        );
+        assert_eq!(
+            vec!["2025-03-03 10:00 UTC", "2025-03-10 10:00 UTC"],
+            dates(
+                "FREQ=MONTHLY;INTERVAL=3;BYDAY=MO;COUNT=2",
+                "2025-03-01 10:00",
+                Tz::UTC,
+                10
+            )
+        );
+        assert_eq!(
+            vec!["2024-09-17 10:00 UTC", "2024-12-24 10:00 UTC"],
+            dates(
+                "FREQ=MONTHLY;INTERVAL=3;BYDAY=-2TU;COUNT=2",
+                "2024-09-01 10:00",
+                Tz::UTC,
+                10
+            )
+        );
+        assert_eq!(
+            vec!["2024-12-30 10:00 UTC"],
+            dates("FREQ=MONTHLY;BYDAY=5MO", "2024-11-01 10:00", Tz::UTC, 1)
+        );
+    }
+
+    #[test]
+    fn dst_test() {
+        // 2024-03-10 02:30(EST -> EDT) 존재하지 않는 시각은 03:30으로 해석
+        assert_eq!(
+            vec![
+                "2024-03-09 02:30 EST",
+                "2024-03-10 03:30 EDT",
+                "2024-03-11 02:30 EDT"
+            ],
+            dates(
+                "FREQ=DAILY;COUNT=3",
+                "2024-03-09 02:30",
+                Tz::America__New_York,
+                5
+            )
+        );
+        // 2024-11-03 01:30 중복되는 시각은 이른 시각(EDT)
+        assert_eq!(
+            vec!["2024-11-03 01:30 EDT", "2024-11-04 01:30 EST"],
+            dates(
+                "FREQ=DAILY;COUNT=2",
+                "2024-11-03 01:30",
+                Tz::America__New_York,
+                5
+            )
+        );
+
+        // UTC 기준 종료 시각
+        let rule = RecurrenceRule::parse("FREQ=DAILY;UNTIL=20241123T000000Z").unwrap();
+        let result: Vec<DateTime<Tz>> = rule
+            .occurrences(&local("2024-11-21 09:00"), Tz::Asia__Seoul)
+            .collect();
+
+        assert_eq!(3, result.len());
+    }
+
+    #[test]
+    fn range_limit_test() {
+        let rule = RecurrenceRule::parse("FREQ=MONTHLY").unwrap();
+
+        assert_eq!(
+            1,
+            rule.occurrences(&NaiveDate::MAX.and_time(NaiveTime::MIN), Tz::UTC)
+                .count()
+        );
+        assert_eq!(
+            0,
+            rule.occurrences(&NaiveDateTime::MAX, Tz::America__New_York)
+                .count()
+        );
+    }
+}
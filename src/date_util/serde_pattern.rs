@@ -0,0 +1,304 @@
+//! 날짜 및 시간 패턴 기반 serde 직렬화 함수 모음
+//!
+//! `serde` feature 활성화시 사용 가능하며, [DateTime<Utc>] 및 [NaiveDateTime]을 지정된 패턴과 timezone의
+//! 문자열로 직렬화(역직렬화)한다. `#[serde(with = "...")]`에 사용할 module은 [crate::serde_pattern] macro로
+//! 생성한다.
+//!
+//! ```rust
+//! use chrono::{DateTime, NaiveDateTime, Utc};
+//! use serde::{Deserialize, Serialize};
+//!
+//! cliff3_util::serde_pattern!(kst, "%Y-%m-%d %H:%M:%S", chrono_tz::Tz::Asia__Seoul);
+//! cliff3_util::serde_pattern!(compact, "%Y%m%d%H%M%S");
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Event {
+//!     #[serde(with = "kst")]
+//!     created_at: DateTime<Utc>,
+//!
+//!     #[serde(with = "compact")]
+//!     local: NaiveDateTime,
+//! }
+//!
+//! let event: Event = serde_json::from_str(
+//!     r#"{"created_at":"2024-11-22 10:29:48","local":"20241122102948"}"#,
+//! )
+//! .unwrap();
+//!
+//! assert_eq!("2024-11-22 01:29:48 UTC", event.created_at.to_string());
+//! assert_eq!(
+//!     r#"{"created_at":"2024-11-22 10:29:48","local":"20241122102948"}"#,
+//!     serde_json::to_string(&event).unwrap()
+//! );
+//! ```
+
+use alloc::string::String;
+use core::fmt::Write;
+
+use chrono::{DateTime, NaiveDateTime, Utc};
+use chrono_tz::Tz;
+use serde::{de, ser, Deserialize, Deserializer, Serializer};
+
+use super::{cache, DateTimeConverter};
+use crate::error::LibError;
+
+#[doc(hidden)]
+pub mod __private {
+    pub use chrono::{DateTime, NaiveDateTime, Utc};
+    pub use serde::{Deserializer, Serializer};
+}
+
+/// UTC [DateTime]을 지정된 timezone의 지역 시각 문자열로 직렬화
+///
+/// # Arguments
+///
+/// - `value` - 직렬화 대상
+/// - `pattern` - 날짜 및 시간 패턴 (e.g. '%Y-%m-%d %H:%M:%S')
+/// - `timezone` - 문자열로 표현할 timezone [Tz]
+/// - `serializer` - [Serializer]
+///
+/// # Errors
+///
+/// - `S::Error` - 잘못된 패턴
+pub fn serialize_utc<S: Serializer>(
+    value: &DateTime<Utc>,
+    pattern: &str,
+    timezone: &Tz,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serialize_naive(
+        &value.with_timezone(timezone).naive_local(),
+        pattern,
+        serializer,
+    )
+}
+
+/// 지정된 timezone의 지역 시각 문자열을 UTC [DateTime]으로 역직렬화([local_datetime_to_utc](super::local_datetime_to_utc) 참고)
+///
+/// # Arguments
+///
+/// - `pattern` - 날짜 및 시간 패턴 (e.g. '%Y-%m-%d %H:%M:%S')
+/// - `timezone` - 문자열의 timezone [Tz]
+/// - `deserializer` - [Deserializer]
+///
+/// # Errors
+///
+/// - `D::Error` - 잘못된 날짜 및 시간 형식 혹은 패턴
+pub fn deserialize_utc<'de, D: Deserializer<'de>>(
+    pattern: &str,
+    timezone: &Tz,
+    deserializer: D,
+) -> Result<DateTime<Utc>, D::Error> {
+    let value = String::deserialize(deserializer)?;
+
+    DateTimeConverter::new(pattern, *timezone)
+        .and_then(|converter| converter.to_utc(&value))
+        .map_err(|e| de::Error::custom(e.get_message()))
+}
+
+/// [NaiveDateTime]을 지정된 패턴의 문자열로 직렬화
+///
+/// # Arguments
+///
+/// - `value` - 직렬화 대상
+/// - `pattern` - 날짜 및 시간 패턴 (e.g. '%Y-%m-%d %H:%M:%S')
+/// - `serializer` - [Serializer]
+///
+/// # Errors
+///
+/// - `S::Error` - 잘못된 패턴
+pub fn serialize_naive<S: Serializer>(
+    value: &NaiveDateTime,
+    pattern: &str,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let items = cache::compiled_pattern(pattern)
+        .map_err(|e| ser::Error::custom(format_args!("잘못된 패턴 입니다. ({e})")))?;
+    let mut formatted = String::new();
+
+    // offset 등 NaiveDateTime으로 표현할 수 없는 항목은 format 오류를 반환한다
+    write!(formatted, "{}", value.format_with_items(items.iter()))
+        .map_err(|_| ser::Error::custom("날짜 및 시간을 패턴으로 표현할 수 없습니다."))?;
+
+    serializer.serialize_str(&formatted)
+}
+
+/// 지정된 패턴의 문자열을 [NaiveDateTime]으로 역직렬화
+///
+/// # Arguments
+///
+/// - `pattern` - 날짜 및 시간 패턴 (e.g. '%Y-%m-%d %H:%M:%S')
+/// - `deserializer` - [Deserializer]
+///
+/// # Errors
+///
+/// - `D::Error` - 잘못된 날짜 및 시간 형식 혹은 패턴
+pub fn deserialize_naive<'de, D: Deserializer<'de>>(
+    pattern: &str,
+    deserializer: D,
+) -> Result<NaiveDateTime, D::Error> {
+    let value = String::deserialize(deserializer)?;
+
+    cache::parse_datetime(&value, pattern).map_err(de::Error::custom)
+}
+
+/// `#[serde(with = "...")]`에 사용할 날짜 및 시간 패턴 직렬화 module 생성
+///
+/// - `serde_pattern!(name, pattern, timezone)` - [DateTime<Utc>](chrono::DateTime)을 `timezone`의 지역 시각으로
+///   표현([serialize_utc], [deserialize_utc])
+/// - `serde_pattern!(name, pattern)` - [NaiveDateTime](chrono::NaiveDateTime)을 그대로 표현([serialize_naive],
+///   [deserialize_naive])
+///
+/// `timezone` 표현식은 생성된 module 내에서 평가되며, 상위 module의 `use` 선언을 사용할 수 있다. 함수 내부에서
+/// 생성할 경우 함수 내 `use` 선언은 사용할 수 없으므로 전체 경로를 지정한다.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::{DateTime, Utc};
+/// use serde::Serialize;
+///
+/// cliff3_util::serde_pattern!(
+///     pub(crate) new_york,
+///     "%m/%d/%Y %I:%M %p",
+///     chrono_tz::Tz::America__New_York
+/// );
+///
+/// #[derive(Serialize)]
+/// struct Meeting {
+///     #[serde(with = "new_york")]
+///     start: DateTime<Utc>,
+/// }
+///
+/// let meeting = Meeting {
+///     start: "2024-07-01T16:30:00Z".parse().unwrap(),
+/// };
+///
+/// assert_eq!(
+///     r#"{"start":"07/01/2024 12:30 PM"}"#,
+///     serde_json::to_string(&meeting).unwrap()
+/// );
+/// ```
+#[macro_export]
+macro_rules! serde_pattern {
+    ($vis:vis $name:ident, $pattern:expr, $timezone:expr $(,)?) => {
+        #[allow(dead_code)]
+        $vis mod $name {
+            #[allow(unused_imports)]
+            use super::*;
+            use $crate::date_util::serde_pattern::__private as __serde_pattern;
+
+            pub fn serialize<S: __serde_pattern::Serializer>(
+                value: &__serde_pattern::DateTime<__serde_pattern::Utc>,
+                serializer: S,
+            ) -> ::core::result::Result<S::Ok, S::Error> {
+                $crate::date_util::serde_pattern::serialize_utc(
+                    value,
+                    $pattern,
+                    &$timezone,
+                    serializer,
+                )
+            }
+
+            pub fn deserialize<'de, D: __serde_pattern::Deserializer<'de>>(
+                deserializer: D,
+            ) -> ::core::result::Result<__serde_pattern::DateTime<__serde_pattern::Utc>, D::Error>
+            {
+                $crate::date_util::serde_pattern::deserialize_utc($pattern, &$timezone, deserializer)
+            }
+        }
+    };
+    ($vis:vis $name:ident, $pattern:expr $(,)?) => {
+        #[allow(dead_code)]
+        $vis mod $name {
+            use $crate::date_util::serde_pattern::__private as __serde_pattern;
+
+            pub fn serialize<S: __serde_pattern::Serializer>(
+                value: &__serde_pattern::NaiveDateTime,
+                serializer: S,
+            ) -> ::core::result::Result<S::Ok, S::Error> {
+                $crate::date_util::serde_pattern::serialize_naive(value, $pattern, serializer)
+            }
+
+            pub fn deserialize<'de, D: __serde_pattern::Deserializer<'de>>(
+                deserializer: D,
+            ) -> ::core::result::Result<__serde_pattern::NaiveDateTime, D::Error> {
+                $crate::date_util::serde_pattern::deserialize_naive($pattern, deserializer)
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+
+    crate::serde_pattern!(kst, "%Y-%m-%d %H:%M:%S", Tz::Asia__Seoul);
+    crate::serde_pattern!(new_york, "%Y-%m-%d %H:%M", Tz::America__New_York);
+    crate::serde_pattern!(compact, "%Y%m%d%H%M%S");
+    crate::serde_pattern!(with_offset, "%Y-%m-%d %H:%M:%S %z");
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Record {
+        #[serde(with = "kst")]
+        utc: DateTime<Utc>,
+
+        #[serde(with = "compact")]
+        naive: NaiveDateTime,
+    }
+
+    #[test]
+    fn round_trip_test() {
+        let record = Record {
+            utc: Utc.with_ymd_and_hms(2024, 11, 22, 1, 29, 48).unwrap(),
+            naive: NaiveDateTime::parse_from_str("2024-11-22 10:29:48", "%Y-%m-%d %H:%M:%S")
+                .unwrap(),
+        };
+        let json = serde_json::to_string(&record).unwrap();
+
+        assert_eq!(
+            r#"{"utc":"2024-11-22 10:29:48","naive":"20241122102948"}"#,
+            json
+        );
+        assert_eq!(record, serde_json::from_str(&json).unwrap());
+        assert!(
+            serde_json::from_str::<Record>(r#"{"utc":"2024-11-22","naive":"20241122102948"}"#)
+                .is_err()
+        );
+        assert!(serde_json::from_str::<Record>(
+            r#"{"utc":"2024-11-22 10:29:48","naive":"2024-11-22"}"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn dst_test() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Local(#[serde(with = "new_york")] DateTime<Utc>);
+
+        // 일광 절약 시간(DST) 적용 여부에 따라 offset이 달라진다
+        let summer: Local = serde_json::from_str(r#""2024-07-01 12:00""#).unwrap();
+
+        assert_eq!("2024-07-01 16:00:00 UTC", summer.0.to_string());
+        assert_eq!(
+            r#""2024-07-01 12:00""#,
+            serde_json::to_string(&summer).unwrap()
+        );
+
+        let winter: Local = serde_json::from_str(r#""2024-12-01 12:00""#).unwrap();
+
+        assert_eq!("2024-12-01 17:00:00 UTC", winter.0.to_string());
+    }
+
+    #[test]
+    fn unrepresentable_pattern_test() {
+        #[derive(Serialize)]
+        struct Offset(#[serde(with = "with_offset")] NaiveDateTime);
+
+        // NaiveDateTime은 offset(%z)을 표현할 수 없다
+        assert!(serde_json::to_string(&Offset(NaiveDateTime::MIN)).is_err());
+    }
+}
@@ -0,0 +1,636 @@
+//! Debounce/throttle 관련 함수 모음
+//!
+//! - [Debouncer] - 마지막 event 이후 지정된 시간 동안 추가 event가 없을 경우 모아둔 event를 한번에
+//!   처리(e.g. 파일 변경 event 묶음 처리)
+//! - [Throttler] - 지정된 간격마다 최대 한번만 실행(e.g. 알림 발송 제한)
+//!
+//! [Debouncer::push] 및 [Throttler::try_acquire]는 대기하지 않으므로 비동기 코드에서도 사용할 수 있으며,
+//! `async` feature 활성화시 tokio task 기반의 [AsyncDebouncer] 및 [Throttler::wait]를 사용할 수 있다.
+
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use crate::error::InvalidArgumentError;
+
+type Callback<T> = Box<dyn Fn(Vec<T>) + Send + 'static>;
+
+/// 지정된 간격마다 최대 한번만 실행을 허용하는 throttler
+///
+/// 최초 호출은 즉시 허용된다(leading edge).
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use cliff3_util::debounce_util::Throttler;
+///
+/// let throttler = Throttler::new(Duration::from_secs(60)).unwrap();
+///
+/// assert_eq!(Some(1), throttler.call(|| 1));
+/// assert_eq!(None, throttler.call(|| 2));
+/// assert_eq!(1, throttler.suppressed());
+/// ```
+#[derive(Debug)]
+pub struct Throttler {
+    interval: Duration,
+
+    state: Mutex<ThrottleState>,
+}
+
+#[derive(Debug, Default)]
+struct ThrottleState {
+    /// 마지막 실행 시각
+    last: Option<Instant>,
+
+    /// 실행이 거부된 횟수
+    suppressed: u64,
+}
+
+impl Throttler {
+    /// [Throttler] 생성
+    ///
+    /// # Arguments
+    ///
+    /// - `interval` - 최소 실행 간격
+    ///
+    /// # Errors
+    ///
+    /// - [InvalidArgumentError] - `interval`이 0일 경우
+    pub fn new(interval: Duration) -> Result<Self, InvalidArgumentError> {
+        if interval.is_zero() {
+            return Err(InvalidArgumentError::from("실행 간격은 0보다 커야 합니다."));
+        }
+
+        Ok(Throttler {
+            interval,
+            state: Mutex::new(ThrottleState::default()),
+        })
+    }
+
+    /// 최소 실행 간격 반환
+    #[inline]
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// 실행 허용 여부 반환. 허용된 경우 실행한 것으로 기록한다.
+    pub fn try_acquire(&self) -> bool {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let now = Instant::now();
+
+        match state.last {
+            Some(last) if now.duration_since(last) < self.interval => {
+                state.suppressed += 1;
+
+                false
+            }
+            _ => {
+                state.last = Some(now);
+
+                true
+            }
+        }
+    }
+
+    /// 실행이 허용된 경우에만 `f` 실행
+    ///
+    /// # Return
+    ///
+    /// - 실행 결과. 실행하지 않은 경우 `None`
+    pub fn call<R, F: FnOnce() -> R>(&self, f: F) -> Option<R> {
+        if self.try_acquire() {
+            Some(f())
+        } else {
+            None
+        }
+    }
+
+    /// 다음 실행이 허용될 때까지 남은 시간 반환
+    pub fn remaining(&self) -> Duration {
+        let state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+
+        state.last.map_or(Duration::ZERO, |last| {
+            self.interval.saturating_sub(last.elapsed())
+        })
+    }
+
+    /// 실행이 거부된 횟수 반환
+    pub fn suppressed(&self) -> u64 {
+        self.state
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .suppressed
+    }
+
+    /// 실행이 허용될 때까지 현재 thread 대기 후 실행한 것으로 기록
+    pub fn wait_blocking(&self) {
+        while !self.try_acquire_silently() {
+            thread::sleep(self.remaining());
+        }
+    }
+
+    /// 실행이 허용될 때까지 대기 후 실행한 것으로 기록
+    #[cfg(feature = "async")]
+    pub async fn wait(&self) {
+        while !self.try_acquire_silently() {
+            tokio::time::sleep(self.remaining()).await;
+        }
+    }
+
+    /// 거부 횟수를 기록하지 않는 [Throttler::try_acquire]
+    fn try_acquire_silently(&self) -> bool {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let now = Instant::now();
+
+        match state.last {
+            Some(last) if now.duration_since(last) < self.interval => false,
+            _ => {
+                state.last = Some(now);
+
+                true
+            }
+        }
+    }
+}
+
+struct DebounceState<T> {
+    events: Vec<T>,
+
+    /// 첫 번째 event 시각(최대 대기 시간 계산용)
+    first: Option<Instant>,
+
+    /// 마지막 event 시각
+    last: Option<Instant>,
+
+    /// 즉시 처리 요청 여부
+    flush: bool,
+
+    closed: bool,
+}
+
+struct DebounceShared<T> {
+    state: Mutex<DebounceState<T>>,
+
+    signal: Condvar,
+}
+
+/// event를 모아 일정 시간 동안 추가 event가 없을 경우 한번에 처리하는 debouncer
+///
+/// callback은 별도 thread에서 실행되며, [Drop]시 남아있는 event를 처리한 후 종료된다.
+///
+/// # Example
+///
+/// ```rust
+/// use std::sync::{Arc, Mutex};
+/// use std::time::Duration;
+/// use cliff3_util::debounce_util::Debouncer;
+///
+/// let received = Arc::new(Mutex::new(vec![]));
+/// let r = received.clone();
+/// let debouncer = Debouncer::new(Duration::from_millis(20), move |events: Vec<&str>| {
+///     r.lock().unwrap().push(events);
+/// })
+/// .unwrap();
+///
+/// debouncer.push("a.txt");
+/// debouncer.push("b.txt");
+/// drop(debouncer);
+///
+/// assert_eq!(vec![vec!["a.txt", "b.txt"]], *received.lock().unwrap());
+/// ```
+pub struct Debouncer<T: Send + 'static> {
+    shared: Arc<DebounceShared<T>>,
+
+    handle: Option<JoinHandle<()>>,
+}
+
+impl<T: Send + 'static> Debouncer<T> {
+    /// [Debouncer] 생성
+    ///
+    /// # Arguments
+    ///
+    /// - `delay` - 마지막 event 이후 대기 시간
+    /// - `callback` - event 묶음 처리 함수
+    ///
+    /// # Errors
+    ///
+    /// - [InvalidArgumentError] - `delay`가 0일 경우
+    pub fn new<F>(delay: Duration, callback: F) -> Result<Self, InvalidArgumentError>
+    where
+        F: Fn(Vec<T>) + Send + 'static,
+    {
+        Self::create(delay, None, Box::new(callback))
+    }
+
+    /// 최대 대기 시간을 지정하여 [Debouncer] 생성
+    ///
+    /// event가 계속 발생하더라도 첫 번째 event 이후 `max_wait`가 지나면 처리한다.
+    ///
+    /// # Errors
+    ///
+    /// - [InvalidArgumentError] - `delay`가 0이거나 `max_wait`가 `delay`보다 작을 경우
+    pub fn with_max_wait<F>(
+        delay: Duration,
+        max_wait: Duration,
+        callback: F,
+    ) -> Result<Self, InvalidArgumentError>
+    where
+        F: Fn(Vec<T>) + Send + 'static,
+    {
+        if max_wait < delay {
+            return Err(InvalidArgumentError::from(
+                "최대 대기 시간은 대기 시간보다 작을 수 없습니다.",
+            ));
+        }
+
+        Self::create(delay, Some(max_wait), Box::new(callback))
+    }
+
+    fn create(
+        delay: Duration,
+        max_wait: Option<Duration>,
+        callback: Callback<T>,
+    ) -> Result<Self, InvalidArgumentError> {
+        if delay.is_zero() {
+            return Err(InvalidArgumentError::from("대기 시간은 0보다 커야 합니다."));
+        }
+
+        let shared = Arc::new(DebounceShared {
+            state: Mutex::new(DebounceState {
+                events: vec![],
+                first: None,
+                last: None,
+                flush: false,
+                closed: false,
+            }),
+            signal: Condvar::new(),
+        });
+        let inner = shared.clone();
+        let handle = thread::spawn(move || run_debouncer(inner, delay, max_wait, callback));
+
+        Ok(Debouncer {
+            shared,
+            handle: Some(handle),
+        })
+    }
+
+    /// event 추가
+    pub fn push(&self, event: T) {
+        let mut state = self.shared.state.lock().unwrap_or_else(|e| e.into_inner());
+        let now = Instant::now();
+
+        state.events.push(event);
+        state.first.get_or_insert(now);
+        state.last = Some(now);
+        self.shared.signal.notify_all();
+    }
+
+    /// 대기 중인 event 즉시 처리 요청
+    pub fn flush(&self) {
+        self.shared
+            .state
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .flush = true;
+        self.shared.signal.notify_all();
+    }
+
+    /// 대기 중인 event 수 반환
+    pub fn pending(&self) -> usize {
+        self.shared
+            .state
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .events
+            .len()
+    }
+}
+
+impl<T: Send + 'static> Drop for Debouncer<T> {
+    fn drop(&mut self) {
+        self.shared
+            .state
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .closed = true;
+        self.shared.signal.notify_all();
+
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+fn run_debouncer<T>(
+    shared: Arc<DebounceShared<T>>,
+    delay: Duration,
+    max_wait: Option<Duration>,
+    callback: Callback<T>,
+) {
+    loop {
+        let mut state = shared.state.lock().unwrap_or_else(|e| e.into_inner());
+
+        let events = loop {
+            if state.events.is_empty() {
+                state.flush = false;
+
+                if state.closed {
+                    return;
+                }
+
+                state = shared.signal.wait(state).unwrap_or_else(|e| e.into_inner());
+                continue;
+            }
+
+            let now = Instant::now();
+            let deadline = deadline(&state, delay, max_wait);
+
+            if state.flush || state.closed || deadline.is_some_and(|v| now >= v) {
+                state.flush = false;
+                state.first = None;
+                state.last = None;
+
+                break std::mem::take(&mut state.events);
+            }
+
+            // 처리 시각이 범위를 벗어날 경우 flush 혹은 종료시까지 대기
+            state = match deadline {
+                Some(v) => {
+                    shared
+                        .signal
+                        .wait_timeout(state, v - now)
+                        .unwrap_or_else(|e| e.into_inner())
+                        .0
+                }
+                None => shared.signal.wait(state).unwrap_or_else(|e| e.into_inner()),
+            };
+        };
+
+        drop(state);
+        callback(events);
+    }
+}
+
+/// event 처리 시각 계산. [Instant] 범위를 벗어날 경우 `None`
+fn deadline<T>(
+    state: &DebounceState<T>,
+    delay: Duration,
+    max_wait: Option<Duration>,
+) -> Option<Instant> {
+    let quiet = state.last.and_then(|v| v.checked_add(delay));
+    let limit = state
+        .first
+        .zip(max_wait)
+        .and_then(|(first, max_wait)| first.checked_add(max_wait));
+
+    match (quiet, limit) {
+        (Some(quiet), Some(limit)) => Some(quiet.min(limit)),
+        (quiet, limit) => quiet.or(limit),
+    }
+}
+
+/// tokio task 기반 debouncer
+///
+/// [Debouncer]와 동일하게 동작하며 callback은 tokio task에서 실행된다. tokio runtime 내에서 생성해야
+/// 하며, [Drop]시 남아있는 event를 처리한 후 task가 종료된다.
+///
+/// # Example
+///
+/// ```rust
+/// use std::sync::{Arc, Mutex};
+/// use std::time::Duration;
+/// use cliff3_util::debounce_util::AsyncDebouncer;
+///
+/// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+/// let received = Arc::new(Mutex::new(vec![]));
+/// let r = received.clone();
+/// let debouncer = AsyncDebouncer::new(Duration::from_millis(20), move |events: Vec<u32>| {
+///     r.lock().unwrap().extend(events);
+/// })
+/// .unwrap();
+///
+/// debouncer.push(1);
+/// debouncer.push(2);
+/// tokio::time::sleep(Duration::from_millis(50)).await;
+///
+/// assert_eq!(vec![1, 2], *received.lock().unwrap());
+/// # });
+/// ```
+#[cfg(feature = "async")]
+pub struct AsyncDebouncer<T: Send + 'static> {
+    sender: tokio::sync::mpsc::UnboundedSender<DebounceMessage<T>>,
+}
+
+#[cfg(feature = "async")]
+enum DebounceMessage<T> {
+    Event(T),
+
+    Flush,
+}
+
+#[cfg(feature = "async")]
+impl<T: Send + 'static> AsyncDebouncer<T> {
+    /// [AsyncDebouncer] 생성
+    ///
+    /// # Arguments
+    ///
+    /// - `delay` - 마지막 event 이후 대기 시간
+    /// - `callback` - event 묶음 처리 함수
+    ///
+    /// # Errors
+    ///
+    /// - [InvalidArgumentError] - `delay`가 0일 경우
+    ///
+    /// # Panics
+    ///
+    /// - tokio runtime 외부에서 호출할 경우
+    pub fn new<F>(delay: Duration, callback: F) -> Result<Self, InvalidArgumentError>
+    where
+        F: Fn(Vec<T>) + Send + 'static,
+    {
+        if delay.is_zero() {
+            return Err(InvalidArgumentError::from("대기 시간은 0보다 커야 합니다."));
+        }
+
+        let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel();
+
+        tokio::spawn(async move {
+            let mut events: Vec<T> = vec![];
+
+            loop {
+                let message = if events.is_empty() {
+                    receiver.recv().await
+                } else {
+                    match tokio::time::timeout(delay, receiver.recv()).await {
+                        Ok(v) => v,
+                        Err(_) => {
+                            // 대기 시간 동안 추가 event 없음
+                            callback(std::mem::take(&mut events));
+                            continue;
+                        }
+                    }
+                };
+
+                match message {
+                    Some(DebounceMessage::Event(v)) => events.push(v),
+                    Some(DebounceMessage::Flush) => {
+                        if !events.is_empty() {
+                            callback(std::mem::take(&mut events));
+                        }
+                    }
+                    None => {
+                        if !events.is_empty() {
+                            callback(events);
+                        }
+
+                        break;
+                    }
+                }
+            }
+        });
+
+        Ok(AsyncDebouncer { sender })
+    }
+
+    /// event 추가
+    pub fn push(&self, event: T) {
+        let _ = self.sender.send(DebounceMessage::Event(event));
+    }
+
+    /// 대기 중인 event 즉시 처리 요청
+    pub fn flush(&self) {
+        let _ = self.sender.send(DebounceMessage::Flush);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn throttler_test() {
+        let throttler = Throttler::new(Duration::from_millis(30)).unwrap();
+
+        assert!(throttler.try_acquire());
+        assert!(!throttler.try_acquire());
+        assert!(throttler.remaining() > Duration::ZERO);
+
+        let start = Instant::now();
+
+        throttler.wait_blocking();
+
+        assert!(start.elapsed() >= Duration::from_millis(20));
+        assert_eq!(1, throttler.suppressed());
+        assert!(Throttler::new(Duration::ZERO).is_err());
+    }
+
+    #[test]
+    fn debouncer_test() {
+        let received = Arc::new(Mutex::new(vec![]));
+        let r = received.clone();
+        let debouncer = Debouncer::new(Duration::from_millis(40), move |events: Vec<u32>| {
+            r.lock().unwrap().push(events);
+        })
+        .unwrap();
+
+        debouncer.push(1);
+        thread::sleep(Duration::from_millis(10));
+        debouncer.push(2);
+
+        assert_eq!(2, debouncer.pending());
+
+        thread::sleep(Duration::from_millis(100));
+        debouncer.push(3);
+        debouncer.flush();
+        thread::sleep(Duration::from_millis(20));
+
+        assert_eq!(vec![vec![1, 2], vec![3]], *received.lock().unwrap());
+        assert!(Debouncer::new(Duration::ZERO, |_: Vec<u32>| {}).is_err());
+    }
+
+    #[test]
+    fn max_wait_test() {
+        let received = Arc::new(Mutex::new(vec![]));
+        let r = received.clone();
+        let debouncer = Debouncer::with_max_wait(
+            Duration::from_millis(30),
+            Duration::from_millis(60),
+            move |events: Vec<u32>| {
+                r.lock().unwrap().push(events.len());
+            },
+        )
+        .unwrap();
+
+        // 대기 시간보다 짧은 간격으로 계속 event 발생
+        for i in 0..10 {
+            debouncer.push(i);
+            thread::sleep(Duration::from_millis(15));
+        }
+
+        drop(debouncer);
+
+        let received = received.lock().unwrap();
+
+        assert!(received.len() >= 2, "{:?}", received);
+        assert_eq!(10, received.iter().sum::<usize>());
+        assert!(Debouncer::with_max_wait(
+            Duration::from_secs(2),
+            Duration::from_secs(1),
+            |_: Vec<u32>| {}
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn overflow_delay_test() {
+        let received = Arc::new(Mutex::new(vec![]));
+        let r = received.clone();
+        let debouncer =
+            Debouncer::with_max_wait(Duration::MAX, Duration::MAX, move |events: Vec<u32>| {
+                r.lock().unwrap().push(events);
+            })
+            .unwrap();
+
+        // 처리 시각이 범위를 벗어나는 경우 flush 혹은 종료시까지 대기
+        debouncer.push(1);
+        thread::sleep(Duration::from_millis(20));
+
+        assert_eq!(1, debouncer.pending());
+        assert!(received.lock().unwrap().is_empty());
+
+        debouncer.flush();
+        thread::sleep(Duration::from_millis(20));
+        debouncer.push(2);
+        drop(debouncer);
+
+        assert_eq!(vec![vec![1], vec![2]], *received.lock().unwrap());
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn async_test() {
+        let throttler = Throttler::new(Duration::from_millis(20)).unwrap();
+        let start = Instant::now();
+
+        throttler.wait().await;
+        throttler.wait().await;
+
+        assert!(start.elapsed() >= Duration::from_millis(15));
+
+        let received = Arc::new(Mutex::new(vec![]));
+        let r = received.clone();
+        let debouncer = AsyncDebouncer::new(Duration::from_millis(20), move |events: Vec<u32>| {
+            r.lock().unwrap().push(events);
+        })
+        .unwrap();
+
+        debouncer.push(1);
+        debouncer.push(2);
+        debouncer.flush();
+        debouncer.push(3);
+        tokio::time::sleep(Duration::from_millis(60)).await;
+
+        assert_eq!(vec![vec![1, 2], vec![3]], *received.lock().unwrap());
+    }
+}
@@ -0,0 +1,342 @@
+//! 구조체 변경 내역(diff) 관련 함수 모음
+//!
+//! serde 직렬화 결과를 비교하여 항목 경로 단위의 추가/삭제/변경 내역을 반환한다. 엔티티 변경 이력(audit
+//! trail) 기록 등에 사용한다.
+//!
+//! 항목 경로는 object 항목을 `.`, 배열 요소를 `[index]`로 표기한다 (e.g. `address.city`, `tags[0]`).
+//! [Maskable]을 구현한 구조체는 [diff_structs_masked]를 이용하여 변경 전/후 값을 마스킹된 값으로 기록할
+//! 수 있다.
+
+use std::fmt::{Display, Formatter};
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::error::InvalidArgumentError;
+use crate::string_util::mask::Maskable;
+
+/// 변경 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ChangeKind {
+    /// 항목 추가
+    Added,
+
+    /// 항목 삭제
+    Removed,
+
+    /// 값 변경
+    Modified,
+}
+
+/// 항목별 변경 내역
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FieldChange {
+    /// 항목 경로
+    pub path: String,
+
+    /// 변경 종류
+    pub kind: ChangeKind,
+
+    /// 변경 전 값. [ChangeKind::Added]일 경우 `None`
+    pub before: Option<Value>,
+
+    /// 변경 후 값. [ChangeKind::Removed]일 경우 `None`
+    pub after: Option<Value>,
+}
+
+impl Display for FieldChange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let null = Value::Null;
+        let before = self.before.as_ref().unwrap_or(&null);
+        let after = self.after.as_ref().unwrap_or(&null);
+
+        match self.kind {
+            ChangeKind::Added => write!(f, "+ {}: {}", self.path, after),
+            ChangeKind::Removed => write!(f, "- {}: {}", self.path, before),
+            ChangeKind::Modified => write!(f, "~ {}: {} -> {}", self.path, before, after),
+        }
+    }
+}
+
+/// 경로 구성 요소
+#[derive(Debug, Clone)]
+enum Segment {
+    Key(String),
+    Index(usize),
+}
+
+/// 경로를 포함한 변경 내역
+struct RawChange {
+    segments: Vec<Segment>,
+    kind: ChangeKind,
+    before: Option<Value>,
+    after: Option<Value>,
+}
+
+/// 두 값의 변경 내역 반환
+///
+/// object는 항목 단위, 배열은 요소 위치 단위로 비교하며 그 외의 값은 전체를 비교한다.
+///
+/// # Arguments
+///
+/// - `old` - 변경 전 값
+/// - `new` - 변경 후 값
+///
+/// # Return
+///
+/// - 항목 경로 단위 변경 내역(경로 순)
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 직렬화 실패
+///
+/// # Example
+///
+/// ```rust
+/// use serde::Serialize;
+/// use cliff3_util::diff_util::{diff_structs, ChangeKind};
+///
+/// #[derive(Serialize)]
+/// struct Member {
+///     name: String,
+///     age: u32,
+///     tags: Vec<String>,
+/// }
+///
+/// let old = Member { name: "홍길동".to_owned(), age: 20, tags: vec!["a".to_owned()] };
+/// let new = Member { name: "홍길동".to_owned(), age: 21, tags: vec!["a".to_owned(), "b".to_owned()] };
+/// let changes = diff_structs(&old, &new).unwrap();
+///
+/// assert_eq!(2, changes.len());
+/// assert_eq!("~ age: 20 -> 21", changes[0].to_string());
+/// assert_eq!("tags[1]", changes[1].path);
+/// assert_eq!(ChangeKind::Added, changes[1].kind);
+/// ```
+pub fn diff_structs<T: Serialize>(
+    old: &T,
+    new: &T,
+) -> Result<Vec<FieldChange>, InvalidArgumentError> {
+    let (old, new) = (to_value(old)?, to_value(new)?);
+    let mut changes = vec![];
+
+    diff_values(&mut vec![], Some(&old), Some(&new), &mut changes);
+
+    Ok(changes.into_iter().map(to_field_change).collect())
+}
+
+/// 두 값의 변경 내역을 마스킹된 값으로 반환
+///
+/// 변경 여부는 원본 값으로 판단하며, 변경 전/후 값은 [Maskable::masked] 결과의 값으로 기록한다. 마스킹되는
+/// 부분만 변경된 경우에도 변경 내역에 포함된다.
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 직렬화 실패
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "derive")]
+/// # {
+/// use serde::Serialize;
+/// use cliff3_util::diff_util::diff_structs_masked;
+/// use cliff3_util::string_util::mask::Maskable;
+///
+/// #[derive(Clone, Serialize, Maskable)]
+/// struct Account {
+///     id: u64,
+///
+///     #[mask(full)]
+///     password: String,
+/// }
+///
+/// let old = Account { id: 1, password: "old".to_owned() };
+/// let new = Account { id: 1, password: "new-password".to_owned() };
+/// let changes = diff_structs_masked(&old, &new).unwrap();
+///
+/// assert_eq!(r#"~ password: "***" -> "************""#, changes[0].to_string());
+/// # }
+/// ```
+pub fn diff_structs_masked<T: Serialize + Maskable>(
+    old: &T,
+    new: &T,
+) -> Result<Vec<FieldChange>, InvalidArgumentError> {
+    let (masked_old, masked_new) = (to_value(&old.masked())?, to_value(&new.masked())?);
+    let (old, new) = (to_value(old)?, to_value(new)?);
+    let mut changes = vec![];
+
+    diff_values(&mut vec![], Some(&old), Some(&new), &mut changes);
+
+    Ok(changes
+        .into_iter()
+        .map(|mut change| {
+            change.before = change
+                .before
+                .and(lookup(&masked_old, &change.segments).cloned());
+            change.after = change
+                .after
+                .and(lookup(&masked_new, &change.segments).cloned());
+
+            to_field_change(change)
+        })
+        .collect())
+}
+
+fn to_value<T: Serialize>(target: &T) -> Result<Value, InvalidArgumentError> {
+    serde_json::to_value(target)
+        .map_err(|e| InvalidArgumentError::new(&format!("직렬화 실패 입니다({}).", e)))
+}
+
+fn diff_values(
+    segments: &mut Vec<Segment>,
+    old: Option<&Value>,
+    new: Option<&Value>,
+    changes: &mut Vec<RawChange>,
+) {
+    match (old, new) {
+        (Some(Value::Object(o)), Some(Value::Object(n))) => {
+            let mut keys: Vec<&String> = o.keys().chain(n.keys()).collect();
+
+            keys.sort();
+            keys.dedup();
+
+            for key in keys {
+                segments.push(Segment::Key(key.clone()));
+                diff_values(segments, o.get(key), n.get(key), changes);
+                segments.pop();
+            }
+        }
+        (Some(Value::Array(o)), Some(Value::Array(n))) => {
+            for i in 0..o.len().max(n.len()) {
+                segments.push(Segment::Index(i));
+                diff_values(segments, o.get(i), n.get(i), changes);
+                segments.pop();
+            }
+        }
+        (Some(o), Some(n)) if o == n => {}
+        (None, None) => {}
+        (o, n) => changes.push(RawChange {
+            segments: segments.clone(),
+            kind: match (o, n) {
+                (None, _) => ChangeKind::Added,
+                (_, None) => ChangeKind::Removed,
+                _ => ChangeKind::Modified,
+            },
+            before: o.cloned(),
+            after: n.cloned(),
+        }),
+    }
+}
+
+fn lookup<'a>(value: &'a Value, segments: &[Segment]) -> Option<&'a Value> {
+    segments
+        .iter()
+        .try_fold(value, |current, segment| match segment {
+            Segment::Key(key) => current.get(key),
+            Segment::Index(index) => current.get(index),
+        })
+}
+
+fn to_field_change(change: RawChange) -> FieldChange {
+    let mut path = String::new();
+
+    for segment in &change.segments {
+        match segment {
+            Segment::Key(key) if path.is_empty() => path.push_str(key),
+            Segment::Key(key) => {
+                path.push('.');
+                path.push_str(key);
+            }
+            Segment::Index(index) => path.push_str(&format!("[{}]", index)),
+        }
+    }
+
+    FieldChange {
+        path,
+        kind: change.kind,
+        before: change.before,
+        after: change.after,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn diff_structs_test() {
+        let old = json!({
+            "name": "a",
+            "address": {"city": "서울", "zip": "04524"},
+            "items": [{"id": 1}, {"id": 2}, {"id": 3}],
+            "memo": null,
+            "removed": true
+        });
+        let new = json!({
+            "name": "a",
+            "address": {"city": "부산", "zip": "04524"},
+            "items": [{"id": 1}, {"id": 20}],
+            "memo": "x",
+            "added": [1]
+        });
+        let changes = diff_structs(&old, &new).unwrap();
+        let lines: Vec<String> = changes.iter().map(|c| c.to_string()).collect();
+
+        assert_eq!(
+            vec![
+                r#"+ added: [1]"#,
+                r#"~ address.city: "서울" -> "부산""#,
+                r#"~ items[1].id: 2 -> 20"#,
+                r#"- items[2]: {"id":3}"#,
+                r#"~ memo: null -> "x""#,
+                r#"- removed: true"#,
+            ],
+            lines
+        );
+        assert_eq!(None, changes[0].before);
+        assert_eq!(None, changes[3].after);
+        assert!(diff_structs(&old, &old).unwrap().is_empty());
+
+        // 최상위 값이 object가 아닐 경우 경로는 빈 문자열
+        let changes = diff_structs(&1, &2).unwrap();
+
+        assert_eq!("", changes[0].path);
+        assert_eq!(ChangeKind::Modified, changes[0].kind);
+    }
+
+    #[derive(Clone, Serialize)]
+    struct Secret {
+        id: u64,
+        token: String,
+    }
+
+    impl Maskable for Secret {
+        fn masked(&self) -> Self {
+            Secret {
+                id: self.id,
+                token: "***".to_owned(),
+            }
+        }
+    }
+
+    #[test]
+    fn diff_structs_masked_test() {
+        let old = Secret {
+            id: 1,
+            token: "a".to_owned(),
+        };
+        let new = Secret {
+            id: 2,
+            token: "b".to_owned(),
+        };
+        let changes = diff_structs_masked(&old, &new).unwrap();
+
+        assert_eq!(2, changes.len());
+        assert_eq!(Some(json!(2)), changes[0].after);
+        assert_eq!(Some(json!("***")), changes[1].before);
+        assert_eq!(Some(json!("***")), changes[1].after);
+    }
+}
@@ -5,11 +5,12 @@
 //! * [rsa](https://crates.io/crates/rsa)
 
 use std::fmt::{Display, Formatter};
+use std::io::{Read, Write};
 
 use openssl::error::ErrorStack;
 use openssl::pkey::Private;
 use openssl::rsa::{Padding, Rsa};
-use openssl::symm::{decrypt, encrypt, Cipher};
+use openssl::symm::{decrypt, encrypt, Cipher, Crypter, Mode};
 use sha2::{Digest, Sha256 as sha2_256, Sha512 as sha2_512};
 
 use crate::error::{InvalidArgumentError, LibError, MissingArgumentError};
@@ -44,6 +45,7 @@ use crate::error::{InvalidArgumentError, LibError, MissingArgumentError};
 // CryptoError -------------------------------------------------------------------------------------
 /// 암호화 처리 중 발생하는 오류
 #[derive(PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CryptoError {
     message: String,
 }
@@ -82,7 +84,7 @@ impl LibError for CryptoError {
 
 // Define enum -------------------------------------------------------------------------------------
 /// SHA 256/512
-#[derive(PartialEq)]
+#[derive(PartialEq, Debug)]
 #[allow(non_camel_case_types)]
 pub enum SHA_TYPE {
     /// SHA-256
@@ -93,7 +95,7 @@ pub enum SHA_TYPE {
 }
 
 /// AES 128/256
-#[derive(PartialEq)]
+#[derive(PartialEq, Debug)]
 #[allow(non_camel_case_types)]
 pub enum AES_TYPE {
     /// AES-128
@@ -230,8 +232,124 @@ pub fn make_sha_hash_string(
     }
 }
 
+/// 디렉토리 하위의 모든 파일을 `SHA` 알고리즘을 이용하여 병렬 hash 처리 후 반환
+///
+/// `rayon` feature 활성화시 사용 가능하다. 하위 디렉토리를 포함하여 탐색하며 파일 내용 전체를 메모리에
+/// 올리지 않고 처리한다. 빈 파일은 빈 입력의 hash 값을 반환한다.
+///
+/// # Arguments
+///
+/// - `dir` - 대상 디렉토리
+/// - `hash_type` - [SHA_TYPE]
+///
+/// # Return
+///
+/// - (`dir` 기준 상대 경로, hex 문자열 hash) 목록(경로 순) `Result<Vec<(PathBuf, String)>, CryptoError>`
+///
+/// # Errors
+///
+/// - [CryptoError] - 디렉토리 탐색 혹은 파일 읽기 실패
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::encrypt_util::{par_hash_directory, SHA_TYPE};
+///
+/// let dir = std::env::temp_dir().join(format!("cliff3_hash_doc_{}", std::process::id()));
+///
+/// std::fs::create_dir_all(dir.join("sub")).unwrap();
+/// std::fs::write(dir.join("a.txt"), "test").unwrap();
+/// std::fs::write(dir.join("sub").join("b.txt"), "").unwrap();
+///
+/// let result = par_hash_directory(&dir, SHA_TYPE::SHA_256).unwrap();
+///
+/// assert_eq!(std::path::Path::new("a.txt"), result[0].0);
+/// assert_eq!("9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08", result[0].1);
+/// assert_eq!(std::path::Path::new("sub").join("b.txt"), result[1].0);
+///
+/// std::fs::remove_dir_all(&dir).unwrap();
+/// ```
+#[cfg(feature = "rayon")]
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        level = "debug",
+        skip_all,
+        fields(dir = %dir.display(), algorithm = ?hash_type, files)
+    )
+)]
+pub fn par_hash_directory(
+    dir: &std::path::Path,
+    hash_type: SHA_TYPE,
+) -> Result<Vec<(std::path::PathBuf, String)>, CryptoError> {
+    use rayon::prelude::*;
+    use std::path::{Path, PathBuf};
+
+    fn collect_files(dir: &Path, files: &mut Vec<PathBuf>) -> std::io::Result<()> {
+        for entry in std::fs::read_dir(dir)? {
+            let path = entry?.path();
+
+            if path.is_dir() {
+                collect_files(&path, files)?;
+            } else {
+                files.push(path);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn hash_file<D: Digest>(path: &Path) -> std::io::Result<String> {
+        let mut file = std::fs::File::open(path)?;
+        let mut buffer = vec![0u8; 8 * 1024];
+        let mut hasher = D::new();
+
+        loop {
+            let read = file.read(&mut buffer)?;
+
+            if read == 0 {
+                break;
+            }
+
+            hasher.update(&buffer[..read]);
+        }
+
+        let v: Vec<String> = hasher.finalize().iter().map(|b| format!("{:02x}", b)).collect();
+
+        Ok(v.join(""))
+    }
+
+    let io_error = |e: std::io::Error| {
+        CryptoError::from(
+            format!("디렉토리 hash 처리 중 오류가 발생하였습니다({}).", e).as_str(),
+        )
+    };
+    let mut files = vec![];
+
+    collect_files(dir, &mut files).map_err(io_error)?;
+    files.sort();
+
+    #[cfg(feature = "tracing")]
+    tracing::Span::current().record("files", files.len());
+
+    files
+        .par_iter()
+        .map(|path| {
+            let hash = match hash_type {
+                SHA_TYPE::SHA_256 => hash_file::<sha2_256>(path),
+                SHA_TYPE::SHA_512 => hash_file::<sha2_512>(path),
+            }
+            .map_err(io_error)?;
+            let relative = path.strip_prefix(dir).unwrap_or(path).to_path_buf();
+
+            Ok((relative, hash))
+        })
+        .collect()
+}
+
 /// AES 암호화 결과
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AESResult {
     /// Salt
     salt: Option<Vec<u8>>,
@@ -413,6 +531,19 @@ pub fn validate_salt(salt: Option<&[u8]>) -> Result<(), InvalidArgumentError> {
 ///
 /// assert!(unwrapped.result().len() > 0);
 /// ```
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        level = "debug",
+        skip_all,
+        fields(
+            algorithm = ?enc_type,
+            size = target.len(),
+            salted = salt.is_some(),
+            repeat_count = repeat_count,
+        )
+    )
+)]
 pub fn aes_encrypt(
     enc_type: AES_TYPE,
     target: &[u8],
@@ -522,6 +653,19 @@ pub fn aes_encrypt(
 ///
 /// assert_eq!(plain_text, String::from_utf8_lossy(decrypted_raw.as_ref()));
 /// ```
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        level = "debug",
+        skip_all,
+        fields(
+            algorithm = ?enc_type,
+            size = target.map_or(0, |v| v.len()),
+            salted = salt.is_some(),
+            repeat_count = repeat_count,
+        )
+    )
+)]
 pub fn aes_decrypt(
     enc_type: AES_TYPE,
     target: Option<&[u8]>,
@@ -586,6 +730,308 @@ pub fn aes_decrypt(
     }
 }
 
+// AES stream --------------------------------------------------------------------------------------
+/// 스트림 암/복호화시 한 번에 읽는 크기
+const AES_STREAM_BUFFER_SIZE: usize = 8 * 1024;
+
+/// [aes_encrypt]와 동일한 방식(key 및 iv 생성)의 `AES 128/256` 스트림 암호화
+///
+/// 대상 전체를 메모리에 올리지 않고 `reader`에서 읽은 내용을 암호화하여 `writer`에 기록한다. 암호화
+/// 결과는 동일한 인자로 [aes_encrypt]를 호출한 결과와 같다. iv는 `secret`, `salt` 및 `repeat_count`로
+/// 생성되므로 [aes_decrypt_stream]을 이용하여 복호화할 수 있다.
+///
+/// # Arguments
+///
+/// - `enc_type` - [AES_TYPE]
+/// - `reader` - 암호화 대상
+/// - `writer` - 암호화 결과 기록 대상
+/// - `secret` - Secret key
+/// - `salt` - salt (8 bytes) ([validate_salt] 참고)
+/// - `repeat_count` - 반복 횟수
+///
+/// # Return
+///
+/// - 기록된 byte 수 `Result<u64, Box<dyn LibError>>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - `salt`의 길이가 `8 bytes`가 아닐 경우
+/// - [CryptoError] - key 생성, 암호화 혹은 읽기/쓰기 실패
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::encrypt_util::{aes_decrypt_stream, aes_encrypt, aes_encrypt_stream, AES_TYPE};
+///
+/// let plain_text = "스트림 암호화 대상".repeat(1000);
+/// let salt = Some("12ag3$s!".as_bytes());
+/// let mut encrypted: Vec<u8> = vec![];
+///
+/// aes_encrypt_stream(AES_TYPE::AES_256, &mut plain_text.as_bytes(), &mut encrypted, b"secret", salt, 10).unwrap();
+///
+/// let result = aes_encrypt(AES_TYPE::AES_256, plain_text.as_bytes(), b"secret", salt, 10).unwrap();
+///
+/// assert_eq!(result.result(), encrypted.as_slice());
+///
+/// let mut decrypted: Vec<u8> = vec![];
+///
+/// aes_decrypt_stream(AES_TYPE::AES_256, &mut encrypted.as_slice(), &mut decrypted, b"secret", salt, 10).unwrap();
+///
+/// assert_eq!(plain_text.as_bytes(), decrypted.as_slice());
+/// ```
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        level = "debug",
+        skip_all,
+        fields(algorithm = ?enc_type, salted = salt.is_some(), repeat_count = repeat_count, bytes)
+    )
+)]
+pub fn aes_encrypt_stream<R: Read, W: Write>(
+    enc_type: AES_TYPE,
+    reader: &mut R,
+    writer: &mut W,
+    secret: &[u8],
+    salt: Option<&[u8]>,
+    repeat_count: usize,
+) -> Result<u64, Box<dyn LibError>> {
+    let mut crypter = aes_crypter(enc_type, Mode::Encrypt, secret, salt, repeat_count)?;
+
+    aes_stream(&mut crypter, reader, writer)
+}
+
+/// [aes_encrypt_stream] 혹은 [aes_encrypt]의 암호화 결과를 스트림 복호화
+///
+/// # Arguments
+///
+/// - `enc_type` - [AES_TYPE]
+/// - `reader` - 복호화 대상
+/// - `writer` - 복호화 결과 기록 대상
+/// - `secret` - Secret key
+/// - `salt` - 암호화시 사용한 `salt` ([validate_salt] 참고)
+/// - `repeat_count` - 암호화시 지정한 반복 횟수
+///
+/// # Return
+///
+/// - 기록된 byte 수 `Result<u64, Box<dyn LibError>>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - `salt`의 길이가 `8 bytes`가 아닐 경우
+/// - [CryptoError] - key 생성, 복호화(잘못된 key 혹은 padding) 혹은 읽기/쓰기 실패
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        level = "debug",
+        skip_all,
+        fields(algorithm = ?enc_type, salted = salt.is_some(), repeat_count = repeat_count, bytes)
+    )
+)]
+pub fn aes_decrypt_stream<R: Read, W: Write>(
+    enc_type: AES_TYPE,
+    reader: &mut R,
+    writer: &mut W,
+    secret: &[u8],
+    salt: Option<&[u8]>,
+    repeat_count: usize,
+) -> Result<u64, Box<dyn LibError>> {
+    let mut crypter = aes_crypter(enc_type, Mode::Decrypt, secret, salt, repeat_count)?;
+
+    aes_stream(&mut crypter, reader, writer)
+}
+
+/// [aes_encrypt_stream]의 비동기 버전
+///
+/// `async` feature 활성화시 사용 가능하며 [tokio::io::AsyncRead]/[tokio::io::AsyncWrite]를 이용한다.
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - `salt`의 길이가 `8 bytes`가 아닐 경우
+/// - [CryptoError] - key 생성, 암호화 혹은 읽기/쓰기 실패
+#[cfg(feature = "async")]
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        level = "debug",
+        skip_all,
+        fields(algorithm = ?enc_type, salted = salt.is_some(), repeat_count = repeat_count, bytes)
+    )
+)]
+pub async fn aes_encrypt_stream_async<R, W>(
+    enc_type: AES_TYPE,
+    reader: &mut R,
+    writer: &mut W,
+    secret: &[u8],
+    salt: Option<&[u8]>,
+    repeat_count: usize,
+) -> Result<u64, Box<dyn LibError>>
+where
+    R: tokio::io::AsyncRead + Unpin,
+    W: tokio::io::AsyncWrite + Unpin,
+{
+    let mut crypter = aes_crypter(enc_type, Mode::Encrypt, secret, salt, repeat_count)?;
+
+    aes_stream_async(&mut crypter, reader, writer).await
+}
+
+/// [aes_decrypt_stream]의 비동기 버전
+///
+/// `async` feature 활성화시 사용 가능하다.
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - `salt`의 길이가 `8 bytes`가 아닐 경우
+/// - [CryptoError] - key 생성, 복호화(잘못된 key 혹은 padding) 혹은 읽기/쓰기 실패
+#[cfg(feature = "async")]
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        level = "debug",
+        skip_all,
+        fields(algorithm = ?enc_type, salted = salt.is_some(), repeat_count = repeat_count, bytes)
+    )
+)]
+pub async fn aes_decrypt_stream_async<R, W>(
+    enc_type: AES_TYPE,
+    reader: &mut R,
+    writer: &mut W,
+    secret: &[u8],
+    salt: Option<&[u8]>,
+    repeat_count: usize,
+) -> Result<u64, Box<dyn LibError>>
+where
+    R: tokio::io::AsyncRead + Unpin,
+    W: tokio::io::AsyncWrite + Unpin,
+{
+    let mut crypter = aes_crypter(enc_type, Mode::Decrypt, secret, salt, repeat_count)?;
+
+    aes_stream_async(&mut crypter, reader, writer).await
+}
+
+/// [aes_encrypt]와 동일한 방식으로 key 및 iv를 생성한 [Crypter] 반환
+fn aes_crypter(
+    enc_type: AES_TYPE,
+    mode: Mode,
+    secret: &[u8],
+    salt: Option<&[u8]>,
+    repeat_count: usize,
+) -> Result<Crypter, Box<dyn LibError>> {
+    validate_salt(salt).map_err(|e| Box::new(e) as Box<dyn LibError>)?;
+
+    let cipher = if AES_TYPE::AES_128 == enc_type {
+        Cipher::aes_128_cbc()
+    } else {
+        Cipher::aes_256_cbc()
+    };
+    let crypto_error = |_| {
+        Box::new(CryptoError::from(
+            "AES 스트림 처리 중 오류가 발생하였습니다.",
+        )) as Box<dyn LibError>
+    };
+    let key_spec = openssl::pkcs5::bytes_to_key(
+        cipher,
+        openssl::hash::MessageDigest::md5(),
+        secret,
+        salt,
+        repeat_count as i32,
+    )
+    .map_err(crypto_error)?;
+
+    Crypter::new(cipher, mode, key_spec.key.as_slice(), key_spec.iv.as_deref()).map_err(crypto_error)
+}
+
+fn aes_stream<R: Read, W: Write>(
+    crypter: &mut Crypter,
+    reader: &mut R,
+    writer: &mut W,
+) -> Result<u64, Box<dyn LibError>> {
+    let mut input = vec![0u8; AES_STREAM_BUFFER_SIZE];
+    let mut output = vec![0u8; AES_STREAM_BUFFER_SIZE + 16];
+    let mut written: u64 = 0;
+
+    loop {
+        let read = reader.read(&mut input).map_err(stream_io_error)?;
+        let count = if read == 0 {
+            crypter.finalize(&mut output).map_err(stream_crypto_error)?
+        } else {
+            crypter
+                .update(&input[..read], &mut output)
+                .map_err(stream_crypto_error)?
+        };
+
+        writer.write_all(&output[..count]).map_err(stream_io_error)?;
+        written += count as u64;
+
+        if read == 0 {
+            break;
+        }
+    }
+
+    writer.flush().map_err(stream_io_error)?;
+
+    #[cfg(feature = "tracing")]
+    tracing::Span::current().record("bytes", written);
+
+    Ok(written)
+}
+
+#[cfg(feature = "async")]
+async fn aes_stream_async<R, W>(
+    crypter: &mut Crypter,
+    reader: &mut R,
+    writer: &mut W,
+) -> Result<u64, Box<dyn LibError>>
+where
+    R: tokio::io::AsyncRead + Unpin,
+    W: tokio::io::AsyncWrite + Unpin,
+{
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let mut input = vec![0u8; AES_STREAM_BUFFER_SIZE];
+    let mut output = vec![0u8; AES_STREAM_BUFFER_SIZE + 16];
+    let mut written: u64 = 0;
+
+    loop {
+        let read = reader.read(&mut input).await.map_err(stream_io_error)?;
+        let count = if read == 0 {
+            crypter.finalize(&mut output).map_err(stream_crypto_error)?
+        } else {
+            crypter
+                .update(&input[..read], &mut output)
+                .map_err(stream_crypto_error)?
+        };
+
+        writer
+            .write_all(&output[..count])
+            .await
+            .map_err(stream_io_error)?;
+        written += count as u64;
+
+        if read == 0 {
+            break;
+        }
+    }
+
+    writer.flush().await.map_err(stream_io_error)?;
+
+    #[cfg(feature = "tracing")]
+    tracing::Span::current().record("bytes", written);
+
+    Ok(written)
+}
+
+fn stream_io_error(error: std::io::Error) -> Box<dyn LibError> {
+    Box::new(CryptoError::from(
+        format!("AES 스트림 읽기/쓰기 오류 입니다({}).", error).as_str(),
+    ))
+}
+
+fn stream_crypto_error(error: ErrorStack) -> Box<dyn LibError> {
+    Box::new(CryptoError::from(
+        format!("AES 스트림 암/복호화 오류 입니다({}).", error).as_str(),
+    ))
+}
+
 // RSA ---------------------------------------------------------------------------------------------
 // #[allow(non_camel_case_types)]
 // enum LoadKeyType {
@@ -803,6 +1249,10 @@ impl RSAResult {
 /// - [Rsa]
 /// - [Private]
 /// - [CryptoError]
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "debug", skip_all, fields(bits = bit_size.bit()))
+)]
 pub fn generate_rsa_keypair(bit_size: RSA_BIT) -> Result<Rsa<Private>, CryptoError> {
     let rsa: Result<Rsa<Private>, ErrorStack> = Rsa::generate(bit_size.bit() as u32);
 
@@ -866,6 +1316,14 @@ pub fn generate_rsa_keypair(bit_size: RSA_BIT) -> Result<Rsa<Private>, CryptoErr
 /// assert!(raw.public_modulus().len() > 0, "공개키 계수 반환 실패");
 /// assert_eq!(raw.result().len(), RSA_BIT::B_4096.bytes() as usize, "암호화 결과 길이 불일치");
 /// ```
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        level = "debug",
+        skip_all,
+        fields(size = target.len(), bits = bit_size.bit())
+    )
+)]
 pub fn rsa_encrypt_without_key(
     target: &[u8],
     bit_size: RSA_BIT,
@@ -942,6 +1400,10 @@ pub fn rsa_encrypt_without_key(
 ///
 /// assert_eq!(decrypted_text, plaint_text, "복호화 실패");
 /// ```
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "debug", skip_all, fields(size = target.len()))
+)]
 pub fn rsa_decrypt(target: &[u8], prv_key: &[u8]) -> Result<Vec<u8>, CryptoError> {
     let private_key = Rsa::private_key_from_pem(prv_key);
 
@@ -1250,4 +1712,109 @@ mod tests {
 
         println!("원문: {:?}\n복호화 결과: {:?}", PLAIN_TEXT, decrypt2_result);
     }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_hash_directory_test() {
+        let dir = std::env::temp_dir().join(format!("cliff3_hash_{}", std::process::id()));
+
+        std::fs::create_dir_all(dir.join("b")).unwrap();
+
+        for i in 0..20 {
+            std::fs::write(
+                dir.join("b").join(format!("{:02}.txt", i)),
+                PLAIN_TEXT.repeat(i),
+            )
+            .unwrap();
+        }
+
+        std::fs::write(dir.join("a.txt"), "test").unwrap();
+
+        let result = par_hash_directory(&dir, SHA_TYPE::SHA_512).unwrap();
+
+        assert_eq!(21, result.len());
+        assert_eq!(std::path::Path::new("a.txt"), result[0].0);
+        assert_eq!(
+            make_sha_hash_string(SHA_TYPE::SHA_512, b"test", None).unwrap(),
+            result[0].1
+        );
+        assert_eq!(
+            make_sha_hash_string(SHA_TYPE::SHA_512, PLAIN_TEXT.repeat(19).as_bytes(), None)
+                .unwrap(),
+            result[20].1
+        );
+        assert!(par_hash_directory(&dir.join("none"), SHA_TYPE::SHA_256).is_err());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn aes_stream_test() {
+        let plain_text = PLAIN_TEXT.repeat(500);
+        let salt = Some(b"abcdefgh".as_slice());
+        let mut encrypted: Vec<u8> = vec![];
+
+        aes_encrypt_stream(
+            AES_TYPE::AES_128,
+            &mut plain_text.as_bytes(),
+            &mut encrypted,
+            b"secret",
+            salt,
+            5,
+        )
+        .unwrap();
+
+        let mut decrypted: Vec<u8> = vec![];
+        let written =
+            aes_decrypt_stream(AES_TYPE::AES_128, &mut encrypted.as_slice(), &mut decrypted, b"secret", salt, 5)
+                .unwrap();
+
+        assert_eq!(plain_text.len() as u64, written);
+        assert_eq!(plain_text.as_bytes(), decrypted.as_slice());
+
+        // 잘못된 key
+        let mut decrypted: Vec<u8> = vec![];
+
+        assert!(aes_decrypt_stream(
+            AES_TYPE::AES_128,
+            &mut encrypted.as_slice(),
+            &mut decrypted,
+            b"wrong",
+            salt,
+            5
+        )
+        .is_err());
+        assert!(aes_encrypt_stream(
+            AES_TYPE::AES_128,
+            &mut plain_text.as_bytes(),
+            &mut vec![],
+            b"secret",
+            Some(b"short".as_slice()),
+            5
+        )
+        .is_err());
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn aes_stream_async_test() {
+        let plain_text = PLAIN_TEXT.repeat(500);
+        let mut encrypted: Vec<u8> = vec![];
+
+        aes_encrypt_stream_async(AES_TYPE::AES_256, &mut plain_text.as_bytes(), &mut encrypted, b"secret", None, 1)
+            .await
+            .unwrap();
+
+        let expected = aes_encrypt(AES_TYPE::AES_256, plain_text.as_bytes(), b"secret", None, 1).unwrap();
+
+        assert_eq!(expected.result(), encrypted.as_slice());
+
+        let mut decrypted: Vec<u8> = vec![];
+
+        aes_decrypt_stream_async(AES_TYPE::AES_256, &mut encrypted.as_slice(), &mut decrypted, b"secret", None, 1)
+            .await
+            .unwrap();
+
+        assert_eq!(plain_text.as_bytes(), decrypted.as_slice());
+    }
 }
@@ -1,6 +1,8 @@
 //! 라이브러리 공용 오류 정의 module
 
-use std::fmt::{Debug, Display, Formatter};
+use alloc::borrow::ToOwned;
+use alloc::string::String;
+use core::fmt::{Debug, Display, Formatter};
 
 /// 라이브러리에서 사용하는 오류에 대한 공통 정의 trait
 ///
@@ -22,6 +24,7 @@ pub trait LibError: Debug {
 // MissingArgumentError ----------------------------------------------------------------------------
 /// 인자 누락 오류
 #[derive(PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MissingArgumentError {
     message: String,
 }
@@ -40,7 +43,7 @@ impl LibError for MissingArgumentError {
     }
 
     fn get_type_name_from_instance(&self) -> &str {
-        return std::any::type_name::<MissingArgumentError>();
+        return core::any::type_name::<MissingArgumentError>();
     }
 }
 
@@ -53,7 +56,7 @@ impl From<&str> for MissingArgumentError {
 }
 
 impl Display for MissingArgumentError {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         write!(f, "Missing argument error.")
     }
 }
@@ -61,6 +64,7 @@ impl Display for MissingArgumentError {
 // InvalidArgumentError ----------------------------------------------------------------------------
 /// 잘못된 인자에 대한 오류
 #[derive(PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InvalidArgumentError {
     message: String,
 }
@@ -87,12 +91,12 @@ impl LibError for InvalidArgumentError {
     }
 
     fn get_type_name_from_instance(&self) -> &str {
-        return std::any::type_name::<InvalidArgumentError>();
+        return core::any::type_name::<InvalidArgumentError>();
     }
 }
 
 impl Display for InvalidArgumentError {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         write!(f, "Invalid argument error.")
     }
 }
@@ -0,0 +1,182 @@
+//! 테스트 데이터(fixture) 생성 관련 함수 모음
+//!
+//! 형식 및 검증 번호는 올바르지만 실제로 발급되지 않는 범위의 주민등록번호, 사업자등록번호, 카드번호 및
+//! 전화번호를 생성하는 [FakeGenerator]를 제공한다. 생성된 값은 [crate::validate_util]의 검사 함수를
+//! 통과한다.
+//!
+//! - 주민등록번호 - 지역 코드(8 ~ 9번째 자리)를 발급에 사용되지 않는 `96` ~ `99`로 생성
+//! - 사업자등록번호 - 세무서 코드(앞 3자리)를 존재하지 않는 `000`으로 생성
+//! - 카드번호 - 결제 대행사의 시험용 BIN(e.g. `411111`)으로 생성
+//! - 전화번호 - 할당되지 않는 국번(`010-0XXX`)으로 생성
+
+use chrono::{Datelike, Duration, NaiveDate};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::validate_util::{assemble_business_number, assemble_rrn, join_digits, luhn_check_digit};
+
+/// 카드 브랜드(시험용 BIN)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CardBrand {
+    /// Visa (`411111`, 16자리)
+    Visa,
+
+    /// Mastercard (`555555`, 16자리)
+    Mastercard,
+
+    /// American Express (`378282`, 15자리)
+    Amex,
+}
+
+impl CardBrand {
+    /// (시험용 BIN, 전체 자릿수)
+    fn spec(&self) -> (&'static str, usize) {
+        match self {
+            CardBrand::Visa => ("411111", 16),
+            CardBrand::Mastercard => ("555555", 16),
+            CardBrand::Amex => ("378282", 15),
+        }
+    }
+}
+
+/// 테스트용 식별번호 생성기
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::fake_util::{CardBrand, FakeGenerator};
+/// use cliff3_util::validate_util::{validate_card_number, validate_rrn};
+///
+/// let mut generator = FakeGenerator::with_seed(42);
+/// let rrn = generator.rrn();
+///
+/// assert_eq!(rrn, validate_rrn(&rrn).unwrap().normalized());
+/// assert!(validate_card_number(&generator.card_number(CardBrand::Visa)).is_ok());
+///
+/// // 동일한 seed는 동일한 값 생성
+/// assert_eq!(rrn, FakeGenerator::with_seed(42).rrn());
+/// ```
+#[derive(Debug, Clone)]
+pub struct FakeGenerator {
+    rng: StdRng,
+}
+
+impl Default for FakeGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FakeGenerator {
+    /// 무작위 seed로 생성
+    pub fn new() -> Self {
+        FakeGenerator {
+            rng: StdRng::from_entropy(),
+        }
+    }
+
+    /// 지정된 seed로 생성. 동일한 seed는 동일한 순서의 값을 생성한다.
+    pub fn with_seed(seed: u64) -> Self {
+        FakeGenerator {
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+
+    /// 주민등록번호 생성 (`YYMMDD-NNNNNNN`)
+    ///
+    /// 생년월일은 1950년 ~ 2019년 범위이다.
+    pub fn rrn(&mut self) -> String {
+        let start = NaiveDate::from_ymd_opt(1950, 1, 1).unwrap();
+        let end = NaiveDate::from_ymd_opt(2019, 12, 31).unwrap();
+        let birth = start + Duration::days(self.rng.gen_range(0..=(end - start).num_days()));
+        let female = self.rng.gen_bool(0.5);
+        // 지역 코드 96 ~ 99
+        let mut serial = vec![9, self.rng.gen_range(6..=9)];
+
+        serial.extend((0..3).map(|_| self.rng.gen_range(0..=9)));
+
+        assemble_rrn((birth.year(), birth.month(), birth.day()), female, &serial)
+    }
+
+    /// 사업자등록번호 생성 (`000-NN-NNNNN`)
+    pub fn business_number(&mut self) -> String {
+        let mut digits = vec![0, 0, 0];
+
+        digits.extend((0..6).map(|_| self.rng.gen_range(0..=9)));
+
+        assemble_business_number(&digits)
+    }
+
+    /// 카드번호 생성(구분자 없음)
+    pub fn card_number(&mut self, brand: CardBrand) -> String {
+        let (bin, length) = brand.spec();
+        let mut digits: Vec<u32> = bin.chars().filter_map(|c| c.to_digit(10)).collect();
+
+        while digits.len() < length - 1 {
+            digits.push(self.rng.gen_range(0..=9));
+        }
+
+        digits.push(luhn_check_digit(&digits));
+
+        join_digits(&digits)
+    }
+
+    /// 휴대전화번호 생성 (`010-0NNN-NNNN`)
+    pub fn phone_number(&mut self) -> String {
+        format!(
+            "010-0{:03}-{:04}",
+            self.rng.gen_range(0..1000),
+            self.rng.gen_range(0..10000)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validate_util::{validate_brn, validate_card_number, validate_rrn};
+
+    #[test]
+    fn generate_test() {
+        let mut generator = FakeGenerator::new();
+
+        for _ in 0..200 {
+            let rrn = generator.rrn();
+
+            assert_eq!(rrn, validate_rrn(&rrn).unwrap().normalized());
+            assert!(matches!(&rrn[8..10], "96" | "97" | "98" | "99"), "{}", rrn);
+
+            let business_number = generator.business_number();
+
+            assert_eq!(business_number, validate_brn(&business_number).unwrap());
+            assert!(business_number.starts_with("000-"));
+
+            for brand in [CardBrand::Visa, CardBrand::Mastercard, CardBrand::Amex] {
+                let card = generator.card_number(brand);
+
+                assert_eq!(brand.spec().1, card.len());
+                assert!(card.starts_with(brand.spec().0));
+                assert!(validate_card_number(&card).is_ok(), "{}", card);
+            }
+
+            let phone = generator.phone_number();
+
+            assert_eq!(13, phone.len());
+            assert!(phone.starts_with("010-0"));
+        }
+    }
+
+    #[test]
+    fn seed_test() {
+        let mut a = FakeGenerator::with_seed(7);
+        let mut b = FakeGenerator::with_seed(7);
+
+        assert_eq!(a.rrn(), b.rrn());
+        assert_eq!(a.phone_number(), b.phone_number());
+        assert_eq!(
+            a.card_number(CardBrand::Amex),
+            b.card_number(CardBrand::Amex)
+        );
+    }
+}
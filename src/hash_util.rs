@@ -0,0 +1,357 @@
+//! 비암호화(non-cryptographic) hash 관련 함수 모음
+//!
+//! Shard 선택, cache key 생성 등 암호학적 안전성이 필요하지 않은 경우에 사용한다. 비밀번호 저장이나
+//! 무결성 검증에는 [crate::encrypt_util]의 SHA 관련 함수를 사용해야 한다.
+
+use crate::error::InvalidArgumentError;
+
+/// FNV-1a 32 bit offset basis
+const FNV_32_OFFSET: u32 = 0x811c_9dc5;
+
+/// FNV-1a 32 bit prime
+const FNV_32_PRIME: u32 = 0x0100_0193;
+
+/// FNV-1a 64 bit offset basis
+const FNV_64_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+
+/// FNV-1a 64 bit prime
+const FNV_64_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// 대상 bytes의 FNV-1a 64 bit hash 반환
+///
+/// # Arguments
+///
+/// - `target` - Hash 대상
+///
+/// # Return
+///
+/// - 64 bit hash
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::hash_util::fnv1a;
+///
+/// assert_eq!(0xcbf29ce484222325, fnv1a(b""));
+/// assert_eq!(0xaf63dc4c8601ec8c, fnv1a(b"a"));
+/// ```
+pub fn fnv1a(target: &[u8]) -> u64 {
+    target.iter().fold(FNV_64_OFFSET, |hash, b| {
+        (hash ^ *b as u64).wrapping_mul(FNV_64_PRIME)
+    })
+}
+
+/// 대상 bytes의 FNV-1a 32 bit hash 반환
+///
+/// # Arguments
+///
+/// - `target` - Hash 대상
+///
+/// # Return
+///
+/// - 32 bit hash
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::hash_util::fnv1a_32;
+///
+/// assert_eq!(0x811c9dc5, fnv1a_32(b""));
+/// assert_eq!(0xe40c292c, fnv1a_32(b"a"));
+/// ```
+pub fn fnv1a_32(target: &[u8]) -> u32 {
+    target.iter().fold(FNV_32_OFFSET, |hash, b| {
+        (hash ^ *b as u32).wrapping_mul(FNV_32_PRIME)
+    })
+}
+
+/// 대상 bytes의 MurmurHash3(x86, 32 bit) 반환
+///
+/// # Arguments
+///
+/// - `target` - Hash 대상
+/// - `seed` - Seed
+///
+/// # Return
+///
+/// - 32 bit hash
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::hash_util::murmur3_32;
+///
+/// assert_eq!(0, murmur3_32(b"", 0));
+/// assert_eq!(0x248bfa47, murmur3_32(b"hello", 0));
+/// ```
+pub fn murmur3_32(target: &[u8], seed: u32) -> u32 {
+    const C1: u32 = 0xcc9e_2d51;
+    const C2: u32 = 0x1b87_3593;
+
+    let mut h1 = seed;
+    let mut chunks = target.chunks_exact(4);
+
+    for chunk in chunks.by_ref() {
+        let mut k1 = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+
+        k1 = k1.wrapping_mul(C1).rotate_left(15).wrapping_mul(C2);
+        h1 ^= k1;
+        h1 = h1.rotate_left(13).wrapping_mul(5).wrapping_add(0xe654_6b64);
+    }
+
+    let tail = chunks.remainder();
+
+    if !tail.is_empty() {
+        let mut k1: u32 = 0;
+
+        for (i, b) in tail.iter().enumerate() {
+            k1 ^= (*b as u32) << (8 * i);
+        }
+
+        k1 = k1.wrapping_mul(C1).rotate_left(15).wrapping_mul(C2);
+        h1 ^= k1;
+    }
+
+    h1 ^= target.len() as u32;
+
+    fmix32(h1)
+}
+
+/// 대상 bytes의 MurmurHash3(x64, 128 bit) 반환
+///
+/// 반환값의 하위 64 bit는 `h1`, 상위 64 bit는 `h2`로 참조 구현의 little-endian 출력 순서와 동일하다.
+///
+/// # Arguments
+///
+/// - `target` - Hash 대상
+/// - `seed` - Seed
+///
+/// # Return
+///
+/// - 128 bit hash
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::hash_util::murmur3_128;
+///
+/// assert_eq!(0, murmur3_128(b"", 0));
+/// assert_ne!(murmur3_128(b"hello", 0), murmur3_128(b"hello", 1));
+/// ```
+pub fn murmur3_128(target: &[u8], seed: u32) -> u128 {
+    const C1: u64 = 0x87c3_7b91_1142_53d5;
+    const C2: u64 = 0x4cf5_ad43_2745_937f;
+
+    let mut h1 = seed as u64;
+    let mut h2 = seed as u64;
+    let mut chunks = target.chunks_exact(16);
+
+    for chunk in chunks.by_ref() {
+        let mut k1 = u64::from_le_bytes(chunk[0..8].try_into().unwrap_or_default());
+        let mut k2 = u64::from_le_bytes(chunk[8..16].try_into().unwrap_or_default());
+
+        k1 = k1.wrapping_mul(C1).rotate_left(31).wrapping_mul(C2);
+        h1 ^= k1;
+        h1 = h1
+            .rotate_left(27)
+            .wrapping_add(h2)
+            .wrapping_mul(5)
+            .wrapping_add(0x52dc_e729);
+
+        k2 = k2.wrapping_mul(C2).rotate_left(33).wrapping_mul(C1);
+        h2 ^= k2;
+        h2 = h2
+            .rotate_left(31)
+            .wrapping_add(h1)
+            .wrapping_mul(5)
+            .wrapping_add(0x3849_5ab5);
+    }
+
+    let tail = chunks.remainder();
+    let mut k1: u64 = 0;
+    let mut k2: u64 = 0;
+
+    for (i, b) in tail.iter().enumerate() {
+        if i < 8 {
+            k1 ^= (*b as u64) << (8 * i);
+        } else {
+            k2 ^= (*b as u64) << (8 * (i - 8));
+        }
+    }
+
+    if tail.len() > 8 {
+        k2 = k2.wrapping_mul(C2).rotate_left(33).wrapping_mul(C1);
+        h2 ^= k2;
+    }
+
+    if !tail.is_empty() {
+        k1 = k1.wrapping_mul(C1).rotate_left(31).wrapping_mul(C2);
+        h1 ^= k1;
+    }
+
+    h1 ^= target.len() as u64;
+    h2 ^= target.len() as u64;
+
+    h1 = h1.wrapping_add(h2);
+    h2 = h2.wrapping_add(h1);
+
+    h1 = fmix64(h1);
+    h2 = fmix64(h2);
+
+    h1 = h1.wrapping_add(h2);
+    h2 = h2.wrapping_add(h1);
+
+    ((h2 as u128) << 64) | h1 as u128
+}
+
+/// MurmurHash3 32 bit finalization mix
+#[inline]
+fn fmix32(mut h: u32) -> u32 {
+    h ^= h >> 16;
+    h = h.wrapping_mul(0x85eb_ca6b);
+    h ^= h >> 13;
+    h = h.wrapping_mul(0xc2b2_ae35);
+    h ^= h >> 16;
+
+    h
+}
+
+/// MurmurHash3 64 bit finalization mix
+#[inline]
+fn fmix64(mut k: u64) -> u64 {
+    k ^= k >> 33;
+    k = k.wrapping_mul(0xff51_afd7_ed55_8ccd);
+    k ^= k >> 33;
+    k = k.wrapping_mul(0xc4ce_b9fe_1a85_ec53);
+    k ^= k >> 33;
+
+    k
+}
+
+/// Jump consistent hash를 이용하여 `key`가 속할 bucket 번호(`0..buckets`) 반환
+///
+/// Bucket 수가 `n`에서 `n + 1`로 증가할 경우 약 `1 / (n + 1)`의 key만 새 bucket으로 이동한다.
+///
+/// # Arguments
+///
+/// - `key` - 64 bit key
+/// - `buckets` - 전체 bucket 수
+///
+/// # Return
+///
+/// - Bucket 번호 `Result<u32, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - `buckets`가 0일 경우
+///
+/// # Link
+///
+/// - [A Fast, Minimal Memory, Consistent Hash Algorithm](https://arxiv.org/abs/1406.2294)
+pub fn jump_hash(mut key: u64, buckets: u32) -> Result<u32, InvalidArgumentError> {
+    if buckets == 0 {
+        return Err(InvalidArgumentError::from("bucket 수는 0보다 커야 합니다."));
+    }
+
+    let mut b: i64 = -1;
+    let mut j: i64 = 0;
+
+    while j < buckets as i64 {
+        b = j;
+        key = key.wrapping_mul(2_862_933_555_777_941_757).wrapping_add(1);
+        j = ((b + 1) as f64 * ((1u64 << 31) as f64 / ((key >> 33) + 1) as f64)) as i64;
+    }
+
+    Ok(b as u32)
+}
+
+/// 임의의 bytes key를 [fnv1a]로 hash 처리한 후 [jump_hash]로 bucket 번호 반환
+///
+/// # Arguments
+///
+/// - `key` - Key
+/// - `buckets` - 전체 bucket 수
+///
+/// # Return
+///
+/// - Bucket 번호 `Result<u32, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - `buckets`가 0일 경우
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::hash_util::consistent_hash;
+///
+/// let bucket = consistent_hash(b"user:1234", 16).unwrap();
+///
+/// assert!(bucket < 16);
+/// assert_eq!(bucket, consistent_hash(b"user:1234", 16).unwrap());
+/// assert!(consistent_hash(b"user:1234", 0).is_err());
+/// ```
+pub fn consistent_hash(key: &[u8], buckets: u32) -> Result<u32, InvalidArgumentError> {
+    jump_hash(fnv1a(key), buckets)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fnv1a_test() {
+        assert_eq!(0x85944171f73967e8, fnv1a(b"foobar"));
+        assert_eq!(0xbf9cf968, fnv1a_32(b"foobar"));
+    }
+
+    #[test]
+    fn murmur3_32_test() {
+        assert_eq!(0x514e28b7, murmur3_32(b"", 1));
+        assert_eq!(0xfaf6cdb3, murmur3_32(b"Hello, world!", 1234));
+        assert_eq!(
+            0x2e4ff723,
+            murmur3_32(b"The quick brown fox jumps over the lazy dog", 0)
+        );
+        // 4 bytes 미만 tail
+        assert_eq!(0x3c2569b2, murmur3_32(b"a", 0));
+    }
+
+    #[test]
+    fn murmur3_128_test() {
+        let target = b"The quick brown fox jumps over the lazy dog";
+
+        assert_eq!(0x7a433ca9c49a9347_e34bbc7bbc071b6c, murmur3_128(target, 0));
+        assert_eq!(
+            0x5b1e906a48ae1d19_cbd8a7b341bd9b02,
+            murmur3_128(b"hello", 0)
+        );
+        // 8 bytes 미만 tail
+        assert_eq!(0xe6b53a48510e895a_85555565f6597889, murmur3_128(b"a", 0));
+    }
+
+    #[test]
+    fn jump_hash_test() {
+        assert!(jump_hash(1, 0).is_err());
+
+        for key in 0..1000u64 {
+            assert_eq!(0, jump_hash(key, 1).unwrap());
+        }
+
+        // bucket 증가시 이동하는 key는 새 bucket으로만 이동
+        let mut moved = 0;
+
+        for key in 0..10_000u64 {
+            let before = jump_hash(key, 10).unwrap();
+            let after = jump_hash(key, 11).unwrap();
+
+            if before != after {
+                assert_eq!(10, after);
+                moved += 1;
+            }
+        }
+
+        // 약 1/11
+        assert!(moved > 700 && moved < 1100, "moved: {moved}");
+    }
+}
@@ -0,0 +1,480 @@
+//! 다국어(i18n) 메시지 관련 함수 모음
+//!
+//! locale별 `key -> message` 목록을 JSON 혹은 TOML 형식으로 불러와 관리하는 [MessageBundle]을 제공한다.
+//! 중첩된 항목은 `.`으로 연결된 key로 변환된다(e.g. `{"mail": {"subject": ".."}}` -> `mail.subject`).
+//!
+//! 메시지 검색시 `ko-KR` -> `ko` -> 기본 locale 순서로 검색하며, 메시지의 `{name}` 형태 placeholder는
+//! [MessageBundle::format]에 전달된 인자로 치환된다.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use crate::error::InvalidArgumentError;
+use crate::string_util::interpolate::{interpolate_with, MissingKey};
+
+/// 지원 파일 형식
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MessageFormat {
+    /// JSON
+    Json,
+
+    /// TOML
+    Toml,
+}
+
+impl MessageFormat {
+    /// 파일 확장자로 형식 판단
+    ///
+    /// # Return
+    ///
+    /// - `json` 혹은 `toml`(대소문자 무시)이 아닐 경우 `None`
+    pub fn from_path(path: &Path) -> Option<MessageFormat> {
+        match path.extension()?.to_str()?.to_ascii_lowercase().as_str() {
+            "json" => Some(MessageFormat::Json),
+            "toml" => Some(MessageFormat::Toml),
+            _ => None,
+        }
+    }
+}
+
+/// locale별 메시지 모음
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::i18n_util::MessageBundle;
+///
+/// let mut bundle = MessageBundle::new("en");
+///
+/// bundle.load_json_str("en", r#"{"greeting": "Hello, {name}!", "bye": "Bye"}"#).unwrap();
+/// bundle.load_toml_str("ko", r#"greeting = "{name}님, 안녕하세요!""#).unwrap();
+///
+/// assert_eq!(
+///     Some("홍길동님, 안녕하세요!".to_owned()),
+///     bundle.format("ko-KR", "greeting", &[("name", "홍길동")]).unwrap()
+/// );
+/// // ko-KR -> ko -> en
+/// assert_eq!(Some("Bye"), bundle.get("ko-KR", "bye"));
+/// assert_eq!(None, bundle.get("ko-KR", "unknown"));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MessageBundle {
+    /// 기본 locale
+    default_locale: String,
+
+    /// locale별 메시지
+    messages: HashMap<String, HashMap<String, String>>,
+}
+
+impl MessageBundle {
+    /// [MessageBundle] 생성
+    ///
+    /// # Arguments
+    ///
+    /// - `default_locale` - 메시지를 찾지 못한 경우 마지막으로 검색할 locale (e.g. `en`)
+    pub fn new(default_locale: &str) -> Self {
+        MessageBundle {
+            default_locale: normalize_locale(default_locale),
+            messages: HashMap::new(),
+        }
+    }
+
+    /// 기본 locale 반환
+    #[inline]
+    pub fn default_locale(&self) -> &str {
+        self.default_locale.as_str()
+    }
+
+    /// 등록된 locale 목록 반환(정렬)
+    pub fn locales(&self) -> Vec<&str> {
+        let mut locales: Vec<&str> = self.messages.keys().map(|v| v.as_str()).collect();
+
+        locales.sort_unstable();
+
+        locales
+    }
+
+    /// 메시지 추가. 동일한 key가 있을 경우 대체한다.
+    pub fn insert(&mut self, locale: &str, key: &str, message: &str) {
+        self.messages
+            .entry(normalize_locale(locale))
+            .or_default()
+            .insert(key.to_owned(), message.to_owned());
+    }
+
+    /// JSON 문자열에서 메시지 추가
+    ///
+    /// # Arguments
+    ///
+    /// - `locale` - 대상 locale
+    /// - `source` - JSON object 문자열
+    ///
+    /// # Errors
+    ///
+    /// - [InvalidArgumentError] - 잘못된 JSON 형식 혹은 배열/null 항목 포함
+    pub fn load_json_str(
+        &mut self,
+        locale: &str,
+        source: &str,
+    ) -> Result<(), InvalidArgumentError> {
+        let value: serde_json::Value = serde_json::from_str(source)
+            .map_err(|e| InvalidArgumentError::new(&format!("JSON 형식 오류 입니다({}).", e)))?;
+        let mut flat: Vec<(String, String)> = vec![];
+
+        match value {
+            serde_json::Value::Object(map) => flatten_json("", map, &mut flat)?,
+            _ => return Err(InvalidArgumentError::from("JSON object 형식이 아닙니다.")),
+        }
+
+        self.extend(locale, flat);
+
+        Ok(())
+    }
+
+    /// TOML 문자열에서 메시지 추가
+    ///
+    /// # Arguments
+    ///
+    /// - `locale` - 대상 locale
+    /// - `source` - TOML 문자열
+    ///
+    /// # Errors
+    ///
+    /// - [InvalidArgumentError] - 잘못된 TOML 형식 혹은 배열 항목 포함
+    pub fn load_toml_str(
+        &mut self,
+        locale: &str,
+        source: &str,
+    ) -> Result<(), InvalidArgumentError> {
+        let table: toml::Table = source.parse().map_err(|e: toml::de::Error| {
+            InvalidArgumentError::new(&format!("TOML 형식 오류 입니다({}).", e.message()))
+        })?;
+        let mut flat: Vec<(String, String)> = vec![];
+
+        flatten_toml("", table, &mut flat)?;
+        self.extend(locale, flat);
+
+        Ok(())
+    }
+
+    /// 파일에서 메시지 추가. 파일 형식은 확장자(`json`, `toml`)로 판단한다.
+    ///
+    /// # Errors
+    ///
+    /// - [InvalidArgumentError] - 지원하지 않는 확장자, 파일 읽기 실패 혹은 형식 오류
+    pub fn load_file<P: AsRef<Path>>(
+        &mut self,
+        locale: &str,
+        path: P,
+    ) -> Result<(), InvalidArgumentError> {
+        let path = path.as_ref();
+        let format = MessageFormat::from_path(path).ok_or_else(|| {
+            InvalidArgumentError::new(&format!("지원하지 않는 파일 형식 입니다({:?}).", path))
+        })?;
+        let source = fs::read_to_string(path).map_err(|e| {
+            InvalidArgumentError::new(&format!("파일 읽기 실패 입니다({:?}, {}).", path, e))
+        })?;
+
+        match format {
+            MessageFormat::Json => self.load_json_str(locale, &source),
+            MessageFormat::Toml => self.load_toml_str(locale, &source),
+        }
+    }
+
+    /// 디렉토리 내 `<locale>.json` 혹은 `<locale>.toml` 파일에서 메시지 추가
+    ///
+    /// 지원하지 않는 확장자의 파일 및 하위 디렉토리는 무시한다.
+    ///
+    /// # Return
+    ///
+    /// - 불러온 파일 수
+    ///
+    /// # Errors
+    ///
+    /// - [InvalidArgumentError] - 디렉토리 읽기 실패 혹은 파일 형식 오류
+    pub fn load_dir<P: AsRef<Path>>(&mut self, dir: P) -> Result<usize, InvalidArgumentError> {
+        let dir = dir.as_ref();
+        let entries = fs::read_dir(dir).map_err(|e| {
+            InvalidArgumentError::new(&format!("디렉토리 읽기 실패 입니다({:?}, {}).", dir, e))
+        })?;
+        let mut count = 0;
+
+        for entry in entries.flatten() {
+            let path = entry.path();
+
+            if !path.is_file() || MessageFormat::from_path(&path).is_none() {
+                continue;
+            }
+
+            if let Some(locale) = path.file_stem().and_then(|v| v.to_str()) {
+                self.load_file(locale, &path)?;
+                count += 1;
+            }
+        }
+
+        Ok(count)
+    }
+
+    /// 메시지 검색 순서 반환
+    ///
+    /// `zh-Hant-TW` -> `zh-Hant` -> `zh` -> 기본 locale 순서이며 중복은 제외된다.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cliff3_util::i18n_util::MessageBundle;
+    ///
+    /// let bundle = MessageBundle::new("en");
+    ///
+    /// assert_eq!(vec!["ko-KR", "ko", "en"], bundle.fallback_chain("ko_kr"));
+    /// assert_eq!(vec!["en"], bundle.fallback_chain("en"));
+    /// ```
+    pub fn fallback_chain(&self, locale: &str) -> Vec<String> {
+        let mut chain: Vec<String> = vec![];
+        let mut current = normalize_locale(locale);
+
+        loop {
+            chain.push(current.clone());
+
+            match current.rfind('-') {
+                Some(i) => current.truncate(i),
+                None => break,
+            }
+        }
+
+        if !self.default_locale.is_empty() && !chain.contains(&self.default_locale) {
+            chain.push(self.default_locale.clone());
+        }
+
+        chain
+    }
+
+    /// 메시지 검색
+    ///
+    /// [MessageBundle::fallback_chain] 순서로 검색한다.
+    pub fn get(&self, locale: &str, key: &str) -> Option<&str> {
+        self.fallback_chain(locale).iter().find_map(|l| {
+            self.messages
+                .get(l)
+                .and_then(|m| m.get(key))
+                .map(|v| v.as_str())
+        })
+    }
+
+    /// 메시지 검색 후 placeholder 치환
+    ///
+    /// `{name}` 형태의 placeholder를 `args`의 값으로 치환하며, 인자가 없는 placeholder는 그대로 유지한다.
+    /// `{{`, `}}`는 각각 `{`, `}`로 변환된다([interpolate_with] 참고).
+    ///
+    /// # Arguments
+    ///
+    /// - `locale` - 대상 locale
+    /// - `key` - 메시지 key
+    /// - `args` - `(placeholder 명칭, 값)` 목록
+    ///
+    /// # Return
+    ///
+    /// - 치환 결과. 메시지가 없을 경우 `None` `Result<Option<String>, InvalidArgumentError>`
+    ///
+    /// # Errors
+    ///
+    /// - [InvalidArgumentError] - 메시지에 닫히지 않은 `{`, 짝이 없는 `}` 혹은 빈 명칭이 있을 경우
+    pub fn format(
+        &self,
+        locale: &str,
+        key: &str,
+        args: &[(&str, &str)],
+    ) -> Result<Option<String>, InvalidArgumentError> {
+        self.get(locale, key)
+            .map(|v| interpolate_with(v, args, MissingKey::Keep))
+            .transpose()
+    }
+
+    fn extend(&mut self, locale: &str, flat: Vec<(String, String)>) {
+        self.messages
+            .entry(normalize_locale(locale))
+            .or_default()
+            .extend(flat);
+    }
+}
+
+/// locale 표기 통일 (e.g. `ko_kr` -> `ko-KR`, `zh-hant-tw` -> `zh-Hant-TW`)
+fn normalize_locale(locale: &str) -> String {
+    locale
+        .trim()
+        .split(['-', '_'])
+        .filter(|v| !v.is_empty())
+        .enumerate()
+        .map(|(i, v)| match (i, v.len()) {
+            (0, _) => v.to_ascii_lowercase(),
+            (_, 2) => v.to_ascii_uppercase(),
+            // script subtag는 영문자 4자만 인정(그 외는 그대로 사용)
+            (_, 4) if v.chars().all(|c| c.is_ascii_alphabetic()) => {
+                let lower = v.to_ascii_lowercase();
+
+                lower[..1].to_ascii_uppercase() + &lower[1..]
+            }
+            _ => v.to_owned(),
+        })
+        .collect::<Vec<String>>()
+        .join("-")
+}
+
+fn join_key(prefix: &str, key: &str) -> String {
+    if prefix.is_empty() {
+        key.to_owned()
+    } else {
+        format!("{}.{}", prefix, key)
+    }
+}
+
+fn flatten_json(
+    prefix: &str,
+    map: serde_json::Map<String, serde_json::Value>,
+    flat: &mut Vec<(String, String)>,
+) -> Result<(), InvalidArgumentError> {
+    for (k, v) in map {
+        let key = join_key(prefix, &k);
+
+        match v {
+            serde_json::Value::Object(m) => flatten_json(&key, m, flat)?,
+            serde_json::Value::String(s) => flat.push((key, s)),
+            serde_json::Value::Number(n) => flat.push((key, n.to_string())),
+            serde_json::Value::Bool(b) => flat.push((key, b.to_string())),
+            _ => {
+                return Err(InvalidArgumentError::new(&format!(
+                    "지원하지 않는 메시지 형식 입니다({}).",
+                    key
+                )))
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn flatten_toml(
+    prefix: &str,
+    table: toml::Table,
+    flat: &mut Vec<(String, String)>,
+) -> Result<(), InvalidArgumentError> {
+    for (k, v) in table {
+        let key = join_key(prefix, &k);
+
+        match v {
+            toml::Value::Table(t) => flatten_toml(&key, t, flat)?,
+            toml::Value::String(s) => flat.push((key, s)),
+            toml::Value::Integer(i) => flat.push((key, i.to_string())),
+            toml::Value::Float(f) => flat.push((key, f.to_string())),
+            toml::Value::Boolean(b) => flat.push((key, b.to_string())),
+            _ => {
+                return Err(InvalidArgumentError::new(&format!(
+                    "지원하지 않는 메시지 형식 입니다({}).",
+                    key
+                )))
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_locale_test() {
+        assert_eq!("ko-KR", normalize_locale("ko_kr"));
+        assert_eq!("ko-KR", normalize_locale(" KO-KR "));
+        assert_eq!("zh-Hant-TW", normalize_locale("zh-hant-tw"));
+        assert_eq!("es-419", normalize_locale("es-419"));
+        assert_eq!("en-éab", normalize_locale("en-éab"));
+        assert_eq!("en-1234", normalize_locale("en-1234"));
+    }
+
+    #[test]
+    fn non_ascii_locale_test() {
+        let mut bundle = MessageBundle::new("ko");
+
+        bundle.insert("en-éab", "k", "v");
+
+        assert_eq!(Some("v"), bundle.get("en-éab", "k"));
+        assert_eq!(None, MessageBundle::new("ko").get("en-éab", "k"));
+    }
+
+    #[test]
+    fn load_test() {
+        let mut bundle = MessageBundle::new("en");
+
+        bundle
+            .load_json_str(
+                "en",
+                r#"{"mail": {"subject": "Order {id}", "retry": 3}, "ok": true}"#,
+            )
+            .unwrap();
+        bundle
+            .load_toml_str("ko", "[mail]\nsubject = \"주문 {id}\"\n")
+            .unwrap();
+
+        assert_eq!(Some("Order {id}"), bundle.get("en-US", "mail.subject"));
+        assert_eq!(Some("3"), bundle.get("ko", "mail.retry"));
+        assert_eq!(Some("true"), bundle.get("en", "ok"));
+        assert_eq!(
+            Some("주문 10".to_owned()),
+            bundle
+                .format("ko-KR", "mail.subject", &[("id", "10")])
+                .unwrap()
+        );
+        assert_eq!(vec!["en", "ko"], bundle.locales());
+
+        assert!(bundle.load_json_str("en", "[1, 2]").is_err());
+        assert!(bundle.load_json_str("en", r#"{"a": [1]}"#).is_err());
+        assert!(bundle.load_toml_str("en", "a = ").is_err());
+        assert!(bundle.load_toml_str("en", "a = [1]").is_err());
+    }
+
+    #[test]
+    fn format_test() {
+        let mut bundle = MessageBundle::new("ko");
+
+        bundle.insert("ko", "count", "{name}님 {count}건");
+        bundle.insert("ko", "escape", "{{name}} {name} {unknown}");
+        bundle.insert("ko", "broken", "미완성 {name");
+
+        let args = [("name", "홍길동"), ("count", "3")];
+
+        assert_eq!(
+            Ok(Some("홍길동님 3건".to_owned())),
+            bundle.format("ko", "count", &args)
+        );
+        assert_eq!(
+            Ok(Some("{name} 홍길동 {unknown}".to_owned())),
+            bundle.format("ko", "escape", &args)
+        );
+        assert_eq!(Ok(None), bundle.format("ko", "none", &args));
+        assert!(bundle.format("ko", "broken", &args).is_err());
+    }
+
+    #[test]
+    fn load_dir_test() {
+        let dir = std::env::temp_dir().join(format!("cliff3_i18n_{}", std::process::id()));
+
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("ko-KR.toml"), "hello = \"안녕\"").unwrap();
+        fs::write(dir.join("en.json"), r#"{"hello": "Hi", "bye": "Bye"}"#).unwrap();
+        fs::write(dir.join("readme.txt"), "ignored").unwrap();
+
+        let mut bundle = MessageBundle::new("en");
+        let result = bundle.load_dir(&dir);
+
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(Ok(2), result);
+        assert_eq!(Some("안녕"), bundle.get("ko-KR", "hello"));
+        assert_eq!(Some("Bye"), bundle.get("ko-KR", "bye"));
+        assert!(bundle.load_file("en", "none.yaml").is_err());
+    }
+}
@@ -135,6 +135,10 @@ impl DirectoryDateType {
 ///
 /// assert!(deleted_dir.is_ok());
 /// ```
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "debug", skip_all, fields(parent = %parent_path.display()))
+)]
 pub fn generate_path(
     parent_path: &Path,
     date_type: DirectoryDateType,
@@ -167,6 +171,49 @@ pub fn generate_path(
     return Ok(result.into_boxed_path());
 }
 
+/// [generate_path]의 비동기 버전
+///
+/// `async` feature 활성화시 사용 가능하며 [tokio::fs::create_dir_all]을 이용한다.
+///
+/// # Arguments
+///
+/// - `parent_path` - 생성하고자 하는 경로의 부모 directory
+/// - `date_type` - [DirectoryDateType]
+/// - `separator` - 날짜 정보 사이에 입력될 문자열 (e.g. **-**, **_**)
+///
+/// # Return
+///
+/// - 생성 결과 `Result<Box<Path>, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] 부모 경로가 존재하지 않을 경우 혹은 [tokio::fs::create_dir_all] 실패
+#[cfg(feature = "async")]
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "debug", skip_all, fields(parent = %parent_path.display()))
+)]
+pub async fn generate_path_async(
+    parent_path: &Path,
+    date_type: DirectoryDateType,
+    separator: Option<&str>,
+) -> Result<Box<Path>, InvalidArgumentError> {
+    if !tokio::fs::try_exists(parent_path).await.unwrap_or(false) {
+        let message = format!("[{:?}] 경로가 존재하지 않습니다.", parent_path.as_os_str());
+
+        return Err(InvalidArgumentError::new(message.as_str()));
+    }
+
+    let dir_string = date_type.generate_path_string(&Local::now(), separator);
+    let result = parent_path.join(dir_string);
+
+    tokio::fs::create_dir_all(&result)
+        .await
+        .map_err(|e| InvalidArgumentError::new(format!("{:?}", e).as_str()))?;
+
+    Ok(result.into_boxed_path())
+}
+
 #[cfg(test)]
 mod tests {
     use crate::io_util::{generate_path, DirectoryDateType};
@@ -194,4 +241,26 @@ mod tests {
 
         assert!(deleted_dir.is_ok());
     }
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn generate_path_async_test() {
+        use crate::io_util::generate_path_async;
+
+        let parent = std::env::temp_dir();
+        let result = generate_path_async(&parent, DirectoryDateType::YYYYMM, Some("-"))
+            .await
+            .unwrap();
+
+        assert!(result.exists());
+        assert_eq!(parent, result.parent().unwrap());
+        assert!(generate_path_async(
+            Path::new("/not/exist/path"),
+            DirectoryDateType::YYYY,
+            None
+        )
+        .await
+        .is_err());
+
+        std::fs::remove_dir(result).unwrap();
+    }
 }
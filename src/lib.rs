@@ -2,14 +2,84 @@
 //!
 //! # Feature flags
 //!
+//! - `std` - 표준 라이브러리(std) 사용. `std`가 필요한 feature는 모두 `std`를 포함
 //! - `string` - 문자열 유틸리티 함수 활성화
-//! - `encrypt` - 암복호화 및 Hash 관련 함수 활성화
+//! - `encrypt` - 암복호화 및 Hash 관련 함수 활성화(openssl 포함)
 //! - `io` - I/O 유틸리티 관련 함수 활성화
-//! - `date` - 날짜(chrono) 관련 함수 활성화
+//! - `date` - 날짜(chrono, chrono-tz) 관련 함수 활성화
+//! - `rate_limit` - 처리율 제한(rate limit) 관련 함수 활성화
+//! - `cache` - TTL/LRU in-memory cache 활성화
+//! - `page` - 페이지 처리(pagination) 관련 함수 활성화
+//! - `hash` - 비암호화(non-cryptographic) hash 관련 함수 활성화
+//! - `validate` - 식별번호(자동차 등록번호, 운전면허번호 등) 유효성 검사 관련 함수 활성화
+//! - `retry` - 재시도(retry) 관련 함수 활성화
+//! - `schedule` - cron 표현식 및 고정 간격 작업 스케줄러 활성화(`date` 포함)
+//! - `version` - Semantic version 해석 및 비교 관련 함수 활성화
+//! - `table` - CLI 출력용 텍스트 표 관련 함수 활성화(`string` 포함)
+//! - `timing` - 실행 시간 측정 및 집계 관련 함수 활성화
+//! - `batch` - 동시 실행 수 제한 일괄 처리 관련 함수 활성화
+//! - `debounce` - Debounce/throttle 관련 함수 활성화
+//! - `codec` - 바이트 배열 변환(hexdump, base32 등) 관련 함수 활성화
 //! - `default` - 위 함수 모두 포함
+//!
+//! 각 feature는 해당 module에서 사용하는 의존성만 포함한다. 일부 module만 필요한 경우(e.g. WASM 등 실행
+//! 파일 크기가 중요한 환경) 아래와 같이 `default`를 제외하고 필요한 feature만 지정하여 timezone data(chrono-tz)
+//! 및 암호화(openssl) 의존성을 제외할 수 있다.
+//!
+//! ```toml
+//! [dependencies]
+//! cliff3-util = { version = "0.2.5", default-features = false, features = ["string", "validate"] }
+//! ```
+//!
+//! `std` feature(`default`에 포함)를 제외할 경우 `no_std`(`alloc` 필요) 환경에서 사용할 수 있다. `no_std`
+//! 환경에서는 `std`가 필요하지 않은 `string`(무작위 문자열 생성 제외), `validate`, `hash` 및 `codec`
+//! feature만 지정할 수 있다.
+//!
+//! ```toml
+//! [dependencies]
+//! cliff3-util = { version = "0.2.5", default-features = false, features = ["validate", "codec"] }
+//! ```
+//!
+//! `wasm32-unknown-unknown` 환경에서는 `string`, `date`, `validate`, `hash`, `codec`, `page`, `version` 및
+//! `table` feature를 사용할 수 있다. 무작위 값은 `getrandom`의 `js` 방식(`crypto.getRandomValues`)으로
+//! 생성하며, 파일 I/O 관련 module(`io_util`)은 제외된다. 시각(`std::time::Instant`) 및 thread를 사용하는
+//! module은 실행시 panic이 발생하므로 사용하지 않는다.
+//!
+//! 다음 feature는 `default`에 포함되지 않으며 필요한 경우 별도로 활성화한다.
+//!
+//! - `async` - 비동기(tokio) 관련 함수 활성화(`io_util`, `http_util`, `retry_util`, `rate_limit_util` 및
+//!   `encrypt_util` AES 스트림 처리의 `_async` 함수)
+//! - `http` - HTTP 요청 관련 함수 활성화
+//! - `i18n` - 다국어 메시지(JSON/TOML) 관련 함수 활성화(`string` 포함)
+//! - `tracing` - `tracing` crate를 이용한 실행 정보 기록 활성화. 파일 I/O, 암복호화, HTTP 요청 및 일괄 처리
+//!   함수는 크기, 알고리즘 등을 기록한 `debug` 수준 span을 생성한다(입력 값 및 URL query는 기록하지 않음)
+//! - `config` - 기본값, 설정 파일, 환경 변수 및 명령행 지정값 병합 관련 함수 활성화
+//! - `icu` - ICU 기반 언어별(한국어, 일본어, 라틴 문자) 문자열 정렬 활성화(`string` 포함)
+//! - `euckr` - EUC-KR(CP949) 문자열 변환 관련 함수 활성화(`string` 포함, `no_std` 사용 가능)
+//! - `anonymize` - 레코드 및 CSV 비식별화(hash, 마스킹, 가명처리 등) 관련 함수 활성화(`string` 포함)
+//! - `diff` - serde 기반 구조체 변경 내역(diff) 관련 함수 활성화(`string` 포함)
+//! - `convert` - JSON/TOML/YAML 형식 변환 관련 함수 활성화
+//! - `template` - mustache 형식 template 처리 관련 함수 활성화(`string` 포함)
+//! - `test-util` - 테스트 데이터용 가짜 식별번호 생성 관련 함수 활성화(`validate` 포함)
+//! - `serde` - 공개 데이터 구조체(검증 결과, 오류, 옵션 등)의 `Serialize`/`Deserialize` 구현 활성화. `date`와
+//!   함께 활성화할 경우 날짜 및 시간 패턴 직렬화 module(`date_util::serde_pattern`) 제공
+//! - `json` - `serde_json::Value`를 문자열 치환(`string_util::interpolate`) 값으로 사용. `http`, `i18n`,
+//!   `anonymize`, `diff`, `convert`, `template` 및 `config`에 포함
+//! - `rayon` - `rayon`을 이용한 대량 처리(날짜 변환, 검증, 마스킹, 디렉토리 hash) `par_` 함수 활성화
+//! - `cli` - `cliff3` 명령행 도구(`date`, `hash`, `encrypt`/`decrypt`, `mask`, `uuid`) 빌드
+//!   (`cargo install cliff3-util --features cli`)
+//! - `proptest` - 날짜 및 시간 문자열, 전화번호, 주민등록번호, 사업자등록번호 및 AES 암호문의 `proptest`
+//!   strategy 활성화(`validate`, `date` 포함)
+//! - `derive` - `#[derive(Maskable)]`, `#[timed]` 등 derive/attribute macro 활성화
+
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
 
 pub mod error;
 
+pub mod prelude;
+
 // string_util.rs 파일에 다음과 같이 설정하여도 됨
 // #![cfg(any(feature = "default", feature = "string"))]
 #[cfg(any(feature = "string", feature = "default"))]
@@ -18,8 +88,77 @@ pub mod string_util;
 #[cfg(any(feature = "encrypt", feature = "default"))]
 pub mod encrypt_util;
 
-#[cfg(any(feature = "io", feature = "default"))]
+#[cfg(all(
+    any(feature = "io", feature = "default"),
+    not(all(target_arch = "wasm32", target_os = "unknown"))
+))]
 pub mod io_util;
 
 #[cfg(any(feature = "date", feature = "default"))]
 pub mod date_util;
+
+#[cfg(any(feature = "rate_limit", feature = "default"))]
+pub mod rate_limit_util;
+
+#[cfg(any(feature = "cache", feature = "default"))]
+pub mod cache_util;
+
+#[cfg(any(feature = "page", feature = "default"))]
+pub mod page_util;
+
+#[cfg(any(feature = "hash", feature = "default"))]
+pub mod hash_util;
+
+#[cfg(any(feature = "validate", feature = "default"))]
+pub mod validate_util;
+
+#[cfg(any(feature = "retry", feature = "default"))]
+pub mod retry_util;
+
+#[cfg(any(feature = "schedule", feature = "default"))]
+pub mod schedule_util;
+
+#[cfg(any(feature = "version", feature = "default"))]
+pub mod version_util;
+
+#[cfg(any(feature = "table", feature = "default"))]
+pub mod table_util;
+
+#[cfg(any(feature = "timing", feature = "default"))]
+pub mod timing_util;
+
+#[cfg(any(feature = "batch", feature = "default"))]
+pub mod batch_util;
+
+#[cfg(any(feature = "debounce", feature = "default"))]
+pub mod debounce_util;
+
+#[cfg(any(feature = "codec", feature = "default"))]
+pub mod codec_util;
+
+#[cfg(feature = "http")]
+pub mod http_util;
+
+#[cfg(feature = "i18n")]
+pub mod i18n_util;
+
+#[cfg(feature = "config")]
+pub mod config_util;
+
+#[cfg(feature = "test-util")]
+pub mod fake_util;
+
+#[cfg(feature = "anonymize")]
+pub mod anonymize_util;
+
+#[cfg(feature = "diff")]
+pub mod diff_util;
+
+#[cfg(feature = "convert")]
+pub mod convert_util;
+
+#[cfg(feature = "template")]
+pub mod template_util;
+
+#[cfg(feature = "proptest")]
+pub mod proptest_util;
@@ -0,0 +1,318 @@
+//! 페이지 처리(pagination) 관련 함수 모음
+
+use crate::error::InvalidArgumentError;
+
+/// 페이지 번호 목록 항목
+///
+/// # Link
+///
+/// - [Paginator::page_window]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PageItem {
+    /// 페이지 번호
+    Page(u64),
+
+    /// 생략된 페이지 구간(`…`)
+    Ellipsis,
+}
+
+/// 페이지 계산기
+///
+/// 페이지 번호는 **1**부터 시작하며 전체 페이지 수는 전체 항목이 없더라도 최소 1이다.
+/// 현재 페이지가 범위를 벗어날 경우 `1` 혹은 마지막 페이지로 보정한다.
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::page_util::{PageItem, Paginator};
+///
+/// let paginator = Paginator::new(195, 10, 5).unwrap();
+///
+/// assert_eq!(20, paginator.total_pages());
+/// assert_eq!(40, paginator.offset());
+/// assert_eq!(10, paginator.limit());
+/// assert!(paginator.has_prev());
+/// assert!(paginator.has_next());
+///
+/// // 1 … 4 5 6 … 20
+/// assert_eq!(
+///     vec![
+///         PageItem::Page(1),
+///         PageItem::Ellipsis,
+///         PageItem::Page(4),
+///         PageItem::Page(5),
+///         PageItem::Page(6),
+///         PageItem::Ellipsis,
+///         PageItem::Page(20),
+///     ],
+///     paginator.page_window(1)
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Paginator {
+    /// 전체 항목 수
+    total_items: u64,
+
+    /// 페이지당 항목 수
+    page_size: u64,
+
+    /// 현재 페이지(보정된 값)
+    current_page: u64,
+}
+
+impl Paginator {
+    /// [Paginator] 생성
+    ///
+    /// # Arguments
+    ///
+    /// - `total_items` - 전체 항목 수
+    /// - `page_size` - 페이지당 항목 수
+    /// - `current_page` - 현재 페이지(1부터 시작). 범위를 벗어날 경우 보정
+    ///
+    /// # Return
+    ///
+    /// - 생성 결과 `Result<Paginator, InvalidArgumentError>`
+    ///
+    /// # Errors
+    ///
+    /// - [InvalidArgumentError] - `page_size`가 0일 경우
+    pub fn new(
+        total_items: u64,
+        page_size: u64,
+        current_page: u64,
+    ) -> Result<Self, InvalidArgumentError> {
+        if page_size == 0 {
+            return Err(InvalidArgumentError::from(
+                "페이지당 항목 수는 0보다 커야 합니다.",
+            ));
+        }
+
+        let total_pages = Self::calculate_total_pages(total_items, page_size);
+
+        Ok(Paginator {
+            total_items,
+            page_size,
+            current_page: current_page.clamp(1, total_pages),
+        })
+    }
+
+    #[inline]
+    fn calculate_total_pages(total_items: u64, page_size: u64) -> u64 {
+        total_items.div_ceil(page_size).max(1)
+    }
+
+    /// 전체 항목 수 반환
+    #[inline]
+    pub fn total_items(&self) -> u64 {
+        self.total_items
+    }
+
+    /// 페이지당 항목 수 반환
+    #[inline]
+    pub fn page_size(&self) -> u64 {
+        self.page_size
+    }
+
+    /// 현재 페이지 반환
+    #[inline]
+    pub fn current_page(&self) -> u64 {
+        self.current_page
+    }
+
+    /// 전체 페이지 수 반환
+    #[inline]
+    pub fn total_pages(&self) -> u64 {
+        Self::calculate_total_pages(self.total_items, self.page_size)
+    }
+
+    /// 현재 페이지의 첫 항목 위치(0부터 시작, SQL `OFFSET`) 반환
+    #[inline]
+    pub fn offset(&self) -> u64 {
+        (self.current_page - 1) * self.page_size
+    }
+
+    /// 조회할 항목 수(SQL `LIMIT`) 반환
+    #[inline]
+    pub fn limit(&self) -> u64 {
+        self.page_size
+    }
+
+    /// 현재 페이지에 실제로 포함되는 항목 수 반환
+    pub fn items_on_page(&self) -> u64 {
+        self.total_items
+            .saturating_sub(self.offset())
+            .min(self.page_size)
+    }
+
+    /// 이전 페이지 존재 여부
+    #[inline]
+    pub fn has_prev(&self) -> bool {
+        self.current_page > 1
+    }
+
+    /// 다음 페이지 존재 여부
+    #[inline]
+    pub fn has_next(&self) -> bool {
+        self.current_page < self.total_pages()
+    }
+
+    /// 이전 페이지 번호 반환
+    pub fn prev_page(&self) -> Option<u64> {
+        if self.has_prev() {
+            Some(self.current_page - 1)
+        } else {
+            None
+        }
+    }
+
+    /// 다음 페이지 번호 반환
+    pub fn next_page(&self) -> Option<u64> {
+        if self.has_next() {
+            Some(self.current_page + 1)
+        } else {
+            None
+        }
+    }
+
+    /// 화면 표시용 페이지 번호 목록 반환
+    ///
+    /// 첫 페이지, 마지막 페이지 및 현재 페이지 앞뒤로 `neighbors`개의 페이지를 포함하며, 나머지 구간은
+    /// [PageItem::Ellipsis]로 대체한다. 생략 구간이 한 페이지뿐일 경우 해당 페이지 번호를 그대로 표시한다.
+    ///
+    /// # Arguments
+    ///
+    /// - `neighbors` - 현재 페이지 앞뒤로 표시할 페이지 수
+    ///
+    /// # Return
+    ///
+    /// - 페이지 번호 목록
+    pub fn page_window(&self, neighbors: u64) -> Vec<PageItem> {
+        let total_pages = self.total_pages();
+        let start = self.current_page.saturating_sub(neighbors).max(1);
+        let end = self.current_page.saturating_add(neighbors).min(total_pages);
+        let mut result: Vec<PageItem> = vec![];
+
+        if start > 1 {
+            result.push(PageItem::Page(1));
+
+            match start {
+                2 => {}
+                3 => result.push(PageItem::Page(2)),
+                _ => result.push(PageItem::Ellipsis),
+            }
+        }
+
+        for page in start..=end {
+            result.push(PageItem::Page(page));
+        }
+
+        if end < total_pages {
+            match total_pages - end {
+                1 => {}
+                2 => result.push(PageItem::Page(total_pages - 1)),
+                _ => result.push(PageItem::Ellipsis),
+            }
+
+            result.push(PageItem::Page(total_pages));
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn paginator_test() {
+        assert!(Paginator::new(10, 0, 1).is_err());
+
+        let paginator = Paginator::new(101, 10, 11).unwrap();
+
+        assert_eq!(11, paginator.total_pages());
+        assert_eq!(100, paginator.offset());
+        assert_eq!(1, paginator.items_on_page());
+        assert!(!paginator.has_next());
+        assert_eq!(Some(10), paginator.prev_page());
+        assert_eq!(None, paginator.next_page());
+
+        // 정확히 나누어 떨어질 경우
+        let paginator = Paginator::new(100, 10, 1).unwrap();
+
+        assert_eq!(10, paginator.total_pages());
+        assert_eq!(0, paginator.offset());
+        assert_eq!(10, paginator.items_on_page());
+        assert!(!paginator.has_prev());
+        assert_eq!(Some(2), paginator.next_page());
+    }
+
+    #[test]
+    fn clamp_current_page_test() {
+        // 범위 초과
+        let paginator = Paginator::new(25, 10, 100).unwrap();
+
+        assert_eq!(3, paginator.current_page());
+        assert_eq!(20, paginator.offset());
+        assert_eq!(5, paginator.items_on_page());
+
+        // 0 페이지
+        assert_eq!(1, Paginator::new(25, 10, 0).unwrap().current_page());
+
+        // 항목 없음
+        let paginator = Paginator::new(0, 10, 3).unwrap();
+
+        assert_eq!(1, paginator.total_pages());
+        assert_eq!(1, paginator.current_page());
+        assert_eq!(0, paginator.offset());
+        assert_eq!(0, paginator.items_on_page());
+        assert!(!paginator.has_prev());
+        assert!(!paginator.has_next());
+        assert_eq!(vec![PageItem::Page(1)], paginator.page_window(2));
+    }
+
+    #[test]
+    fn page_window_test() {
+        use PageItem::{Ellipsis, Page};
+
+        let window = |current: u64| Paginator::new(200, 10, current).unwrap().page_window(1);
+
+        assert_eq!(vec![Page(1), Page(2), Ellipsis, Page(20)], window(1));
+        assert_eq!(
+            vec![Page(1), Page(2), Page(3), Ellipsis, Page(20)],
+            window(2)
+        );
+        // 생략 구간이 한 페이지일 경우 번호 표시
+        assert_eq!(
+            vec![Page(1), Page(2), Page(3), Page(4), Ellipsis, Page(20)],
+            window(3)
+        );
+        assert_eq!(
+            vec![
+                Page(1),
+                Ellipsis,
+                Page(9),
+                Page(10),
+                Page(11),
+                Ellipsis,
+                Page(20)
+            ],
+            window(10)
+        );
+        assert_eq!(
+            vec![Page(1), Ellipsis, Page(17), Page(18), Page(19), Page(20)],
+            window(18)
+        );
+        assert_eq!(vec![Page(1), Ellipsis, Page(19), Page(20)], window(20));
+
+        // 전체 페이지가 적을 경우 모두 표시
+        let paginator = Paginator::new(50, 10, 3).unwrap();
+
+        assert_eq!(
+            vec![Page(1), Page(2), Page(3), Page(4), Page(5)],
+            paginator.page_window(1)
+        );
+    }
+}
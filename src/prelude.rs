@@ -0,0 +1,236 @@
+//! 자주 사용하는 trait 모음
+//!
+//! 문자열, 경로 및 날짜에 대해 각 module의 함수를 method 형태로 호출할 수 있는 확장 trait([StrExt], [PathExt],
+//! [DateTimeExt])과 [LibError]를 제공한다. 활성화된 feature에 해당하는 method만 제공된다.
+//!
+//! ```rust
+//! use cliff3_util::prelude::*;
+//!
+//! assert_eq!("ho**@test.com", "hong@test.com".mask_email());
+//! assert_eq!("010-****-5678", "010-1234-5678".to_owned().mask_phone());
+//! assert_eq!(22, chrono::Datelike::day(&"20241122".parse_date("%Y%m%d").unwrap()));
+//! ```
+
+#[cfg(any(feature = "date", feature = "default"))]
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+#[cfg(any(feature = "date", feature = "default"))]
+use chrono_tz::Tz;
+
+#[cfg(any(feature = "string", feature = "default"))]
+use alloc::string::String;
+
+#[cfg(any(feature = "date", feature = "io", feature = "default"))]
+use crate::error::InvalidArgumentError;
+pub use crate::error::LibError;
+
+#[cfg(any(feature = "date", feature = "default"))]
+pub use crate::date_util::DateTimeExt;
+
+/// 문자열 확장 trait
+///
+/// [str]에 대해 구현되어 있으므로 [String]에서도 사용할 수 있다.
+pub trait StrExt {
+    /// 이메일 마스킹([crate::string_util::mask::mask_email])
+    #[cfg(any(feature = "string", feature = "default"))]
+    fn mask_email(&self) -> String;
+
+    /// 전화번호 마스킹([crate::string_util::mask::mask_phone])
+    #[cfg(any(feature = "string", feature = "default"))]
+    fn mask_phone(&self) -> String;
+
+    /// 전체 마스킹([crate::string_util::mask::mask_full])
+    #[cfg(any(feature = "string", feature = "default"))]
+    fn mask_full(&self) -> String;
+
+    /// 이메일 형식 여부([crate::string_util::validate_email])
+    #[cfg(any(feature = "string", feature = "default"))]
+    fn is_email(&self) -> bool;
+
+    /// 한글 초성 추출([crate::string_util::extract_initial_consonant])
+    #[cfg(any(feature = "string", feature = "default"))]
+    fn initial_consonants(&self) -> String;
+
+    /// 지정된 패턴의 날짜로 변환
+    ///
+    /// # Errors
+    ///
+    /// - [InvalidArgumentError] - 잘못된 날짜 형식 혹은 패턴
+    #[cfg(any(feature = "date", feature = "default"))]
+    fn parse_date(&self, pattern: &str) -> Result<NaiveDate, InvalidArgumentError>;
+
+    /// 지정된 패턴의 날짜 및 시간으로 변환
+    ///
+    /// # Errors
+    ///
+    /// - [InvalidArgumentError] - 잘못된 날짜 및 시간 형식 혹은 패턴
+    #[cfg(any(feature = "date", feature = "default"))]
+    fn parse_datetime(&self, pattern: &str) -> Result<NaiveDateTime, InvalidArgumentError>;
+
+    /// 지정된 timezone의 날짜 및 시간을 UTC로 변환([crate::date_util::local_datetime_to_utc])
+    ///
+    /// # Errors
+    ///
+    /// - [InvalidArgumentError] - 잘못된 날짜 및 시간 형식 혹은 패턴
+    #[cfg(any(feature = "date", feature = "default"))]
+    fn to_utc(&self, pattern: &str, timezone: &Tz) -> Result<DateTime<Utc>, InvalidArgumentError>;
+}
+
+impl StrExt for str {
+    #[cfg(any(feature = "string", feature = "default"))]
+    fn mask_email(&self) -> String {
+        crate::string_util::mask::mask_email(self)
+    }
+
+    #[cfg(any(feature = "string", feature = "default"))]
+    fn mask_phone(&self) -> String {
+        crate::string_util::mask::mask_phone(self)
+    }
+
+    #[cfg(any(feature = "string", feature = "default"))]
+    fn mask_full(&self) -> String {
+        crate::string_util::mask::mask_full(self)
+    }
+
+    #[cfg(any(feature = "string", feature = "default"))]
+    fn is_email(&self) -> bool {
+        crate::string_util::validate_email(Some(self)).unwrap_or(false)
+    }
+
+    #[cfg(any(feature = "string", feature = "default"))]
+    fn initial_consonants(&self) -> String {
+        crate::string_util::extract_initial_consonant(Some(self)).unwrap_or_default()
+    }
+
+    #[cfg(any(feature = "date", feature = "default"))]
+    fn parse_date(&self, pattern: &str) -> Result<NaiveDate, InvalidArgumentError> {
+        NaiveDate::parse_from_str(self, pattern)
+            .map_err(|e| InvalidArgumentError::new(&alloc::format!("{e:#?}")))
+    }
+
+    #[cfg(any(feature = "date", feature = "default"))]
+    fn parse_datetime(&self, pattern: &str) -> Result<NaiveDateTime, InvalidArgumentError> {
+        NaiveDateTime::parse_from_str(self, pattern)
+            .map_err(|e| InvalidArgumentError::new(&alloc::format!("{e:#?}")))
+    }
+
+    #[cfg(any(feature = "date", feature = "default"))]
+    fn to_utc(&self, pattern: &str, timezone: &Tz) -> Result<DateTime<Utc>, InvalidArgumentError> {
+        crate::date_util::local_datetime_to_utc(self, pattern, timezone)
+    }
+}
+
+/// 경로 확장 trait
+///
+/// [std::path::Path]에 대해 구현되어 있으므로 [std::path::PathBuf]에서도 사용할 수 있다.
+#[cfg(all(
+    any(feature = "io", feature = "default"),
+    not(all(target_arch = "wasm32", target_os = "unknown"))
+))]
+pub trait PathExt {
+    /// 하위에 현재 날짜 directory 생성([crate::io_util::generate_path])
+    ///
+    /// # Errors
+    ///
+    /// - [InvalidArgumentError] - 경로가 존재하지 않을 경우 혹은 directory 생성 실패
+    fn date_path(
+        &self,
+        date_type: crate::io_util::DirectoryDateType,
+        separator: Option<&str>,
+    ) -> Result<Box<std::path::Path>, InvalidArgumentError>;
+
+    /// [PathExt::date_path]의 비동기 버전([crate::io_util::generate_path_async])
+    ///
+    /// # Errors
+    ///
+    /// - [InvalidArgumentError] - 경로가 존재하지 않을 경우 혹은 directory 생성 실패
+    #[cfg(feature = "async")]
+    #[allow(async_fn_in_trait)]
+    async fn date_path_async(
+        &self,
+        date_type: crate::io_util::DirectoryDateType,
+        separator: Option<&str>,
+    ) -> Result<Box<std::path::Path>, InvalidArgumentError>;
+}
+
+#[cfg(all(
+    any(feature = "io", feature = "default"),
+    not(all(target_arch = "wasm32", target_os = "unknown"))
+))]
+impl PathExt for std::path::Path {
+    fn date_path(
+        &self,
+        date_type: crate::io_util::DirectoryDateType,
+        separator: Option<&str>,
+    ) -> Result<Box<std::path::Path>, InvalidArgumentError> {
+        crate::io_util::generate_path(self, date_type, separator)
+    }
+
+    #[cfg(feature = "async")]
+    async fn date_path_async(
+        &self,
+        date_type: crate::io_util::DirectoryDateType,
+        separator: Option<&str>,
+    ) -> Result<Box<std::path::Path>, InvalidArgumentError> {
+        crate::io_util::generate_path_async(self, date_type, separator).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(any(feature = "string", feature = "default"))]
+    #[test]
+    fn str_ext_test() {
+        use super::StrExt;
+
+        assert_eq!("ho**@test.com", "hong@test.com".mask_email());
+        assert_eq!("010-****-5678", "010-1234-5678".mask_phone());
+        assert_eq!("***", String::from("abc").mask_full());
+        assert!("hong@test.com".is_email());
+        assert!(!"hong".is_email());
+        assert_eq!("ㅎㄱ", "한글".initial_consonants());
+    }
+
+    #[cfg(any(feature = "date", feature = "default"))]
+    #[test]
+    fn str_ext_date_test() {
+        use super::{StrExt, Tz};
+        use chrono::{Datelike, Timelike};
+
+        let date = "20241122".parse_date("%Y%m%d").unwrap();
+
+        assert_eq!((2024, 11, 22), (date.year(), date.month(), date.day()));
+        assert!("20241132".parse_date("%Y%m%d").is_err());
+
+        let datetime = "2024-11-22 10:29".parse_datetime("%Y-%m-%d %H:%M").unwrap();
+
+        assert_eq!(29, datetime.minute());
+        assert_eq!(
+            1,
+            "20241122102948"
+                .to_utc("%Y%m%d%H%M%S", &Tz::Asia__Seoul)
+                .unwrap()
+                .hour()
+        );
+    }
+
+    #[cfg(any(feature = "io", feature = "default"))]
+    #[test]
+    fn path_ext_test() {
+        use super::PathExt;
+        use crate::io_util::DirectoryDateType;
+
+        let parent = std::env::temp_dir().join(format!("cliff3_prelude_{}", std::process::id()));
+
+        std::fs::create_dir_all(&parent).unwrap();
+
+        let created = parent.date_path(DirectoryDateType::YYYY, None).unwrap();
+
+        assert!(created.exists());
+        assert!(parent
+            .join("none")
+            .date_path(DirectoryDateType::YYYY, None)
+            .is_err());
+
+        std::fs::remove_dir_all(&parent).unwrap();
+    }
+}
@@ -0,0 +1,379 @@
+//! [proptest](https://docs.rs/proptest) strategy 모음
+//!
+//! 이 crate의 검사 및 변환 함수를 사용하는 코드를 property 기반으로 테스트할 수 있도록 올바른 값과
+//! 올바르지 않은 값을 생성하는 strategy를 제공한다. 모든 strategy는 구성 요소(생년월일, 자릿수 등)로부터
+//! 값을 생성하므로 실패한 경우 구성 요소 단위로 축소(shrink)된다.
+//!
+//! - 날짜 및 시간 문자열 - [datetime_string], [invalid_datetime_string]
+//! - 전화번호 - [phone_number], [invalid_phone_number]
+//! - 주민등록번호 - [rrn], [invalid_rrn]
+//! - 사업자등록번호 - [business_number], [invalid_business_number]
+//! - AES 암호문 - [aes_envelope], [invalid_aes_envelope] (`encrypt` feature 필요)
+//!
+//! ```rust
+//! use cliff3_util::proptest_util::{invalid_rrn, rrn};
+//! use cliff3_util::validate_util::validate_rrn;
+//! use proptest::prelude::*;
+//!
+//! proptest!(|(valid in rrn(), invalid in invalid_rrn())| {
+//!     prop_assert_eq!(&valid, &validate_rrn(&valid).unwrap().normalized());
+//!     prop_assert!(validate_rrn(&invalid).is_err());
+//! });
+//! ```
+
+use chrono::{DateTime, Datelike, NaiveDate, NaiveDateTime};
+use proptest::prelude::*;
+
+use crate::validate_util::{assemble_business_number, assemble_rrn};
+
+/// [invalid_datetime_string]이 생성하는 문자열의 패턴
+pub const DATETIME_PATTERN: &str = "%Y-%m-%d %H:%M:%S";
+
+/// `1900-01-01 00:00:00` (UTC timestamp)
+const MIN_TIMESTAMP: i64 = -2_208_988_800;
+
+/// `2099-12-31 23:59:59` (UTC timestamp)
+const MAX_TIMESTAMP: i64 = 4_102_444_799;
+
+/// 1900년 ~ 2099년 범위의 날짜 및 시간 생성
+pub fn naive_datetime() -> impl Strategy<Value = NaiveDateTime> {
+    (MIN_TIMESTAMP..=MAX_TIMESTAMP).prop_filter_map("범위를 벗어난 timestamp", |v| {
+        DateTime::from_timestamp(v, 0).map(|v| v.naive_utc())
+    })
+}
+
+/// 지정된 패턴의 날짜 및 시간 문자열 생성
+///
+/// 생성된 문자열은 동일한 `pattern`으로 해석(e.g. [crate::date_util::local_datetime_to_utc])할 수 있다.
+///
+/// # Arguments
+///
+/// - `pattern` - 날짜 및 시간 패턴(e.g. `%Y-%m-%d %H:%M:%S`). 올바르지 않은 패턴일 경우 생성시 panic이
+///   발생한다.
+pub fn datetime_string(pattern: &'static str) -> impl Strategy<Value = String> {
+    naive_datetime().prop_map(move |v| v.format(pattern).to_string())
+}
+
+/// [DATETIME_PATTERN] 형식이지만 존재하지 않는 날짜 및 시간 문자열 생성
+///
+/// 월(13 ~ 99), 일(해당 월의 마지막 일 이후), 시(24 ~ 99), 분(60 ~ 99) 및 초(61 ~ 99) 중 하나가 범위를
+/// 벗어나거나 숫자가 아닌 문자열을 생성한다.
+pub fn invalid_datetime_string() -> impl Strategy<Value = String> {
+    let format = |(y, mo, d, h, mi, s): (i32, u32, u32, u32, u32, u32)| {
+        format!("{:04}-{:02}-{:02} {:02}:{:02}:{:02}", y, mo, d, h, mi, s)
+    };
+    let invalid_day = (1900..2100i32, 1..=12u32).prop_flat_map(|(y, mo)| {
+        let last_day = last_day_of_month(y, mo);
+
+        (
+            Just(y),
+            Just(mo),
+            (last_day + 1)..=99,
+            0..24u32,
+            0..60u32,
+            0..60u32,
+        )
+    });
+
+    prop_oneof![
+        (
+            1900..2100i32,
+            13..=99u32,
+            1..=28u32,
+            0..24u32,
+            0..60u32,
+            0..60u32
+        )
+            .prop_map(format),
+        invalid_day.prop_map(format),
+        (
+            1900..2100i32,
+            1..=12u32,
+            1..=28u32,
+            24..=99u32,
+            0..60u32,
+            0..60u32
+        )
+            .prop_map(format),
+        (
+            1900..2100i32,
+            1..=12u32,
+            1..=28u32,
+            0..24u32,
+            60..=99u32,
+            0..60u32
+        )
+            .prop_map(format),
+        (
+            1900..2100i32,
+            1..=12u32,
+            1..=28u32,
+            0..24u32,
+            0..60u32,
+            61..=99u32
+        )
+            .prop_map(format),
+        "[a-zA-Z가-힣 ]{1,19}",
+    ]
+}
+
+/// 해당 월의 마지막 일
+fn last_day_of_month(year: i32, month: u32) -> u32 {
+    let (year, month) = if month == 12 {
+        (year + 1, 1)
+    } else {
+        (year, month + 1)
+    };
+
+    NaiveDate::from_ymd_opt(year, month, 1)
+        .and_then(|v| v.pred_opt())
+        .map_or(28, |v| v.day())
+}
+
+/// 휴대전화(`01X-NNNN-NNNN`) 혹은 서울 지역(`02-NNNN-NNNN`) 전화번호 생성
+///
+/// 가운데 자리는 3 ~ 4자리이며 구분자(`-`)를 포함하지 않는 번호도 생성한다.
+pub fn phone_number() -> impl Strategy<Value = String> {
+    prop_oneof![
+        "01[016789]-[0-9]{3,4}-[0-9]{4}",
+        "01[016789][0-9]{7,8}",
+        "02-[0-9]{3,4}-[0-9]{4}",
+        "02[0-9]{7,8}",
+    ]
+}
+
+/// 전화번호 형식이 아닌 문자열 생성
+///
+/// 8자리 미만의 숫자, 국번이 1 ~ 2자리인 번호 혹은 숫자 이외의 문자를 포함한 번호를 생성한다.
+pub fn invalid_phone_number() -> impl Strategy<Value = String> {
+    prop_oneof![
+        "[0-9]{1,7}",
+        "01[016789]-[0-9]{1,2}-[0-9]{4}",
+        "01[016789]-[a-zA-Z]{4}-[0-9]{4}",
+    ]
+}
+
+/// 검증 번호가 올바른 주민등록번호(`YYMMDD-NNNNNNN`) 생성
+///
+/// 생년월일은 1900년 ~ 2019년 범위이며 [crate::validate_util::validate_rrn] 검사를 통과한다.
+pub fn rrn() -> impl Strategy<Value = String> {
+    let start = NaiveDate::from_ymd_opt(1900, 1, 1).unwrap_or_default();
+    let end = NaiveDate::from_ymd_opt(2019, 12, 31).unwrap_or_default();
+
+    (
+        0..=(end - start).num_days(),
+        any::<bool>(),
+        proptest::collection::vec(0..=9u32, 5),
+    )
+        .prop_map(move |(days, female, rest)| {
+            let birth = start + chrono::Duration::days(days);
+
+            assemble_rrn((birth.year(), birth.month(), birth.day()), female, &rest)
+        })
+}
+
+/// 올바르지 않은 주민등록번호 생성
+///
+/// 검증 번호가 다른 번호, 존재하지 않는 생년월일(월이 13 ~ 99) 혹은 자릿수가 다른 번호를 생성한다.
+pub fn invalid_rrn() -> impl Strategy<Value = String> {
+    prop_oneof![
+        (rrn(), 1..=9u32).prop_map(|(v, offset)| replace_last_digit(&v, offset)),
+        "[0-9]{2}(1[3-9]|[2-9][0-9])[0-2][0-9]-[1-4][0-9]{6}",
+        "[0-9]{1,12}",
+        "[0-9]{14,16}",
+    ]
+}
+
+/// 검증 번호가 올바른 사업자등록번호(`NNN-NN-NNNNN`) 생성
+///
+/// [crate::validate_util::validate_brn] 검사를 통과한다.
+pub fn business_number() -> impl Strategy<Value = String> {
+    proptest::collection::vec(0..=9u32, 9).prop_map(|digits| assemble_business_number(&digits))
+}
+
+/// 올바르지 않은 사업자등록번호 생성
+///
+/// 검증 번호가 다른 번호 혹은 자릿수가 다른 번호를 생성한다.
+pub fn invalid_business_number() -> impl Strategy<Value = String> {
+    prop_oneof![
+        (business_number(), 1..=9u32).prop_map(|(v, offset)| replace_last_digit(&v, offset)),
+        "[0-9]{1,9}",
+        "[0-9]{11,13}",
+    ]
+}
+
+/// 마지막 자리 숫자를 `offset`만큼 변경(`offset`은 1 ~ 9)
+fn replace_last_digit(target: &str, offset: u32) -> String {
+    let mut result = target.to_owned();
+
+    if let Some(d) = result.pop().and_then(|c| c.to_digit(10)) {
+        result.extend(char::from_digit((d + offset) % 10, 10));
+    }
+
+    result
+}
+
+/// AES(AES-256) 암호문 및 암호화에 사용된 값
+///
+/// [crate::encrypt_util::aes_decrypt]에 `encrypted`, `secret`, `iv`, `salt` 및
+/// `repeat_count`를 지정하여 복호화 할 수 있다.
+#[cfg(any(feature = "encrypt", feature = "default"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AesEnvelope {
+    /// 원본 데이터
+    pub plain: Vec<u8>,
+
+    /// Secret key
+    pub secret: Vec<u8>,
+
+    /// Salt (8 bytes)
+    pub salt: Option<Vec<u8>>,
+
+    /// 반복 횟수
+    pub repeat_count: usize,
+
+    /// Initialize vector
+    pub iv: Vec<u8>,
+
+    /// 암호문
+    pub encrypted: Vec<u8>,
+}
+
+/// 복호화 가능한 AES 암호문 생성
+///
+/// 원본 데이터(1 ~ 256 bytes), secret(1 ~ 32 bytes), salt 및 반복 횟수(1 ~ 4)를 생성하여
+/// [crate::encrypt_util::aes_encrypt]로 암호화한다.
+#[cfg(any(feature = "encrypt", feature = "default"))]
+pub fn aes_envelope() -> impl Strategy<Value = AesEnvelope> {
+    use proptest::collection::vec;
+
+    (
+        vec(any::<u8>(), 1..=256),
+        vec(any::<u8>(), 1..=32),
+        proptest::option::of(vec(any::<u8>(), 8)),
+        1..=4usize,
+    )
+        .prop_filter_map(
+            "AES 암호화 실패",
+            |(plain, secret, salt, repeat_count)| {
+                let result = crate::encrypt_util::aes_encrypt(
+                    crate::encrypt_util::AES_TYPE::AES_256,
+                    &plain,
+                    &secret,
+                    salt.as_deref(),
+                    repeat_count,
+                )
+                .ok()?;
+
+                Some(AesEnvelope {
+                    iv: result.iv().to_vec(),
+                    encrypted: result.result().to_vec(),
+                    plain,
+                    secret,
+                    salt,
+                    repeat_count,
+                })
+            },
+        )
+}
+
+/// 복호화 할 수 없는 AES 암호문 생성
+///
+/// [aes_envelope]의 암호문에서 1 ~ 15 bytes를 제거하여 block 크기(16 bytes) 단위가 아닌 암호문을 생성한다.
+#[cfg(any(feature = "encrypt", feature = "default"))]
+pub fn invalid_aes_envelope() -> impl Strategy<Value = AesEnvelope> {
+    (aes_envelope(), 1..16usize).prop_map(|(mut envelope, cut)| {
+        let len = envelope.encrypted.len().saturating_sub(cut);
+
+        envelope.encrypted.truncate(len);
+        envelope
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::date_util::local_datetime_to_utc;
+    use crate::string_util::mask::mask_phone;
+    use crate::validate_util::{validate_brn, validate_rrn};
+    use chrono_tz::Tz;
+
+    /// 구분자를 제외하고 9 ~ 11자리 숫자이며, 구분자가 있을 경우 가운데 자리가 3자리 이상인 번호
+    fn is_phone_number(target: &str) -> bool {
+        let groups: Vec<&str> = target.split('-').collect();
+        let length: usize = groups.iter().map(|v| v.len()).sum();
+
+        groups
+            .iter()
+            .all(|v| !v.is_empty() && v.chars().all(|c| c.is_ascii_digit()))
+            && (9..=11).contains(&length)
+            && (groups.len() == 1 || (groups.len() == 3 && groups[1].len() >= 3))
+    }
+
+    proptest! {
+        #[test]
+        fn datetime_string_test(
+            valid in datetime_string(DATETIME_PATTERN),
+            invalid in invalid_datetime_string()
+        ) {
+            prop_assert!(local_datetime_to_utc(&valid, DATETIME_PATTERN, &Tz::UTC).is_ok());
+            prop_assert!(local_datetime_to_utc(&invalid, DATETIME_PATTERN, &Tz::UTC).is_err());
+        }
+
+        #[test]
+        fn phone_number_test(valid in phone_number(), invalid in invalid_phone_number()) {
+            let masked = mask_phone(&valid);
+
+            prop_assert!(masked.contains('*'));
+            prop_assert_eq!(&valid[valid.len() - 4..], &masked[masked.len() - 4..]);
+            prop_assert!(is_phone_number(&valid));
+            prop_assert!(!is_phone_number(&invalid));
+        }
+
+        #[test]
+        fn rrn_test(valid in rrn(), invalid in invalid_rrn()) {
+            prop_assert_eq!(&valid, &validate_rrn(&valid).unwrap().normalized());
+            prop_assert!(validate_rrn(&invalid).is_err());
+        }
+
+        #[test]
+        fn business_number_test(
+            valid in business_number(),
+            invalid in invalid_business_number()
+        ) {
+            prop_assert_eq!(&valid, &validate_brn(&valid).unwrap());
+            prop_assert!(validate_brn(&invalid).is_err());
+        }
+    }
+
+    #[cfg(any(feature = "encrypt", feature = "default"))]
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(32))]
+
+        #[test]
+        fn aes_envelope_test(valid in aes_envelope(), invalid in invalid_aes_envelope()) {
+            use crate::encrypt_util::{aes_decrypt, AES_TYPE};
+
+            let decrypted = aes_decrypt(
+                AES_TYPE::AES_256,
+                Some(&valid.encrypted),
+                &valid.secret,
+                &valid.iv,
+                valid.salt.as_deref(),
+                valid.repeat_count,
+            )
+            .unwrap();
+
+            prop_assert_eq!(&valid.plain, &decrypted.to_vec());
+            prop_assert!(aes_decrypt(
+                AES_TYPE::AES_256,
+                Some(&invalid.encrypted),
+                &invalid.secret,
+                &invalid.iv,
+                invalid.salt.as_deref(),
+                invalid.repeat_count,
+            )
+            .is_err());
+        }
+    }
+}
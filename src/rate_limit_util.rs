@@ -0,0 +1,331 @@
+//! 처리율 제한(rate limit) 관련 함수 모음
+//!
+//! 외부 API 호출 등 초당 요청 수(QPS)가 제한되는 경우 사용할 수 있는 [RateLimiter]를 제공한다.
+//! 내부 상태는 [Mutex]로 보호되므로 [std::sync::Arc]로 감싸 여러 thread에서 공유할 수 있다.
+
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::error::InvalidArgumentError;
+
+/// 처리율 제한 방식
+///
+/// - `TokenBucket` - 지정된 속도로 token을 채우며 최대 `capacity`만큼 순간 요청(burst)을 허용
+/// - `FixedWindow` - 고정된 시간 구간(window)마다 최대 `limit`개의 요청 허용
+///
+/// # Link
+///
+/// - [RateLimiter]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RateLimitType {
+    /// Token bucket
+    TokenBucket,
+
+    /// 고정 시간 구간
+    FixedWindow,
+}
+
+/// 내부 상태
+#[derive(Debug)]
+enum LimiterState {
+    TokenBucket {
+        /// 현재 남은 token
+        tokens: f64,
+
+        /// 마지막 token 충전 시각
+        last_refill: Instant,
+    },
+    FixedWindow {
+        /// 현재 구간 시작 시각
+        window_start: Instant,
+
+        /// 현재 구간에서 허용된 요청 수
+        count: u32,
+    },
+}
+
+/// Thread-safe 처리율 제한기
+///
+/// [RateLimiter::token_bucket] 혹은 [RateLimiter::fixed_window]를 이용하여 생성한다.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use cliff3_util::rate_limit_util::RateLimiter;
+///
+/// // 초당 5회, 최대 5회 burst
+/// let limiter = RateLimiter::token_bucket(5, Duration::from_secs(1)).unwrap();
+///
+/// for _ in 0..5 {
+///     assert!(limiter.try_acquire());
+/// }
+///
+/// assert!(!limiter.try_acquire());
+/// ```
+#[derive(Debug)]
+pub struct RateLimiter {
+    /// 제한 방식
+    limit_type: RateLimitType,
+
+    /// 허용 요청 수(token bucket일 경우 최대 token 수)
+    limit: u32,
+
+    /// 허용 요청 수에 대한 기준 시간
+    period: Duration,
+
+    /// 내부 상태
+    state: Mutex<LimiterState>,
+}
+
+impl RateLimiter {
+    /// Token bucket 방식의 [RateLimiter] 생성
+    ///
+    /// `period`마다 `capacity`개의 token이 일정한 속도로 충전되며, 최초 생성시에는 token이
+    /// 가득 찬 상태로 시작한다.
+    ///
+    /// # Arguments
+    ///
+    /// - `capacity` - 최대 token 수 (`period` 동안 허용되는 요청 수)
+    /// - `period` - `capacity`만큼의 token이 충전되는 시간
+    ///
+    /// # Return
+    ///
+    /// - 생성 결과 `Result<RateLimiter, InvalidArgumentError>`
+    ///
+    /// # Errors
+    ///
+    /// - [InvalidArgumentError] - `capacity` 혹은 `period`가 0일 경우
+    pub fn token_bucket(capacity: u32, period: Duration) -> Result<Self, InvalidArgumentError> {
+        Self::validate(capacity, period)?;
+
+        Ok(RateLimiter {
+            limit_type: RateLimitType::TokenBucket,
+            limit: capacity,
+            period,
+            state: Mutex::new(LimiterState::TokenBucket {
+                tokens: capacity as f64,
+                last_refill: Instant::now(),
+            }),
+        })
+    }
+
+    /// 고정 시간 구간(fixed window) 방식의 [RateLimiter] 생성
+    ///
+    /// # Arguments
+    ///
+    /// - `limit` - 구간당 허용 요청 수
+    /// - `window` - 구간 길이
+    ///
+    /// # Return
+    ///
+    /// - 생성 결과 `Result<RateLimiter, InvalidArgumentError>`
+    ///
+    /// # Errors
+    ///
+    /// - [InvalidArgumentError] - `limit` 혹은 `window`가 0일 경우
+    pub fn fixed_window(limit: u32, window: Duration) -> Result<Self, InvalidArgumentError> {
+        Self::validate(limit, window)?;
+
+        Ok(RateLimiter {
+            limit_type: RateLimitType::FixedWindow,
+            limit,
+            period: window,
+            state: Mutex::new(LimiterState::FixedWindow {
+                window_start: Instant::now(),
+                count: 0,
+            }),
+        })
+    }
+
+    /// 생성 인자 유효성 검사
+    fn validate(limit: u32, period: Duration) -> Result<(), InvalidArgumentError> {
+        if limit == 0 {
+            return Err(InvalidArgumentError::from(
+                "허용 요청 수는 0보다 커야 합니다.",
+            ));
+        }
+
+        if period.is_zero() {
+            return Err(InvalidArgumentError::from("기준 시간은 0보다 커야 합니다."));
+        }
+
+        Ok(())
+    }
+
+    /// 제한 방식 반환
+    #[inline]
+    pub fn limit_type(&self) -> RateLimitType {
+        self.limit_type
+    }
+
+    /// 허용 요청 수 반환
+    #[inline]
+    pub fn limit(&self) -> u32 {
+        self.limit
+    }
+
+    /// 기준 시간 반환
+    #[inline]
+    pub fn period(&self) -> Duration {
+        self.period
+    }
+
+    /// 대기 없이 요청 허용 여부 확인
+    ///
+    /// 허용될 경우 token(혹은 구간 내 요청 수)을 소모하고 `true`를 반환한다.
+    ///
+    /// # Return
+    ///
+    /// - 허용 여부
+    pub fn try_acquire(&self) -> bool {
+        self.acquire_or_wait_time().is_none()
+    }
+
+    /// 요청이 허용될 때까지 현재 thread를 대기
+    ///
+    /// # Link
+    ///
+    /// - [std::thread::sleep]
+    pub fn acquire_blocking(&self) {
+        while let Some(wait) = self.acquire_or_wait_time() {
+            thread::sleep(wait);
+        }
+    }
+
+    /// 요청이 허용될 때까지 비동기로 대기
+    ///
+    /// `async` feature 활성화시 사용 가능하며 [tokio::time::sleep]을 이용한다.
+    #[cfg(feature = "async")]
+    pub async fn acquire(&self) {
+        while let Some(wait) = self.acquire_or_wait_time() {
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// 요청 허용을 시도하고, 허용되지 않을 경우 다음 허용까지의 대기 시간을 반환
+    fn acquire_or_wait_time(&self) -> Option<Duration> {
+        let now = Instant::now();
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+
+        match &mut *state {
+            LimiterState::TokenBucket {
+                tokens,
+                last_refill,
+            } => {
+                let rate = self.limit as f64 / self.period.as_secs_f64(); // 초당 충전 token
+                let elapsed = now.duration_since(*last_refill).as_secs_f64();
+
+                *tokens = (*tokens + elapsed * rate).min(self.limit as f64);
+                *last_refill = now;
+
+                if *tokens >= 1.0 {
+                    *tokens -= 1.0;
+
+                    None
+                } else {
+                    Some(Duration::from_secs_f64((1.0 - *tokens) / rate))
+                }
+            }
+            LimiterState::FixedWindow {
+                window_start,
+                count,
+            } => {
+                let elapsed = now.duration_since(*window_start);
+
+                if elapsed >= self.period {
+                    *window_start = now;
+                    *count = 0;
+                }
+
+                if *count < self.limit {
+                    *count += 1;
+
+                    None
+                } else {
+                    Some(self.period - now.duration_since(*window_start))
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    #[test]
+    fn invalid_argument_test() {
+        assert!(RateLimiter::token_bucket(0, Duration::from_secs(1)).is_err());
+        assert!(RateLimiter::token_bucket(1, Duration::ZERO).is_err());
+        assert!(RateLimiter::fixed_window(0, Duration::from_secs(1)).is_err());
+        assert!(RateLimiter::fixed_window(1, Duration::ZERO).is_err());
+    }
+
+    #[test]
+    fn token_bucket_test() {
+        let limiter = RateLimiter::token_bucket(3, Duration::from_millis(300)).unwrap();
+
+        assert_eq!(RateLimitType::TokenBucket, limiter.limit_type());
+
+        for _ in 0..3 {
+            assert!(limiter.try_acquire(), "burst 허용 실패");
+        }
+
+        assert!(!limiter.try_acquire(), "token 소진 후 허용됨");
+
+        // 100ms당 1개 충전
+        thread::sleep(Duration::from_millis(120));
+
+        assert!(limiter.try_acquire(), "token 충전 후 허용 실패");
+        assert!(!limiter.try_acquire());
+    }
+
+    #[test]
+    fn fixed_window_test() {
+        let limiter = RateLimiter::fixed_window(2, Duration::from_millis(100)).unwrap();
+
+        assert!(limiter.try_acquire());
+        assert!(limiter.try_acquire());
+        assert!(!limiter.try_acquire(), "구간 허용 수 초과");
+
+        thread::sleep(Duration::from_millis(110));
+
+        assert!(limiter.try_acquire(), "다음 구간 허용 실패");
+    }
+
+    #[test]
+    fn acquire_blocking_test() {
+        let limiter = Arc::new(RateLimiter::fixed_window(2, Duration::from_millis(50)).unwrap());
+        let start = Instant::now();
+        let handles: Vec<_> = (0..3)
+            .map(|_| {
+                let limiter = Arc::clone(&limiter);
+
+                thread::spawn(move || limiter.acquire_blocking())
+            })
+            .collect();
+
+        for h in handles {
+            h.join().unwrap();
+        }
+
+        // 3번째 요청은 다음 구간까지 대기
+        assert!(start.elapsed() >= Duration::from_millis(45));
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn acquire_async_test() {
+        let limiter = RateLimiter::token_bucket(1, Duration::from_millis(50)).unwrap();
+        let start = Instant::now();
+
+        limiter.acquire().await;
+        limiter.acquire().await;
+
+        assert!(start.elapsed() >= Duration::from_millis(45));
+    }
+}
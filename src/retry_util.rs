@@ -0,0 +1,316 @@
+//! 재시도(retry) 관련 함수 모음
+//!
+//! 지수 백오프(exponential backoff) 기반의 [RetryPolicy]와 이를 이용하여 작업을 재시도하는 [retry]를
+//! 제공한다. `async` feature 활성화시 동일한 정책을 사용하는 비동기 함수(`retry_async`)를 사용할 수 있다.
+
+#[cfg(feature = "async")]
+use std::future::Future;
+use std::thread;
+use std::time::Duration;
+
+/// 재시도 정책
+///
+/// `n`번째 재시도 전 대기 시간은 `initial_delay * multiplier^(n - 1)`이며 `max_delay`를 넘지 않는다.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use cliff3_util::retry_util::RetryPolicy;
+///
+/// let policy = RetryPolicy::new(3, Duration::from_millis(100))
+///     .with_multiplier(2.0)
+///     .with_max_delay(Duration::from_millis(150));
+///
+/// assert_eq!(Some(Duration::from_millis(100)), policy.delay_for(1));
+/// assert_eq!(Some(Duration::from_millis(150)), policy.delay_for(2));
+/// assert_eq!(None, policy.delay_for(4));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RetryPolicy {
+    /// 최대 재시도 횟수(최초 시도 제외)
+    max_retries: u32,
+
+    /// 첫 번째 재시도 전 대기 시간
+    initial_delay: Duration,
+
+    /// 최대 대기 시간
+    max_delay: Duration,
+
+    /// 대기 시간 증가 배수
+    multiplier: f64,
+}
+
+impl Default for RetryPolicy {
+    /// 최대 3회, 200ms부터 2배씩 증가(최대 5초)
+    fn default() -> Self {
+        RetryPolicy {
+            max_retries: 3,
+            initial_delay: Duration::from_millis(200),
+            max_delay: Duration::from_secs(5),
+            multiplier: 2.0,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// [RetryPolicy] 생성
+    ///
+    /// 증가 배수는 `2.0`, 최대 대기 시간은 [RetryPolicy::default]와 동일하다.
+    ///
+    /// # Arguments
+    ///
+    /// - `max_retries` - 최대 재시도 횟수(최초 시도 제외)
+    /// - `initial_delay` - 첫 번째 재시도 전 대기 시간
+    pub fn new(max_retries: u32, initial_delay: Duration) -> Self {
+        RetryPolicy {
+            max_retries,
+            initial_delay,
+            ..Default::default()
+        }
+    }
+
+    /// 재시도 하지 않는 정책 반환
+    pub fn none() -> Self {
+        RetryPolicy {
+            max_retries: 0,
+            ..Default::default()
+        }
+    }
+
+    /// 최대 대기 시간 지정
+    pub fn with_max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+
+        self
+    }
+
+    /// 대기 시간 증가 배수 지정. `1.0` 미만일 경우 `1.0`으로 처리
+    pub fn with_multiplier(mut self, multiplier: f64) -> Self {
+        self.multiplier = multiplier.max(1.0);
+
+        self
+    }
+
+    /// 최대 재시도 횟수 반환
+    #[inline]
+    pub fn max_retries(&self) -> u32 {
+        self.max_retries
+    }
+
+    /// `attempt`번째 재시도(1부터 시작) 전 대기 시간 반환
+    ///
+    /// 최대 재시도 횟수를 초과할 경우 `None`을 반환한다.
+    pub fn delay_for(&self, attempt: u32) -> Option<Duration> {
+        if attempt == 0 || attempt > self.max_retries {
+            return None;
+        }
+
+        let factor = self
+            .multiplier
+            .powi((attempt - 1).min(i32::MAX as u32) as i32);
+        let delay = self.initial_delay.as_secs_f64() * factor;
+
+        // Duration 범위를 벗어날 경우 최대 대기 시간 적용
+        Some(Duration::try_from_secs_f64(delay).map_or(self.max_delay, |v| v.min(self.max_delay)))
+    }
+}
+
+/// [RetryPolicy]에 따라 작업을 재시도
+///
+/// `f`가 `Err`를 반환하고 `should_retry`가 `true`를 반환할 경우 정책에 따라 대기 후 재시도한다.
+/// `f`에는 현재 시도 횟수(0부터 시작)가 전달된다.
+///
+/// # Arguments
+///
+/// - `policy` - [RetryPolicy]
+/// - `should_retry` - 재시도 여부 판단 함수
+/// - `f` - 대상 작업
+///
+/// # Return
+///
+/// - 마지막 시도 결과
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use cliff3_util::retry_util::{retry, RetryPolicy};
+///
+/// let policy = RetryPolicy::new(3, Duration::from_millis(1));
+/// let result: Result<u32, &str> = retry(&policy, |_| true, |attempt| {
+///     if attempt < 2 {
+///         Err("fail")
+///     } else {
+///         Ok(attempt)
+///     }
+/// });
+///
+/// assert_eq!(Ok(2), result);
+/// ```
+pub fn retry<T, E, R, F>(policy: &RetryPolicy, should_retry: R, mut f: F) -> Result<T, E>
+where
+    R: Fn(&E) -> bool,
+    F: FnMut(u32) -> Result<T, E>,
+{
+    let mut attempt: u32 = 0;
+
+    loop {
+        match f(attempt) {
+            Ok(v) => return Ok(v),
+            Err(e) => {
+                attempt += 1;
+
+                match policy.delay_for(attempt) {
+                    Some(delay) if should_retry(&e) => thread::sleep(delay),
+                    _ => return Err(e),
+                }
+            }
+        }
+    }
+}
+
+/// [RetryPolicy]에 따라 비동기 작업을 재시도
+///
+/// `async` feature 활성화시 사용 가능하며, 재시도 전 대기는 [tokio::time::sleep]을 이용한다. 그 외의
+/// 동작은 [retry]와 동일하다.
+///
+/// # Arguments
+///
+/// - `policy` - [RetryPolicy]
+/// - `should_retry` - 재시도 여부 판단 함수
+/// - `f` - 대상 작업. 현재 시도 횟수(0부터 시작)를 전달받아 `Future`를 반환
+///
+/// # Return
+///
+/// - 마지막 시도 결과
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use cliff3_util::retry_util::{retry_async, RetryPolicy};
+///
+/// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+/// let policy = RetryPolicy::new(3, Duration::from_millis(1));
+/// let result: Result<u32, &str> = retry_async(&policy, |_| true, |attempt| async move {
+///     if attempt < 2 {
+///         Err("fail")
+///     } else {
+///         Ok(attempt)
+///     }
+/// })
+/// .await;
+///
+/// assert_eq!(Ok(2), result);
+/// # });
+/// ```
+#[cfg(feature = "async")]
+pub async fn retry_async<T, E, R, F, Fut>(
+    policy: &RetryPolicy,
+    should_retry: R,
+    mut f: F,
+) -> Result<T, E>
+where
+    R: Fn(&E) -> bool,
+    F: FnMut(u32) -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+{
+    let mut attempt: u32 = 0;
+
+    loop {
+        match f(attempt).await {
+            Ok(v) => return Ok(v),
+            Err(e) => {
+                attempt += 1;
+
+                match policy.delay_for(attempt) {
+                    Some(delay) if should_retry(&e) => tokio::time::sleep(delay).await,
+                    _ => return Err(e),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn delay_for_test() {
+        let policy = RetryPolicy::default();
+
+        assert_eq!(None, policy.delay_for(0));
+        assert_eq!(Some(Duration::from_millis(200)), policy.delay_for(1));
+        assert_eq!(Some(Duration::from_millis(400)), policy.delay_for(2));
+        assert_eq!(Some(Duration::from_millis(800)), policy.delay_for(3));
+        assert_eq!(None, policy.delay_for(4));
+        assert_eq!(None, RetryPolicy::none().delay_for(1));
+
+        // Duration 범위를 벗어나는 대기 시간
+        let policy = RetryPolicy::new(u32::MAX, Duration::from_millis(200));
+
+        assert_eq!(
+            Some(Duration::MAX),
+            policy.with_max_delay(Duration::MAX).delay_for(100)
+        );
+        assert_eq!(Some(policy.max_delay), policy.delay_for(100));
+        assert_eq!(Some(policy.max_delay), policy.delay_for(u32::MAX));
+    }
+
+    #[test]
+    fn retry_test() {
+        let policy = RetryPolicy::new(2, Duration::from_millis(1));
+        let mut calls = 0;
+
+        // 재시도 횟수 초과
+        let result: Result<(), u32> = retry(
+            &policy,
+            |_| true,
+            |attempt| {
+                calls += 1;
+                Err(attempt)
+            },
+        );
+
+        assert_eq!(Err(2), result);
+        assert_eq!(3, calls);
+
+        // 재시도 대상이 아닌 오류
+        calls = 0;
+
+        let result: Result<(), u32> = retry(
+            &policy,
+            |e| *e != 0,
+            |attempt| {
+                calls += 1;
+                Err(attempt)
+            },
+        );
+
+        assert_eq!(Err(0), result);
+        assert_eq!(1, calls);
+    }
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn retry_async_test() {
+        let policy = RetryPolicy::new(2, Duration::from_millis(1));
+        let calls = std::cell::Cell::new(0);
+        let result: Result<(), u32> = retry_async(
+            &policy,
+            |e| *e != 1,
+            |attempt| {
+                calls.set(calls.get() + 1);
+
+                async move { Err(attempt) }
+            },
+        )
+        .await;
+
+        // 두번째 시도 결과(1)는 재시도 대상이 아님
+        assert_eq!(Err(1), result);
+        assert_eq!(2, calls.get());
+    }
+}
@@ -0,0 +1,769 @@
+//! 작업 스케줄러 관련 함수 모음
+//!
+//! cron 표현식([CronSchedule]) 혹은 고정 간격으로 작업을 등록하여 실행하는 in-process 스케줄러
+//! [Scheduler]를 제공한다. 작업은 내부 thread pool(혹은 `async` feature 활성화시 tokio runtime)에서
+//! 실행되며, [Scheduler::shutdown] 호출시 실행 중인 작업이 종료될 때까지 대기한다.
+
+use std::fmt::{Debug, Formatter};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::mpsc::{self, Sender};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+
+use crate::date_util::cron::CronSchedule;
+use crate::error::InvalidArgumentError;
+
+/// 누락된 실행 시각을 한번에 처리하는 최대 횟수
+const MAX_CATCH_UP: u64 = 1_000;
+
+/// 등록된 작업이 없을 경우 대기 시간
+const IDLE_WAIT: Duration = Duration::from_secs(60);
+
+type Task = Arc<dyn Fn() + Send + Sync + 'static>;
+
+/// 작업 실행 주기
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum JobSchedule {
+    /// 고정 간격
+    Interval(Duration),
+
+    /// cron 표현식
+    Cron(CronSchedule),
+}
+
+impl JobSchedule {
+    /// 지정된 시각 이후의 다음 실행 시각 반환
+    fn next_after(&self, after: &DateTime<Utc>, timezone: &Tz) -> Option<DateTime<Utc>> {
+        match self {
+            JobSchedule::Interval(v) => {
+                after.checked_add_signed(chrono::Duration::from_std(*v).ok()?)
+            }
+            JobSchedule::Cron(v) => v
+                .next_after(&after.with_timezone(timezone))
+                .map(|t| t.with_timezone(&Utc)),
+        }
+    }
+}
+
+/// 실행 시각이 지난(누락된) 작업 처리 방식
+///
+/// 작업 실행이 지연되어 여러 실행 시각이 지난 경우 적용된다.
+///
+/// - `Skip` - 유예 시간([Scheduler::with_grace_period])이 지난 실행 시각은 실행하지 않음
+/// - `RunOnce` - 누락된 실행 시각 수와 관계없이 한번만 실행
+/// - `RunAll` - 누락된 실행 시각마다 실행(최대 1,000회)
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MissedRunPolicy {
+    /// 실행하지 않음
+    Skip,
+
+    /// 한번만 실행
+    #[default]
+    RunOnce,
+
+    /// 모두 실행
+    RunAll,
+}
+
+/// [Scheduler]에 등록할 작업
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use cliff3_util::schedule_util::{Job, MissedRunPolicy};
+///
+/// let job = Job::cron("report", "0 9 * * 1-5", || println!("daily report"))
+///     .unwrap()
+///     .with_missed_run_policy(MissedRunPolicy::Skip);
+///
+/// assert_eq!("report", job.name());
+/// assert!(Job::interval("invalid", Duration::ZERO, || {}).is_err());
+/// ```
+#[derive(Clone)]
+pub struct Job {
+    /// 작업 명칭(식별자)
+    name: String,
+
+    /// 실행 주기
+    schedule: JobSchedule,
+
+    /// 누락된 실행 처리 방식
+    policy: MissedRunPolicy,
+
+    /// 실행 대상
+    task: Task,
+}
+
+impl Debug for Job {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Job")
+            .field("name", &self.name)
+            .field("schedule", &self.schedule)
+            .field("policy", &self.policy)
+            .finish()
+    }
+}
+
+impl Job {
+    /// 고정 간격으로 실행되는 작업 생성
+    ///
+    /// 최초 실행은 스케줄러 등록 후 `period`가 지난 시점이다.
+    ///
+    /// # Arguments
+    ///
+    /// - `name` - 작업 명칭
+    /// - `period` - 실행 간격
+    /// - `task` - 실행 대상
+    ///
+    /// # Errors
+    ///
+    /// - [InvalidArgumentError] - `period`가 0일 경우
+    pub fn interval<F>(name: &str, period: Duration, task: F) -> Result<Job, InvalidArgumentError>
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        if period.is_zero() {
+            return Err(InvalidArgumentError::from("실행 간격은 0보다 커야 합니다."));
+        }
+
+        Ok(Self::create(name, JobSchedule::Interval(period), task))
+    }
+
+    /// cron 표현식으로 실행되는 작업 생성
+    ///
+    /// # Arguments
+    ///
+    /// - `name` - 작업 명칭
+    /// - `expression` - cron 표현식. [CronSchedule::parse] 참고
+    /// - `task` - 실행 대상
+    ///
+    /// # Errors
+    ///
+    /// - [InvalidArgumentError] - 잘못된 cron 표현식
+    pub fn cron<F>(name: &str, expression: &str, task: F) -> Result<Job, InvalidArgumentError>
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        let schedule = CronSchedule::parse(expression)?;
+
+        Ok(Self::create(name, JobSchedule::Cron(schedule), task))
+    }
+
+    fn create<F>(name: &str, schedule: JobSchedule, task: F) -> Job
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        Job {
+            name: name.to_owned(),
+            schedule,
+            policy: MissedRunPolicy::default(),
+            task: Arc::new(task),
+        }
+    }
+
+    /// 누락된 실행 처리 방식 지정. 기본값은 [MissedRunPolicy::RunOnce]
+    pub fn with_missed_run_policy(mut self, policy: MissedRunPolicy) -> Self {
+        self.policy = policy;
+
+        self
+    }
+
+    /// 작업 명칭 반환
+    #[inline]
+    pub fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    /// 실행 주기 반환
+    #[inline]
+    pub fn schedule(&self) -> &JobSchedule {
+        &self.schedule
+    }
+}
+
+/// 실행 방식
+#[derive(Debug, Clone)]
+enum Executor {
+    /// 내부 thread pool(thread 수)
+    ThreadPool(usize),
+
+    /// tokio runtime의 blocking thread pool
+    #[cfg(feature = "async")]
+    Tokio(tokio::runtime::Handle),
+}
+
+/// 작업별 상태
+struct JobEntry {
+    job: Job,
+
+    /// 다음 실행 시각
+    next_run: Option<DateTime<Utc>>,
+}
+
+struct State {
+    jobs: Vec<JobEntry>,
+
+    shutdown: bool,
+}
+
+/// dispatcher/worker 공유 상태
+struct Shared {
+    state: Mutex<State>,
+
+    /// 작업 등록/삭제 및 종료 알림
+    signal: Condvar,
+
+    /// 실행 중(대기 포함)인 작업 수
+    in_flight: Mutex<usize>,
+
+    /// 실행 중인 작업 없음 알림
+    idle: Condvar,
+}
+
+impl Shared {
+    fn state(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn begin(&self) {
+        *self.in_flight.lock().unwrap_or_else(|e| e.into_inner()) += 1;
+    }
+
+    fn finish(&self) {
+        let mut count = self.in_flight.lock().unwrap_or_else(|e| e.into_inner());
+
+        *count -= 1;
+
+        if *count == 0 {
+            self.idle.notify_all();
+        }
+    }
+
+    fn wait_idle(&self) {
+        let mut count = self.in_flight.lock().unwrap_or_else(|e| e.into_inner());
+
+        while *count > 0 {
+            count = self.idle.wait(count).unwrap_or_else(|e| e.into_inner());
+        }
+    }
+}
+
+/// 작업 전달 대상
+enum Runner {
+    Pool(Sender<Task>),
+
+    #[cfg(feature = "async")]
+    Tokio(tokio::runtime::Handle),
+}
+
+impl Runner {
+    fn run(&self, shared: &Arc<Shared>, task: &Task) {
+        shared.begin();
+
+        let task = task.clone();
+        let inner = shared.clone();
+        let wrapped: Task = Arc::new(move || {
+            // 작업 panic이 worker 및 스케줄러에 전파되지 않도록 처리
+            let _ = catch_unwind(AssertUnwindSafe(|| task()));
+
+            inner.finish();
+        });
+
+        match self {
+            Runner::Pool(sender) => {
+                // worker가 모두 종료된 경우
+                if sender.send(wrapped).is_err() {
+                    shared.finish();
+                }
+            }
+            #[cfg(feature = "async")]
+            Runner::Tokio(handle) => {
+                handle.spawn_blocking(move || wrapped());
+            }
+        }
+    }
+}
+
+/// 경량 in-process 작업 스케줄러
+///
+/// 작업은 [Scheduler::start] 전후 언제든 등록/삭제할 수 있으며, cron 표현식은
+/// [Scheduler::with_timezone]에서 지정한 timezone(기본값 UTC) 기준으로 계산된다.
+/// [Drop]시 [Scheduler::shutdown]이 호출된다.
+///
+/// # Example
+///
+/// ```rust
+/// use std::sync::Arc;
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+/// use std::time::Duration;
+/// use cliff3_util::schedule_util::{Job, Scheduler};
+///
+/// let counter = Arc::new(AtomicUsize::new(0));
+/// let c = counter.clone();
+/// let mut scheduler = Scheduler::new().with_workers(2);
+///
+/// scheduler
+///     .add(Job::interval("tick", Duration::from_millis(20), move || {
+///         c.fetch_add(1, Ordering::SeqCst);
+///     }).unwrap())
+///     .unwrap();
+/// scheduler.start();
+///
+/// std::thread::sleep(Duration::from_millis(110));
+/// scheduler.shutdown();
+///
+/// assert!(counter.load(Ordering::SeqCst) >= 2);
+/// ```
+pub struct Scheduler {
+    shared: Arc<Shared>,
+
+    executor: Executor,
+
+    /// cron 표현식 기준 timezone
+    timezone: Tz,
+
+    /// [MissedRunPolicy::Skip] 적용시 유예 시간
+    grace_period: Duration,
+
+    /// dispatcher 및 worker thread
+    threads: Vec<JoinHandle<()>>,
+
+    started: bool,
+}
+
+impl Default for Scheduler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Debug for Scheduler {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Scheduler")
+            .field("jobs", &self.job_names())
+            .field("executor", &self.executor)
+            .field("timezone", &self.timezone)
+            .field("grace_period", &self.grace_period)
+            .field("started", &self.started)
+            .finish()
+    }
+}
+
+impl Scheduler {
+    /// [Scheduler] 생성
+    ///
+    /// 기본값은 thread pool(4개), UTC, 유예 시간 1초이다.
+    pub fn new() -> Self {
+        Scheduler {
+            shared: Arc::new(Shared {
+                state: Mutex::new(State {
+                    jobs: vec![],
+                    shutdown: false,
+                }),
+                signal: Condvar::new(),
+                in_flight: Mutex::new(0),
+                idle: Condvar::new(),
+            }),
+            executor: Executor::ThreadPool(4),
+            timezone: Tz::UTC,
+            grace_period: Duration::from_secs(1),
+            threads: vec![],
+            started: false,
+        }
+    }
+
+    /// 작업을 실행할 thread 수 지정. `0`일 경우 `1`로 처리
+    ///
+    /// [Scheduler::start] 이후 호출시 적용되지 않는다.
+    pub fn with_workers(mut self, workers: usize) -> Self {
+        self.executor = Executor::ThreadPool(workers.max(1));
+
+        self
+    }
+
+    /// 작업을 tokio runtime의 blocking thread pool에서 실행하도록 지정
+    ///
+    /// [Scheduler::start] 이후 호출시 적용되지 않는다.
+    ///
+    /// # Arguments
+    ///
+    /// - `handle` - 작업을 실행할 tokio runtime handle
+    #[cfg(feature = "async")]
+    pub fn with_tokio(mut self, handle: tokio::runtime::Handle) -> Self {
+        self.executor = Executor::Tokio(handle);
+
+        self
+    }
+
+    /// cron 표현식 기준 timezone 지정
+    pub fn with_timezone(mut self, timezone: Tz) -> Self {
+        self.timezone = timezone;
+
+        self
+    }
+
+    /// [MissedRunPolicy::Skip] 적용시 실행 시각이 지났어도 실행하는 유예 시간 지정
+    pub fn with_grace_period(mut self, grace_period: Duration) -> Self {
+        self.grace_period = grace_period;
+
+        self
+    }
+
+    /// 작업 등록
+    ///
+    /// # Arguments
+    ///
+    /// - `job` - 등록 대상 [Job]
+    ///
+    /// # Errors
+    ///
+    /// - [InvalidArgumentError] - 동일한 명칭의 작업이 등록되어 있을 경우
+    pub fn add(&self, job: Job) -> Result<(), InvalidArgumentError> {
+        let mut state = self.shared.state();
+
+        if state.jobs.iter().any(|e| e.job.name == job.name) {
+            return Err(InvalidArgumentError::new(&format!(
+                "이미 등록된 작업 입니다({}).",
+                job.name
+            )));
+        }
+
+        let next_run = job.schedule.next_after(&Utc::now(), &self.timezone);
+
+        state.jobs.push(JobEntry { job, next_run });
+        self.shared.signal.notify_all();
+
+        Ok(())
+    }
+
+    /// 작업 삭제. 실행 중인 작업은 중단되지 않는다.
+    ///
+    /// # Return
+    ///
+    /// - 삭제 여부
+    pub fn remove(&self, name: &str) -> bool {
+        let mut state = self.shared.state();
+        let before = state.jobs.len();
+
+        state.jobs.retain(|e| e.job.name != name);
+        self.shared.signal.notify_all();
+
+        before != state.jobs.len()
+    }
+
+    /// 등록된 작업 명칭 목록 반환
+    pub fn job_names(&self) -> Vec<String> {
+        self.shared
+            .state()
+            .jobs
+            .iter()
+            .map(|e| e.job.name.clone())
+            .collect()
+    }
+
+    /// 지정된 작업의 다음 실행 시각 반환
+    pub fn next_run(&self, name: &str) -> Option<DateTime<Utc>> {
+        self.shared
+            .state()
+            .jobs
+            .iter()
+            .find(|e| e.job.name == name)
+            .and_then(|e| e.next_run)
+    }
+
+    /// 스케줄러 시작. 이미 시작되었을 경우 무시된다.
+    pub fn start(&mut self) {
+        if self.started {
+            return;
+        }
+
+        self.started = true;
+
+        let runner = match &self.executor {
+            Executor::ThreadPool(workers) => {
+                let (sender, receiver) = mpsc::channel::<Task>();
+                let receiver = Arc::new(Mutex::new(receiver));
+
+                for _ in 0..*workers {
+                    let receiver = receiver.clone();
+
+                    self.threads.push(thread::spawn(move || loop {
+                        let task = receiver.lock().unwrap_or_else(|e| e.into_inner()).recv();
+
+                        match task {
+                            Ok(task) => task(),
+                            Err(_) => break,
+                        }
+                    }));
+                }
+
+                Runner::Pool(sender)
+            }
+            #[cfg(feature = "async")]
+            Executor::Tokio(handle) => Runner::Tokio(handle.clone()),
+        };
+
+        let shared = self.shared.clone();
+        let timezone = self.timezone;
+        let grace_period = self.grace_period;
+
+        // dispatcher 종료시 runner(sender)가 drop 되어 worker가 종료된다
+        self.threads.insert(
+            0,
+            thread::spawn(move || dispatch(shared, runner, timezone, grace_period)),
+        );
+    }
+
+    /// 스케줄러 종료
+    ///
+    /// 새로운 작업 실행을 중지하고 이미 실행 중이거나 대기 중인 작업이 종료될 때까지 대기한다.
+    /// 종료 후 [Scheduler::start]를 다시 호출할 수 없다.
+    pub fn shutdown(&mut self) {
+        self.shared.state().shutdown = true;
+        self.shared.signal.notify_all();
+
+        // dispatcher를 먼저 종료하여 새로운 작업 전달 중지
+        for handle in self.threads.drain(..) {
+            let _ = handle.join();
+        }
+
+        self.shared.wait_idle();
+    }
+}
+
+impl Drop for Scheduler {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+/// 실행 시각이 된 작업을 [Runner]에 전달
+fn dispatch(shared: Arc<Shared>, runner: Runner, timezone: Tz, grace_period: Duration) {
+    let mut state = shared.state();
+
+    while !state.shutdown {
+        let now = Utc::now();
+        let mut earliest: Option<DateTime<Utc>> = None;
+
+        for entry in state.jobs.iter_mut() {
+            if let Some(next_run) = entry.next_run.filter(|v| *v <= now) {
+                let (count, last, following) =
+                    collect_due(&entry.job.schedule, next_run, &now, &timezone);
+
+                for _ in 0..run_count(entry.job.policy, count, &last, &now, grace_period) {
+                    runner.run(&shared, &entry.job.task);
+                }
+
+                entry.next_run = following;
+            }
+
+            if let Some(v) = entry.next_run {
+                earliest = Some(earliest.map_or(v, |e| e.min(v)));
+            }
+        }
+
+        let wait = earliest
+            .map(|v| (v - Utc::now()).to_std().unwrap_or(Duration::ZERO))
+            .unwrap_or(IDLE_WAIT);
+
+        state = shared
+            .signal
+            .wait_timeout(state, wait)
+            .unwrap_or_else(|e| e.into_inner())
+            .0;
+    }
+}
+
+/// `first`부터 `now`까지 지난 실행 시각 수, 마지막 실행 시각 및 다음 실행 시각 반환
+fn collect_due(
+    schedule: &JobSchedule,
+    first: DateTime<Utc>,
+    now: &DateTime<Utc>,
+    timezone: &Tz,
+) -> (u64, DateTime<Utc>, Option<DateTime<Utc>>) {
+    let mut count = 0;
+    let mut last = first;
+    let mut next = Some(first);
+
+    while let Some(v) = next.filter(|v| v <= now) {
+        if count == MAX_CATCH_UP {
+            // 나머지 누락된 실행 시각은 건너뜀
+            next = schedule.next_after(now, timezone);
+            break;
+        }
+
+        count += 1;
+        last = v;
+        next = schedule.next_after(&v, timezone);
+    }
+
+    (count, last, next)
+}
+
+/// [MissedRunPolicy]에 따른 실행 횟수 반환
+fn run_count(
+    policy: MissedRunPolicy,
+    count: u64,
+    last: &DateTime<Utc>,
+    now: &DateTime<Utc>,
+    grace_period: Duration,
+) -> u64 {
+    match policy {
+        MissedRunPolicy::Skip => {
+            let late = (*now - *last).to_std().unwrap_or(Duration::ZERO);
+
+            u64::from(count > 0 && late <= grace_period)
+        }
+        MissedRunPolicy::RunOnce => count.min(1),
+        MissedRunPolicy::RunAll => count,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+    #[test]
+    fn collect_due_test() {
+        let schedule = JobSchedule::Interval(Duration::from_secs(10));
+        let first = Utc.with_ymd_and_hms(2024, 11, 22, 0, 0, 0).unwrap();
+        let now = Utc.with_ymd_and_hms(2024, 11, 22, 0, 0, 35).unwrap();
+        let (count, last, next) = collect_due(&schedule, first, &now, &Tz::UTC);
+
+        assert_eq!(4, count);
+        assert_eq!(Utc.with_ymd_and_hms(2024, 11, 22, 0, 0, 30).unwrap(), last);
+        assert_eq!(
+            Some(Utc.with_ymd_and_hms(2024, 11, 22, 0, 0, 40).unwrap()),
+            next
+        );
+
+        // KST 09:00 매일
+        let schedule = JobSchedule::Cron(CronSchedule::parse("0 9 * * *").unwrap());
+        let first = Utc.with_ymd_and_hms(2024, 11, 22, 0, 0, 0).unwrap();
+        let now = Utc.with_ymd_and_hms(2024, 11, 24, 1, 0, 0).unwrap();
+        let (count, _, next) = collect_due(&schedule, first, &now, &Tz::Asia__Seoul);
+
+        assert_eq!(3, count);
+        assert_eq!(
+            Some(Utc.with_ymd_and_hms(2024, 11, 25, 0, 0, 0).unwrap()),
+            next
+        );
+    }
+
+    #[test]
+    fn run_count_test() {
+        let now = Utc.with_ymd_and_hms(2024, 11, 22, 0, 0, 35).unwrap();
+        let late = Utc.with_ymd_and_hms(2024, 11, 22, 0, 0, 30).unwrap();
+        let grace = Duration::from_secs(1);
+
+        assert_eq!(0, run_count(MissedRunPolicy::Skip, 4, &late, &now, grace));
+        assert_eq!(1, run_count(MissedRunPolicy::Skip, 1, &now, &now, grace));
+        assert_eq!(
+            1,
+            run_count(MissedRunPolicy::RunOnce, 4, &late, &now, grace)
+        );
+        assert_eq!(4, run_count(MissedRunPolicy::RunAll, 4, &late, &now, grace));
+        assert_eq!(0, run_count(MissedRunPolicy::RunAll, 0, &late, &now, grace));
+    }
+
+    #[test]
+    fn add_remove_test() {
+        let scheduler = Scheduler::new();
+        let job = Job::interval("a", Duration::from_secs(1), || {}).unwrap();
+
+        assert!(scheduler.add(job.clone()).is_ok());
+        assert!(scheduler.add(job).is_err());
+        assert!(scheduler.next_run("a").is_some());
+        assert_eq!(vec!["a".to_owned()], scheduler.job_names());
+        assert!(scheduler.remove("a"));
+        assert!(!scheduler.remove("a"));
+        assert!(Job::cron("b", "* * *", || {}).is_err());
+
+        // 다음 실행 시각이 범위를 벗어나는 주기
+        let job = Job::interval("huge", Duration::from_secs(10_000_000_000_000), || {}).unwrap();
+
+        assert!(scheduler.add(job).is_ok());
+        assert!(scheduler.next_run("huge").is_none());
+    }
+
+    #[test]
+    fn graceful_shutdown_test() {
+        let finished = Arc::new(AtomicBool::new(false));
+        let f = finished.clone();
+        let mut scheduler = Scheduler::new().with_workers(1);
+
+        scheduler
+            .add(
+                Job::interval("slow", Duration::from_millis(10), move || {
+                    thread::sleep(Duration::from_millis(100));
+                    f.store(true, Ordering::SeqCst);
+                })
+                .unwrap()
+                .with_missed_run_policy(MissedRunPolicy::Skip),
+            )
+            .unwrap();
+        scheduler.start();
+
+        thread::sleep(Duration::from_millis(30));
+        scheduler.shutdown();
+
+        assert!(finished.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn panic_job_test() {
+        let counter = Arc::new(AtomicUsize::new(0));
+        let c = counter.clone();
+        let mut scheduler = Scheduler::new().with_workers(1);
+
+        scheduler
+            .add(Job::interval("panic", Duration::from_millis(10), || panic!("job panic")).unwrap())
+            .unwrap();
+        scheduler
+            .add(
+                Job::interval("count", Duration::from_millis(10), move || {
+                    c.fetch_add(1, Ordering::SeqCst);
+                })
+                .unwrap(),
+            )
+            .unwrap();
+        scheduler.start();
+
+        thread::sleep(Duration::from_millis(60));
+        scheduler.shutdown();
+
+        assert!(counter.load(Ordering::SeqCst) >= 2);
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn tokio_executor_test() {
+        let counter = Arc::new(AtomicUsize::new(0));
+        let c = counter.clone();
+        let mut scheduler = Scheduler::new().with_tokio(tokio::runtime::Handle::current());
+
+        scheduler
+            .add(
+                Job::interval("tick", Duration::from_millis(10), move || {
+                    c.fetch_add(1, Ordering::SeqCst);
+                })
+                .unwrap(),
+            )
+            .unwrap();
+        scheduler.start();
+
+        tokio::time::sleep(Duration::from_millis(60)).await;
+        scheduler.shutdown();
+
+        assert!(counter.load(Ordering::SeqCst) >= 2);
+    }
+}
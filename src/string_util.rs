@@ -2,202 +2,292 @@
 //!
 //! 한글 초/중/종성 분리 관련 소스 출처는 [가사시니](https://gs.saro.me/2018/10/01/백업-가리사니-자바-한글분해-Stream-API,-StringBuilder,-raw-속도-테스트.html)님 블로그 입니다.
 
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::format;
+
 use crate::error::MissingArgumentError;
-use lazy_static::lazy_static;
+#[cfg(feature = "std")]
 use rand::Rng;
-use regex::Regex;
+
+pub mod case;
+pub mod html;
+pub mod interpolate;
+pub mod josa;
+pub mod mask;
+pub mod normalize;
+pub mod number;
+pub mod romanize;
+pub mod similarity;
+pub mod url;
+pub mod width;
+
+#[cfg(feature = "icu")]
+pub mod collate;
+
+#[cfg(feature = "euckr")]
+pub mod euckr;
+
+#[cfg(feature = "std")]
+pub mod random;
+
+#[cfg(feature = "std")]
+pub mod uuid;
 
 // 마스킹 처리용 문자
 // const APPLY_MASK: &str = "*";
 
-lazy_static! {
-    /// 이메일 정규식
-    static ref EMAIL_REGEX: Regex = Regex::new(r"^[\w\-]+(\.[\w\-]+)*@([A-Za-z0-9-]+\.)+[A-Za-z]{2,4}$").unwrap();
-
-    static ref RANDOM_SOURCE: Vec<&'static str> = vec![
-        "0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "0", "a", "b", "c", "d", "e", "f", "g",
-        "h", "i", "j", "k", "l", "m", "n", "o", "p", "q", "r", "s", "t", "u", "v", "w", "x", "y",
-        "z", "A", "B", "C", "D", "E", "F", "G", "H", "I", "J", "K", "L", "M", "N", "O", "P", "Q",
-        "R", "S", "T", "U", "V", "W", "X", "Y", "Z",
-    ];
-
-    static ref RANDOM_SOURCE_SPEC: Vec<&'static str> = vec![
-        "0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "0", "a", "b", "c", "d", "e", "f", "g",
-        "h", "i", "j", "k", "l", "m", "n", "o", "p", "q", "r", "s", "t", "u", "v", "w", "x", "y",
-        "z", "A", "B", "C", "D", "E", "F", "G", "H", "I", "J", "K", "L", "M", "N", "O", "P", "Q",
-        "R", "S", "T", "U", "V", "W", "X", "Y", "Z", "~", "`", "!", "@", "#", "$", "%", "^", "&",
-        "*", "(", ")", "-", "_", "=", "+", "[", "{", "]", "}", ";", ":", "'", "\"", ",", "<", ".",
-        ">", "/", "?", "\\"
-    ];
-
-    // -----------------------------------------------------------------------------------------------------------------
-    // 한글 관련
-    // -----------------------------------------------------------------------------------------------------------------
-    /// 한글 자음(초성)
-    static ref KO_CONSONANTS: Vec<char> = vec![
-        'ㄱ', 'ㄲ', 'ㄴ', 'ㄷ', 'ㄸ', 'ㄹ', 'ㅁ', 'ㅂ', 'ㅃ', 'ㅅ', 'ㅆ', 'ㅇ', 'ㅈ', 'ㅉ', 'ㅊ', 'ㅋ',
-        'ㅌ', 'ㅍ', 'ㅎ',
-    ];
-
-    /// 한글 자음 분해(된소리 포함)
-    static ref KO_SEPARATED_CONSONANTS: Vec<Vec<char>> = vec![
-        vec!['ㄱ'],
-        vec!['ㄱ', 'ㄱ'],
-        vec!['ㄴ'],
-        vec!['ㄷ'],
-        vec!['ㄷ', 'ㄷ'],
-        vec!['ㄹ'],
-        vec!['ㅁ'],
-        vec!['ㅂ'],
-        vec!['ㅂ', 'ㅂ'],
-        vec!['ㅅ'],
-        vec!['ㅅ', 'ㅅ'],
-        vec!['ㅇ'],
-        vec!['ㅈ'],
-        vec!['ㅈ', 'ㅈ'],
-        vec!['ㅊ'],
-        vec!['ㅋ'],
-        vec!['ㅌ'],
-        vec!['ㅍ'],
-        vec!['ㅎ'],
-    ];
-
-    /// 한글 모음
-    static ref KO_VOWELS: Vec<char> = vec![
-        'ㅏ', 'ㅐ', 'ㅑ', 'ㅒ', 'ㅓ', 'ㅔ', 'ㅕ', 'ㅖ', 'ㅗ', 'ㅘ', 'ㅙ', 'ㅚ', 'ㅛ', 'ㅜ', 'ㅝ', 'ㅞ',
-        'ㅟ', 'ㅠ', 'ㅡ', 'ㅢ', 'ㅣ',
-    ];
-
-    /// 한글 모음 분해
-    static ref KO_SEPARATED_VOWELS: Vec<Vec<char>> = vec![
-        vec!['ㅏ'],
-        vec!['ㅐ'],
-        vec!['ㅑ'],
-        vec!['ㅒ'],
-        vec!['ㅓ'],
-        vec!['ㅔ'],
-        vec!['ㅕ'],
-        vec!['ㅖ'],
-        vec!['ㅗ'],
-        vec!['ㅗ', 'ㅏ'],
-        vec!['ㅗ', 'ㅐ'],
-        vec!['ㅗ', 'ㅣ'],
-        vec!['ㅛ'],
-        vec!['ㅜ'],
-        vec!['ㅜ', 'ㅓ'],
-        vec!['ㅜ', 'ㅔ'],
-        vec!['ㅜ', 'ㅣ'],
-        vec!['ㅠ'],
-        vec!['ㅡ'],
-        vec!['ㅡ', 'ㅣ'],
-        vec!['ㅣ'],
-    ];
-
-    /// 한글 받침
-    static ref KO_FINAL_CONSONANTS: Vec<char> = vec![
-        0 as char, 'ㄱ', 'ㄲ', 'ㄳ', 'ㄴ', 'ㄵ', 'ㄶ', 'ㄷ', 'ㄹ', 'ㄺ', 'ㄻ', 'ㄼ', 'ㄽ', 'ㄾ', 'ㄿ', 'ㅀ', 'ㅁ',
-        'ㅂ', 'ㅄ', 'ㅅ', 'ㅆ', 'ㅇ', 'ㅈ', 'ㅊ', 'ㅋ', 'ㅌ', 'ㅍ', 'ㅎ',
-    ];
-
-    /// 한글 받침 분해
-    static ref KO_SEPARATED_FINAL_CONSONANTS: Vec<Vec<char>> = vec![
-        vec![],
-        vec!['ㄱ'],
-        vec!['ㄱ', 'ㄱ'],
-        vec!['ㄱ', 'ㅅ'],
-        vec!['ㄴ'],
-        vec!['ㄴ', 'ㅈ'],
-        vec!['ㄴ', 'ㅎ'],
-        vec!['ㄷ'],
-        vec!['ㄹ'],
-        vec!['ㄹ', 'ㄱ'],
-        vec!['ㄹ', 'ㅁ'],
-        vec!['ㄹ', 'ㅂ'],
-        vec!['ㄹ', 'ㅅ'],
-        vec!['ㄹ', 'ㅌ'],
-        vec!['ㄹ', 'ㅍ'],
-        vec!['ㄹ', 'ㅎ'],
-        vec!['ㅁ'],
-        vec!['ㅂ'],
-        vec!['ㅂ', 'ㅅ'],
-        vec!['ㅅ'],
-        vec!['ㅅ', 'ㅅ'],
-        vec!['ㅇ'],
-        vec!['ㅈ'],
-        vec!['ㅊ'],
-        vec!['ㅋ'],
-        vec!['ㅌ'],
-        vec!['ㅍ'],
-        vec!['ㅎ'],
-    ];
-
-    /// 한글 쌍자음/이중 모음 분해
-    static ref KO_SEPARATED_FORTES_VOWELS: Vec<Vec<char>> = vec![
-        vec!['ㄱ'],
-        vec!['ㄱ', 'ㄱ'],
-        vec!['ㄱ', 'ㅅ'],
-        vec!['ㄴ'],
-        vec!['ㄴ', 'ㅈ'],
-        vec!['ㄴ', 'ㅎ'],
-        vec!['ㄷ'],
-        vec!['ㄸ'],
-        vec!['ㄹ'],
-        vec!['ㄹ', 'ㄱ'],
-        vec!['ㄹ', 'ㅁ'],
-        vec!['ㄹ', 'ㅂ'],
-        vec!['ㄹ', 'ㅅ'],
-        vec!['ㄹ', 'ㄷ'],
-        vec!['ㄹ', 'ㅍ'],
-        vec!['ㄹ', 'ㅎ'],
-        vec!['ㅁ'],
-        vec!['ㅂ'],
-        vec!['ㅂ', 'ㅂ'],
-        vec!['ㅂ', 'ㅅ'],
-        vec!['ㅅ'],
-        vec!['ㅅ', 'ㅅ'],
-        vec!['ㅇ'],
-        vec!['ㅈ'],
-        vec!['ㅈ', 'ㅈ'],
-        vec!['ㅊ'],
-        vec!['ㅋ'],
-        vec!['ㅌ'],
-        vec!['ㅍ'],
-        vec!['ㅎ'],
-        vec!['ㅏ'],
-        vec!['ㅐ'],
-        vec!['ㅑ'],
-        vec!['ㅒ'],
-        vec!['ㅓ'],
-        vec!['ㅔ'],
-        vec!['ㅕ'],
-        vec!['ㅖ'],
-        vec!['ㅗ'],
-        vec!['ㅗ', 'ㅏ'],
-        vec!['ㅗ', 'ㅐ'],
-        vec!['ㅗ', 'ㅣ'],
-        vec!['ㅛ'],
-        vec!['ㅜ'],
-        vec!['ㅜ', 'ㅓ'],
-        vec!['ㅜ', 'ㅔ'],
-        vec!['ㅜ', 'ㅣ'],
-        vec!['ㅠ'],
-        vec!['ㅡ'],
-        vec!['ㅡ', 'ㅣ'],
-        vec!['ㅣ'],
-    ];
-}
+#[cfg(feature = "std")]
+static RANDOM_SOURCE: &[&str] = &[
+    "0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "0", "a", "b", "c", "d", "e", "f", "g",
+    "h", "i", "j", "k", "l", "m", "n", "o", "p", "q", "r", "s", "t", "u", "v", "w", "x", "y",
+    "z", "A", "B", "C", "D", "E", "F", "G", "H", "I", "J", "K", "L", "M", "N", "O", "P", "Q",
+    "R", "S", "T", "U", "V", "W", "X", "Y", "Z",
+];
+
+#[cfg(feature = "std")]
+static RANDOM_SOURCE_SPEC: &[&str] = &[
+    "0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "0", "a", "b", "c", "d", "e", "f", "g",
+    "h", "i", "j", "k", "l", "m", "n", "o", "p", "q", "r", "s", "t", "u", "v", "w", "x", "y",
+    "z", "A", "B", "C", "D", "E", "F", "G", "H", "I", "J", "K", "L", "M", "N", "O", "P", "Q",
+    "R", "S", "T", "U", "V", "W", "X", "Y", "Z", "~", "`", "!", "@", "#", "$", "%", "^", "&",
+    "*", "(", ")", "-", "_", "=", "+", "[", "{", "]", "}", ";", ":", "'", "\"", ",", "<", ".",
+    ">", "/", "?", "\\"
+];
+
+// -----------------------------------------------------------------------------------------------------------------
+// 한글 관련
+// -----------------------------------------------------------------------------------------------------------------
+/// 한글 자음(초성)
+static KO_CONSONANTS: &[char] = &[
+    'ㄱ', 'ㄲ', 'ㄴ', 'ㄷ', 'ㄸ', 'ㄹ', 'ㅁ', 'ㅂ', 'ㅃ', 'ㅅ', 'ㅆ', 'ㅇ', 'ㅈ', 'ㅉ', 'ㅊ', 'ㅋ',
+    'ㅌ', 'ㅍ', 'ㅎ',
+];
+
+/// 한글 자음 분해(된소리 포함)
+static KO_SEPARATED_CONSONANTS: &[&[char]] = &[
+    &['ㄱ'],
+    &['ㄱ', 'ㄱ'],
+    &['ㄴ'],
+    &['ㄷ'],
+    &['ㄷ', 'ㄷ'],
+    &['ㄹ'],
+    &['ㅁ'],
+    &['ㅂ'],
+    &['ㅂ', 'ㅂ'],
+    &['ㅅ'],
+    &['ㅅ', 'ㅅ'],
+    &['ㅇ'],
+    &['ㅈ'],
+    &['ㅈ', 'ㅈ'],
+    &['ㅊ'],
+    &['ㅋ'],
+    &['ㅌ'],
+    &['ㅍ'],
+    &['ㅎ'],
+];
+
+/// 한글 모음
+static KO_VOWELS: &[char] = &[
+    'ㅏ', 'ㅐ', 'ㅑ', 'ㅒ', 'ㅓ', 'ㅔ', 'ㅕ', 'ㅖ', 'ㅗ', 'ㅘ', 'ㅙ', 'ㅚ', 'ㅛ', 'ㅜ', 'ㅝ', 'ㅞ',
+    'ㅟ', 'ㅠ', 'ㅡ', 'ㅢ', 'ㅣ',
+];
+
+/// 한글 모음 분해
+static KO_SEPARATED_VOWELS: &[&[char]] = &[
+    &['ㅏ'],
+    &['ㅐ'],
+    &['ㅑ'],
+    &['ㅒ'],
+    &['ㅓ'],
+    &['ㅔ'],
+    &['ㅕ'],
+    &['ㅖ'],
+    &['ㅗ'],
+    &['ㅗ', 'ㅏ'],
+    &['ㅗ', 'ㅐ'],
+    &['ㅗ', 'ㅣ'],
+    &['ㅛ'],
+    &['ㅜ'],
+    &['ㅜ', 'ㅓ'],
+    &['ㅜ', 'ㅔ'],
+    &['ㅜ', 'ㅣ'],
+    &['ㅠ'],
+    &['ㅡ'],
+    &['ㅡ', 'ㅣ'],
+    &['ㅣ'],
+];
+
+/// 한글 받침
+static KO_FINAL_CONSONANTS: &[char] = &[
+    0 as char, 'ㄱ', 'ㄲ', 'ㄳ', 'ㄴ', 'ㄵ', 'ㄶ', 'ㄷ', 'ㄹ', 'ㄺ', 'ㄻ', 'ㄼ', 'ㄽ', 'ㄾ', 'ㄿ', 'ㅀ', 'ㅁ',
+    'ㅂ', 'ㅄ', 'ㅅ', 'ㅆ', 'ㅇ', 'ㅈ', 'ㅊ', 'ㅋ', 'ㅌ', 'ㅍ', 'ㅎ',
+];
+
+/// 한글 받침 분해
+static KO_SEPARATED_FINAL_CONSONANTS: &[&[char]] = &[
+    &[],
+    &['ㄱ'],
+    &['ㄱ', 'ㄱ'],
+    &['ㄱ', 'ㅅ'],
+    &['ㄴ'],
+    &['ㄴ', 'ㅈ'],
+    &['ㄴ', 'ㅎ'],
+    &['ㄷ'],
+    &['ㄹ'],
+    &['ㄹ', 'ㄱ'],
+    &['ㄹ', 'ㅁ'],
+    &['ㄹ', 'ㅂ'],
+    &['ㄹ', 'ㅅ'],
+    &['ㄹ', 'ㅌ'],
+    &['ㄹ', 'ㅍ'],
+    &['ㄹ', 'ㅎ'],
+    &['ㅁ'],
+    &['ㅂ'],
+    &['ㅂ', 'ㅅ'],
+    &['ㅅ'],
+    &['ㅅ', 'ㅅ'],
+    &['ㅇ'],
+    &['ㅈ'],
+    &['ㅊ'],
+    &['ㅋ'],
+    &['ㅌ'],
+    &['ㅍ'],
+    &['ㅎ'],
+];
+
+/// 한글 쌍자음/이중 모음 분해
+static KO_SEPARATED_FORTES_VOWELS: &[&[char]] = &[
+    &['ㄱ'],
+    &['ㄱ', 'ㄱ'],
+    &['ㄱ', 'ㅅ'],
+    &['ㄴ'],
+    &['ㄴ', 'ㅈ'],
+    &['ㄴ', 'ㅎ'],
+    &['ㄷ'],
+    &['ㄸ'],
+    &['ㄹ'],
+    &['ㄹ', 'ㄱ'],
+    &['ㄹ', 'ㅁ'],
+    &['ㄹ', 'ㅂ'],
+    &['ㄹ', 'ㅅ'],
+    &['ㄹ', 'ㄷ'],
+    &['ㄹ', 'ㅍ'],
+    &['ㄹ', 'ㅎ'],
+    &['ㅁ'],
+    &['ㅂ'],
+    &['ㅂ', 'ㅂ'],
+    &['ㅂ', 'ㅅ'],
+    &['ㅅ'],
+    &['ㅅ', 'ㅅ'],
+    &['ㅇ'],
+    &['ㅈ'],
+    &['ㅈ', 'ㅈ'],
+    &['ㅊ'],
+    &['ㅋ'],
+    &['ㅌ'],
+    &['ㅍ'],
+    &['ㅎ'],
+    &['ㅏ'],
+    &['ㅐ'],
+    &['ㅑ'],
+    &['ㅒ'],
+    &['ㅓ'],
+    &['ㅔ'],
+    &['ㅕ'],
+    &['ㅖ'],
+    &['ㅗ'],
+    &['ㅗ', 'ㅏ'],
+    &['ㅗ', 'ㅐ'],
+    &['ㅗ', 'ㅣ'],
+    &['ㅛ'],
+    &['ㅜ'],
+    &['ㅜ', 'ㅓ'],
+    &['ㅜ', 'ㅔ'],
+    &['ㅜ', 'ㅣ'],
+    &['ㅠ'],
+    &['ㅡ'],
+    &['ㅡ', 'ㅣ'],
+    &['ㅣ'],
+];
 
 /// 주어진 이메일 주소의 유효성 검사 결과를 반환한다.
 ///
+/// [is_valid_email]의 `strict` 검사와 동일하다(domain에 `.` 포함 필요).
+///
 /// 만약 대상 문자열이 `None`일 경우 [`MissingArgumentError`]를 반환한다.
 pub fn validate_email(target: Option<&str>) -> Result<bool, MissingArgumentError> {
     // TODO(joonho): 2023-10-03 한글 도메인 및 ID 포함
     match target {
         None => Err(MissingArgumentError::default()),
-        Some(v) => Ok(EMAIL_REGEX.is_match(v)),
+        Some(v) => Ok(is_valid_email(v, true)),
     }
 }
 
+/// 이메일 계정(local part)에 허용되는 특수 문자(RFC 5322 `atext`)
+const EMAIL_LOCAL_SPECIALS: &str = "!#$%&'*+/=?^_`{|}~-";
+
+/// 이메일 주소 유효성 검사
+///
+/// RFC 5321을 기준으로 한 실용적인 검사이며 다음 조건을 확인한다. 따옴표로 감싼 계정(`"a b"@test.com`) 및
+/// IP 주소 domain(`user@[127.0.0.1]`)은 허용하지 않는다.
+///
+/// - 전체 254자, 계정 64자, domain 253자 이하(byte 기준)
+/// - 계정은 `.`으로 구분된 빈 문자열이 아닌 묶음이며 영문자, 숫자, 한글 등 문자 및 `` !#$%&'*+/=?^_`{|}~- `` 허용
+/// - domain은 `.`으로 구분된 1 ~ 63자의 영문자, 숫자 및 `-`(처음과 마지막 제외) 묶음
+/// - 최상위 domain(TLD)은 숫자로만 구성될 수 없음
+///
+/// `strict`가 `true`일 경우 domain에 `.`이 하나 이상 포함되어야 한다(e.g. `user@localhost` 불가).
+///
+/// # Arguments
+///
+/// - `target` - 검사 대상 이메일 주소
+/// - `strict` - domain의 `.` 포함 여부 검사
+///
+/// # Return
+///
+/// - 유효한 이메일 주소일 경우 `true`
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::is_valid_email;
+///
+/// assert!(is_valid_email("joonho.son+test@me.com", true));
+/// assert!(is_valid_email("admin@localhost", false));
+/// assert!(!is_valid_email("admin@localhost", true));
+/// assert!(!is_valid_email("joonho..son@me.com", false));
+/// assert!(!is_valid_email("joonho@-me.com", false));
+/// ```
+pub fn is_valid_email(target: &str, strict: bool) -> bool {
+    let Some((local, domain)) = target.rsplit_once('@') else {
+        return false;
+    };
+
+    if target.len() > 254 || local.is_empty() || local.len() > 64 || domain.len() > 253 {
+        return false;
+    }
+
+    let valid_local = local.split('.').all(|v| {
+        !v.is_empty()
+            && v.chars()
+                .all(|c| c.is_alphanumeric() || EMAIL_LOCAL_SPECIALS.contains(c))
+    });
+    let labels: Vec<&str> = domain.split('.').collect();
+    let valid_domain = labels.iter().all(|v| {
+        (1..=63).contains(&v.len())
+            && !v.starts_with('-')
+            && !v.ends_with('-')
+            && v.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+    });
+    let valid_top_level = labels.len() == 1
+        || labels
+            .last()
+            .is_some_and(|v| !v.chars().all(|c| c.is_ascii_digit()));
+
+    valid_local && valid_domain && valid_top_level && (!strict || labels.len() > 1)
+}
+
 /// 주어진 문자열에서 한글 초성만 추출.
 ///
 /// 한글이 아닌 다른 문자(한자, 알파벳, 이모티콘, 특수 문자 등)는 그대로 반환한다.
@@ -219,27 +309,91 @@ pub fn validate_email(target: Option<&str>) -> Result<bool, MissingArgumentError
 ///
 /// - 추출 결과. `Result<String, MissingArgumentError>`
 pub fn extract_initial_consonant(target: Option<&str>) -> Result<String, MissingArgumentError> {
-    match target {
-        None => Err(MissingArgumentError::default()),
-        Some(v) => {
-            let result = {
-                let mut temp = String::with_capacity(v.chars().count()); // 글자수 만큼 미리 생성
+    target
+        .map(extract_choseong)
+        .ok_or_else(MissingArgumentError::default)
+}
 
-                for (_, t) in v.chars().enumerate() {
-                    if t >= '가' && t <= '힣' {
-                        temp += KO_CONSONANTS[(((t as u32) - ('가' as u32)) / 588) as usize]
-                            .to_string()
-                            .as_str();
-                    } else {
-                        temp += t.to_string().as_str();
-                    }
-                }
+/// 주어진 문자열에서 한글 초성만 추출([extract_initial_consonant] 참고)
+///
+/// 한글 완성형 음절이 아닌 문자(영문자, 숫자, 한글 자모 등)는 그대로 반환한다.
+///
+/// # Arguments
+///
+/// - `target` - 추출 대상 문자열
+///
+/// # Return
+///
+/// - 추출 결과
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::extract_choseong;
+///
+/// assert_eq!("ㅎㄱㄷ", extract_choseong("홍길동"));
+/// assert_eq!("ㅅㅇ 2024 Team", extract_choseong("서울 2024 Team"));
+/// ```
+pub fn extract_choseong(target: &str) -> String {
+    target.chars().map(choseong_of).collect()
+}
 
-                temp
-            };
+/// 초성 검색 일치 여부
+///
+/// `query`의 각 글자가 `text`의 연속된 글자와 일치하는지 확인한다. `query`의 한글 자음(`ㄱ` ~ `ㅎ`)은
+/// 해당 초성으로 시작하는 음절 또는 같은 자음과 일치하며, 그 외 글자는 같은 글자(영문자는 대소문자 구분 없음)와
+/// 일치한다. 자동 완성 등의 초성 검색에 사용한다.
+///
+/// # Arguments
+///
+/// - `text` - 검색 대상 문자열
+/// - `query` - 검색어(e.g. `ㅎㄱㄷ`, `ㅎ길ㄷ`)
+///
+/// # Return
+///
+/// - 일치 여부. `query`가 빈 문자열일 경우 `true`
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::matches_choseong;
+///
+/// assert!(matches_choseong("홍길동", "ㅎㄱㄷ"));
+/// assert!(matches_choseong("대한민국 서울", "ㅁㄱ ㅅ"));
+/// assert!(matches_choseong("홍길동", "ㄱ동"));
+/// assert!(matches_choseong("Rust 프로그래밍", "rust ㅍㄹ"));
+/// assert!(!matches_choseong("홍길동", "ㅎㄷ"));
+/// ```
+pub fn matches_choseong(text: &str, query: &str) -> bool {
+    let text: Vec<char> = text.chars().collect();
+    let query: Vec<char> = query.chars().collect();
 
-            Ok(result)
-        }
+    if query.is_empty() {
+        return true;
+    }
+
+    if query.len() > text.len() {
+        return false;
+    }
+
+    text.windows(query.len()).any(|window| {
+        window.iter().zip(query.iter()).all(|(t, q)| {
+            if KO_CONSONANTS.contains(q) {
+                choseong_of(*t) == *q
+            } else {
+                t.eq_ignore_ascii_case(q)
+            }
+        })
+    })
+}
+
+/// 한글 음절의 초성 반환. 한글 음절이 아닐 경우 해당 문자 반환
+#[inline]
+fn choseong_of(c: char) -> char {
+    if ('가'..='힣').contains(&c) {
+        KO_CONSONANTS[(((c as u32) - ('가' as u32)) / 588) as usize]
+    } else {
+        c
     }
 }
 
@@ -408,6 +562,214 @@ pub fn separate_consonant_vowel_completely(
     }
 }
 
+/// 한글 음절을 초/중/종성으로 분해
+///
+/// 초성의 된소리, 중성의 이중모음 및 종성의 겹받침은 분리하지 않는다([separate_simple_consonant_vowel]와 동일).
+///
+/// # Arguments
+///
+/// - `syllable` - 분해 대상 음절(`가` ~ `힣`)
+///
+/// # Return
+///
+/// - (초성, 중성, 종성) `Option<(char, char, Option<char>)>`. 한글 완성형 음절이 아닐 경우 `None`
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::decompose_hangul;
+///
+/// assert_eq!(Some(('ㅎ', 'ㅏ', Some('ㄴ'))), decompose_hangul('한'));
+/// assert_eq!(Some(('ㄲ', 'ㅘ', None)), decompose_hangul('꽈'));
+/// assert_eq!(Some(('ㅇ', 'ㅓ', Some('ㅄ'))), decompose_hangul('없'));
+/// assert_eq!(None, decompose_hangul('A'));
+/// ```
+pub fn decompose_hangul(syllable: char) -> Option<(char, char, Option<char>)> {
+    if !('가'..='힣').contains(&syllable) {
+        return None;
+    }
+
+    let offset = (syllable as u32) - ('가' as u32);
+    let jong = (offset % 28) as usize;
+
+    Some((
+        KO_CONSONANTS[(offset / 588) as usize],
+        KO_VOWELS[((offset % 588) / 28) as usize],
+        if jong == 0 { None } else { Some(KO_FINAL_CONSONANTS[jong]) },
+    ))
+}
+
+/// 초/중/종성을 한글 음절로 조합
+///
+/// # Arguments
+///
+/// - `cho` - 초성(`ㄱ` ~ `ㅎ`, 된소리 포함)
+/// - `jung` - 중성(`ㅏ` ~ `ㅣ`, 이중모음 포함)
+/// - `jong` - 종성(겹받침 포함). 받침이 없을 경우 `None`
+///
+/// # Return
+///
+/// - 조합 결과 `Option<char>`. 초/중/종성으로 사용할 수 없는 문자가 포함된 경우 `None`
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::compose_hangul;
+///
+/// assert_eq!(Some('한'), compose_hangul('ㅎ', 'ㅏ', Some('ㄴ')));
+/// assert_eq!(Some('꽈'), compose_hangul('ㄲ', 'ㅘ', None));
+/// // `ㄸ`은 받침으로 사용할 수 없음
+/// assert_eq!(None, compose_hangul('ㄷ', 'ㅏ', Some('ㄸ')));
+/// ```
+pub fn compose_hangul(cho: char, jung: char, jong: Option<char>) -> Option<char> {
+    let cho = KO_CONSONANTS.iter().position(|v| *v == cho)?;
+    let jung = KO_VOWELS.iter().position(|v| *v == jung)?;
+    let jong = match jong {
+        None => 0,
+        Some(c) => KO_FINAL_CONSONANTS[1..].iter().position(|v| *v == c)? + 1,
+    };
+
+    char::from_u32(('가' as u32) + ((cho * 21 + jung) * 28 + jong) as u32)
+}
+
+/// 문자열의 한글 음절을 초/중/종성 자모로 분해([decompose_hangul] 참고)
+///
+/// 한글 완성형 음절이 아닌 문자는 그대로 반환한다.
+///
+/// # Arguments
+///
+/// - `target` - 분해 대상 문자열
+///
+/// # Return
+///
+/// - 분해 결과
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::to_jamo;
+///
+/// assert_eq!("ㅎㅏㄴㄱㅡㄹ 2024", to_jamo("한글 2024"));
+/// ```
+pub fn to_jamo(target: &str) -> String {
+    let mut result = String::with_capacity(target.len() * 3);
+
+    for c in target.chars() {
+        match decompose_hangul(c) {
+            Some((cho, jung, jong)) => {
+                result.push(cho);
+                result.push(jung);
+
+                if let Some(jong) = jong {
+                    result.push(jong);
+                }
+            }
+            None => result.push(c),
+        }
+    }
+
+    result
+}
+
+/// 자모 목록을 한글 음절로 조합([to_jamo]의 역변환)
+///
+/// 초성 다음에 중성이 오는 경우 음절로 조합하며, 음절로 조합할 수 없는 자모 및 한글 자모가 아닌 문자는 그대로
+/// 반환한다. 분리된 이중모음(e.g. `ㅗ` + `ㅏ`) 및 겹받침(e.g. `ㄹ` + `ㄱ`)은 하나로 조합하며, 받침 다음에
+/// 중성이 오는 경우 해당 자음은 다음 음절의 초성으로 사용한다.
+///
+/// # Arguments
+///
+/// - `target` - 조합 대상 문자열
+///
+/// # Return
+///
+/// - 조합 결과
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::from_jamo;
+///
+/// assert_eq!("한글 2024", from_jamo("ㅎㅏㄴㄱㅡㄹ 2024"));
+/// assert_eq!("과일", from_jamo("ㄱㅗㅏㅇㅣㄹ"));
+/// assert_eq!("닭을", from_jamo("ㄷㅏㄹㄱㅇㅡㄹ"));
+/// assert_eq!("달기", from_jamo("ㄷㅏㄹㄱㅣ"));
+/// assert_eq!("ㅋㅋ", from_jamo("ㅋㅋ"));
+/// ```
+pub fn from_jamo(target: &str) -> String {
+    let chars: Vec<char> = target.chars().collect();
+    let at = |i: usize| chars.get(i).copied();
+    let is_vowel = |c: Option<char>| c.is_some_and(|v| KO_VOWELS.contains(&v));
+    let mut result = String::with_capacity(target.len());
+    let mut i = 0;
+
+    while i < chars.len() {
+        let cho = chars[i];
+
+        if !KO_CONSONANTS.contains(&cho) || !is_vowel(at(i + 1)) {
+            result.push(cho);
+            i += 1;
+
+            continue;
+        }
+
+        let mut jung = chars[i + 1];
+        let mut next = i + 2;
+
+        if let Some(combined) =
+            at(next).and_then(|v| combine_jamo(KO_SEPARATED_VOWELS, KO_VOWELS, jung, v))
+        {
+            jung = combined;
+            next += 1;
+        }
+
+        let mut jong = None;
+
+        if let Some(c) = at(next) {
+            let is_final = KO_FINAL_CONSONANTS[1..].contains(&c);
+
+            // 다음 자모가 중성일 경우 다음 음절의 초성
+            if is_final && (!KO_CONSONANTS.contains(&c) || !is_vowel(at(next + 1))) {
+                jong = Some(c);
+                next += 1;
+
+                if let Some(combined) = at(next)
+                    .filter(|v| *v != c && !is_vowel(at(next + 1)))
+                    .and_then(|v| {
+                        combine_jamo(KO_SEPARATED_FINAL_CONSONANTS, KO_FINAL_CONSONANTS, c, v)
+                    })
+                {
+                    jong = Some(combined);
+                    next += 1;
+                }
+            }
+        }
+
+        match compose_hangul(cho, jung, jong) {
+            Some(syllable) => result.push(syllable),
+            None => result.extend(&chars[i..next]),
+        }
+
+        i = next;
+    }
+
+    result
+}
+
+/// 분해 목록(`separated`)을 기준으로 두 자모를 하나로 조합(e.g. `ㅗ` + `ㅏ` -> `ㅘ`)
+#[inline]
+fn combine_jamo(
+    separated: &[&[char]],
+    combined: &[char],
+    first: char,
+    second: char,
+) -> Option<char> {
+    separated
+        .iter()
+        .position(|v| **v == [first, second])
+        .map(|i| combined[i])
+}
+
 /// 대상 슬라이스를 16진수 형태 문자열로 반환.
 ///
 /// # Arguments
@@ -449,6 +811,7 @@ pub fn to_hex(target: Option<&[u8]>, to_uppercase: bool) -> Option<String> {
 /// # Return
 ///
 /// - 생성된 문자열
+#[cfg(feature = "std")]
 pub fn generate_random_string(length: u32) -> Option<String> {
     let mut random = rand::thread_rng();
     let mut count: u32 = 0;
@@ -477,6 +840,7 @@ pub fn generate_random_string(length: u32) -> Option<String> {
 /// # Return
 ///
 /// - 생성된 문자열
+#[cfg(feature = "std")]
 pub fn generate_random_string_with_spec(length: u32) -> Option<String> {
     let mut random = rand::thread_rng();
     let mut count: u32 = 0;
@@ -531,6 +895,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn validate_email_consistency_test() {
+        for email in [
+            "a+b@c.co.kr",
+            "a@-c.io",
+            "a@c-.io",
+            "joonho@me.travel",
+            "a@localhost",
+        ] {
+            assert_eq!(
+                is_valid_email(email, true),
+                validate_email(Some(email)).unwrap(),
+                "{}",
+                email
+            );
+        }
+
+        assert!(validate_email(Some("a+b@c.co.kr")).unwrap());
+        assert!(!validate_email(Some("a@-c.io")).unwrap());
+    }
+
     #[test]
     #[should_panic]
     fn invalid_email_should_panic_test() {
@@ -561,6 +946,59 @@ mod tests {
         );
     }
 
+    #[test]
+    fn is_valid_email_test() {
+        for email in [
+            "joonho.son@me.com",
+            "a@b.co",
+            "user+tag@sub.example.co.kr",
+            "o'brien@test.com",
+            "홍길동@test.com",
+            "user@xn--3e0b707e.kr",
+            "user@123.example.com",
+        ] {
+            assert!(is_valid_email(email, true), "{}", email);
+        }
+
+        for email in [
+            "",
+            "@test.com",
+            "test@",
+            "test",
+            ".test@test.com",
+            "test.@test.com",
+            "te st@test.com",
+            "a@b@test.com",
+            "test@test..com",
+            "test@test.com.",
+            "test@-test.com",
+            "test@test-.com",
+            "test@test_1.com",
+            "test@한글도메인.com",
+            "test@127.0.0.1",
+            "\"test\"@test.com",
+        ] {
+            assert!(!is_valid_email(email, false), "{}", email);
+        }
+
+        // domain의 `.` 포함 여부
+        assert!(is_valid_email("admin@localhost", false));
+        assert!(!is_valid_email("admin@localhost", true));
+
+        // 길이 제한
+        let local = "a".repeat(64);
+        let label = "b".repeat(63);
+
+        assert!(is_valid_email(&format!("{}@test.com", local), true));
+        assert!(!is_valid_email(&format!("a{}@test.com", local), true));
+        assert!(is_valid_email(&format!("test@{}.com", label), true));
+        assert!(!is_valid_email(&format!("test@b{}.com", label), true));
+        assert!(!is_valid_email(
+            &format!("{}@{}.{}.{}.{}", local, label, label, label, label),
+            true
+        ));
+    }
+
     #[test]
     fn extract_initial_consonant_test() {
         let mut target = "한글만 있습니다.";
@@ -608,6 +1046,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn choseong_test() {
+        assert_eq!("ㅎㄱㄷ", extract_choseong("홍길동"));
+        assert_eq!("ㄲㅊ ABC-123 ㅋㅋ", extract_choseong("까치 ABC-123 ㅋㅋ"));
+        assert_eq!("", extract_choseong(""));
+
+        assert!(matches_choseong("홍길동", "ㅎㄱㄷ"));
+        assert!(matches_choseong("홍길동", "길ㄷ"));
+        assert!(matches_choseong("홍길동", ""));
+        assert!(matches_choseong("아이폰 iPhone15", "ㅇㅍ iphone"));
+        assert!(matches_choseong("ㅋㅋ", "ㅋ"));
+        assert!(!matches_choseong("홍길동", "ㅎㄱㄷㅇ"));
+        assert!(!matches_choseong("홍길동", "ㄱㅎ"));
+        // 된소리는 예사소리와 구분
+        assert!(!matches_choseong("까치", "ㄱㅊ"));
+        assert!(!matches_choseong("", "ㄱ"));
+    }
+
     #[test]
     fn separate_consonant_vowel_test() {
         let mut target = "한글만";
@@ -711,6 +1167,45 @@ mod tests {
     }
 
     #[test]
+    fn jamo_test() {
+        assert_eq!(Some(('ㄱ', 'ㅏ', None)), decompose_hangul('가'));
+        assert_eq!(Some(('ㅎ', 'ㅣ', Some('ㅎ'))), decompose_hangul('힣'));
+        assert_eq!(None, decompose_hangul('ㄱ'));
+        assert_eq!(Some('가'), compose_hangul('ㄱ', 'ㅏ', None));
+        assert_eq!(Some('힣'), compose_hangul('ㅎ', 'ㅣ', Some('ㅎ')));
+        assert_eq!(None, compose_hangul('ㅏ', 'ㅏ', None));
+        assert_eq!(None, compose_hangul('ㄱ', 'ㄱ', None));
+
+        // 모든 음절 분해/조합
+        for c in '가'..='힣' {
+            let (cho, jung, jong) = decompose_hangul(c).unwrap();
+
+            assert_eq!(Some(c), compose_hangul(cho, jung, jong));
+        }
+
+        let target = "많이 읽었다! 꽃밭에서 Rust 2024 ㅋㅋ";
+
+        assert_eq!(
+            "ㅁㅏㄶㅇㅣ ㅇㅣㄺㅇㅓㅆㄷㅏ! ㄲㅗㅊㅂㅏㅌㅇㅔㅅㅓ Rust 2024 ㅋㅋ",
+            to_jamo(target)
+        );
+        assert_eq!(target, from_jamo(&to_jamo(target)));
+        assert_eq!(
+            separate_simple_consonant_vowel(Some(target)).unwrap(),
+            to_jamo(target)
+        );
+        // 분리된 이중모음/겹받침 조합
+        assert_eq!("왜 없어", from_jamo("ㅇㅗㅐ ㅇㅓㅂㅅㅇㅓ"));
+        assert_eq!("값", from_jamo("ㄱㅏㅂㅅ"));
+        assert_eq!("갑시", from_jamo("ㄱㅏㅂㅅㅣ"));
+        assert_eq!("각ㄱ", from_jamo("ㄱㅏㄱㄱ"));
+        // 조합할 수 없는 자모
+        assert_eq!("ㅏㄱ", from_jamo("ㅏㄱ"));
+        assert_eq!("", from_jamo(""));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
     fn random_string_test() {
         let length = 17;
         let result = generate_random_string(length);
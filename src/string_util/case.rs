@@ -0,0 +1,295 @@
+//! 대소문자 표기 형식(snake_case, camelCase 등) 변환 관련 함수 모음
+//!
+//! 문자열을 단어 단위로 분리한 후 지정된 형식으로 다시 조합한다. 단어는 다음 기준으로 분리한다.
+//!
+//! - 영문자, 숫자 및 한글 등 문자가 아닌 모든 문자(공백, `_`, `-`, `.` 등)는 구분자로 사용하며 결과에서 제외
+//! - 소문자, 숫자 혹은 대소문자가 없는 문자 다음의 대문자(e.g. `fooBar` -> `foo`, `Bar`)
+//! - 연속된 대문자(약어) 다음에 소문자가 오는 경우 마지막 대문자(e.g. `HTTPServer` -> `HTTP`, `Server`)
+//! - 숫자는 앞 단어에 포함(e.g. `utf8String` -> `utf8`, `String`)
+//!
+//! ```rust
+//! use cliff3_util::string_util::case::{to_camel_case, to_snake_case};
+//!
+//! assert_eq!("http_server", to_snake_case("HTTPServer"));
+//! assert_eq!("userId", to_camel_case("user_id"));
+//! ```
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// 문자열을 단어 목록으로 분리
+fn split_words(target: &str) -> Vec<&str> {
+    let chars: Vec<(usize, char)> = target.char_indices().collect();
+    let mut words = Vec::new();
+    let mut start: Option<usize> = None;
+
+    for (i, (position, c)) in chars.iter().enumerate() {
+        if !c.is_alphanumeric() {
+            if let Some(begin) = start.take() {
+                words.push(&target[begin..*position]);
+            }
+
+            continue;
+        }
+
+        if let Some(begin) = start {
+            let prev = chars[i - 1].1;
+            let next_lowercase = chars.get(i + 1).is_some_and(|(_, v)| v.is_lowercase());
+
+            if c.is_uppercase() && (!prev.is_uppercase() || next_lowercase) {
+                words.push(&target[begin..*position]);
+                start = Some(*position);
+            }
+        } else {
+            start = Some(*position);
+        }
+    }
+
+    if let Some(begin) = start {
+        words.push(&target[begin..]);
+    }
+
+    words
+}
+
+/// 단어의 첫 글자는 대문자, 나머지는 소문자로 추가
+fn push_capitalized(result: &mut String, word: &str) {
+    let mut chars = word.chars();
+
+    if let Some(first) = chars.next() {
+        result.extend(first.to_uppercase());
+        result.push_str(&chars.as_str().to_lowercase());
+    }
+}
+
+/// 단어를 구분자로 연결
+fn join_words(target: &str, separator: char, uppercase: bool) -> String {
+    let mut result = String::with_capacity(target.len() + 4);
+
+    for (i, word) in split_words(target).into_iter().enumerate() {
+        if i > 0 {
+            result.push(separator);
+        }
+
+        if uppercase {
+            result.push_str(&word.to_uppercase());
+        } else {
+            result.push_str(&word.to_lowercase());
+        }
+    }
+
+    result
+}
+
+/// snake_case 형식으로 변환
+///
+/// # Arguments
+///
+/// - `target` - 변환 대상 문자열
+///
+/// # Return
+///
+/// - 변환 결과
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::case::to_snake_case;
+///
+/// assert_eq!("http_server", to_snake_case("HTTPServer"));
+/// assert_eq!("user_id", to_snake_case("userID"));
+/// assert_eq!("utf8_string", to_snake_case("utf8String"));
+/// assert_eq!("created_at", to_snake_case("Created At"));
+/// ```
+pub fn to_snake_case(target: &str) -> String {
+    join_words(target, '_', false)
+}
+
+/// kebab-case 형식으로 변환
+///
+/// # Arguments
+///
+/// - `target` - 변환 대상 문자열
+///
+/// # Return
+///
+/// - 변환 결과
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::case::to_kebab_case;
+///
+/// assert_eq!("http-server", to_kebab_case("HTTPServer"));
+/// assert_eq!("max-retry-count", to_kebab_case("max_retry_count"));
+/// ```
+pub fn to_kebab_case(target: &str) -> String {
+    join_words(target, '-', false)
+}
+
+/// SCREAMING_SNAKE_CASE 형식으로 변환
+///
+/// # Arguments
+///
+/// - `target` - 변환 대상 문자열
+///
+/// # Return
+///
+/// - 변환 결과
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::case::to_screaming_snake;
+///
+/// assert_eq!("MAX_RETRY_COUNT", to_screaming_snake("maxRetryCount"));
+/// assert_eq!("HTTP_SERVER", to_screaming_snake("HTTPServer"));
+/// ```
+pub fn to_screaming_snake(target: &str) -> String {
+    join_words(target, '_', true)
+}
+
+/// camelCase 형식으로 변환
+///
+/// 첫 단어는 소문자, 이후 단어는 첫 글자만 대문자로 표기한다(약어 포함, e.g. `HTTPServer` -> `httpServer`).
+///
+/// # Arguments
+///
+/// - `target` - 변환 대상 문자열
+///
+/// # Return
+///
+/// - 변환 결과
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::case::to_camel_case;
+///
+/// assert_eq!("httpServer", to_camel_case("HTTPServer"));
+/// assert_eq!("userId", to_camel_case("user_id"));
+/// assert_eq!("x509Certificate", to_camel_case("X509-certificate"));
+/// ```
+pub fn to_camel_case(target: &str) -> String {
+    let mut result = String::with_capacity(target.len());
+
+    for (i, word) in split_words(target).into_iter().enumerate() {
+        if i == 0 {
+            result.push_str(&word.to_lowercase());
+        } else {
+            push_capitalized(&mut result, word);
+        }
+    }
+
+    result
+}
+
+/// PascalCase 형식으로 변환
+///
+/// 모든 단어의 첫 글자만 대문자로 표기한다(약어 포함, e.g. `HTTPServer` -> `HttpServer`).
+///
+/// # Arguments
+///
+/// - `target` - 변환 대상 문자열
+///
+/// # Return
+///
+/// - 변환 결과
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::case::to_pascal_case;
+///
+/// assert_eq!("HttpServer", to_pascal_case("HTTPServer"));
+/// assert_eq!("UserId", to_pascal_case("user_id"));
+/// ```
+pub fn to_pascal_case(target: &str) -> String {
+    let mut result = String::with_capacity(target.len());
+
+    for word in split_words(target) {
+        push_capitalized(&mut result, word);
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_words_test() {
+        for (target, expected) in [
+            ("", Vec::<&str>::new()),
+            ("___", vec![]),
+            ("foo", vec!["foo"]),
+            ("fooBar", vec!["foo", "Bar"]),
+            ("FooBar", vec!["Foo", "Bar"]),
+            ("HTTPServer", vec!["HTTP", "Server"]),
+            (
+                "getHTTPResponseCode",
+                vec!["get", "HTTP", "Response", "Code"],
+            ),
+            ("userID", vec!["user", "ID"]),
+            ("HTTP2Server", vec!["HTTP2", "Server"]),
+            ("utf8String", vec!["utf8", "String"]),
+            ("version2update", vec!["version2update"]),
+            ("2fa_code", vec!["2fa", "code"]),
+            (
+                "foo__bar--baz  qux.quux",
+                vec!["foo", "bar", "baz", "qux", "quux"],
+            ),
+            ("ABC", vec!["ABC"]),
+            ("A", vec!["A"]),
+            ("사용자Id", vec!["사용자", "Id"]),
+            ("사용자_이름", vec!["사용자", "이름"]),
+        ] {
+            assert_eq!(expected, split_words(target), "{}", target);
+        }
+    }
+
+    #[test]
+    fn case_conversion_test() {
+        for (target, snake, camel, pascal) in [
+            ("HTTPServer", "http_server", "httpServer", "HttpServer"),
+            ("http_server", "http_server", "httpServer", "HttpServer"),
+            ("httpServer", "http_server", "httpServer", "HttpServer"),
+            ("Http-Server", "http_server", "httpServer", "HttpServer"),
+            (
+                "XMLHttpRequest",
+                "xml_http_request",
+                "xmlHttpRequest",
+                "XmlHttpRequest",
+            ),
+            ("user_id_2", "user_id_2", "userId2", "UserId2"),
+            (
+                "base64Encode",
+                "base64_encode",
+                "base64Encode",
+                "Base64Encode",
+            ),
+            (
+                "  leading space",
+                "leading_space",
+                "leadingSpace",
+                "LeadingSpace",
+            ),
+            ("", "", "", ""),
+        ] {
+            assert_eq!(snake, to_snake_case(target), "{}", target);
+            assert_eq!(camel, to_camel_case(target), "{}", target);
+            assert_eq!(pascal, to_pascal_case(target), "{}", target);
+        }
+
+        assert_eq!("xml-http-request", to_kebab_case("XMLHttpRequest"));
+        assert_eq!("XML_HTTP_REQUEST", to_screaming_snake("XMLHttpRequest"));
+        assert_eq!("MAX_SIZE", to_screaming_snake("max-size"));
+        // 변환 결과를 다시 변환
+        assert_eq!(
+            "httpServer",
+            to_camel_case(&to_screaming_snake("httpServer"))
+        );
+        assert_eq!("HttpServer", to_pascal_case(&to_kebab_case("HttpServer")));
+    }
+}
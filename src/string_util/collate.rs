@@ -0,0 +1,119 @@
+//! 언어별 문자열 정렬(collation) 관련 함수 모음
+//!
+//! ICU collator(Unicode Collation Algorithm)를 이용하여 언어별 규칙에 따라 문자열을 비교 및 정렬한다.
+
+use std::cmp::Ordering;
+
+use icu_collator::options::{CollatorOptions, Strength};
+use icu_collator::{Collator, CollatorBorrowed};
+use icu_locale_core::locale;
+
+/// 정렬 규칙 언어
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Locale {
+    /// 한국어. 한글(가나다 순)을 라틴 문자보다 우선 정렬
+    Korean,
+
+    /// 일본어. 히라가나/가타카나(오십음 순) 후 한자 정렬
+    Japanese,
+
+    /// 라틴 문자. 대소문자 및 발음 구별 기호(accent)를 구분하지 않음 (e.g. `École` == `ecole`)
+    Latin,
+}
+
+impl Locale {
+    fn collator(&self) -> CollatorBorrowed<'static> {
+        let (locale, strength) = match self {
+            Locale::Korean => (locale!("ko"), None),
+            Locale::Japanese => (locale!("ja"), None),
+            Locale::Latin => (locale!("und"), Some(Strength::Primary)),
+        };
+        let mut options = CollatorOptions::default();
+
+        options.strength = strength;
+
+        Collator::try_new(locale.into(), options).expect("내장된 collation data가 필요합니다.")
+    }
+}
+
+/// 언어별 규칙으로 두 문자열 비교
+///
+/// # Arguments
+///
+/// - `a` - 비교 대상 문자열
+/// - `b` - 비교 대상 문자열
+/// - `locale` - 정렬 규칙 언어
+///
+/// # Return
+///
+/// - 비교 결과
+///
+/// # Example
+///
+/// ```rust
+/// use std::cmp::Ordering;
+/// use cliff3_util::string_util::collate::{collate, Locale};
+///
+/// assert_eq!(Ordering::Less, collate("가방", "나무", Locale::Korean));
+/// assert_eq!(Ordering::Less, collate("하늘", "apple", Locale::Korean));
+/// assert_eq!(Ordering::Equal, collate("École", "ecole", Locale::Latin));
+/// ```
+pub fn collate(a: &str, b: &str, locale: Locale) -> Ordering {
+    locale.collator().compare(a, b)
+}
+
+/// 언어별 규칙으로 정렬
+///
+/// 동일하게 비교되는 문자열은 기존 순서를 유지한다(stable sort).
+///
+/// # Arguments
+///
+/// - `target` - 정렬 대상
+/// - `locale` - 정렬 규칙 언어
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::collate::{sort_localized, Locale};
+///
+/// let mut names = vec!["さくら".to_owned(), "カメラ".to_owned(), "あい".to_owned()];
+///
+/// sort_localized(&mut names, Locale::Japanese);
+///
+/// assert_eq!(vec!["あい", "カメラ", "さくら"], names);
+/// ```
+pub fn sort_localized<S: AsRef<str>>(target: &mut [S], locale: Locale) {
+    let collator = locale.collator();
+
+    target.sort_by(|a, b| collator.compare(a.as_ref(), b.as_ref()));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sort_localized_test() {
+        let mut target = vec!["하늘", "Zebra", "가방", "apple", "나무", "각도"];
+
+        sort_localized(&mut target, Locale::Korean);
+
+        assert_eq!(
+            vec!["가방", "각도", "나무", "하늘", "apple", "Zebra"],
+            target
+        );
+
+        let mut target = vec!["Émile", "zoo", "apple", "Eve", "emile", "Apple"];
+
+        sort_localized(&mut target, Locale::Latin);
+
+        // 대소문자 및 accent 무시, 동일 항목은 기존 순서 유지
+        assert_eq!(
+            vec!["apple", "Apple", "Émile", "emile", "Eve", "zoo"],
+            target
+        );
+        assert_eq!(Ordering::Equal, collate("RESUME", "résumé", Locale::Latin));
+        assert_eq!(Ordering::Less, collate("いぬ", "漢字", Locale::Japanese));
+    }
+}
@@ -0,0 +1,275 @@
+//! EUC-KR(CP949) 문자열 변환 관련 함수 모음
+//!
+//! 공공기관, 금융기관 등 이전 시스템과 주고받는 EUC-KR byte 배열을 UTF-8 문자열로 변환하거나 그 반대로
+//! 변환한다. 변환은 [encoding_rs]의 EUC-KR(WHATWG Encoding Standard 기준, Windows CP949와 동일)을 사용하므로
+//! 완성형(KS X 1001)에 포함되지 않은 한글(e.g. `똠`, `햏`)을 포함한 현대 한글 11,172자를 모두 변환한다.
+//!
+//! ```rust
+//! use cliff3_util::string_util::euckr::{euckr_to_utf8, utf8_to_euckr};
+//!
+//! let bytes = utf8_to_euckr("한글 ABC").unwrap();
+//!
+//! assert_eq!(vec![0xc7, 0xd1, 0xb1, 0xdb, b' ', b'A', b'B', b'C'], bytes);
+//! assert_eq!("한글 ABC", euckr_to_utf8(&bytes).unwrap());
+//! ```
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use encoding_rs::{DecoderResult, EncoderResult, EUC_KR};
+
+use crate::error::InvalidArgumentError;
+
+/// 변환할 수 없는 문자(byte)의 처리 방식
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EncodingMode {
+    /// [InvalidArgumentError] 반환
+    #[default]
+    Strict,
+
+    /// 대체 문자로 변환. UTF-8 변환시 `U+FFFD`(`�`), EUC-KR 변환시 `?`
+    Lossy,
+}
+
+/// EUC-KR byte 배열을 UTF-8 문자열로 변환
+///
+/// # Arguments
+///
+/// - `bytes` - EUC-KR byte 배열
+///
+/// # Return
+///
+/// - 변환 결과 `Result<String, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - EUC-KR 형식이 아닌 byte를 포함할 경우
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::euckr::euckr_to_utf8;
+///
+/// assert_eq!("똠방각하", euckr_to_utf8(&[0x8c, 0x63, 0xb9, 0xe6, 0xb0, 0xa2, 0xc7, 0xcf]).unwrap());
+/// assert!(euckr_to_utf8(&[0xc7, 0xd1, 0xb1]).is_err());
+/// ```
+pub fn euckr_to_utf8(bytes: &[u8]) -> Result<String, InvalidArgumentError> {
+    euckr_to_utf8_with(bytes, EncodingMode::Strict)
+}
+
+/// 처리 방식을 지정하여 EUC-KR byte 배열을 UTF-8 문자열로 변환
+///
+/// # Arguments
+///
+/// - `bytes` - EUC-KR byte 배열
+/// - `mode` - 변환할 수 없는 byte의 처리 방식 [EncodingMode]
+///
+/// # Return
+///
+/// - 변환 결과 `Result<String, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - [EncodingMode::Strict]일 경우 EUC-KR 형식이 아닌 byte를 포함할 경우
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::euckr::{euckr_to_utf8_with, EncodingMode};
+///
+/// assert_eq!(
+///     "한\u{FFFD}A",
+///     euckr_to_utf8_with(&[0xc7, 0xd1, 0xff, b'A'], EncodingMode::Lossy).unwrap()
+/// );
+/// ```
+pub fn euckr_to_utf8_with(
+    bytes: &[u8],
+    mode: EncodingMode,
+) -> Result<String, InvalidArgumentError> {
+    let mut decoder = EUC_KR.new_decoder_without_bom_handling();
+    let mut result = String::with_capacity(
+        decoder
+            .max_utf8_buffer_length_without_replacement(bytes.len())
+            .unwrap_or(bytes.len()),
+    );
+    let mut position = 0;
+
+    loop {
+        let (found, read) =
+            decoder.decode_to_string_without_replacement(&bytes[position..], &mut result, true);
+
+        position += read;
+
+        match found {
+            DecoderResult::InputEmpty => break,
+            DecoderResult::OutputFull => result.reserve(bytes.len() - position + 16),
+            DecoderResult::Malformed(malformed, consumed) => {
+                if mode == EncodingMode::Strict {
+                    return Err(InvalidArgumentError::new(&format!(
+                        "EUC-KR 형식이 아닌 byte가 있습니다({}번째 byte).",
+                        position - malformed as usize - consumed as usize
+                    )));
+                }
+
+                result.push(char::REPLACEMENT_CHARACTER);
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+/// UTF-8 문자열을 EUC-KR byte 배열로 변환
+///
+/// # Arguments
+///
+/// - `target` - 변환 대상 문자열
+///
+/// # Return
+///
+/// - 변환 결과 `Result<Vec<u8>, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - EUC-KR로 변환할 수 없는 문자(e.g. emoji, 옛한글)를 포함할 경우
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::euckr::utf8_to_euckr;
+///
+/// assert_eq!(vec![0x8c, 0x63], utf8_to_euckr("똠").unwrap());
+/// assert!(utf8_to_euckr("한글😀").is_err());
+/// ```
+pub fn utf8_to_euckr(target: &str) -> Result<Vec<u8>, InvalidArgumentError> {
+    utf8_to_euckr_with(target, EncodingMode::Strict)
+}
+
+/// 처리 방식을 지정하여 UTF-8 문자열을 EUC-KR byte 배열로 변환
+///
+/// # Arguments
+///
+/// - `target` - 변환 대상 문자열
+/// - `mode` - 변환할 수 없는 문자의 처리 방식 [EncodingMode]
+///
+/// # Return
+///
+/// - 변환 결과 `Result<Vec<u8>, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - [EncodingMode::Strict]일 경우 EUC-KR로 변환할 수 없는 문자를 포함할 경우
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::euckr::{utf8_to_euckr_with, EncodingMode};
+///
+/// assert_eq!(
+///     vec![0xc7, 0xd1, b'?', b'A'],
+///     utf8_to_euckr_with("한😀A", EncodingMode::Lossy).unwrap()
+/// );
+/// ```
+pub fn utf8_to_euckr_with(
+    target: &str,
+    mode: EncodingMode,
+) -> Result<Vec<u8>, InvalidArgumentError> {
+    let mut encoder = EUC_KR.new_encoder();
+    let mut result = Vec::with_capacity(
+        encoder
+            .max_buffer_length_from_utf8_without_replacement(target.len())
+            .unwrap_or(target.len()),
+    );
+    let mut rest = target;
+
+    loop {
+        let (found, read) =
+            encoder.encode_from_utf8_to_vec_without_replacement(rest, &mut result, true);
+
+        rest = &rest[read..];
+
+        match found {
+            EncoderResult::InputEmpty => break,
+            EncoderResult::OutputFull => result.reserve(rest.len() * 2 + 16),
+            EncoderResult::Unmappable(c) => {
+                if mode == EncodingMode::Strict {
+                    return Err(InvalidArgumentError::new(&format!(
+                        "EUC-KR로 변환할 수 없는 문자가 있습니다({}, U+{:04X}).",
+                        c, c as u32
+                    )));
+                }
+
+                result.push(b'?');
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::LibError;
+
+    #[test]
+    fn euckr_test() {
+        for (text, bytes) in [
+            ("", vec![]),
+            ("ABC 123", b"ABC 123".to_vec()),
+            ("가", vec![0xb0, 0xa1]),
+            ("힝", vec![0xc8, 0xfe]),
+            // 완성형에 포함되지 않은 한글(CP949 확장)
+            ("똠햏", vec![0x8c, 0x63, 0xc1, 0x64]),
+            ("ㄱ", vec![0xa4, 0xa1]),
+            ("漢字", vec![0xf9, 0xd3, 0xed, 0xae]),
+            ("①※", vec![0xa8, 0xe7, 0xa1, 0xd8]),
+        ] {
+            assert_eq!(bytes, utf8_to_euckr(text).unwrap(), "{}", text);
+            assert_eq!(text, euckr_to_utf8(&bytes).unwrap(), "{}", text);
+        }
+
+        // 현대 한글 11,172자 전체
+        let hangul: String = ('가'..='힣').collect();
+        let bytes = utf8_to_euckr(&hangul).unwrap();
+
+        assert_eq!(11172 * 2, bytes.len());
+        assert_eq!(hangul, euckr_to_utf8(&bytes).unwrap());
+        assert_eq!(EncodingMode::Strict, EncodingMode::default());
+    }
+
+    #[test]
+    fn euckr_error_test() {
+        // 잘못된 byte 및 잘린 byte 배열
+        for bytes in [vec![0xff], vec![b'A', 0xc7], vec![0xc7, 0xd1, 0x80, 0x20]] {
+            assert!(euckr_to_utf8(&bytes).is_err(), "{:?}", bytes);
+        }
+
+        let error = euckr_to_utf8(&[b'A', b'B', 0xff]).unwrap_err();
+
+        assert!(error.get_message().contains("2번째"), "{}", error);
+        assert_eq!(
+            "AB\u{FFFD}\u{FFFD}C",
+            euckr_to_utf8_with(&[b'A', b'B', 0xff, 0xff, b'C'], EncodingMode::Lossy).unwrap()
+        );
+        assert_eq!(
+            "A\u{FFFD}",
+            euckr_to_utf8_with(&[b'A', 0xc7], EncodingMode::Lossy).unwrap()
+        );
+
+        // 변환할 수 없는 문자(emoji, 첫가끝 자모, 기호)
+        for text in ["😀", "a\u{1100}\u{1161}", "\u{2B50}"] {
+            assert!(utf8_to_euckr(text).is_err(), "{}", text);
+        }
+
+        let error = utf8_to_euckr("한😀").unwrap_err();
+
+        assert!(error.get_message().contains("U+1F600"), "{}", error);
+        assert_eq!(
+            b"??A?".to_vec(),
+            utf8_to_euckr_with("😀😀A\u{2B50}", EncodingMode::Lossy).unwrap()
+        );
+    }
+}
@@ -0,0 +1,394 @@
+//! HTML 문자열 처리 관련 함수 모음
+//!
+//! 사용자 입력 값을 메일 본문 등 HTML 문서에 포함하기 전 특수 문자를 entity로 변환하거나, 변환된 문자열을
+//! 원래 문자열로 복원한다. 또한 편집기(rich-text editor)에서 작성한 HTML의 미리보기 등을 위해 tag를 제거한
+//! 일반 텍스트를 생성한다.
+//!
+//! ```rust
+//! use cliff3_util::string_util::html::{escape_html, unescape_html};
+//!
+//! let escaped = escape_html("<script>alert('홍길동 & \"친구\"')</script>");
+//!
+//! assert_eq!(
+//!     "&lt;script&gt;alert(&#39;홍길동 &amp; &quot;친구&quot;&#39;)&lt;/script&gt;",
+//!     escaped
+//! );
+//! assert_eq!(
+//!     "<script>alert('홍길동 & \"친구\"')</script>",
+//!     unescape_html(&escaped)
+//! );
+//! ```
+
+use alloc::format;
+use alloc::string::String;
+
+/// 복원 대상 entity 및 문자
+const NAMED_ENTITIES: &[(&str, char)] = &[
+    ("amp", '&'),
+    ("lt", '<'),
+    ("gt", '>'),
+    ("quot", '"'),
+    ("apos", '\''),
+];
+
+/// 내용 전체를 제거하는 tag
+const RAW_TEXT_TAGS: &[&str] = &["script", "style"];
+
+/// 앞/뒤 문자열과 구분하기 위해 공백으로 변환하는 tag
+const BLOCK_TAGS: &[&str] = &[
+    "address",
+    "article",
+    "aside",
+    "blockquote",
+    "br",
+    "dd",
+    "div",
+    "dl",
+    "dt",
+    "figcaption",
+    "figure",
+    "footer",
+    "form",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "header",
+    "hr",
+    "li",
+    "main",
+    "nav",
+    "ol",
+    "p",
+    "pre",
+    "section",
+    "table",
+    "td",
+    "th",
+    "tr",
+    "ul",
+];
+
+/// HTML 특수 문자(`&`, `<`, `>`, `"`, `'`)를 entity로 변환
+///
+/// 작은 따옴표(`'`)는 HTML 4와의 호환을 위해 `&apos;`가 아닌 `&#39;`로 변환한다. 본문 및 따옴표로 감싼
+/// 속성 값에 사용할 수 있다.
+///
+/// # Arguments
+///
+/// - `target` - 변환 대상 문자열
+///
+/// # Return
+///
+/// - 변환 결과
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::html::escape_html;
+///
+/// assert_eq!("a &lt; b &amp;&amp; c &gt; d", escape_html("a < b && c > d"));
+/// assert_eq!("&quot;홍길동&quot;", escape_html("\"홍길동\""));
+/// ```
+pub fn escape_html(target: &str) -> String {
+    let mut result = String::with_capacity(target.len() + target.len() / 8);
+
+    for c in target.chars() {
+        match c {
+            '&' => result.push_str("&amp;"),
+            '<' => result.push_str("&lt;"),
+            '>' => result.push_str("&gt;"),
+            '"' => result.push_str("&quot;"),
+            '\'' => result.push_str("&#39;"),
+            _ => result.push(c),
+        }
+    }
+
+    result
+}
+
+/// HTML entity를 원래 문자로 복원
+///
+/// 다음 entity를 복원하며, 그 외 entity 혹은 `;`로 끝나지 않는 entity는 변환하지 않는다.
+///
+/// - `&amp;`, `&lt;`, `&gt;`, `&quot;`, `&apos;`
+/// - 10진수(`&#39;`) 및 16진수(`&#x27;`, `&#X27;`) 문자 참조. 유효하지 않은 code point(`0`, surrogate,
+///   `0x10FFFF` 초과)는 `U+FFFD`(`�`)로 변환
+///
+/// # Arguments
+///
+/// - `target` - 복원 대상 문자열
+///
+/// # Return
+///
+/// - 복원 결과
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::html::unescape_html;
+///
+/// assert_eq!("a < b && c > d", unescape_html("a &lt; b &amp;&amp; c &gt; d"));
+/// assert_eq!("한글 😀", unescape_html("&#xD55C;&#44544; &#x1F600;"));
+/// assert_eq!("&nbsp; &amp", unescape_html("&nbsp; &amp"));
+/// ```
+pub fn unescape_html(target: &str) -> String {
+    let mut result = String::with_capacity(target.len());
+    let mut rest = target;
+
+    while let Some(position) = rest.find('&') {
+        result.push_str(&rest[..position]);
+        rest = &rest[position..];
+
+        match decode_entity(rest) {
+            Some((c, length)) => {
+                result.push(c);
+                rest = &rest[length..];
+            }
+            None => {
+                result.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    result.push_str(rest);
+
+    result
+}
+
+/// HTML tag를 제거한 일반 텍스트 반환
+///
+/// - 모든 tag와 주석(`<!-- -->`)을 제거하며 `<script>`, `<style>`은 내용까지 제거
+/// - `<p>`, `<div>`, `<br>`, `<li>` 등 block tag는 공백으로 변환하여 앞/뒤 문자열과 구분
+/// - [unescape_html]로 entity를 복원하며 `&nbsp;`는 공백으로 변환
+/// - 연속된 공백(줄바꿈 포함)은 하나의 공백으로 변환하고 앞/뒤 공백 제거
+///
+/// 뒤에 tag 명칭이 오지 않는 `<`(e.g. `a < b`)는 그대로 출력하며, 닫히지 않은 tag(e.g. 중간에 잘린 HTML의
+/// `<a href=`)는 이후 문자열과 함께 제거한다.
+///
+/// # Arguments
+///
+/// - `target` - 변환 대상 HTML
+///
+/// # Return
+///
+/// - 변환 결과
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::html::strip_html;
+///
+/// let html = r#"<style>p { color: red; }</style>
+/// <p>안녕하세요, <b>홍길동</b>님!</p><p>1 &lt; 2 &amp;&nbsp;<a href="/x?a=1&b=2">링크</a></p>
+/// <script>alert("<p>")</script>"#;
+///
+/// assert_eq!("안녕하세요, 홍길동님! 1 < 2 & 링크", strip_html(html));
+/// ```
+pub fn strip_html(target: &str) -> String {
+    let lowercase = target.to_ascii_lowercase();
+    let mut text = String::with_capacity(target.len());
+    let mut cursor = 0;
+
+    while let Some(found) = target[cursor..].find('<') {
+        let start = cursor + found;
+        let rest = &target[start + 1..];
+
+        text.push_str(&target[cursor..start]);
+
+        if rest.starts_with("!--") {
+            cursor = lowercase[start..]
+                .find("-->")
+                .map_or(target.len(), |v| start + v + 3);
+
+            continue;
+        }
+
+        let closing = rest.starts_with('/');
+        let name_start = start + 1 + usize::from(closing);
+        let name = lowercase[name_start..]
+            .split(|c: char| !c.is_ascii_alphanumeric())
+            .next()
+            .unwrap_or_default();
+        let first = target[name_start..].chars().next();
+
+        if !first.is_some_and(|c| c.is_ascii_alphabetic() || (!closing && (c == '!' || c == '?'))) {
+            // tag가 아닌 `<`
+            text.push('<');
+            cursor = start + 1;
+
+            continue;
+        }
+
+        let Some(end) = tag_end(&target[start..]).map(|v| start + v) else {
+            cursor = target.len();
+
+            break;
+        };
+
+        cursor = end;
+
+        if BLOCK_TAGS.contains(&name) {
+            text.push(' ');
+        } else if !closing && RAW_TEXT_TAGS.contains(&name) && !target[..end].ends_with("/>") {
+            // 닫는 tag까지 제거
+            cursor = match lowercase[end..].find(&format!("</{}", name)) {
+                Some(v) => tag_end(&target[end + v..]).map_or(target.len(), |e| end + v + e),
+                None => target.len(),
+            };
+            text.push(' ');
+        }
+    }
+
+    text.push_str(&target[cursor..]);
+
+    let text = unescape_html(&text.replace("&nbsp;", " "));
+    let mut result = String::with_capacity(text.len());
+
+    for word in text.split_whitespace() {
+        if !result.is_empty() {
+            result.push(' ');
+        }
+
+        result.push_str(word);
+    }
+
+    result
+}
+
+/// `<`로 시작하는 tag의 끝(`>` 다음) 위치 반환. 따옴표로 감싼 속성 값(`=` 다음) 내의 `>`는 제외
+fn tag_end(target: &str) -> Option<usize> {
+    let mut quote: Option<char> = None;
+    let mut prev = '<';
+
+    for (i, c) in target.char_indices() {
+        match (quote, c) {
+            (Some(q), _) if q == c => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') if prev == '=' => quote = Some(c),
+            (None, '>') => return Some(i + 1),
+            _ => {}
+        }
+
+        if !c.is_whitespace() {
+            prev = c;
+        }
+    }
+
+    None
+}
+
+/// `&`로 시작하는 문자열의 entity 복원. 복원한 문자와 entity 길이(byte) 반환
+fn decode_entity(target: &str) -> Option<(char, usize)> {
+    // 가장 긴 entity(`&#x10FFFF;`, `&#1114111;`)보다 긴 경우 제외
+    let end = target.bytes().take(12).position(|v| v == b';')?;
+    let name = &target[1..end];
+    let c = if let Some(number) = name.strip_prefix('#') {
+        let code = match number.strip_prefix(['x', 'X']) {
+            Some(hex) if is_digits(hex, 16) => u32::from_str_radix(hex, 16).ok()?,
+            None if is_digits(number, 10) => number.parse::<u32>().ok()?,
+            _ => return None,
+        };
+
+        char::from_u32(code)
+            .filter(|v| *v != '\0')
+            .unwrap_or(char::REPLACEMENT_CHARACTER)
+    } else {
+        NAMED_ENTITIES
+            .iter()
+            .find(|(entity, _)| *entity == name)
+            .map(|(_, c)| *c)?
+    };
+
+    Some((c, end + 1))
+}
+
+/// 빈 문자열이 아니며 모두 지정된 진법의 숫자인지 여부
+fn is_digits(target: &str, radix: u32) -> bool {
+    !target.is_empty() && target.chars().all(|c| c.is_digit(radix))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escape_html_test() {
+        for (target, expected) in [
+            ("", ""),
+            ("plain 한글", "plain 한글"),
+            ("&", "&amp;"),
+            ("<>\"'", "&lt;&gt;&quot;&#39;"),
+            ("&amp;", "&amp;amp;"),
+            (
+                "<a href=\"x\">홍길동</a>",
+                "&lt;a href=&quot;x&quot;&gt;홍길동&lt;/a&gt;",
+            ),
+        ] {
+            assert_eq!(expected, escape_html(target), "{}", target);
+            assert_eq!(target, unescape_html(&escape_html(target)), "{}", target);
+        }
+    }
+
+    #[test]
+    fn unescape_html_test() {
+        for (target, expected) in [
+            ("", ""),
+            ("&amp;&lt;&gt;&quot;&apos;", "&<>\"'"),
+            ("&#39;&#x27;&#X27;&#0039;", "''''"),
+            ("&#xAC00;&#44033;", "가각"),
+            ("&#x1F600;", "😀"),
+            // 유효하지 않은 code point
+            ("&#0;&#xD800;&#x110000;", "\u{FFFD}\u{FFFD}\u{FFFD}"),
+            ("&#99999999999;", "&#99999999999;"),
+            // 변환하지 않는 entity
+            ("&nbsp;&copy;&AMP;", "&nbsp;&copy;&AMP;"),
+            ("&amp &lt", "&amp &lt"),
+            ("&#;&#x;&#-1;&#x+1;&#1a;", "&#;&#x;&#-1;&#x+1;&#1a;"),
+            ("a & b", "a & b"),
+            ("&", "&"),
+            ("&&amp;;", "&&;"),
+            ("&amp;lt;", "&lt;"),
+        ] {
+            assert_eq!(expected, unescape_html(target), "{}", target);
+        }
+    }
+
+    #[test]
+    fn strip_html_test() {
+        for (target, expected) in [
+            ("", ""),
+            ("plain text", "plain text"),
+            ("<p>a</p><p>b</p>", "a b"),
+            ("<b>홍</b>길동", "홍길동"),
+            ("line1<br>line2<br/>line3<BR />", "line1 line2 line3"),
+            ("<ul><li>하나</li><li>둘</li></ul>", "하나 둘"),
+            ("  a \n\t  b  ", "a b"),
+            ("a&nbsp;&nbsp;b&#160;c", "a b c"),
+            ("&lt;p&gt; is a tag", "<p> is a tag"),
+            ("a < b > c", "a < b > c"),
+            ("1<2", "1<2"),
+            ("</ >", "</ >"),
+            ("<a href=\"x>y\" title='>'>링크</a>", "링크"),
+            ("<p class=it's>값</p>", "값"),
+            ("<!-- 주석 <p>x</p> -->본문", "본문"),
+            ("<!DOCTYPE html><html><body>본문</body></html>", "본문"),
+            ("<?xml version=\"1.0\"?>본문", "본문"),
+            // script/style 내용 제거
+            ("a<script>var s = \"</p>\";</script>b", "a b"),
+            ("a<SCRIPT type=\"x\">x</Script >b", "a b"),
+            ("<style>p { color: red; }</style><p>본문</p>", "본문"),
+            ("a<script src=\"x.js\"/>b", "ab"),
+            ("a<script>닫히지 않음", "a"),
+            ("<scripts>본문</scripts>", "본문"),
+            // 닫히지 않은 tag, 주석
+            ("본문 <a href=", "본문"),
+            ("본문<!-- 주석", "본문"),
+        ] {
+            assert_eq!(expected, strip_html(target), "{}", target);
+        }
+    }
+}
@@ -0,0 +1,368 @@
+//! `{name}` 형식의 간단한 문자열 치환 관련 함수 모음
+//!
+//! 반복, 조건 등이 필요 없는 알림 메시지, 로그 문구 등을 template engine(`template_util`) 없이
+//! 생성한다. 치환 문법은 다음과 같다.
+//!
+//! - `{name}` - `name` 값으로 치환. 명칭 앞/뒤 공백은 무시
+//! - `{{`, `}}` - 각각 `{`, `}` 문자 출력
+//!
+//! 값은 [InterpolateContext]를 구현한 `HashMap`, `BTreeMap`, `(명칭, 값)` 목록 및 `serde_json::Value`(`json` feature
+//! 활성화시)에서 조회한다.
+//!
+//! ```rust
+//! use std::collections::HashMap;
+//! use cliff3_util::string_util::interpolate::interpolate;
+//!
+//! let mut values = HashMap::new();
+//!
+//! values.insert("name", "홍길동".to_owned());
+//! values.insert("count", 3.to_string());
+//!
+//! assert_eq!(
+//!     "Hello 홍길동, you have 3 items {count}",
+//!     interpolate("Hello {name}, you have {count} items {{count}}", &values).unwrap()
+//! );
+//! ```
+
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::{String, ToString};
+use core::borrow::Borrow;
+use core::fmt::Display;
+
+use crate::error::InvalidArgumentError;
+
+/// 치환 값이 없는 명칭의 처리 방법
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MissingKey {
+    /// [InvalidArgumentError] 반환
+    #[default]
+    Error,
+
+    /// `{name}` 그대로 출력
+    Keep,
+
+    /// 빈 문자열로 치환
+    Empty,
+}
+
+/// [interpolate]의 치환 값 조회 대상
+pub trait InterpolateContext {
+    /// 명칭에 해당하는 치환 값 반환. 값이 없을 경우 `None`
+    fn lookup(&self, key: &str) -> Option<String>;
+}
+
+impl<T: InterpolateContext + ?Sized> InterpolateContext for &T {
+    fn lookup(&self, key: &str) -> Option<String> {
+        (**self).lookup(key)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, V, S> InterpolateContext for std::collections::HashMap<K, V, S>
+where
+    K: Borrow<str> + core::hash::Hash + Eq,
+    V: Display,
+    S: core::hash::BuildHasher,
+{
+    fn lookup(&self, key: &str) -> Option<String> {
+        self.get(key).map(|v| v.to_string())
+    }
+}
+
+impl<K, V> InterpolateContext for BTreeMap<K, V>
+where
+    K: Borrow<str> + Ord,
+    V: Display,
+{
+    fn lookup(&self, key: &str) -> Option<String> {
+        self.get(key).map(|v| v.to_string())
+    }
+}
+
+/// `(명칭, 값)` 목록. 같은 명칭이 여러 개일 경우 첫 번째 값 사용
+impl<K, V> InterpolateContext for [(K, V)]
+where
+    K: Borrow<str>,
+    V: Display,
+{
+    fn lookup(&self, key: &str) -> Option<String> {
+        self.iter()
+            .find(|(k, _)| k.borrow() == key)
+            .map(|(_, v)| v.to_string())
+    }
+}
+
+/// `.`으로 중첩 항목 지정(e.g. `{user.name}`, `{items.0}`). 문자열은 따옴표 없이 출력하며 `null`은 값이
+/// 없는 것으로 처리
+#[cfg(feature = "json")]
+impl InterpolateContext for serde_json::Value {
+    fn lookup(&self, key: &str) -> Option<String> {
+        let mut current = self;
+
+        for name in key.split('.') {
+            current = match current {
+                serde_json::Value::Object(v) => v.get(name)?,
+                serde_json::Value::Array(v) => v.get(name.parse::<usize>().ok()?)?,
+                _ => return None,
+            };
+        }
+
+        match current {
+            serde_json::Value::Null => None,
+            serde_json::Value::String(v) => Some(v.clone()),
+            v => Some(v.to_string()),
+        }
+    }
+}
+
+/// 치환 값이 없는 명칭은 오류([MissingKey::Error])로 처리하여 문자열 치환
+///
+/// # Arguments
+///
+/// - `template` - 치환 대상 문자열
+/// - `context` - 치환 값 [InterpolateContext]
+///
+/// # Return
+///
+/// - 치환 결과 `Result<String, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 닫히지 않은 `{`, 짝이 없는 `}`, 빈 명칭 혹은 치환 값이 없는 명칭이 있을 경우
+///
+/// # Example
+///
+/// ```rust
+/// use std::collections::BTreeMap;
+/// use cliff3_util::string_util::interpolate::interpolate;
+///
+/// let values = BTreeMap::from([("id", 42)]);
+///
+/// assert_eq!("주문 번호: 42", interpolate("주문 번호: { id }", &values).unwrap());
+/// assert!(interpolate("{name}", &values).is_err());
+/// assert!(interpolate("{id", &values).is_err());
+/// ```
+pub fn interpolate<C>(template: &str, context: &C) -> Result<String, InvalidArgumentError>
+where
+    C: InterpolateContext + ?Sized,
+{
+    interpolate_with(template, context, MissingKey::Error)
+}
+
+/// 치환 값이 없는 명칭의 처리 방법을 지정하여 문자열 치환
+///
+/// # Arguments
+///
+/// - `template` - 치환 대상 문자열
+/// - `context` - 치환 값 [InterpolateContext]
+/// - `missing` - 치환 값이 없는 명칭의 처리 방법 [MissingKey]
+///
+/// # Return
+///
+/// - 치환 결과 `Result<String, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 닫히지 않은 `{`, 짝이 없는 `}` 혹은 빈 명칭이 있을 경우.
+///   [MissingKey::Error]일 경우 치환 값이 없는 명칭이 있을 경우
+///
+/// # Example
+///
+/// ```rust
+/// use std::collections::HashMap;
+/// use cliff3_util::string_util::interpolate::{interpolate_with, MissingKey};
+///
+/// let values = HashMap::from([("name", "홍길동")]);
+/// let template = "{name}님 {greeting}";
+///
+/// assert_eq!(
+///     "홍길동님 {greeting}",
+///     interpolate_with(template, &values, MissingKey::Keep).unwrap()
+/// );
+/// assert_eq!(
+///     "홍길동님 ",
+///     interpolate_with(template, &values, MissingKey::Empty).unwrap()
+/// );
+/// assert!(interpolate_with(template, &values, MissingKey::Error).is_err());
+/// ```
+pub fn interpolate_with<C>(
+    template: &str,
+    context: &C,
+    missing: MissingKey,
+) -> Result<String, InvalidArgumentError>
+where
+    C: InterpolateContext + ?Sized,
+{
+    let mut result = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(position) = rest.find(['{', '}']) {
+        result.push_str(&rest[..position]);
+
+        let tail = &rest[position..];
+
+        if tail.starts_with("{{") || tail.starts_with("}}") {
+            result.push_str(&tail[..1]);
+            rest = &tail[2..];
+
+            continue;
+        }
+
+        let offset = template.len() - tail.len();
+
+        if tail.starts_with('}') {
+            return Err(InvalidArgumentError::new(&format!(
+                "{}번째 위치에 짝이 없는 '}}'가 있습니다.",
+                offset
+            )));
+        }
+
+        let close = tail.find('}').ok_or_else(|| {
+            InvalidArgumentError::new(&format!("{}번째 위치의 '{{'가 닫히지 않았습니다.", offset))
+        })?;
+        let name = tail[1..close].trim();
+
+        if name.is_empty() || name.contains('{') {
+            return Err(InvalidArgumentError::new(&format!(
+                "{}번째 위치의 명칭이 올바르지 않습니다.",
+                offset
+            )));
+        }
+
+        match context.lookup(name) {
+            Some(v) => result.push_str(&v),
+            None => match missing {
+                MissingKey::Error => {
+                    return Err(InvalidArgumentError::new(&format!(
+                        "치환 값이 없습니다({}).",
+                        name
+                    )))
+                }
+                MissingKey::Keep => result.push_str(&tail[..=close]),
+                MissingKey::Empty => {}
+            },
+        }
+
+        rest = &tail[close + 1..];
+    }
+
+    result.push_str(rest);
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interpolate_test() {
+        let values: BTreeMap<String, String> = [("name", "홍길동"), ("count", "3"), ("empty", "")]
+            .into_iter()
+            .map(|(k, v)| (k.to_owned(), v.to_owned()))
+            .collect();
+
+        for (template, expected) in [
+            ("", ""),
+            ("plain text", "plain text"),
+            ("{name}", "홍길동"),
+            ("{ name }님", "홍길동님"),
+            ("{name}{count}{empty}", "홍길동3"),
+            ("{{name}}", "{name}"),
+            ("{{{name}}}", "{홍길동}"),
+            ("a }} b {{", "a } b {"),
+            ("json: {{\"count\": {count}}}", "json: {\"count\": 3}"),
+        ] {
+            assert_eq!(
+                expected,
+                interpolate(template, &values).unwrap(),
+                "{}",
+                template
+            );
+        }
+
+        for template in ["{name", "name}", "{}", "{ }", "{a{b}", "{missing}"] {
+            assert!(interpolate(template, &values).is_err(), "{}", template);
+        }
+
+        for (missing, expected) in [
+            (MissingKey::Keep, "홍길동: { missing } {x}"),
+            (MissingKey::Empty, "홍길동:  "),
+        ] {
+            assert_eq!(
+                expected,
+                interpolate_with("{name}: { missing } {x}", &values, missing).unwrap()
+            );
+        }
+
+        // 문법 오류는 처리 방법과 관계 없이 오류
+        assert!(interpolate_with("{name", &values, MissingKey::Keep).is_err());
+
+        let values = BTreeMap::from([("price", 1.5), ("count", 2.0)]);
+
+        assert_eq!(
+            "1.5 x 2",
+            interpolate("{price} x {count}", &values).unwrap()
+        );
+        assert_eq!(MissingKey::Error, MissingKey::default());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn interpolate_hash_map_test() {
+        let values = std::collections::HashMap::from([("name", "홍길동")]);
+
+        assert_eq!("홍길동님", interpolate("{name}님", &values).unwrap());
+        assert_eq!("홍길동님", interpolate("{name}님", &&values).unwrap());
+        assert!(interpolate("{missing}", &values).is_err());
+    }
+
+    #[test]
+    fn interpolate_pairs_test() {
+        let values = [("name", "홍길동"), ("count", "3"), ("name", "임꺽정")];
+
+        assert_eq!(
+            "홍길동님 3건",
+            interpolate("{name}님 {count}건", &values[..]).unwrap()
+        );
+        assert_eq!(
+            "{unknown} 3",
+            interpolate_with("{unknown} {count}", &values[..], MissingKey::Keep).unwrap()
+        );
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn interpolate_json_test() {
+        let values = serde_json::json!({
+            "user": { "name": "홍길동", "age": 30 },
+            "items": ["책", "펜"],
+            "vip": false,
+            "memo": null,
+        });
+
+        assert_eq!(
+            "홍길동(30) 책, 펜 false",
+            interpolate(
+                "{user.name}({user.age}) {items.0}, {items.1} {vip}",
+                &values
+            )
+            .unwrap()
+        );
+        assert_eq!(
+            "{\"age\":30,\"name\":\"홍길동\"}",
+            interpolate("{user}", &values).unwrap()
+        );
+
+        for key in ["memo", "user.email", "items.2", "items.x", "vip.value"] {
+            assert_eq!(
+                "",
+                interpolate_with(&format!("{{{}}}", key), &values, MissingKey::Empty).unwrap(),
+                "{}",
+                key
+            );
+        }
+    }
+}
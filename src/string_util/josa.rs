@@ -0,0 +1,334 @@
+//! 한글 조사(josa) 선택 관련 함수 모음
+//!
+//! 단어의 마지막 글자 받침 여부에 따라 `은/는`, `이/가`, `을/를`, `과/와`, `으로/로`를 선택한다. 숫자는 한자어
+//! 읽기(e.g. `3` 삼, `10` 십), 영문자는 발음을 기준으로 판단하며, 괄호 등 문장 부호는 건너뛰고 판단한다.
+//!
+//! ```rust
+//! use cliff3_util::string_util::josa::{attach_josa, Josa};
+//!
+//! assert_eq!("사과를", attach_josa("사과", Josa::EulReul));
+//! assert_eq!("서울로", attach_josa("서울", Josa::EuroRo));
+//! assert_eq!("3개월이", attach_josa("3개월", Josa::IGa));
+//! assert_eq!("10은", attach_josa("10", Josa::EunNeun));
+//! assert_eq!("API는", attach_josa("API", Josa::EunNeun));
+//! ```
+
+use alloc::string::String;
+use core::str::FromStr;
+
+use crate::error::InvalidArgumentError;
+
+/// 조사 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Josa {
+    /// 은/는
+    EunNeun,
+
+    /// 이/가
+    IGa,
+
+    /// 을/를
+    EulReul,
+
+    /// 과/와
+    GwaWa,
+
+    /// 으로/로(`ㄹ` 받침은 `로`)
+    EuroRo,
+}
+
+impl Josa {
+    /// 조사 형태 (받침이 있을 경우, 받침이 없을 경우, 판단할 수 없을 경우) 반환
+    pub fn forms(&self) -> (&'static str, &'static str, &'static str) {
+        match self {
+            Josa::EunNeun => ("은", "는", "은(는)"),
+            Josa::IGa => ("이", "가", "이(가)"),
+            Josa::EulReul => ("을", "를", "을(를)"),
+            Josa::GwaWa => ("과", "와", "과(와)"),
+            Josa::EuroRo => ("으로", "로", "(으)로"),
+        }
+    }
+
+    /// 지정된 단어에 해당하는 조사 형태 반환
+    ///
+    /// 받침 여부를 판단할 수 없는 경우(빈 문자열, 한글/숫자/영문자가 아닌 문자로 끝나는 경우 등) 두 형태를
+    /// 함께 표기한 형태(e.g. `은(는)`)를 반환한다.
+    ///
+    /// # Arguments
+    ///
+    /// - `word` - 조사를 붙일 단어
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cliff3_util::string_util::josa::Josa;
+    ///
+    /// assert_eq!("이", Josa::IGa.select("책"));
+    /// assert_eq!("가", Josa::IGa.select("나무"));
+    /// assert_eq!("이(가)", Josa::IGa.select("😀"));
+    /// ```
+    pub fn select(&self, word: &str) -> &'static str {
+        let (with_final, without_final, unknown) = self.forms();
+
+        match final_sound(word) {
+            FinalSound::Rieul if *self == Josa::EuroRo => without_final,
+            FinalSound::Rieul | FinalSound::Consonant => with_final,
+            FinalSound::None => without_final,
+            FinalSound::Unknown => unknown,
+        }
+    }
+}
+
+impl FromStr for Josa {
+    type Err = InvalidArgumentError;
+
+    /// `은/는`, `은(는)`, `은`, `는` 등 조사 표기를 [Josa]로 변환
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let found = [
+            Josa::EunNeun,
+            Josa::IGa,
+            Josa::EulReul,
+            Josa::GwaWa,
+            Josa::EuroRo,
+        ]
+        .into_iter()
+        .find(|v| {
+            let (with_final, without_final, unknown) = v.forms();
+
+            s == with_final
+                || s == without_final
+                || s == unknown
+                || s.split_once('/') == Some((with_final, without_final))
+                || s.split_once('/') == Some((without_final, with_final))
+        });
+
+        found.ok_or_else(|| InvalidArgumentError::new("지원하지 않는 조사 입니다."))
+    }
+}
+
+/// 마지막 글자의 받침 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FinalSound {
+    /// 받침 없음
+    None,
+
+    /// `ㄹ` 받침
+    Rieul,
+
+    /// `ㄹ` 이외의 받침
+    Consonant,
+
+    /// 판단 불가
+    Unknown,
+}
+
+/// 단어 마지막 글자의 받침 종류
+///
+/// 뒤쪽의 괄호 내용(e.g. `홍길동(교수)`의 `(교수)`)은 제외하며, 한글, 숫자 및 영문자가 아닌 뒤쪽 문자는
+/// 건너뛴다.
+fn final_sound(word: &str) -> FinalSound {
+    let mut word = word.trim_end();
+
+    while let Some(rest) = word.strip_suffix(')') {
+        match rest.rfind('(') {
+            Some(i) => word = rest[..i].trim_end(),
+            None => break,
+        }
+    }
+
+    let trimmed = word.trim_end_matches(|c: char| !(is_syllable(c) || c.is_ascii_alphanumeric()));
+
+    match trimmed.chars().last() {
+        Some(c) if is_syllable(c) => match (c as u32 - '가' as u32) % 28 {
+            0 => FinalSound::None,
+            8 => FinalSound::Rieul,
+            _ => FinalSound::Consonant,
+        },
+        Some(c) if c.is_ascii_digit() => number_final_sound(trimmed),
+        Some(_) => latin_final_sound(trimmed),
+        None => FinalSound::Unknown,
+    }
+}
+
+/// 한글 완성형 음절(`가` ~ `힣`) 여부
+#[inline]
+fn is_syllable(c: char) -> bool {
+    ('가'..='힣').contains(&c)
+}
+
+/// 숫자의 한자어 읽기 기준 받침 종류(e.g. `3` 삼, `20` 이십, `1000` 천, `10000` 만)
+fn number_final_sound(word: &str) -> FinalSound {
+    let digits: String = word
+        .chars()
+        .rev()
+        .take_while(|c| c.is_ascii_digit())
+        .collect();
+    let zeros = digits.chars().take_while(|c| *c == '0').count();
+
+    // 영
+    if zeros == digits.len() {
+        return FinalSound::Consonant;
+    }
+
+    match zeros {
+        // 일, 이, 삼, 사, 오, 육, 칠, 팔, 구
+        0 => match digits.chars().next() {
+            Some('1' | '7' | '8') => FinalSound::Rieul,
+            Some('3' | '6') => FinalSound::Consonant,
+            _ => FinalSound::None,
+        },
+        // 십, 백, 천
+        1..=3 => FinalSound::Consonant,
+        // 만, 억, 조, 경 단위로 끝나는 경우(e.g. `100000` 십만)
+        _ => match zeros / 4 {
+            1 | 2 | 4 => FinalSound::Consonant,
+            3 => FinalSound::None,
+            _ => FinalSound::Unknown,
+        },
+    }
+}
+
+/// 영문자의 발음 기준 받침 종류
+///
+/// 모두 대문자일 경우 약어로 보아 마지막 글자의 이름(e.g. `L` 엘, `M` 엠)으로, 그 외에는 단어의 끝 발음
+/// (e.g. `-m`, `-n`, `-ng` 및 모음 뒤의 `-k`, `-p`, `-t`는 받침, `-l`은 `ㄹ` 받침)으로 판단한다.
+fn latin_final_sound(word: &str) -> FinalSound {
+    let letters: String = word
+        .chars()
+        .rev()
+        .take_while(|c| c.is_ascii_alphabetic())
+        .collect();
+
+    if letters.chars().all(|c| c.is_ascii_uppercase()) {
+        return match letters.chars().next() {
+            Some('L' | 'R') => FinalSound::Rieul,
+            Some('M' | 'N') => FinalSound::Consonant,
+            Some(_) => FinalSound::None,
+            None => FinalSound::Unknown,
+        };
+    }
+
+    let letters = letters.to_ascii_lowercase();
+    let mut chars = letters.chars();
+
+    match (chars.next(), chars.next()) {
+        (Some('l'), _) => FinalSound::Rieul,
+        (Some('m' | 'n'), _) | (Some('g'), Some('n')) => FinalSound::Consonant,
+        // 모음 뒤의 -k, -p, -t(e.g. `Book` 북, `Chat` 챗, `Rust` 러스트)
+        (Some('k' | 'p' | 't'), Some('a' | 'e' | 'i' | 'o' | 'u')) => FinalSound::Consonant,
+        (Some(_), _) => FinalSound::None,
+        (None, _) => FinalSound::Unknown,
+    }
+}
+
+/// 단어에 받침 여부에 따른 조사를 붙여 반환([Josa::select] 참고)
+///
+/// # Arguments
+///
+/// - `word` - 조사를 붙일 단어
+/// - `josa` - 조사 종류 [Josa]
+///
+/// # Return
+///
+/// - 조사를 붙인 단어
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::josa::{attach_josa, Josa};
+///
+/// assert_eq!("홍길동은", attach_josa("홍길동", Josa::EunNeun));
+/// assert_eq!("철수와", attach_josa("철수", Josa::GwaWa));
+/// assert_eq!("부산으로", attach_josa("부산", Josa::EuroRo));
+/// assert_eq!("\"Rust\"를", attach_josa("\"Rust\"", Josa::EulReul));
+/// ```
+pub fn attach_josa(word: &str, josa: Josa) -> String {
+    let mut result = String::with_capacity(word.len() + 6);
+
+    result.push_str(word);
+    result.push_str(josa.select(word));
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hangul_test() {
+        assert_eq!("책을", attach_josa("책", Josa::EulReul));
+        assert_eq!("나무를", attach_josa("나무", Josa::EulReul));
+        assert_eq!("물로", attach_josa("물", Josa::EuroRo));
+        assert_eq!("집으로", attach_josa("집", Josa::EuroRo));
+        assert_eq!("바다로", attach_josa("바다", Josa::EuroRo));
+        assert_eq!("달과", attach_josa("달", Josa::GwaWa));
+        assert_eq!("홍길동(교수)은", attach_josa("홍길동(교수)", Josa::EunNeun));
+        assert_eq!("은(는)", Josa::EunNeun.select(""));
+        assert_eq!("(으)로", Josa::EuroRo.select("!!"));
+        // 한글 자모만 있는 경우
+        assert_eq!("이(가)", Josa::IGa.select("ㅋㅋ"));
+    }
+
+    #[test]
+    fn number_test() {
+        for (word, expected) in [
+            ("0", "으로"),
+            ("1", "로"),
+            ("2", "로"),
+            ("3", "으로"),
+            ("6", "으로"),
+            ("7", "로"),
+            ("9", "로"),
+            ("10", "으로"),
+            ("21", "로"),
+            ("100", "으로"),
+            ("1000", "으로"),
+            ("10000", "으로"),
+            ("100000", "으로"),
+            ("1000000000000", "로"),
+            ("10000000000000", "로"),
+            ("100000000", "으로"),
+            ("v1.2", "로"),
+        ] {
+            assert_eq!(expected, Josa::EuroRo.select(word), "{}", word);
+        }
+    }
+
+    #[test]
+    fn latin_test() {
+        for (word, expected) in [
+            ("API", "는"),
+            ("SQL", "은"),
+            ("HTML", "은"),
+            ("PDF", "는"),
+            ("CNN", "은"),
+            ("Rust", "는"),
+            ("Chat", "은"),
+            ("Python", "은"),
+            ("Java", "는"),
+            ("iPhone", "는"),
+            ("King", "은"),
+            ("Book", "은"),
+        ] {
+            assert_eq!(expected, Josa::EunNeun.select(word), "{}", word);
+        }
+
+        assert_eq!("Google로", attach_josa("Google", Josa::EuroRo));
+        assert_eq!("Excel로", attach_josa("Excel", Josa::EuroRo));
+        assert_eq!("URL로", attach_josa("URL", Josa::EuroRo));
+    }
+
+    #[test]
+    fn from_str_test() {
+        assert_eq!(Ok(Josa::EunNeun), "은/는".parse());
+        assert_eq!(Ok(Josa::EunNeun), "는/은".parse());
+        assert_eq!(Ok(Josa::IGa), "가".parse());
+        assert_eq!(Ok(Josa::EulReul), "을(를)".parse());
+        assert_eq!(Ok(Josa::EuroRo), "(으)로".parse());
+        assert_eq!(Ok(Josa::GwaWa), "과".parse());
+        assert!("의".parse::<Josa>().is_err());
+        assert!("은/가".parse::<Josa>().is_err());
+    }
+}
@@ -0,0 +1,795 @@
+//! 개인정보 마스킹 관련 함수 모음
+//!
+//! 로그 출력 등 개인정보가 노출되지 않아야 하는 경우 사용한다. 형식이 올바르지 않은 값은 일부 정보가
+//! 노출되지 않도록 전체를 마스킹한다.
+//!
+//! `derive` feature 활성화시 `#[derive(Maskable)]`을 이용하여 구조체 단위로 마스킹할 수 있다.
+//!
+//! ```rust
+//! # #[cfg(feature = "derive")]
+//! # {
+//! use cliff3_util::string_util::mask::Maskable;
+//!
+//! #[derive(Clone, Maskable)]
+//! struct Member {
+//!     id: u64,
+//!
+//!     #[mask(email)]
+//!     email: String,
+//!
+//!     #[mask(phone)]
+//!     phone: Option<String>,
+//!
+//!     #[mask(full)]
+//!     password: String,
+//! }
+//!
+//! let member = Member {
+//!     id: 1,
+//!     email: "hong@test.com".to_owned(),
+//!     phone: Some("010-1234-5678".to_owned()),
+//!     password: "secret".to_owned(),
+//! };
+//! let masked = member.masked();
+//!
+//! assert_eq!("ho**@test.com", masked.email);
+//! assert_eq!(Some("010-****-5678".to_owned()), masked.phone);
+//! assert_eq!("******", masked.password);
+//! assert_eq!(
+//!     r#"Member { id: 1, email: "ho**@test.com", phone: Some("010-****-5678"), password: "******" }"#,
+//!     format!("{:?}", member)
+//! );
+//! # }
+//! ```
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+#[cfg(feature = "derive")]
+pub use cliff3_util_derive::Maskable;
+
+/// 마스킹 처리용 기본 문자
+pub const DEFAULT_MASK_CHAR: char = '*';
+
+/// 마스킹 종류
+///
+/// # Link
+///
+/// - [MaskField]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum MaskKind {
+    /// 이메일([mask_email])
+    Email,
+
+    /// 전화번호([mask_phone])
+    Phone,
+
+    /// 전체([mask_full])
+    Full,
+
+    /// 주민등록번호([mask_rrn])
+    Rrn,
+
+    /// 카드번호([mask_card])
+    Card,
+
+    /// 이름([mask_name])
+    Name,
+}
+
+impl MaskKind {
+    /// 해당 종류의 마스킹 함수를 적용하여 반환
+    pub fn apply(&self, target: &str) -> String {
+        self.apply_with(target, &MaskOptions::default())
+    }
+
+    /// 해당 종류의 마스킹 함수를 지정된 [MaskOptions]로 적용하여 반환
+    pub fn apply_with(&self, target: &str, options: &MaskOptions) -> String {
+        match self {
+            MaskKind::Email => mask_email_with(target, options),
+            MaskKind::Phone => mask_phone_with(target, options),
+            MaskKind::Full => mask_full_with(target, options),
+            MaskKind::Rrn => mask_rrn_with(target, options),
+            MaskKind::Card => mask_card_with(target, options),
+            MaskKind::Name => mask_name_with(target, options),
+        }
+    }
+}
+
+/// 마스킹 가능한 구조체 정의 trait
+///
+/// 일반적으로 `#[derive(Maskable)]`을 이용하여 구현한다.
+pub trait Maskable {
+    /// 마스킹 대상 항목을 마스킹 처리한 복사본 반환
+    fn masked(&self) -> Self;
+}
+
+/// 마스킹 가능한 항목 정의 trait
+///
+/// `#[derive(Maskable)]`에서 `#[mask(..)]`이 지정된 항목에 사용되며 [String], [`Option<String>`]
+/// 및 [`Vec<String>`]에 대해 구현되어 있다.
+pub trait MaskField {
+    /// 지정된 [MaskKind]로 마스킹 처리한 값 반환
+    fn mask_field(&self, kind: MaskKind) -> Self;
+}
+
+impl MaskField for String {
+    fn mask_field(&self, kind: MaskKind) -> Self {
+        kind.apply(self)
+    }
+}
+
+impl<T: MaskField> MaskField for Option<T> {
+    fn mask_field(&self, kind: MaskKind) -> Self {
+        self.as_ref().map(|v| v.mask_field(kind))
+    }
+}
+
+impl<T: MaskField> MaskField for Vec<T> {
+    fn mask_field(&self, kind: MaskKind) -> Self {
+        self.iter().map(|v| v.mask_field(kind)).collect()
+    }
+}
+
+/// 마스킹 설정
+///
+/// 앞/뒤로 노출할 길이를 지정하지 않을 경우 각 마스킹 함수의 기본 규칙을 따른다. 전화번호, 주민등록번호 및
+/// 카드번호는 숫자 수, 그 외에는 문자 수 기준이며 노출할 길이의 합이 대상 길이 이상일 경우 마스킹하지 않는다.
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::mask::{mask_phone_with, MaskOptions};
+///
+/// let options = MaskOptions::new().with_mask_char('#').with_suffix(2);
+///
+/// assert_eq!("010-####-##78", mask_phone_with("010-1234-5678", &options));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MaskOptions {
+    mask_char: char,
+
+    prefix: Option<usize>,
+
+    suffix: Option<usize>,
+}
+
+impl Default for MaskOptions {
+    /// [DEFAULT_MASK_CHAR], 각 마스킹 함수의 기본 노출 길이
+    fn default() -> Self {
+        MaskOptions {
+            mask_char: DEFAULT_MASK_CHAR,
+            prefix: None,
+            suffix: None,
+        }
+    }
+}
+
+impl MaskOptions {
+    /// 기본 설정으로 생성
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 마스킹 문자 지정
+    pub fn with_mask_char(mut self, mask_char: char) -> Self {
+        self.mask_char = mask_char;
+
+        self
+    }
+
+    /// 앞에서부터 노출할 길이 지정
+    pub fn with_prefix(mut self, prefix: usize) -> Self {
+        self.prefix = Some(prefix);
+
+        self
+    }
+
+    /// 뒤에서부터 노출할 길이 지정
+    pub fn with_suffix(mut self, suffix: usize) -> Self {
+        self.suffix = Some(suffix);
+
+        self
+    }
+
+    /// 마스킹 문자
+    pub fn mask_char(&self) -> char {
+        self.mask_char
+    }
+
+    /// 앞에서부터 노출할 길이. 지정하지 않을 경우 `None`
+    pub fn prefix(&self) -> Option<usize> {
+        self.prefix
+    }
+
+    /// 뒤에서부터 노출할 길이. 지정하지 않을 경우 `None`
+    pub fn suffix(&self) -> Option<usize> {
+        self.suffix
+    }
+}
+
+/// `counted`에 해당하는 문자 중 앞 `prefix`개, 뒤 `suffix`개를 제외하고 마스킹. 그 외 문자는 유지한다.
+fn mask_between(
+    target: &str,
+    prefix: usize,
+    suffix: usize,
+    mask_char: char,
+    counted: impl Fn(char) -> bool,
+) -> String {
+    let total = target.chars().filter(|c| counted(*c)).count();
+    let suffix_start = total.saturating_sub(suffix);
+    let mut index = 0;
+
+    target
+        .chars()
+        .map(|c| {
+            if !counted(c) {
+                return c;
+            }
+
+            let masked = index >= prefix && index < suffix_start;
+
+            index += 1;
+
+            if masked {
+                mask_char
+            } else {
+                c
+            }
+        })
+        .collect()
+}
+
+/// 문자열 전체를 마스킹
+///
+/// 문자 수(byte 수가 아님)만큼 [DEFAULT_MASK_CHAR]로 대체한다.
+///
+/// # Arguments
+///
+/// - `target` - 마스킹 대상
+///
+/// # Return
+///
+/// - 마스킹 결과
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::mask::mask_full;
+///
+/// assert_eq!("***", mask_full("홍길동"));
+/// ```
+pub fn mask_full(target: &str) -> String {
+    mask_full_with(target, &MaskOptions::default())
+}
+
+/// 문자열을 지정된 [MaskOptions]로 마스킹([mask_full] 참고)
+///
+/// 노출할 길이를 지정하지 않을 경우 `0`으로 처리한다.
+///
+/// # Arguments
+///
+/// - `target` - 마스킹 대상
+/// - `options` - [MaskOptions]
+///
+/// # Return
+///
+/// - 마스킹 결과
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::mask::{mask_full_with, MaskOptions};
+///
+/// assert_eq!("ab####gh", mask_full_with("abcdefgh", &MaskOptions::new().with_mask_char('#').with_prefix(2).with_suffix(2)));
+/// ```
+pub fn mask_full_with(target: &str, options: &MaskOptions) -> String {
+    mask_between(
+        target,
+        options.prefix.unwrap_or(0),
+        options.suffix.unwrap_or(0),
+        options.mask_char,
+        |_| true,
+    )
+}
+
+/// 형식이 올바르지 않은 값 전체를 지정된 마스킹 문자로 대체
+fn mask_invalid(target: &str, options: &MaskOptions) -> String {
+    target.chars().map(|_| options.mask_char).collect()
+}
+
+/// 이메일 주소 마스킹
+///
+/// `@` 앞 계정의 처음 2글자(계정이 2글자 이하일 경우 1글자)만 남기고 마스킹하며 domain은 유지한다.
+/// `@`가 없거나 계정이 빈 문자열일 경우 전체를 마스킹한다.
+///
+/// # Arguments
+///
+/// - `target` - 마스킹 대상 이메일 주소
+///
+/// # Return
+///
+/// - 마스킹 결과
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::mask::mask_email;
+///
+/// assert_eq!("ho**@test.com", mask_email("hong@test.com"));
+/// assert_eq!("a*@test.com", mask_email("ab@test.com"));
+/// assert_eq!("*******", mask_email("invalid"));
+/// ```
+pub fn mask_email(target: &str) -> String {
+    mask_email_with(target, &MaskOptions::default())
+}
+
+/// 이메일 주소를 지정된 [MaskOptions]로 마스킹([mask_email] 참고)
+///
+/// 노출할 길이는 `@` 앞 계정에 적용하며, 지정하지 않을 경우 [mask_email]의 규칙(뒤는 `0`)을 따른다.
+///
+/// # Arguments
+///
+/// - `target` - 마스킹 대상 이메일 주소
+/// - `options` - [MaskOptions]
+///
+/// # Return
+///
+/// - 마스킹 결과
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::mask::{mask_email_with, MaskOptions};
+///
+/// let options = MaskOptions::new().with_prefix(1).with_suffix(1);
+///
+/// assert_eq!("j********n@me.com", mask_email_with("joonho.son@me.com", &options));
+/// ```
+pub fn mask_email_with(target: &str, options: &MaskOptions) -> String {
+    let (local, domain) = match target.rsplit_once('@') {
+        Some((l, d)) if !l.is_empty() => (l, d),
+        _ => return mask_invalid(target, options),
+    };
+    let length = local.chars().count();
+    let prefix = options.prefix.unwrap_or(if length <= 2 { 1 } else { 2 });
+    let mut result = mask_between(
+        local,
+        prefix,
+        options.suffix.unwrap_or(0),
+        options.mask_char,
+        |_| true,
+    );
+
+    result.push('@');
+    result.push_str(domain);
+
+    result
+}
+
+/// 전화번호 마스킹
+///
+/// `-`, 공백 등으로 구분된 경우 첫 번째와 마지막 숫자 묶음을 제외한 나머지 숫자를 마스킹한다.
+/// 구분자가 없을 경우 지역번호(`02`는 2자리, 그 외 3자리)와 뒤 4자리를 제외한 숫자를 마스킹하며,
+/// 숫자가 8자리 미만일 경우 뒤 4자리를 제외하고 모두 마스킹한다. 숫자가 아닌 문자는 유지한다.
+///
+/// # Arguments
+///
+/// - `target` - 마스킹 대상 전화번호
+///
+/// # Return
+///
+/// - 마스킹 결과
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::mask::mask_phone;
+///
+/// assert_eq!("010-****-5678", mask_phone("010-1234-5678"));
+/// assert_eq!("02-***-4567", mask_phone("02-123-4567"));
+/// assert_eq!("010****5678", mask_phone("01012345678"));
+/// assert_eq!("02****5678", mask_phone("0212345678"));
+/// ```
+pub fn mask_phone(target: &str) -> String {
+    mask_phone_with(target, &MaskOptions::default())
+}
+
+/// 전화번호를 지정된 [MaskOptions]로 마스킹([mask_phone] 참고)
+///
+/// 노출할 길이는 숫자 수 기준이며, 지정하지 않을 경우 [mask_phone]의 규칙을 따른다.
+///
+/// # Arguments
+///
+/// - `target` - 마스킹 대상 전화번호
+/// - `options` - [MaskOptions]
+///
+/// # Return
+///
+/// - 마스킹 결과
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::mask::{mask_phone_with, MaskOptions};
+///
+/// assert_eq!("010-12##-####", mask_phone_with("010-1234-5678", &MaskOptions::new().with_mask_char('#').with_prefix(5).with_suffix(0)));
+/// ```
+pub fn mask_phone_with(target: &str, options: &MaskOptions) -> String {
+    let groups: Vec<&str> = target
+        .split(|c: char| !c.is_ascii_digit())
+        .filter(|v| !v.is_empty())
+        .collect();
+    let digits: usize = groups.iter().map(|v| v.len()).sum();
+    let (prefix, suffix) = match groups.as_slice() {
+        [first, .., last] if groups.len() >= 3 => (first.len(), last.len()),
+        _ if digits < 8 => (0, 4),
+        _ if target.trim_start().starts_with("02") => (2, 4),
+        _ => (3, 4),
+    };
+
+    mask_between(
+        target,
+        options.prefix.unwrap_or(prefix),
+        options.suffix.unwrap_or(suffix),
+        options.mask_char,
+        |c| c.is_ascii_digit(),
+    )
+}
+
+/// 주민등록번호(외국인등록번호) 마스킹
+///
+/// 생년월일과 성별 구분 숫자(앞 7자리)를 제외한 숫자를 마스킹한다(e.g. `900101-1******`). 숫자가 13자리가
+/// 아닐 경우 전체를 마스킹한다.
+///
+/// # Arguments
+///
+/// - `target` - 마스킹 대상 주민등록번호
+///
+/// # Return
+///
+/// - 마스킹 결과
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::mask::mask_rrn;
+///
+/// assert_eq!("900101-1******", mask_rrn("900101-1234567"));
+/// assert_eq!("9001011******", mask_rrn("9001011234567"));
+/// assert_eq!("******", mask_rrn("900101"));
+/// ```
+pub fn mask_rrn(target: &str) -> String {
+    mask_rrn_with(target, &MaskOptions::default())
+}
+
+/// 주민등록번호를 지정된 [MaskOptions]로 마스킹([mask_rrn] 참고)
+///
+/// 노출할 길이는 숫자 수 기준이며, 지정하지 않을 경우 앞 7자리, 뒤 0자리이다.
+///
+/// # Arguments
+///
+/// - `target` - 마스킹 대상 주민등록번호
+/// - `options` - [MaskOptions]
+///
+/// # Return
+///
+/// - 마스킹 결과
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::mask::{mask_rrn_with, MaskOptions};
+///
+/// assert_eq!("900101-XXXXXXX", mask_rrn_with("900101-1234567", &MaskOptions::new().with_mask_char('X').with_prefix(6)));
+/// ```
+pub fn mask_rrn_with(target: &str, options: &MaskOptions) -> String {
+    if target.chars().filter(|c| c.is_ascii_digit()).count() != 13 {
+        return mask_invalid(target, options);
+    }
+
+    mask_between(
+        target,
+        options.prefix.unwrap_or(7),
+        options.suffix.unwrap_or(0),
+        options.mask_char,
+        |c| c.is_ascii_digit(),
+    )
+}
+
+/// 카드번호 마스킹
+///
+/// 앞 6자리(발급사 식별 번호)와 뒤 4자리를 제외한 숫자를 마스킹한다(e.g. `1234-56**-****-3456`). 숫자가
+/// 12 ~ 19자리가 아닐 경우 전체를 마스킹한다.
+///
+/// # Arguments
+///
+/// - `target` - 마스킹 대상 카드번호
+///
+/// # Return
+///
+/// - 마스킹 결과
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::mask::mask_card;
+///
+/// assert_eq!("1234-56**-****-3456", mask_card("1234-5678-9012-3456"));
+/// assert_eq!("123456******3456", mask_card("1234567890123456"));
+/// assert_eq!("*********", mask_card("1234-5678"));
+/// ```
+pub fn mask_card(target: &str) -> String {
+    mask_card_with(target, &MaskOptions::default())
+}
+
+/// 카드번호를 지정된 [MaskOptions]로 마스킹([mask_card] 참고)
+///
+/// 노출할 길이는 숫자 수 기준이며, 지정하지 않을 경우 앞 6자리, 뒤 4자리이다.
+///
+/// # Arguments
+///
+/// - `target` - 마스킹 대상 카드번호
+/// - `options` - [MaskOptions]
+///
+/// # Return
+///
+/// - 마스킹 결과
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::mask::{mask_card_with, MaskOptions};
+///
+/// assert_eq!("****-****-****-3456", mask_card_with("1234-5678-9012-3456", &MaskOptions::new().with_prefix(0)));
+/// ```
+pub fn mask_card_with(target: &str, options: &MaskOptions) -> String {
+    let digits = target.chars().filter(|c| c.is_ascii_digit()).count();
+
+    if !(12..=19).contains(&digits) {
+        return mask_invalid(target, options);
+    }
+
+    mask_between(
+        target,
+        options.prefix.unwrap_or(6),
+        options.suffix.unwrap_or(4),
+        options.mask_char,
+        |c| c.is_ascii_digit(),
+    )
+}
+
+/// 이름 마스킹
+///
+/// 3글자 이상일 경우 처음과 마지막 글자, 2글자일 경우 처음 글자를 제외하고 마스킹하며 1글자일 경우 전체를
+/// 마스킹한다. 공백은 유지하며 글자 수에 포함하지 않는다.
+///
+/// # Arguments
+///
+/// - `target` - 마스킹 대상 이름
+///
+/// # Return
+///
+/// - 마스킹 결과
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::mask::mask_name;
+///
+/// assert_eq!("홍*동", mask_name("홍길동"));
+/// assert_eq!("남**수", mask_name("남궁민수"));
+/// assert_eq!("홍*", mask_name("홍길"));
+/// assert_eq!("J*** ****h", mask_name("John Smith"));
+/// ```
+pub fn mask_name(target: &str) -> String {
+    mask_name_with(target, &MaskOptions::default())
+}
+
+/// 이름을 지정된 [MaskOptions]로 마스킹([mask_name] 참고)
+///
+/// 노출할 길이는 공백을 제외한 글자 수 기준이며, 지정하지 않을 경우 [mask_name]의 규칙을 따른다.
+///
+/// # Arguments
+///
+/// - `target` - 마스킹 대상 이름
+/// - `options` - [MaskOptions]
+///
+/// # Return
+///
+/// - 마스킹 결과
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::mask::{mask_name_with, MaskOptions};
+///
+/// assert_eq!("홍○○", mask_name_with("홍길동", &MaskOptions::new().with_mask_char('○').with_suffix(0)));
+/// ```
+pub fn mask_name_with(target: &str, options: &MaskOptions) -> String {
+    let length = target.chars().filter(|c| !c.is_whitespace()).count();
+    let (prefix, suffix) = match length {
+        0 | 1 => (0, 0),
+        2 => (1, 0),
+        _ => (1, 1),
+    };
+
+    mask_between(
+        target,
+        options.prefix.unwrap_or(prefix),
+        options.suffix.unwrap_or(suffix),
+        options.mask_char,
+        |c| !c.is_whitespace(),
+    )
+}
+
+/// 대상 목록 전체를 지정된 [MaskKind]로 병렬 마스킹
+///
+/// `rayon` feature 활성화시 사용 가능하며 결과는 입력 순서를 유지한다.
+///
+/// # Arguments
+///
+/// - `targets` - 마스킹 대상 목록
+/// - `kind` - [MaskKind]
+///
+/// # Return
+///
+/// - 입력 순서와 동일한 마스킹 결과 목록
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::mask::{par_mask, MaskKind};
+///
+/// let result = par_mask(&["010-1234-5678", "02-123-4567"], MaskKind::Phone);
+///
+/// assert_eq!(vec!["010-****-5678", "02-***-4567"], result);
+/// ```
+#[cfg(feature = "rayon")]
+pub fn par_mask<S: AsRef<str> + Sync>(targets: &[S], kind: MaskKind) -> Vec<String> {
+    use rayon::prelude::*;
+
+    targets.par_iter().map(|v| kind.apply(v.as_ref())).collect()
+}
+
+/// [Maskable] 구현 구조체 목록 전체를 병렬 마스킹
+///
+/// `rayon` feature 활성화시 사용 가능하며 결과는 입력 순서를 유지한다.
+///
+/// # Arguments
+///
+/// - `items` - 마스킹 대상 목록
+///
+/// # Return
+///
+/// - 입력 순서와 동일한 [Maskable::masked] 결과 목록
+#[cfg(feature = "rayon")]
+pub fn par_masked<T: Maskable + Sync + Send>(items: &[T]) -> Vec<T> {
+    use rayon::prelude::*;
+
+    items.par_iter().map(Maskable::masked).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mask_email_test() {
+        assert_eq!("jo********@me.com", mask_email("joonho.son@me.com"));
+        assert_eq!("a@test.com", mask_email("a@test.com"));
+        assert_eq!("홍길*@한글.com", mask_email("홍길동@한글.com"));
+        assert_eq!("*********", mask_email("@test.com"));
+        assert_eq!("", mask_email(""));
+    }
+
+    #[test]
+    fn mask_phone_test() {
+        assert_eq!("010 **** 5678", mask_phone("010 1234 5678"));
+        assert_eq!("+82 **-****-5678", mask_phone("+82 10-1234-5678"));
+        assert_eq!("***-4567", mask_phone("123-4567"));
+        assert_eq!("1234", mask_phone("1234"));
+    }
+
+    #[test]
+    fn mask_rrn_card_test() {
+        assert_eq!("900101-5******", mask_rrn("900101-5234567"));
+        assert_eq!("**************", mask_rrn("900101-123456a"));
+        assert_eq!("", mask_rrn(""));
+        assert_eq!(
+            "9001**-*******",
+            mask_rrn_with("900101-1234567", &MaskOptions::new().with_prefix(4))
+        );
+
+        // 15자리(AMEX) 및 19자리
+        assert_eq!("3782-82****-*0005", mask_card("3782-822463-10005"));
+        assert_eq!("378282*****0005", mask_card("378282246310005"));
+        assert_eq!("620000*********0007", mask_card("6200000000000000007"));
+        assert_eq!("********************", mask_card("62000000000000000070"));
+        assert_eq!(
+            "1234 5678 9012 ####",
+            mask_card_with(
+                "1234 5678 9012 3456",
+                &MaskOptions::new()
+                    .with_mask_char('#')
+                    .with_prefix(12)
+                    .with_suffix(0)
+            )
+        );
+    }
+
+    #[test]
+    fn mask_name_test() {
+        assert_eq!("*", mask_name("홍"));
+        assert_eq!("", mask_name(""));
+        assert_eq!(
+            "제갈**량",
+            mask_name_with("제갈공명량", &MaskOptions::new().with_prefix(2))
+        );
+        // 노출 길이의 합이 길이 이상일 경우 마스킹하지 않음
+        assert_eq!(
+            "홍길동",
+            mask_name_with("홍길동", &MaskOptions::new().with_prefix(2).with_suffix(2))
+        );
+    }
+
+    #[test]
+    fn mask_options_test() {
+        let options = MaskOptions::new().with_mask_char('-');
+
+        assert_eq!('-', options.mask_char());
+        assert_eq!(None, options.prefix());
+        assert_eq!(Some(3), options.with_suffix(3).suffix());
+        assert_eq!("ho--@test.com", mask_email_with("hong@test.com", &options));
+        assert_eq!("-------", mask_email_with("invalid", &options));
+        assert_eq!("---", mask_full_with("abc", &options));
+        assert_eq!("홍-동", MaskKind::Name.apply_with("홍길동", &options));
+        assert_eq!("010-****-5678", MaskKind::Phone.apply("010-1234-5678"));
+        assert_eq!("900101-1******", MaskKind::Rrn.apply("900101-1234567"));
+    }
+
+    #[test]
+    fn mask_field_test() {
+        let target = Some("010-1234-5678".to_owned());
+
+        assert_eq!(
+            Some("010-****-5678".to_owned()),
+            target.mask_field(MaskKind::Phone)
+        );
+        assert_eq!(None, None::<String>.mask_field(MaskKind::Phone));
+        assert_eq!(
+            vec!["**".to_owned(), "***".to_owned()],
+            vec!["ab".to_owned(), "abc".to_owned()].mask_field(MaskKind::Full)
+        );
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_mask_test() {
+        let targets: Vec<String> = (0..1000).map(|i| format!("010-1234-{:04}", i)).collect();
+        let result = par_mask(&targets, MaskKind::Phone);
+
+        assert_eq!(1000, result.len());
+        assert_eq!("010-****-0000", result[0]);
+        assert_eq!("010-****-0999", result[999]);
+
+        #[derive(Debug, PartialEq)]
+        struct Member(String);
+
+        impl Maskable for Member {
+            fn masked(&self) -> Self {
+                Member(mask_full(&self.0))
+            }
+        }
+
+        let result = par_masked(&[Member("ab".to_owned()), Member("abc".to_owned())]);
+
+        assert_eq!(
+            vec![Member("**".to_owned()), Member("***".to_owned())],
+            result
+        );
+    }
+}
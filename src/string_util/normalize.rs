@@ -0,0 +1,190 @@
+//! Unicode 정규화(normalization) 관련 함수 모음
+//!
+//! macOS 파일 이름 등 NFD(자모 분리) 형식으로 전달된 한글은 화면에는 같은 문자로 보이지만 NFC(완성형)
+//! 문자열과 비교하면 다른 문자열이 된다. 비교 및 저장 전 같은 정규화 형식으로 변환하여 사용한다.
+//!
+//! ```rust
+//! use cliff3_util::string_util::normalize::{is_nfc, normalize_nfc};
+//!
+//! // "한글"의 NFD 형식(초성, 중성, 종성 분리)
+//! let nfd = "\u{1112}\u{1161}\u{11ab}\u{1100}\u{1173}\u{11af}";
+//!
+//! assert_ne!("한글", nfd);
+//! assert!(!is_nfc(nfd));
+//! assert_eq!("한글", normalize_nfc(nfd));
+//! ```
+
+use alloc::string::String;
+
+use unicode_normalization::UnicodeNormalization;
+
+/// NFC(Canonical Composition) 형식으로 변환
+///
+/// 분리된 자모 및 결합 문자를 완성형 문자로 조합한다.
+///
+/// # Arguments
+///
+/// - `target` - 변환 대상 문자열
+///
+/// # Return
+///
+/// - 변환 결과
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::normalize::normalize_nfc;
+///
+/// assert_eq!("가", normalize_nfc("\u{1100}\u{1161}"));
+/// assert_eq!("é", normalize_nfc("e\u{301}"));
+/// // 호환 문자는 변환하지 않음
+/// assert_eq!("ＡＢＣ", normalize_nfc("ＡＢＣ"));
+/// ```
+pub fn normalize_nfc(target: &str) -> String {
+    target.nfc().collect()
+}
+
+/// NFKC(Compatibility Composition) 형식으로 변환
+///
+/// NFC 변환과 함께 전각 문자(`Ａ`), 원 문자(`①`), 단위 기호(`㎏`) 등 호환 문자를 일반 문자로 변환한다.
+/// 변환 결과가 원래 의미와 다를 수 있으므로 검색, 비교 등에 사용한다.
+///
+/// # Arguments
+///
+/// - `target` - 변환 대상 문자열
+///
+/// # Return
+///
+/// - 변환 결과
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::normalize::normalize_nfkc;
+///
+/// assert_eq!("ABC123", normalize_nfkc("ＡＢＣ１２３"));
+/// assert_eq!("1kg", normalize_nfkc("①㎏"));
+/// assert_eq!("가", normalize_nfkc("\u{1100}\u{1161}"));
+/// ```
+pub fn normalize_nfkc(target: &str) -> String {
+    target.nfkc().collect()
+}
+
+/// NFC 형식 여부
+///
+/// # Arguments
+///
+/// - `target` - 검사 대상 문자열
+///
+/// # Return
+///
+/// - NFC 형식일 경우 `true`
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::normalize::is_nfc;
+///
+/// assert!(is_nfc("한글"));
+/// assert!(is_nfc(""));
+/// assert!(!is_nfc("\u{1112}\u{1161}\u{11ab}"));
+/// ```
+pub fn is_nfc(target: &str) -> bool {
+    unicode_normalization::is_nfc(target)
+}
+
+/// 비교용 문자열로 변환
+///
+/// NFKC 변환 후 소문자로 변환하며, 연속된 공백(전각 공백, 줄바꿈 포함)은 하나의 공백으로 변환하고 앞/뒤
+/// 공백을 제거한다. 소문자 변환은 [str::to_lowercase]를 사용하므로 독일어 `ß` 등 일부 문자는 Unicode case
+/// folding 결과와 다를 수 있다.
+///
+/// # Arguments
+///
+/// - `target` - 변환 대상 문자열
+///
+/// # Return
+///
+/// - 변환 결과
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::normalize::normalize_for_comparison;
+///
+/// // NFD 형식의 파일 이름과 입력 값 비교
+/// assert_eq!(
+///     normalize_for_comparison("  보고서\u{3000}ＦＩＮＡＬ.PDF "),
+///     normalize_for_comparison("\u{1107}\u{1169}\u{1100}\u{1169}\u{1109}\u{1165} final.pdf")
+/// );
+/// assert_eq!("보고서 final.pdf", normalize_for_comparison("보고서 Final.PDF"));
+/// ```
+pub fn normalize_for_comparison(target: &str) -> String {
+    // 소문자 변환 결과가 NFC 형식이 아닐 수 있으므로(e.g. `İ` -> `i̇`) 다시 NFC 변환
+    let lowercase = normalize_nfkc(target).to_lowercase();
+    let mut result = String::with_capacity(lowercase.len());
+
+    for word in lowercase.nfc().collect::<String>().split_whitespace() {
+        if !result.is_empty() {
+            result.push(' ');
+        }
+
+        result.push_str(word);
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_test() {
+        // 한글 NFD(현대 한글 자모) 및 호환 자모
+        let nfd = "\u{1112}\u{1161}\u{11ab}\u{1100}\u{1173}\u{11af} \u{1109}\u{1173}\u{1110}\u{1173}\u{1105}\u{1175}\u{11bc}";
+
+        assert_eq!("한글 스트링", normalize_nfc(nfd));
+        assert_eq!("한글 스트링", normalize_nfkc(nfd));
+        assert!(is_nfc(&normalize_nfc(nfd)));
+        assert!(!is_nfc(nfd));
+        assert_eq!("ㄱㅏ", normalize_nfc("ㄱㅏ"), "호환 자모는 조합하지 않음");
+
+        for (target, nfc, nfkc) in [
+            ("", "", ""),
+            ("abc", "abc", "abc"),
+            ("A\u{30a}", "Å", "Å"),
+            ("Å", "Å", "Å"),
+            ("ﬁ", "ﬁ", "fi"),
+            ("ＡＢＣ　１２３", "ＡＢＣ　１２３", "ABC 123"),
+            ("㈜", "㈜", "(주)"),
+            ("ｶﾞ", "ｶﾞ", "ガ"),
+        ] {
+            assert_eq!(nfc, normalize_nfc(target), "{}", target);
+            assert_eq!(nfkc, normalize_nfkc(target), "{}", target);
+            assert!(is_nfc(nfc), "{}", target);
+        }
+    }
+
+    #[test]
+    fn normalize_for_comparison_test() {
+        for (target, expected) in [
+            ("", ""),
+            ("   ", ""),
+            ("Hello  World", "hello world"),
+            ("\tＨｅｌｌｏ\u{3000}\n World ", "hello world"),
+            ("\u{1112}\u{1161}\u{11ab}\u{1100}\u{1173}\u{11af}", "한글"),
+            ("㈜ 홍길동", "(주) 홍길동"),
+            ("Ⅻ", "xii"),
+            ("㎆", "mb"),
+            ("İstanbul", "i\u{307}stanbul"),
+        ] {
+            assert_eq!(expected, normalize_for_comparison(target), "{}", target);
+        }
+
+        assert_eq!(
+            normalize_for_comparison("Cafe\u{301} LATTE"),
+            normalize_for_comparison("café latte")
+        );
+    }
+}
@@ -0,0 +1,1118 @@
+//! 숫자 표기 관련 함수 모음
+//!
+//! 숫자를 한글(한자어, 고유어)로 읽은 형태로 변환하거나 한글로 표기된 숫자를 다시 숫자로 변환한다. 또한
+//! 천 단위 구분자(e.g. `1,234,567.89`) 및 크기 단위(e.g. `117.7 MiB`)를 포함한 형태로 변환하거나 해당
+//! 형태의 문자열을 숫자로 변환한다.
+//!
+//! ```rust
+//! use cliff3_util::string_util::number::{amount_to_korean, korean_to_number, number_to_korean};
+//!
+//! assert_eq!("백이십삼만사천오백육십칠", number_to_korean(1234567));
+//! assert_eq!("일금 일백이십삼만사천오백육십칠원정", amount_to_korean(1234567));
+//! assert_eq!(1234567, korean_to_number("백이십삼만 사천오백육십칠").unwrap());
+//!
+//! use cliff3_util::string_util::number::{format_thousands, parse_thousands};
+//!
+//! assert_eq!("1,234,567.89", format_thousands(1234567.89));
+//! assert_eq!(Ok(1234567.89), parse_thousands::<f64>("1,234,567.89"));
+//!
+//! use cliff3_util::string_util::number::{humanize_bytes, parse_bytes};
+//!
+//! assert_eq!("117.7 MiB", humanize_bytes(123456789));
+//! assert_eq!(Ok(1_500_000_000), parse_bytes("1.5GB"));
+//! ```
+
+use alloc::string::String;
+use core::fmt::Display;
+use core::str::FromStr;
+
+use crate::error::InvalidArgumentError;
+
+/// 한자어 숫자(`0` ~ `9`)
+static SINO_DIGITS: &[char] = &['영', '일', '이', '삼', '사', '오', '육', '칠', '팔', '구'];
+
+/// 한자어 작은 단위(십, 백, 천)
+static SMALL_UNITS: &[(char, u64)] = &[('천', 1000), ('백', 100), ('십', 10)];
+
+/// 한자어 큰 단위(만, 억, 조, 경)
+static LARGE_UNITS: &[(char, u64)] = &[
+    ('경', 10_000_000_000_000_000),
+    ('조', 1_000_000_000_000),
+    ('억', 100_000_000),
+    ('만', 10_000),
+];
+
+/// 고유어 일의 자리(하나 ~ 아홉). (단독, 관형사형)
+static NATIVE_ONES: &[(&str, &str)] = &[
+    ("", ""),
+    ("하나", "한"),
+    ("둘", "두"),
+    ("셋", "세"),
+    ("넷", "네"),
+    ("다섯", "다섯"),
+    ("여섯", "여섯"),
+    ("일곱", "일곱"),
+    ("여덟", "여덟"),
+    ("아홉", "아홉"),
+];
+
+/// 고유어 십의 자리(열 ~ 아흔)
+static NATIVE_TENS: &[&str] = &[
+    "", "열", "스물", "서른", "마흔", "쉰", "예순", "일흔", "여든", "아흔",
+];
+
+/// 숫자를 한자어 읽기로 변환
+///
+/// `십`, `백`, `천` 및 `만` 앞의 `일`은 생략하며(e.g. `10000` 만), `억` 이상 단위 앞의 `일`은 표기한다
+/// (e.g. `100000000` 일억). 음수는 `마이너스 `를 앞에 붙인다.
+///
+/// # Arguments
+///
+/// - `n` - 변환 대상 숫자
+///
+/// # Return
+///
+/// - 변환 결과(e.g. `1234567` 백이십삼만사천오백육십칠, `0` 영)
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::number::number_to_korean;
+///
+/// assert_eq!("영", number_to_korean(0));
+/// assert_eq!("십일", number_to_korean(11));
+/// assert_eq!("만오백", number_to_korean(10500));
+/// assert_eq!("일억이천만", number_to_korean(120_000_000));
+/// assert_eq!("마이너스 삼십", number_to_korean(-30));
+/// ```
+pub fn number_to_korean(n: i64) -> String {
+    let mut result = String::new();
+
+    if n < 0 {
+        result.push_str("마이너스 ");
+    }
+
+    push_sino(&mut result, n.unsigned_abs(), false);
+
+    result
+}
+
+/// 금액을 계약서, 영수증 등에 사용하는 `일금 ... 원정` 형태로 변환
+///
+/// 위변조 방지를 위하여 모든 단위 앞의 `일`을 표기한다(e.g. `10000` 일만).
+///
+/// # Arguments
+///
+/// - `amount` - 금액
+///
+/// # Return
+///
+/// - 변환 결과(e.g. `1500000` 일금 일백오십만원정)
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::number::amount_to_korean;
+///
+/// assert_eq!("일금 일백오십만원정", amount_to_korean(1_500_000));
+/// assert_eq!("일금 일만일십원정", amount_to_korean(10_010));
+/// assert_eq!("일금 영원정", amount_to_korean(0));
+/// ```
+pub fn amount_to_korean(amount: u64) -> String {
+    let mut result = String::from("일금 ");
+
+    push_sino(&mut result, amount, true);
+    result.push_str("원정");
+
+    result
+}
+
+/// 숫자를 고유어 읽기로 변환
+///
+/// 고유어 읽기는 `1` ~ `99` 범위만 지원한다. 관형사형(`attributive`)은 단위 명사 앞에 사용하는 형태이다
+/// (e.g. `한 개`, `스무 살`).
+///
+/// # Arguments
+///
+/// - `n` - 변환 대상 숫자(`1` ~ `99`)
+/// - `attributive` - 관형사형 여부
+///
+/// # Return
+///
+/// - 변환 결과 `Option<String>`. 지원하지 않는 범위일 경우 `None`
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::number::number_to_native_korean;
+///
+/// assert_eq!(Some("스물셋".to_owned()), number_to_native_korean(23, false));
+/// assert_eq!(Some("스물세".to_owned()), number_to_native_korean(23, true));
+/// assert_eq!(Some("스무".to_owned()), number_to_native_korean(20, true));
+/// assert_eq!(None, number_to_native_korean(100, false));
+/// ```
+pub fn number_to_native_korean(n: u32, attributive: bool) -> Option<String> {
+    if !(1..=99).contains(&n) {
+        return None;
+    }
+
+    let (tens, ones) = ((n / 10) as usize, (n % 10) as usize);
+    let mut result = String::new();
+
+    if tens == 2 && ones == 0 && attributive {
+        result.push_str("스무");
+    } else {
+        result.push_str(NATIVE_TENS[tens]);
+    }
+
+    let (standalone, prenominal) = NATIVE_ONES[ones];
+
+    result.push_str(if attributive { prenominal } else { standalone });
+
+    Some(result)
+}
+
+/// 한자어로 표기된 숫자를 숫자로 변환
+///
+/// 공백, 앞쪽의 `마이너스`, `일금`, `금` 및 뒤쪽의 `원정`, `원`은 무시하며, 아라비아 숫자와 단위를 함께
+/// 사용한 형태(e.g. `3억 2500만`)도 변환할 수 있다. `1` 생략(e.g. `만`, `십`) 및 `일` 표기(e.g. `일만`,
+/// `일십`)를 모두 지원한다.
+///
+/// # Arguments
+///
+/// - `text` - 변환 대상 문자열
+///
+/// # Return
+///
+/// - 변환 결과 `Result<i64, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 빈 문자열, 지원하지 않는 문자, 단위 순서가 잘못된 경우(e.g. `만억`, `십백`)
+///   혹은 `i64` 범위를 벗어나는 경우
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::number::korean_to_number;
+///
+/// assert_eq!(10500, korean_to_number("만오백").unwrap());
+/// assert_eq!(325_000_000, korean_to_number("3억 2500만").unwrap());
+/// assert_eq!(1_500_000, korean_to_number("일금 일백오십만원정").unwrap());
+/// assert_eq!(-30, korean_to_number("마이너스 삼십").unwrap());
+/// assert!(korean_to_number("삼만억").is_err());
+/// ```
+pub fn korean_to_number(text: &str) -> Result<i64, InvalidArgumentError> {
+    let mut target = text.trim();
+    let negative = match target.strip_prefix("마이너스") {
+        Some(rest) => {
+            target = rest;
+            true
+        }
+        None => false,
+    };
+
+    target = target.trim_start();
+    target = target
+        .strip_prefix("일금")
+        .or_else(|| target.strip_prefix("금"))
+        .unwrap_or(target);
+    target = target
+        .strip_suffix("원정")
+        .or_else(|| target.strip_suffix("원"))
+        .unwrap_or(target);
+
+    let invalid = || InvalidArgumentError::new("올바른 한글 숫자 형식이 아닙니다.");
+    let overflow = || InvalidArgumentError::new("숫자 범위를 벗어납니다.");
+    let mut total: u64 = 0;
+    // 큰 단위(만, 억 등) 미만의 값
+    let mut section: u64 = 0;
+    // 단위가 붙기 전의 숫자
+    let mut digit: Option<u64> = None;
+    let mut last_small = u64::MAX;
+    let mut last_large = u64::MAX;
+    let mut prev: Option<char> = None;
+
+    for c in target.chars().filter(|c| !c.is_whitespace()) {
+        if let Some(value) = c.to_digit(10) {
+            // 한자어 숫자 뒤의 아라비아 숫자(e.g. `삼3`)
+            if digit.is_some() && !prev.is_some_and(|v| v.is_ascii_digit()) {
+                return Err(invalid());
+            }
+
+            let current = digit.unwrap_or(0);
+
+            digit = Some(
+                current
+                    .checked_mul(10)
+                    .and_then(|v| v.checked_add(value as u64))
+                    .ok_or_else(overflow)?,
+            );
+        } else if let Some(value) = SINO_DIGITS.iter().position(|v| *v == c) {
+            if digit.is_some() {
+                return Err(invalid());
+            }
+
+            digit = Some(value as u64);
+        } else if let Some((_, unit)) = SMALL_UNITS.iter().find(|(v, _)| *v == c) {
+            if *unit >= last_small {
+                return Err(invalid());
+            }
+
+            section = digit
+                .take()
+                .unwrap_or(1)
+                .checked_mul(*unit)
+                .and_then(|v| v.checked_add(section))
+                .ok_or_else(overflow)?;
+            last_small = *unit;
+        } else if let Some((_, unit)) = LARGE_UNITS.iter().find(|(v, _)| *v == c) {
+            if *unit >= last_large {
+                return Err(invalid());
+            }
+
+            section = section
+                .checked_add(digit.take().unwrap_or(0))
+                .ok_or_else(overflow)?;
+            total = section
+                .max(1)
+                .checked_mul(*unit)
+                .and_then(|v| v.checked_add(total))
+                .ok_or_else(overflow)?;
+            section = 0;
+            last_small = u64::MAX;
+            last_large = *unit;
+        } else {
+            return Err(invalid());
+        }
+
+        prev = Some(c);
+    }
+
+    if prev.is_none() {
+        return Err(invalid());
+    }
+
+    let value = total
+        .checked_add(section)
+        .and_then(|v| v.checked_add(digit.unwrap_or(0)))
+        .ok_or_else(overflow)?;
+
+    if negative {
+        0i64.checked_sub_unsigned(value).ok_or_else(overflow)
+    } else {
+        i64::try_from(value).map_err(|_| overflow())
+    }
+}
+
+/// [format_thousands_with], [parse_thousands_with] 형식 설정
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ThousandsFormat {
+    separator: char,
+
+    decimal_point: char,
+
+    fraction_digits: Option<usize>,
+}
+
+impl Default for ThousandsFormat {
+    /// 천 단위 구분자 `,`, 소수점 `.`, 소수 자릿수 입력값 유지
+    fn default() -> Self {
+        ThousandsFormat {
+            separator: ',',
+            decimal_point: '.',
+            fraction_digits: None,
+        }
+    }
+}
+
+impl ThousandsFormat {
+    /// 기본 설정으로 생성
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 천 단위 구분자 지정(e.g. `.`, ` `, `'`)
+    pub fn with_separator(mut self, separator: char) -> Self {
+        self.separator = separator;
+
+        self
+    }
+
+    /// 소수점 지정(e.g. `,`)
+    pub fn with_decimal_point(mut self, decimal_point: char) -> Self {
+        self.decimal_point = decimal_point;
+
+        self
+    }
+
+    /// 소수 자릿수 지정([format_thousands_with]에서 사용, 정수에는 적용하지 않음). `None`일 경우 입력값의
+    /// 표기를 그대로 사용
+    pub fn with_fraction_digits(mut self, fraction_digits: Option<usize>) -> Self {
+        self.fraction_digits = fraction_digits;
+
+        self
+    }
+
+    /// 천 단위 구분자
+    pub fn separator(&self) -> char {
+        self.separator
+    }
+
+    /// 소수점
+    pub fn decimal_point(&self) -> char {
+        self.decimal_point
+    }
+
+    /// 소수 자릿수
+    pub fn fraction_digits(&self) -> Option<usize> {
+        self.fraction_digits
+    }
+}
+
+/// 숫자에 천 단위 구분자(`,`)를 추가하여 반환
+///
+/// # Arguments
+///
+/// - `n` - 대상 숫자(정수 혹은 실수)
+///
+/// # Return
+///
+/// - 변환 결과(e.g. `1234567.89` 1,234,567.89)
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::number::format_thousands;
+///
+/// assert_eq!("1,234,567", format_thousands(1234567));
+/// assert_eq!("-1,234.5", format_thousands(-1234.5));
+/// assert_eq!("255", format_thousands(255u8));
+/// ```
+pub fn format_thousands<N: Display>(n: N) -> String {
+    format_thousands_with(n, &ThousandsFormat::default())
+}
+
+/// 숫자를 지정된 형식으로 천 단위 구분자를 추가하여 반환([ThousandsFormat] 참고)
+///
+/// `NaN`, `inf` 등 숫자 형태로 표기되지 않는 값은 그대로 반환한다.
+///
+/// # Arguments
+///
+/// - `n` - 대상 숫자(정수 혹은 실수)
+/// - `format` - 형식 설정
+///
+/// # Return
+///
+/// - 변환 결과
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::number::{format_thousands_with, ThousandsFormat};
+///
+/// let format = ThousandsFormat::new()
+///     .with_separator('.')
+///     .with_decimal_point(',')
+///     .with_fraction_digits(Some(2));
+///
+/// assert_eq!("1.234.567,89", format_thousands_with(1234567.891, &format));
+/// assert_eq!("1.000,00", format_thousands_with(1000.0, &format));
+/// ```
+pub fn format_thousands_with<N: Display>(n: N, format: &ThousandsFormat) -> String {
+    let plain = match format.fraction_digits {
+        Some(digits) => alloc::format!("{:.*}", digits, n),
+        None => alloc::format!("{}", n),
+    };
+    let (sign, unsigned) = match plain.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", plain.as_str()),
+    };
+    let (integer, fraction) = match unsigned.split_once('.') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (unsigned, None),
+    };
+    let is_digits = |v: &str| !v.is_empty() && v.bytes().all(|b| b.is_ascii_digit());
+
+    if !is_digits(integer) || !fraction.is_none_or(is_digits) {
+        return plain;
+    }
+
+    let mut result = String::with_capacity(plain.len() + integer.len() / 3 + 1);
+
+    result.push_str(sign);
+
+    for (i, c) in integer.chars().enumerate() {
+        if i > 0 && (integer.len() - i) % 3 == 0 {
+            result.push(format.separator);
+        }
+
+        result.push(c);
+    }
+
+    if let Some(fraction) = fraction {
+        result.push(format.decimal_point);
+        result.push_str(fraction);
+    }
+
+    result
+}
+
+/// 천 단위 구분자(`,`)가 포함된 문자열을 숫자로 변환
+///
+/// # Arguments
+///
+/// - `text` - 변환 대상 문자열(e.g. `1,234,567.89`)
+///
+/// # Return
+///
+/// - 변환 결과 `Result<T, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 형식이 올바르지 않은 경우([parse_thousands_with] 참고)
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::number::parse_thousands;
+///
+/// assert_eq!(Ok(1234567.89), parse_thousands::<f64>("1,234,567.89"));
+/// assert_eq!(Ok(-1234), parse_thousands::<i64>("-1,234"));
+/// assert_eq!(Ok(1234), parse_thousands::<u32>("1234"));
+/// assert!(parse_thousands::<i64>("12,34").is_err());
+/// assert!(parse_thousands::<u8>("1,000").is_err());
+/// ```
+pub fn parse_thousands<T: FromStr>(text: &str) -> Result<T, InvalidArgumentError> {
+    parse_thousands_with(text, &ThousandsFormat::default())
+}
+
+/// 지정된 형식의 천 단위 구분자가 포함된 문자열을 숫자로 변환([ThousandsFormat] 참고)
+///
+/// 앞뒤 공백은 무시하며, 구분자를 사용하지 않거나 모든 자리에 올바르게 사용한 경우(첫 묶음 1 ~ 3자리, 이후
+/// 3자리)만 허용한다. 소수 부분에는 구분자를 사용할 수 없다.
+///
+/// # Arguments
+///
+/// - `text` - 변환 대상 문자열
+/// - `format` - 형식 설정. 소수 자릿수 설정은 사용하지 않는다.
+///
+/// # Return
+///
+/// - 변환 결과 `Result<T, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 구분자 위치가 잘못된 경우(e.g. `1,23`, `1,,234`, `,123`), 숫자가 아닌 문자가
+///   포함된 경우, 천 단위 구분자와 소수점이 같은 경우 혹은 대상 타입으로 변환할 수 없는 경우(e.g. 범위 초과,
+///   정수 타입에 소수 지정)
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::number::{parse_thousands_with, ThousandsFormat};
+///
+/// let format = ThousandsFormat::new().with_separator('.').with_decimal_point(',');
+///
+/// assert_eq!(Ok(1234567.5), parse_thousands_with::<f64>("1.234.567,5", &format));
+/// assert!(parse_thousands_with::<f64>("1,234,567.5", &format).is_err());
+/// ```
+pub fn parse_thousands_with<T: FromStr>(
+    text: &str,
+    format: &ThousandsFormat,
+) -> Result<T, InvalidArgumentError> {
+    let invalid = || InvalidArgumentError::new("올바른 숫자 형식이 아닙니다.");
+
+    if format.separator == format.decimal_point {
+        return Err(InvalidArgumentError::new(
+            "천 단위 구분자와 소수점은 같을 수 없습니다.",
+        ));
+    }
+
+    let target = text.trim();
+    let (sign, unsigned) = match target.strip_prefix(['-', '+']) {
+        Some(rest) => (&target[..1], rest),
+        None => ("", target),
+    };
+    let (integer, fraction) = match unsigned.split_once(format.decimal_point) {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (unsigned, None),
+    };
+    let is_digits = |v: &str| !v.is_empty() && v.bytes().all(|b| b.is_ascii_digit());
+
+    if !fraction.is_none_or(is_digits) {
+        return Err(invalid());
+    }
+
+    let mut normalized = String::with_capacity(target.len());
+
+    normalized.push_str(sign);
+
+    for (i, group) in integer.split(format.separator).enumerate() {
+        let valid = match i {
+            0 => is_digits(group) && (group.len() <= 3 || !integer.contains(format.separator)),
+            _ => is_digits(group) && group.len() == 3,
+        };
+
+        if !valid {
+            return Err(invalid());
+        }
+
+        normalized.push_str(group);
+    }
+
+    if let Some(fraction) = fraction {
+        normalized.push('.');
+        normalized.push_str(fraction);
+    }
+
+    normalized.parse::<T>().map_err(|_| invalid())
+}
+
+/// 크기(byte) 표기 단위
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ByteUnit {
+    /// 1024 단위(`KiB`, `MiB`, `GiB` 등)
+    #[default]
+    Binary,
+
+    /// 1000 단위(`KB`, `MB`, `GB` 등)
+    Decimal,
+}
+
+impl ByteUnit {
+    /// 단위 크기(`1024` 혹은 `1000`)
+    pub fn base(&self) -> u64 {
+        match self {
+            ByteUnit::Binary => 1024,
+            ByteUnit::Decimal => 1000,
+        }
+    }
+
+    /// `B`를 제외한 단위 표기 목록
+    fn symbols(&self) -> &'static [&'static str] {
+        match self {
+            ByteUnit::Binary => &["KiB", "MiB", "GiB", "TiB", "PiB", "EiB"],
+            ByteUnit::Decimal => &["KB", "MB", "GB", "TB", "PB", "EB"],
+        }
+    }
+}
+
+/// 크기(byte)를 읽기 쉬운 형태(1024 단위)로 변환([humanize_bytes_with] 참고)
+///
+/// # Arguments
+///
+/// - `bytes` - 크기(byte)
+///
+/// # Return
+///
+/// - 변환 결과(e.g. `123456789` 117.7 MiB)
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::number::humanize_bytes;
+///
+/// assert_eq!("117.7 MiB", humanize_bytes(123456789));
+/// assert_eq!("512 B", humanize_bytes(512));
+/// assert_eq!("1.0 KiB", humanize_bytes(1024));
+/// ```
+pub fn humanize_bytes(bytes: u64) -> String {
+    humanize_bytes_with(bytes, ByteUnit::Binary)
+}
+
+/// 크기(byte)를 지정된 단위 기준의 읽기 쉬운 형태로 변환
+///
+/// 단위 크기 미만은 `B` 단위 정수로, 이상은 소수 첫째 자리까지 반올림하여 표기한다. 반올림 결과가 단위 크기
+/// 이상일 경우 다음 단위로 표기한다(e.g. `1048575` 1.0 MiB).
+///
+/// # Arguments
+///
+/// - `bytes` - 크기(byte)
+/// - `unit` - 표기 단위 [ByteUnit]
+///
+/// # Return
+///
+/// - 변환 결과
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::number::{humanize_bytes_with, ByteUnit};
+///
+/// assert_eq!("123.5 MB", humanize_bytes_with(123456789, ByteUnit::Decimal));
+/// assert_eq!("1.0 MiB", humanize_bytes_with(1048575, ByteUnit::Binary));
+/// assert_eq!("16.0 EiB", humanize_bytes_with(u64::MAX, ByteUnit::Binary));
+/// ```
+pub fn humanize_bytes_with(bytes: u64, unit: ByteUnit) -> String {
+    let base = unit.base() as f64;
+
+    if bytes < unit.base() {
+        return alloc::format!("{} B", bytes);
+    }
+
+    let symbols = unit.symbols();
+    let mut value = bytes as f64 / base;
+    let mut index = 0;
+
+    // 소수 첫째 자리 반올림 결과 기준(`no_std` 환경에서는 `f64::round` 사용 불가)
+    while value >= base - 0.05 && index + 1 < symbols.len() {
+        value /= base;
+        index += 1;
+    }
+
+    alloc::format!("{:.1} {}", value, symbols[index])
+}
+
+/// 읽기 쉬운 형태의 크기 문자열을 byte 단위로 변환
+///
+/// 숫자(정수 혹은 소수)와 단위 사이의 공백은 무시하며 단위는 대소문자를 구분하지 않는다. 단위는 다음과 같이
+/// 처리하며, 소수점 이하 byte는 반올림한다.
+///
+/// - 단위 없음, `B` - byte
+/// - `KB`, `MB`, `GB`, `TB`, `PB`, `EB` - 1000 단위
+/// - `KiB`, `MiB`, `GiB`, `TiB`, `PiB`, `EiB` 및 `K`, `M`, `G`, `T`, `P`, `E` - 1024 단위
+///
+/// # Arguments
+///
+/// - `text` - 변환 대상 문자열(e.g. `1.5GB`, `512 KiB`, `10M`)
+///
+/// # Return
+///
+/// - 변환 결과 `Result<u64, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 숫자 형식 혹은 단위가 올바르지 않거나(음수 포함) `u64` 범위를 벗어나는 경우
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::number::parse_bytes;
+///
+/// assert_eq!(Ok(1_500_000_000), parse_bytes("1.5GB"));
+/// assert_eq!(Ok(1_610_612_736), parse_bytes("1.5 GiB"));
+/// assert_eq!(Ok(10_485_760), parse_bytes("10M"));
+/// assert_eq!(Ok(512), parse_bytes("512"));
+/// assert!(parse_bytes("1.5 GX").is_err());
+/// ```
+pub fn parse_bytes(text: &str) -> Result<u64, InvalidArgumentError> {
+    let invalid = || InvalidArgumentError::new("올바른 크기 형식이 아닙니다.");
+    let overflow = || InvalidArgumentError::new("크기 범위를 벗어납니다.");
+    let target = text.trim();
+    let split = target
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(target.len());
+    let (number, symbol) = (&target[..split], target[split..].trim_start());
+    let (integer, fraction) = number.split_once('.').unwrap_or((number, ""));
+
+    if integer.is_empty()
+        || number.ends_with('.')
+        || !(integer.bytes().chain(fraction.bytes())).all(|b| b.is_ascii_digit())
+    {
+        return Err(invalid());
+    }
+
+    let symbol = symbol.to_ascii_lowercase();
+    let multiplier: u128 = match symbol.as_str() {
+        "" | "b" => 1,
+        _ => {
+            let (unit, position) = [ByteUnit::Decimal, ByteUnit::Binary]
+                .iter()
+                .find_map(|unit| {
+                    unit.symbols()
+                        .iter()
+                        .position(|v| v.eq_ignore_ascii_case(&symbol))
+                        .map(|position| (*unit, position))
+                })
+                .or_else(|| {
+                    ["k", "m", "g", "t", "p", "e"]
+                        .iter()
+                        .position(|v| *v == symbol)
+                        .map(|position| (ByteUnit::Binary, position))
+                })
+                .ok_or_else(invalid)?;
+
+            (unit.base() as u128).pow(position as u32 + 1)
+        }
+    };
+    // 소수점 이하는 최대 18자리까지 사용
+    let fraction = &fraction[..fraction.len().min(18)];
+    let scale = 10u128.pow(fraction.len() as u32);
+    let mut value: u128 = 0;
+
+    for b in integer.bytes().chain(fraction.bytes()) {
+        value = value
+            .checked_mul(10)
+            .and_then(|v| v.checked_add((b - b'0') as u128))
+            .ok_or_else(overflow)?;
+    }
+
+    let bytes = value
+        .checked_mul(multiplier)
+        .map(|v| (v + scale / 2) / scale)
+        .ok_or_else(overflow)?;
+
+    u64::try_from(bytes).map_err(|_| overflow())
+}
+
+/// 숫자의 한자어 읽기를 추가. `formal`이 `true`일 경우 모든 단위 앞의 `일`을 표기한다.
+fn push_sino(result: &mut String, n: u64, formal: bool) {
+    if n == 0 {
+        result.push(SINO_DIGITS[0]);
+
+        return;
+    }
+
+    let mut rest = n;
+
+    for (unit_char, unit) in LARGE_UNITS
+        .iter()
+        .copied()
+        .chain(core::iter::once((' ', 1)))
+    {
+        let section = rest / unit;
+
+        rest %= unit;
+
+        if section == 0 {
+            continue;
+        }
+
+        // `만` 앞의 `일`은 생략
+        if !(section == 1 && unit == 10_000 && !formal) {
+            push_section(result, section, formal);
+        }
+
+        if unit > 1 {
+            result.push(unit_char);
+        }
+    }
+}
+
+/// `1` ~ `9999` 범위의 한자어 읽기를 추가(`경` 단위는 u64 범위에서 최대 `1844`)
+fn push_section(result: &mut String, section: u64, formal: bool) {
+    let mut rest = section;
+
+    for (unit_char, unit) in SMALL_UNITS {
+        let value = rest / unit;
+
+        rest %= unit;
+
+        if value == 0 {
+            continue;
+        }
+
+        if value > 1 || formal {
+            result.push(SINO_DIGITS[value as usize]);
+        }
+
+        result.push(*unit_char);
+    }
+
+    if rest > 0 {
+        result.push(SINO_DIGITS[rest as usize]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn number_to_korean_test() {
+        for (n, expected) in [
+            (0, "영"),
+            (1, "일"),
+            (10, "십"),
+            (15, "십오"),
+            (100, "백"),
+            (1001, "천일"),
+            (10_000, "만"),
+            (11_000, "만천"),
+            (21_000, "이만천"),
+            (1_000_000, "백만"),
+            (100_010_001, "일억만일"),
+            (1_000_000_000_000, "일조"),
+            (
+                i64::MAX,
+                "구백이십이경삼천삼백칠십이조삼백육십팔억오천사백칠십칠만오천팔백칠",
+            ),
+            (
+                i64::MIN,
+                "마이너스 구백이십이경삼천삼백칠십이조삼백육십팔억오천사백칠십칠만오천팔백팔",
+            ),
+        ] {
+            assert_eq!(expected, number_to_korean(n), "{}", n);
+        }
+
+        assert_eq!("일금 일억원정", amount_to_korean(100_000_000));
+        assert_eq!("일금 일천일백일십일원정", amount_to_korean(1111));
+        assert_eq!(
+            "일금 일천팔백사십사경육천칠백사십사조칠백삼십칠억구백오십오만일천육백일십오원정",
+            amount_to_korean(u64::MAX)
+        );
+    }
+
+    #[test]
+    fn native_korean_test() {
+        for (n, standalone, attributive) in [
+            (1, "하나", "한"),
+            (4, "넷", "네"),
+            (10, "열", "열"),
+            (11, "열하나", "열한"),
+            (20, "스물", "스무"),
+            (21, "스물하나", "스물한"),
+            (99, "아흔아홉", "아흔아홉"),
+        ] {
+            assert_eq!(
+                Some(standalone.to_owned()),
+                number_to_native_korean(n, false)
+            );
+            assert_eq!(
+                Some(attributive.to_owned()),
+                number_to_native_korean(n, true)
+            );
+        }
+
+        assert_eq!(None, number_to_native_korean(0, false));
+        assert_eq!(None, number_to_native_korean(100, true));
+    }
+
+    #[test]
+    fn korean_to_number_test() {
+        for n in [
+            0,
+            1,
+            10,
+            19,
+            110,
+            10_000,
+            10_500,
+            100_010_001,
+            123_456_789,
+            i64::MAX,
+            i64::MIN,
+        ] {
+            assert_eq!(Ok(n), korean_to_number(&number_to_korean(n)), "{}", n);
+        }
+
+        for n in [0, 1_500_000, 10_010, u32::MAX as u64] {
+            assert_eq!(
+                Ok(n as i64),
+                korean_to_number(&amount_to_korean(n)),
+                "{}",
+                n
+            );
+        }
+
+        assert_eq!(Ok(10_000), korean_to_number("일만"));
+        assert_eq!(Ok(30_000), korean_to_number("3만원"));
+        assert_eq!(Ok(1_002_000), korean_to_number(" 백만 이천 "));
+        assert_eq!(Ok(12_345), korean_to_number("1만 2345"));
+
+        for invalid in [
+            "",
+            "   ",
+            "일이",
+            "십백",
+            "만억",
+            "만만",
+            "삼만x",
+            "원",
+            "마이너스",
+            "1000경",
+            "삼3",
+        ] {
+            assert!(korean_to_number(invalid).is_err(), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn format_thousands_test() {
+        assert_eq!("0", format_thousands(0));
+        assert_eq!("100", format_thousands(100));
+        assert_eq!("1,000", format_thousands(1000));
+        assert_eq!("-100,000", format_thousands(-100_000));
+        assert_eq!("9,223,372,036,854,775,807", format_thousands(i64::MAX));
+        assert_eq!("-9,223,372,036,854,775,808", format_thousands(i64::MIN));
+        assert_eq!("0.5", format_thousands(0.5));
+        assert_eq!("-0.5", format_thousands(-0.5));
+        assert_eq!("1,234,567.891", format_thousands(1234567.891));
+        assert_eq!("NaN", format_thousands(f64::NAN));
+        assert_eq!("-inf", format_thousands(f64::NEG_INFINITY));
+
+        let format = ThousandsFormat::new()
+            .with_separator(' ')
+            .with_fraction_digits(Some(1));
+
+        assert_eq!(' ', format.separator());
+        assert_eq!('.', format.decimal_point());
+        assert_eq!(Some(1), format.fraction_digits());
+        assert_eq!("1 234 567.9", format_thousands_with(1234567.89, &format));
+        // 정수는 소수 자릿수 미적용
+        assert_eq!("-1 000", format_thousands_with(-1000, &format));
+        assert_eq!(
+            "12,346",
+            format_thousands_with(
+                12345.6,
+                &ThousandsFormat::new().with_fraction_digits(Some(0))
+            )
+        );
+    }
+
+    #[test]
+    fn parse_thousands_test() {
+        assert_eq!(Ok(1234567), parse_thousands::<i64>("1,234,567"));
+        assert_eq!(Ok(1234567), parse_thousands::<i64>("1234567"));
+        assert_eq!(Ok(-1234567), parse_thousands::<i64>(" -1,234,567 "));
+        assert_eq!(Ok(1234), parse_thousands::<i64>("+1,234"));
+        assert_eq!(Ok(0), parse_thousands::<i64>("0"));
+        assert_eq!(Ok(1234567.89), parse_thousands::<f64>("1,234,567.89"));
+        assert_eq!(Ok(-0.25), parse_thousands::<f64>("-0.25"));
+        assert_eq!(Ok(999.0), parse_thousands::<f32>("999"));
+        assert_eq!(
+            Ok(i64::MIN),
+            parse_thousands::<i64>("-9,223,372,036,854,775,808")
+        );
+
+        for invalid in [
+            "",
+            " ",
+            "-",
+            ",",
+            "1,23",
+            "12,34,567",
+            "1,,234",
+            ",123",
+            "123,",
+            "1,2345",
+            "1.2.3",
+            ".5",
+            "5.",
+            "1,234.5,6",
+            "1 234",
+            "--1",
+            "1e3",
+            "abc",
+            "일,234",
+        ] {
+            assert!(parse_thousands::<f64>(invalid).is_err(), "{}", invalid);
+        }
+
+        // 대상 타입 범위 초과, 정수 타입에 소수 지정
+        assert!(parse_thousands::<u8>("1,000").is_err());
+        assert!(parse_thousands::<u32>("-1").is_err());
+        assert!(parse_thousands::<i64>("1,234.5").is_err());
+        assert!(parse_thousands::<i64>("9,223,372,036,854,775,808").is_err());
+
+        let format = ThousandsFormat::new()
+            .with_separator('.')
+            .with_decimal_point(',');
+
+        assert_eq!(
+            Ok(1234567.89),
+            parse_thousands_with::<f64>("1.234.567,89", &format)
+        );
+        assert!(parse_thousands_with::<f64>("1,234,567.89", &format).is_err());
+        assert!(parse_thousands_with::<i64>(
+            "1,234",
+            &ThousandsFormat::new().with_decimal_point(',')
+        )
+        .is_err());
+
+        // 형식 변환 후 다시 변환
+        for n in [0.0, 1.5, -1234.25, 1e15, -987654321.125] {
+            assert_eq!(Ok(n), parse_thousands::<f64>(&format_thousands(n)));
+            assert_eq!(
+                Ok(n),
+                parse_thousands_with::<f64>(&format_thousands_with(n, &format), &format)
+            );
+        }
+    }
+
+    #[test]
+    fn humanize_bytes_test() {
+        for (bytes, binary, decimal) in [
+            (0, "0 B", "0 B"),
+            (999, "999 B", "999 B"),
+            (1000, "1000 B", "1.0 KB"),
+            (1023, "1023 B", "1.0 KB"),
+            (1024, "1.0 KiB", "1.0 KB"),
+            (1536, "1.5 KiB", "1.5 KB"),
+            (999_949, "976.5 KiB", "999.9 KB"),
+            (999_950, "976.5 KiB", "1.0 MB"),
+            (1_048_575, "1.0 MiB", "1.0 MB"),
+            (123_456_789, "117.7 MiB", "123.5 MB"),
+            (1 << 40, "1.0 TiB", "1.1 TB"),
+            (u64::MAX, "16.0 EiB", "18.4 EB"),
+        ] {
+            assert_eq!(
+                binary,
+                humanize_bytes_with(bytes, ByteUnit::Binary),
+                "{}",
+                bytes
+            );
+            assert_eq!(
+                decimal,
+                humanize_bytes_with(bytes, ByteUnit::Decimal),
+                "{}",
+                bytes
+            );
+        }
+
+        assert_eq!(
+            humanize_bytes(4096),
+            humanize_bytes_with(4096, ByteUnit::default())
+        );
+    }
+
+    #[test]
+    fn parse_bytes_test() {
+        for (text, expected) in [
+            ("0", 0),
+            ("512", 512),
+            ("512B", 512),
+            ("1 kb", 1000),
+            ("1KiB", 1024),
+            ("1k", 1024),
+            ("1.5GB", 1_500_000_000),
+            ("1.5 GiB", 1_610_612_736),
+            (" 10 M ", 10_485_760),
+            ("0.5 B", 1),
+            ("0.4 B", 0),
+            ("1.0005 KB", 1001),
+            ("15.99 EiB", 18_435_214_858_663_483_146),
+            ("18446744073709551615", u64::MAX),
+        ] {
+            assert_eq!(Ok(expected), parse_bytes(text), "{}", text);
+        }
+
+        for invalid in [
+            "",
+            "B",
+            "-1",
+            "1.",
+            ".5",
+            "1.2.3",
+            "1 XB",
+            "1 KiBB",
+            "1 bytes",
+            "1e3",
+            "1,024",
+            "18446744073709551616",
+            "16 EiB",
+        ] {
+            assert!(parse_bytes(invalid).is_err(), "{}", invalid);
+        }
+
+        // 형식 변환 후 다시 변환(소수 첫째 자리 반올림 오차 범위)
+        for bytes in [1024, 123_456_789, 5 << 30] {
+            let parsed = parse_bytes(&humanize_bytes(bytes)).unwrap();
+
+            assert!(parsed.abs_diff(bytes) <= bytes / 200, "{}", bytes);
+        }
+    }
+}
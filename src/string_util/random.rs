@@ -0,0 +1,170 @@
+//! 문자 집합(charset) 지정 무작위 문자열 생성 관련 함수 모음
+//!
+//! 임시 비밀번호, 주문 번호, 초대 코드 등의 생성에 사용한다. 무작위 값은 암호학적으로 안전한 난수 생성기
+//! (CSPRNG, [rand::rngs::ThreadRng])를 사용하며 각 문자는 문자 집합에서 균등한 확률로 선택한다.
+//!
+//! ```rust
+//! use cliff3_util::string_util::random::{random_string, Charset};
+//!
+//! let code = random_string(8, Charset::Unambiguous).unwrap();
+//!
+//! assert_eq!(8, code.chars().count());
+//! assert!(code.chars().all(|c| !"0O1lI".contains(c)));
+//! ```
+
+use rand::seq::SliceRandom;
+
+use crate::error::InvalidArgumentError;
+
+/// 영문 대/소문자 및 숫자
+pub const ALPHANUMERIC: &str = "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+/// 16진수(소문자)
+pub const HEX: &str = "0123456789abcdef";
+
+/// URL에 그대로 사용할 수 있는 문자(RFC 4648 base64url)
+pub const URL_SAFE: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// 숫자
+pub const DIGITS: &str = "0123456789";
+
+/// 혼동하기 쉬운 문자(`0`, `O`, `o`, `1`, `l`, `I`)를 제외한 영문 대/소문자 및 숫자
+pub const UNAMBIGUOUS: &str = "23456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnpqrstuvwxyz";
+
+/// 무작위 문자열 생성에 사용할 문자 집합
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Charset {
+    /// [ALPHANUMERIC]
+    Alphanumeric,
+
+    /// [HEX]
+    Hex,
+
+    /// [URL_SAFE]
+    UrlSafe,
+
+    /// [DIGITS]
+    Digits,
+
+    /// [UNAMBIGUOUS]
+    Unambiguous,
+
+    /// 사용자 지정 문자 집합. 중복된 문자는 한 번만 사용한다.
+    Custom(String),
+}
+
+impl Charset {
+    /// 문자 집합의 문자 목록(중복 제외)
+    pub fn chars(&self) -> Vec<char> {
+        let source = match self {
+            Charset::Alphanumeric => ALPHANUMERIC,
+            Charset::Hex => HEX,
+            Charset::UrlSafe => URL_SAFE,
+            Charset::Digits => DIGITS,
+            Charset::Unambiguous => UNAMBIGUOUS,
+            Charset::Custom(v) => v.as_str(),
+        };
+        let mut chars: Vec<char> = Vec::with_capacity(source.len());
+
+        for c in source.chars() {
+            if !chars.contains(&c) {
+                chars.push(c);
+            }
+        }
+
+        chars
+    }
+}
+
+/// 지정된 문자 집합으로 무작위 문자열 생성
+///
+/// # Arguments
+///
+/// - `length` - 생성할 문자열 길이(문자 수)
+/// - `charset` - 문자 집합 [Charset]
+///
+/// # Return
+///
+/// - 생성 결과 `Result<String, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 사용자 지정 문자 집합이 빈 문자열일 경우
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::random::{random_string, Charset};
+///
+/// let order_number = random_string(12, Charset::Digits).unwrap();
+///
+/// assert_eq!(12, order_number.len());
+/// assert!(order_number.chars().all(|c| c.is_ascii_digit()));
+///
+/// let custom = random_string(6, Charset::Custom("가나다라".to_owned())).unwrap();
+///
+/// assert!(custom.chars().all(|c| "가나다라".contains(c)));
+/// assert!(random_string(6, Charset::Custom(String::new())).is_err());
+/// ```
+pub fn random_string(length: usize, charset: Charset) -> Result<String, InvalidArgumentError> {
+    let chars = charset.chars();
+
+    if chars.is_empty() {
+        return Err(InvalidArgumentError::new("문자 집합이 비어 있습니다."));
+    }
+
+    let mut random = rand::thread_rng();
+
+    Ok((0..length)
+        .filter_map(|_| chars.choose(&mut random))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn random_string_test() {
+        for (charset, source) in [
+            (Charset::Alphanumeric, ALPHANUMERIC),
+            (Charset::Hex, HEX),
+            (Charset::UrlSafe, URL_SAFE),
+            (Charset::Digits, DIGITS),
+            (Charset::Unambiguous, UNAMBIGUOUS),
+        ] {
+            let result = random_string(256, charset.clone()).unwrap();
+
+            assert_eq!(256, result.chars().count());
+            assert!(result.chars().all(|c| source.contains(c)), "{:?}", charset);
+            assert_eq!(source.len(), charset.chars().len());
+        }
+
+        assert_eq!("", random_string(0, Charset::Hex).unwrap());
+        assert_eq!(64, URL_SAFE.len());
+        assert!(UNAMBIGUOUS.chars().all(|c| !"0O1lIo".contains(c)));
+
+        // 사용자 지정 문자 집합(중복 제외)
+        let custom = Charset::Custom("ababab가".to_owned());
+
+        assert_eq!(vec!['a', 'b', '가'], custom.chars());
+        assert_eq!(
+            "aaaa",
+            random_string(4, Charset::Custom("a".to_owned())).unwrap()
+        );
+        assert!(random_string(4, Charset::Custom(String::new())).is_err());
+    }
+
+    #[test]
+    fn distribution_test() {
+        // 모든 문자가 선택되는지 확인
+        let result = random_string(4096, Charset::Hex).unwrap();
+
+        assert!(HEX.chars().all(|c| result.contains(c)));
+        assert_ne!(
+            random_string(32, Charset::Alphanumeric).unwrap(),
+            random_string(32, Charset::Alphanumeric).unwrap()
+        );
+    }
+}
@@ -0,0 +1,480 @@
+//! 한글 로마자 표기(국어의 로마자 표기법) 관련 함수 모음
+//!
+//! 문화체육관광부 고시 「국어의 로마자 표기법」을 기준으로 한글을 로마자로 변환한다. 음절 사이의 연음,
+//! 비음화, 유음화, 구개음화 및 `ㅎ` 격음화 등 기본적인 음운 변화를 반영하며, 된소리되기는 반영하지 않는다.
+//! 단어 사이(공백, 문장 부호 등)에서는 음운 변화를 적용하지 않으며 한글이 아닌 문자는 그대로 반환한다.
+//!
+//! 형태소 분석을 하지 않으므로 사잇소리 및 `ㄴ` 첨가(e.g. `학여울` Hangnyeoul)는 반영하지 않으며, 체언의
+//! `ㄱ, ㄷ, ㅂ` + `ㅎ`(e.g. `묵호` Mukho)은 [RomanizeOptions::with_noun]을 지정한 경우에만 반영한다.
+//!
+//! ```rust
+//! use cliff3_util::string_util::romanize::romanize;
+//!
+//! assert_eq!("hangeul", romanize("한글"));
+//! assert_eq!("jongno", romanize("종로"));
+//! assert_eq!("silla", romanize("신라"));
+//! assert_eq!("gachi", romanize("같이"));
+//! assert_eq!("seoul teukbyeolsi", romanize("서울 특별시"));
+//! ```
+//!
+//! # Link
+//!
+//! - [국어의 로마자 표기법](https://www.korean.go.kr/kornorms/regltn/regltnView.do?regltn_code=0004)
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// 초성 표기(`ㄱ` ~ `ㅎ`)
+static INITIALS: &[&str] = &[
+    "g", "kk", "n", "d", "tt", "r", "m", "b", "pp", "s", "ss", "", "j", "jj", "ch", "k", "t", "p",
+    "h",
+];
+
+/// 중성 표기(`ㅏ` ~ `ㅣ`)
+static VOWELS: &[&str] = &[
+    "a", "ae", "ya", "yae", "eo", "e", "yeo", "ye", "o", "wa", "wae", "oe", "yo", "u", "wo", "we",
+    "wi", "yu", "eu", "ui", "i",
+];
+
+/// 종성 대표음 표기(받침 없음, `ㄱ` ~ `ㅎ`)
+static FINALS: &[&str] = &[
+    "", "k", "k", "k", "n", "n", "n", "t", "l", "k", "m", "l", "l", "l", "p", "l", "m", "p", "p",
+    "t", "t", "ng", "t", "t", "k", "t", "p", "t",
+];
+
+/// 다음 음절 초성이 `ㅇ`일 경우(연음) 종성, 초성 표기
+static LIAISONS: &[(&str, &str)] = &[
+    ("", ""),
+    ("", "g"),
+    ("", "kk"),
+    ("k", "s"),
+    ("", "n"),
+    ("n", "j"),
+    ("", "n"),
+    ("", "d"),
+    ("", "r"),
+    ("l", "g"),
+    ("l", "m"),
+    ("l", "b"),
+    ("l", "s"),
+    ("l", "t"),
+    ("l", "p"),
+    ("", "r"),
+    ("", "m"),
+    ("", "b"),
+    ("p", "s"),
+    ("", "s"),
+    ("", "ss"),
+    ("ng", ""),
+    ("", "j"),
+    ("", "ch"),
+    ("", "k"),
+    ("", "t"),
+    ("", "p"),
+    ("", ""),
+];
+
+/// 두 글자 성씨(인명 표기에서 사용)
+static DOUBLE_SURNAMES: &[&str] = &[
+    "남궁", "황보", "제갈", "선우", "독고", "사공", "서문", "동방", "어금", "망절",
+];
+
+/// 초성 `ㅇ` 위치
+const INITIAL_IEUNG: usize = 11;
+
+/// 중성 `ㅣ` 위치
+const VOWEL_I: usize = 20;
+
+/// [romanize_with] 변환 설정
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RomanizeOptions {
+    name: bool,
+    noun: bool,
+}
+
+impl RomanizeOptions {
+    /// 기본 설정(일반 표기)으로 생성
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 인명 표기 여부 지정
+    ///
+    /// 인명 표기일 경우 각 단어의 첫 음절(두 글자 성씨일 경우 두 음절)을 성, 나머지를 이름으로 보아 띄어 쓰고
+    /// 첫 글자를 대문자로 표기한다. 이름에서 일어나는 음운 변화는 반영하지 않으며, 성씨의 관용 표기(e.g. `김`
+    /// Kim, `이` Lee)는 적용하지 않는다.
+    pub fn with_name(mut self, name: bool) -> Self {
+        self.name = name;
+
+        self
+    }
+
+    /// 인명 표기 여부
+    pub fn name(&self) -> bool {
+        self.name
+    }
+
+    /// 체언(명사) 표기 여부 지정
+    ///
+    /// 체언 표기일 경우 `ㄱ, ㄷ, ㅂ` 뒤의 `ㅎ`을 격음화하지 않고 밝혀 적는다(e.g. `묵호` Mukho, `집현전`
+    /// Jiphyeonjeon). 지명 등 체언만 변환할 경우 사용한다.
+    pub fn with_noun(mut self, noun: bool) -> Self {
+        self.noun = noun;
+
+        self
+    }
+
+    /// 체언(명사) 표기 여부
+    pub fn noun(&self) -> bool {
+        self.noun
+    }
+}
+
+/// 한글을 로마자로 변환(일반 표기)
+///
+/// 용언 기준으로 `ㄱ, ㄷ, ㅂ` + `ㅎ`을 격음화하여 표기하므로 체언은 [romanize_with]에
+/// [RomanizeOptions::with_noun]을 지정하여 변환한다. `ㄴ` 첨가(e.g. `학여울` Hangnyeoul, `알약` allyak)는
+/// 반영하지 않고 연음으로 표기한다(`hagyeoul`, `aryak`).
+///
+/// # Arguments
+///
+/// - `text` - 변환 대상 문자열
+///
+/// # Return
+///
+/// - 변환 결과(소문자)
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::romanize::romanize;
+///
+/// assert_eq!("baengma", romanize("백마"));
+/// assert_eq!("wangsimni", romanize("왕십리"));
+/// assert_eq!("joko", romanize("좋고"));
+/// assert_eq!("gangnam-gu 2024", romanize("강남-구 2024"));
+/// // `ㄴ` 첨가 미반영
+/// assert_eq!("hagyeoul", romanize("학여울"));
+/// ```
+pub fn romanize(text: &str) -> String {
+    romanize_with(text, &RomanizeOptions::default())
+}
+
+/// 한글을 지정된 설정으로 로마자로 변환([RomanizeOptions] 참고)
+///
+/// # Arguments
+///
+/// - `text` - 변환 대상 문자열
+/// - `options` - 변환 설정
+///
+/// # Return
+///
+/// - 변환 결과
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::romanize::{romanize_with, RomanizeOptions};
+///
+/// let options = RomanizeOptions::new().with_name(true);
+///
+/// assert_eq!("Hong Gildong", romanize_with("홍길동", &options));
+/// // 이름의 음운 변화는 반영하지 않음
+/// assert_eq!("Han Boknam", romanize_with("한복남", &options));
+/// assert_eq!("Namgung Min", romanize_with("남궁민", &options));
+///
+/// let options = RomanizeOptions::new().with_noun(true);
+///
+/// assert_eq!("mukho", romanize_with("묵호", &options));
+/// ```
+pub fn romanize_with(text: &str, options: &RomanizeOptions) -> String {
+    let mut result = String::with_capacity(text.len() * 2);
+    let mut word: Vec<(usize, usize, usize)> = Vec::new();
+
+    for c in text.chars() {
+        match split_syllable(c) {
+            Some(syllable) => word.push(syllable),
+            None => {
+                push_word(&mut result, &word, options);
+                word.clear();
+                result.push(c);
+            }
+        }
+    }
+
+    push_word(&mut result, &word, options);
+
+    result
+}
+
+/// 한글 음절의 (초성, 중성, 종성) 위치 반환
+#[inline]
+fn split_syllable(c: char) -> Option<(usize, usize, usize)> {
+    if !('가'..='힣').contains(&c) {
+        return None;
+    }
+
+    let offset = (c as u32 - '가' as u32) as usize;
+
+    Some((offset / 588, (offset % 588) / 28, offset % 28))
+}
+
+/// 한글 음절 목록(단어)을 로마자로 변환하여 추가
+fn push_word(result: &mut String, word: &[(usize, usize, usize)], options: &RomanizeOptions) {
+    if word.is_empty() {
+        return;
+    }
+
+    if !options.name {
+        push_syllables(result, word, options);
+
+        return;
+    }
+
+    let surname = if word.len() > 2 && is_double_surname(&word[..2]) {
+        2
+    } else {
+        1
+    };
+
+    push_syllables(result, &word[..surname], options);
+
+    if word.len() > surname {
+        result.push(' ');
+        push_syllables(result, &word[surname..], options);
+    }
+}
+
+/// 두 글자 성씨 여부
+fn is_double_surname(syllables: &[(usize, usize, usize)]) -> bool {
+    DOUBLE_SURNAMES.iter().any(|v| {
+        v.chars()
+            .map(split_syllable)
+            .eq(syllables.iter().map(|v| Some(*v)))
+    })
+}
+
+/// 음절 목록을 로마자로 변환하여 추가
+///
+/// 일반 표기일 경우 음절 사이의 음운 변화를 반영하며, 인명 표기일 경우 음운 변화 없이 첫 글자를 대문자로
+/// 표기한다.
+fn push_syllables(
+    result: &mut String,
+    syllables: &[(usize, usize, usize)],
+    options: &RomanizeOptions,
+) {
+    let start = result.len();
+    let mut initial = INITIALS[syllables[0].0];
+
+    for (i, (_, vowel, final_consonant)) in syllables.iter().enumerate() {
+        result.push_str(initial);
+        result.push_str(VOWELS[*vowel]);
+
+        match syllables.get(i + 1) {
+            Some((next_initial, next_vowel, _)) if !options.name => {
+                let (final_sound, next) =
+                    transition(*final_consonant, *next_initial, *next_vowel, options.noun);
+
+                result.push_str(final_sound);
+                initial = next;
+            }
+            Some((next_initial, _, _)) => {
+                result.push_str(FINALS[*final_consonant]);
+                initial = INITIALS[*next_initial];
+            }
+            None => result.push_str(FINALS[*final_consonant]),
+        }
+    }
+
+    if options.name {
+        result[start..start + 1].make_ascii_uppercase();
+    }
+}
+
+/// 앞 음절 종성과 다음 음절 초성 사이의 음운 변화를 반영한 (종성, 초성) 표기 반환
+///
+/// `noun`이 `true`일 경우 `ㄱ, ㄷ, ㅂ` 뒤의 `ㅎ`을 밝혀 적는다.
+fn transition(
+    final_consonant: usize,
+    next_initial: usize,
+    next_vowel: usize,
+    noun: bool,
+) -> (&'static str, &'static str) {
+    let sound = FINALS[final_consonant];
+    let initial = INITIALS[next_initial];
+
+    if final_consonant == 0 {
+        return (sound, initial);
+    }
+
+    // 연음 및 구개음화(e.g. `굳이` guji, `같이` gachi)
+    if next_initial == INITIAL_IEUNG {
+        return match (final_consonant, next_vowel) {
+            (7, VOWEL_I) => ("", "j"),
+            (13, VOWEL_I) => ("l", "ch"),
+            (25, VOWEL_I) => ("", "ch"),
+            _ => LIAISONS[final_consonant],
+        };
+    }
+
+    // `ㅎ`(ㄶ, ㅀ 포함) + `ㄱ, ㄷ, ㅈ` 격음화(e.g. `좋고` joko), `ㅎ` + `ㄴ`(e.g. `놓는` nonneun)
+    if matches!(final_consonant, 6 | 15 | 27) {
+        let rest = match final_consonant {
+            6 => "n",
+            15 => "l",
+            _ => "",
+        };
+
+        match (next_initial, rest) {
+            (0, _) => return (rest, "k"),
+            (3, _) => return (rest, "t"),
+            (12, _) => return (rest, "ch"),
+            (2, "l") => return ("l", "l"),
+            (2, _) => return ("n", "n"),
+            (9, _) => return (rest, "s"),
+            _ => {}
+        }
+    }
+
+    match (sound, next_initial) {
+        // 비음화(e.g. `백마` baengma, `닫는` danneun, `밥물` bammul)
+        ("k", 2 | 6) => ("ng", initial),
+        ("t", 2 | 6) => ("n", initial),
+        ("p", 2 | 6) => ("m", initial),
+        // 유음화(e.g. `신라` silla, `별내` byeollae, `물리` mulli)
+        ("n" | "l", 5) | ("l", 2) => ("l", "l"),
+        // `ㄹ`의 비음화(e.g. `종로` jongno, `백로` baengno, `협력` hyeomnyeok)
+        ("m" | "ng", 5) => (sound, "n"),
+        ("k", 5) => ("ng", "n"),
+        ("p", 5) => ("m", "n"),
+        ("t", 5) => ("n", "n"),
+        // 체언의 `ㄱ, ㄷ, ㅂ` + `ㅎ`(e.g. `묵호` mukho, `집현전` jiphyeonjeon)
+        ("k" | "t" | "p", 18) if noun => (sound, initial),
+        // `ㄱ, ㄷ, ㅂ, ㅈ` + `ㅎ` 격음화(e.g. `축하` chuka, `굳히다` guchida)
+        ("k", 18) => ("", "k"),
+        ("p", 18) => ("", "p"),
+        ("t", 18) if final_consonant == 7 && next_vowel == VOWEL_I => ("", "ch"),
+        ("t", 18) if final_consonant == 22 => ("", "ch"),
+        ("t", 18) => ("", "t"),
+        _ => (sound, initial),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn romanize_test() {
+        for (word, expected) in [
+            // 모음, 자음
+            ("가", "ga"),
+            ("의사", "uisa"),
+            ("왜관", "waegwan"),
+            ("구미", "gumi"),
+            ("옥천", "okcheon"),
+            ("합덕", "hapdeok"),
+            ("월곶", "wolgot"),
+            ("벚꽃", "beotkkot"),
+            ("한밭", "hanbat"),
+            ("구리", "guri"),
+            ("설악", "seorak"),
+            ("칠곡", "chilgok"),
+            ("임실", "imsil"),
+            ("울릉", "ulleung"),
+            ("대관령", "daegwallyeong"),
+            ("닭", "dak"),
+            ("여덟", "yeodeol"),
+            // 연음
+            ("강아지", "gangaji"),
+            ("닭이", "dalgi"),
+            ("없어", "eopseo"),
+            ("많아", "mana"),
+            ("싫어", "sireo"),
+            // 비음화, 유음화
+            ("난로", "nallo"),
+            ("별내", "byeollae"),
+            ("백로", "baengno"),
+            ("십리", "simni"),
+            ("협력", "hyeomnyeok"),
+            ("밥물", "bammul"),
+            // 구개음화, 격음화
+            ("해돋이", "haedoji"),
+            ("굳히다", "guchida"),
+            ("좋다", "jota"),
+            ("놓는", "nonneun"),
+            ("잡혀", "japyeo"),
+            ("맞히다", "machida"),
+            ("전화", "jeonhwa"),
+            // 된소리되기 미반영
+            ("압구정", "apgujeong"),
+            ("낙동강", "nakdonggang"),
+        ] {
+            assert_eq!(expected, romanize(word), "{}", word);
+        }
+
+        assert_eq!("", romanize(""));
+        assert_eq!("Seoul-si, 2024 ㅋㅋ", romanize("Seoul-si, 2024 ㅋㅋ"));
+        // 단어 사이에는 음운 변화 미적용
+        assert_eq!("bak mal", romanize("박 말"));
+        assert_eq!("jeju-do", romanize("제주-도"));
+        // `ㄴ` 첨가 미반영(연음으로 표기)
+        assert_eq!("hagyeoul", romanize("학여울"));
+        assert_eq!("aryak", romanize("알약"));
+    }
+
+    #[test]
+    fn noun_test() {
+        let options = RomanizeOptions::new().with_noun(true);
+
+        assert!(options.noun());
+        assert!(!RomanizeOptions::default().noun());
+
+        for (word, expected) in [
+            ("묵호", "mukho"),
+            ("집현전", "jiphyeonjeon"),
+            ("낙화", "nakhwa"),
+            // `ㅎ` 종성 및 그 외 음운 변화는 동일하게 반영
+            ("좋고", "joko"),
+            ("종로", "jongno"),
+            ("같이", "gachi"),
+        ] {
+            assert_eq!(expected, romanize_with(word, &options), "{}", word);
+        }
+
+        assert_eq!("muko", romanize("묵호"));
+        assert_eq!("jipyeonjeon", romanize("집현전"));
+        // 인명 표기와 함께 지정 가능
+        assert_eq!(
+            "Hong Gildong",
+            romanize_with("홍길동", &options.with_name(true))
+        );
+    }
+
+    #[test]
+    fn name_test() {
+        let options = RomanizeOptions::new().with_name(true);
+
+        assert!(options.name());
+        assert!(!RomanizeOptions::default().name());
+
+        for (name, expected) in [
+            ("홍길동", "Hong Gildong"),
+            ("김복남", "Gim Boknam"),
+            ("송나리", "Song Nari"),
+            ("정석", "Jeong Seok"),
+            ("이", "I"),
+            ("남궁민수", "Namgung Minsu"),
+            ("황보", "Hwang Bo"),
+            ("제갈공명", "Jegal Gongmyeong"),
+        ] {
+            assert_eq!(expected, romanize_with(name, &options), "{}", name);
+        }
+
+        assert_eq!(
+            "Hong Gildong, Gim Cheolsu",
+            romanize_with("홍길동, 김철수", &options)
+        );
+    }
+}
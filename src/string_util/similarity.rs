@@ -0,0 +1,378 @@
+//! 문자열 유사도(편집 거리) 관련 함수 모음
+//!
+//! Levenshtein 편집 거리 및 Jaro-Winkler 유사도를 제공하며, 모두 byte가 아닌 문자(`char`) 단위로 계산하므로
+//! 한글 등 다중 byte 문자도 한 글자로 계산한다. 고객 이름 등의 중복 확인에 사용한다.
+//!
+//! ```rust
+//! use cliff3_util::string_util::similarity::{jaro_winkler, levenshtein, similarity};
+//!
+//! assert_eq!(1, levenshtein("홍길동", "홍길순"));
+//! assert_eq!(3, levenshtein("kitten", "sitting"));
+//! assert!(similarity("홍길동", "홍길순") > 0.6);
+//! assert!(jaro_winkler("홍길동", "홍길순") > 0.8);
+//! ```
+
+use alloc::vec::Vec;
+
+/// 두 문자열의 Levenshtein 편집 거리(삽입, 삭제, 치환 횟수) 반환
+///
+/// # Arguments
+///
+/// - `a` - 비교 문자열
+/// - `b` - 비교 문자열
+///
+/// # Return
+///
+/// - 편집 거리
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::similarity::levenshtein;
+///
+/// assert_eq!(0, levenshtein("서울", "서울"));
+/// assert_eq!(2, levenshtein("서울특별시", "서울시"));
+/// assert_eq!(3, levenshtein("", "abc"));
+/// ```
+pub fn levenshtein(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    distance(&a, &b, usize::MAX).unwrap_or(usize::MAX)
+}
+
+/// 최대 편집 거리 이하일 경우 두 문자열의 Levenshtein 편집 거리 반환
+///
+/// 계산 중 편집 거리가 최대 편집 거리를 넘는 것이 확실한 경우 즉시 종료하므로, 많은 문자열 중 비슷한
+/// 문자열을 찾는 경우 [levenshtein]보다 빠르다.
+///
+/// # Arguments
+///
+/// - `a` - 비교 문자열
+/// - `b` - 비교 문자열
+/// - `max_distance` - 최대 편집 거리
+///
+/// # Return
+///
+/// - 편집 거리 `Option<usize>`. 최대 편집 거리를 넘을 경우 `None`
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::similarity::levenshtein_within;
+///
+/// assert_eq!(Some(1), levenshtein_within("홍길동", "홍길순", 1));
+/// assert_eq!(None, levenshtein_within("홍길동", "김철수", 2));
+/// ```
+pub fn levenshtein_within(a: &str, b: &str, max_distance: usize) -> Option<usize> {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    distance(&a, &b, max_distance)
+}
+
+/// 두 문자열의 유사도 반환
+///
+/// `1 - 편집 거리 / 긴 문자열의 문자 수`로 계산하며, 두 문자열이 모두 빈 문자열일 경우 `1.0`을 반환한다.
+///
+/// # Arguments
+///
+/// - `a` - 비교 문자열
+/// - `b` - 비교 문자열
+///
+/// # Return
+///
+/// - 유사도(`0.0` ~ `1.0`)
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::similarity::similarity;
+///
+/// assert_eq!(1.0, similarity("홍길동", "홍길동"));
+/// assert_eq!(0.75, similarity("홍길동전", "홍길동"));
+/// assert_eq!(0.0, similarity("abc", "xyz"));
+/// ```
+pub fn similarity(a: &str, b: &str) -> f64 {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let longest = a.len().max(b.len());
+
+    if longest == 0 {
+        return 1.0;
+    }
+
+    let distance = distance(&a, &b, usize::MAX).unwrap_or(longest);
+
+    1.0 - distance as f64 / longest as f64
+}
+
+/// 두 문자열의 Jaro 유사도 반환
+///
+/// 일정 범위(긴 문자열 문자 수의 절반 - 1) 내에서 일치하는 문자 수와 순서가 바뀐 문자 수로 계산한다. 두
+/// 문자열이 모두 빈 문자열일 경우 `1.0`, 하나만 빈 문자열일 경우 `0.0`을 반환한다.
+///
+/// # Arguments
+///
+/// - `a` - 비교 문자열
+/// - `b` - 비교 문자열
+///
+/// # Return
+///
+/// - 유사도(`0.0` ~ `1.0`)
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::similarity::jaro;
+///
+/// assert!((jaro("MARTHA", "MARHTA") - 0.944).abs() < 0.001);
+/// assert_eq!(0.0, jaro("abc", "xyz"));
+/// ```
+pub fn jaro(a: &str, b: &str) -> f64 {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    jaro_chars(&a, &b)
+}
+
+/// 두 문자열의 Jaro-Winkler 유사도 반환
+///
+/// [jaro] 유사도에 공통 접두사(최대 4자)의 가중치(`0.1`)를 더한다. 짧은 이름, ID 등 앞부분이 같을수록 같은
+/// 대상일 가능성이 높은 경우 [crate::string_util::similarity::similarity]보다 적합하다.
+///
+/// # Arguments
+///
+/// - `a` - 비교 문자열
+/// - `b` - 비교 문자열
+///
+/// # Return
+///
+/// - 유사도(`0.0` ~ `1.0`)
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::similarity::jaro_winkler;
+///
+/// assert!((jaro_winkler("MARTHA", "MARHTA") - 0.961).abs() < 0.001);
+/// assert!(jaro_winkler("홍길동", "홍길순") > jaro_winkler("홍길동", "김길동"));
+/// ```
+pub fn jaro_winkler(a: &str, b: &str) -> f64 {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let jaro = jaro_chars(&a, &b);
+    let prefix = a.iter().zip(&b).take(4).take_while(|(x, y)| x == y).count();
+
+    jaro + prefix as f64 * 0.1 * (1.0 - jaro)
+}
+
+/// Jaro 유사도 계산
+fn jaro_chars(a: &[char], b: &[char]) -> f64 {
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+
+    let window = (a.len().max(b.len()) / 2).saturating_sub(1);
+    let mut a_matched = alloc::vec![false; a.len()];
+    let mut b_matched = alloc::vec![false; b.len()];
+    let mut matches = 0usize;
+
+    for (i, ac) in a.iter().enumerate() {
+        let start = i.saturating_sub(window);
+        let end = (i + window + 1).min(b.len());
+
+        for j in start..end {
+            if !b_matched[j] && b[j] == *ac {
+                a_matched[i] = true;
+                b_matched[j] = true;
+                matches += 1;
+
+                break;
+            }
+        }
+    }
+
+    if matches == 0 {
+        return 0.0;
+    }
+
+    // 일치한 문자 중 순서가 다른 문자 수
+    let a_chars = a
+        .iter()
+        .zip(&a_matched)
+        .filter(|(_, m)| **m)
+        .map(|(c, _)| c);
+    let b_chars = b
+        .iter()
+        .zip(&b_matched)
+        .filter(|(_, m)| **m)
+        .map(|(c, _)| c);
+    let transpositions = a_chars.zip(b_chars).filter(|(x, y)| x != y).count() / 2;
+    let m = matches as f64;
+
+    (m / a.len() as f64 + m / b.len() as f64 + (m - transpositions as f64) / m) / 3.0
+}
+
+/// 편집 거리 계산. 최대 편집 거리를 넘을 경우 `None`
+fn distance(a: &[char], b: &[char], max_distance: usize) -> Option<usize> {
+    // 공통 앞/뒤 부분 제외
+    let prefix = a.iter().zip(b).take_while(|(x, y)| x == y).count();
+    let (a, b) = (&a[prefix..], &b[prefix..]);
+    let suffix = a
+        .iter()
+        .rev()
+        .zip(b.iter().rev())
+        .take_while(|(x, y)| x == y)
+        .count();
+    let (a, b) = (&a[..a.len() - suffix], &b[..b.len() - suffix]);
+    // 짧은 문자열 기준으로 계산
+    let (short, long) = if a.len() <= b.len() { (a, b) } else { (b, a) };
+
+    if long.len() - short.len() > max_distance {
+        return None;
+    }
+
+    if short.is_empty() {
+        return Some(long.len());
+    }
+
+    let mut previous: Vec<usize> = (0..=short.len()).collect();
+    let mut current: Vec<usize> = alloc::vec![0; short.len() + 1];
+
+    for (i, lc) in long.iter().enumerate() {
+        current[0] = i + 1;
+
+        let mut row_min = current[0];
+
+        for (j, sc) in short.iter().enumerate() {
+            let cost = usize::from(lc != sc);
+
+            current[j + 1] = (previous[j] + cost)
+                .min(previous[j + 1] + 1)
+                .min(current[j] + 1);
+            row_min = row_min.min(current[j + 1]);
+        }
+
+        // 이후 행의 값은 현재 행의 최소값보다 작아질 수 없음
+        if row_min > max_distance {
+            return None;
+        }
+
+        core::mem::swap(&mut previous, &mut current);
+    }
+
+    Some(previous[short.len()]).filter(|v| *v <= max_distance)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn levenshtein_test() {
+        for (a, b, expected) in [
+            ("", "", 0),
+            ("abc", "", 3),
+            ("", "abc", 3),
+            ("kitten", "sitting", 3),
+            ("flaw", "lawn", 2),
+            ("saturday", "sunday", 3),
+            ("abc", "abc", 0),
+            ("abc", "cba", 2),
+            ("홍길동", "홍길순", 1),
+            ("홍길동", "길동", 1),
+            ("김철수", "김 철수", 1),
+            ("대한민국", "민국", 2),
+            ("😀👍", "👍😀", 2),
+        ] {
+            assert_eq!(expected, levenshtein(a, b), "{} / {}", a, b);
+            assert_eq!(expected, levenshtein(b, a), "{} / {}", b, a);
+        }
+    }
+
+    #[test]
+    fn levenshtein_within_test() {
+        assert_eq!(Some(3), levenshtein_within("kitten", "sitting", 3));
+        assert_eq!(None, levenshtein_within("kitten", "sitting", 2));
+        assert_eq!(Some(0), levenshtein_within("", "", 0));
+        assert_eq!(None, levenshtein_within("a", "abcdef", 4));
+        assert_eq!(Some(0), levenshtein_within("홍길동", "홍길동", 0));
+        assert_eq!(None, levenshtein_within("홍길동", "홍길순", 0));
+
+        // levenshtein과 동일한 결과
+        let names = ["홍길동", "홍길순", "홍 길동", "김길동", "Hong Gildong", ""];
+
+        for a in names {
+            for b in names {
+                let expected = levenshtein(a, b);
+
+                for max in 0..12 {
+                    assert_eq!(
+                        Some(expected).filter(|v| *v <= max),
+                        levenshtein_within(a, b, max),
+                        "{} / {} / {}",
+                        a,
+                        b,
+                        max
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn similarity_test() {
+        assert_eq!(1.0, similarity("", ""));
+        assert_eq!(0.0, similarity("", "abc"));
+        assert_eq!(1.0, similarity("홍길동", "홍길동"));
+        assert!((similarity("kitten", "sitting") - (1.0 - 3.0 / 7.0)).abs() < f64::EPSILON);
+        assert!(similarity("홍길동", "홍길순") > similarity("홍길동", "김철수"));
+    }
+
+    #[test]
+    fn jaro_winkler_test() {
+        let close = |expected: f64, actual: f64| (expected - actual).abs() < 0.001;
+
+        for (a, b, jaro_expected, winkler_expected) in [
+            ("MARTHA", "MARHTA", 0.944, 0.961),
+            ("DWAYNE", "DUANE", 0.822, 0.840),
+            ("DIXON", "DICKSONX", 0.767, 0.813),
+            ("CRATE", "TRACE", 0.733, 0.733),
+            ("abc", "abc", 1.0, 1.0),
+            ("abc", "xyz", 0.0, 0.0),
+            ("", "", 1.0, 1.0),
+            ("", "abc", 0.0, 0.0),
+            ("a", "a", 1.0, 1.0),
+        ] {
+            assert!(
+                close(jaro_expected, jaro(a, b)),
+                "{} / {}: {}",
+                a,
+                b,
+                jaro(a, b)
+            );
+            assert!(close(jaro(a, b), jaro(b, a)), "{} / {}", a, b);
+            assert!(
+                close(winkler_expected, jaro_winkler(a, b)),
+                "{} / {}: {}",
+                a,
+                b,
+                jaro_winkler(a, b)
+            );
+        }
+
+        // 한글은 문자 단위로 계산(byte 단위일 경우 음절 일부가 일치하는 것으로 계산됨)
+        assert!(close(7.0 / 9.0, jaro("홍길동", "홍길순")));
+        assert!(close(
+            7.0 / 9.0 + 0.2 * (2.0 / 9.0),
+            jaro_winkler("홍길동", "홍길순")
+        ));
+        assert_eq!(0.0, jaro("가", "각"));
+        assert!(jaro_winkler("홍길동", "홍길순") > jaro_winkler("홍길동", "김길동"));
+    }
+}
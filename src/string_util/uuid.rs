@@ -0,0 +1,415 @@
+//! UUID(RFC 9562) 생성 및 형식 변환 관련 함수 모음
+//!
+//! 무작위 값 기반의 version 4([new_uuid_v4]) 및 시각 순서로 정렬되어 database 기본 키(index)에 적합한
+//! version 7([new_uuid_v7])을 생성한다. 무작위 값은 [rand::rngs::ThreadRng]를 사용한다.
+//!
+//! ```rust
+//! use cliff3_util::string_util::uuid::{new_uuid_v4, new_uuid_v7, parse_uuid, UuidFormat};
+//!
+//! let id = new_uuid_v4();
+//!
+//! assert_eq!(4, id.version());
+//! assert_eq!(36, id.to_string().len());
+//! assert_eq!(id, parse_uuid(&id.format(UuidFormat::Braced)).unwrap());
+//!
+//! // version 7은 생성 순서로 정렬
+//! let first = new_uuid_v7();
+//! let second = new_uuid_v7();
+//!
+//! assert!(first < second);
+//! assert!(first.to_string() < second.to_string());
+//! ```
+
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use rand::{Rng, RngCore};
+
+use crate::error::InvalidArgumentError;
+
+/// version 7 UUID의 마지막 생성 시각(ms) 및 순번(12 bit)
+static V7_STATE: Mutex<(u64, u16)> = Mutex::new((0, 0));
+
+/// UUID 문자열 형식
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum UuidFormat {
+    /// 구분자 없는 32자 (e.g. `67e5504410b1426f9247bb680e5fe0c8`)
+    Simple,
+
+    /// `-`로 구분된 36자 (e.g. `67e55044-10b1-426f-9247-bb680e5fe0c8`)
+    #[default]
+    Hyphenated,
+
+    /// `{}`로 감싼 38자 (e.g. `{67e55044-10b1-426f-9247-bb680e5fe0c8}`)
+    Braced,
+
+    /// `urn:uuid:` 접두사를 포함한 45자 (e.g. `urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8`)
+    Urn,
+}
+
+/// UUID(128 bit)
+///
+/// 바이트 배열 순서로 비교하므로 version 7 UUID는 생성 순서로 정렬된다. 문자열 변환([Display])은
+/// [UuidFormat::Hyphenated] 형식(소문자)을 사용한다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Uuid([u8; 16]);
+
+impl Uuid {
+    /// 모든 bit가 `0`인 nil UUID
+    pub const NIL: Uuid = Uuid([0; 16]);
+
+    /// 바이트 배열(big-endian)로 생성
+    #[inline]
+    pub const fn from_bytes(bytes: [u8; 16]) -> Self {
+        Uuid(bytes)
+    }
+
+    /// 바이트 배열(big-endian) 반환
+    #[inline]
+    pub const fn as_bytes(&self) -> &[u8; 16] {
+        &self.0
+    }
+
+    /// 정수(`u128`) 반환
+    #[inline]
+    pub const fn as_u128(&self) -> u128 {
+        u128::from_be_bytes(self.0)
+    }
+
+    /// version(4, 7 등) 반환
+    #[inline]
+    pub const fn version(&self) -> u8 {
+        self.0[6] >> 4
+    }
+
+    /// version 7 UUID의 생성 시각(Unix epoch 기준 ms) 반환. 다른 version일 경우 `None`
+    pub fn timestamp_millis(&self) -> Option<u64> {
+        if self.version() != 7 {
+            return None;
+        }
+
+        let mut bytes = [0u8; 8];
+
+        bytes[2..].copy_from_slice(&self.0[..6]);
+
+        Some(u64::from_be_bytes(bytes))
+    }
+
+    /// 지정된 형식의 문자열(소문자)로 변환
+    ///
+    /// # Arguments
+    ///
+    /// - `format` - 문자열 형식 [UuidFormat]
+    ///
+    /// # Return
+    ///
+    /// - 변환 결과
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cliff3_util::string_util::uuid::{Uuid, UuidFormat};
+    ///
+    /// let id = Uuid::from_bytes(*b"\x67\xe5\x50\x44\x10\xb1\x42\x6f\x92\x47\xbb\x68\x0e\x5f\xe0\xc8");
+    ///
+    /// assert_eq!("67e5504410b1426f9247bb680e5fe0c8", id.format(UuidFormat::Simple));
+    /// assert_eq!("{67e55044-10b1-426f-9247-bb680e5fe0c8}", id.format(UuidFormat::Braced));
+    /// assert_eq!("urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8", id.format(UuidFormat::Urn));
+    /// ```
+    pub fn format(&self, format: UuidFormat) -> String {
+        let hex: String = self.0.iter().map(|b| format!("{:02x}", b)).collect();
+
+        if format == UuidFormat::Simple {
+            return hex;
+        }
+
+        let hyphenated = format!(
+            "{}-{}-{}-{}-{}",
+            &hex[0..8],
+            &hex[8..12],
+            &hex[12..16],
+            &hex[16..20],
+            &hex[20..32]
+        );
+
+        match format {
+            UuidFormat::Braced => format!("{{{}}}", hyphenated),
+            UuidFormat::Urn => format!("urn:uuid:{}", hyphenated),
+            _ => hyphenated,
+        }
+    }
+}
+
+impl Display for Uuid {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.format(UuidFormat::Hyphenated))
+    }
+}
+
+impl FromStr for Uuid {
+    type Err = InvalidArgumentError;
+
+    /// [parse_uuid] 참고
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_uuid(s)
+    }
+}
+
+/// 무작위 값 기반의 version 4 UUID 생성
+///
+/// # Return
+///
+/// - 생성 결과 [Uuid]
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::uuid::new_uuid_v4;
+///
+/// let id = new_uuid_v4();
+///
+/// assert_eq!(4, id.version());
+/// assert_ne!(id, new_uuid_v4());
+/// ```
+pub fn new_uuid_v4() -> Uuid {
+    let mut bytes = [0u8; 16];
+
+    rand::thread_rng().fill_bytes(&mut bytes);
+
+    Uuid(with_version(bytes, 4))
+}
+
+/// 시각 순서로 정렬되는 version 7 UUID 생성
+///
+/// 앞 48 bit는 생성 시각(Unix epoch 기준 ms), 이후 12 bit는 같은 ms 내의 순번(시작 값은 무작위)이며 나머지는
+/// 무작위 값이다. 같은 process 내에서 생성한 UUID는 시스템 시각이 과거로 변경되더라도 항상 이전에 생성한
+/// UUID보다 크다.
+///
+/// # Return
+///
+/// - 생성 결과 [Uuid]
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::{SystemTime, UNIX_EPOCH};
+/// use cliff3_util::string_util::uuid::new_uuid_v7;
+///
+/// let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis() as u64;
+/// let ids: Vec<_> = (0..1000).map(|_| new_uuid_v7()).collect();
+///
+/// assert!(ids.windows(2).all(|v| v[0] < v[1]));
+/// assert!(ids[0].timestamp_millis().unwrap() >= now);
+/// ```
+pub fn new_uuid_v7() -> Uuid {
+    let mut random = rand::thread_rng();
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |v| v.as_millis() as u64);
+    let (millis, sequence) = {
+        let mut state = V7_STATE.lock().unwrap_or_else(|e| e.into_inner());
+        let (last, last_sequence) = *state;
+
+        *state = if now > last {
+            // 순번 증가 여유를 위해 시작 값은 하위 11 bit 범위
+            (now, random.gen_range(0..0x800))
+        } else if last_sequence < 0xfff {
+            (last, last_sequence + 1)
+        } else {
+            // 같은 ms 내의 순번을 모두 사용한 경우 다음 ms 사용
+            (last + 1, 0)
+        };
+
+        *state
+    };
+    let mut bytes = [0u8; 16];
+
+    random.fill_bytes(&mut bytes[8..]);
+    bytes[..6].copy_from_slice(&millis.to_be_bytes()[2..]);
+    bytes[6..8].copy_from_slice(&sequence.to_be_bytes());
+
+    Uuid(with_version(bytes, 7))
+}
+
+/// UUID 문자열 해석
+///
+/// 대소문자를 구분하지 않으며 [UuidFormat]의 모든 형식을 허용한다.
+///
+/// # Arguments
+///
+/// - `target` - UUID 문자열
+///
+/// # Return
+///
+/// - 해석 결과 `Result<Uuid, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 허용되지 않는 형식 혹은 16진수가 아닌 문자
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::uuid::parse_uuid;
+///
+/// let id = parse_uuid("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+///
+/// assert_eq!(id, parse_uuid("67E5504410B1426F9247BB680E5FE0C8").unwrap());
+/// assert_eq!(id, parse_uuid("{67e55044-10b1-426f-9247-bb680e5fe0c8}").unwrap());
+/// assert_eq!(id, parse_uuid("urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap());
+/// assert!(parse_uuid("67e55044-10b1-426f-9247-bb680e5fe0c").is_err());
+/// assert!(parse_uuid("67e5504410b1-426f-9247-bb680e5fe0c8").is_err());
+/// ```
+pub fn parse_uuid(target: &str) -> Result<Uuid, InvalidArgumentError> {
+    let invalid =
+        || InvalidArgumentError::new(&format!("UUID 형식이 올바르지 않습니다({}).", target));
+    let hyphenated = if let Some(v) = target.strip_prefix('{').and_then(|v| v.strip_suffix('}')) {
+        v
+    } else if target
+        .get(..9)
+        .is_some_and(|v| v.eq_ignore_ascii_case("urn:uuid:"))
+    {
+        &target[9..]
+    } else if target.len() == 32 {
+        return decode_hex(target).ok_or_else(invalid);
+    } else {
+        target
+    };
+    let groups: Vec<&str> = hyphenated.split('-').collect();
+
+    if groups.iter().map(|v| v.len()).ne([8, 4, 4, 4, 12]) {
+        return Err(invalid());
+    }
+
+    decode_hex(&groups.concat()).ok_or_else(invalid)
+}
+
+/// version 및 variant(RFC 9562) bit 지정
+fn with_version(mut bytes: [u8; 16], version: u8) -> [u8; 16] {
+    bytes[6] = (bytes[6] & 0x0f) | (version << 4);
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+
+    bytes
+}
+
+/// 32자 16진수 문자열을 [Uuid]로 변환
+fn decode_hex(target: &str) -> Option<Uuid> {
+    if target.len() != 32 || !target.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    u128::from_str_radix(target, 16)
+        .ok()
+        .map(|v| Uuid(v.to_be_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn uuid_v4_test() {
+        let ids: Vec<Uuid> = (0..100).map(|_| new_uuid_v4()).collect();
+
+        for id in &ids {
+            let text = id.to_string();
+
+            assert_eq!(4, id.version());
+            assert_eq!(0x80, id.as_bytes()[8] & 0xc0);
+            assert_eq!(Some('4'), text.chars().nth(14));
+            assert!("89ab".contains(text.chars().nth(19).unwrap()));
+            assert_eq!(None, id.timestamp_millis());
+        }
+
+        assert!(ids.iter().enumerate().all(|(i, v)| !ids[..i].contains(v)));
+    }
+
+    #[test]
+    fn uuid_v7_test() {
+        let before = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as u64;
+        // 같은 ms 내의 순번(4096개)을 넘도록 생성
+        let ids: Vec<Uuid> = (0..10_000).map(|_| new_uuid_v7()).collect();
+
+        assert!(ids.windows(2).all(|v| v[0] < v[1]));
+        assert!(ids.windows(2).all(|v| v[0].to_string() < v[1].to_string()));
+
+        for id in [ids[0], ids[ids.len() - 1]] {
+            assert_eq!(7, id.version());
+            assert_eq!(0x80, id.as_bytes()[8] & 0xc0);
+            assert!(id.timestamp_millis().unwrap() >= before);
+        }
+
+        // 다른 thread에서 생성한 UUID와 중복되지 않음
+        let handles: Vec<_> = (0..4)
+            .map(|_| std::thread::spawn(|| (0..1000).map(|_| new_uuid_v7()).collect::<Vec<_>>()))
+            .collect();
+        let mut all: Vec<Uuid> = handles
+            .into_iter()
+            .flat_map(|v| v.join().unwrap())
+            .collect();
+
+        all.sort();
+        all.dedup();
+
+        assert_eq!(4000, all.len());
+    }
+
+    #[test]
+    fn uuid_format_test() {
+        let id = Uuid::from_bytes([
+            0x01, 0x8f, 0x2d, 0x3c, 0x4b, 0x5a, 0x7a, 0xbc, 0x9d, 0xef, 0x01, 0x23, 0x45, 0x67,
+            0x89, 0xab,
+        ]);
+
+        assert_eq!(7, id.version());
+        assert_eq!(Some(0x018f_2d3c_4b5a), id.timestamp_millis());
+        assert_eq!(0x018f_2d3c_4b5a_7abc_9def_0123_4567_89ab, id.as_u128());
+
+        for (format, expected) in [
+            (UuidFormat::Simple, "018f2d3c4b5a7abc9def0123456789ab"),
+            (
+                UuidFormat::Hyphenated,
+                "018f2d3c-4b5a-7abc-9def-0123456789ab",
+            ),
+            (UuidFormat::Braced, "{018f2d3c-4b5a-7abc-9def-0123456789ab}"),
+            (
+                UuidFormat::Urn,
+                "urn:uuid:018f2d3c-4b5a-7abc-9def-0123456789ab",
+            ),
+        ] {
+            assert_eq!(expected, id.format(format));
+            assert_eq!(id, parse_uuid(expected).unwrap());
+            assert_eq!(id, expected.to_uppercase().parse::<Uuid>().unwrap());
+        }
+
+        assert_eq!(id.format(UuidFormat::default()), id.to_string());
+        assert_eq!(
+            "00000000-0000-0000-0000-000000000000",
+            Uuid::NIL.to_string()
+        );
+
+        for target in [
+            "",
+            "018f2d3c4b5a7abc9def0123456789a",
+            "018f2d3c4b5a7abc9def0123456789abc",
+            "018f2d3c4b5a7abc9def0123456789ag",
+            "018f2d3c-4b5a-7abc-9def0123456789ab",
+            "018f2d3c-4b5a-7abc-9def-0123456789ab-",
+            "018f2d3c-4b5a-7abc-9def-+123456789ab",
+            "{018f2d3c-4b5a-7abc-9def-0123456789ab",
+            "{018f2d3c4b5a7abc9def0123456789ab}",
+            "urn:uuid:018f2d3c4b5a7abc9def0123456789ab",
+            "urn:uuid:",
+            "018f2d3c-4b5a-7abc-9def-0123456789수",
+            "a가나다라",
+        ] {
+            assert!(parse_uuid(target).is_err(), "{}", target);
+        }
+    }
+}
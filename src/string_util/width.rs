@@ -0,0 +1,466 @@
+//! 문자열 표시 폭(display width) 기준 정렬 및 자르기 관련 함수 모음
+//!
+//! 터미널 등 고정폭 글꼴 환경에서 한글, 한자 등 동아시아 전각(East Asian Wide) 문자는 폭 2, 일반 ASCII
+//! 문자는 폭 1, 결합 문자 및 제어 문자는 폭 0으로 계산한다.
+//!
+//! 정렬(공백 추가)은 표시 폭 기준으로 처리하여 한글/ASCII 혼합 문자열을 터미널 표, 고정폭 파일 등에서 정렬할
+//! 수 있도록 한다. 문자열 자르기는 사용자가 인식하는 문자 단위(extended grapheme cluster)로 처리하여 한글
+//! 자모 조합, emoji 및 결합 문자열(e.g. `e` + `U+0301`)이 중간에 잘리지 않도록 한다.
+//!
+//! 또한 이전 시스템, Excel 등에서 전달된 전각 영문/숫자(e.g. `ＡＢＣ１２３`)와 반각 한글 자모(e.g. `ﾡ`)를 일반
+//! 문자로 변환한다([to_half_width], [to_full_width]).
+
+use alloc::string::String;
+
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+/// 문자열 표시 폭 반환
+///
+/// # Arguments
+///
+/// - `text` - 대상 문자열
+///
+/// # Return
+///
+/// - 표시 폭
+///
+/// # Link
+///
+/// - [UnicodeWidthStr::width]
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::width::display_width;
+///
+/// assert_eq!(5, display_width("hello"));
+/// assert_eq!(6, display_width("홍길동"));
+/// assert_eq!(9, display_width("ID 홍길동"));
+/// ```
+#[inline]
+pub fn display_width(text: &str) -> usize {
+    UnicodeWidthStr::width(text)
+}
+
+/// 문자 표시 폭 반환. 제어 문자는 `0`
+///
+/// # Link
+///
+/// - [UnicodeWidthChar::width]
+#[inline]
+pub fn char_width(c: char) -> usize {
+    UnicodeWidthChar::width(c).unwrap_or(0)
+}
+
+/// 표시 폭이 `width`가 되도록 왼쪽에 공백을 추가(오른쪽 정렬)
+///
+/// 표시 폭이 `width` 이상일 경우 그대로 반환한다(필요한 경우 [truncate_width] 사용).
+///
+/// # Arguments
+///
+/// - `text` - 대상 문자열
+/// - `width` - 표시 폭
+///
+/// # Return
+///
+/// - 정렬 결과
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::width::pad_left;
+///
+/// assert_eq!("    홍길동", pad_left("홍길동", 10));
+/// assert_eq!("   ID", pad_left("ID", 5));
+/// assert_eq!("홍길동", pad_left("홍길동", 4));
+/// ```
+pub fn pad_left(text: &str, width: usize) -> String {
+    pad(text, width, |space| (space, 0))
+}
+
+/// 표시 폭이 `width`가 되도록 오른쪽에 공백을 추가(왼쪽 정렬, [pad_left] 참고)
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::width::pad_right;
+///
+/// assert_eq!("홍길동    |", format!("{}|", pad_right("홍길동", 10)));
+/// ```
+pub fn pad_right(text: &str, width: usize) -> String {
+    pad(text, width, |space| (0, space))
+}
+
+/// 표시 폭이 `width`가 되도록 양쪽에 공백을 추가(가운데 정렬, [pad_left] 참고)
+///
+/// 추가할 공백이 홀수일 경우 오른쪽에 하나 더 추가한다.
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::width::center;
+///
+/// assert_eq!("  홍길동  ", center("홍길동", 10));
+/// assert_eq!(" 홍길동  ", center("홍길동", 9));
+/// ```
+pub fn center(text: &str, width: usize) -> String {
+    pad(text, width, |space| (space / 2, space - space / 2))
+}
+
+/// 추가할 공백 수를 (왼쪽, 오른쪽)으로 나누어 추가
+fn pad<F: Fn(usize) -> (usize, usize)>(text: &str, width: usize, split: F) -> String {
+    let (left, right) = split(width.saturating_sub(display_width(text)));
+    let mut result = String::with_capacity(text.len() + left + right);
+
+    result.extend(core::iter::repeat_n(' ', left));
+    result.push_str(text);
+    result.extend(core::iter::repeat_n(' ', right));
+
+    result
+}
+
+/// 문자열을 최대 문자(grapheme) 수에 맞춰 자르기
+///
+/// 문자열이 최대 문자 수를 넘을 경우 말줄임 표시(`ellipsis`)를 포함하여 최대 문자 수가 되도록 자른다.
+/// 말줄임 표시가 최대 문자 수 이상일 경우 말줄임 표시를 최대 문자 수에 맞춰 자른다.
+///
+/// # Arguments
+///
+/// - `text` - 대상 문자열
+/// - `max` - 최대 문자 수(말줄임 표시 포함)
+/// - `ellipsis` - 말줄임 표시(e.g. `...`, `…`). 사용하지 않을 경우 빈 문자열
+///
+/// # Return
+///
+/// - 자른 결과
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::width::truncate_graphemes;
+///
+/// assert_eq!("안녕하…", truncate_graphemes("안녕하세요", 4, "…"));
+/// assert_eq!("안녕하세요", truncate_graphemes("안녕하세요", 5, "…"));
+/// // 가족 emoji(ZWJ sequence)는 한 문자
+/// assert_eq!("👨‍👩‍👧..", truncate_graphemes("👨‍👩‍👧👨‍👩‍👧👨‍👩‍👧👨‍👩‍👧", 3, ".."));
+/// ```
+pub fn truncate_graphemes(text: &str, max: usize, ellipsis: &str) -> String {
+    if text.graphemes(true).nth(max).is_none() {
+        return text.into();
+    }
+
+    let ellipsis_count = ellipsis.graphemes(true).count();
+
+    if ellipsis_count >= max {
+        return take_graphemes(ellipsis, |_, count| count <= max);
+    }
+
+    let mut result = take_graphemes(text, |_, count| count <= max - ellipsis_count);
+
+    result.push_str(ellipsis);
+
+    result
+}
+
+/// 문자열을 최대 표시 폭에 맞춰 자르기([display_width] 참고)
+///
+/// 문자열이 최대 표시 폭을 넘을 경우 말줄임 표시(`ellipsis`)를 포함하여 최대 표시 폭을 넘지 않도록 자른다.
+/// 전각 문자 등으로 인해 결과의 표시 폭은 최대 표시 폭보다 작을 수 있다. 말줄임 표시가 최대 표시 폭 이상일
+/// 경우 말줄임 표시를 최대 표시 폭에 맞춰 자른다.
+///
+/// # Arguments
+///
+/// - `text` - 대상 문자열
+/// - `max_width` - 최대 표시 폭(말줄임 표시 포함)
+/// - `ellipsis` - 말줄임 표시(e.g. `...`, `…`). 사용하지 않을 경우 빈 문자열
+///
+/// # Return
+///
+/// - 자른 결과
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::width::{display_width, truncate_width};
+///
+/// // `안녕`(4) + `...`(3)
+/// assert_eq!("안녕...", truncate_width("안녕하세요", 8, "..."));
+/// assert_eq!("ID 홍...", truncate_width("ID 홍길동", 8, "..."));
+/// assert_eq!("ID 홍길동", truncate_width("ID 홍길동", 9, "..."));
+/// assert!(display_width(&truncate_width("가나다라", 5, "")) <= 5);
+/// ```
+pub fn truncate_width(text: &str, max_width: usize, ellipsis: &str) -> String {
+    if display_width(text) <= max_width {
+        return text.into();
+    }
+
+    let ellipsis_width = display_width(ellipsis);
+
+    if ellipsis_width >= max_width {
+        return take_graphemes(ellipsis, |width, _| width <= max_width);
+    }
+
+    let mut result = take_graphemes(text, |width, _| width <= max_width - ellipsis_width);
+
+    result.push_str(ellipsis);
+
+    result
+}
+
+/// 조건(누적 표시 폭, 누적 문자 수)을 만족하는 동안의 앞부분 문자(grapheme) 반환
+fn take_graphemes<F: Fn(usize, usize) -> bool>(text: &str, accept: F) -> String {
+    let mut result = String::new();
+    let mut width = 0;
+
+    for (i, grapheme) in text.graphemes(true).enumerate() {
+        width += display_width(grapheme);
+
+        if !accept(width, i + 1) {
+            break;
+        }
+
+        result.push_str(grapheme);
+    }
+
+    result
+}
+
+/// 전각 기호와 대응하는 반각 기호(`U+FFE0` ~ `U+FFE6`)
+const FULL_WIDTH_SYMBOLS: [(char, char); 7] = [
+    ('\u{FFE0}', '\u{A2}'),
+    ('\u{FFE1}', '\u{A3}'),
+    ('\u{FFE2}', '\u{AC}'),
+    ('\u{FFE3}', '\u{AF}'),
+    ('\u{FFE4}', '\u{A6}'),
+    ('\u{FFE5}', '\u{A5}'),
+    ('\u{FFE6}', '\u{20A9}'),
+];
+
+/// 반각 한글 자모 범위(시작, 끝)와 대응하는 한글 호환 자모 시작 문자
+const HALF_WIDTH_JAMO: [(u32, u32, u32); 6] = [
+    (0xFFA0, 0xFFA0, 0x3164),
+    (0xFFA1, 0xFFBE, 0x3131),
+    (0xFFC2, 0xFFC7, 0x314F),
+    (0xFFCA, 0xFFCF, 0x3155),
+    (0xFFD2, 0xFFD7, 0x315B),
+    (0xFFDA, 0xFFDC, 0x3161),
+];
+
+/// 전각 문자를 반각 문자로 변환
+///
+/// 다음 문자를 변환하며 한글, 한자 등 그 외 문자는 변환하지 않는다.
+///
+/// - 전각 ASCII 문자(`U+FF01` ~ `U+FF5E`, e.g. `Ａ`, `１`, `！`) -> ASCII 문자
+/// - 전각 공백(`U+3000`) -> 공백
+/// - 전각 기호(`￠`, `￡`, `￢`, `￣`, `￤`, `￥`, `￦`) -> 반각 기호(`¢`, `£`, `¬`, `¯`, `¦`, `¥`, `₩`)
+///
+/// 한글 자모는 전각(한글 호환 자모)이 일반적인 형식이므로 반각 자모로 변환하지 않는다. 반각 한글 자모는
+/// [to_full_width]로 변환한다(NFKC 변환시 한글 호환 자모가 아닌 초성/중성 자모(`U+1100` ~)로 변환됨).
+///
+/// # Arguments
+///
+/// - `text` - 변환 대상 문자열
+///
+/// # Return
+///
+/// - 변환 결과
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::width::to_half_width;
+///
+/// assert_eq!("ABC123", to_half_width("ＡＢＣ１２３"));
+/// assert_eq!("홍길동 (010-1234-5678)", to_half_width("홍길동　（０１０－１２３４－５６７８）"));
+/// assert_eq!("₩1,000", to_half_width("￦１，０００"));
+/// ```
+pub fn to_half_width(text: &str) -> String {
+    text.chars()
+        .map(|c| match c {
+            '\u{FF01}'..='\u{FF5E}' => char::from_u32(c as u32 - 0xFEE0).unwrap_or(c),
+            '\u{3000}' => ' ',
+            _ => FULL_WIDTH_SYMBOLS
+                .iter()
+                .find(|(full, _)| *full == c)
+                .map_or(c, |(_, half)| *half),
+        })
+        .collect()
+}
+
+/// 반각 문자를 전각 문자로 변환
+///
+/// [to_half_width]에서 변환하는 문자의 반대 방향으로 변환하며, 반각 한글 자모(`U+FFA0` ~ `U+FFDC`, e.g.
+/// `ﾡ`, `ￂ`)를 한글 호환 자모(e.g. `ㄱ`, `ㅏ`)로 변환한다. 반각 가타카나 등 그 외 문자는 변환하지 않는다.
+///
+/// # Arguments
+///
+/// - `text` - 변환 대상 문자열
+///
+/// # Return
+///
+/// - 변환 결과
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::string_util::width::to_full_width;
+///
+/// assert_eq!("ＡＢＣ　１２３", to_full_width("ABC 123"));
+/// assert_eq!("ㄱㅏ한글", to_full_width("\u{FFA1}\u{FFC2}한글"));
+/// ```
+pub fn to_full_width(text: &str) -> String {
+    text.chars()
+        .map(|c| match c {
+            '\u{21}'..='\u{7E}' => char::from_u32(c as u32 + 0xFEE0).unwrap_or(c),
+            ' ' => '\u{3000}',
+            '\u{FFA0}'..='\u{FFDC}' => HALF_WIDTH_JAMO
+                .iter()
+                .find(|(start, end, _)| (*start..=*end).contains(&(c as u32)))
+                .and_then(|(start, _, jamo)| char::from_u32(jamo + (c as u32 - start)))
+                .unwrap_or(c),
+            _ => FULL_WIDTH_SYMBOLS
+                .iter()
+                .find(|(_, half)| *half == c)
+                .map_or(c, |(full, _)| *full),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_width_test() {
+        assert_eq!(0, display_width(""));
+        assert_eq!(4, display_width("ａｂ"));
+        // 결합 문자(U+0301)
+        assert_eq!(1, display_width("e\u{301}"));
+        assert_eq!(2, char_width('가'));
+        assert_eq!(0, char_width('\u{7}'));
+    }
+
+    #[test]
+    fn pad_test() {
+        // 한글/ASCII 혼합 열 정렬
+        let rows = ["ID", "홍길동", "Kim 철수", "😀"];
+
+        for row in rows {
+            assert_eq!(8, display_width(&pad_left(row, 8)), "{}", row);
+            assert_eq!(8, display_width(&pad_right(row, 8)), "{}", row);
+            assert_eq!(8, display_width(&center(row, 8)), "{}", row);
+        }
+
+        assert_eq!("  Kim 철수", pad_left("Kim 철수", 10));
+        assert_eq!("Kim 철수  ", pad_right("Kim 철수", 10));
+        assert_eq!(" Kim 철수 ", center("Kim 철수", 10));
+        assert_eq!("   ", center("", 3));
+        assert_eq!("홍길동", pad_right("홍길동", 0));
+        assert_eq!("홍길동", center("홍길동", 5));
+    }
+
+    #[test]
+    fn truncate_graphemes_test() {
+        assert_eq!("", truncate_graphemes("", 3, "..."));
+        assert_eq!("abc", truncate_graphemes("abc", 3, "..."));
+        assert_eq!("a...", truncate_graphemes("abcde", 4, "..."));
+        assert_eq!("abcd", truncate_graphemes("abcde", 4, ""));
+        assert_eq!("", truncate_graphemes("abcde", 0, ""));
+        // 말줄임 표시가 최대 문자 수 이상
+        assert_eq!("..", truncate_graphemes("abcde", 2, "..."));
+        // 결합 문자(U+0301), 조합형 한글(ㅎ + ㅏ + ㄴ)
+        assert_eq!(
+            "e\u{301}…",
+            truncate_graphemes("e\u{301}e\u{301}e\u{301}", 2, "…")
+        );
+        assert_eq!(
+            "\u{1112}\u{1161}\u{11AB}…",
+            truncate_graphemes("\u{1112}\u{1161}\u{11AB}\u{1100}\u{1173}\u{11AF}!", 2, "…")
+        );
+        // 국기 emoji(regional indicator)
+        assert_eq!("🇰🇷…", truncate_graphemes("🇰🇷🇺🇸🇯🇵", 2, "…"));
+    }
+
+    #[test]
+    fn truncate_width_test() {
+        assert_eq!("abc", truncate_width("abc", 3, "..."));
+        assert_eq!("ab...", truncate_width("abcdef", 5, "..."));
+        // 전각 문자는 폭 2이므로 최대 표시 폭보다 작을 수 있음
+        assert_eq!("가…", truncate_width("가나다", 4, "…"));
+        assert_eq!("가나", truncate_width("가나다", 5, ""));
+        assert_eq!("..", truncate_width("가나다", 2, "..."));
+        assert_eq!("", truncate_width("가나다", 1, ""));
+        assert_eq!(
+            "e\u{301}e\u{301}~",
+            truncate_width("e\u{301}e\u{301}e\u{301}x", 3, "~")
+        );
+
+        for max_width in 0..12 {
+            let result = truncate_width("ID 홍길동 👍 ok", max_width, "..");
+
+            assert!(
+                display_width(&result) <= max_width,
+                "{}: {}",
+                max_width,
+                result
+            );
+        }
+    }
+
+    #[test]
+    fn full_width_test() {
+        let ascii: String = (0x21u8..=0x7e).map(char::from).collect();
+        let full: String = ('\u{FF01}'..='\u{FF5E}').collect();
+
+        assert_eq!(ascii, to_half_width(&full));
+        assert_eq!(full, to_full_width(&ascii));
+        assert!(full.chars().all(|c| char_width(c) == 2));
+
+        for (half, full) in [
+            ("", ""),
+            (" ", "\u{3000}"),
+            ("a b", "ａ\u{3000}ｂ"),
+            (
+                "\u{A2}\u{A3}\u{AC}\u{AF}\u{A6}\u{A5}\u{20A9}",
+                "￠￡￢￣￤￥￦",
+            ),
+        ] {
+            assert_eq!(half, to_half_width(full));
+            assert_eq!(full, to_full_width(half));
+        }
+
+        // 변환하지 않는 문자
+        for target in ["한글漢字", "ㄱㅏ", "ｶﾞ", "\t\n", "①"] {
+            assert_eq!(target, to_half_width(target));
+        }
+
+        assert_eq!("\t\n①ｶﾞ", to_full_width("\t\n①ｶﾞ"));
+    }
+
+    #[test]
+    fn half_width_jamo_test() {
+        let half: String = ('\u{FFA0}'..='\u{FFDC}')
+            .filter(|c| {
+                !matches!(
+                    *c as u32,
+                    0xFFBF..=0xFFC1 | 0xFFC8 | 0xFFC9 | 0xFFD0 | 0xFFD1 | 0xFFD8 | 0xFFD9
+                )
+            })
+            .collect();
+        let compatibility: String = core::iter::once('\u{3164}')
+            .chain('\u{3131}'..='\u{3163}')
+            .collect();
+
+        assert_eq!(52, half.chars().count());
+        assert_eq!(compatibility, to_full_width(&half));
+        // 한글 호환 자모는 반각 자모로 변환하지 않음
+        assert_eq!(compatibility, to_half_width(&compatibility));
+        assert_eq!(
+            "ㅋㅋ ㄱㄴ",
+            to_half_width(&to_full_width("\u{FFBB}\u{FFBB} \u{FFA1}\u{FFA4}"))
+        );
+        // 사용하지 않는 code point
+        assert_eq!("\u{FFBF}\u{FFC8}", to_full_width("\u{FFBF}\u{FFC8}"));
+    }
+}
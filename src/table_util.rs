@@ -0,0 +1,553 @@
+//! 텍스트 표(table) 출력 관련 함수 모음
+//!
+//! CLI 출력용 ASCII/Unicode 표를 생성하는 [TableBuilder]를 제공한다. 각 열의 폭은
+//! [display_width]로 계산하므로 한글이 포함된 열도 정렬된다. 동일한 데이터를 CSV 및 Markdown 형식으로
+//! 변환할 수 있다.
+
+use crate::string_util::width::{center, display_width, pad_left, pad_right, truncate_width};
+
+/// ANSI 굵은 글씨 시작
+const ANSI_BOLD: &str = "\x1b[1m";
+
+/// ANSI 스타일 초기화
+const ANSI_RESET: &str = "\x1b[0m";
+
+/// 표 테두리 형식
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TableStyle {
+    /// `+`, `-`, `|` 테두리
+    Ascii,
+
+    /// `┌`, `─`, `│` 등 Unicode box drawing 테두리
+    Unicode,
+
+    /// 테두리 없이 공백으로 구분
+    Plain,
+}
+
+/// 열 정렬
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Align {
+    #[default]
+    Left,
+
+    Right,
+
+    Center,
+}
+
+/// 테두리 구성 문자
+struct Border {
+    horizontal: char,
+    vertical: char,
+    /// 상단 (왼쪽, 교차, 오른쪽)
+    top: [char; 3],
+    /// 머리글 하단 (왼쪽, 교차, 오른쪽)
+    middle: [char; 3],
+    /// 하단 (왼쪽, 교차, 오른쪽)
+    bottom: [char; 3],
+}
+
+const ASCII_BORDER: Border = Border {
+    horizontal: '-',
+    vertical: '|',
+    top: ['+', '+', '+'],
+    middle: ['+', '+', '+'],
+    bottom: ['+', '+', '+'],
+};
+
+const UNICODE_BORDER: Border = Border {
+    horizontal: '─',
+    vertical: '│',
+    top: ['┌', '┬', '┐'],
+    middle: ['├', '┼', '┤'],
+    bottom: ['└', '┴', '┘'],
+};
+
+/// 열 설정
+#[derive(Debug, Clone, Default)]
+struct Column {
+    header: String,
+
+    align: Align,
+
+    /// 최대 표시 폭
+    max_width: Option<usize>,
+}
+
+/// 텍스트 표 생성
+///
+/// 열 개수는 머리글 개수로 결정되며, 부족한 항목은 빈 값으로 채우고 초과된 항목은 무시한다.
+/// 항목의 줄바꿈 문자는 공백으로 변환된다.
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::table_util::{Align, TableBuilder, TableStyle};
+///
+/// let table = TableBuilder::new(&["이름", "나이"])
+///     .with_style(TableStyle::Ascii)
+///     .with_align(1, Align::Right)
+///     .add_row(&["홍길동", "30"])
+///     .add_row(&["Kim", "7"]);
+///
+/// let expected = "\
+/// +--------+------+
+/// | 이름   | 나이 |
+/// +--------+------+
+/// | 홍길동 |   30 |
+/// | Kim    |    7 |
+/// +--------+------+";
+///
+/// assert_eq!(expected, table.build());
+/// assert_eq!("이름,나이\r\n홍길동,30\r\nKim,7\r\n", table.to_csv());
+/// ```
+#[derive(Debug, Clone)]
+pub struct TableBuilder {
+    columns: Vec<Column>,
+
+    rows: Vec<Vec<String>>,
+
+    style: TableStyle,
+
+    /// 머리글 굵은 글씨(ANSI) 여부
+    bold_header: bool,
+
+    /// 머리글 대문자 변환 여부
+    uppercase_header: bool,
+
+    /// 말줄임 문자열
+    ellipsis: String,
+}
+
+impl TableBuilder {
+    /// [TableBuilder] 생성. 기본 형식은 [TableStyle::Unicode]
+    ///
+    /// # Arguments
+    ///
+    /// - `headers` - 열 머리글 목록
+    pub fn new<S: AsRef<str>>(headers: &[S]) -> Self {
+        TableBuilder {
+            columns: headers
+                .iter()
+                .map(|h| Column {
+                    header: clean(h.as_ref()),
+                    ..Default::default()
+                })
+                .collect(),
+            rows: vec![],
+            style: TableStyle::Unicode,
+            bold_header: false,
+            uppercase_header: false,
+            ellipsis: "…".to_owned(),
+        }
+    }
+
+    /// 테두리 형식 지정
+    pub fn with_style(mut self, style: TableStyle) -> Self {
+        self.style = style;
+
+        self
+    }
+
+    /// 열 정렬 지정. 범위를 벗어난 열은 무시한다.
+    pub fn with_align(mut self, column: usize, align: Align) -> Self {
+        if let Some(c) = self.columns.get_mut(column) {
+            c.align = align;
+        }
+
+        self
+    }
+
+    /// 열 최대 표시 폭 지정. 초과하는 항목은 말줄임 문자열을 붙여 자른다.
+    pub fn with_max_width(mut self, column: usize, max_width: usize) -> Self {
+        if let Some(c) = self.columns.get_mut(column) {
+            c.max_width = Some(max_width);
+        }
+
+        self
+    }
+
+    /// 말줄임 문자열 지정. 기본값은 `…`
+    pub fn with_ellipsis(mut self, ellipsis: &str) -> Self {
+        self.ellipsis = ellipsis.to_owned();
+
+        self
+    }
+
+    /// 머리글 굵은 글씨(ANSI escape) 적용 여부 지정
+    ///
+    /// [TableBuilder::build]에만 적용된다.
+    pub fn with_bold_header(mut self, bold: bool) -> Self {
+        self.bold_header = bold;
+
+        self
+    }
+
+    /// 머리글 대문자 변환 여부 지정
+    ///
+    /// [TableBuilder::build]에만 적용된다.
+    pub fn with_uppercase_header(mut self, uppercase: bool) -> Self {
+        self.uppercase_header = uppercase;
+
+        self
+    }
+
+    /// 행 추가
+    pub fn add_row<S: AsRef<str>>(mut self, row: &[S]) -> Self {
+        self.push_row(row);
+
+        self
+    }
+
+    /// 행 추가(반복문 등에서 사용)
+    pub fn push_row<S: AsRef<str>>(&mut self, row: &[S]) {
+        let mut cells: Vec<String> = row
+            .iter()
+            .take(self.columns.len())
+            .map(|v| clean(v.as_ref()))
+            .collect();
+
+        cells.resize(self.columns.len(), String::new());
+        self.rows.push(cells);
+    }
+
+    /// 행 개수 반환
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// 행 존재 여부
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// 표 문자열 생성
+    ///
+    /// 마지막 줄에는 줄바꿈 문자가 추가되지 않는다.
+    pub fn build(&self) -> String {
+        let headers: Vec<String> = self
+            .columns
+            .iter()
+            .map(|c| {
+                let header = if self.uppercase_header {
+                    c.header.to_uppercase()
+                } else {
+                    c.header.clone()
+                };
+
+                self.truncate(&header, c.max_width)
+            })
+            .collect();
+        let rows: Vec<Vec<String>> = self
+            .rows
+            .iter()
+            .map(|r| {
+                r.iter()
+                    .zip(self.columns.iter())
+                    .map(|(v, c)| self.truncate(v, c.max_width))
+                    .collect()
+            })
+            .collect();
+        let widths = column_widths(&headers, &rows);
+        let mut lines: Vec<String> = vec![];
+
+        let border = match self.style {
+            TableStyle::Ascii => Some(&ASCII_BORDER),
+            TableStyle::Unicode => Some(&UNICODE_BORDER),
+            TableStyle::Plain => None,
+        };
+
+        if let Some(b) = border {
+            lines.push(separator(&widths, b.horizontal, &b.top));
+        }
+
+        let styled_headers: Vec<String> = headers
+            .iter()
+            .zip(widths.iter())
+            .zip(self.columns.iter())
+            .map(|((h, w), c)| {
+                let padded = pad(h, *w, c.align);
+
+                if self.bold_header {
+                    format!("{}{}{}", ANSI_BOLD, padded, ANSI_RESET)
+                } else {
+                    padded
+                }
+            })
+            .collect();
+
+        lines.push(self.join_cells(styled_headers, border));
+
+        match border {
+            Some(b) => lines.push(separator(&widths, b.horizontal, &b.middle)),
+            None => lines.push(
+                widths
+                    .iter()
+                    .map(|w| "-".repeat(*w))
+                    .collect::<Vec<String>>()
+                    .join("  "),
+            ),
+        }
+
+        for row in rows.iter() {
+            let cells = row
+                .iter()
+                .zip(widths.iter())
+                .zip(self.columns.iter())
+                .map(|((v, w), c)| pad(v, *w, c.align))
+                .collect();
+
+            lines.push(self.join_cells(cells, border));
+        }
+
+        if let Some(b) = border {
+            lines.push(separator(&widths, b.horizontal, &b.bottom));
+        }
+
+        lines.join("\n")
+    }
+
+    /// CSV(RFC 4180) 형식 문자열 생성
+    ///
+    /// 줄 구분자는 `\r\n`이며 `,`, `"`, 줄바꿈 문자가 포함된 항목은 `"`로 감싼다. 열 최대 폭 및
+    /// 머리글 스타일은 적용되지 않는다.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::new();
+        let headers = self.columns.iter().map(|c| c.header.as_str());
+
+        for line in std::iter::once(headers.collect::<Vec<&str>>()).chain(
+            self.rows
+                .iter()
+                .map(|r| r.iter().map(|v| v.as_str()).collect()),
+        ) {
+            let fields: Vec<String> = line.iter().map(|v| csv_field(v)).collect();
+
+            csv.push_str(&fields.join(","));
+            csv.push_str("\r\n");
+        }
+
+        csv
+    }
+
+    /// Markdown 표 형식 문자열 생성
+    ///
+    /// 열 정렬은 구분선(`:---`, `---:`, `:---:`)으로 표현되며 `|` 문자는 `\|`로 변환된다.
+    pub fn to_markdown(&self) -> String {
+        let headers: Vec<String> = self
+            .columns
+            .iter()
+            .map(|c| escape_markdown(&self.truncate(&c.header, c.max_width)))
+            .collect();
+        let rows: Vec<Vec<String>> = self
+            .rows
+            .iter()
+            .map(|r| {
+                r.iter()
+                    .zip(self.columns.iter())
+                    .map(|(v, c)| escape_markdown(&self.truncate(v, c.max_width)))
+                    .collect()
+            })
+            .collect();
+        // 구분선 최소 폭 3
+        let widths: Vec<usize> = column_widths(&headers, &rows)
+            .into_iter()
+            .map(|w| w.max(3))
+            .collect();
+        let line = |cells: Vec<String>| format!("| {} |", cells.join(" | "));
+        let mut lines: Vec<String> = vec![];
+
+        lines.push(line(
+            headers
+                .iter()
+                .zip(widths.iter())
+                .zip(self.columns.iter())
+                .map(|((h, w), c)| pad(h, *w, c.align))
+                .collect(),
+        ));
+        lines.push(line(
+            widths
+                .iter()
+                .zip(self.columns.iter())
+                .map(|(w, c)| match c.align {
+                    Align::Left => "-".repeat(*w),
+                    Align::Right => format!("{}:", "-".repeat(w - 1)),
+                    Align::Center => format!(":{}:", "-".repeat(w - 2)),
+                })
+                .collect(),
+        ));
+
+        for row in rows.iter() {
+            lines.push(line(
+                row.iter()
+                    .zip(widths.iter())
+                    .zip(self.columns.iter())
+                    .map(|((v, w), c)| pad(v, *w, c.align))
+                    .collect(),
+            ));
+        }
+
+        lines.join("\n")
+    }
+
+    fn join_cells(&self, cells: Vec<String>, border: Option<&Border>) -> String {
+        match border {
+            Some(b) => format!(
+                "{v} {} {v}",
+                cells.join(&format!(" {} ", b.vertical)),
+                v = b.vertical
+            ),
+            None => cells.join("  ").trim_end().to_owned(),
+        }
+    }
+
+    /// 최대 표시 폭에 맞춰 자르기
+    fn truncate(&self, text: &str, max_width: Option<usize>) -> String {
+        match max_width {
+            Some(max_width) => truncate_width(text, max_width, &self.ellipsis),
+            None => text.to_owned(),
+        }
+    }
+}
+
+/// 줄바꿈 문자를 공백으로 변환
+fn clean(text: &str) -> String {
+    text.replace("\r\n", " ").replace(['\r', '\n'], " ")
+}
+
+fn column_widths(headers: &[String], rows: &[Vec<String>]) -> Vec<usize> {
+    headers
+        .iter()
+        .enumerate()
+        .map(|(i, h)| {
+            rows.iter()
+                .map(|r| display_width(&r[i]))
+                .chain(std::iter::once(display_width(h)))
+                .max()
+                .unwrap_or(0)
+        })
+        .collect()
+}
+
+/// 표시 폭 기준 정렬
+fn pad(text: &str, width: usize, align: Align) -> String {
+    match align {
+        Align::Left => pad_right(text, width),
+        Align::Right => pad_left(text, width),
+        Align::Center => center(text, width),
+    }
+}
+
+fn separator(widths: &[usize], horizontal: char, corners: &[char; 3]) -> String {
+    let segments: Vec<String> = widths
+        .iter()
+        .map(|w| horizontal.to_string().repeat(w + 2))
+        .collect();
+
+    format!(
+        "{}{}{}",
+        corners[0],
+        segments.join(&corners[1].to_string()),
+        corners[2]
+    )
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_owned()
+    }
+}
+
+fn escape_markdown(value: &str) -> String {
+    value.replace('|', "\\|")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> TableBuilder {
+        TableBuilder::new(&["ID", "이름", "메모"])
+            .with_align(0, Align::Right)
+            .with_align(2, Align::Center)
+            .add_row(&["1", "홍길동", "a|b"])
+            .add_row(&["20", "Kim"])
+    }
+
+    #[test]
+    fn unicode_test() {
+        let expected = "\
+┌────┬────────┬──────┐
+│ ID │ 이름   │ 메모 │
+├────┼────────┼──────┤
+│  1 │ 홍길동 │ a|b  │
+│ 20 │ Kim    │      │
+└────┴────────┴──────┘";
+
+        assert_eq!(expected, sample().build());
+    }
+
+    #[test]
+    fn plain_and_header_style_test() {
+        let table = TableBuilder::new(&["id", "name"])
+            .with_style(TableStyle::Plain)
+            .with_uppercase_header(true)
+            .add_row(&["1", "홍길동"]);
+
+        assert_eq!("ID  NAME\n--  ------\n1   홍길동", table.build());
+
+        let bold = table.with_bold_header(true).build();
+
+        assert!(bold.starts_with("\x1b[1mID\x1b[0m  \x1b[1mNAME  \x1b[0m"));
+    }
+
+    #[test]
+    fn truncate_test() {
+        let table = TableBuilder::new(&["내용"])
+            .with_style(TableStyle::Ascii)
+            .with_max_width(0, 7)
+            .add_row(&["가나다라마바사"])
+            .add_row(&["abc"]);
+
+        let expected = "\
++---------+
+| 내용    |
++---------+
+| 가나다… |
+| abc     |
++---------+";
+
+        assert_eq!(expected, table.build());
+
+        let table = table.with_ellipsis("...");
+
+        assert!(table.build().contains("| 가나... |"));
+    }
+
+    #[test]
+    fn export_test() {
+        let table = TableBuilder::new(&["a", "b"])
+            .add_row(&["1,2", "say \"hi\""])
+            .add_row(&["줄\n바꿈", ""]);
+
+        assert_eq!(
+            "a,b\r\n\"1,2\",\"say \"\"hi\"\"\"\r\n줄 바꿈,\r\n",
+            table.to_csv()
+        );
+
+        let expected = "\
+|  ID | 이름   | 메모 |
+| --: | ------ | :--: |
+|   1 | 홍길동 | a\\|b |
+|  20 | Kim    |      |";
+
+        assert_eq!(expected, sample().to_markdown());
+        assert_eq!(2, sample().len());
+    }
+}
@@ -0,0 +1,620 @@
+//! 문자열 template 처리 관련 함수 모음
+//!
+//! mustache 형식의 template을 serde 직렬화 가능한 context로 처리한다. 지원하는 tag는 아래와 같다.
+//!
+//! - `{{name}}` - 값 출력(HTML escape 적용). `.`으로 중첩 항목 지정 (e.g. `{{user.name}}`), `{{.}}`은 현재 항목
+//! - `{{{name}}}`, `{{& name}}` - escape 없이 출력
+//! - `{{#name}}..{{/name}}` - 배열일 경우 각 요소마다 반복, 그 외에는 값이 있을 경우(`false`, `null`, 빈 배열
+//!   제외) 한 번 출력
+//! - `{{^name}}..{{/name}}` - 값이 없을 경우 출력
+//! - `{{> name}}` - partial template 포함(`<partials 경로>/<name>.mustache`)
+//! - `{{! comment}}` - 주석
+//!
+//! 한 줄에 section, 주석 혹은 partial tag만 있을 경우 해당 줄은 출력하지 않는다.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::error::InvalidArgumentError;
+use crate::string_util::html::escape_html;
+
+/// partial template 기본 확장자
+pub const DEFAULT_PARTIAL_EXTENSION: &str = "mustache";
+
+/// partial template 최대 중첩 깊이
+const MAX_PARTIAL_DEPTH: usize = 32;
+
+/// template 원본
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TemplateSource<'a> {
+    /// template 문자열
+    Str(&'a str),
+
+    /// template 파일. partial 경로가 지정되지 않았을 경우 파일이 위치한 경로를 partial 경로로 사용
+    Path(&'a Path),
+}
+
+impl<'a> From<&'a str> for TemplateSource<'a> {
+    fn from(value: &'a str) -> Self {
+        TemplateSource::Str(value)
+    }
+}
+
+impl<'a> From<&'a Path> for TemplateSource<'a> {
+    fn from(value: &'a Path) -> Self {
+        TemplateSource::Path(value)
+    }
+}
+
+impl<'a> From<&'a PathBuf> for TemplateSource<'a> {
+    fn from(value: &'a PathBuf) -> Self {
+        TemplateSource::Path(value.as_path())
+    }
+}
+
+/// 기본 설정([TemplateEngine::new])으로 template 처리
+///
+/// # Arguments
+///
+/// - `source` - template 문자열(`&str`) 혹은 파일 경로(`&Path`)
+/// - `context` - template context
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - template 문법 오류, 파일 읽기 실패 혹은 context 직렬화 실패
+///
+/// # Example
+///
+/// ```rust
+/// use serde::Serialize;
+/// use cliff3_util::template_util::render_template;
+///
+/// #[derive(Serialize)]
+/// struct Order {
+///     name: String,
+///     items: Vec<Item>,
+///     vip: bool,
+/// }
+///
+/// #[derive(Serialize)]
+/// struct Item {
+///     title: String,
+///     price: u32,
+/// }
+///
+/// let order = Order {
+///     name: "<홍길동>".to_owned(),
+///     items: vec![
+///         Item { title: "책".to_owned(), price: 15000 },
+///         Item { title: "펜".to_owned(), price: 1000 },
+///     ],
+///     vip: false,
+/// };
+/// let template = "{{name}}님 주문 내역\n\
+///                 {{#items}}\n\
+///                 - {{title}}: {{price}}원\n\
+///                 {{/items}}\n\
+///                 {{^vip}}\n\
+///                 일반 회원\n\
+///                 {{/vip}}\n";
+///
+/// assert_eq!(
+///     "&lt;홍길동&gt;님 주문 내역\n- 책: 15000원\n- 펜: 1000원\n일반 회원\n",
+///     render_template(template, &order).unwrap()
+/// );
+/// ```
+pub fn render_template<'a, S, T>(source: S, context: &T) -> Result<String, InvalidArgumentError>
+where
+    S: Into<TemplateSource<'a>>,
+    T: Serialize,
+{
+    TemplateEngine::new().render(source, context)
+}
+
+/// template 처리 설정
+#[derive(Debug, Clone)]
+pub struct TemplateEngine {
+    partials_dir: Option<PathBuf>,
+
+    partial_extension: String,
+
+    escape_html: bool,
+}
+
+impl Default for TemplateEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TemplateEngine {
+    /// HTML escape를 적용하는 [TemplateEngine] 생성
+    pub fn new() -> Self {
+        TemplateEngine {
+            partials_dir: None,
+            partial_extension: DEFAULT_PARTIAL_EXTENSION.to_owned(),
+            escape_html: true,
+        }
+    }
+
+    /// partial template 경로 지정
+    pub fn with_partials_dir<P: AsRef<Path>>(mut self, dir: P) -> Self {
+        self.partials_dir = Some(dir.as_ref().to_path_buf());
+
+        self
+    }
+
+    /// partial template 확장자 지정(`.` 제외). 기본값은 [DEFAULT_PARTIAL_EXTENSION]
+    pub fn with_partial_extension(mut self, extension: &str) -> Self {
+        self.partial_extension = extension.trim_start_matches('.').to_owned();
+
+        self
+    }
+
+    /// `{{name}}` 출력시 HTML escape 적용 여부 지정. 일반 텍스트(e.g. 메일 본문) 생성시 `false`로 지정
+    pub fn with_escape_html(mut self, escape_html: bool) -> Self {
+        self.escape_html = escape_html;
+
+        self
+    }
+
+    /// template 처리
+    ///
+    /// [render_template] 참고
+    ///
+    /// # Errors
+    ///
+    /// - [InvalidArgumentError] - template 문법 오류, 파일 읽기 실패 혹은 context 직렬화 실패
+    pub fn render<'a, S, T>(&self, source: S, context: &T) -> Result<String, InvalidArgumentError>
+    where
+        S: Into<TemplateSource<'a>>,
+        T: Serialize,
+    {
+        let context = serde_json::to_value(context).map_err(|e| {
+            InvalidArgumentError::new(&format!("context 직렬화 실패 입니다({}).", e))
+        })?;
+        let (template, partials_dir) = match source.into() {
+            TemplateSource::Str(v) => (v.to_owned(), self.partials_dir.clone()),
+            TemplateSource::Path(path) => (
+                read_file(path)?,
+                self.partials_dir
+                    .clone()
+                    .or_else(|| path.parent().map(|v| v.to_path_buf())),
+            ),
+        };
+        let mut renderer = Renderer {
+            engine: self,
+            partials_dir,
+            partials: HashMap::new(),
+            depth: 0,
+        };
+        let nodes = parse(&template)?;
+        let mut result = String::new();
+
+        renderer.render_nodes(&nodes, &mut vec![&context], &mut result)?;
+
+        Ok(result)
+    }
+}
+
+fn read_file(path: &Path) -> Result<String, InvalidArgumentError> {
+    fs::read_to_string(path).map_err(|e| {
+        InvalidArgumentError::new(&format!(
+            "template 파일 읽기 실패 입니다({:?}, {}).",
+            path, e
+        ))
+    })
+}
+
+/// template 구성 요소
+#[derive(Debug, Clone, PartialEq)]
+enum Node {
+    Text(String),
+
+    Variable {
+        name: String,
+        escape: bool,
+    },
+
+    Section {
+        name: String,
+        inverted: bool,
+        children: Vec<Node>,
+    },
+
+    Partial(String),
+}
+
+/// tag 종류
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum TagKind {
+    Variable,
+    Raw,
+    Section,
+    Inverted,
+    Close,
+    Comment,
+    Partial,
+}
+
+impl TagKind {
+    /// 해당 tag만 있는 줄을 출력에서 제외할지 여부
+    fn standalone(&self) -> bool {
+        !matches!(self, TagKind::Variable | TagKind::Raw)
+    }
+}
+
+/// template 해석
+fn parse(template: &str) -> Result<Vec<Node>, InvalidArgumentError> {
+    // (section 명칭, 시작 행 번호, 상위 node 목록, inverted 여부)
+    let mut stack: Vec<(String, usize, Vec<Node>, bool)> = vec![];
+    let mut nodes: Vec<Node> = vec![];
+    let mut text = String::new();
+    let mut cursor = 0;
+    let mut last_tag_end = 0;
+
+    while let Some(found) = template[cursor..].find("{{") {
+        let start = cursor + found;
+        let line = template[..start].matches('\n').count() + 1;
+        let (kind, inner, end) = if template[start..].starts_with("{{{") {
+            let close = template[start + 3..]
+                .find("}}}")
+                .ok_or_else(|| unclosed_tag(line))?;
+
+            (
+                TagKind::Raw,
+                &template[start + 3..start + 3 + close],
+                start + 3 + close + 3,
+            )
+        } else {
+            let close = template[start + 2..]
+                .find("}}")
+                .ok_or_else(|| unclosed_tag(line))?;
+            let inner = &template[start + 2..start + 2 + close];
+            let kind = match inner.chars().next() {
+                Some('&') => TagKind::Raw,
+                Some('#') => TagKind::Section,
+                Some('^') => TagKind::Inverted,
+                Some('/') => TagKind::Close,
+                Some('!') => TagKind::Comment,
+                Some('>') => TagKind::Partial,
+                _ => TagKind::Variable,
+            };
+            let inner = match kind {
+                TagKind::Variable => inner,
+                _ => &inner[1..],
+            };
+
+            (kind, inner, start + 2 + close + 2)
+        };
+        let name = inner.trim().to_owned();
+
+        text.push_str(&template[cursor..start]);
+        cursor = end;
+
+        // 해당 tag만 있는 줄은 줄 전체 제외
+        if kind.standalone() {
+            let line_start = template[..start].rfind('\n').map_or(0, |v| v + 1);
+            let line_end = template[end..]
+                .find('\n')
+                .map_or(template.len(), |v| end + v);
+
+            if line_start >= last_tag_end
+                && template[line_start..start].trim().is_empty()
+                && template[end..line_end].trim().is_empty()
+            {
+                text.truncate(text.len() - (start - line_start));
+                cursor = (line_end + 1).min(template.len());
+            }
+        }
+
+        last_tag_end = cursor;
+
+        if !text.is_empty() {
+            nodes.push(Node::Text(std::mem::take(&mut text)));
+        }
+
+        if name.is_empty() && kind != TagKind::Comment {
+            return Err(InvalidArgumentError::new(&format!(
+                "{}번째 줄에 명칭이 없는 tag가 있습니다.",
+                line
+            )));
+        }
+
+        match kind {
+            TagKind::Variable | TagKind::Raw => nodes.push(Node::Variable {
+                name,
+                escape: kind == TagKind::Variable,
+            }),
+            TagKind::Section | TagKind::Inverted => {
+                stack.push((
+                    name,
+                    line,
+                    std::mem::take(&mut nodes),
+                    kind == TagKind::Inverted,
+                ));
+            }
+            TagKind::Close => {
+                let (open, _, parent, inverted) = stack.pop().ok_or_else(|| {
+                    InvalidArgumentError::new(&format!(
+                        "{}번째 줄의 {{{{/{}}}}}에 해당하는 section이 없습니다.",
+                        line, name
+                    ))
+                })?;
+
+                if open != name {
+                    return Err(InvalidArgumentError::new(&format!(
+                        "{}번째 줄의 {{{{/{}}}}}가 {{{{#{}}}}}와 일치하지 않습니다.",
+                        line, name, open
+                    )));
+                }
+
+                let children = std::mem::replace(&mut nodes, parent);
+
+                nodes.push(Node::Section {
+                    name,
+                    inverted,
+                    children,
+                });
+            }
+            TagKind::Comment => {}
+            TagKind::Partial => nodes.push(Node::Partial(name)),
+        }
+    }
+
+    if let Some((name, line, _, _)) = stack.pop() {
+        return Err(InvalidArgumentError::new(&format!(
+            "{}번째 줄의 {{{{#{}}}}}가 닫히지 않았습니다.",
+            line, name
+        )));
+    }
+
+    text.push_str(&template[cursor..]);
+
+    if !text.is_empty() {
+        nodes.push(Node::Text(text));
+    }
+
+    Ok(nodes)
+}
+
+fn unclosed_tag(line: usize) -> InvalidArgumentError {
+    InvalidArgumentError::new(&format!("{}번째 줄의 tag가 닫히지 않았습니다.", line))
+}
+
+struct Renderer<'e> {
+    engine: &'e TemplateEngine,
+
+    partials_dir: Option<PathBuf>,
+
+    /// 해석된 partial template
+    partials: HashMap<String, Vec<Node>>,
+
+    depth: usize,
+}
+
+impl Renderer<'_> {
+    fn render_nodes(
+        &mut self,
+        nodes: &[Node],
+        stack: &mut Vec<&Value>,
+        output: &mut String,
+    ) -> Result<(), InvalidArgumentError> {
+        for node in nodes {
+            match node {
+                Node::Text(v) => output.push_str(v),
+                Node::Variable { name, escape } => {
+                    let value = match lookup(stack, name) {
+                        None | Some(Value::Null) => String::new(),
+                        Some(Value::String(v)) => v.clone(),
+                        Some(v) => v.to_string(),
+                    };
+
+                    if *escape && self.engine.escape_html {
+                        output.push_str(&escape_html(&value));
+                    } else {
+                        output.push_str(&value);
+                    }
+                }
+                Node::Section {
+                    name,
+                    inverted,
+                    children,
+                } => {
+                    let value = lookup(stack, name);
+                    let truthy = match value {
+                        None | Some(Value::Null) | Some(Value::Bool(false)) => false,
+                        Some(Value::Array(v)) => !v.is_empty(),
+                        _ => true,
+                    };
+
+                    match (inverted, value) {
+                        (true, _) if !truthy => self.render_nodes(children, stack, output)?,
+                        (false, Some(Value::Array(items))) => {
+                            for item in items {
+                                stack.push(item);
+                                self.render_nodes(children, stack, output)?;
+                                stack.pop();
+                            }
+                        }
+                        (false, Some(v)) if truthy => {
+                            stack.push(v);
+                            self.render_nodes(children, stack, output)?;
+                            stack.pop();
+                        }
+                        _ => {}
+                    }
+                }
+                Node::Partial(name) => {
+                    if self.depth >= MAX_PARTIAL_DEPTH {
+                        return Err(InvalidArgumentError::new(&format!(
+                            "partial 중첩 깊이가 {}을 초과하였습니다({}).",
+                            MAX_PARTIAL_DEPTH, name
+                        )));
+                    }
+
+                    let partial = self.load_partial(name)?;
+
+                    self.depth += 1;
+                    self.render_nodes(&partial, stack, output)?;
+                    self.depth -= 1;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn load_partial(&mut self, name: &str) -> Result<Vec<Node>, InvalidArgumentError> {
+        if let Some(nodes) = self.partials.get(name) {
+            return Ok(nodes.clone());
+        }
+
+        let dir = self.partials_dir.as_ref().ok_or_else(|| {
+            InvalidArgumentError::new(&format!("partial 경로가 지정되지 않았습니다({}).", name))
+        })?;
+
+        // 절대 경로, 상위 경로 등은 partial 경로를 벗어날 수 있으므로 허용하지 않음
+        if !Path::new(name)
+            .components()
+            .all(|v| matches!(v, Component::Normal(_)))
+        {
+            return Err(InvalidArgumentError::new(&format!(
+                "허용되지 않는 partial 명칭 입니다({}).",
+                name
+            )));
+        }
+
+        let path = dir.join(format!("{}.{}", name, self.engine.partial_extension));
+        let nodes = parse(&read_file(&path)?)?;
+
+        self.partials.insert(name.to_owned(), nodes.clone());
+
+        Ok(nodes)
+    }
+}
+
+/// context stack에서 값 검색. 첫 번째 항목은 상위 context 방향으로 검색한다.
+fn lookup<'v>(stack: &[&'v Value], name: &str) -> Option<&'v Value> {
+    if name == "." {
+        return stack.last().copied();
+    }
+
+    let mut segments = name.split('.');
+    let first = segments.next()?;
+    let found = stack.iter().rev().find_map(|v| v.get(first))?;
+
+    segments.try_fold(found, |current, key| current.get(key))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::LibError;
+    use serde_json::json;
+
+    #[test]
+    fn render_test() {
+        let context = json!({
+            "title": "A & B",
+            "html": "<b>굵게</b>",
+            "user": {"name": "홍길동", "tags": ["a", "b"]},
+            "empty": [],
+            "zero": 0
+        });
+        let render = |template: &str| render_template(template, &context).unwrap();
+
+        assert_eq!("A &amp; B", render("{{title}}"));
+        assert_eq!("<b>굵게</b><b>굵게</b>", render("{{{html}}}{{& html}}"));
+        assert_eq!("홍길동", render("{{ user.name }}"));
+        assert_eq!("[a][b]", render("{{#user.tags}}[{{.}}]{{/user.tags}}"));
+        // 상위 context 검색
+        assert_eq!(
+            "A &amp; B:a A &amp; B:b ",
+            render("{{#user.tags}}{{title}}:{{.}} {{/user.tags}}")
+        );
+        assert_eq!("홍길동", render("{{#user}}{{name}}{{/user}}"));
+        assert_eq!(
+            "없음",
+            render("{{#empty}}x{{/empty}}{{^empty}}없음{{/empty}}")
+        );
+        assert_eq!("0", render("{{#zero}}{{.}}{{/zero}}{{! 주석 }}"));
+        assert_eq!("", render("{{unknown}}{{#unknown}}x{{/unknown}}"));
+
+        // 단독 tag 줄 제외
+        assert_eq!(
+            "<ul>\n  <li>a</li>\n  <li>b</li>\n</ul>\n",
+            render("<ul>\n  {{#user.tags}}\n  <li>{{.}}</li>\n  {{/user.tags}}\n</ul>\n")
+        );
+
+        let text = TemplateEngine::new()
+            .with_escape_html(false)
+            .render("{{title}}", &context)
+            .unwrap();
+
+        assert_eq!("A & B", text);
+    }
+
+    #[test]
+    fn parse_error_test() {
+        let context = json!({});
+
+        assert!(render_template("{{#a}}x", &context)
+            .unwrap_err()
+            .get_message()
+            .contains("닫히지 않았습니다"));
+        assert!(render_template("{{#a}}{{/b}}", &context).is_err());
+        assert!(render_template("{{/a}}", &context).is_err());
+        assert!(render_template("{{a", &context).is_err());
+        assert!(render_template("{{}}", &context).is_err());
+        assert!(render_template("{{> header}}", &context).is_err());
+    }
+
+    #[test]
+    fn partial_test() {
+        let dir = std::env::temp_dir().join(format!("cliff3_template_{}", std::process::id()));
+
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("item.mustache"), "<li>{{name}}</li>\n").unwrap();
+        fs::write(dir.join("loop.mustache"), "{{> loop}}").unwrap();
+        fs::write(
+            dir.join("page.html"),
+            "<ul>\n{{#items}}\n{{> item}}\n{{/items}}\n</ul>",
+        )
+        .unwrap();
+
+        let context = json!({"items": [{"name": "a"}, {"name": "<b>"}]});
+        let result = render_template(&dir.join("page.html"), &context);
+        let recursive = TemplateEngine::new()
+            .with_partials_dir(&dir)
+            .render("{{> loop}}", &context);
+        // partial 경로 밖의 파일
+        let outside = dir.join("outside");
+
+        fs::create_dir_all(&outside).unwrap();
+        fs::write(outside.join("secret.mustache"), "secret").unwrap();
+
+        let engine = TemplateEngine::new().with_partials_dir(dir.join("partials"));
+        let absolute = engine.render(
+            format!("{{{{> {}}}}}", outside.join("secret").display()).as_str(),
+            &context,
+        );
+        let parent = engine.render("{{> ../outside/secret}}", &context);
+        let current = engine.render("{{> ./secret}}", &context);
+
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(
+            "<ul>\n<li>a</li>\n<li>&lt;b&gt;</li>\n</ul>",
+            result.unwrap()
+        );
+        assert!(recursive.is_err());
+        assert!(absolute.is_err());
+        assert!(parent.is_err());
+        assert!(current.is_err());
+    }
+}
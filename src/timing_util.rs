@@ -0,0 +1,596 @@
+//! 실행 시간 측정 관련 함수 모음
+//!
+//! 작업 실행 시간을 측정하는 [time_it], 범위(scope) 단위로 측정하는 [TimingGuard], 측정 결과를 label별로
+//! 집계하여 백분위수(percentile)를 계산하는 [TimerRegistry] 및 구간(lap) 측정이 가능한 [Stopwatch]를 제공한다.
+//!
+//! [time_it] 및 [TimingGuard]의 측정 결과는 [TimerRegistry::global]에 기록되며, `tracing` feature
+//! 활성화시 `debug` 수준의 event로도 기록된다. `derive` feature 활성화시 `#[timed]` attribute를
+//! 이용하여 함수 실행 시간을 측정할 수 있다.
+//!
+//! ```rust
+//! # #[cfg(feature = "derive")]
+//! # {
+//! use cliff3_util::timing_util::{timed, TimerRegistry};
+//!
+//! #[timed("doc.sum")]
+//! fn sum(values: &[u64]) -> u64 {
+//!     values.iter().sum()
+//! }
+//!
+//! assert_eq!(6, sum(&[1, 2, 3]));
+//! assert!(TimerRegistry::global().stats("doc.sum").is_some());
+//! # }
+//! ```
+
+use std::collections::{HashMap, VecDeque};
+use std::fmt::{Display, Formatter};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use lazy_static::lazy_static;
+
+#[cfg(feature = "derive")]
+pub use cliff3_util_derive::timed;
+
+/// label별 기본 최대 보관 측정값 수
+pub const DEFAULT_MAX_SAMPLES: usize = 10_000;
+
+lazy_static! {
+    static ref GLOBAL_REGISTRY: TimerRegistry = TimerRegistry::new();
+}
+
+/// 작업 실행 시간 측정
+///
+/// 측정 결과는 [TimerRegistry::global]에 `label`로 기록된다.
+///
+/// # Arguments
+///
+/// - `label` - 측정 대상 명칭
+/// - `f` - 측정 대상 작업
+///
+/// # Return
+///
+/// - (작업 결과, 실행 시간)
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::timing_util::time_it;
+///
+/// let (result, elapsed) = time_it("doc.time_it", || 1 + 1);
+///
+/// assert_eq!(2, result);
+/// assert!(elapsed.as_secs() < 1);
+/// ```
+pub fn time_it<T, F>(label: &str, f: F) -> (T, Duration)
+where
+    F: FnOnce() -> T,
+{
+    let start = Instant::now();
+    let result = f();
+    let elapsed = start.elapsed();
+
+    finish(label, elapsed);
+
+    (result, elapsed)
+}
+
+/// 측정 결과 기록
+fn finish(label: &str, elapsed: Duration) {
+    #[cfg(feature = "tracing")]
+    tracing::debug!(
+        label,
+        elapsed_us = elapsed.as_micros() as u64,
+        "execution time"
+    );
+
+    GLOBAL_REGISTRY.record(label, elapsed);
+}
+
+/// 범위(scope) 실행 시간 측정
+///
+/// 생성 시점부터 [Drop] 시점까지의 시간을 측정하여 [TimerRegistry::global]에 기록한다. `return`, `?`
+/// 혹은 panic으로 범위를 벗어나는 경우에도 기록된다.
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::timing_util::{TimerRegistry, TimingGuard};
+///
+/// {
+///     let _guard = TimingGuard::new("doc.guard");
+///     // 측정 대상 작업
+/// }
+///
+/// assert_eq!(1, TimerRegistry::global().stats("doc.guard").unwrap().count);
+/// ```
+#[derive(Debug)]
+pub struct TimingGuard {
+    label: String,
+
+    start: Instant,
+}
+
+impl TimingGuard {
+    /// 측정 시작
+    pub fn new(label: &str) -> Self {
+        TimingGuard {
+            label: label.to_owned(),
+            start: Instant::now(),
+        }
+    }
+
+    /// 현재까지의 경과 시간 반환
+    #[inline]
+    pub fn elapsed(&self) -> Duration {
+        self.start.elapsed()
+    }
+}
+
+impl Drop for TimingGuard {
+    fn drop(&mut self) {
+        finish(&self.label, self.start.elapsed());
+    }
+}
+
+/// 시작/정지 및 구간(lap) 측정이 가능한 경과 시간 측정기
+///
+/// 정지 중인 시간은 경과 시간에 포함하지 않으며, [TimerRegistry]에 기록하지 않는다.
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::timing_util::Stopwatch;
+///
+/// let mut stopwatch = Stopwatch::start_new();
+///
+/// let first = stopwatch.lap();
+/// let second = stopwatch.lap();
+/// let total = stopwatch.stop();
+///
+/// assert!(!stopwatch.is_running());
+/// assert_eq!(&[first, second], stopwatch.laps());
+/// assert!(first + second <= total);
+/// assert!(stopwatch.to_string().starts_with("elapsed="));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Stopwatch {
+    /// 실행 중일 경우 마지막 시작 시각
+    started: Option<Instant>,
+
+    /// 마지막 시작 이전까지 누적된 경과 시간
+    accumulated: Duration,
+
+    laps: Vec<Duration>,
+}
+
+impl Stopwatch {
+    /// 정지 상태의 [Stopwatch] 생성
+    pub fn new() -> Self {
+        Stopwatch::default()
+    }
+
+    /// 실행 상태의 [Stopwatch] 생성
+    pub fn start_new() -> Self {
+        let mut stopwatch = Stopwatch::new();
+
+        stopwatch.start();
+        stopwatch
+    }
+
+    /// 측정 시작. 이미 실행 중일 경우 무시한다.
+    pub fn start(&mut self) {
+        if self.started.is_none() {
+            self.started = Some(Instant::now());
+        }
+    }
+
+    /// 측정 정지
+    ///
+    /// # Return
+    ///
+    /// - 전체 경과 시간
+    pub fn stop(&mut self) -> Duration {
+        if let Some(started) = self.started.take() {
+            self.accumulated += started.elapsed();
+        }
+
+        self.accumulated
+    }
+
+    /// 경과 시간 및 구간 기록 초기화. 정지 상태가 된다.
+    pub fn reset(&mut self) {
+        *self = Stopwatch::new();
+    }
+
+    /// 경과 시간 및 구간 기록을 초기화하고 다시 시작
+    pub fn restart(&mut self) {
+        self.reset();
+        self.start();
+    }
+
+    /// 실행 중 여부
+    #[inline]
+    pub fn is_running(&self) -> bool {
+        self.started.is_some()
+    }
+
+    /// 전체 경과 시간 반환
+    pub fn elapsed(&self) -> Duration {
+        self.accumulated + self.started.map_or(Duration::ZERO, |v| v.elapsed())
+    }
+
+    /// 구간 기록
+    ///
+    /// # Return
+    ///
+    /// - 이전 구간(혹은 시작) 이후의 경과 시간
+    pub fn lap(&mut self) -> Duration {
+        let recorded: Duration = self.laps.iter().sum();
+        let lap = self.elapsed().saturating_sub(recorded);
+
+        self.laps.push(lap);
+
+        lap
+    }
+
+    /// 기록된 구간 목록
+    #[inline]
+    pub fn laps(&self) -> &[Duration] {
+        &self.laps
+    }
+}
+
+impl Display for Stopwatch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "elapsed={:?}", self.elapsed())?;
+
+        if !self.laps.is_empty() {
+            write!(f, " laps=[")?;
+
+            for (i, lap) in self.laps.iter().enumerate() {
+                if i > 0 {
+                    write!(f, ", ")?;
+                }
+
+                write!(f, "{:?}", lap)?;
+            }
+
+            write!(f, "]")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// label별 집계 결과
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimerStats {
+    /// 측정 대상 명칭
+    pub label: String,
+
+    /// 전체 측정 횟수
+    pub count: u64,
+
+    /// 전체 실행 시간 합계
+    pub total: Duration,
+
+    pub min: Duration,
+
+    pub max: Duration,
+
+    pub mean: Duration,
+
+    /// 50 백분위수(중앙값)
+    pub p50: Duration,
+
+    /// 90 백분위수
+    pub p90: Duration,
+
+    /// 95 백분위수
+    pub p95: Duration,
+
+    /// 99 백분위수
+    pub p99: Duration,
+}
+
+impl Display for TimerStats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}: count={} total={:?} min={:?} mean={:?} p50={:?} p90={:?} p95={:?} p99={:?} max={:?}",
+            self.label,
+            self.count,
+            self.total,
+            self.min,
+            self.mean,
+            self.p50,
+            self.p90,
+            self.p95,
+            self.p99,
+            self.max
+        )
+    }
+}
+
+/// label별 측정값
+#[derive(Debug, Default)]
+struct Samples {
+    count: u64,
+
+    total: Duration,
+
+    min: Duration,
+
+    max: Duration,
+
+    /// 최근 측정값(백분위수 계산용)
+    recent: VecDeque<Duration>,
+}
+
+/// Thread-safe 실행 시간 집계
+///
+/// 횟수, 합계, 최소/최대값은 전체 측정값 기준이며 백분위수는 label별 최근 `max_samples`개의 측정값으로
+/// 계산한다.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use cliff3_util::timing_util::TimerRegistry;
+///
+/// let registry = TimerRegistry::new();
+///
+/// for ms in 1..=100 {
+///     registry.record("query", Duration::from_millis(ms));
+/// }
+///
+/// let stats = registry.stats("query").unwrap();
+///
+/// assert_eq!(100, stats.count);
+/// assert_eq!(Duration::from_millis(50), stats.p50);
+/// assert_eq!(Duration::from_millis(99), stats.p99);
+/// ```
+#[derive(Debug)]
+pub struct TimerRegistry {
+    timers: Mutex<HashMap<String, Samples>>,
+
+    max_samples: usize,
+}
+
+impl Default for TimerRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TimerRegistry {
+    /// [TimerRegistry] 생성. label별 최대 보관 측정값 수는 [DEFAULT_MAX_SAMPLES]
+    pub fn new() -> Self {
+        Self::with_max_samples(DEFAULT_MAX_SAMPLES)
+    }
+
+    /// label별 최대 보관 측정값 수를 지정하여 생성. `0`일 경우 `1`로 처리
+    pub fn with_max_samples(max_samples: usize) -> Self {
+        TimerRegistry {
+            timers: Mutex::new(HashMap::new()),
+            max_samples: max_samples.max(1),
+        }
+    }
+
+    /// [time_it], [TimingGuard] 및 `#[timed]`에서 사용하는 전역 [TimerRegistry] 반환
+    pub fn global() -> &'static TimerRegistry {
+        &GLOBAL_REGISTRY
+    }
+
+    /// 측정값 기록
+    pub fn record(&self, label: &str, elapsed: Duration) {
+        let mut timers = self.timers.lock().unwrap_or_else(|e| e.into_inner());
+        let samples = timers.entry(label.to_owned()).or_default();
+
+        if samples.count == 0 || elapsed < samples.min {
+            samples.min = elapsed;
+        }
+
+        samples.max = samples.max.max(elapsed);
+        samples.count += 1;
+        samples.total += elapsed;
+
+        if samples.recent.len() == self.max_samples {
+            samples.recent.pop_front();
+        }
+
+        samples.recent.push_back(elapsed);
+    }
+
+    /// 작업 실행 시간을 측정하여 기록
+    ///
+    /// # Return
+    ///
+    /// - 작업 결과
+    pub fn time<T, F>(&self, label: &str, f: F) -> T
+    where
+        F: FnOnce() -> T,
+    {
+        let start = Instant::now();
+        let result = f();
+
+        self.record(label, start.elapsed());
+
+        result
+    }
+
+    /// label별 집계 결과 반환
+    pub fn stats(&self, label: &str) -> Option<TimerStats> {
+        let timers = self.timers.lock().unwrap_or_else(|e| e.into_inner());
+
+        timers.get(label).map(|s| to_stats(label, s))
+    }
+
+    /// 전체 집계 결과 반환(label 순 정렬)
+    pub fn report(&self) -> Vec<TimerStats> {
+        let timers = self.timers.lock().unwrap_or_else(|e| e.into_inner());
+        let mut report: Vec<TimerStats> = timers.iter().map(|(k, v)| to_stats(k, v)).collect();
+
+        report.sort_by(|a, b| a.label.cmp(&b.label));
+
+        report
+    }
+
+    /// 전체 측정값 삭제
+    pub fn reset(&self) {
+        self.timers
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clear();
+    }
+}
+
+fn to_stats(label: &str, samples: &Samples) -> TimerStats {
+    let mut sorted: Vec<Duration> = samples.recent.iter().copied().collect();
+
+    sorted.sort_unstable();
+
+    TimerStats {
+        label: label.to_owned(),
+        count: samples.count,
+        total: samples.total,
+        min: samples.min,
+        max: samples.max,
+        mean: match samples.count {
+            0 => Duration::ZERO,
+            n => Duration::from_nanos((samples.total.as_nanos() / n as u128) as u64),
+        },
+        p50: percentile(&sorted, 50.0),
+        p90: percentile(&sorted, 90.0),
+        p95: percentile(&sorted, 95.0),
+        p99: percentile(&sorted, 99.0),
+    }
+}
+
+/// nearest-rank 방식 백분위수
+fn percentile(sorted: &[Duration], p: f64) -> Duration {
+    if sorted.is_empty() {
+        return Duration::ZERO;
+    }
+
+    let rank = (p / 100.0 * sorted.len() as f64).ceil() as usize;
+
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn percentile_test() {
+        let sorted: Vec<Duration> = (1..=10).map(Duration::from_millis).collect();
+
+        assert_eq!(Duration::ZERO, percentile(&[], 50.0));
+        assert_eq!(Duration::from_millis(5), percentile(&sorted, 50.0));
+        assert_eq!(Duration::from_millis(9), percentile(&sorted, 90.0));
+        assert_eq!(Duration::from_millis(10), percentile(&sorted, 99.0));
+        assert_eq!(Duration::from_millis(1), percentile(&sorted, 0.0));
+    }
+
+    #[test]
+    fn registry_test() {
+        let registry = TimerRegistry::with_max_samples(3);
+
+        for ms in [10, 40, 20, 30] {
+            registry.record("a", Duration::from_millis(ms));
+        }
+
+        registry.time("b", || ());
+
+        let stats = registry.stats("a").unwrap();
+
+        assert_eq!(4, stats.count);
+        assert_eq!(Duration::from_millis(10), stats.min);
+        assert_eq!(Duration::from_millis(40), stats.max);
+        assert_eq!(Duration::from_millis(25), stats.mean);
+        // 최근 3개(40, 20, 30) 기준
+        assert_eq!(Duration::from_millis(30), stats.p50);
+        assert_eq!(
+            vec!["a", "b"],
+            registry
+                .report()
+                .iter()
+                .map(|s| s.label.as_str())
+                .collect::<Vec<&str>>()
+        );
+        assert!(stats.to_string().starts_with("a: count=4"));
+
+        registry.reset();
+
+        assert!(registry.stats("a").is_none());
+    }
+
+    #[test]
+    fn stopwatch_test() {
+        let pause = Duration::from_millis(5);
+        let mut stopwatch = Stopwatch::new();
+
+        assert!(!stopwatch.is_running());
+        assert_eq!(Duration::ZERO, stopwatch.elapsed());
+        assert_eq!("elapsed=0ns", stopwatch.to_string());
+
+        stopwatch.start();
+        std::thread::sleep(pause);
+
+        let first = stopwatch.lap();
+
+        assert!(first >= pause);
+
+        let stopped = stopwatch.stop();
+
+        // 정지 중인 시간은 제외
+        std::thread::sleep(pause);
+
+        assert_eq!(stopped, stopwatch.elapsed());
+
+        stopwatch.start();
+        stopwatch.start();
+        std::thread::sleep(pause);
+
+        let second = stopwatch.lap();
+        let total = stopwatch.stop();
+
+        assert!(second >= pause);
+        assert_eq!(vec![first, second], stopwatch.laps());
+        assert!(first + second <= total);
+        assert!(stopwatch.to_string().contains(" laps=["));
+
+        stopwatch.restart();
+
+        assert!(stopwatch.is_running());
+        assert!(stopwatch.laps().is_empty());
+
+        stopwatch.reset();
+
+        assert!(!stopwatch.is_running());
+        assert_eq!(Duration::ZERO, stopwatch.elapsed());
+    }
+
+    #[test]
+    fn guard_test() {
+        let result: Result<(), ()> = (|| {
+            let _guard = TimingGuard::new("timing_util.guard_test");
+
+            Err(())?;
+
+            Ok(())
+        })();
+
+        assert!(result.is_err());
+        assert_eq!(
+            1,
+            TimerRegistry::global()
+                .stats("timing_util.guard_test")
+                .unwrap()
+                .count
+        );
+    }
+}
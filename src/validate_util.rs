@@ -0,0 +1,1457 @@
+//! 식별번호 유효성 검사 관련 함수 모음
+//!
+//! 각 유효성 검사 함수는 입력값의 공백 및 `-`를 제거하여 검사하며, 성공시 정규화된 형태(`normalized`)와
+//! 마스킹된 형태(`masked`)를 제공하는 구조체를 반환한다. 도서 및 상품 번호(ISBN, EAN-13, UPC-A) 및
+//! 사업자등록번호([validate_brn]) 검사 함수는 검증 번호(check digit)를 확인하여 정규화된 문자열을 반환한다.
+//! [validate_rrn]은 생년월일, 성별 및 외국인 여부를 제공하는 [ResidentNumber]를 반환하며, 카드번호
+//! ([validate_card_number])는 Luhn 검증 번호를 확인하여 숫자만으로 구성된 번호를 반환한다.
+
+use alloc::borrow::ToOwned;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::{Display, Formatter};
+
+use crate::error::{InvalidArgumentError, LibError};
+use crate::string_util::mask::{mask_card, DEFAULT_MASK_CHAR};
+
+/// 공백 및 `-` 제거
+#[inline]
+fn strip_separators(target: &str) -> String {
+    target
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != '-')
+        .collect()
+}
+
+// ValidationError ---------------------------------------------------------------------------------
+/// 유효성 검사 실패 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum ValidationErrorKind {
+    /// 자릿수 혹은 허용되지 않는 문자 등 형식 불일치
+    Format,
+
+    /// 검증 번호(check digit) 불일치
+    Checksum,
+}
+
+/// 유효성 검사 실패 오류
+///
+/// 실패 종류([ValidationErrorKind])를 구분해야 하는 검사 함수에서 사용하며 [InvalidArgumentError]로 변환할 수
+/// 있다.
+#[derive(PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ValidationError {
+    kind: ValidationErrorKind,
+
+    message: String,
+}
+
+impl ValidationError {
+    pub fn new(kind: ValidationErrorKind, message: &str) -> Self {
+        ValidationError {
+            kind,
+            message: message.to_owned(),
+        }
+    }
+
+    /// 실패 종류 반환
+    #[inline]
+    pub fn kind(&self) -> ValidationErrorKind {
+        self.kind
+    }
+}
+
+impl Display for ValidationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Validation error({:?}).", self.kind)
+    }
+}
+
+impl LibError for ValidationError {
+    fn get_message(&self) -> &str {
+        self.message.as_str()
+    }
+
+    fn get_type_name_from_instance(&self) -> &str {
+        core::any::type_name::<ValidationError>()
+    }
+}
+
+impl From<ValidationError> for InvalidArgumentError {
+    fn from(value: ValidationError) -> Self {
+        InvalidArgumentError::new(value.get_message())
+    }
+}
+
+// 자동차 등록번호 -----------------------------------------------------------------------------------
+/// 자동차 등록번호판 지역 명칭
+const PLATE_REGIONS: &[&str] = &[
+    "서울", "부산", "대구", "인천", "광주", "대전", "울산", "세종", "경기", "강원", "충북", "충남",
+    "전북", "전남", "경북", "경남", "제주",
+];
+
+/// 자동차 용도
+///
+/// # Link
+///
+/// - [VehiclePlate::usage]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PlateUsage {
+    /// 비사업용(자가용)
+    Private,
+
+    /// 사업용(택시, 버스 등 `아`, `바`, `사`, `자`)
+    Commercial,
+
+    /// 대여 사업용(렌터카 `하`, `허`, `호`)
+    Rental,
+
+    /// 택배 사업용(`배`)
+    Delivery,
+}
+
+impl PlateUsage {
+    /// 등록번호의 한글 문자로 용도 반환
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '가' | '나' | '다' | '라' | '마' | '거' | '너' | '더' | '러' | '머' | '버' | '서'
+            | '어' | '저' | '고' | '노' | '도' | '로' | '모' | '보' | '소' | '오' | '조' | '구'
+            | '누' | '두' | '루' | '무' | '부' | '수' | '우' | '주' => {
+                Some(PlateUsage::Private)
+            }
+            '아' | '바' | '사' | '자' => Some(PlateUsage::Commercial),
+            '하' | '허' | '호' => Some(PlateUsage::Rental),
+            '배' => Some(PlateUsage::Delivery),
+            _ => None,
+        }
+    }
+}
+
+/// 자동차 등록번호
+///
+/// # Link
+///
+/// - [validate_plate_kr]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VehiclePlate {
+    /// 지역 명칭(구형 번호판 혹은 사업용 번호판)
+    region: Option<String>,
+
+    /// 차종 번호(2자리 혹은 3자리)
+    class_number: String,
+
+    /// 용도 문자
+    usage_char: char,
+
+    /// 일련 번호(4자리)
+    serial: String,
+}
+
+impl VehiclePlate {
+    /// 지역 명칭 반환
+    #[inline]
+    pub fn region(&self) -> Option<&str> {
+        self.region.as_deref()
+    }
+
+    /// 차종 번호(2자리 혹은 3자리) 반환
+    #[inline]
+    pub fn class_number(&self) -> &str {
+        self.class_number.as_str()
+    }
+
+    /// 용도 문자 반환
+    #[inline]
+    pub fn usage_char(&self) -> char {
+        self.usage_char
+    }
+
+    /// 일련 번호 반환
+    #[inline]
+    pub fn serial(&self) -> &str {
+        self.serial.as_str()
+    }
+
+    /// 용도 반환
+    pub fn usage(&self) -> PlateUsage {
+        PlateUsage::from_char(self.usage_char).unwrap_or(PlateUsage::Private)
+    }
+
+    /// 정규화된 등록번호 반환 (e.g. `123가 4567`, `서울 12바 3456`)
+    pub fn normalized(&self) -> String {
+        self.format(self.serial.as_str())
+    }
+
+    /// 일련 번호 앞 2자리를 마스킹한 등록번호 반환 (e.g. `123가 **67`)
+    pub fn masked(&self) -> String {
+        let serial: String = self
+            .serial
+            .chars()
+            .enumerate()
+            .map(|(i, c)| if i < 2 { DEFAULT_MASK_CHAR } else { c })
+            .collect();
+
+        self.format(serial.as_str())
+    }
+
+    fn format(&self, serial: &str) -> String {
+        match &self.region {
+            None => format!("{}{} {}", self.class_number, self.usage_char, serial),
+            Some(r) => format!("{} {}{} {}", r, self.class_number, self.usage_char, serial),
+        }
+    }
+}
+
+impl Display for VehiclePlate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.normalized())
+    }
+}
+
+/// 자동차 등록번호 유효성 검사
+///
+/// 다음 형식을 허용하며 공백 및 `-`는 무시한다.
+///
+/// - `12가3456` - 2자리 차종 번호
+/// - `123가4567` - 3자리 차종 번호(2019년 이후 신규 번호판)
+/// - `서울12바3456` - 지역 명칭 포함
+///
+/// # Arguments
+///
+/// - `target` - 검사 대상 등록번호
+///
+/// # Return
+///
+/// - 검사 결과 `Result<VehiclePlate, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 형식 불일치, 지역 명칭 혹은 용도 문자 오류
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::validate_util::{validate_plate_kr, PlateUsage};
+///
+/// let plate = validate_plate_kr("123가4567").unwrap();
+///
+/// assert_eq!("123가 4567", plate.normalized());
+/// assert_eq!("123가 **67", plate.masked());
+/// assert_eq!(PlateUsage::Private, plate.usage());
+///
+/// let plate = validate_plate_kr("서울 12 바 3456").unwrap();
+///
+/// assert_eq!(Some("서울"), plate.region());
+/// assert_eq!(PlateUsage::Commercial, plate.usage());
+/// assert_eq!("서울 12바 3456", plate.normalized());
+///
+/// assert!(validate_plate_kr("1가3456").is_err());
+/// assert!(validate_plate_kr("12갑3456").is_err());
+/// ```
+pub fn validate_plate_kr(target: &str) -> Result<VehiclePlate, InvalidArgumentError> {
+    let stripped = strip_separators(target);
+
+    if stripped.is_empty() {
+        return Err(InvalidArgumentError::from(
+            "자동차 등록번호가 누락되었습니다.",
+        ));
+    }
+
+    let region = PLATE_REGIONS
+        .iter()
+        .find(|r| stripped.starts_with(*r))
+        .map(|r| r.to_string());
+    let rest: Vec<char> = match &region {
+        None => stripped.chars().collect(),
+        Some(r) => stripped[r.len()..].chars().collect(),
+    };
+    let class_length = rest.iter().take_while(|c| c.is_ascii_digit()).count();
+
+    if !(2..=3).contains(&class_length) || rest.len() != class_length + 5 {
+        return Err(InvalidArgumentError::from(
+            "자동차 등록번호 형식이 올바르지 않습니다.",
+        ));
+    }
+
+    let usage_char = rest[class_length];
+    let serial: String = rest[class_length + 1..].iter().collect();
+
+    if PlateUsage::from_char(usage_char).is_none() {
+        return Err(InvalidArgumentError::from(
+            "자동차 등록번호의 용도 문자가 올바르지 않습니다.",
+        ));
+    }
+
+    if !serial.chars().all(|c| c.is_ascii_digit()) {
+        return Err(InvalidArgumentError::from(
+            "자동차 등록번호 형식이 올바르지 않습니다.",
+        ));
+    }
+
+    Ok(VehiclePlate {
+        region,
+        class_number: rest[..class_length].iter().collect(),
+        usage_char,
+        serial,
+    })
+}
+
+// 운전면허번호 -------------------------------------------------------------------------------------
+/// 운전면허번호 지역 코드 및 명칭
+const LICENSE_REGIONS: &[(u8, &str)] = &[
+    (11, "서울"),
+    (12, "부산"),
+    (13, "경기"),
+    (14, "강원"),
+    (15, "충북"),
+    (16, "충남"),
+    (17, "전북"),
+    (18, "전남"),
+    (19, "경북"),
+    (20, "경남"),
+    (21, "제주"),
+    (22, "대구"),
+    (23, "인천"),
+    (24, "광주"),
+    (25, "대전"),
+    (26, "울산"),
+    (28, "경기북부"),
+];
+
+/// 운전면허번호
+///
+/// # Link
+///
+/// - [validate_driver_license_kr]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DriverLicense {
+    /// 지역 코드
+    region_code: u8,
+
+    /// 발급 연도(2자리)
+    year: String,
+
+    /// 일련 번호(6자리)
+    serial: String,
+
+    /// 검증 번호(2자리)
+    check: String,
+}
+
+impl DriverLicense {
+    /// 지역 코드 반환
+    #[inline]
+    pub fn region_code(&self) -> u8 {
+        self.region_code
+    }
+
+    /// 지역 명칭 반환
+    pub fn region_name(&self) -> &'static str {
+        LICENSE_REGIONS
+            .iter()
+            .find(|(code, _)| *code == self.region_code)
+            .map(|(_, name)| *name)
+            .unwrap_or_default()
+    }
+
+    /// 발급 연도(2자리) 반환
+    #[inline]
+    pub fn year(&self) -> &str {
+        self.year.as_str()
+    }
+
+    /// 일련 번호 반환
+    #[inline]
+    pub fn serial(&self) -> &str {
+        self.serial.as_str()
+    }
+
+    /// 정규화된 운전면허번호 반환 (e.g. `11-17-123456-01`)
+    pub fn normalized(&self) -> String {
+        format!(
+            "{:02}-{}-{}-{}",
+            self.region_code, self.year, self.serial, self.check
+        )
+    }
+
+    /// 일련 번호를 마스킹한 운전면허번호 반환 (e.g. `11-17-******-01`)
+    pub fn masked(&self) -> String {
+        let serial: String = self.serial.chars().map(|_| DEFAULT_MASK_CHAR).collect();
+
+        format!(
+            "{:02}-{}-{}-{}",
+            self.region_code, self.year, serial, self.check
+        )
+    }
+}
+
+impl Display for DriverLicense {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.normalized())
+    }
+}
+
+/// 운전면허번호 유효성 검사
+///
+/// `지역 코드(2) - 연도(2) - 일련 번호(6) - 검증 번호(2)` 형식이며, 지역 코드 대신 지역 명칭
+/// (e.g. `서울 17-123456-01`)으로 표기된 형식도 허용한다. 공백 및 `-`는 무시한다.
+///
+/// 검증 번호 산출 방식은 공개되어 있지 않으므로 형식 및 지역 코드만 검사한다.
+///
+/// # Arguments
+///
+/// - `target` - 검사 대상 운전면허번호
+///
+/// # Return
+///
+/// - 검사 결과 `Result<DriverLicense, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 형식 불일치 혹은 알 수 없는 지역 코드
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::validate_util::validate_driver_license_kr;
+///
+/// let license = validate_driver_license_kr("111712345601").unwrap();
+///
+/// assert_eq!("11-17-123456-01", license.normalized());
+/// assert_eq!("11-17-******-01", license.masked());
+/// assert_eq!("서울", license.region_name());
+///
+/// let license = validate_driver_license_kr("부산 17-123456-01").unwrap();
+///
+/// assert_eq!(12, license.region_code());
+/// assert!(validate_driver_license_kr("99-17-123456-01").is_err());
+/// ```
+pub fn validate_driver_license_kr(target: &str) -> Result<DriverLicense, InvalidArgumentError> {
+    let stripped = strip_separators(target);
+
+    if stripped.is_empty() {
+        return Err(InvalidArgumentError::from("운전면허번호가 누락되었습니다."));
+    }
+
+    // 지역 명칭 표기일 경우 지역 코드로 변환
+    let by_name = LICENSE_REGIONS
+        .iter()
+        .filter(|(_, name)| stripped.starts_with(name))
+        .max_by_key(|(_, name)| name.len());
+    let (region_code, digits) = match by_name {
+        Some((code, name)) => (Some(*code), &stripped[name.len()..]),
+        None => (None, stripped.as_str()),
+    };
+    let expected_length = if region_code.is_some() { 10 } else { 12 };
+
+    if digits.len() != expected_length || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return Err(InvalidArgumentError::from(
+            "운전면허번호 형식이 올바르지 않습니다.",
+        ));
+    }
+
+    let (region_code, digits) = match region_code {
+        Some(v) => (v, digits),
+        None => (digits[..2].parse::<u8>().unwrap_or_default(), &digits[2..]),
+    };
+
+    if !LICENSE_REGIONS.iter().any(|(code, _)| *code == region_code) {
+        return Err(InvalidArgumentError::from(
+            "운전면허번호의 지역 코드가 올바르지 않습니다.",
+        ));
+    }
+
+    Ok(DriverLicense {
+        region_code,
+        year: digits[..2].to_owned(),
+        serial: digits[2..8].to_owned(),
+        check: digits[8..].to_owned(),
+    })
+}
+
+// 도서/상품 번호 ---------------------------------------------------------------------------------
+/// 검증 번호(check digit) 산출 방식
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CheckDigitKind {
+    /// ISBN-10 (9자리 + 검증 번호, `0`-`9` 혹은 `X`)
+    Isbn10,
+
+    /// ISBN-13 (12자리 + 검증 번호), EAN-13과 동일한 방식
+    Isbn13,
+
+    /// EAN-13 (12자리 + 검증 번호)
+    Ean13,
+
+    /// UPC-A (11자리 + 검증 번호)
+    Upc,
+}
+
+impl CheckDigitKind {
+    /// 검증 번호를 제외한 자릿수
+    fn body_length(&self) -> usize {
+        match self {
+            CheckDigitKind::Isbn10 => 9,
+            CheckDigitKind::Isbn13 | CheckDigitKind::Ean13 => 12,
+            CheckDigitKind::Upc => 11,
+        }
+    }
+}
+
+/// 검증 번호 산출
+///
+/// # Arguments
+///
+/// - `kind` - 산출 방식
+/// - `digits` - 검증 번호를 제외한 번호. 공백 및 `-`는 무시한다.
+///
+/// # Return
+///
+/// - 검증 번호. ISBN-10의 경우 `X`(10)일 수 있음
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 숫자 이외의 문자 포함 혹은 자릿수 불일치
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::validate_util::{compute_check_digit, CheckDigitKind};
+///
+/// assert_eq!(Ok('7'), compute_check_digit(CheckDigitKind::Isbn13, "978-0-306-40615"));
+/// assert_eq!(Ok('2'), compute_check_digit(CheckDigitKind::Isbn10, "0-306-40615"));
+/// assert_eq!(Ok('X'), compute_check_digit(CheckDigitKind::Isbn10, "0-8044-2957"));
+/// assert_eq!(Ok('2'), compute_check_digit(CheckDigitKind::Upc, "03600029145"));
+/// assert!(compute_check_digit(CheckDigitKind::Ean13, "12345").is_err());
+/// ```
+pub fn compute_check_digit(
+    kind: CheckDigitKind,
+    digits: &str,
+) -> Result<char, InvalidArgumentError> {
+    let stripped = strip_separators(digits);
+
+    if stripped.len() != kind.body_length() || !stripped.chars().all(|c| c.is_ascii_digit()) {
+        return Err(InvalidArgumentError::new(&format!(
+            "검증 번호를 제외한 {}자리 숫자이어야 합니다.",
+            kind.body_length()
+        )));
+    }
+
+    let values = stripped.bytes().map(|b| (b - b'0') as u32);
+    let check = match kind {
+        CheckDigitKind::Isbn10 => {
+            let sum: u32 = values.zip((2..=10).rev()).map(|(v, w)| v * w).sum();
+
+            (11 - sum % 11) % 11
+        }
+        CheckDigitKind::Isbn13 | CheckDigitKind::Ean13 | CheckDigitKind::Upc => {
+            // 검증 번호 바로 앞 자리부터 3, 1 가중치 반복
+            let sum: u32 = values
+                .rev()
+                .enumerate()
+                .map(|(i, v)| if i % 2 == 0 { v * 3 } else { v })
+                .sum();
+
+            (10 - sum % 10) % 10
+        }
+    };
+
+    Ok(match check {
+        10 => 'X',
+        v => char::from_digit(v, 10).unwrap(),
+    })
+}
+
+/// 검증 번호 포함 번호 검사
+fn validate_check_digit(
+    kind: CheckDigitKind,
+    target: &str,
+    name: &str,
+) -> Result<String, InvalidArgumentError> {
+    let stripped = strip_separators(target).to_ascii_uppercase();
+
+    // byte 단위로 분리하므로 ASCII가 아닌 문자는 미리 제외
+    if !stripped.is_ascii() || stripped.len() != kind.body_length() + 1 {
+        return Err(InvalidArgumentError::new(&format!(
+            "{} 형식이 올바르지 않습니다.",
+            name
+        )));
+    }
+
+    let (body, check) = stripped.split_at(kind.body_length());
+    let expected = compute_check_digit(kind, body)
+        .map_err(|_| InvalidArgumentError::new(&format!("{} 형식이 올바르지 않습니다.", name)))?;
+
+    if !check.starts_with(expected) {
+        return Err(InvalidArgumentError::new(&format!(
+            "{}의 검증 번호가 올바르지 않습니다.",
+            name
+        )));
+    }
+
+    Ok(stripped)
+}
+
+/// ISBN-10 유효성 검사
+///
+/// 공백 및 `-`는 무시하며 검증 번호 `x`는 대소문자를 구분하지 않는다.
+///
+/// # Return
+///
+/// - 구분자를 제거한 10자리 번호
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 형식 불일치 혹은 검증 번호 불일치
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::validate_util::validate_isbn10;
+///
+/// assert_eq!("0306406152", validate_isbn10("0-306-40615-2").unwrap());
+/// assert_eq!("080442957X", validate_isbn10("0-8044-2957-x").unwrap());
+/// assert!(validate_isbn10("0-306-40615-3").is_err());
+/// ```
+pub fn validate_isbn10(target: &str) -> Result<String, InvalidArgumentError> {
+    validate_check_digit(CheckDigitKind::Isbn10, target, "ISBN-10")
+}
+
+/// ISBN-13 유효성 검사
+///
+/// EAN-13 검사와 동일하며 `978` 혹은 `979`로 시작해야 한다. 공백 및 `-`는 무시한다.
+///
+/// # Return
+///
+/// - 구분자를 제거한 13자리 번호
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 형식 불일치, 접두어 불일치 혹은 검증 번호 불일치
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::validate_util::validate_isbn13;
+///
+/// assert_eq!("9780306406157", validate_isbn13("978-0-306-40615-7").unwrap());
+/// assert!(validate_isbn13("978-0-306-40615-8").is_err());
+/// ```
+pub fn validate_isbn13(target: &str) -> Result<String, InvalidArgumentError> {
+    let result = validate_check_digit(CheckDigitKind::Isbn13, target, "ISBN-13")?;
+
+    if !result.starts_with("978") && !result.starts_with("979") {
+        return Err(InvalidArgumentError::from(
+            "ISBN-13은 978 혹은 979로 시작해야 합니다.",
+        ));
+    }
+
+    Ok(result)
+}
+
+/// EAN-13 유효성 검사
+///
+/// # Return
+///
+/// - 구분자를 제거한 13자리 번호
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 형식 불일치 혹은 검증 번호 불일치
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::validate_util::validate_ean13;
+///
+/// assert_eq!("8801234567893", validate_ean13("880 1234 56789 3").unwrap());
+/// assert!(validate_ean13("8801234567890").is_err());
+/// ```
+pub fn validate_ean13(target: &str) -> Result<String, InvalidArgumentError> {
+    validate_check_digit(CheckDigitKind::Ean13, target, "EAN-13")
+}
+
+/// UPC-A 유효성 검사
+///
+/// # Return
+///
+/// - 구분자를 제거한 12자리 번호
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 형식 불일치 혹은 검증 번호 불일치
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::validate_util::validate_upc;
+///
+/// assert_eq!("036000291452", validate_upc("0 36000 29145 2").unwrap());
+/// assert!(validate_upc("036000291453").is_err());
+/// ```
+pub fn validate_upc(target: &str) -> Result<String, InvalidArgumentError> {
+    validate_check_digit(CheckDigitKind::Upc, target, "UPC")
+}
+
+// 주민등록번호/사업자등록번호/카드번호 --------------------------------------------------------------
+/// 주민등록번호 검증 번호 가중치
+const RRN_WEIGHTS: [u32; 12] = [2, 3, 4, 5, 6, 7, 8, 9, 2, 3, 4, 5];
+
+/// 사업자등록번호 검증 번호 가중치
+const BUSINESS_NUMBER_WEIGHTS: [u32; 9] = [1, 3, 7, 1, 3, 7, 1, 3, 5];
+
+/// 숫자 문자열을 자릿수 값 목록으로 변환. 숫자 이외의 문자 포함시 `None`
+fn to_digits(target: &str) -> Option<Vec<u32>> {
+    target.chars().map(|c| c.to_digit(10)).collect()
+}
+
+/// 주민등록번호 앞 12자리의 검증 번호
+pub(crate) fn rrn_check_digit(digits: &[u32]) -> u32 {
+    let sum: u32 = digits.iter().zip(RRN_WEIGHTS).map(|(d, w)| d * w).sum();
+
+    (11 - sum % 11) % 10
+}
+
+/// 사업자등록번호 앞 9자리의 검증 번호
+pub(crate) fn business_number_check_digit(digits: &[u32]) -> u32 {
+    let sum: u32 = digits
+        .iter()
+        .zip(BUSINESS_NUMBER_WEIGHTS)
+        .map(|(d, w)| d * w)
+        .sum::<u32>()
+        + digits[8] * 5 / 10;
+
+    (10 - sum % 10) % 10
+}
+
+/// Luhn 검증 번호(검증 번호를 제외한 자릿수 대상)
+pub(crate) fn luhn_check_digit(digits: &[u32]) -> u32 {
+    let sum: u32 = digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(i, &d)| match (i % 2 == 0, d * 2) {
+            (true, v) if v > 9 => v - 9,
+            (true, v) => v,
+            (false, _) => d,
+        })
+        .sum();
+
+    (10 - sum % 10) % 10
+}
+
+/// 자릿수 값 목록을 숫자 문자열로 변환
+#[cfg(any(feature = "test-util", feature = "proptest"))]
+pub(crate) fn join_digits(digits: &[u32]) -> String {
+    digits
+        .iter()
+        .filter_map(|&d| char::from_digit(d, 10))
+        .collect()
+}
+
+/// 생년월일, 성별 및 일련번호(5자리)로 검증 번호를 포함한 주민등록번호(`YYMMDD-NNNNNNN`) 생성
+///
+/// 성별 자리는 출생 연도에 따라 `1`, `2`(1900년대) 혹은 `3`, `4`(2000년대)를 사용한다.
+#[cfg(any(feature = "test-util", feature = "proptest"))]
+pub(crate) fn assemble_rrn(birth_date: (i32, u32, u32), female: bool, serial: &[u32]) -> String {
+    let (year, month, day) = birth_date;
+    let gender = if year < 2000 { 1 } else { 3 } + female as u32;
+    let year = year.rem_euclid(100) as u32;
+    let mut digits = vec![
+        year / 10,
+        year % 10,
+        month / 10,
+        month % 10,
+        day / 10,
+        day % 10,
+    ];
+
+    digits.push(gender);
+    digits.extend_from_slice(serial);
+    digits.push(rrn_check_digit(&digits));
+
+    let joined = join_digits(&digits);
+
+    format!("{}-{}", &joined[..6], &joined[6..])
+}
+
+/// 앞 9자리로 검증 번호를 포함한 사업자등록번호(`NNN-NN-NNNNN`) 생성
+#[cfg(any(feature = "test-util", feature = "proptest"))]
+pub(crate) fn assemble_business_number(digits: &[u32]) -> String {
+    let mut digits = digits.to_vec();
+
+    digits.push(business_number_check_digit(&digits));
+
+    let joined = join_digits(&digits);
+
+    format!("{}-{}-{}", &joined[..3], &joined[3..5], &joined[5..])
+}
+
+/// 성별
+///
+/// # Link
+///
+/// - [ResidentNumber::gender]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Gender {
+    /// 남성(성별 자리 `1`, `3`, `5`, `7`, `9`)
+    Male,
+
+    /// 여성(성별 자리 `2`, `4`, `6`, `8`, `0`)
+    Female,
+}
+
+/// 주민등록번호(외국인등록번호)
+///
+/// # Link
+///
+/// - [validate_rrn]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ResidentNumber {
+    /// 구분자를 제거한 번호(13자리)
+    digits: String,
+
+    /// 출생 연도(4자리)
+    birth_year: i32,
+
+    /// 출생 월
+    birth_month: u32,
+
+    /// 출생 일
+    birth_day: u32,
+
+    /// 성별
+    gender: Gender,
+
+    /// 외국인 여부(성별 자리 `5`-`8`)
+    foreigner: bool,
+}
+
+impl ResidentNumber {
+    /// 생년월일 (연도(4자리), 월, 일) 반환
+    #[inline]
+    pub fn birth_date(&self) -> (i32, u32, u32) {
+        (self.birth_year, self.birth_month, self.birth_day)
+    }
+
+    /// 성별 반환
+    #[inline]
+    pub fn gender(&self) -> Gender {
+        self.gender
+    }
+
+    /// 외국인등록번호 여부 반환
+    #[inline]
+    pub fn is_foreigner(&self) -> bool {
+        self.foreigner
+    }
+
+    /// 정규화된 주민등록번호 반환 (e.g. `900101-1234568`)
+    pub fn normalized(&self) -> String {
+        format!("{}-{}", &self.digits[..6], &self.digits[6..])
+    }
+
+    /// 생년월일과 성별 자리를 제외하고 마스킹한 주민등록번호 반환 (e.g. `900101-1******`)
+    pub fn masked(&self) -> String {
+        crate::string_util::mask::mask_rrn(&self.normalized())
+    }
+}
+
+impl Display for ResidentNumber {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.normalized())
+    }
+}
+
+/// 주민등록번호 유효성 검사
+///
+/// 생년월일, 성별 자리(`1`-`8`, `9`, `0`) 및 검증 번호를 검사하며 `-` 포함 여부와 관계없이 13자리 숫자를
+/// 허용한다. 성별 자리에 따라 출생 연도(`1`, `2`, `5`, `6`은 1900년대, `3`, `4`, `7`, `8`은 2000년대, `9`,
+/// `0`은 1800년대)와 외국인 여부(`5`-`8`)를 판단한다. 2020년 10월 이후 발급된 번호는 검증 번호 규칙을 따르지
+/// 않으므로 이 함수로 검사할 수 없다.
+///
+/// # Arguments
+///
+/// - `target` - 검사 대상 주민등록번호
+///
+/// # Return
+///
+/// - 검사 결과 `Result<ResidentNumber, InvalidArgumentError>`
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 형식 불일치, 잘못된 생년월일 혹은 검증 번호 불일치
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::validate_util::{validate_rrn, Gender};
+///
+/// let rrn = validate_rrn("900101-1234568").unwrap();
+///
+/// assert_eq!((1990, 1, 1), rrn.birth_date());
+/// assert_eq!(Gender::Male, rrn.gender());
+/// assert!(!rrn.is_foreigner());
+/// assert_eq!("900101-1******", rrn.masked());
+///
+/// let rrn = validate_rrn("0002294000004").unwrap();
+///
+/// assert_eq!((2000, 2, 29), rrn.birth_date());
+/// assert_eq!(Gender::Female, rrn.gender());
+/// assert!(validate_rrn("900101-1234567").is_err());
+/// ```
+pub fn validate_rrn(target: &str) -> Result<ResidentNumber, InvalidArgumentError> {
+    let stripped = strip_separators(target);
+    let digits = match to_digits(&stripped) {
+        Some(v) if v.len() == 13 => v,
+        _ => {
+            return Err(InvalidArgumentError::from(
+                "주민등록번호 형식이 올바르지 않습니다.",
+            ))
+        }
+    };
+    let century = match digits[6] {
+        1 | 2 | 5 | 6 => 1900,
+        3 | 4 | 7 | 8 => 2000,
+        _ => 1800,
+    };
+    let birth_year = century + (digits[0] * 10 + digits[1]) as i32;
+    let birth_month = digits[2] * 10 + digits[3];
+    let birth_day = digits[4] * 10 + digits[5];
+
+    if !is_valid_date(birth_year, birth_month, birth_day) {
+        return Err(InvalidArgumentError::from(
+            "주민등록번호의 생년월일이 올바르지 않습니다.",
+        ));
+    }
+
+    if rrn_check_digit(&digits[..12]) != digits[12] {
+        return Err(InvalidArgumentError::from(
+            "주민등록번호의 검증 번호가 올바르지 않습니다.",
+        ));
+    }
+
+    Ok(ResidentNumber {
+        digits: stripped,
+        birth_year,
+        birth_month,
+        birth_day,
+        gender: if digits[6] % 2 == 1 {
+            Gender::Male
+        } else {
+            Gender::Female
+        },
+        foreigner: (5..=8).contains(&digits[6]),
+    })
+}
+
+/// 존재하는 날짜 여부
+fn is_valid_date(year: i32, month: u32, day: u32) -> bool {
+    let leap = (year % 4 == 0 && year % 100 != 0) || year % 400 == 0;
+    let last_day = match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if leap => 29,
+        2 => 28,
+        _ => return false,
+    };
+
+    (1..=last_day).contains(&day)
+}
+
+/// 사업자등록번호 유효성 검사
+///
+/// 국세청 검증 번호 산출 방식(가중치 `1, 3, 7, 1, 3, 7, 1, 3, 5`)으로 마지막 자리를 검사하며 `-` 포함 여부와
+/// 관계없이 10자리 숫자를 허용한다.
+///
+/// # Arguments
+///
+/// - `target` - 검사 대상 사업자등록번호
+///
+/// # Return
+///
+/// - `NNN-NN-NNNNN` 형식으로 정규화된 번호 `Result<String, ValidationError>`
+///
+/// # Errors
+///
+/// - [ValidationError] - 형식 불일치([ValidationErrorKind::Format]) 혹은 검증 번호
+///   불일치([ValidationErrorKind::Checksum])
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::validate_util::{validate_brn, ValidationErrorKind};
+///
+/// assert_eq!("123-45-67891", validate_brn("1234567891").unwrap());
+/// assert_eq!(ValidationErrorKind::Checksum, validate_brn("123-45-67890").unwrap_err().kind());
+/// assert_eq!(ValidationErrorKind::Format, validate_brn("123-45-6789").unwrap_err().kind());
+/// ```
+pub fn validate_brn(target: &str) -> Result<String, ValidationError> {
+    let formatted = format_brn(target)?;
+    let digits: Vec<u32> = formatted.chars().filter_map(|c| c.to_digit(10)).collect();
+
+    if business_number_check_digit(&digits[..9]) != digits[9] {
+        return Err(ValidationError::new(
+            ValidationErrorKind::Checksum,
+            "사업자등록번호의 검증 번호가 올바르지 않습니다.",
+        ));
+    }
+
+    Ok(formatted)
+}
+
+/// 사업자등록번호를 `NNN-NN-NNNNN` 형식으로 변환
+///
+/// 형식(10자리 숫자)만 확인하며 검증 번호는 검사하지 않는다([validate_brn] 참고). 공백 및 `-`는 무시한다.
+///
+/// # Arguments
+///
+/// - `target` - 변환 대상 사업자등록번호
+///
+/// # Return
+///
+/// - 변환 결과 `Result<String, ValidationError>`
+///
+/// # Errors
+///
+/// - [ValidationError] - 형식 불일치([ValidationErrorKind::Format])
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::validate_util::format_brn;
+///
+/// assert_eq!("123-45-67890", format_brn("1234567890").unwrap());
+/// assert_eq!("123-45-67890", format_brn("12345 67890").unwrap());
+/// assert!(format_brn("123456789a").is_err());
+/// ```
+pub fn format_brn(target: &str) -> Result<String, ValidationError> {
+    let stripped = strip_separators(target);
+
+    if stripped.len() != 10 || !stripped.chars().all(|c| c.is_ascii_digit()) {
+        return Err(ValidationError::new(
+            ValidationErrorKind::Format,
+            "사업자등록번호 형식이 올바르지 않습니다.",
+        ));
+    }
+
+    Ok(format!(
+        "{}-{}-{}",
+        &stripped[..3],
+        &stripped[3..5],
+        &stripped[5..]
+    ))
+}
+
+/// Luhn 검증 번호 검사
+///
+/// 카드번호, IMEI 등 Luhn 알고리즘을 사용하는 번호의 마지막 자리(검증 번호)를 검사한다. 공백 및 `-`는
+/// 무시하며 자릿수는 검사하지 않는다. 카드번호 형식(12 ~ 19자리)까지 확인할 경우 [validate_card_number]를
+/// 사용한다.
+///
+/// # Arguments
+///
+/// - `target` - 검사 대상 번호
+///
+/// # Return
+///
+/// - 2자리 이상의 숫자이며 검증 번호가 일치할 경우 `true`
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::validate_util::luhn_check;
+///
+/// assert!(luhn_check("4111-1111-1111-1111"));
+/// assert!(luhn_check("49-015420-323751-8"));
+/// assert!(!luhn_check("4111 1111 1111 1112"));
+/// assert!(!luhn_check("0"));
+/// ```
+pub fn luhn_check(target: &str) -> bool {
+    match to_digits(&strip_separators(target)) {
+        Some(digits) if digits.len() >= 2 => {
+            luhn_check_digit(&digits[..digits.len() - 1]) == digits[digits.len() - 1]
+        }
+        _ => false,
+    }
+}
+
+/// 카드번호 유효성 검사(Luhn)
+///
+/// 12 ~ 19자리 숫자의 Luhn 검증 번호([luhn_check])를 검사한다. 공백 및 `-`는 무시한다.
+///
+/// # Arguments
+///
+/// - `target` - 대상 카드번호
+///
+/// # Return
+///
+/// - 구분자를 제거한 번호
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 형식 불일치 혹은 검증 번호 불일치
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::validate_util::validate_card_number;
+///
+/// assert_eq!("4111111111111111", validate_card_number("4111 1111 1111 1111").unwrap());
+/// assert!(validate_card_number("4111-1111-1111-1112").is_err());
+/// ```
+pub fn validate_card_number(target: &str) -> Result<String, InvalidArgumentError> {
+    let stripped = strip_separators(target);
+
+    if !(12..=19).contains(&stripped.len()) || !stripped.chars().all(|c| c.is_ascii_digit()) {
+        return Err(InvalidArgumentError::from(
+            "카드번호 형식이 올바르지 않습니다.",
+        ));
+    }
+
+    if !luhn_check(&stripped) {
+        return Err(InvalidArgumentError::from(
+            "카드번호의 검증 번호가 올바르지 않습니다.",
+        ));
+    }
+
+    Ok(stripped)
+}
+
+/// 카드번호 유효성 검사([validate_card_number]) 후 마스킹
+///
+/// 앞 6자리와 뒤 4자리를 제외한 숫자를 마스킹하며([mask_card]), 공백 및 `-` 등 입력 형식은 유지한다.
+///
+/// # Arguments
+///
+/// - `target` - 대상 카드번호
+///
+/// # Return
+///
+/// - 마스킹 결과
+///
+/// # Errors
+///
+/// - [InvalidArgumentError] - 형식 불일치 혹은 검증 번호 불일치
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::validate_util::mask_card_number;
+///
+/// assert_eq!("4111 11** **** 1111", mask_card_number("4111 1111 1111 1111").unwrap());
+/// assert_eq!("378282*****0005", mask_card_number("378282246310005").unwrap());
+/// assert!(mask_card_number("4111-1111-1111-1112").is_err());
+/// ```
+pub fn mask_card_number(target: &str) -> Result<String, InvalidArgumentError> {
+    validate_card_number(target)?;
+
+    Ok(mask_card(target))
+}
+
+/// 대상 목록 전체를 지정된 검증 함수로 병렬 검증
+///
+/// `rayon` feature 활성화시 사용 가능하며 결과는 입력 순서를 유지한다.
+///
+/// # Arguments
+///
+/// - `targets` - 검증 대상 목록
+/// - `validator` - 검증 함수 (e.g. [validate_rrn], [validate_brn])
+///
+/// # Return
+///
+/// - 입력 순서와 동일한 검증 결과 목록
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::validate_util::{par_validate, validate_brn};
+///
+/// let result = par_validate(&["1234567891", "123-45-67890"], validate_brn);
+///
+/// assert!(result[0].is_ok());
+/// assert!(result[1].is_err());
+/// ```
+#[cfg(feature = "rayon")]
+pub fn par_validate<S, T, E, F>(targets: &[S], validator: F) -> Vec<Result<T, E>>
+where
+    S: AsRef<str> + Sync,
+    T: Send,
+    E: Send,
+    F: Fn(&str) -> Result<T, E> + Sync + Send,
+{
+    use rayon::prelude::*;
+
+    targets.par_iter().map(|v| validator(v.as_ref())).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_plate_kr_test() {
+        let plate = validate_plate_kr("12가 3456").unwrap();
+
+        assert_eq!(None, plate.region());
+        assert_eq!("12", plate.class_number());
+        assert_eq!('가', plate.usage_char());
+        assert_eq!("3456", plate.serial());
+        assert_eq!("12가 3456", plate.to_string());
+        assert_eq!("12가 **56", plate.masked());
+
+        assert_eq!(
+            PlateUsage::Rental,
+            validate_plate_kr("123허-4567").unwrap().usage()
+        );
+        assert_eq!(
+            PlateUsage::Delivery,
+            validate_plate_kr("80배1234").unwrap().usage()
+        );
+        assert_eq!(
+            "경기 12아 3456",
+            validate_plate_kr("경기12아3456").unwrap().normalized()
+        );
+
+        assert!(validate_plate_kr("").is_err());
+        assert!(validate_plate_kr("1234가5678").is_err(), "4자리 차종 번호");
+        assert!(validate_plate_kr("12가345").is_err(), "3자리 일련 번호");
+        assert!(validate_plate_kr("12가34567").is_err(), "5자리 일련 번호");
+        assert!(validate_plate_kr("12A3456").is_err(), "한글 미포함");
+        assert!(
+            validate_plate_kr("평양12가3456").is_err(),
+            "알 수 없는 지역"
+        );
+        assert!(validate_plate_kr("12가34a6").is_err());
+    }
+
+    #[test]
+    fn validate_driver_license_kr_test() {
+        let license = validate_driver_license_kr("28-21-000001-99").unwrap();
+
+        assert_eq!(28, license.region_code());
+        assert_eq!("경기북부", license.region_name());
+        assert_eq!("21", license.year());
+        assert_eq!("000001", license.serial());
+        assert_eq!("28-21-000001-99", license.to_string());
+
+        // 지역 명칭 중 "경기"와 "경기북부" 구분
+        assert_eq!(
+            28,
+            validate_driver_license_kr("경기북부 21-000001-99")
+                .unwrap()
+                .region_code()
+        );
+        assert_eq!(
+            13,
+            validate_driver_license_kr("경기 21-000001-99")
+                .unwrap()
+                .region_code()
+        );
+
+        assert!(validate_driver_license_kr("").is_err());
+        assert!(validate_driver_license_kr("27-21-000001-99").is_err());
+        assert!(validate_driver_license_kr("11-21-000001-9").is_err());
+        assert!(validate_driver_license_kr("11-21-00000a-99").is_err());
+        assert!(validate_driver_license_kr("서울 11-21-000001-99").is_err());
+    }
+
+    #[test]
+    fn check_digit_test() {
+        // 동일 도서의 ISBN-10 및 ISBN-13
+        assert_eq!(
+            Ok('7'),
+            compute_check_digit(CheckDigitKind::Isbn10, "893490135")
+        );
+        assert_eq!("8934901357", validate_isbn10("89-349-0135-7").unwrap());
+        assert_eq!(
+            "9788934901358",
+            validate_isbn13("978-89-349-0135-8").unwrap()
+        );
+
+        assert!(compute_check_digit(CheckDigitKind::Upc, "0360002914a").is_err());
+        assert!(validate_isbn10("X306406152").is_err());
+        assert!(validate_isbn10("03064061522").is_err());
+        assert!(
+            validate_isbn13("1234567890128").is_err(),
+            "978/979 이외의 접두어"
+        );
+        assert_eq!("1234567890128", validate_ean13("1234567890128").unwrap());
+        assert!(validate_ean13("").is_err());
+        assert!(validate_upc("03600029145").is_err(), "11자리");
+
+        // byte 길이는 같지만 ASCII가 아닌 문자를 포함한 경우
+        assert!(validate_isbn10("1가가가").is_err());
+        assert!(validate_isbn10("0306406가").is_err());
+        assert!(validate_isbn13("1가가가가").is_err());
+        assert!(validate_ean13("1가가가가").is_err());
+        assert!(validate_upc("가가가가").is_err());
+    }
+
+    #[test]
+    fn luhn_check_test() {
+        for target in [
+            "4111111111111111",
+            "4111-1111-1111-1111",
+            " 5555 5555 5555 4444 ",
+            "378282246310005",
+            "490154203237518",
+            "18",
+            "00",
+        ] {
+            assert!(luhn_check(target), "{}", target);
+        }
+
+        for target in [
+            "",
+            "0",
+            "-",
+            "4111111111111112",
+            "4111111111111111a",
+            "4111_1111",
+        ] {
+            assert!(!luhn_check(target), "{}", target);
+        }
+
+        assert_eq!(
+            "5555-55**-****-4444",
+            mask_card_number("5555-5555-5555-4444").unwrap()
+        );
+        assert_eq!(
+            "378282*****0005",
+            mask_card_number("378282246310005").unwrap(),
+            "15자리"
+        );
+        assert!(mask_card_number("18").is_err(), "자릿수 불일치");
+        assert!(mask_card_number("41111111111").is_err(), "11자리");
+        assert!(mask_card_number("5555-5555-5555-4445").is_err());
+
+        assert_eq!(
+            Ok("5555555555554444".to_owned()),
+            validate_card_number(" 5555-5555-5555-4444 ")
+        );
+        assert!(validate_card_number("378282246310005").is_ok(), "15자리");
+        assert!(validate_card_number("41111111111").is_err(), "11자리");
+        assert!(validate_card_number("4111111111111111a").is_err());
+        assert!(validate_card_number("5555-5555-5555-4445").is_err());
+    }
+
+    #[test]
+    fn validate_brn_test() {
+        assert_eq!("123-45-67891", validate_brn(" 123 45 67891 ").unwrap());
+        assert_eq!("123-45-67890", format_brn("123-4567890").unwrap());
+
+        let error = validate_brn("1234567890").unwrap_err();
+
+        assert_eq!(ValidationErrorKind::Checksum, error.kind());
+        assert_eq!(
+            "사업자등록번호의 검증 번호가 올바르지 않습니다.",
+            error.get_message()
+        );
+        assert_eq!(
+            "사업자등록번호의 검증 번호가 올바르지 않습니다.",
+            InvalidArgumentError::from(error).get_message()
+        );
+
+        for target in ["", "123456789", "12345678901", "123-45-6789a"] {
+            assert_eq!(
+                ValidationErrorKind::Format,
+                validate_brn(target).unwrap_err().kind()
+            );
+            assert!(format_brn(target).is_err());
+        }
+    }
+
+    #[test]
+    fn validate_rrn_test() {
+        // 외국인(5, 1900년대 남성)
+        let rrn = validate_rrn("850315-5123458").unwrap();
+
+        assert_eq!((1985, 3, 15), rrn.birth_date());
+        assert_eq!(Gender::Male, rrn.gender());
+        assert!(rrn.is_foreigner());
+        assert_eq!("850315-5123458", rrn.to_string());
+
+        // 외국인(8, 2000년대 여성)
+        let rrn = validate_rrn(" 0505058123457 ").unwrap();
+
+        assert_eq!((2005, 5, 5), rrn.birth_date());
+        assert_eq!(Gender::Female, rrn.gender());
+        assert!(rrn.is_foreigner());
+        assert_eq!("050505-8******", rrn.masked());
+
+        // 1800년대(9) 남성
+        let rrn = validate_rrn("990228-9123456").unwrap();
+
+        assert_eq!((1899, 2, 28), rrn.birth_date());
+        assert_eq!(Gender::Male, rrn.gender());
+        assert!(!rrn.is_foreigner());
+
+        // 2000년대(4) 출생, 윤년
+        assert_eq!(
+            "000229-4000004",
+            validate_rrn("000229 4000004").unwrap().normalized()
+        );
+        assert!(
+            validate_rrn("010229-3000000").is_err(),
+            "윤년이 아닌 2월 29일"
+        );
+        assert!(validate_rrn("").is_err());
+        assert!(validate_rrn("9001011234a68").is_err());
+
+        assert_eq!(
+            "주민등록번호 형식이 올바르지 않습니다.",
+            validate_rrn("900101-123456").unwrap_err().get_message()
+        );
+        assert_eq!(
+            "주민등록번호의 생년월일이 올바르지 않습니다.",
+            validate_rrn("900230-1234568").unwrap_err().get_message()
+        );
+        assert_eq!(
+            "주민등록번호의 검증 번호가 올바르지 않습니다.",
+            validate_rrn("900101-1234567").unwrap_err().get_message()
+        );
+    }
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_test() {
+        let plate = validate_plate_kr("12가3456").unwrap();
+        let json = serde_json::to_value(&plate).unwrap();
+
+        assert_eq!(serde_json::json!("12"), json["class_number"]);
+        assert_eq!(plate, serde_json::from_value::<VehiclePlate>(json).unwrap());
+        assert_eq!(
+            r#""Isbn13""#,
+            serde_json::to_string(&CheckDigitKind::Isbn13).unwrap()
+        );
+    }
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_validate_test() {
+        let targets = [
+            "4111 1111 1111 1111",
+            "4111 1111 1111 1112",
+            "978-89-6626-095-9",
+        ];
+        let result = par_validate(&targets, mask_card_number);
+
+        assert_eq!(Ok("4111 11** **** 1111".to_owned()), result[0]);
+        assert!(result[1].is_err());
+        assert!(result[2].is_err());
+        assert_eq!(
+            vec![true, false],
+            par_validate(&["000229-4000004", "000229-4000005"], validate_rrn)
+                .iter()
+                .map(Result::is_ok)
+                .collect::<Vec<bool>>()
+        );
+    }
+}
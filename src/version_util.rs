@@ -0,0 +1,764 @@
+//! 버전(Semantic Versioning 2.0) 관련 함수 모음
+//!
+//! [SemVer 2.0](https://semver.org/lang/ko/) 규칙에 따른 버전 해석 및 비교([SemVer]), 범위 비교([VersionReq])
+//! 함수를 제공한다. 범위 표현식은 Cargo와 동일한 규칙을 따른다.
+
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use crate::error::InvalidArgumentError;
+#[cfg(feature = "serde")]
+use crate::error::LibError;
+
+/// pre-release 식별자
+///
+/// 숫자 식별자는 숫자 크기로, 문자 식별자는 ASCII 순서로 비교하며 숫자 식별자가 항상 작다.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Identifier {
+    /// 숫자 식별자
+    Numeric(u64),
+
+    /// 문자 식별자
+    Alpha(String),
+}
+
+impl Display for Identifier {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Identifier::Numeric(v) => write!(f, "{}", v),
+            Identifier::Alpha(v) => write!(f, "{}", v),
+        }
+    }
+}
+
+/// 버전 증가 단위
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BumpLevel {
+    /// 주(major) 버전
+    Major,
+
+    /// 부(minor) 버전
+    Minor,
+
+    /// 수(patch) 버전
+    Patch,
+}
+
+/// Semantic version
+///
+/// 비교시 build metadata는 우선순위에 영향을 주지 않으며([SemVer::cmp_precedence]), [Ord]는
+/// 우선순위가 같을 경우에만 build metadata를 문자열로 비교한다.
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::version_util::{BumpLevel, SemVer};
+///
+/// let version = SemVer::parse("1.4.0-rc.1").unwrap();
+///
+/// assert!(version < SemVer::parse("1.4.0").unwrap());
+/// assert!(version > SemVer::parse("1.4.0-beta.11").unwrap());
+/// assert!(version.satisfies(">=1.4.0-rc.0, <2").unwrap());
+/// assert!(!version.satisfies("^1.2").unwrap());
+/// assert_eq!("1.4.0", version.bump(BumpLevel::Patch).to_string());
+/// assert_eq!("2.0.0", version.bump(BumpLevel::Major).to_string());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SemVer {
+    pub major: u64,
+
+    pub minor: u64,
+
+    pub patch: u64,
+
+    /// pre-release 식별자 목록
+    pub pre: Vec<Identifier>,
+
+    /// build metadata
+    pub build: Vec<String>,
+}
+
+impl SemVer {
+    /// [SemVer] 생성
+    pub fn new(major: u64, minor: u64, patch: u64) -> Self {
+        SemVer {
+            major,
+            minor,
+            patch,
+            pre: vec![],
+            build: vec![],
+        }
+    }
+
+    /// 버전 문자열 해석
+    ///
+    /// 앞뒤 공백 및 앞쪽의 `v`(`V`)는 무시한다.
+    ///
+    /// # Arguments
+    ///
+    /// - `version` - 버전 문자열 (e.g. `1.4.0-rc.1+build.5`)
+    ///
+    /// # Return
+    ///
+    /// - 해석 결과 `Result<SemVer, InvalidArgumentError>`
+    ///
+    /// # Errors
+    ///
+    /// - [InvalidArgumentError] - SemVer 2.0 형식이 아닌 경우(e.g. `1.2`, `01.2.3`, `1.2.3-`)
+    pub fn parse(version: &str) -> Result<SemVer, InvalidArgumentError> {
+        let trimmed = version.trim();
+        let trimmed = trimmed.strip_prefix(['v', 'V']).unwrap_or(trimmed);
+        let (rest, build) = match trimmed.split_once('+') {
+            Some((r, b)) => (r, parse_build(b, version)?),
+            None => (trimmed, vec![]),
+        };
+        let (core, pre) = match rest.split_once('-') {
+            Some((c, p)) => (c, parse_pre(p, version)?),
+            None => (rest, vec![]),
+        };
+        let numbers: Vec<&str> = core.split('.').collect();
+
+        if numbers.len() != 3 {
+            return Err(version_error(version));
+        }
+
+        Ok(SemVer {
+            major: parse_numeric(numbers[0]).ok_or_else(|| version_error(version))?,
+            minor: parse_numeric(numbers[1]).ok_or_else(|| version_error(version))?,
+            patch: parse_numeric(numbers[2]).ok_or_else(|| version_error(version))?,
+            pre,
+            build,
+        })
+    }
+
+    /// pre-release 버전 여부
+    #[inline]
+    pub fn is_prerelease(&self) -> bool {
+        !self.pre.is_empty()
+    }
+
+    /// build metadata를 제외한 우선순위 비교
+    pub fn cmp_precedence(&self, other: &SemVer) -> Ordering {
+        self.major
+            .cmp(&other.major)
+            .then(self.minor.cmp(&other.minor))
+            .then(self.patch.cmp(&other.patch))
+            .then_with(|| compare_pre(&self.pre, &other.pre))
+    }
+
+    /// 범위 비교
+    ///
+    /// # Arguments
+    ///
+    /// - `requirement` - 범위 표현식. [VersionReq::parse] 참고
+    ///
+    /// # Errors
+    ///
+    /// - [InvalidArgumentError] - 잘못된 범위 표현식
+    pub fn satisfies(&self, requirement: &str) -> Result<bool, InvalidArgumentError> {
+        Ok(VersionReq::parse(requirement)?.matches(self))
+    }
+
+    /// 버전 증가
+    ///
+    /// 하위 버전은 `0`으로 초기화되며 pre-release 및 build metadata는 제거된다. pre-release 버전의 경우
+    /// 해당 단위의 하위 버전이 모두 `0`이면 pre-release만 제거한다(e.g. `2.0.0-rc.1` Major -> `2.0.0`).
+    pub fn bump(&self, level: BumpLevel) -> SemVer {
+        let pre = self.is_prerelease();
+
+        match level {
+            BumpLevel::Major if pre && self.minor == 0 && self.patch == 0 => {
+                SemVer::new(self.major, 0, 0)
+            }
+            BumpLevel::Major => SemVer::new(self.major + 1, 0, 0),
+            BumpLevel::Minor if pre && self.patch == 0 => SemVer::new(self.major, self.minor, 0),
+            BumpLevel::Minor => SemVer::new(self.major, self.minor + 1, 0),
+            BumpLevel::Patch if pre => SemVer::new(self.major, self.minor, self.patch),
+            BumpLevel::Patch => SemVer::new(self.major, self.minor, self.patch + 1),
+        }
+    }
+}
+
+impl Display for SemVer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)?;
+
+        if !self.pre.is_empty() {
+            write!(f, "-{}", join(&self.pre))?;
+        }
+
+        if !self.build.is_empty() {
+            write!(f, "+{}", self.build.join("."))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for SemVer {
+    type Err = InvalidArgumentError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        SemVer::parse(s)
+    }
+}
+
+/// 버전 문자열(e.g. `"1.2.3-beta.1"`)로 직렬화
+#[cfg(feature = "serde")]
+impl serde::Serialize for SemVer {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for SemVer {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+
+        SemVer::parse(&value).map_err(|e| serde::de::Error::custom(e.get_message()))
+    }
+}
+
+impl PartialOrd for SemVer {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SemVer {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.cmp_precedence(other)
+            .then_with(|| self.build.cmp(&other.build))
+    }
+}
+
+/// 범위 비교 연산자
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Op {
+    Exact,
+    Greater,
+    GreaterEq,
+    Less,
+    LessEq,
+    Tilde,
+    Caret,
+    Wildcard,
+}
+
+/// 범위 비교 항목. 생략된 minor/patch는 `None`
+#[derive(Debug, Clone, PartialEq)]
+struct Comparator {
+    op: Op,
+    major: u64,
+    minor: Option<u64>,
+    patch: Option<u64>,
+    pre: Vec<Identifier>,
+}
+
+/// 버전 범위
+///
+/// `,` 혹은 공백으로 구분된 항목은 모두 만족해야 하며(AND), `||`로 구분된 항목은 하나만 만족하면 된다(OR).
+/// 연산자가 없는 경우 `^`로 처리한다.
+///
+/// - `=1.2.3`, `>1.2`, `>=1.2.3`, `<2`, `<=1.2.3`
+/// - `~1.2.3` - `>=1.2.3, <1.3.0`
+/// - `^1.2.3` - `>=1.2.3, <2.0.0` (`^0.2.3` - `>=0.2.3, <0.3.0`)
+/// - `1.2.*`, `1.x`, `*`
+///
+/// pre-release 버전은 동일한 `major.minor.patch`에 pre-release가 지정된 항목이 있는 경우에만 만족한다.
+///
+/// # Example
+///
+/// ```rust
+/// use cliff3_util::version_util::{SemVer, VersionReq};
+///
+/// let req = VersionReq::parse("^1.2 || ~0.9.3").unwrap();
+///
+/// assert!(req.matches(&SemVer::parse("1.9.0").unwrap()));
+/// assert!(req.matches(&SemVer::parse("0.9.7").unwrap()));
+/// assert!(!req.matches(&SemVer::parse("0.10.0").unwrap()));
+/// assert!(!req.matches(&SemVer::parse("2.0.0-rc.1").unwrap()));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct VersionReq {
+    /// OR로 연결된 AND 항목 목록
+    sets: Vec<Vec<Comparator>>,
+}
+
+impl VersionReq {
+    /// 범위 표현식 해석
+    ///
+    /// # Errors
+    ///
+    /// - [InvalidArgumentError] - 잘못된 범위 표현식
+    pub fn parse(requirement: &str) -> Result<VersionReq, InvalidArgumentError> {
+        let mut sets = vec![];
+
+        for set in requirement.split("||") {
+            let comparators = tokenize(set)
+                .iter()
+                .map(|v| parse_comparator(v, requirement))
+                .collect::<Result<Vec<Comparator>, InvalidArgumentError>>()?;
+
+            if comparators.is_empty() {
+                return Err(req_error(requirement));
+            }
+
+            sets.push(comparators);
+        }
+
+        Ok(VersionReq { sets })
+    }
+
+    /// 범위 만족 여부
+    pub fn matches(&self, version: &SemVer) -> bool {
+        self.sets.iter().any(|set| {
+            set.iter().all(|c| c.matches(version))
+                && (!version.is_prerelease() || set.iter().any(|c| c.allows_pre(version)))
+        })
+    }
+}
+
+impl FromStr for VersionReq {
+    type Err = InvalidArgumentError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        VersionReq::parse(s)
+    }
+}
+
+impl Comparator {
+    fn matches(&self, v: &SemVer) -> bool {
+        match self.op {
+            Op::Exact | Op::Wildcard => self.exact(v),
+            Op::Greater => self.greater(v),
+            Op::GreaterEq => self.exact(v) || self.greater(v),
+            Op::Less => self.less(v),
+            Op::LessEq => self.exact(v) || self.less(v),
+            Op::Tilde => self.tilde(v),
+            Op::Caret => self.caret(v),
+        }
+    }
+
+    /// pre-release 버전 허용 여부
+    fn allows_pre(&self, v: &SemVer) -> bool {
+        !self.pre.is_empty()
+            && self.major == v.major
+            && self.minor == Some(v.minor)
+            && self.patch == Some(v.patch)
+    }
+
+    fn exact(&self, v: &SemVer) -> bool {
+        if v.major != self.major {
+            return false;
+        }
+
+        match (self.minor, self.patch) {
+            (None, _) => true,
+            (Some(minor), _) if v.minor != minor => false,
+            (Some(_), None) => true,
+            (Some(_), Some(patch)) => v.patch == patch && v.pre == self.pre,
+        }
+    }
+
+    fn greater(&self, v: &SemVer) -> bool {
+        if v.major != self.major {
+            return v.major > self.major;
+        }
+
+        let Some(minor) = self.minor else {
+            return false;
+        };
+
+        if v.minor != minor {
+            return v.minor > minor;
+        }
+
+        let Some(patch) = self.patch else {
+            return false;
+        };
+
+        if v.patch != patch {
+            return v.patch > patch;
+        }
+
+        compare_pre(&v.pre, &self.pre) == Ordering::Greater
+    }
+
+    fn less(&self, v: &SemVer) -> bool {
+        if v.major != self.major {
+            return v.major < self.major;
+        }
+
+        let Some(minor) = self.minor else {
+            return false;
+        };
+
+        if v.minor != minor {
+            return v.minor < minor;
+        }
+
+        let Some(patch) = self.patch else {
+            return false;
+        };
+
+        if v.patch != patch {
+            return v.patch < patch;
+        }
+
+        compare_pre(&v.pre, &self.pre) == Ordering::Less
+    }
+
+    fn tilde(&self, v: &SemVer) -> bool {
+        if v.major != self.major {
+            return false;
+        }
+
+        match (self.minor, self.patch) {
+            (None, _) => true,
+            (Some(minor), _) if v.minor != minor => false,
+            (Some(_), None) => true,
+            (Some(_), Some(patch)) if v.patch != patch => v.patch > patch,
+            _ => compare_pre(&v.pre, &self.pre) != Ordering::Less,
+        }
+    }
+
+    fn caret(&self, v: &SemVer) -> bool {
+        if v.major != self.major {
+            return false;
+        }
+
+        let Some(minor) = self.minor else {
+            return true;
+        };
+
+        let Some(patch) = self.patch else {
+            return if self.major > 0 {
+                v.minor >= minor
+            } else {
+                v.minor == minor
+            };
+        };
+
+        if self.major > 0 {
+            if v.minor != minor {
+                return v.minor > minor;
+            }
+
+            if v.patch != patch {
+                return v.patch > patch;
+            }
+        } else if minor > 0 {
+            if v.minor != minor {
+                return false;
+            }
+
+            if v.patch != patch {
+                return v.patch > patch;
+            }
+        } else if v.minor != minor || v.patch != patch {
+            return false;
+        }
+
+        compare_pre(&v.pre, &self.pre) != Ordering::Less
+    }
+}
+
+/// pre-release 비교. pre-release가 없는 버전이 더 크다.
+fn compare_pre(a: &[Identifier], b: &[Identifier]) -> Ordering {
+    match (a.is_empty(), b.is_empty()) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        (false, false) => a.cmp(b),
+    }
+}
+
+/// 연산자와 버전 사이의 공백을 붙여 항목 단위로 분리 (e.g. `>= 1.2 < 2` -> `[">=1.2", "<2"]`)
+fn tokenize(set: &str) -> Vec<String> {
+    let mut tokens: Vec<String> = vec![];
+    let mut pending_op = String::new();
+
+    for part in set.split([',', ' ', '\t']).filter(|v| !v.is_empty()) {
+        if part.chars().all(|c| "=<>~^".contains(c)) {
+            pending_op.push_str(part);
+        } else {
+            tokens.push(format!("{}{}", pending_op, part));
+            pending_op.clear();
+        }
+    }
+
+    if !pending_op.is_empty() {
+        tokens.push(pending_op);
+    }
+
+    tokens
+}
+
+fn parse_comparator(token: &str, requirement: &str) -> Result<Comparator, InvalidArgumentError> {
+    let (op, rest) = [
+        (">=", Op::GreaterEq),
+        ("<=", Op::LessEq),
+        (">", Op::Greater),
+        ("<", Op::Less),
+        ("=", Op::Exact),
+        ("~", Op::Tilde),
+        ("^", Op::Caret),
+    ]
+    .iter()
+    .find_map(|(p, op)| token.strip_prefix(p).map(|r| (Some(*op), r)))
+    .unwrap_or((None, token));
+
+    // build metadata는 범위 비교에 사용하지 않음
+    let rest = rest.split_once('+').map_or(rest, |(r, _)| r);
+    let (core, pre) = match rest.split_once('-') {
+        Some((c, p)) => (c, parse_pre(p, requirement)?),
+        None => (rest, vec![]),
+    };
+    let mut numbers: Vec<Option<u64>> = vec![];
+    let mut wildcard = false;
+
+    for part in core.split('.') {
+        if matches!(part, "*" | "x" | "X") {
+            wildcard = true;
+            numbers.push(None);
+        } else if wildcard {
+            // 와일드카드 이후 숫자 불가 (e.g. `1.*.3`)
+            return Err(req_error(requirement));
+        } else {
+            numbers.push(Some(
+                parse_numeric(part).ok_or_else(|| req_error(requirement))?,
+            ));
+        }
+    }
+
+    if numbers.len() > 3 || (wildcard && !pre.is_empty()) {
+        return Err(req_error(requirement));
+    }
+
+    let get = |i: usize| numbers.get(i).copied().flatten();
+
+    if wildcard && numbers[0].is_none() {
+        // `*`는 모든 버전
+        return Ok(Comparator {
+            op: Op::GreaterEq,
+            major: 0,
+            minor: Some(0),
+            patch: Some(0),
+            pre: vec![],
+        });
+    }
+
+    let op = match op {
+        Some(v) => v,
+        None if wildcard => Op::Wildcard,
+        None => Op::Caret,
+    };
+
+    Ok(Comparator {
+        op,
+        major: get(0).ok_or_else(|| req_error(requirement))?,
+        minor: get(1),
+        patch: if get(1).is_some() { get(2) } else { None },
+        pre,
+    })
+}
+
+/// 선행 0이 없는 숫자 해석
+fn parse_numeric(value: &str) -> Option<u64> {
+    if value.is_empty()
+        || !value.bytes().all(|b| b.is_ascii_digit())
+        || (value.len() > 1 && value.starts_with('0'))
+    {
+        return None;
+    }
+
+    value.parse().ok()
+}
+
+fn is_identifier(value: &str) -> bool {
+    !value.is_empty()
+        && value
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b == b'-')
+}
+
+fn parse_pre(value: &str, source: &str) -> Result<Vec<Identifier>, InvalidArgumentError> {
+    value
+        .split('.')
+        .map(|v| {
+            if !is_identifier(v) {
+                Err(version_error(source))
+            } else if v.bytes().all(|b| b.is_ascii_digit()) {
+                parse_numeric(v)
+                    .map(Identifier::Numeric)
+                    .ok_or_else(|| version_error(source))
+            } else {
+                Ok(Identifier::Alpha(v.to_owned()))
+            }
+        })
+        .collect()
+}
+
+fn parse_build(value: &str, source: &str) -> Result<Vec<String>, InvalidArgumentError> {
+    value
+        .split('.')
+        .map(|v| {
+            if is_identifier(v) {
+                Ok(v.to_owned())
+            } else {
+                Err(version_error(source))
+            }
+        })
+        .collect()
+}
+
+fn join(identifiers: &[Identifier]) -> String {
+    identifiers
+        .iter()
+        .map(|v| v.to_string())
+        .collect::<Vec<String>>()
+        .join(".")
+}
+
+fn version_error(version: &str) -> InvalidArgumentError {
+    InvalidArgumentError::new(&format!("잘못된 버전 형식 입니다({}).", version))
+}
+
+fn req_error(requirement: &str) -> InvalidArgumentError {
+    InvalidArgumentError::new(&format!("잘못된 버전 범위 형식 입니다({}).", requirement))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn v(version: &str) -> SemVer {
+        SemVer::parse(version).unwrap()
+    }
+
+    #[test]
+    fn parse_test() {
+        let version = v("v1.4.0-rc.1+build.5");
+
+        assert_eq!((1, 4, 0), (version.major, version.minor, version.patch));
+        assert_eq!(
+            vec![Identifier::Alpha("rc".to_owned()), Identifier::Numeric(1)],
+            version.pre
+        );
+        assert_eq!(vec!["build", "5"], version.build);
+        assert_eq!("1.4.0-rc.1+build.5", version.to_string());
+
+        for invalid in [
+            "1.2",
+            "1.2.3.4",
+            "01.2.3",
+            "1.2.3-",
+            "1.2.3-01",
+            "1.2.3+",
+            "1.2.3-a..b",
+            "a.b.c",
+            "",
+        ] {
+            assert!(SemVer::parse(invalid).is_err(), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn ordering_test() {
+        // semver.org 예시
+        let ordered = [
+            "1.0.0-alpha",
+            "1.0.0-alpha.1",
+            "1.0.0-alpha.beta",
+            "1.0.0-beta",
+            "1.0.0-beta.2",
+            "1.0.0-beta.11",
+            "1.0.0-rc.1",
+            "1.0.0",
+            "1.0.1",
+            "1.1.0",
+            "2.0.0",
+        ];
+
+        for pair in ordered.windows(2) {
+            assert!(v(pair[0]) < v(pair[1]), "{} < {}", pair[0], pair[1]);
+        }
+
+        assert_eq!(Ordering::Equal, v("1.0.0+a").cmp_precedence(&v("1.0.0+b")));
+        assert!(v("1.0.0+a") < v("1.0.0+b"));
+    }
+
+    #[test]
+    fn satisfies_test() {
+        let cases = [
+            ("^1.2", "1.2.0", true),
+            ("^1.2", "1.9.9", true),
+            ("^1.2", "2.0.0", false),
+            ("^1.2", "1.1.9", false),
+            ("^0.2.3", "0.2.9", true),
+            ("^0.2.3", "0.3.0", false),
+            ("^0.0.3", "0.0.4", false),
+            ("~1.2.3", "1.2.9", true),
+            ("~1.2.3", "1.3.0", false),
+            ("~1", "1.9.0", true),
+            ("1.2.*", "1.2.7", true),
+            ("1.x", "2.0.0", false),
+            ("*", "3.1.4", true),
+            ("=1.2", "1.2.5", true),
+            (">1.2", "1.2.9", false),
+            (">1.2", "1.3.0", true),
+            ("<=1.2", "1.2.9", true),
+            (">= 1.2.3, < 2", "1.5.0", true),
+            (">=1.2.3 <1.5", "1.5.0", false),
+            ("<1.0 || >=2.0", "1.5.0", false),
+            ("<1.0 || >=2.0", "2.1.0", true),
+            // pre-release
+            ("^1.2", "1.3.0-rc.1", false),
+            (">=1.4.0-rc.0", "1.4.0-rc.1", true),
+            (">=1.4.0-rc.0", "1.5.0-rc.1", false),
+            ("<1.4.0", "1.4.0-rc.1", false),
+        ];
+
+        for (req, version, expected) in cases {
+            assert_eq!(
+                expected,
+                v(version).satisfies(req).unwrap(),
+                "{} {}",
+                version,
+                req
+            );
+        }
+
+        for invalid in ["", ">=", "1.*.3", "^a", "1.2.3.4", "1.* || "] {
+            assert!(VersionReq::parse(invalid).is_err(), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn bump_test() {
+        assert_eq!(v("2.0.0"), v("1.4.7-rc.1+b").bump(BumpLevel::Major));
+        assert_eq!(v("1.5.0"), v("1.4.7").bump(BumpLevel::Minor));
+        assert_eq!(v("1.4.8"), v("1.4.7").bump(BumpLevel::Patch));
+        assert_eq!(v("2.0.0"), v("2.0.0-rc.1").bump(BumpLevel::Major));
+        assert_eq!(v("1.5.0"), v("1.5.0-beta").bump(BumpLevel::Minor));
+        assert_eq!(v("1.4.7"), v("1.4.7-rc.1").bump(BumpLevel::Patch));
+    }
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_test() {
+        let version = v("1.2.3-beta.1+build.5");
+        let json = serde_json::to_string(&version).unwrap();
+
+        assert_eq!(r#""1.2.3-beta.1+build.5""#, json);
+        assert_eq!(version, serde_json::from_str::<SemVer>(&json).unwrap());
+        assert!(serde_json::from_str::<SemVer>(r#""1.2""#).is_err());
+        assert_eq!(
+            BumpLevel::Minor,
+            serde_json::from_str::<BumpLevel>(r#""Minor""#).unwrap()
+        );
+    }
+}
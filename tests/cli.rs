@@ -0,0 +1,129 @@
+//! `cliff3` 명령행 도구 테스트(`cli` feature)
+
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+fn cliff3(args: &[&str], stdin: Option<&[u8]>) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_cliff3"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.unwrap_or_default())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(args: &[&str]) -> String {
+    let output = cliff3(args, None);
+
+    assert!(output.status.success(), "{:?}", output);
+
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn date_test() {
+    assert_eq!(
+        "2024-11-22 01:29:48\n",
+        stdout(&[
+            "date",
+            "convert",
+            "2024-11-22 10:29:48",
+            "--from",
+            "Asia/Seoul"
+        ])
+    );
+    assert_eq!(
+        "20241122065948\n",
+        stdout(&[
+            "date",
+            "convert",
+            "20241122102948",
+            "-p",
+            "%Y%m%d%H%M%S",
+            "--from",
+            "Asia/Seoul",
+            "--to",
+            "Asia/Kolkata"
+        ])
+    );
+
+    let output = cliff3(&["date", "convert", "now", "--from", "Mars/Olympus"], None);
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Mars/Olympus"));
+}
+
+#[test]
+fn hash_mask_uuid_test() {
+    assert_eq!(
+        "4edf07edc95b2fdcbcaf2378fd12d8ac212c2aa6e326c59c3e629be3039d6432\n",
+        stdout(&["hash", "text", "test", "--salt", "salt"])
+    );
+
+    let path = std::env::temp_dir().join(format!("cliff3_cli_{}", std::process::id()));
+
+    std::fs::write(&path, "test").unwrap();
+
+    assert_eq!(
+        format!(
+            "9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08  {}\n",
+            path.display()
+        ),
+        stdout(&["hash", "file", path.to_str().unwrap()])
+    );
+
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(
+        "010-****-5678\n",
+        stdout(&["mask", "phone", "010-1234-5678"])
+    );
+    assert_eq!(
+        "ho**@test.com\n",
+        stdout(&["mask", "email", "hong@test.com"])
+    );
+
+    let uuids = stdout(&["uuid", "--count", "3"]);
+    let uuids: Vec<&str> = uuids.lines().collect();
+
+    assert_eq!(3, uuids.len());
+    assert_eq!(36, uuids[0].len());
+    assert_eq!(Some('4'), uuids[0].chars().nth(14));
+    assert_ne!(uuids[0], uuids[1]);
+}
+
+#[test]
+fn encrypt_test() {
+    let plain = "암호화 대상 문자열".repeat(100);
+    let encrypted = cliff3(
+        &["encrypt", "-s", "secret", "--salt", "12ag3$s!"],
+        Some(plain.as_bytes()),
+    );
+
+    assert!(encrypted.status.success());
+    assert_ne!(plain.as_bytes(), encrypted.stdout.as_slice());
+
+    let decrypted = cliff3(
+        &["decrypt", "-s", "secret", "--salt", "12ag3$s!"],
+        Some(&encrypted.stdout),
+    );
+
+    assert!(decrypted.status.success());
+    assert_eq!(plain.as_bytes(), decrypted.stdout.as_slice());
+
+    let failed = cliff3(
+        &["decrypt", "-s", "wrong", "--salt", "12ag3$s!"],
+        Some(&encrypted.stdout),
+    );
+
+    assert!(!failed.status.success());
+}
@@ -0,0 +1,54 @@
+//! `wasm32-unknown-unknown` 대상 테스트
+//!
+//! `wasm-bindgen-test-runner`(`wasm-bindgen-cli`)와 node.js가 필요하다.
+//!
+//! ```text
+//! $ cargo install wasm-bindgen-cli
+//! $ cargo test --target wasm32-unknown-unknown --no-default-features --features string,date,validate --test wasm
+//! ```
+#![cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+
+use chrono::NaiveDateTime;
+use chrono_tz::Asia::Seoul;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+use cliff3_util::date_util::{get_week_start_end, local_datetime_to_utc};
+use cliff3_util::string_util::mask::mask_phone;
+use cliff3_util::string_util::{extract_initial_consonant, generate_random_string, validate_email};
+use cliff3_util::validate_util::{validate_card_number, validate_isbn13, validate_rrn};
+
+#[wasm_bindgen_test]
+fn date_test() {
+    let utc = local_datetime_to_utc("2024-03-01 09:00:00", "%Y-%m-%d %H:%M:%S", &Seoul).unwrap();
+
+    assert_eq!(
+        "2024-03-01 00:00:00",
+        utc.format("%Y-%m-%d %H:%M:%S").to_string()
+    );
+
+    let target = NaiveDateTime::parse_from_str("2024-03-06 12:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    let (monday, sunday) = get_week_start_end(&target).unwrap();
+
+    assert_eq!("2024-03-04", monday.format("%Y-%m-%d").to_string());
+    assert_eq!("2024-03-10", sunday.format("%Y-%m-%d").to_string());
+}
+
+#[wasm_bindgen_test]
+fn string_test() {
+    assert_eq!("ㅎㄱ", extract_initial_consonant(Some("한글")).unwrap());
+    assert!(validate_email(Some("test@test.com")).unwrap());
+    assert_eq!("010-****-5678", mask_phone("010-1234-5678"));
+    // getrandom(js) 사용
+    assert_eq!(16, generate_random_string(16).unwrap().len());
+}
+
+#[wasm_bindgen_test]
+fn validate_test() {
+    assert_eq!(
+        "9788966260959",
+        validate_isbn13("978-89-6626-095-9").unwrap()
+    );
+    assert!(validate_rrn("000229-4000004").is_ok());
+    assert!(validate_card_number("4111 1111 1111 1111").is_ok());
+    assert!(validate_card_number("4111 1111 1111 1112").is_err());
+}